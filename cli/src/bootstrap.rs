@@ -0,0 +1,103 @@
+//! Sample curve set and config snippet of the `bootstrap` command, which prepares the authority
+//! for development on devnet or a local validator
+
+use serde_json::{json, Map, Value};
+
+use curvy::state::curve::{CurveParams, CurveY, MAX_Y_CNT};
+
+use crate::output::RestoredCurve;
+
+/// Decimals of utilization (`x`) and rate (`y`) of the sample curves
+pub const SAMPLE_DECIMALS: u8 = 4;
+
+/// Borrow rate curves: name, formula and rates at utilization 0%, 10% .. 100%
+const SAMPLES: [(&str, &str, [CurveY; 11]); 3] = [
+    (
+        "SOL-borrow-demo",
+        "kink at 80%",
+        [0, 50, 100, 150, 200, 250, 300, 350, 400, 2_000, 10_000],
+    ),
+    (
+        "USDC-borrow-demo",
+        "kink at 90%",
+        [0, 40, 80, 120, 160, 200, 240, 280, 320, 360, 5_000],
+    ),
+    (
+        "ETH-borrow-demo",
+        "kink at 70%",
+        [0, 60, 120, 180, 240, 300, 360, 420, 1_500, 4_000, 8_000],
+    ),
+];
+
+/// Params of the sample curves created by `bootstrap`
+pub fn sample_curves() -> Vec<CurveParams> {
+    SAMPLES
+        .iter()
+        .map(|(name, formula, samples)| {
+            let mut y = [0; MAX_Y_CNT];
+            y[..samples.len()].copy_from_slice(samples);
+            // utilization step of 0.1
+            CurveParams::new(
+                name,
+                formula,
+                0,
+                1_000,
+                samples.len() as u8,
+                SAMPLE_DECIMALS,
+                y,
+            )
+        })
+        .collect()
+}
+
+/// Config of downstream services reading the bootstrapped curves from `rpc_url`
+pub fn config_snippet(rpc_url: &str, curves: &[RestoredCurve]) -> Value {
+    let curves = curves
+        .iter()
+        .filter_map(|restored| {
+            let curve = restored.curve?;
+            Some((restored.name.clone(), Value::String(curve.to_string())))
+        })
+        .collect::<Map<_, _>>();
+
+    json!({
+        "rpc_url": rpc_url,
+        "program_id": curvy::ID.to_string(),
+        "curves": curves,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use solana_sdk::pubkey::Pubkey;
+
+    use curvy::state::curve::Curve;
+
+    use crate::output::RestoreAction;
+
+    use super::*;
+
+    #[test]
+    fn samples_are_valid_curves() {
+        let samples = sample_curves();
+        for params in &samples {
+            Curve::check_params(params).unwrap();
+        }
+
+        let curves = samples
+            .iter()
+            .map(|params| RestoredCurve {
+                name: curvy::state::utils::bytes_to_cow(&params.name).into_owned(),
+                action: RestoreAction::Created,
+                curve: Some(Pubkey::new_unique()),
+                signature: None,
+            })
+            .collect::<Vec<_>>();
+        let config = config_snippet("http://localhost:8899", &curves);
+        assert_eq!(config["curves"].as_object().unwrap().len(), SAMPLES.len());
+        assert_eq!(
+            config["curves"]["SOL-borrow-demo"],
+            curves[0].curve.unwrap().to_string()
+        );
+    }
+}
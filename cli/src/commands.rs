@@ -0,0 +1,2677 @@
+use std::path::Path;
+use std::str::FromStr;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use anyhow::{anyhow, bail, Context, Result};
+use solana_sdk::hash::hash;
+use solana_sdk::native_token::{lamports_to_sol, sol_to_lamports};
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::{read_keypair_file, Keypair, Signature};
+use solana_sdk::signer::Signer;
+use texture_common::_export::Zeroable;
+use texture_common::account::PodAccount;
+use texture_common::math::Decimal;
+
+use curvy::instruction::AlterCurve;
+use curvy::state::config::Config;
+use curvy::state::curve::{Curve, CurveParams, CurveX, CurveY, YTransform, MAX_Y_CNT};
+use curvy::state::curve_confidence::CurveConfidenceBps;
+use curvy::state::curve_extension::{
+    CurveExtension, CurveGuardrails, CurveSchedule, CurveTags, ExtensionType,
+};
+use curvy::state::curve_series::MAX_EXTRA_SERIES;
+use curvy::state::grid_curve::{GridCurveParams, MAX_GRID_Y_CNT};
+use curvy::state::utils::{bytes_to_cow, str_to_array};
+use curvy::state::wide_curve::{WideCurveParams, WideCurveY, MAX_WIDE_Y_CNT};
+use curvy_client::{
+    error_table, fit_per_transaction, load_curves, ConsumerLayout, CurveUpdate, CurveView,
+    CurvyClient, ExpectedState, FieldChange, ReconcileAction, ReconcilePlan, Snapshot, WithContext,
+};
+use curvy_utils::{
+    approximation_error, calc_y_series, calc_y_with_confidence, changed_points, curve_from_base64,
+    describe_change, describe_curve, fit_uniform, invert_y_transform, smooth, to_scaled, twa_curve,
+    AnyCurve, CurvePack, Rounding, SummaryStyle,
+};
+
+use crate::bootstrap::{config_snippet, sample_curves};
+use crate::confirm::{confirm_plan_action, ConfirmOptions};
+use crate::export::export;
+use crate::fixture::{curve_fixture, record_fixture};
+use crate::lint::lint_files;
+use crate::opts::{
+    AcceptOwnerArgs, AlterCurveArgs, AlterGridCurveArgs, AlterWideCurveArgs, ApplyArgs,
+    BootstrapArgs, CalcYArgs, CheckConsumerArgs, Command, CreateCurveArgs, CreateGridCurveArgs,
+    CreateWideCurveArgs, CsvSource, CurveArgs, CurveForMintArgs, CurvesArgs, DecodeAccountArgs,
+    DelegateArgs, DeleteArgs, DeleteCurveArgs, ErrorsArgs, EvaluateCurveArgs, ExportAllArgs,
+    FitArgs, InitConfigArgs, LintFilesArgs, PackArgs, PatchYArgs, PlanArgs, ProposeOwnerArgs,
+    RecordFixtureArgs, ReserveCurveArgs, ResolveCurveArgs, RestoreArgs, SetAlterDelayArgs,
+    SetAssetArgs, SetConfigArgs, SetCurveExpiryArgs, SetCurveExtensionArgs, SetCurveOwnersArgs,
+    SetCurveStatusArgs, SetCreationFrozenArgs, SetSeriesArgs, SmoothArgs, SnapshotArgs, SummarizeArgs,
+    TwaArgs,
+};
+use crate::output::{AppliedAction, CurveList, RestoreAction, RestoredCurve};
+use crate::source::HttpSource;
+use crate::CommandOutput;
+
+/// CSV column with per-sample confidence, see [Samples::confidence]
+pub const CONFIDENCE_COLUMN: &str = "confidence";
+
+/// Parses Y value written with decimal point, e.g. `1.500000` or `-0.25`, into raw units
+fn parse_curve_y(s: &str) -> Result<i64> {
+    Ok(s.trim().replace('.', "").parse::<i64>()?)
+}
+
+/// Raw `y` stored as is, or as `i32` bits when the curve has signed Y (see [Samples::y_signed])
+fn to_curve_y(value: i64, y_signed: bool) -> Result<CurveY> {
+    let stored = if y_signed {
+        i32::try_from(value).map(|value| value as CurveY).ok()
+    } else {
+        CurveY::try_from(value).ok()
+    };
+
+    stored.ok_or_else(|| anyhow!("y {value} is out of Y range"))
+}
+
+/// Curve samples read from CSV
+pub struct Samples {
+    /// Absolute value of the first `x`, see [Samples::x0_negative]
+    pub x0: CurveX,
+    pub x_step: CurveX,
+    /// The first `x` is negative
+    pub x0_negative: bool,
+    /// Some `y` is negative, values are stored as `i32` bits, see [Curve::y_value]
+    pub y_signed: bool,
+    pub y_count: u8,
+    pub y: [CurveY; MAX_Y_CNT],
+    /// Additional `y` series from the columns after `f_x`
+    pub series: Vec<[CurveY; MAX_Y_CNT]>,
+    /// Per-sample confidence in basis points of `y` from the `confidence` column
+    pub confidence: Option<[CurveConfidenceBps; MAX_Y_CNT]>,
+}
+
+impl Samples {
+    /// Replaces `y` values of the main and additional series, raw units with `decimals`, by
+    /// their stored form under `transform` (see [YTransform]), updating [Samples::y_signed]
+    pub fn transform_y(&mut self, transform: YTransform, decimals: u8) -> Result<()> {
+        if transform == YTransform::Linear {
+            return Ok(());
+        }
+
+        let count = self.y_count as usize;
+        let stored = std::iter::once(&self.y)
+            .chain(&self.series)
+            .map(|y| {
+                y[..count]
+                    .iter()
+                    .map(|y| {
+                        let raw = if self.y_signed {
+                            *y as i32 as i64
+                        } else {
+                            *y as i64
+                        };
+                        let value = Decimal::from_i128_with_scale(raw as i128, decimals as u32)?;
+                        let stored = invert_y_transform(transform, value, decimals as u32)
+                            .map_err(|err| anyhow!("y {value}: {err}"))?;
+                        to_scaled::<i64>(stored, decimals as u32, Rounding::Nearest)
+                    })
+                    .collect::<Result<Vec<_>>>()
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        self.y_signed = stored.iter().flatten().any(|value| *value < 0);
+        for (y, stored) in std::iter::once(&mut self.y)
+            .chain(&mut self.series)
+            .zip(stored)
+        {
+            for (y, value) in y.iter_mut().zip(stored) {
+                *y = to_curve_y(value, self.y_signed)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Reads curve samples from CSV file with `x,f_x` columns
+pub fn read_samples(path: &Path) -> Result<Samples> {
+    parse_samples(csv::Reader::from_path(path)?)
+}
+
+/// Loads curve samples from the file or URL verifying pinned checksum.
+/// Returns `None` when no source is given.
+pub async fn load_samples(source: &CsvSource, decimals: Option<u8>) -> Result<Option<Samples>> {
+    match (&source.csv, &source.url) {
+        (Some(path), _) => {
+            let data =
+                std::fs::read(path).with_context(|| format!("reading {}", path.display()))?;
+            if let Some(expected) = &source.sha256 {
+                verify_sha256(&data, expected)?;
+            }
+            parse_samples(csv::Reader::from_reader(data.as_slice())).map(Some)
+        }
+        (None, Some(url)) => {
+            let mut http = HttpSource::new(url);
+            http.sha256 = source.sha256.clone();
+            http.decimals = decimals;
+            // nothing is cached yet, so the document is always returned
+            http.fetch().await
+        }
+        (None, None) => {
+            if source.sha256.is_some() {
+                bail!("--sha256 requires --csv or --url");
+            }
+            Ok(None)
+        }
+    }
+}
+
+/// Fails unless SHA-256 of `data` is `expected` (hex)
+pub fn verify_sha256(data: &[u8], expected: &str) -> Result<()> {
+    let actual = hash(data)
+        .to_bytes()
+        .iter()
+        .map(|byte| format!("{byte:02x}"))
+        .collect::<String>();
+
+    if !actual.eq_ignore_ascii_case(expected.trim()) {
+        bail!("CSV data checksum mismatch: expected sha256 {expected}, got {actual}");
+    }
+
+    Ok(())
+}
+
+/// Reads curve samples from CSV with `x,f_x` columns, `x` may be negative. Up to
+/// [MAX_EXTRA_SERIES] more columns
+/// (e.g. `x,f_x,lower,upper`) are read as additional series on the same X grid, except the
+/// `confidence` column holding per-sample confidence in basis points of `y`.
+pub fn parse_samples<R: std::io::Read>(mut reader: csv::Reader<R>) -> Result<Samples> {
+    let headers = reader.headers()?.clone();
+    if headers.len() < 2 {
+        bail!("expected `x,f_x` columns, found `{}`", headers.as_slice());
+    }
+    let confidence_column = headers
+        .iter()
+        .skip(2)
+        .position(|header| header.trim() == CONFIDENCE_COLUMN)
+        .map(|idx| idx + 2);
+    let series_cnt = headers.len() - 2 - confidence_column.is_some() as usize;
+    if series_cnt > MAX_EXTRA_SERIES {
+        bail!("max {MAX_EXTRA_SERIES} additional series allowed, got {series_cnt}");
+    }
+
+    let rows = reader
+        .records()
+        .enumerate()
+        .map(|(idx, record)| {
+            let record = record?;
+            let line = idx + 2;
+            if record.len() != headers.len() {
+                bail!("line {line}: expected {} columns", headers.len());
+            }
+            let x = record[0]
+                .trim()
+                .parse::<i64>()
+                .with_context(|| format!("line {line}: x `{}`", &record[0]))?;
+            let y = record
+                .iter()
+                .enumerate()
+                .skip(1)
+                .filter(|(column, _)| Some(*column) != confidence_column)
+                .map(|(_, value)| {
+                    parse_curve_y(value).with_context(|| format!("line {line}: y `{value}`"))
+                })
+                .collect::<Result<Vec<_>>>()?;
+            let confidence = confidence_column
+                .map(|column| {
+                    let value = record[column].trim();
+                    value
+                        .parse::<CurveConfidenceBps>()
+                        .with_context(|| format!("line {line}: confidence `{value}`"))
+                })
+                .transpose()?;
+
+            Ok((x, y, confidence))
+        })
+        .collect::<Result<Vec<(i64, Vec<i64>, Option<CurveConfidenceBps>)>>>()?;
+
+    let y_signed = rows
+        .iter()
+        .any(|(_, y, _)| y.iter().any(|value| *value < 0));
+    let rows = rows
+        .into_iter()
+        .enumerate()
+        .map(|(idx, (x, y, confidence))| {
+            let y = y
+                .into_iter()
+                .map(|value| to_curve_y(value, y_signed))
+                .collect::<Result<Vec<_>>>()
+                .with_context(|| format!("line {}", idx + 2))?;
+            Ok((x, y, confidence))
+        })
+        .collect::<Result<Vec<_>>>()?;
+    let points = rows
+        .iter()
+        .map(|(x, y, _)| (*x, y[0]))
+        .collect::<Vec<(i64, CurveY)>>();
+
+    if points.len() < 2 {
+        bail!("at least 2 points required, got {}", points.len());
+    }
+
+    if points.len() > MAX_Y_CNT {
+        bail!("max {} points allowed, got {}", MAX_Y_CNT, points.len());
+    }
+
+    let mut y: [CurveY; MAX_Y_CNT] = Zeroable::zeroed();
+    for (i, (_x, f_x)) in points.iter().enumerate() {
+        y[i] = *f_x;
+    }
+
+    let series = (1..=series_cnt)
+        .map(|series| {
+            let mut y: [CurveY; MAX_Y_CNT] = Zeroable::zeroed();
+            for (i, (_x, values, _)) in rows.iter().enumerate() {
+                y[i] = values[series];
+            }
+            y
+        })
+        .collect();
+
+    let confidence = confidence_column.map(|_| {
+        let mut confidence: [CurveConfidenceBps; MAX_Y_CNT] = Zeroable::zeroed();
+        for (i, (_x, _y, value)) in rows.iter().enumerate() {
+            confidence[i] = value.unwrap_or_default();
+        }
+        confidence
+    });
+
+    let x0 = CurveX::try_from(points[0].0.unsigned_abs())
+        .map_err(|_| anyhow!("x0 {} is out of X range", points[0].0))?;
+    let x_step = CurveX::try_from(points[1].0 - points[0].0)
+        .map_err(|_| anyhow!("x step {} is out of X range", points[1].0 - points[0].0))?;
+
+    Ok(Samples {
+        x0,
+        x_step,
+        x0_negative: points[0].0 < 0,
+        y_signed,
+        y_count: points.len() as u8,
+        y,
+        series,
+        confidence,
+    })
+}
+
+/// Wide curve samples read from CSV
+pub struct WideSamples {
+    pub x0: CurveX,
+    pub x_step: CurveX,
+    pub y_count: u8,
+    pub y: [WideCurveY; MAX_WIDE_Y_CNT],
+}
+
+/// Reads wide curve samples from CSV file with `x,f_x` columns. Additional columns are not
+/// supported by wide curves.
+pub fn read_wide_samples(path: &Path) -> Result<WideSamples> {
+    let mut reader =
+        csv::Reader::from_path(path).with_context(|| format!("reading {}", path.display()))?;
+    let headers = reader.headers()?.clone();
+    if headers.len() != 2 {
+        bail!("expected `x,f_x` columns, found `{}`", headers.as_slice());
+    }
+
+    let points = reader
+        .records()
+        .enumerate()
+        .map(|(idx, record)| {
+            let record = record?;
+            let line = idx + 2;
+            let x = record[0]
+                .trim()
+                .parse::<CurveX>()
+                .with_context(|| format!("line {line}: x `{}`", &record[0]))?;
+            let f_x = record[1]
+                .trim()
+                .replace('.', "")
+                .parse::<WideCurveY>()
+                .with_context(|| format!("line {line}: y `{}`", &record[1]))?;
+            Ok((x, f_x))
+        })
+        .collect::<Result<Vec<(CurveX, WideCurveY)>>>()?;
+
+    if points.len() < 2 {
+        bail!("at least 2 points required, got {}", points.len());
+    }
+
+    if points.len() > MAX_WIDE_Y_CNT {
+        bail!(
+            "max {} points allowed, got {}",
+            MAX_WIDE_Y_CNT,
+            points.len()
+        );
+    }
+
+    let mut y = [0; MAX_WIDE_Y_CNT];
+    for (i, (_x, f_x)) in points.iter().enumerate() {
+        y[i] = *f_x;
+    }
+
+    Ok(WideSamples {
+        x0: points[0].0,
+        x_step: points[1].0 - points[0].0,
+        y_count: points.len() as u8,
+        y,
+    })
+}
+
+/// Grid curve samples read from CSV
+pub struct GridSamples {
+    pub y_count: u8,
+    pub x: [CurveX; MAX_GRID_Y_CNT],
+    pub y: [CurveY; MAX_GRID_Y_CNT],
+}
+
+/// Reads grid curve samples from CSV file with `x,f_x` columns, `x` values are kept as is.
+/// Additional columns are not supported by grid curves.
+pub fn read_grid_samples(path: &Path) -> Result<GridSamples> {
+    let mut reader =
+        csv::Reader::from_path(path).with_context(|| format!("reading {}", path.display()))?;
+    let headers = reader.headers()?.clone();
+    if headers.len() != 2 {
+        bail!("expected `x,f_x` columns, found `{}`", headers.as_slice());
+    }
+
+    let points = reader
+        .records()
+        .enumerate()
+        .map(|(idx, record)| {
+            let record = record?;
+            let line = idx + 2;
+            let x = record[0]
+                .trim()
+                .parse::<CurveX>()
+                .with_context(|| format!("line {line}: x `{}`", &record[0]))?;
+            let f_x = record[1]
+                .trim()
+                .replace('.', "")
+                .parse::<CurveY>()
+                .with_context(|| format!("line {line}: y `{}`", &record[1]))?;
+            Ok((x, f_x))
+        })
+        .collect::<Result<Vec<(CurveX, CurveY)>>>()?;
+
+    if points.len() < 2 {
+        bail!("at least 2 points required, got {}", points.len());
+    }
+
+    if points.len() > MAX_GRID_Y_CNT {
+        bail!(
+            "max {} points allowed, got {}",
+            MAX_GRID_Y_CNT,
+            points.len()
+        );
+    }
+
+    if let Some(idx) = points.windows(2).position(|pair| pair[0].0 >= pair[1].0) {
+        bail!("line {}: x must be greater than on previous line", idx + 3);
+    }
+
+    let mut x = [0; MAX_GRID_Y_CNT];
+    let mut y = [0; MAX_GRID_Y_CNT];
+    for (i, (x_i, f_x)) in points.iter().enumerate() {
+        x[i] = *x_i;
+        y[i] = *f_x;
+    }
+
+    Ok(GridSamples {
+        y_count: points.len() as u8,
+        x,
+        y,
+    })
+}
+
+/// Writes curve samples to CSV with `x,f_x` columns readable by [read_samples]
+pub fn write_samples(
+    path: &Path,
+    x0: CurveX,
+    x_step: CurveX,
+    y: &[CurveY],
+    decimals: u8,
+) -> Result<()> {
+    let mut writer = csv::Writer::from_path(path)?;
+    writer.write_record(["x", "f_x"])?;
+
+    let scale = 10_u64.pow(decimals as u32);
+    for (idx, y) in y.iter().enumerate() {
+        let x = x0 as u64 + idx as u64 * x_step as u64;
+        let f_x = match decimals {
+            0 => y.to_string(),
+            _ => format!(
+                "{}.{:0width$}",
+                *y as u64 / scale,
+                *y as u64 % scale,
+                width = decimals as usize
+            ),
+        };
+        writer.write_record([x.to_string(), f_x])?;
+    }
+
+    writer.flush()?;
+    Ok(())
+}
+
+/// Reads original samples from CSV with `x,f_x` columns as (x, y) in human-readable units.
+/// Unlike [parse_samples] the number of points and decimals is not limited.
+pub fn read_reference_samples(path: &Path, decimals: u8) -> Result<Vec<(Decimal, Decimal)>> {
+    let mut reader =
+        csv::Reader::from_path(path).with_context(|| format!("reading {}", path.display()))?;
+    reader
+        .records()
+        .enumerate()
+        .map(|(idx, record)| {
+            let record = record?;
+            let (Some(x), Some(f_x)) = (record.get(0), record.get(1)) else {
+                bail!("line {}: expected 2 columns", idx + 2);
+            };
+            let x = rust_decimal::Decimal::from_str(x.trim())
+                .with_context(|| format!("line {}: x `{x}`", idx + 2))?;
+            let f_x = rust_decimal::Decimal::from_str(f_x.trim())
+                .with_context(|| format!("line {}: f_x `{f_x}`", idx + 2))?;
+
+            // `x` is in curve units, i.e. scaled by `decimals`
+            Ok((
+                Decimal::from_i128_with_scale(x.mantissa(), x.scale() + decimals as u32)?,
+                Decimal::from_i128_with_scale(f_x.mantissa(), f_x.scale())?,
+            ))
+        })
+        .collect()
+}
+
+/// Runs any command, destructive ones are confirmed according to `confirm`
+pub async fn run(
+    cmd: &Command,
+    client: &CurvyClient,
+    confirm: &ConfirmOptions,
+) -> Result<CommandOutput> {
+    match cmd {
+        Command::CreateCurve(args) => run_create_curve(args, client).await,
+        Command::ReserveCurve(args) => run_reserve_curve(args, client).await,
+        Command::AlterCurve(args) => run_alter_curve(args, client).await,
+        Command::CreateWideCurve(args) => run_create_wide_curve(args, client).await,
+        Command::AlterWideCurve(args) => run_alter_wide_curve(args, client).await,
+        Command::CreateGridCurve(args) => run_create_grid_curve(args, client).await,
+        Command::AlterGridCurve(args) => run_alter_grid_curve(args, client).await,
+        Command::DeleteCurve(args) => run_delete_curve(args, client, confirm).await,
+        Command::TopUpRent(args) => run_top_up_rent(args, client).await,
+        Command::SetAsset(args) => run_set_asset(args, client).await,
+        Command::ClearAsset(args) => run_clear_asset(args, client).await,
+        Command::FreezeCurve(args) => run_freeze_curve(args, client, confirm).await,
+        Command::SetCurveStatus(args) => run_set_curve_status(args, client).await,
+        Command::MarkForDeletion(args) => run_mark_for_deletion(args, client).await,
+        Command::CancelDeletion(args) => run_cancel_deletion(args, client).await,
+        Command::SetAlterDelay(args) => run_set_alter_delay(args, client).await,
+        Command::SetCurveExpiry(args) => run_set_curve_expiry(args, client).await,
+        Command::ProposeAlter(args) => run_propose_alter(args, client).await,
+        Command::ApplyAlter(args) => run_apply_alter(args, client).await,
+        Command::CancelAlter(args) => run_cancel_alter(args, client).await,
+        Command::AddDelegate(args) => run_add_delegate(args, client).await,
+        Command::RemoveDelegate(args) => run_remove_delegate(args, client).await,
+        Command::SetCurveOwners(args) => run_set_curve_owners(args, client).await,
+        Command::SetCurveExtension(args) => run_set_curve_extension(args, client).await,
+        Command::PatchY(args) => run_patch_y(args, client).await,
+        Command::ProposeOwner(args) => run_propose_owner(args, client, confirm).await,
+        Command::AcceptOwner(args) => run_accept_owner(args, client).await,
+        Command::SetSeries(args) => run_set_series(args, client).await,
+        Command::DeleteSeries(args) => run_delete_series(args, client).await,
+        Command::DeleteConfidence(args) => run_delete_confidence(args, client).await,
+        Command::CurveForMint(args) => run_curve_for_mint(args, client).await,
+        Command::ResolveCurve(args) => run_resolve_curve(args, client).await,
+        Command::MigrateCurve(args) => run_migrate_curve(args, client, confirm).await,
+        Command::Curve(args) => run_curve(args, client).await,
+        Command::Curves(args) => run_curves(args, client).await,
+        Command::Pack(args) => run_pack(args, client).await,
+        Command::Restore(args) => run_restore(args, client).await,
+        Command::Plan(args) => run_plan(args, client).await,
+        Command::Apply(args) => run_apply(args, client, confirm).await,
+        Command::Snapshot(args) => run_snapshot(args, client).await,
+        Command::ExportAll(args) => run_export_all(args, client).await,
+        Command::Owners => run_owners(client).await,
+        Command::InitConfig(args) => run_init_config(args, client).await,
+        Command::SetConfig(args) => run_set_config(args, client).await,
+        Command::SetCreationFrozen(args) => run_set_creation_frozen(args, client).await,
+        Command::Config => run_config(None, client).await,
+        Command::Bootstrap(args) => run_bootstrap(args, client).await,
+        Command::RecordFixture(args) => run_record_fixture(args, client).await,
+        Command::DecodeAccount(args) => run_decode_account(args),
+        Command::Fit(args) => run_fit(args),
+        Command::Smooth(args) => run_smooth(args),
+        Command::LintFiles(args) => run_lint_files(args),
+        Command::CalcY(args) => run_calc_y(args, client).await,
+        Command::EvaluateCurve(args) => run_evaluate_curve(args, client).await,
+        Command::Twa(args) => run_twa(args, client).await,
+        Command::Summarize(args) => run_summarize(args, client).await,
+        Command::Profile(args) => run_profile(args, client).await,
+        Command::CheckConsumer(args) => run_check_consumer(args, client).await,
+        Command::Errors(args) => run_errors(args),
+    }
+}
+
+pub async fn run_create_curve(
+    args: &CreateCurveArgs,
+    client: &CurvyClient,
+) -> Result<CommandOutput> {
+    let y_decimals = args.y_decimals.unwrap_or(args.decimals);
+    let tags = args.tags.as_deref().map(CurveTags::new).transpose()?;
+    let mut samples = load_samples(&args.source, Some(y_decimals))
+        .await?
+        .ok_or_else(|| anyhow!("either --csv or --url is required"))?;
+    samples.transform_y(args.y_transform, y_decimals)?;
+
+    let mut params = CurveParams::new(
+        &args.name,
+        &args.formula,
+        samples.x0,
+        samples.x_step,
+        samples.y_count,
+        args.decimals,
+        samples.y,
+    );
+    params.y_decimals = y_decimals;
+
+    let approximation = match &args.reference {
+        Some(path) => {
+            let mut curve = Curve::from_init_params((params, client.authority.pubkey()));
+            curve.x0_negative = samples.x0_negative as u8;
+            curve.y_signed = samples.y_signed as u8;
+            curve.y_transform = args.y_transform as u8;
+            let samples = read_reference_samples(path, args.decimals)?;
+            let report = approximation_error(&curve, &samples)?;
+
+            if let Some(max_error) = args.max_error {
+                let max_error =
+                    Decimal::from_i128_with_scale(max_error.mantissa(), max_error.scale())?;
+                if report.max > max_error {
+                    bail!(
+                        "max interpolation error {} at x={} exceeds {max_error}",
+                        report.max,
+                        report.max_at.unwrap_or_default()
+                    );
+                }
+            }
+
+            Some(report)
+        }
+        None => None,
+    };
+
+    let created = if let Some(curve_keypair) = &args.curve_keypair {
+        let curve_keypair = read_keypair_file(&curve_keypair.0)
+            .map_err(|err| anyhow!("reading curve keypair: {}", err))?;
+        client
+            .create_curve_with_keypair(params, &curve_keypair, client.priority_fee)
+            .await?
+    } else if args.derived {
+        client.create_curve_pda(params, client.priority_fee).await?
+    } else {
+        client.create_curve(params, client.priority_fee).await?
+    };
+
+    // does not fit in the create transaction, sent first so the curve does not stay on
+    // positive X for long
+    let x_sign = match (created.signature, samples.x0_negative) {
+        (Some(_), true) => Some(
+            client
+                .set_curve_x_sign(created.curve, true, client.priority_fee, false)
+                .await?
+                .signature,
+        ),
+        _ => None,
+    };
+    let y_sign = match (created.signature, samples.y_signed) {
+        (Some(_), true) => Some(
+            client
+                .set_curve_y_sign(created.curve, true, client.priority_fee, false)
+                .await?
+                .signature,
+        ),
+        _ => None,
+    };
+    let y_transform = match created.signature {
+        Some(_) if args.y_transform != YTransform::Linear => Some(
+            client
+                .set_curve_y_transform(created.curve, args.y_transform, client.priority_fee, false)
+                .await?
+                .signature,
+        ),
+        _ => None,
+    };
+
+    let tags = match (created.signature, tags) {
+        (Some(_), Some(tags)) => Some(
+            client
+                .set_curve_extension(
+                    created.curve,
+                    ExtensionType::Tags,
+                    tags.to_value(),
+                    client.priority_fee,
+                    false,
+                )
+                .await?
+                .signature,
+        ),
+        _ => None,
+    };
+
+    let mut series = vec![];
+    if created.signature.is_some() {
+        for (idx, y) in samples.series.iter().enumerate() {
+            let signature = client
+                .set_curve_series(created.curve, idx as u8 + 1, *y, client.priority_fee, false)
+                .await?;
+            series.push(signature.signature);
+        }
+    }
+
+    let confidence = match (created.signature, samples.confidence) {
+        (Some(_), Some(confidence)) => Some(
+            client
+                .set_curve_confidence(created.curve, confidence, client.priority_fee, false)
+                .await?
+                .signature,
+        ),
+        _ => None,
+    };
+
+    // sent last, the steps above are refused on a timelocked curve
+    let alter_delay = match (created.signature, args.alter_delay) {
+        (Some(_), Some(slots)) => Some(
+            client
+                .set_alter_delay(created.curve, slots, client.priority_fee, false)
+                .await?
+                .signature,
+        ),
+        _ => None,
+    };
+
+    Ok(CommandOutput::Created {
+        view: Box::new(created),
+        approximation,
+        series,
+        confidence,
+        x_sign,
+        y_sign,
+        y_transform,
+        tags,
+        alter_delay,
+    })
+}
+
+pub async fn run_reserve_curve(
+    args: &ReserveCurveArgs,
+    client: &CurvyClient,
+) -> Result<CommandOutput> {
+    let curve_keypair = match &args.curve_keypair {
+        Some(curve_keypair) => read_keypair_file(&curve_keypair.0)
+            .map_err(|err| anyhow!("reading curve keypair: {}", err))?,
+        None => Keypair::new(),
+    };
+
+    let reserved = client
+        .reserve_curve(
+            &args.name,
+            &args.formula,
+            &curve_keypair,
+            client.priority_fee,
+        )
+        .await?;
+
+    Ok(CommandOutput::Created {
+        view: Box::new(reserved),
+        approximation: None,
+        series: vec![],
+        confidence: None,
+        x_sign: None,
+        y_sign: None,
+        y_transform: None,
+        tags: None,
+        alter_delay: None,
+    })
+}
+
+pub async fn run_alter_curve(args: &AlterCurveArgs, client: &CurvyClient) -> Result<CommandOutput> {
+    let current_view = client.curve(&args.curve).await?.into_inner();
+    let current = current_view.curve;
+    if let Some(expected) = args.expected_revision {
+        client.check_revision(&args.curve, &current, expected)?;
+    }
+    // `Some(None)` removes the tags
+    let new_tags = args
+        .tags
+        .as_deref()
+        .map(|tags| (!tags.is_empty()).then(|| CurveTags::new(tags)).transpose())
+        .transpose()?;
+    let y_transform = args.y_transform.unwrap_or(current.y_transform());
+    let samples = load_transformed_samples(args, &current, y_transform).await?;
+    if samples
+        .as_ref()
+        .is_some_and(|samples| !samples.series.is_empty())
+    {
+        tracing::warn!("additional series columns are ignored, use set-series to update them");
+    }
+
+    let update = curve_update(args, samples.as_ref());
+
+    let altered = update.apply(&current);
+    let mut diff = FieldChange::diff(&CurveParams::from(&current), &altered);
+
+    let signature = client
+        .alter_curve(
+            args.curve,
+            update.name,
+            update.formula,
+            update.x_decimals,
+            update.y_decimals,
+            update.x0,
+            update.x_step,
+            update.y_count,
+            update.y,
+            client.priority_fee,
+            args.force,
+        )
+        .await?;
+
+    let x_sign = match samples.as_ref().map(|samples| samples.x0_negative) {
+        Some(x0_negative) if x0_negative != (current.x0_negative != 0) => {
+            diff.push(x_sign_diff(current.x0_negative != 0, x0_negative));
+            Some(
+                client
+                    .set_curve_x_sign(args.curve, x0_negative, client.priority_fee, args.force)
+                    .await?
+                    .signature,
+            )
+        }
+        _ => None,
+    };
+    let y_sign = match samples.as_ref().map(|samples| samples.y_signed) {
+        Some(y_signed) if y_signed != (current.y_signed != 0) => {
+            diff.push(y_sign_diff(current.y_signed != 0, y_signed));
+            Some(
+                client
+                    .set_curve_y_sign(args.curve, y_signed, client.priority_fee, args.force)
+                    .await?
+                    .signature,
+            )
+        }
+        _ => None,
+    };
+
+    let y_transform = match args.y_transform {
+        Some(y_transform) if y_transform != current.y_transform() => {
+            diff.push(FieldChange {
+                field: "y_transform".to_string(),
+                from: current.y_transform().to_string(),
+                to: y_transform.to_string(),
+            });
+            Some(
+                client
+                    .set_curve_y_transform(args.curve, y_transform, client.priority_fee, args.force)
+                    .await?
+                    .signature,
+            )
+        }
+        _ => None,
+    };
+
+    let tags = match new_tags {
+        Some(tags) => {
+            let signature = client
+                .set_curve_extension(
+                    args.curve,
+                    ExtensionType::Tags,
+                    tags.map_or(vec![], |tags| tags.to_value()),
+                    client.priority_fee,
+                    args.force,
+                )
+                .await?
+                .signature;
+            diff.push(FieldChange {
+                field: ExtensionType::Tags.to_string(),
+                from: extension_text(&current_view, ExtensionType::Tags)?,
+                to: tags.map_or("-".to_string(), |tags| {
+                    bytes_to_cow(&tags.tags).into_owned()
+                }),
+            });
+            Some(signature)
+        }
+        None => None,
+    };
+
+    // does not fit in the alter transaction together with `y`
+    let confidence = match samples.and_then(|samples| samples.confidence) {
+        Some(confidence) => {
+            diff.push(confidence_diff(current.has_confidence != 0, true));
+            Some(
+                client
+                    .set_curve_confidence(args.curve, confidence, client.priority_fee, args.force)
+                    .await?
+                    .signature,
+            )
+        }
+        None => None,
+    };
+
+    Ok(CommandOutput::Altered {
+        curve: args.curve,
+        name: bytes_to_cow(&altered.name).into_owned(),
+        signature: signature.signature,
+        cost: signature.cost,
+        diff,
+        confidence,
+        x_sign,
+        y_sign,
+        y_transform,
+        tags,
+    })
+}
+
+/// Samples of alter-curve or propose-alter stored with `y_transform`, `y` decimals default to
+/// the `current` ones
+async fn load_transformed_samples(
+    args: &AlterCurveArgs,
+    current: &Curve,
+    y_transform: YTransform,
+) -> Result<Option<Samples>> {
+    let y_decimals = args.y_decimals.or(args.decimals);
+    let mut samples = load_samples(&args.source, y_decimals).await?;
+    if let Some(samples) = &mut samples {
+        samples.transform_y(y_transform, y_decimals.unwrap_or(current.y_decimals))?;
+    }
+
+    Ok(samples)
+}
+
+/// Changes of curve params requested by alter-curve or propose-alter
+fn curve_update(args: &AlterCurveArgs, samples: Option<&Samples>) -> CurveUpdate {
+    CurveUpdate {
+        name: args.name.clone(),
+        formula: args.formula.clone(),
+        x_decimals: args.decimals,
+        y_decimals: args.y_decimals.or(args.decimals),
+        x0: samples.map(|samples| samples.x0),
+        x_step: samples.map(|samples| samples.x_step),
+        y_count: samples.map(|samples| samples.y_count),
+        y: samples.map(|samples| samples.y),
+    }
+}
+
+pub async fn run_propose_alter(
+    args: &AlterCurveArgs,
+    client: &CurvyClient,
+) -> Result<CommandOutput> {
+    let current = client.curve(&args.curve).await?.curve;
+    if let Some(expected) = args.expected_revision {
+        client.check_revision(&args.curve, &current, expected)?;
+    }
+    if args
+        .y_transform
+        .is_some_and(|y_transform| y_transform != current.y_transform())
+    {
+        bail!("y transform of a timelocked curve can not be changed");
+    }
+    let samples = load_transformed_samples(args, &current, current.y_transform()).await?;
+    if samples
+        .as_ref()
+        .is_some_and(|samples| !samples.series.is_empty() || samples.confidence.is_some())
+    {
+        tracing::warn!("series and confidence columns are ignored, a proposal changes params only");
+    }
+
+    if let Some(samples) = &samples {
+        if samples.x0_negative != (current.x0_negative != 0)
+            || samples.y_signed != (current.y_signed != 0)
+        {
+            bail!("x0 and y signs of a timelocked curve can not be changed");
+        }
+    }
+
+    let params = curve_update(args, samples.as_ref()).apply(&current);
+    let diff = FieldChange::diff(&CurveParams::from(&current), &params);
+
+    let signature = client
+        .propose_alter(args.curve, params, client.priority_fee, args.force)
+        .await?;
+    let pending = client.pending_alter(&args.curve).await?;
+
+    Ok(CommandOutput::AlterProposed {
+        curve: args.curve,
+        name: bytes_to_cow(&params.name).into_owned(),
+        apply_slot: pending.map(|pending| pending.apply_slot),
+        signature: signature.signature,
+        cost: signature.cost,
+        diff,
+    })
+}
+
+pub async fn run_apply_alter(
+    args: &DeleteCurveArgs,
+    client: &CurvyClient,
+) -> Result<CommandOutput> {
+    let current = client.curve(&args.curve).await?.curve;
+    let signature = client
+        .apply_alter(args.curve, client.priority_fee, args.force)
+        .await?;
+    let altered = client.curve(&args.curve).await?.curve;
+
+    Ok(CommandOutput::Altered {
+        curve: args.curve,
+        name: bytes_to_cow(&altered.name).into_owned(),
+        signature: signature.signature,
+        cost: signature.cost,
+        diff: FieldChange::diff(&CurveParams::from(&current), &CurveParams::from(&altered)),
+        confidence: None,
+        x_sign: None,
+        y_sign: None,
+        y_transform: None,
+        tags: None,
+    })
+}
+
+pub async fn run_cancel_alter(
+    args: &DeleteCurveArgs,
+    client: &CurvyClient,
+) -> Result<CommandOutput> {
+    let curve = client.curve(&args.curve).await?.curve;
+    let pending = client.pending_alter(&args.curve).await?;
+    let signature = client
+        .cancel_alter(args.curve, client.priority_fee, args.force)
+        .await?;
+
+    Ok(CommandOutput::Altered {
+        curve: args.curve,
+        name: bytes_to_cow(&curve.name).into_owned(),
+        signature: signature.signature,
+        cost: signature.cost,
+        diff: vec![FieldChange {
+            field: "pending_alter".to_string(),
+            from: pending.map_or("-".to_string(), |pending| {
+                format!("applicable from slot {}", pending.apply_slot)
+            }),
+            to: "-".to_string(),
+        }],
+        confidence: None,
+        x_sign: None,
+        y_sign: None,
+        y_transform: None,
+        tags: None,
+    })
+}
+
+pub async fn run_set_alter_delay(
+    args: &SetAlterDelayArgs,
+    client: &CurvyClient,
+) -> Result<CommandOutput> {
+    let curve = client.curve(&args.curve).await?.curve;
+    let signature = client
+        .set_alter_delay(args.curve, args.slots, client.priority_fee, args.force)
+        .await?;
+
+    Ok(CommandOutput::Altered {
+        curve: args.curve,
+        name: bytes_to_cow(&curve.name).into_owned(),
+        signature: signature.signature,
+        cost: signature.cost,
+        diff: vec![FieldChange {
+            field: "alter_delay".to_string(),
+            from: curve.alter_delay().to_string(),
+            to: args.slots.to_string(),
+        }],
+        confidence: None,
+        x_sign: None,
+        y_sign: None,
+        y_transform: None,
+        tags: None,
+    })
+}
+
+pub async fn run_set_curve_extension(
+    args: &SetCurveExtensionArgs,
+    client: &CurvyClient,
+) -> Result<CommandOutput> {
+    let mut changes = vec![];
+    if let Some(tags) = &args.tags {
+        let tags = CurveTags::new(tags)?;
+        changes.push((ExtensionType::Tags, tags.to_value()));
+    }
+    if let (Some(min_y), Some(max_y)) = (args.min_y, args.max_y) {
+        let guardrails = CurveGuardrails::new(min_y, max_y);
+        changes.push((ExtensionType::Guardrails, guardrails.to_value()));
+    }
+    if let (Some(valid_from), Some(valid_until)) = (args.valid_from, args.valid_until) {
+        let schedule = CurveSchedule::new(valid_from, valid_until);
+        changes.push((ExtensionType::Schedule, schedule.to_value()));
+    }
+    if let Some(extension_type) = args.remove {
+        changes.push((extension_type, vec![]));
+    }
+    let [(extension_type, value)] = <[_; 1]>::try_from(changes).map_err(|_| {
+        anyhow!("pass one of --tags, --min-y/--max-y, --valid-from/--valid-until or --remove")
+    })?;
+
+    let curve_view = client.curve(&args.curve).await?.into_inner();
+    let from = extension_text(&curve_view, extension_type)?;
+    let signature = client
+        .set_curve_extension(
+            args.curve,
+            extension_type,
+            value,
+            client.priority_fee,
+            args.force,
+        )
+        .await?;
+    let to = extension_text(
+        &client.curve(&args.curve).await?.into_inner(),
+        extension_type,
+    )?;
+
+    Ok(CommandOutput::Altered {
+        curve: args.curve,
+        name: bytes_to_cow(&curve_view.curve.name).into_owned(),
+        signature: signature.signature,
+        cost: signature.cost,
+        diff: vec![FieldChange {
+            field: extension_type.to_string(),
+            from,
+            to,
+        }],
+        confidence: None,
+        x_sign: None,
+        y_sign: None,
+        y_transform: None,
+        tags: None,
+    })
+}
+
+/// Value of the extension of the curve as the CLI prints it, `-` when the curve does not have it
+fn extension_text(curve: &CurveView, extension_type: ExtensionType) -> Result<String> {
+    let text = match extension_type {
+        ExtensionType::Tags => curve
+            .extension::<CurveTags>()?
+            .map(|tags| bytes_to_cow(&tags.tags).into_owned()),
+        ExtensionType::Guardrails => curve
+            .extension::<CurveGuardrails>()?
+            .map(|guardrails| format!("{}..{}", guardrails.min_y(), guardrails.max_y())),
+        ExtensionType::Schedule => curve
+            .extension::<CurveSchedule>()?
+            .map(|schedule| format!("{}..{}", schedule.valid_from(), schedule.valid_until())),
+    };
+
+    Ok(text.unwrap_or_else(|| "-".to_string()))
+}
+
+pub async fn run_set_curve_owners(
+    args: &SetCurveOwnersArgs,
+    client: &CurvyClient,
+) -> Result<CommandOutput> {
+    let curve = client.curve(&args.curve).await?.curve;
+    let owners = client.curve_owners(&args.curve).await?;
+    let signature = client
+        .set_curve_owners(
+            args.curve,
+            args.threshold,
+            args.owners.clone(),
+            client.priority_fee,
+            args.force,
+        )
+        .await?;
+
+    let list = |threshold: u8, owners: Vec<String>| {
+        if threshold == 0 {
+            "-".to_string()
+        } else {
+            format!("{threshold} of {}", owners.join(", "))
+        }
+    };
+
+    Ok(CommandOutput::Altered {
+        curve: args.curve,
+        name: bytes_to_cow(&curve.name).into_owned(),
+        signature: signature.signature,
+        cost: signature.cost,
+        diff: vec![FieldChange {
+            field: "owners".to_string(),
+            from: list(
+                curve.owner_threshold,
+                owners
+                    .iter()
+                    .flat_map(|owners| owners.owners())
+                    .map(Pubkey::to_string)
+                    .collect(),
+            ),
+            to: list(
+                args.threshold,
+                args.owners.iter().map(Pubkey::to_string).collect(),
+            ),
+        }],
+        confidence: None,
+        x_sign: None,
+        y_sign: None,
+        y_transform: None,
+        tags: None,
+    })
+}
+
+pub async fn run_add_delegate(args: &DelegateArgs, client: &CurvyClient) -> Result<CommandOutput> {
+    let curve = client.curve(&args.curve).await?.curve;
+    let signature = client
+        .add_delegate(args.curve, args.delegate, client.priority_fee, args.force)
+        .await?;
+    let delegated = client.curve(&args.curve).await?.curve;
+
+    Ok(CommandOutput::Altered {
+        curve: args.curve,
+        name: bytes_to_cow(&curve.name).into_owned(),
+        signature: signature.signature,
+        cost: signature.cost,
+        diff: vec![delegates_diff(&curve, &delegated)],
+        confidence: None,
+        x_sign: None,
+        y_sign: None,
+        y_transform: None,
+        tags: None,
+    })
+}
+
+pub async fn run_remove_delegate(
+    args: &DelegateArgs,
+    client: &CurvyClient,
+) -> Result<CommandOutput> {
+    let curve = client.curve(&args.curve).await?.curve;
+    let signature = client
+        .remove_delegate(args.curve, args.delegate, client.priority_fee, args.force)
+        .await?;
+    let revoked = client.curve(&args.curve).await?.curve;
+
+    Ok(CommandOutput::Altered {
+        curve: args.curve,
+        name: bytes_to_cow(&curve.name).into_owned(),
+        signature: signature.signature,
+        cost: signature.cost,
+        diff: vec![delegates_diff(&curve, &revoked)],
+        confidence: None,
+        x_sign: None,
+        y_sign: None,
+        y_transform: None,
+        tags: None,
+    })
+}
+
+/// Delegates of the curve before and after, see [Curve::delegates]
+fn delegates_diff(from: &Curve, to: &Curve) -> FieldChange {
+    let show = |curve: &Curve| {
+        let delegates = curve
+            .delegates()
+            .map(|delegate| delegate.to_string())
+            .collect::<Vec<_>>();
+        if delegates.is_empty() {
+            "-".to_string()
+        } else {
+            delegates.join(",")
+        }
+    };
+    FieldChange {
+        field: "delegates".to_string(),
+        from: show(from),
+        to: show(to),
+    }
+}
+
+pub async fn run_create_wide_curve(
+    args: &CreateWideCurveArgs,
+    client: &CurvyClient,
+) -> Result<CommandOutput> {
+    let samples = read_wide_samples(&args.csv)?;
+    let params = WideCurveParams::new(
+        &args.name,
+        &args.formula,
+        samples.x0,
+        samples.x_step,
+        samples.y_count,
+        args.decimals,
+        samples.y,
+    );
+
+    let curve_keypair = args
+        .curve_keypair
+        .as_ref()
+        .map(|curve_keypair| {
+            read_keypair_file(&curve_keypair.0)
+                .map_err(|err| anyhow!("reading curve keypair: {}", err))
+        })
+        .transpose()?;
+    let created = client
+        .create_wide_curve(params, curve_keypair.as_ref(), client.priority_fee)
+        .await?;
+
+    Ok(CommandOutput::Created {
+        view: Box::new(created),
+        approximation: None,
+        series: vec![],
+        confidence: None,
+        x_sign: None,
+        y_sign: None,
+        y_transform: None,
+        tags: None,
+        alter_delay: None,
+    })
+}
+
+pub async fn run_alter_wide_curve(
+    args: &AlterWideCurveArgs,
+    client: &CurvyClient,
+) -> Result<CommandOutput> {
+    let current = WideCurveParams::from(&*client.wide_curve(&args.curve).await?);
+
+    let mut altered = current;
+    if let Some(name) = &args.name {
+        altered.name = str_to_array(name);
+    }
+    if let Some(formula) = &args.formula {
+        altered.formula = str_to_array(formula);
+    }
+    if let Some(decimals) = args.decimals {
+        altered.decimals = decimals;
+    }
+    if let Some(path) = &args.csv {
+        let samples = read_wide_samples(path)?;
+        altered.x0 = samples.x0;
+        altered.x_step = samples.x_step;
+        altered.y_count = samples.y_count;
+        altered.y = samples.y;
+    }
+
+    let signature = client
+        .alter_wide_curve(args.curve, altered, client.priority_fee, args.force)
+        .await?;
+
+    Ok(CommandOutput::Altered {
+        curve: args.curve,
+        name: bytes_to_cow(&altered.name).into_owned(),
+        signature: signature.signature,
+        cost: signature.cost,
+        diff: FieldChange::diff_wide(&current, &altered),
+        confidence: None,
+        x_sign: None,
+        y_sign: None,
+        y_transform: None,
+        tags: None,
+    })
+}
+
+pub async fn run_create_grid_curve(
+    args: &CreateGridCurveArgs,
+    client: &CurvyClient,
+) -> Result<CommandOutput> {
+    let samples = read_grid_samples(&args.csv)?;
+    let params = GridCurveParams::new(
+        &args.name,
+        &args.formula,
+        samples.y_count,
+        args.decimals,
+        samples.x,
+        samples.y,
+    );
+
+    let curve_keypair = args
+        .curve_keypair
+        .as_ref()
+        .map(|curve_keypair| {
+            read_keypair_file(&curve_keypair.0)
+                .map_err(|err| anyhow!("reading curve keypair: {}", err))
+        })
+        .transpose()?;
+    let created = client
+        .create_grid_curve(params, curve_keypair.as_ref(), client.priority_fee)
+        .await?;
+
+    Ok(CommandOutput::Created {
+        view: Box::new(created),
+        approximation: None,
+        series: vec![],
+        confidence: None,
+        x_sign: None,
+        y_sign: None,
+        y_transform: None,
+        tags: None,
+        alter_delay: None,
+    })
+}
+
+pub async fn run_alter_grid_curve(
+    args: &AlterGridCurveArgs,
+    client: &CurvyClient,
+) -> Result<CommandOutput> {
+    let current = GridCurveParams::from(&*client.grid_curve(&args.curve).await?);
+
+    let mut altered = current;
+    if let Some(name) = &args.name {
+        altered.name = str_to_array(name);
+    }
+    if let Some(formula) = &args.formula {
+        altered.formula = str_to_array(formula);
+    }
+    if let Some(decimals) = args.decimals {
+        altered.decimals = decimals;
+    }
+    if let Some(path) = &args.csv {
+        let samples = read_grid_samples(path)?;
+        altered.y_count = samples.y_count;
+        altered.x = samples.x;
+        altered.y = samples.y;
+    }
+
+    let signature = client
+        .alter_grid_curve(args.curve, altered, client.priority_fee, args.force)
+        .await?;
+
+    Ok(CommandOutput::Altered {
+        curve: args.curve,
+        name: bytes_to_cow(&altered.name).into_owned(),
+        signature: signature.signature,
+        cost: signature.cost,
+        diff: FieldChange::diff_grid(&current, &altered),
+        confidence: None,
+        x_sign: None,
+        y_sign: None,
+        y_transform: None,
+        tags: None,
+    })
+}
+
+/// Deletes the curve. Interactive confirmation is up to the caller.
+pub async fn run_delete_curve(
+    args: &DeleteArgs,
+    client: &CurvyClient,
+    confirm: &ConfirmOptions,
+) -> Result<CommandOutput> {
+    confirm.confirm("delete", &args.curve, client).await?;
+
+    let curve = client.any_curve(&args.curve).await?;
+    let signature = client
+        .delete_curve(
+            args.curve,
+            args.rent_destination,
+            client.priority_fee,
+            args.force,
+        )
+        .await?;
+
+    Ok(CommandOutput::Deleted {
+        curve: args.curve,
+        name: bytes_to_cow(curve.name()).into_owned(),
+        signature: signature.signature,
+        cost: signature.cost,
+    })
+}
+
+pub async fn run_top_up_rent(args: &CurveArgs, client: &CurvyClient) -> Result<CommandOutput> {
+    let lamports = client.rent_top_up(&args.curve).await?;
+    let signature = client.top_up_rent(args.curve, client.priority_fee).await?;
+
+    Ok(CommandOutput::ToppedUp {
+        curve: args.curve,
+        lamports,
+        cost: signature.as_ref().and_then(|view| view.cost),
+        signature: signature.map(|view| view.signature),
+    })
+}
+
+pub async fn run_set_asset(args: &SetAssetArgs, client: &CurvyClient) -> Result<CommandOutput> {
+    let curve = client.curve(&args.curve).await?.curve;
+    let signature = client
+        .set_curve_asset(
+            args.curve,
+            args.mint,
+            args.kind,
+            client.priority_fee,
+            args.force,
+        )
+        .await?;
+
+    Ok(CommandOutput::Altered {
+        curve: args.curve,
+        name: bytes_to_cow(&curve.name).into_owned(),
+        signature: signature.signature,
+        cost: signature.cost,
+        diff: asset_diff(curve.asset(), Some((args.mint, args.kind))),
+        confidence: None,
+        x_sign: None,
+        y_sign: None,
+        y_transform: None,
+        tags: None,
+    })
+}
+
+pub async fn run_clear_asset(
+    args: &DeleteCurveArgs,
+    client: &CurvyClient,
+) -> Result<CommandOutput> {
+    let curve = client.curve(&args.curve).await?.curve;
+    let signature = client
+        .clear_curve_asset(args.curve, client.priority_fee, args.force)
+        .await?;
+
+    Ok(CommandOutput::Altered {
+        curve: args.curve,
+        name: bytes_to_cow(&curve.name).into_owned(),
+        signature: signature.signature,
+        cost: signature.cost,
+        diff: asset_diff(curve.asset(), None),
+        confidence: None,
+        x_sign: None,
+        y_sign: None,
+        y_transform: None,
+        tags: None,
+    })
+}
+
+/// Freezes the curve. Interactive confirmation is up to the caller.
+pub async fn run_freeze_curve(
+    args: &DeleteCurveArgs,
+    client: &CurvyClient,
+    confirm: &ConfirmOptions,
+) -> Result<CommandOutput> {
+    confirm.confirm("freeze", &args.curve, client).await?;
+
+    let curve = client.curve(&args.curve).await?.curve;
+    let signature = client
+        .freeze_curve(args.curve, client.priority_fee, args.force)
+        .await?;
+
+    Ok(CommandOutput::Altered {
+        curve: args.curve,
+        name: bytes_to_cow(&curve.name).into_owned(),
+        signature: signature.signature,
+        cost: signature.cost,
+        diff: vec![FieldChange {
+            field: "frozen".to_string(),
+            from: (curve.is_frozen != 0).to_string(),
+            to: true.to_string(),
+        }],
+        confidence: None,
+        x_sign: None,
+        y_sign: None,
+        y_transform: None,
+        tags: None,
+    })
+}
+
+pub async fn run_bootstrap(args: &BootstrapArgs, client: &CurvyClient) -> Result<CommandOutput> {
+    let airdrop = client
+        .airdrop_if_below(
+            sol_to_lamports(args.min_balance),
+            sol_to_lamports(args.airdrop),
+        )
+        .await
+        .context("airdrop to the authority")?;
+    let authority = client.authority.pubkey();
+
+    let mut curves = vec![];
+    for params in sample_curves() {
+        let name = bytes_to_cow(&params.name).into_owned();
+        let restored = match client.resolve_curve(&authority, &name).await? {
+            Some(curve) => RestoredCurve {
+                name,
+                action: RestoreAction::Unchanged,
+                curve: Some(curve),
+                signature: None,
+            },
+            None => {
+                let view = client
+                    .create_curve(params, client.priority_fee)
+                    .await
+                    .with_context(|| format!("creating curve `{name}`"))?;
+                if let Some(error) = view.error {
+                    bail!("creating curve `{name}`: {error}");
+                }
+                RestoredCurve {
+                    name,
+                    action: RestoreAction::Created,
+                    curve: Some(view.curve),
+                    signature: view.signature,
+                }
+            }
+        };
+        curves.push(restored);
+    }
+
+    let rpc_url = client.rpc.url();
+    let balance = client.rpc.get_balance(&authority).await?;
+    Ok(CommandOutput::Bootstrapped {
+        config: config_snippet(&rpc_url, &curves),
+        rpc_url,
+        authority,
+        balance: lamports_to_sol(balance),
+        airdrop,
+        curves,
+    })
+}
+
+pub async fn run_set_curve_expiry(
+    args: &SetCurveExpiryArgs,
+    client: &CurvyClient,
+) -> Result<CommandOutput> {
+    let curve = client.curve(&args.curve).await?.curve;
+    let signature = client
+        .set_curve_expiry(
+            args.curve,
+            args.valid_until,
+            args.validity_period,
+            client.priority_fee,
+            args.force,
+        )
+        .await?;
+
+    let show = |valid_until: Option<i64>| valid_until.map_or("-".to_string(), |t| t.to_string());
+    Ok(CommandOutput::Altered {
+        curve: args.curve,
+        name: bytes_to_cow(&curve.name).into_owned(),
+        signature: signature.signature,
+        cost: signature.cost,
+        diff: vec![
+            FieldChange {
+                field: "valid_until".to_string(),
+                from: show(curve.valid_until()),
+                to: show(args.valid_until),
+            },
+            FieldChange {
+                field: "validity_period".to_string(),
+                from: curve.validity_period().to_string(),
+                to: args.validity_period.to_string(),
+            },
+        ],
+        confidence: None,
+        x_sign: None,
+        y_sign: None,
+        y_transform: None,
+        tags: None,
+    })
+}
+
+pub async fn run_set_curve_status(
+    args: &SetCurveStatusArgs,
+    client: &CurvyClient,
+) -> Result<CommandOutput> {
+    let curve = client.curve(&args.curve).await?.curve;
+    let signature = client
+        .set_curve_status(args.curve, args.status, client.priority_fee, args.force)
+        .await?;
+
+    Ok(CommandOutput::Altered {
+        curve: args.curve,
+        name: bytes_to_cow(&curve.name).into_owned(),
+        signature: signature.signature,
+        cost: signature.cost,
+        diff: vec![FieldChange {
+            field: "status".to_string(),
+            from: curve.status().to_string(),
+            to: args.status.to_string(),
+        }],
+        confidence: None,
+        x_sign: None,
+        y_sign: None,
+        y_transform: None,
+        tags: None,
+    })
+}
+
+/// Marked or unmarked deletion slot of the curve, see [Curve::deletion_slot]
+fn deletion_diff(from: Option<u64>, to: Option<u64>) -> FieldChange {
+    let show = |slot: Option<u64>| slot.map_or("-".to_string(), |slot| slot.to_string());
+    FieldChange {
+        field: "deletion_slot".to_string(),
+        from: show(from),
+        to: show(to),
+    }
+}
+
+pub async fn run_mark_for_deletion(
+    args: &DeleteCurveArgs,
+    client: &CurvyClient,
+) -> Result<CommandOutput> {
+    let curve = client.curve(&args.curve).await?.curve;
+    let signature = client
+        .mark_for_deletion(args.curve, client.priority_fee, args.force)
+        .await?;
+    let marked = client.curve(&args.curve).await?.curve;
+
+    Ok(CommandOutput::Altered {
+        curve: args.curve,
+        name: bytes_to_cow(&curve.name).into_owned(),
+        signature: signature.signature,
+        cost: signature.cost,
+        diff: vec![deletion_diff(curve.deletion_slot(), marked.deletion_slot())],
+        confidence: None,
+        x_sign: None,
+        y_sign: None,
+        y_transform: None,
+        tags: None,
+    })
+}
+
+pub async fn run_cancel_deletion(
+    args: &DeleteCurveArgs,
+    client: &CurvyClient,
+) -> Result<CommandOutput> {
+    let curve = client.curve(&args.curve).await?.curve;
+    let signature = client
+        .cancel_deletion(args.curve, client.priority_fee, args.force)
+        .await?;
+
+    Ok(CommandOutput::Altered {
+        curve: args.curve,
+        name: bytes_to_cow(&curve.name).into_owned(),
+        signature: signature.signature,
+        cost: signature.cost,
+        diff: vec![deletion_diff(curve.deletion_slot(), None)],
+        confidence: None,
+        x_sign: None,
+        y_sign: None,
+        y_transform: None,
+        tags: None,
+    })
+}
+
+/// Raw `y` of `value` with `decimals`, which must not have more decimals than that
+fn scale_y(value: rust_decimal::Decimal, decimals: u8, y_signed: bool) -> Result<CurveY> {
+    let scaled = value * rust_decimal::Decimal::from(10_i64.pow(decimals as u32));
+    if !scaled.fract().is_zero() {
+        bail!("y {value} has more than {decimals} decimals");
+    }
+    let raw = i64::try_from(scaled).map_err(|_| anyhow!("y {value} is out of Y range"))?;
+    to_curve_y(raw, y_signed)
+}
+
+pub async fn run_patch_y(args: &PatchYArgs, client: &CurvyClient) -> Result<CommandOutput> {
+    let curve = client.curve(&args.curve).await?.curve;
+    let values = args
+        .values
+        .iter()
+        .map(|value| scale_y(*value, curve.y_decimals, curve.y_signed != 0))
+        .collect::<Result<Vec<_>>>()?;
+
+    let signature = client
+        .patch_y(
+            args.curve,
+            args.start,
+            &values,
+            client.priority_fee,
+            args.force,
+        )
+        .await?;
+
+    let diff = values
+        .iter()
+        .enumerate()
+        .map(|(offset, y)| {
+            let idx = args.start as usize + offset;
+            FieldChange {
+                field: format!("y[{idx}]"),
+                from: curve
+                    .y
+                    .get(idx)
+                    .map_or("-".to_string(), |y| curve.y_value(*y).to_string()),
+                to: curve.y_value(*y).to_string(),
+            }
+        })
+        .filter(|change| change.from != change.to)
+        .collect();
+
+    Ok(CommandOutput::Altered {
+        curve: args.curve,
+        name: bytes_to_cow(&curve.name).into_owned(),
+        signature: signature.signature,
+        cost: signature.cost,
+        diff,
+        confidence: None,
+        x_sign: None,
+        y_sign: None,
+        y_transform: None,
+        tags: None,
+    })
+}
+
+pub async fn run_propose_owner(
+    args: &ProposeOwnerArgs,
+    client: &CurvyClient,
+    confirm: &ConfirmOptions,
+) -> Result<CommandOutput> {
+    // cancelling a proposal takes nothing away
+    if args.new_owner.is_some() {
+        confirm.confirm("transfer", &args.curve, client).await?;
+    }
+
+    let curve = client.curve(&args.curve).await?.curve;
+    let new_owner = args.new_owner.unwrap_or_default();
+    let signature = client
+        .propose_owner(args.curve, new_owner, client.priority_fee, args.force)
+        .await?;
+
+    let show = |owner: Option<Pubkey>| owner.map_or("-".to_string(), |owner| owner.to_string());
+
+    Ok(CommandOutput::Altered {
+        curve: args.curve,
+        name: bytes_to_cow(&curve.name).into_owned(),
+        signature: signature.signature,
+        cost: signature.cost,
+        diff: vec![FieldChange {
+            field: "pending_owner".to_string(),
+            from: show(curve.pending_owner()),
+            to: show(args.new_owner),
+        }],
+        confidence: None,
+        x_sign: None,
+        y_sign: None,
+        y_transform: None,
+        tags: None,
+    })
+}
+
+pub async fn run_accept_owner(
+    args: &AcceptOwnerArgs,
+    client: &CurvyClient,
+) -> Result<CommandOutput> {
+    let curve = client.curve(&args.curve).await?.curve;
+    let signature = client
+        .accept_owner(args.curve, client.priority_fee, args.force)
+        .await?;
+
+    Ok(CommandOutput::Altered {
+        curve: args.curve,
+        name: bytes_to_cow(&curve.name).into_owned(),
+        signature: signature.signature,
+        cost: signature.cost,
+        diff: vec![FieldChange {
+            field: "owner".to_string(),
+            from: curve.owner.to_string(),
+            to: client.authority.pubkey().to_string(),
+        }],
+        confidence: None,
+        x_sign: None,
+        y_sign: None,
+        y_transform: None,
+        tags: None,
+    })
+}
+
+/// Uploads additional series from CSV columns after `f_x`, one transaction per series
+pub async fn run_set_series(args: &SetSeriesArgs, client: &CurvyClient) -> Result<CommandOutput> {
+    let curve = client.curve(&args.curve).await?.curve;
+    let samples = load_samples(&args.source, Some(curve.y_decimals))
+        .await?
+        .ok_or_else(|| anyhow!("either --csv or --url is required"))?;
+
+    let samples_x0 = if samples.x0_negative {
+        -(samples.x0 as i64)
+    } else {
+        samples.x0 as i64
+    };
+    if (samples_x0, samples.x_step, samples.y_count)
+        != (curve.x0_signed(), curve.x_step, curve.y_count)
+    {
+        bail!(
+            "X grid of the CSV (x0 {}, x_step {}, {} points) differs from the curve (x0 {}, x_step {}, {} points)",
+            samples_x0,
+            samples.x_step,
+            samples.y_count,
+            curve.x0_signed(),
+            curve.x_step,
+            curve.y_count
+        );
+    }
+    if samples.series.is_empty() {
+        bail!("no additional series columns after `f_x`");
+    }
+
+    let mut signatures = vec![];
+    for (idx, y) in samples.series.iter().enumerate() {
+        let signature = client
+            .set_curve_series(
+                args.curve,
+                idx as u8 + 1,
+                *y,
+                client.priority_fee,
+                args.force,
+            )
+            .await?;
+        signatures.push(signature.signature);
+    }
+
+    Ok(CommandOutput::SeriesSet {
+        curve: args.curve,
+        name: bytes_to_cow(&curve.name).into_owned(),
+        signatures,
+        diff: series_diff(curve.series_count, samples.series.len() as u8),
+    })
+}
+
+pub async fn run_delete_series(
+    args: &DeleteCurveArgs,
+    client: &CurvyClient,
+) -> Result<CommandOutput> {
+    let curve = client.curve(&args.curve).await?.curve;
+    let signature = client
+        .delete_curve_series(args.curve, client.priority_fee, args.force)
+        .await?;
+
+    Ok(CommandOutput::Altered {
+        curve: args.curve,
+        name: bytes_to_cow(&curve.name).into_owned(),
+        signature: signature.signature,
+        cost: signature.cost,
+        diff: series_diff(curve.series_count, 0),
+        confidence: None,
+        x_sign: None,
+        y_sign: None,
+        y_transform: None,
+        tags: None,
+    })
+}
+
+pub async fn run_delete_confidence(
+    args: &DeleteCurveArgs,
+    client: &CurvyClient,
+) -> Result<CommandOutput> {
+    let curve = client.curve(&args.curve).await?.curve;
+    let signature = client
+        .delete_curve_confidence(args.curve, client.priority_fee, args.force)
+        .await?;
+
+    Ok(CommandOutput::Altered {
+        curve: args.curve,
+        name: bytes_to_cow(&curve.name).into_owned(),
+        signature: signature.signature,
+        cost: signature.cost,
+        diff: vec![confidence_diff(curve.has_confidence != 0, false)],
+        confidence: None,
+        x_sign: None,
+        y_sign: None,
+        y_transform: None,
+        tags: None,
+    })
+}
+
+fn x_sign_diff(from_negative: bool, to_negative: bool) -> FieldChange {
+    let show = |negative: bool| if negative { "-" } else { "+" }.to_string();
+
+    FieldChange {
+        field: "x0_sign".to_string(),
+        from: show(from_negative),
+        to: show(to_negative),
+    }
+}
+
+fn y_sign_diff(from_signed: bool, to_signed: bool) -> FieldChange {
+    let show = |signed: bool| if signed { "signed" } else { "unsigned" }.to_string();
+
+    FieldChange {
+        field: "y_sign".to_string(),
+        from: show(from_signed),
+        to: show(to_signed),
+    }
+}
+
+fn confidence_diff(from: bool, to: bool) -> FieldChange {
+    let show = |set: bool| if set { "set" } else { "-" }.to_string();
+
+    FieldChange {
+        field: "confidence".to_string(),
+        from: show(from),
+        to: show(to),
+    }
+}
+
+fn series_diff(from: u8, to: u8) -> Vec<FieldChange> {
+    vec![FieldChange {
+        field: "series_count".to_string(),
+        from: from.to_string(),
+        to: to.max(from).to_string(),
+    }]
+}
+
+fn asset_diff(from: Option<(Pubkey, u8)>, to: Option<(Pubkey, u8)>) -> Vec<FieldChange> {
+    let show = |asset: Option<(Pubkey, u8)>| {
+        asset.map_or("-".to_string(), |(mint, kind)| {
+            format!("{mint} (kind {kind})")
+        })
+    };
+
+    vec![FieldChange {
+        field: "asset".to_string(),
+        from: show(from),
+        to: show(to),
+    }]
+}
+
+pub async fn run_curve_for_mint(
+    args: &CurveForMintArgs,
+    client: &CurvyClient,
+) -> Result<CommandOutput> {
+    let owner = args.owner.unwrap_or_else(|| client.authority.pubkey());
+    let curve = client
+        .curve_for_mint(&owner, &args.mint, args.kind)
+        .await?
+        .ok_or_else(|| {
+            anyhow!(
+                "no curve of {owner} is associated with {} kind {}",
+                args.mint,
+                args.kind
+            )
+        })?;
+
+    Ok(CommandOutput::Curve(Box::new(
+        client.curve(&curve).await?.into_inner(),
+    )))
+}
+
+pub async fn run_resolve_curve(
+    args: &ResolveCurveArgs,
+    client: &CurvyClient,
+) -> Result<CommandOutput> {
+    let owner = args.owner.unwrap_or_else(|| client.authority.pubkey());
+    let curve = client
+        .resolve_curve(&owner, &args.name)
+        .await?
+        .ok_or_else(|| anyhow!("no curve of {owner} is registered as {}", args.name))?;
+
+    Ok(CommandOutput::Curve(Box::new(
+        client.curve(&curve).await?.into_inner(),
+    )))
+}
+
+pub async fn run_migrate_curve(
+    args: &CurveArgs,
+    client: &CurvyClient,
+    confirm: &ConfirmOptions,
+) -> Result<CommandOutput> {
+    confirm.confirm("migrate", &args.curve, client).await?;
+
+    let signature = client
+        .migrate_curve(args.curve, client.priority_fee)
+        .await?;
+
+    Ok(CommandOutput::Migrated {
+        curve: args.curve,
+        signature: signature.signature,
+        cost: signature.cost,
+    })
+}
+
+pub async fn run_curve(args: &CurveArgs, client: &CurvyClient) -> Result<CommandOutput> {
+    match client.any_curve(&args.curve).await?.into_inner() {
+        AnyCurve::Wide(curve) => {
+            return Ok(CommandOutput::WideCurve {
+                curve: args.curve,
+                owner: curve.owner,
+                params: Box::new(WideCurveParams::from(&curve)),
+            })
+        }
+        AnyCurve::Grid(curve) => {
+            return Ok(CommandOutput::GridCurve {
+                curve: args.curve,
+                owner: curve.owner,
+                params: Box::new(GridCurveParams::from(&curve)),
+            })
+        }
+        AnyCurve::Curve(_) => {}
+    }
+
+    Ok(CommandOutput::Curve(Box::new(
+        client.curve(&args.curve).await?.into_inner(),
+    )))
+}
+
+pub async fn run_curves(args: &CurvesArgs, client: &CurvyClient) -> Result<CommandOutput> {
+    let mut curves = client
+        .curves(args.tag.as_deref())
+        .await?
+        .into_inner()
+        .curves;
+    if let Some(status) = args.status {
+        curves.retain(|curve| curve.curve.status() == status);
+    }
+    if args.pending_deletion {
+        curves.retain(|curve| curve.curve.deletion_slot().is_some());
+    }
+
+    Ok(CommandOutput::Listed {
+        n: curves.len(),
+        curves: CurveList {
+            curves,
+            preview: args.preview,
+        },
+    })
+}
+
+pub async fn run_snapshot(args: &SnapshotArgs, client: &CurvyClient) -> Result<CommandOutput> {
+    let snapshot = client.snapshot().await?;
+
+    let diff = match &args.compare {
+        Some(compare) => {
+            let previous = std::fs::read_to_string(compare)?;
+            let previous: Snapshot = serde_json::from_str(&previous)?;
+            Some(snapshot.diff(&previous))
+        }
+        None => None,
+    };
+
+    let snapshot = match &args.out {
+        Some(out) => {
+            std::fs::write(out, serde_json::to_string_pretty(&snapshot)?)?;
+            None
+        }
+        None => Some(snapshot),
+    };
+
+    Ok(CommandOutput::Snapshot {
+        snapshot,
+        out: args.out.clone(),
+        diff,
+    })
+}
+
+pub async fn run_plan(args: &PlanArgs, client: &CurvyClient) -> Result<CommandOutput> {
+    let state = std::fs::read_to_string(&args.state)
+        .with_context(|| format!("reading {}", args.state.display()))?;
+    let state: ExpectedState = serde_json::from_str(&state)
+        .with_context(|| format!("parsing {}", args.state.display()))?;
+
+    let WithContext {
+        value: plan, slot, ..
+    } = client.reconcile(&state).await?;
+    let hash = plan.hash()?;
+    if let Some(out) = &args.out {
+        std::fs::write(out, plan.to_bytes()?)?;
+    }
+
+    Ok(CommandOutput::Planned {
+        plan,
+        hash,
+        slot,
+        out: args.out.clone(),
+    })
+}
+
+/// Plan file saved by plan --out, rejected when edited since
+pub fn read_plan(path: &Path) -> Result<ReconcilePlan> {
+    let bytes = std::fs::read(path).with_context(|| format!("reading {}", path.display()))?;
+    ReconcilePlan::from_bytes(&bytes).with_context(|| format!("plan {}", path.display()))
+}
+
+pub async fn run_apply(
+    args: &ApplyArgs,
+    client: &CurvyClient,
+    confirm: &ConfirmOptions,
+) -> Result<CommandOutput> {
+    let plan = read_plan(&args.plan)?;
+    let hash = plan.hash()?;
+
+    // destructive actions are confirmed before anything is applied: all of them when actions
+    // are approved in advance, otherwise protected ones on top of the per-action prompt
+    for action in &plan.actions {
+        let (verb, curve) = match action {
+            ReconcileAction::Delete { curve, .. } => ("delete", curve),
+            ReconcileAction::Transfer { curve, .. } => ("transfer", curve),
+            _ => continue,
+        };
+        if args.auto_approve || confirm.is_protected(curve) {
+            confirm.confirm(verb, curve, client).await?;
+        }
+    }
+
+    let mut actions = vec![];
+    for (idx, action) in plan.actions.iter().enumerate() {
+        if !args.auto_approve && !confirm_plan_action(&hash, idx, plan.actions.len(), action)? {
+            actions.push(AppliedAction {
+                action: action.clone(),
+                applied: false,
+                signatures: vec![],
+            });
+            continue;
+        }
+
+        let signatures = client
+            .apply_reconcile_action(action)
+            .await
+            .with_context(|| format!("applying `{action}`"))?;
+        actions.push(AppliedAction {
+            action: action.clone(),
+            applied: true,
+            signatures,
+        });
+    }
+
+    Ok(CommandOutput::Applied {
+        plan: args.plan.clone(),
+        hash,
+        actions,
+    })
+}
+
+pub async fn run_pack(args: &PackArgs, client: &CurvyClient) -> Result<CommandOutput> {
+    let owner = args.owner.unwrap_or_else(|| client.authority.pubkey());
+    let (curves, _) = load_curves(&client.rpc).await?;
+
+    let mut pack = CurvePack::new(
+        curves
+            .values()
+            .filter(|curve| curve.owner == owner)
+            .map(CurveParams::from),
+    )?;
+    if let Some(path) = &args.sign {
+        let release = read_keypair_file(&path.0)
+            .map_err(|err| anyhow!("reading release keypair: {}", err))?;
+        pack.sign(&release)?;
+    }
+    pack.write(&args.out)?;
+
+    Ok(CommandOutput::Packed {
+        out: args.out.clone(),
+        owner,
+        curves: pack.entries.len(),
+        signer: pack.signature.map(|signature| signature.signer),
+    })
+}
+
+pub async fn run_restore(args: &RestoreArgs, client: &CurvyClient) -> Result<CommandOutput> {
+    let pack = CurvePack::read(&args.pack)?;
+    if let Some(release_key) = &args.release_key {
+        pack.verify(release_key)
+            .with_context(|| format!("pack {}", args.pack.display()))?;
+    }
+    let owner = client.authority.pubkey();
+
+    let mut curves = vec![];
+    for entry in &pack.entries {
+        let existing = match client.resolve_curve(&owner, &entry.name).await? {
+            Some(key) => Some((key, client.curve(&key).await?.curve)),
+            None => None,
+        };
+
+        let restored = match existing {
+            Some((key, curve)) if CurveParams::from(&curve).hash().to_bytes() == entry.hash => {
+                RestoredCurve {
+                    name: entry.name.clone(),
+                    action: RestoreAction::Unchanged,
+                    curve: Some(key),
+                    signature: None,
+                }
+            }
+            Some((key, _)) => {
+                let signature = if args.dry_run {
+                    None
+                } else {
+                    let params = &entry.params;
+                    let view = client
+                        .alter_curve(
+                            key,
+                            Some(entry.name.clone()),
+                            Some(bytes_to_cow(&params.formula).into_owned()),
+                            Some(params.x_decimals),
+                            Some(params.y_decimals),
+                            Some(params.x0),
+                            Some(params.x_step),
+                            Some(params.y_count),
+                            Some(params.y),
+                            client.priority_fee,
+                            false,
+                        )
+                        .await
+                        .with_context(|| format!("altering curve `{}`", entry.name))?;
+                    Some(view.signature)
+                };
+                RestoredCurve {
+                    name: entry.name.clone(),
+                    action: RestoreAction::Altered,
+                    curve: Some(key),
+                    signature,
+                }
+            }
+            None if args.dry_run => RestoredCurve {
+                name: entry.name.clone(),
+                action: RestoreAction::Created,
+                curve: None,
+                signature: None,
+            },
+            None => {
+                let view = client
+                    .create_curve(entry.params, client.priority_fee)
+                    .await
+                    .with_context(|| format!("creating curve `{}`", entry.name))?;
+                if let Some(error) = view.error {
+                    bail!("creating curve `{}`: {error}", entry.name);
+                }
+                RestoredCurve {
+                    name: entry.name.clone(),
+                    action: RestoreAction::Created,
+                    curve: Some(view.curve),
+                    signature: view.signature,
+                }
+            }
+        };
+        curves.push(restored);
+    }
+
+    Ok(CommandOutput::Restored {
+        pack: args.pack.clone(),
+        signer: pack.signature.map(|signature| signature.signer),
+        dry_run: args.dry_run,
+        curves,
+    })
+}
+
+pub async fn run_export_all(args: &ExportAllArgs, client: &CurvyClient) -> Result<CommandOutput> {
+    let (curves, slot) = load_curves(&client.rpc).await?;
+    let mut curves = curves.into_iter().collect::<Vec<_>>();
+    curves.sort_by_key(|(key, _)| *key);
+
+    let stats = export(args.format, &args.out, &curves, slot)?;
+
+    Ok(CommandOutput::Exported {
+        format: args.format,
+        out: args.out.clone(),
+        slot,
+        stats,
+    })
+}
+
+pub async fn run_owners(client: &CurvyClient) -> Result<CommandOutput> {
+    Ok(CommandOutput::Owners {
+        owners: client.owners().await?,
+    })
+}
+
+pub async fn run_init_config(args: &InitConfigArgs, client: &CurvyClient) -> Result<CommandOutput> {
+    if args.creators.is_empty() {
+        tracing::warn!("no creators given, nobody will be able to create curves");
+    }
+
+    let signature = client
+        .init_config(args.creators.clone(), client.priority_fee)
+        .await?;
+
+    run_config(Some(signature.signature), client).await
+}
+
+pub async fn run_set_config(args: &SetConfigArgs, client: &CurvyClient) -> Result<CommandOutput> {
+    if args.creators.is_empty() {
+        tracing::warn!("no creators given, nobody will be able to create curves");
+    }
+
+    let admin = match (args.admin, client.config().await?) {
+        (Some(admin), _) => admin,
+        (None, Some(config)) => config.admin,
+        (None, None) => bail!("program config does not exist, create it by init-config"),
+    };
+
+    let signature = client
+        .set_config(
+            admin,
+            args.creators.clone(),
+            client.priority_fee,
+            args.force,
+        )
+        .await?;
+
+    run_config(Some(signature.signature), client).await
+}
+
+pub async fn run_set_creation_frozen(args: &SetCreationFrozenArgs, client: &CurvyClient) -> Result<CommandOutput> {
+    let signature = client
+        .set_creation_frozen(!args.unfreeze, client.priority_fee, args.force)
+        .await?;
+
+    run_config(Some(signature.signature), client).await
+}
+
+/// Current program config, after the transaction with `signature` when given
+pub async fn run_config(
+    signature: Option<Signature>,
+    client: &CurvyClient,
+) -> Result<CommandOutput> {
+    let config = client.config().await?;
+
+    Ok(CommandOutput::Config {
+        config: Config::find_address().0,
+        admin: config.as_ref().map(|config| config.admin),
+        creation_frozen: config
+            .as_ref()
+            .is_some_and(|config| config.is_creation_frozen()),
+        creators: config
+            .map(|config| config.creators().copied().collect())
+            .unwrap_or_default(),
+        signature,
+    })
+}
+
+pub async fn run_record_fixture(
+    args: &RecordFixtureArgs,
+    client: &CurvyClient,
+) -> Result<CommandOutput> {
+    let account = client.get_account(&args.curve).await?;
+    let slot = account.slot;
+    if account.owner != curvy::ID {
+        bail!("account {} is not owned by the program", args.curve);
+    }
+
+    let source = format!("{} at slot {slot}", client.rpc.url());
+    let fixture = curve_fixture(&args.curve, &account.data, &args.description, &source)?;
+    record_fixture(&args.out, fixture)?;
+
+    Ok(CommandOutput::FixtureRecorded {
+        curve: args.curve,
+        slot,
+        out: args.out.clone(),
+    })
+}
+
+pub fn run_decode_account(args: &DecodeAccountArgs) -> Result<CommandOutput> {
+    Ok(CommandOutput::decoded(curve_from_base64(&args.base64)?))
+}
+
+pub fn run_lint_files(args: &LintFilesArgs) -> Result<CommandOutput> {
+    Ok(CommandOutput::Lint(lint_files(
+        &args.pattern,
+        args.decimals,
+    )?))
+}
+
+pub fn run_fit(args: &FitArgs) -> Result<CommandOutput> {
+    let samples = read_reference_samples(&args.csv, args.decimals)?;
+    let target_error = args
+        .target_error
+        .map(|error| Decimal::from_i128_with_scale(error.mantissa(), error.scale()))
+        .transpose()?;
+
+    let fit = fit_uniform(&samples, args.decimals, target_error)?;
+    write_samples(
+        &args.out,
+        fit.x0,
+        fit.x_step,
+        &fit.y[..fit.y_count as usize],
+        args.decimals,
+    )?;
+
+    Ok(CommandOutput::Fitted {
+        x0: fit.x0,
+        x_step: fit.x_step,
+        y_count: fit.y_count,
+        error: fit.error,
+        out: args.out.clone(),
+    })
+}
+
+pub fn run_smooth(args: &SmoothArgs) -> Result<CommandOutput> {
+    let samples = read_samples(&args.csv)?;
+    if samples.x0_negative || samples.y_signed {
+        bail!("negative samples can not be smoothed");
+    }
+    if !samples.series.is_empty() || samples.confidence.is_some() {
+        tracing::warn!("additional columns are dropped, only `f_x` is smoothed");
+    }
+
+    let params = CurveParams::new(
+        "",
+        "",
+        samples.x0,
+        samples.x_step,
+        samples.y_count,
+        args.decimals,
+        samples.y,
+    );
+    let smoothed = smooth(&params, args.method, args.window)?;
+
+    let threshold =
+        Decimal::from_i128_with_scale(args.threshold.mantissa(), args.threshold.scale())?;
+    let threshold = to_scaled(threshold, args.decimals as u32, Rounding::Down)?;
+    let changed = changed_points(&params, &smoothed, threshold);
+
+    write_samples(
+        &args.out,
+        smoothed.x0,
+        smoothed.x_step,
+        &smoothed.y[..smoothed.y_count as usize],
+        args.decimals,
+    )?;
+
+    Ok(CommandOutput::Smoothed {
+        method: args.method,
+        window: args.window,
+        changed,
+        out: args.out.clone(),
+    })
+}
+
+pub async fn run_profile(args: &CurveArgs, client: &CurvyClient) -> Result<CommandOutput> {
+    let curve = client.curve(&args.curve).await?.curve;
+    let payer = client.authority.pubkey();
+
+    let ix = AlterCurve {
+        curve: args.curve,
+        owner: payer,
+        params: CurveParams::from(&curve),
+    }
+    .into_instruction();
+
+    let profile = client.profile(vec![ix.clone()]).await?;
+
+    let fit = profile
+        .instructions
+        .first()
+        .and_then(|instruction| instruction.units)
+        .filter(|_| profile.error.is_none())
+        .map(|units| fit_per_transaction(&ix, &payer, units));
+
+    Ok(CommandOutput::Profile { profile, fit })
+}
+
+pub fn run_errors(args: &ErrorsArgs) -> Result<CommandOutput> {
+    let errors = error_table()
+        .iter()
+        .filter(|error| args.code.is_none() || args.code == Some(error.code))
+        .copied()
+        .collect::<Vec<_>>();
+
+    if let (Some(code), true) = (args.code, errors.is_empty()) {
+        bail!("unknown error code {code}");
+    }
+
+    Ok(CommandOutput::Errors { errors })
+}
+
+pub async fn run_evaluate_curve(
+    args: &EvaluateCurveArgs,
+    client: &CurvyClient,
+) -> Result<CommandOutput> {
+    let curve = client.curve(&args.curve).await?.curve;
+    let scaled = args.x * rust_decimal::Decimal::from(10_i64.pow(curve.x_decimals as u32));
+    if !scaled.fract().is_zero() {
+        bail!("x {} has more than {} decimals", args.x, curve.x_decimals);
+    }
+    let x = i64::try_from(scaled).map_err(|_| anyhow!("x {} is out of X range", args.x))?;
+
+    let result = match &args.result_keypair {
+        Some(result_keypair) => read_keypair_file(&result_keypair.0)
+            .map_err(|err| anyhow!("reading result keypair: {}", err))?,
+        None => Keypair::new(),
+    };
+
+    let signature = client
+        .evaluate_curve(args.curve, &result, x, client.priority_fee)
+        .await?;
+    let evaluation = client.curve_evaluation(&result.pubkey()).await?;
+
+    Ok(CommandOutput::Evaluated {
+        curve: args.curve,
+        evaluation: result.pubkey(),
+        x: Decimal::from_i128_with_scale(evaluation.x as i128, evaluation.x_decimals as u32)?,
+        y: Decimal::from_i128_with_scale(evaluation.y as i128, evaluation.y_decimals as u32)?,
+        slot: evaluation.slot,
+        signature: signature.signature,
+        cost: signature.cost,
+    })
+}
+
+pub async fn run_calc_y(args: &CalcYArgs, client: &CurvyClient) -> Result<CommandOutput> {
+    let x = Decimal::from_i128_with_scale((args.x * 1_000_000_000.0) as i128, 9)?;
+
+    let any_curve = client.any_curve(&args.curve).await?.into_inner();
+    if !matches!(any_curve, AnyCurve::Curve(_)) {
+        if args.series != 0 {
+            bail!("wide and grid curves have no additional series");
+        }
+        let y = any_curve.calc_y(x)?;
+        return Ok(CommandOutput::CalcY {
+            curve: args.curve,
+            x: args.x,
+            y,
+            confidence: None,
+            scaled: scaled_y(y, args.scaled)?,
+        });
+    }
+
+    let curve = client.curve(&args.curve).await?;
+    let series = match args.series {
+        0 => None,
+        _ => client
+            .curve_series(&args.curve)
+            .await?
+            .map(WithContext::into_inner),
+    };
+
+    // samples after the `y` table extend the curve itself, not its series
+    let y = match args.series {
+        0 => curve.calc_y(x)?,
+        _ => calc_y_series(x, &curve.curve, series.as_ref(), args.series)?,
+    };
+
+    // confidence is kept for the curve `y` table only
+    let confidence = if args.series == 0 && curve.curve.has_confidence != 0 {
+        let confidence = client
+            .curve_confidence(&args.curve)
+            .await?
+            .map(WithContext::into_inner);
+        Some(calc_y_with_confidence(x, &curve.curve, confidence.as_ref())?.1)
+    } else {
+        None
+    };
+
+    Ok(CommandOutput::CalcY {
+        curve: args.curve,
+        x: args.x,
+        y,
+        confidence,
+        scaled: scaled_y(y, args.scaled)?,
+    })
+}
+
+/// `y` with `decimals` rounded down, when requested
+fn scaled_y(y: Decimal, decimals: Option<u32>) -> Result<Option<i128>> {
+    decimals
+        .map(|decimals| to_scaled(y, decimals, Rounding::Down))
+        .transpose()
+}
+
+pub async fn run_check_consumer(
+    args: &CheckConsumerArgs,
+    client: &CurvyClient,
+) -> Result<CommandOutput> {
+    let layout = std::fs::read_to_string(&args.layout)
+        .with_context(|| format!("reading {}", args.layout.display()))?;
+    let layout: ConsumerLayout = serde_json::from_str(&layout)
+        .with_context(|| format!("parsing {}", args.layout.display()))?;
+
+    let tolerance = match args.tolerance {
+        Some(tolerance) => Decimal::from_i128_with_scale(tolerance.mantissa(), tolerance.scale())?,
+        None => Decimal::from_i128_with_scale(1, layout.y_decimals)?,
+    };
+
+    let report = client
+        .check_consumer(&args.curve, &layout, args.per_segment, tolerance)
+        .await?;
+
+    Ok(CommandOutput::ConsumerCheck(report))
+}
+
+pub async fn run_summarize(args: &SummarizeArgs, client: &CurvyClient) -> Result<CommandOutput> {
+    let current = client.curve(&args.curve).await?.curve;
+
+    let style = SummaryStyle {
+        subject: args
+            .subject
+            .clone()
+            .unwrap_or_else(|| bytes_to_cow(&current.name).into_owned()),
+        x_label: args.x_label.clone().unwrap_or_default(),
+        x_percent: args.x_percent,
+        y_percent: args.y_percent,
+        precision: args.precision,
+        points: args
+            .points
+            .iter()
+            .map(|x| Decimal::from_i128_with_scale(x.mantissa(), x.scale()))
+            .collect::<Result<_, _>>()?,
+    };
+
+    let (change, lines) = match load_samples(&args.source, args.decimals).await? {
+        Some(samples) => {
+            let update = CurveUpdate {
+                x_decimals: args.decimals,
+                y_decimals: args.decimals,
+                x0: Some(samples.x0),
+                x_step: Some(samples.x_step),
+                y_count: Some(samples.y_count),
+                y: Some(samples.y),
+                ..Default::default()
+            };
+            let mut proposed = current;
+            proposed.set_params(update.apply(&current));
+            proposed.x0_negative = samples.x0_negative as u8;
+            proposed.y_signed = samples.y_signed as u8;
+
+            (true, describe_change(&current, &proposed, &style)?)
+        }
+        None => (false, describe_curve(&current, &style)?),
+    };
+
+    Ok(CommandOutput::Summary {
+        curve: args.curve,
+        change,
+        lines,
+    })
+}
+
+pub async fn run_twa(args: &TwaArgs, client: &CurvyClient) -> Result<CommandOutput> {
+    let to = SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs() as i64;
+    let from = to - args.window.as_secs() as i64;
+
+    let history = client.curve_history(&args.curve, from).await?;
+    let twa = twa_curve(&history, from, to)?;
+
+    // the first one may become active before the window
+    let versions = history
+        .iter()
+        .enumerate()
+        .filter(|(idx, version)| {
+            let until = history.get(idx + 1).map_or(to, |next| next.since);
+            version.since < to && until > from
+        })
+        .count();
+
+    Ok(CommandOutput::Twa {
+        curve: args.curve,
+        from,
+        to,
+        versions,
+        params: Box::new(CurveParams::from(&twa)),
+        x0_negative: twa.x0_negative != 0,
+    })
+}
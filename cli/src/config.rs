@@ -0,0 +1,47 @@
+use std::path::Path;
+use std::str::FromStr;
+
+use anyhow::{anyhow, Context, Result};
+use solana_sdk::pubkey::Pubkey;
+
+/// CLI config file contents. Missing file means default config.
+#[derive(Debug, Default, serde::Deserialize)]
+pub struct Config {
+    /// Curves which always require typing the curve name to confirm destructive commands,
+    /// even when `--yes` is given.
+    #[serde(default)]
+    pub protected_curves: Vec<String>,
+}
+
+impl Config {
+    pub fn load(path: &Path) -> Result<Self> {
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+
+        let data = std::fs::read_to_string(path)
+            .with_context(|| format!("reading config {}", path.display()))?;
+        let config: Self = serde_json::from_str(&data)
+            .with_context(|| format!("parsing config {}", path.display()))?;
+
+        // validate early so a typo doesn't silently unprotect a curve
+        config.protected()?;
+
+        Ok(config)
+    }
+
+    pub fn protected(&self) -> Result<Vec<Pubkey>> {
+        self.protected_curves
+            .iter()
+            .map(|key| {
+                Pubkey::from_str(key).map_err(|err| anyhow!("protected curve `{key}`: {err}"))
+            })
+            .collect()
+    }
+
+    pub fn is_protected(&self, curve: &Pubkey) -> bool {
+        self.protected()
+            .map(|keys| keys.contains(curve))
+            .unwrap_or(true)
+    }
+}
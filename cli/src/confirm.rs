@@ -0,0 +1,117 @@
+use std::io::{BufRead, Write};
+
+use anyhow::{bail, Result};
+use solana_sdk::hash::Hash;
+use solana_sdk::pubkey::Pubkey;
+
+use curvy::state::utils::bytes_to_cow;
+use curvy_client::{CurvyClient, ReconcileAction};
+use curvy_utils::AnyCurve;
+
+use crate::config::Config;
+
+/// How destructive commands are confirmed. Commands confirm through it themselves, so callers
+/// of [crate::commands::run] get the same prompts as the `curvy` binary.
+#[derive(Debug, Clone, Default)]
+pub struct ConfirmOptions {
+    /// Skip the prompt for curves which are not protected
+    pub assume_yes: bool,
+    /// Curves which always require typing the curve name
+    pub protected: Vec<Pubkey>,
+}
+
+impl ConfirmOptions {
+    pub fn new(assume_yes: bool, config: &Config) -> Result<Self> {
+        Ok(Self {
+            assume_yes,
+            protected: config.protected()?,
+        })
+    }
+
+    pub fn is_protected(&self, curve: &Pubkey) -> bool {
+        self.protected.contains(curve)
+    }
+
+    /// Asks user to confirm destructive `action` on the curve `key`, see [confirm_destructive]
+    pub async fn confirm(&self, action: &str, key: &Pubkey, client: &CurvyClient) -> Result<()> {
+        let protected = self.is_protected(key);
+        if self.assume_yes && !protected {
+            return Ok(());
+        }
+
+        let curve = client.any_curve(key).await?;
+        let consumers = client.curve_consumers(key).await?;
+
+        confirm_destructive(action, key, &curve, consumers, self.assume_yes, protected)
+    }
+}
+
+/// Asks user to confirm destructive `action` on the curve used by `consumers` asset mints.
+/// Regular curves are confirmed with `y`/`yes` (skipped when `assume_yes` is set). Protected
+/// curves always require typing the curve name.
+pub fn confirm_destructive(
+    action: &str,
+    key: &Pubkey,
+    curve: &AnyCurve,
+    consumers: usize,
+    assume_yes: bool,
+    protected: bool,
+) -> Result<()> {
+    let name = bytes_to_cow(curve.name());
+
+    if assume_yes && !protected {
+        return Ok(());
+    }
+
+    eprintln!("About to {action} curve:");
+    eprintln!("  Address   : {key}");
+    eprintln!("  Name      : {name}");
+    eprintln!("  Owner     : {}", curve.owner());
+    eprintln!("  Consumers : {consumers}");
+
+    let answer = if protected {
+        prompt(&format!(
+            "Curve is protected. Type the curve name `{name}` to confirm: "
+        ))?
+    } else {
+        prompt("Continue? [y/N]: ")?
+    };
+
+    let confirmed = if protected {
+        answer == name
+    } else {
+        matches!(answer.to_lowercase().as_str(), "y" | "yes")
+    };
+
+    if !confirmed {
+        bail!("{action} aborted by user");
+    }
+
+    Ok(())
+}
+
+/// Asks user whether to apply action `idx` of `total` of the plan with `hash`, see
+/// [ReconcilePlan::hash](curvy_client::ReconcilePlan::hash). Declined actions are skipped.
+pub fn confirm_plan_action(
+    hash: &Hash,
+    idx: usize,
+    total: usize,
+    action: &ReconcileAction,
+) -> Result<bool> {
+    eprintln!("Action {} of {total} of plan {hash}:", idx + 1);
+    eprintln!("  {action}");
+
+    let answer = prompt("Apply? [y/N]: ")?;
+
+    Ok(matches!(answer.to_lowercase().as_str(), "y" | "yes"))
+}
+
+fn prompt(message: &str) -> Result<String> {
+    eprint!("{message}");
+    std::io::stderr().flush()?;
+
+    let mut answer = String::new();
+    std::io::stdin().lock().read_line(&mut answer)?;
+
+    Ok(answer.trim().to_string())
+}
@@ -0,0 +1,351 @@
+//! Export of all curves into analytics-friendly files, so curves can be joined with other data
+//! (e.g. in DuckDB) without RPC.
+
+use std::path::Path;
+use std::sync::Arc;
+
+use anyhow::Result;
+use parquet::data_type::{ByteArray, ByteArrayType, DoubleType, Int32Type, Int64Type};
+use parquet::file::metadata::KeyValue;
+use parquet::file::properties::WriterProperties;
+use parquet::file::writer::SerializedFileWriter;
+use parquet::schema::parser::parse_message_type;
+use rusqlite::{params, Connection};
+use solana_sdk::clock::Slot;
+use solana_sdk::pubkey::Pubkey;
+
+use curvy::state::curve::{Curve, YTransform};
+use curvy::state::utils::bytes_to_cow;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ExportFormat {
+    Parquet,
+    Sqlite,
+}
+
+impl std::str::FromStr for ExportFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "parquet" => Ok(Self::Parquet),
+            "sqlite" => Ok(Self::Sqlite),
+            _ => Err(format!(
+                "unknown export format `{s}`, expected parquet or sqlite"
+            )),
+        }
+    }
+}
+
+/// Sizes of an export
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize)]
+pub struct ExportStats {
+    pub curves: usize,
+    pub points: usize,
+}
+
+/// Curve point with X and Y scaled by the curve decimals of each axis
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Point {
+    pub idx: u8,
+    pub x: f64,
+    pub y: f64,
+}
+
+/// Points of the curve, `y_count` of them. `y` is the value of the curve, stored values are
+/// transformed, see [YTransform].
+pub fn points(curve: &Curve) -> impl Iterator<Item = Point> + '_ {
+    let x_scale = 10_f64.powi(curve.x_decimals as i32);
+    let y_scale = 10_f64.powi(curve.y_decimals as i32);
+
+    (0..curve.y_count).map(move |idx| {
+        let y = curve.y_value(curve.y[idx as usize]) as f64 / y_scale;
+        Point {
+            idx,
+            x: (curve.x0_signed() as f64 + idx as f64 * curve.x_step as f64) / x_scale,
+            y: match curve.y_transform() {
+                YTransform::Linear => y,
+                YTransform::Log10 => 10_f64.powf(y),
+            },
+        }
+    })
+}
+
+/// Writes `curves` loaded at `slot` into `out`, replacing the file
+pub fn export(
+    format: ExportFormat,
+    out: &Path,
+    curves: &[(Pubkey, Curve)],
+    slot: Slot,
+) -> Result<ExportStats> {
+    if out.exists() {
+        std::fs::remove_file(out)?;
+    }
+
+    match format {
+        ExportFormat::Parquet => export_parquet(out, curves, slot)?,
+        ExportFormat::Sqlite => export_sqlite(out, curves, slot)?,
+    }
+
+    Ok(ExportStats {
+        curves: curves.len(),
+        points: curves.iter().map(|(_, curve)| curve.y_count as usize).sum(),
+    })
+}
+
+const POINTS_SCHEMA: &str = "
+    message curve_points {
+        REQUIRED BYTE_ARRAY curve (UTF8);
+        REQUIRED BYTE_ARRAY owner (UTF8);
+        REQUIRED BYTE_ARRAY name (UTF8);
+        REQUIRED BYTE_ARRAY formula (UTF8);
+        OPTIONAL BYTE_ARRAY asset_mint (UTF8);
+        REQUIRED INT32 decimals;
+        REQUIRED INT32 y_decimals;
+        REQUIRED INT64 slot;
+        OPTIONAL INT32 idx;
+        OPTIONAL DOUBLE x;
+        OPTIONAL DOUBLE y;
+    }
+";
+
+/// Single table of points with metadata of their curve in every row. Curves without points
+/// (e.g. reserved) get one row with null `idx`, `x` and `y`. The slot is also stored in the
+/// file metadata.
+fn export_parquet(out: &Path, curves: &[(Pubkey, Curve)], slot: Slot) -> Result<()> {
+    let mut rows: Vec<(&Pubkey, &Curve, Option<Point>)> = vec![];
+    for (key, curve) in curves {
+        let len = rows.len();
+        rows.extend(points(curve).map(|point| (key, curve, Some(point))));
+        if rows.len() == len {
+            rows.push((key, curve, None));
+        }
+    }
+
+    let text = |value: &dyn Fn(&Pubkey, &Curve) -> String| {
+        rows.iter()
+            .map(|(key, curve, _)| ByteArray::from(value(key, curve).as_str()))
+            .collect::<Vec<_>>()
+    };
+    let asset_mints = rows
+        .iter()
+        .filter_map(|(_, curve, _)| curve.asset())
+        .map(|(mint, _kind)| ByteArray::from(mint.to_string().as_str()))
+        .collect::<Vec<_>>();
+    let asset_levels = rows
+        .iter()
+        .map(|(_, curve, _)| curve.asset().is_some() as i16)
+        .collect::<Vec<_>>();
+    let point_levels = rows
+        .iter()
+        .map(|(_, _, point)| point.is_some() as i16)
+        .collect::<Vec<_>>();
+    let point_values = rows
+        .iter()
+        .filter_map(|(_, _, point)| *point)
+        .collect::<Vec<_>>();
+
+    let props = WriterProperties::builder()
+        .set_key_value_metadata(Some(vec![KeyValue::new(
+            "slot".to_string(),
+            slot.to_string(),
+        )]))
+        .build();
+    let mut writer = SerializedFileWriter::new(
+        std::fs::File::create(out)?,
+        Arc::new(parse_message_type(POINTS_SCHEMA)?),
+        Arc::new(props),
+    )?;
+    let mut row_group = writer.next_row_group()?;
+
+    let mut column_idx = 0;
+    while let Some(mut column) = row_group.next_column()? {
+        match column_idx {
+            0 => column.typed::<ByteArrayType>().write_batch(
+                &text(&|key, _| key.to_string()),
+                None,
+                None,
+            )?,
+            1 => column.typed::<ByteArrayType>().write_batch(
+                &text(&|_, curve| curve.owner.to_string()),
+                None,
+                None,
+            )?,
+            2 => column.typed::<ByteArrayType>().write_batch(
+                &text(&|_, curve| bytes_to_cow(&curve.name).into_owned()),
+                None,
+                None,
+            )?,
+            3 => column.typed::<ByteArrayType>().write_batch(
+                &text(&|_, curve| bytes_to_cow(&curve.formula).into_owned()),
+                None,
+                None,
+            )?,
+            4 => column.typed::<ByteArrayType>().write_batch(
+                &asset_mints,
+                Some(&asset_levels),
+                None,
+            )?,
+            5 => column.typed::<Int32Type>().write_batch(
+                &rows
+                    .iter()
+                    .map(|(_, curve, _)| curve.x_decimals as i32)
+                    .collect::<Vec<_>>(),
+                None,
+                None,
+            )?,
+            6 => column.typed::<Int32Type>().write_batch(
+                &rows
+                    .iter()
+                    .map(|(_, curve, _)| curve.y_decimals as i32)
+                    .collect::<Vec<_>>(),
+                None,
+                None,
+            )?,
+            7 => column.typed::<Int64Type>().write_batch(
+                &vec![slot as i64; rows.len()],
+                None,
+                None,
+            )?,
+            8 => column.typed::<Int32Type>().write_batch(
+                &point_values
+                    .iter()
+                    .map(|point| point.idx as i32)
+                    .collect::<Vec<_>>(),
+                Some(&point_levels),
+                None,
+            )?,
+            9 => column.typed::<DoubleType>().write_batch(
+                &point_values.iter().map(|point| point.x).collect::<Vec<_>>(),
+                Some(&point_levels),
+                None,
+            )?,
+            _ => column.typed::<DoubleType>().write_batch(
+                &point_values.iter().map(|point| point.y).collect::<Vec<_>>(),
+                Some(&point_levels),
+                None,
+            )?,
+        };
+        column.close()?;
+        column_idx += 1;
+    }
+
+    row_group.close()?;
+    writer.close()?;
+
+    Ok(())
+}
+
+const SQLITE_SCHEMA: &str = "
+    CREATE TABLE curves (
+        address TEXT PRIMARY KEY,
+        owner TEXT NOT NULL,
+        name TEXT NOT NULL,
+        formula TEXT NOT NULL,
+        version INTEGER NOT NULL,
+        decimals INTEGER NOT NULL,
+        y_decimals INTEGER NOT NULL,
+        x0 INTEGER NOT NULL,
+        x_step INTEGER NOT NULL,
+        y_count INTEGER NOT NULL,
+        asset_mint TEXT,
+        asset_kind INTEGER,
+        slot INTEGER NOT NULL
+    );
+    CREATE TABLE points (
+        curve TEXT NOT NULL REFERENCES curves (address),
+        idx INTEGER NOT NULL,
+        x REAL NOT NULL,
+        y REAL NOT NULL,
+        PRIMARY KEY (curve, idx)
+    );
+";
+
+/// `curves` table with metadata and `points` table with (curve, idx, x, y) rows
+fn export_sqlite(out: &Path, curves: &[(Pubkey, Curve)], slot: Slot) -> Result<()> {
+    let mut conn = Connection::open(out)?;
+    conn.execute_batch(SQLITE_SCHEMA)?;
+
+    let tx = conn.transaction()?;
+    {
+        let mut insert_curve = tx.prepare(
+            "INSERT INTO curves VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13)",
+        )?;
+        let mut insert_point = tx.prepare("INSERT INTO points VALUES (?1, ?2, ?3, ?4)")?;
+
+        for (key, curve) in curves {
+            let key = key.to_string();
+            let asset = curve.asset();
+
+            insert_curve.execute(params![
+                key,
+                curve.owner.to_string(),
+                bytes_to_cow(&curve.name),
+                bytes_to_cow(&curve.formula),
+                curve.version,
+                curve.x_decimals,
+                curve.y_decimals,
+                curve.x0_signed(),
+                curve.x_step,
+                curve.y_count,
+                asset.map(|(mint, _kind)| mint.to_string()),
+                asset.map(|(_mint, kind)| kind),
+                slot,
+            ])?;
+
+            for point in points(curve) {
+                insert_point.execute(params![key, point.idx, point.x, point.y])?;
+            }
+        }
+    }
+    tx.commit()?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use curvy::state::curve::{CurveParams, MAX_Y_CNT};
+    use texture_common::account::PodAccount;
+
+    use super::*;
+
+    #[test]
+    fn sqlite() {
+        let mut y = [0; MAX_Y_CNT];
+        y[..3].copy_from_slice(&[100, 150, 300]);
+        let params = CurveParams::new("SOL-borrow", "y=f(x)", 10, 5, 3, 2, y);
+        let curve = Curve::from_init_params((params, Pubkey::new_unique()));
+        let key = Pubkey::new_unique();
+
+        let out = std::env::temp_dir().join(format!("curvy-export-{key}.sqlite"));
+        let stats = export(ExportFormat::Sqlite, &out, &[(key, curve)], 42).unwrap();
+        assert_eq!(
+            stats,
+            ExportStats {
+                curves: 1,
+                points: 3
+            }
+        );
+
+        let conn = Connection::open(&out).unwrap();
+        let rows = conn
+            .prepare("SELECT c.name, p.x, p.y FROM points p JOIN curves c ON c.address = p.curve ORDER BY p.idx")
+            .unwrap()
+            .query_map([], |row| Ok((row.get::<_, String>(0)?, row.get(1)?, row.get(2)?)))
+            .unwrap()
+            .collect::<Result<Vec<(String, f64, f64)>, _>>()
+            .unwrap();
+        std::fs::remove_file(&out).unwrap();
+
+        assert_eq!(
+            rows,
+            vec![
+                ("SOL-borrow".to_string(), 0.1, 1.0),
+                ("SOL-borrow".to_string(), 0.15, 1.5),
+                ("SOL-borrow".to_string(), 0.2, 3.0),
+            ]
+        );
+    }
+}
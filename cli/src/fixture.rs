@@ -0,0 +1,122 @@
+//! Compatibility fixtures: raw data of deployed Curve accounts together with the fields the
+//! program wrote, replayed by `program/tests/account_fixtures.rs` to catch layout changes between
+//! releases.
+
+use std::path::Path;
+
+use anyhow::{bail, Result};
+use serde_json::{json, Value};
+use solana_sdk::pubkey::Pubkey;
+
+use curvy::state::curve::Curve;
+use curvy::state::utils::bytes_to_cow;
+use curvy::state::{probe, AccountKind};
+
+/// Fixture of Curve account `address` with `data`. Expected fields are decoded by the current
+/// program, so recorded accounts must be checked before committing the fixture.
+pub fn curve_fixture(
+    address: &Pubkey,
+    data: &[u8],
+    description: &str,
+    source: &str,
+) -> Result<Value> {
+    let version = match probe(data) {
+        Some(AccountKind::Curve { version }) => version,
+        Some(kind) => bail!("unsupported account: {kind}"),
+        None => bail!("not a Curvy account"),
+    };
+
+    let curve = Curve::read(data)?;
+
+    Ok(json!({
+        "description": description,
+        "source": source,
+        "address": address.to_string(),
+        "data": data.iter().map(|byte| format!("{byte:02x}")).collect::<String>(),
+        "expected": {
+            "kind": "Curve",
+            "version": version,
+            "name": bytes_to_cow(&curve.name),
+            "formula": bytes_to_cow(&curve.formula),
+            "owner": curve.owner.to_string(),
+            "x0": curve.x0,
+            "x0_negative": curve.x0_negative != 0,
+            "x_step": curve.x_step,
+            "y_count": curve.y_count,
+            "decimals": curve.x_decimals,
+            "y_decimals": curve.y_decimals,
+            "y": &curve.y[..curve.y_count as usize],
+            "asset_mint": curve.asset().map(|(mint, _)| mint.to_string()),
+            "asset_kind": curve.asset_kind,
+            "series_count": curve.series_count,
+            "has_confidence": curve.has_confidence != 0,
+            "y_signed": curve.y_signed != 0,
+            "is_frozen": curve.is_frozen != 0,
+            "status": curve.status().to_string(),
+            "deletion_slot": curve.deletion_slot(),
+            "alter_delay": curve.alter_delay(),
+            "delegates": curve
+                .delegates()
+                .map(|delegate| delegate.to_string())
+                .collect::<Vec<_>>(),
+            "owner_threshold": curve.owner_threshold,
+            "extensions_len": curve.extensions_len(),
+            "y_transform": curve.y_transform().to_string(),
+            "valid_until": curve.valid_until(),
+            "validity_period": curve.validity_period(),
+            "last_update_slot": curve.last_update_slot(),
+            "last_update_unix": curve.last_update_unix(),
+            "revision": curve.revision(),
+            "tail_y_count": curve.tail_y_count,
+            "pending_owner": curve.pending_owner().map(|owner| owner.to_string()),
+        },
+    }))
+}
+
+/// Adds `fixture` to the fixtures file `out`, replacing the fixture of the same account
+pub fn record_fixture(out: &Path, fixture: Value) -> Result<()> {
+    let mut fixtures: Vec<Value> = match std::fs::read_to_string(out) {
+        Ok(content) => serde_json::from_str(&content)?,
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => vec![],
+        Err(err) => return Err(err.into()),
+    };
+
+    match fixtures
+        .iter_mut()
+        .find(|existing| !fixture["address"].is_null() && existing["address"] == fixture["address"])
+    {
+        Some(existing) => *existing = fixture,
+        None => fixtures.push(fixture),
+    }
+
+    std::fs::write(out, serde_json::to_string_pretty(&fixtures)? + "\n")?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn recorded_fields_match_fixtures() {
+        let fixtures: Vec<Value> =
+            serde_json::from_str(include_str!("../../program/tests/account_fixtures.json"))
+                .unwrap();
+
+        for fixture in fixtures {
+            let data = fixture["data"].as_str().unwrap();
+            let data = (0..data.len())
+                .step_by(2)
+                .map(|idx| u8::from_str_radix(&data[idx..idx + 2], 16).unwrap())
+                .collect::<Vec<_>>();
+
+            let recorded = curve_fixture(&Pubkey::default(), &data, "", "").unwrap();
+            assert_eq!(
+                recorded["expected"], fixture["expected"],
+                "{}",
+                fixture["description"]
+            );
+        }
+    }
+}
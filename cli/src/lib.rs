@@ -0,0 +1,16 @@
+//! Curvy CLI commands as a library. Every command is an async function taking parsed arguments
+//! and a [curvy_client::CurvyClient] and returning [CommandOutput], so other services can reuse
+//! the exact command logic without shelling out to the `curvy` binary.
+
+pub mod bootstrap;
+pub mod commands;
+pub mod config;
+pub mod confirm;
+pub mod export;
+pub mod fixture;
+pub mod lint;
+pub mod opts;
+pub mod output;
+pub mod source;
+
+pub use output::CommandOutput;
@@ -0,0 +1,267 @@
+use std::fmt::{Display, Formatter};
+use std::path::{Path, PathBuf};
+
+use anyhow::{bail, Result};
+
+use curvy::state::curve::{Curve, CurveX, CurveY, MAX_Y_CNT};
+use curvy::state::curve_confidence::CurveConfidenceBps;
+use curvy::state::curve_series::MAX_EXTRA_SERIES;
+use curvy_utils::{CurvePack, PACK_EXTENSION};
+
+use crate::commands::CONFIDENCE_COLUMN;
+
+/// Validates curve definition CSV (`x,f_x` columns, optionally followed by additional series
+/// and `confidence` columns) the same way `create-curve` reads it.
+/// Returns list of violations, empty list means the file is fine.
+pub fn lint_file(path: &Path, decimals: u8) -> Result<Vec<String>> {
+    lint_csv(csv::Reader::from_path(path)?, Some(decimals))
+}
+
+/// Validates params of every curve of `.curvypack` file, see [CurvePack]. A pack which can not
+/// be read, e.g. with a hash mismatch, fails as a whole.
+pub fn lint_pack(path: &Path) -> Result<Vec<String>> {
+    let pack = CurvePack::read(path)?;
+
+    Ok(pack
+        .entries
+        .iter()
+        .filter_map(|entry| {
+            Curve::check_params(&entry.params)
+                .err()
+                .map(|err| format!("curve `{}`: {err}", entry.name))
+        })
+        .collect())
+}
+
+/// Same as [lint_file] for any CSV reader. Number of `f_x` decimals is not checked when
+/// `decimals` is unknown.
+pub fn lint_csv<R: std::io::Read>(
+    mut reader: csv::Reader<R>,
+    decimals: Option<u8>,
+) -> Result<Vec<String>> {
+    let mut violations = vec![];
+
+    let headers = reader.headers()?.clone();
+    if headers.iter().take(2).collect::<Vec<_>>() != ["x", "f_x"] {
+        bail!("expected `x,f_x` header, found `{}`", headers.as_slice());
+    }
+    let series_cnt = headers
+        .iter()
+        .skip(2)
+        .filter(|header| *header != CONFIDENCE_COLUMN)
+        .count();
+    if series_cnt > MAX_EXTRA_SERIES {
+        violations.push(format!(
+            "max {MAX_EXTRA_SERIES} additional series allowed, got {series_cnt}"
+        ));
+    }
+
+    if let Some(decimals) = decimals.filter(|decimals| *decimals > 9) {
+        violations.push(format!("decimals must be in range [0, 9], got {decimals}"));
+    }
+
+    let mut points: Vec<(i64, i64)> = vec![];
+
+    for (idx, record) in reader.records().enumerate() {
+        // header is line 1
+        let line = idx + 2;
+        let record = record?;
+
+        let (Some(x), Some(f_x)) = (record.get(0), record.get(1)) else {
+            violations.push(format!("line {line}: expected 2 columns"));
+            continue;
+        };
+
+        let x = match x.trim().parse::<i64>() {
+            Ok(x) => x,
+            Err(err) => {
+                violations.push(format!("line {line}: x `{x}`: {err}"));
+                continue;
+            }
+        };
+
+        let Some(y) = lint_y(&mut violations, line, "f_x", f_x, decimals) else {
+            continue;
+        };
+        for (column, value) in headers.iter().zip(record.iter()).skip(2) {
+            if column == CONFIDENCE_COLUMN {
+                if let Err(err) = value.trim().parse::<CurveConfidenceBps>() {
+                    violations.push(format!(
+                        "line {line}: {column} `{value}` must be whole basis points: {err}"
+                    ));
+                }
+                continue;
+            }
+            lint_y(&mut violations, line, column, value, decimals);
+        }
+
+        points.push((x, y));
+    }
+
+    if points.len() < 2 {
+        violations.push(format!("at least 2 points required, got {}", points.len()));
+        return Ok(violations);
+    }
+
+    if points.len() > MAX_Y_CNT {
+        violations.push(format!(
+            "max {MAX_Y_CNT} points allowed, got {}",
+            points.len()
+        ));
+    }
+
+    // the curve keeps `x0` as absolute value and its sign
+    let x0 = points[0].0;
+    if CurveX::try_from(x0.unsigned_abs()).is_err() {
+        violations.push(format!("x0 {x0} is out of X range"));
+    }
+
+    let x_step = points[1].0.saturating_sub(points[0].0);
+    if x_step <= 0 {
+        violations.push("x values must be strictly increasing".to_string());
+    }
+
+    for pair in points.windows(2) {
+        let [(x_prev, y_prev), (x, y)] = [pair[0], pair[1]];
+
+        if x.checked_sub(x_prev) != Some(x_step) {
+            violations.push(format!(
+                "x step between {x_prev} and {x} differs from first step {x_step}"
+            ));
+        }
+
+        if y < y_prev {
+            violations.push(format!(
+                "f_x is not monotonic: decreases from {y_prev} to {y} at x={x}"
+            ));
+        }
+    }
+
+    // signed Y is kept as `i32`
+    if points.iter().any(|(_, y)| *y < 0) {
+        if let Some((x, y)) = points.iter().find(|(_, y)| *y > i32::MAX as i64) {
+            violations.push(format!(
+                "f_x {y} at x={x} does not fit signed Y of the curve with negative values"
+            ));
+        }
+    }
+
+    Ok(violations)
+}
+
+/// Checks decimals of Y `value` in `column` and parses it. Negative values are allowed as long
+/// as they fit `i32`, the curve then gets signed Y.
+fn lint_y(
+    violations: &mut Vec<String>,
+    line: usize,
+    column: &str,
+    value: &str,
+    decimals: Option<u8>,
+) -> Option<i64> {
+    let value = value.trim();
+    let fraction_digits = value
+        .split_once('.')
+        .map_or(0, |(_, fraction)| fraction.len());
+    if let Some(decimals) = decimals.filter(|decimals| fraction_digits != *decimals as usize) {
+        violations.push(format!(
+            "line {line}: {column} `{value}` has {fraction_digits} decimals, expected {decimals}"
+        ));
+    }
+
+    match value.replace('.', "").parse::<i64>() {
+        Ok(y) if y < i32::MIN as i64 || y > CurveY::MAX as i64 => {
+            violations.push(format!("line {line}: {column} `{value}` is out of Y range"));
+            None
+        }
+        Ok(y) => Some(y),
+        Err(err) => {
+            violations.push(format!("line {line}: {column} `{value}`: {err}"));
+            None
+        }
+    }
+}
+
+/// Lint results of all files matching a glob pattern
+#[derive(Debug, serde::Serialize)]
+pub struct LintReport {
+    pub pattern: String,
+    pub files: Vec<LintedFile>,
+}
+
+/// Violations found in a single file
+#[serde_with::skip_serializing_none]
+#[derive(Debug, serde::Serialize)]
+pub struct LintedFile {
+    pub path: PathBuf,
+    pub violations: Vec<String>,
+    /// Error which prevented checking the file
+    pub error: Option<String>,
+}
+
+impl LintedFile {
+    pub fn is_ok(&self) -> bool {
+        self.error.is_none() && self.violations.is_empty()
+    }
+}
+
+impl LintReport {
+    pub fn is_ok(&self) -> bool {
+        self.files.iter().all(LintedFile::is_ok)
+    }
+}
+
+impl Display for LintReport {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if self.files.is_empty() {
+            return write!(f, "no files match `{}`", self.pattern);
+        }
+
+        let lines = self.files.iter().flat_map(|file| {
+            let path = file.path.display();
+            match &file.error {
+                Some(err) => vec![format!("{path}: {err}")],
+                None if file.violations.is_empty() => vec![format!("{path}: ok")],
+                None => file
+                    .violations
+                    .iter()
+                    .map(|violation| format!("{path}: {violation}"))
+                    .collect(),
+            }
+        });
+
+        write!(f, "{}", lines.collect::<Vec<_>>().join("\n"))
+    }
+}
+
+/// Lints all files matching glob `pattern`, see [lint_file]
+pub fn lint_files(pattern: &str, decimals: u8) -> Result<LintReport> {
+    let mut files = vec![];
+
+    for path in glob::glob(pattern)? {
+        let path = path?;
+        let is_pack = path.extension().is_some_and(|ext| ext == PACK_EXTENSION);
+        let result = if is_pack {
+            lint_pack(&path)
+        } else {
+            lint_file(&path, decimals)
+        };
+        let file = match result {
+            Ok(violations) => LintedFile {
+                path,
+                violations,
+                error: None,
+            },
+            Err(err) => LintedFile {
+                path,
+                violations: vec![],
+                error: Some(err.to_string()),
+            },
+        };
+        files.push(file);
+    }
+
+    Ok(LintReport {
+        pattern: pattern.to_string(),
+        files,
+    })
+}
@@ -1,218 +1,171 @@
-use anyhow::anyhow;
-use serde::de::Error;
-use serde::{Deserialize, Deserializer};
+use std::io::IsTerminal;
+use std::sync::Arc;
+
+use anyhow::{anyhow, Result};
 use solana_client::nonblocking::rpc_client::RpcClient;
+use solana_client::rpc_config::RpcSendTransactionConfig;
 use solana_sdk::commitment_config::CommitmentConfig;
 use solana_sdk::signature::read_keypair_file;
 use structopt::StructOpt;
-use texture_common::_export::Zeroable;
-use texture_common::math::Decimal;
-
-use curvy::state::curve::{Curve, MAX_Y_CNT};
-use curvy::state::curve::{CurveParams, CurveX, CurveY};
-use curvy_client::CurvyClient as App;
-use curvy_utils::calc_y;
 
-mod opts;
-
-#[derive(serde::Deserialize)]
-struct Row {
-    x: CurveX,
-    #[serde(deserialize_with = "curve_y_from_string")]
-    f_x: CurveY,
-}
-
-fn curve_y_from_string<'de, D>(deserializer: D) -> anyhow::Result<CurveY, D::Error>
-where
-    D: Deserializer<'de>,
-{
-    let s: &str = Deserialize::deserialize(deserializer)?;
-    s.replace('.', "")
-        .parse::<CurveY>()
-        .map_err(D::Error::custom)
-}
+use curvy_cli::confirm::ConfirmOptions;
+use curvy_cli::opts::{self, Command, Opts};
+use curvy_cli::{commands, config, CommandOutput};
+use curvy_client::{
+    AlterGuard, ApprovalHook, Confirmation, CurvyClient as App, Notifier, NotifyTarget, ReadBudget,
+    ReadLimiter, WebhookApproval,
+};
 
 #[tokio::main]
 async fn main() {
     let _tracing_appender_guard = tracing_init();
 
-    let opts = opts::Opts::from_args();
+    let opts = Opts::from_args();
 
-    let keypair = read_keypair_file(opts.authority.0)
-        .map_err(|err| anyhow!("reading authority keypair: {}", err))
-        .unwrap();
-    let rpc = RpcClient::new_with_commitment(
-        opts.url.clone(),
-        CommitmentConfig {
-            commitment: opts.commitment,
-        },
-    );
-
-    let app = App {
-        rpc,
-        authority: keypair,
-        priority_fee: opts.priority_fee,
+    let output = match run(&opts).await {
+        Ok(output) => output,
+        Err(err) => {
+            eprintln!("Error: {err:?}");
+            std::process::exit(1);
+        }
     };
 
-    match opts.cmd {
-        opts::Command::CreateCurve {
-            name,
-            formula,
-            decimals,
-            csv,
-        } => {
-            let points_list = csv::Reader::from_path(csv)
-                .expect("read csv file")
-                .records()
-                .map(|record| {
-                    let row = record
-                        .expect("parse csv file")
-                        .deserialize::<Row>(None)
-                        .expect("deserialize csv row");
-                    (row.x as CurveX, row.f_x as CurveY)
-                })
-                .collect::<Vec<_>>();
-
-            let mut y_values: [CurveY; MAX_Y_CNT] = Zeroable::zeroed();
-
-            for (i, (_x, y)) in points_list.iter().enumerate() {
-                if i >= MAX_Y_CNT {
-                    println!("Error: max {} points allowed", MAX_Y_CNT);
-                }
-                y_values[i] = *y;
+    if let Some(change) = output.change() {
+        let targets = opts
+            .notify_slack
+            .iter()
+            .cloned()
+            .map(NotifyTarget::Slack)
+            .chain(
+                opts.notify_webhook
+                    .iter()
+                    .cloned()
+                    .map(NotifyTarget::Webhook),
+            )
+            .collect::<Vec<_>>();
+
+        if !targets.is_empty() {
+            let mut notifier = Notifier::new(targets);
+            if let Some(template) = &opts.notify_template {
+                notifier.template = template.clone();
             }
-
-            let params = CurveParams::new(
-                &name,
-                &formula,
-                points_list[0].0,
-                points_list[1].0 - points_list[0].0,
-                points_list.len() as u8,
-                decimals,
-                y_values,
-            );
-            let created_curve = app
-                .create_curve(params, app.priority_fee)
-                .await
-                .expect("create curve");
-            println_cmd_out!(&created_curve);
-        }
-        opts::Command::AlterCurve {
-            curve,
-            name,
-            formula,
-            decimals,
-            csv,
-        } => {
-            let (x0, x_step, y_count, y) = if let Some(csv) = csv {
-                let points_list = csv::Reader::from_path(csv)
-                    .expect("read csv file")
-                    .records()
-                    .map(|record| {
-                        let row = record
-                            .expect("parse csv file")
-                            .deserialize::<Row>(None)
-                            .expect("deserialize csv row");
-                        (row.x as CurveX, row.f_x as CurveY)
-                    })
-                    .collect::<Vec<_>>();
-
-                let mut y_values: [CurveY; MAX_Y_CNT] = Zeroable::zeroed();
-
-                for (i, (_x, y)) in points_list.iter().enumerate() {
-                    if i >= MAX_Y_CNT {
-                        println!("Error: max {} points allowed", MAX_Y_CNT);
-                    }
-                    y_values[i] = *y;
-                }
-
-                (
-                    Some(points_list[0].0),
-                    Some(points_list[1].0 - points_list[0].0),
-                    Some(points_list.len() as u8),
-                    Some(y_values),
-                )
-            } else {
-                (None, None, None, None)
-            };
-
-            let signature = app
-                .alter_curve(
-                    curve,
-                    name,
-                    formula,
-                    decimals,
-                    x0,
-                    x_step,
-                    y_count,
-                    y,
-                    app.priority_fee,
-                )
-                .await
-                .expect("alter curve");
-
-            println!("{:#?}", signature);
-            println!("altered curve: {}", curve);
-        }
-        opts::Command::DeleteCurve { curve } => {
-            let signature = app
-                .delete_curve(curve, app.priority_fee)
-                .await
-                .expect("delete curve");
-
-            println!("{:#?}", signature);
-            println!("deleted curve: {}", curve);
-        }
-        opts::Command::Curve { curve } => {
-            let curve = app.curve(&curve).await.expect("get curve");
-            println!("{}", curve);
-        }
-        opts::Command::Curves => {
-            let curves = app.curves().await.expect("get curves");
-
-            for curve in curves.curves {
-                println!("{}", curve);
-
-                print_x_y(&curve.curve);
-
-                println!("======================================");
+            // the change is already made, failed alert must not look like a failed command
+            if let Err(err) = notifier.notify(&change).await {
+                eprintln!("Warning: notification failed: {err:#}");
             }
         }
-        opts::Command::CalcY { curve, x } => {
-            let curve = app.curve(&curve).await.expect("get curve");
-
-            let decimal_x =
-                Decimal::from_i128_with_scale((x * 1_000_000_000.0) as i128, 9).unwrap();
+    }
 
-            let y = calc_y(decimal_x, &curve.curve)
-                .map_err(|err| println!("error: {}", err))
-                .unwrap();
+    match opts.output {
+        opts::OutputFormat::Text => {
+            println!("{output}");
 
-            println!("y = {}", y);
+            // keep stdout clean for the snapshot itself
+            if let CommandOutput::Snapshot {
+                diff: Some(diff), ..
+            } = &output
+            {
+                eprintln!("{diff}");
+            }
         }
+        opts::OutputFormat::Json => match output.to_json() {
+            Ok(json) => println!("{json}"),
+            Err(err) => {
+                eprintln!("Error: {err:?}");
+                std::process::exit(1);
+            }
+        },
     }
+
+    std::process::exit(output.exit_code());
 }
 
-pub fn print_x_y(curve: &Curve) {
-    println!("  X  :  f(x)");
-    let mut x = curve.x0;
-    for idx in 0..curve.y_count {
-        println!(
-            "  {}  :  {}",
-            x as f32 / 10_u32.pow(curve.decimals as u32) as f32,
-            curve.y[idx as usize] as f32 / 10_u32.pow(curve.decimals as u32) as f32
-        );
-        x += curve.x_step;
+async fn run(opts: &Opts) -> Result<CommandOutput> {
+    // offline commands, must not require keypair or RPC
+    match &opts.cmd {
+        Command::LintFiles(args) => return commands::run_lint_files(args),
+        Command::Fit(args) => return commands::run_fit(args),
+        Command::DecodeAccount(args) => return commands::run_decode_account(args),
+        Command::Errors(args) => return commands::run_errors(args),
+        _ => {}
     }
+
+    let app = app(opts)?;
+
+    let config = config::Config::load(&opts.config.0)?;
+    let confirm = ConfirmOptions::new(opts.yes, &config)?;
+
+    commands::run(&opts.cmd, &app, &confirm).await
 }
 
-macro_rules! println_cmd_out {
-    ($out:expr) => {{
-        let out = serde_json::to_string_pretty($out).expect("json");
-        println!("{out}");
-    }};
+fn app(opts: &Opts) -> Result<App> {
+    let keypair = read_keypair_file(&opts.authority.0)
+        .map_err(|err| anyhow!("reading authority keypair: {}", err))?;
+    let co_signers = opts
+        .co_signer
+        .iter()
+        .map(|path| {
+            read_keypair_file(&path.0).map_err(|err| anyhow!("reading co-signer keypair: {}", err))
+        })
+        .collect::<Result<Vec<_>>>()?;
+    let url = match &opts.cmd {
+        Command::Bootstrap(args) => args.cluster.url().to_string(),
+        _ => opts.url.clone(),
+    };
+    let rpc = RpcClient::new_with_commitment(
+        url,
+        CommitmentConfig {
+            commitment: opts.commitment,
+        },
+    );
+
+    Ok(App {
+        rpc,
+        authority: keypair,
+        priority_fee: opts.priority_fee,
+        send_config: RpcSendTransactionConfig {
+            skip_preflight: opts.skip_preflight,
+            preflight_commitment: opts.preflight_commitment,
+            max_retries: opts.max_retries,
+            ..Default::default()
+        },
+        confirmation: match opts.confirmation {
+            opts::ConfirmationMode::Spinner => Confirmation::Spinner,
+            opts::ConfirmationMode::Auto if std::io::stderr().is_terminal() => {
+                Confirmation::Spinner
+            }
+            opts::ConfirmationMode::Auto | opts::ConfirmationMode::Polling => {
+                Confirmation::Polling {
+                    interval: opts.poll_interval.into(),
+                    timeout: opts.confirmation_timeout.into(),
+                }
+            }
+        },
+        approval: opts
+            .approval_webhook
+            .clone()
+            .map(|url| Arc::new(WebhookApproval::new(url)) as Arc<dyn ApprovalHook>),
+        alter_guard: opts
+            .max_alters
+            .map(|max_alters| {
+                let mut guard = AlterGuard::persisted(
+                    max_alters,
+                    opts.alter_window.into(),
+                    opts.alter_history.0.clone(),
+                )?;
+                guard.enforce = true;
+                Ok::<_, anyhow::Error>(guard)
+            })
+            .transpose()?,
+        co_signers,
+        read_limiter: Some(ReadLimiter::new(opts.max_reads.map(|requests| {
+            ReadBudget {
+                requests,
+                window: opts.read_window.into(),
+            }
+        }))),
+    })
 }
-pub(crate) use println_cmd_out;
 
 fn tracing_init() -> tracing_appender::non_blocking::WorkerGuard {
     use tracing_subscriber::filter::LevelFilter;
@@ -1,9 +1,16 @@
+use std::fmt;
 use std::path::PathBuf;
 
 use derive_more::FromStr;
 use solana_sdk::{commitment_config::CommitmentLevel, pubkey::Pubkey};
 use structopt::StructOpt;
 
+use curvy::state::curve::{CurveStatus, YTransform};
+use curvy::state::curve_extension::ExtensionType;
+use curvy_utils::SmoothMethod;
+
+use crate::export::ExportFormat;
+
 #[derive(StructOpt)]
 #[structopt(rename_all = "kebab-case")]
 pub struct Opts {
@@ -23,10 +30,90 @@ pub struct Opts {
     #[structopt(long, short = "k", default_value)]
     pub authority: KeypairPath,
 
+    /// Keypair of another owner of co-owned curve signing together with the authority, see
+    /// set-curve-owners. Repeat for more owners.
+    #[structopt(long)]
+    pub co_signer: Vec<KeypairPath>,
+
+    /// Skip interactive confirmation of destructive commands (protected curves still require
+    /// typing the curve name).
+    #[structopt(long, short = "y")]
+    pub yes: bool,
+
+    /// Approval service URL. Every mutating operation is posted there and sent only when approved.
+    #[structopt(long)]
+    pub approval_webhook: Option<String>,
+
+    /// Path to the CLI config file (JSON).
+    #[structopt(long, default_value)]
+    pub config: ConfigPath,
+
     /// Priority fee in microlamports. For priority_rate=1 you pay 0.2 (1) priority lamports for one ix, for 10_000 - 2_000.
     #[structopt(long)]
     pub priority_fee: Option<u64>,
 
+    /// Send transactions without preflight simulation
+    #[structopt(long)]
+    pub skip_preflight: bool,
+
+    /// Maximum number of times RPC node retries sending a transaction to the leader
+    #[structopt(long)]
+    pub max_retries: Option<usize>,
+
+    /// Commitment used for preflight simulation (defaults to the node setting)
+    #[structopt(long)]
+    pub preflight_commitment: Option<CommitmentLevel>,
+
+    /// Transaction confirmation mode: auto (spinner in terminal, polling otherwise), spinner or
+    /// polling.
+    #[structopt(long, default_value = "auto")]
+    pub confirmation: ConfirmationMode,
+
+    /// Signature status polling interval when confirming by polling
+    #[structopt(long, default_value = "500ms")]
+    pub poll_interval: humantime::Duration,
+
+    /// Give up waiting for confirmation after this time when confirming by polling
+    #[structopt(long, default_value = "90s")]
+    pub confirmation_timeout: humantime::Duration,
+
+    /// Refuse to alter a curve which was altered this many times within `--alter-window` by
+    /// this machine (`--force` overrides)
+    #[structopt(long)]
+    pub max_alters: Option<usize>,
+
+    #[structopt(long, default_value = "10m")]
+    pub alter_window: humantime::Duration,
+
+    /// Alter history file used by `--max-alters`
+    #[structopt(long, default_value)]
+    pub alter_history: AlterHistoryPath,
+
+    /// Send at most this many account reads within `--read-window`, later reads wait.
+    /// Concurrent reads of the same account are always sent once.
+    #[structopt(long)]
+    pub max_reads: Option<usize>,
+
+    #[structopt(long, default_value = "1s")]
+    pub read_window: humantime::Duration,
+
+    /// Post curve changes made by the command to this Slack incoming webhook. May be repeated.
+    #[structopt(long)]
+    pub notify_slack: Vec<String>,
+
+    /// Post curve changes made by the command as JSON to this webhook. May be repeated.
+    #[structopt(long)]
+    pub notify_webhook: Vec<String>,
+
+    /// Notification message template, placeholders: {action}, {name}, {curve}, {diff},
+    /// {signature}, {link}
+    #[structopt(long)]
+    pub notify_template: Option<String>,
+
+    /// Output format: text or json
+    #[structopt(long, short, default_value = "text")]
+    pub output: OutputFormat,
+
     #[structopt(subcommand)]
     pub cmd: Command,
 }
@@ -35,59 +122,983 @@ pub struct Opts {
 #[structopt(rename_all = "kebab-case")]
 pub enum Command {
     /// Creates Curve account. Requires ADMIN privileges.
-    CreateCurve {
-        /// Curve name
-        #[structopt(long)]
-        name: String,
-        /// Human-readable formula
-        #[structopt(long)]
-        formula: String,
-        #[structopt(long, default_value = "6")]
-        decimals: u8,
-        /// Source file (data in CSV)
-        #[structopt(long, parse(from_os_str))]
-        csv: PathBuf,
-    },
+    CreateCurve(CreateCurveArgs),
+    /// Creates Curve account with name and formula only, so its address can be shared before
+    /// the data is ready. Fill it later with alter-curve.
+    ReserveCurve(ReserveCurveArgs),
     /// Alters Curve account
-    AlterCurve {
-        /// Curve account
-        #[structopt(long)]
-        curve: Pubkey,
-        /// Curve name
-        #[structopt(long)]
-        name: Option<String>,
-        /// Human-readable formula
-        #[structopt(long)]
-        formula: Option<String>,
-        #[structopt(long)]
-        decimals: Option<u8>,
-        /// Source file (data in CSV)
-        #[structopt(long, parse(from_os_str))]
-        csv: Option<PathBuf>,
-    },
+    AlterCurve(AlterCurveArgs),
+    /// Creates WideCurve account, a curve with u64 `y` values (up to 65 points) for values not
+    /// fitting in u32, e.g. liquidity depth
+    CreateWideCurve(CreateWideCurveArgs),
+    /// Alters WideCurve account
+    AlterWideCurve(AlterWideCurveArgs),
+    /// Creates GridCurve account, a curve sampled in explicit increasing `x` points (up to 65
+    /// points) instead of uniform steps
+    CreateGridCurve(CreateGridCurveArgs),
+    /// Alters GridCurve account
+    AlterGridCurve(AlterGridCurveArgs),
     /// Deletes Curve account
-    DeleteCurve {
-        /// Curve account
-        #[structopt(long)]
-        curve: Pubkey,
-    },
+    DeleteCurve(DeleteArgs),
+    /// Transfers lamports missing for rent exemption of Curve account from the authority
+    TopUpRent(CurveArgs),
+    /// Associates Curve with SPL mint, at most one curve of the owner per mint and kind
+    SetAsset(SetAssetArgs),
+    /// Removes association of Curve with SPL mint
+    ClearAsset(DeleteCurveArgs),
+    /// Freezes Curve, so its samples, metadata, series and confidence can never be changed and
+    /// it can not be deleted. Irreversible.
+    FreezeCurve(DeleteCurveArgs),
+    /// Moves Curve to another lifecycle stage: draft -> active -> deprecated -> retired.
+    /// Deprecated curves may be activated again, only draft and retired ones can be deleted.
+    /// Active and deprecated curves may be paused to take them out of service, e.g. during an
+    /// incident, and resumed to either stage.
+    SetCurveStatus(SetCurveStatusArgs),
+    /// Marks Curve for deletion, delete-curve accepts it only after a grace period giving
+    /// consumers time to migrate. Curves which are not marked can be deleted at once.
+    MarkForDeletion(DeleteCurveArgs),
+    /// Removes the mark set by mark-for-deletion
+    CancelDeletion(DeleteCurveArgs),
+    /// Timelocks Curve: its samples change only by propose-alter and apply-alter the given
+    /// number of slots later, giving consumers time to react to a compromised authority. The
+    /// delay can only grow.
+    SetAlterDelay(SetAlterDelayArgs),
+    /// Sets the unix timestamp Curve is stale from, on-chain evaluation fails with CurveExpired
+    /// from then on. With a validity period every sample update moves it that far ahead.
+    SetCurveExpiry(SetCurveExpiryArgs),
+    /// Proposes new params of timelocked Curve (same arguments as alter-curve), replacing the
+    /// pending proposal
+    ProposeAlter(AlterCurveArgs),
+    /// Sets params proposed by propose-alter once the alter delay of Curve passed
+    ApplyAlter(DeleteCurveArgs),
+    /// Drops params proposed by propose-alter
+    CancelAlter(DeleteCurveArgs),
+    /// Allows another key, e.g. a hot key of a rate updater, to update samples of Curve by
+    /// alter-curve and patch-y. Only the owner changes other params, manages, transfers or
+    /// deletes the curve.
+    AddDelegate(DelegateArgs),
+    /// Revokes a key added by add-delegate
+    RemoveDelegate(DelegateArgs),
+    /// Makes Curve co-owned: the threshold of the listed owners, the curve owner among them,
+    /// must sign alter-curve and set-curve-owners, other owner commands are refused. Pass
+    /// other owners by --co-signer. Threshold 0 without owners makes the curve single-owned.
+    SetCurveOwners(SetCurveOwnersArgs),
+    /// Sets an extension of Curve: tags, guardrails or schedule. Extensions are kept at the end
+    /// of the account, the authority pays for the grown account.
+    SetCurveExtension(SetCurveExtensionArgs),
+    /// Creates the program config, after it only the listed creators can create curves. The
+    /// authority must be the upgrade authority of the program and becomes the config admin.
+    InitConfig(InitConfigArgs),
+    /// Replaces admin and creator allow-list of the program config
+    SetConfig(SetConfigArgs),
+    /// Freezes creation of curves for a maintenance window, existing curves are managed as
+    /// usual
+    SetCreationFrozen(SetCreationFrozenArgs),
+    /// Get the program config
+    Config,
+    /// Prepares the authority for development on devnet or a local validator: airdrops SOL
+    /// when its balance is low, creates a sample set of borrow rate curves (keeping ones
+    /// created before) and prints a config snippet for downstream services. `--cluster`
+    /// replaces `--url`.
+    Bootstrap(BootstrapArgs),
+    /// Overwrites a few `y` samples of Curve starting at the given index, e.g. to correct
+    /// points after the kink without sending the whole table
+    PatchY(PatchYArgs),
+    /// Proposes new owner of Curve, ownership changes once the new owner runs accept-owner
+    ProposeOwner(ProposeOwnerArgs),
+    /// Takes over Curve proposed to the authority by propose-owner
+    AcceptOwner(AcceptOwnerArgs),
+    /// Sets additional `y` series of Curve (e.g. lower and upper bands) from CSV columns after
+    /// `f_x`. X grid must match the curve.
+    SetSeries(SetSeriesArgs),
+    /// Removes all additional `y` series of Curve
+    DeleteSeries(DeleteCurveArgs),
+    /// Removes per-sample confidence of Curve. Set it with the `confidence` CSV column of
+    /// create-curve or alter-curve.
+    DeleteConfidence(DeleteCurveArgs),
+    /// Finds Curve of the owner associated with SPL mint
+    CurveForMint(CurveForMintArgs),
+    /// Finds Curve the owner created under the name
+    ResolveCurve(ResolveCurveArgs),
+    /// Upgrades Curve account to the current layout version, the authority pays for the grown
+    /// account
+    MigrateCurve(CurveArgs),
     /// Get Curve
-    Curve {
-        /// Curve account
-        #[structopt(long)]
-        curve: Pubkey,
-    },
+    Curve(CurveArgs),
     /// Get all Curves
-    Curves,
+    Curves(CurvesArgs),
+    /// Write all curves with their points to a Parquet or SQLite file for analytics
+    ExportAll(ExportAllArgs),
+    /// Write curves of the owner to a `.curvypack` file, the whole curve set of an environment
+    /// as one artifact
+    Pack(PackArgs),
+    /// Create or alter curves of the authority to match a `.curvypack` file. Curves are found
+    /// by name in the name registry, see resolve-curve.
+    Restore(RestoreArgs),
+    /// Compare curves with an expected state document (curves with their owners, params
+    /// hashes and tags) and print the actions converging them, optionally saved for apply.
+    /// Exits with non-zero code when curves differ from the expected state.
+    Plan(PlanArgs),
+    /// Execute the actions of a plan file saved by plan --out, confirming each one. Actions of
+    /// curves changed since the plan was made fail.
+    Apply(ApplyArgs),
+    /// Save all curves to JSON snapshot and optionally compare with a previous one.
+    /// Exits with non-zero code when curves changed since the previous snapshot.
+    Snapshot(SnapshotArgs),
+    /// Summarize curves by owner: number of curves, rent locked and last update time
+    Owners,
+    /// Append raw data of a Curve account with its decoded fields to compatibility fixtures
+    /// replayed by program tests, see `program/tests/account_fixtures.json`
+    RecordFixture(RecordFixtureArgs),
+    /// Decode and print Curve account data without RPC round trip
+    DecodeAccount(DecodeAccountArgs),
+    /// Compress dense samples into curve CSV choosing `x0`, `x_step` and number of points
+    /// automatically, without RPC
+    Fit(FitArgs),
+    /// Repair spikes of noisy curve CSV by a moving average or median, reporting samples changed
+    /// beyond a threshold, without RPC
+    Smooth(SmoothArgs),
+    /// Validate curve CSV and `.curvypack` files without RPC. Exits with non-zero code on violations, suitable
+    /// for a git pre-commit hook.
+    LintFiles(LintFilesArgs),
     /// Calculate and print Y value for given X on given curve
-    CalcY {
-        /// Curve account
-        #[structopt(long)]
-        curve: Pubkey,
-        /// X coordinate
-        #[structopt(long)]
-        x: f64,
-    },
+    CalcY(CalcYArgs),
+    /// Evaluate Curve at given X on chain and store the result in an account readable by other
+    /// programs. The account is created on the first evaluation.
+    EvaluateCurve(EvaluateCurveArgs),
+    /// Calculate time-weighted average of the curve over the window ending now, rebuilt from
+    /// the curve transactions
+    Twa(TwaArgs),
+    /// Simulate altering Curve with its current params and print consumed compute units and
+    /// how many such alters fit in one transaction
+    Profile(CurveArgs),
+    /// Describe the curve, or its change to proposed samples, in sentences for proposal
+    /// reviewers, e.g. `Borrow APR at 80% utilization changes from 6.2% to 9.5%`
+    Summarize(SummarizeArgs),
+    /// Compare evaluations of a consumer program embedding its own evaluator with ours in
+    /// sampled points, simulating its instruction. Exits with non-zero code on divergences.
+    CheckConsumer(CheckConsumerArgs),
+    /// Print custom error codes of the program, e.g. to find out what `custom program error:
+    /// 0x1d` means
+    Errors(ErrorsArgs),
+}
+
+#[derive(StructOpt)]
+#[structopt(rename_all = "kebab-case")]
+pub struct CreateCurveArgs {
+    /// Curve name
+    #[structopt(long)]
+    pub name: String,
+    /// Human-readable formula
+    #[structopt(long)]
+    pub formula: String,
+    /// Decimals of `x`, and of `f_x` unless `--y-decimals` is given
+    #[structopt(long, default_value = "6")]
+    pub decimals: u8,
+    /// Decimals of `f_x`
+    #[structopt(long)]
+    pub y_decimals: Option<u8>,
+    /// Store `f_x` as `linear` values or `log10` of them, for curves spanning several orders of
+    /// magnitude. CSV holds the values either way, `log10` requires them to be positive.
+    #[structopt(long, default_value = "linear")]
+    pub y_transform: YTransform,
+    /// Comma-separated labels classifying the curve, e.g. `borrow-rate,usdc`
+    #[structopt(long)]
+    pub tags: Option<String>,
+    #[structopt(flatten)]
+    pub source: CsvSource,
+    /// Keypair of the curve account to create. Fresh keypair is generated when omitted.
+    #[structopt(long, conflicts_with = "derived")]
+    pub curve_keypair: Option<KeypairPath>,
+    /// Create the curve at the address derived from the authority and the curve name, so
+    /// consumers can compute it
+    #[structopt(long)]
+    pub derived: bool,
+    /// Original dense samples the curve was fitted or resampled from (CSV with `x,f_x`
+    /// columns, any number of rows and decimals, `x` in the same units as the curve CSV).
+    /// Interpolation error of the curve against them is reported.
+    #[structopt(long, parse(from_os_str))]
+    pub reference: Option<PathBuf>,
+    /// Refuse to create the curve when max interpolation error against `--reference` exceeds
+    /// this value
+    #[structopt(long, requires = "reference")]
+    pub max_error: Option<rust_decimal::Decimal>,
+    /// Timelock the created curve, see set-alter-delay
+    #[structopt(long)]
+    pub alter_delay: Option<u64>,
+}
+
+#[derive(StructOpt)]
+#[structopt(rename_all = "kebab-case")]
+pub struct ReserveCurveArgs {
+    /// Curve name
+    #[structopt(long)]
+    pub name: String,
+    /// Human-readable formula
+    #[structopt(long)]
+    pub formula: String,
+    /// Keypair of the curve account to create. Fresh keypair is generated when omitted.
+    #[structopt(long)]
+    pub curve_keypair: Option<KeypairPath>,
+}
+
+#[derive(StructOpt)]
+#[structopt(rename_all = "kebab-case")]
+pub struct AlterCurveArgs {
+    /// Curve account
+    #[structopt(long)]
+    pub curve: Pubkey,
+    /// Curve name
+    #[structopt(long)]
+    pub name: Option<String>,
+    /// Human-readable formula
+    #[structopt(long)]
+    pub formula: Option<String>,
+    /// Decimals of `x`, and of `f_x` unless `--y-decimals` is given
+    #[structopt(long)]
+    pub decimals: Option<u8>,
+    /// Decimals of `f_x`
+    #[structopt(long)]
+    pub y_decimals: Option<u8>,
+    /// Change how `f_x` is stored: `linear` or `log10`, see create-curve. CSV values are
+    /// stored with the current transform unless given.
+    #[structopt(long)]
+    pub y_transform: Option<YTransform>,
+    /// Replace the curve tags, see create-curve. Empty value removes them.
+    #[structopt(long)]
+    pub tags: Option<String>,
+    #[structopt(flatten)]
+    pub source: CsvSource,
+    /// Revision the changes are based on (see `curve`). Fails when the curve was altered
+    /// since.
+    #[structopt(long)]
+    pub expected_revision: Option<u64>,
+    /// Send the transaction even if the curve is not owned by the authority
+    #[structopt(long)]
+    pub force: bool,
+}
+
+#[derive(StructOpt)]
+#[structopt(rename_all = "kebab-case")]
+pub struct CreateWideCurveArgs {
+    /// Curve name
+    #[structopt(long)]
+    pub name: String,
+    /// Human-readable formula
+    #[structopt(long)]
+    pub formula: String,
+    #[structopt(long, default_value = "6")]
+    pub decimals: u8,
+    /// Source file (data in CSV with `x,f_x` columns)
+    #[structopt(long, parse(from_os_str))]
+    pub csv: PathBuf,
+    /// Keypair of the curve account to create. Fresh keypair is generated when omitted.
+    #[structopt(long)]
+    pub curve_keypair: Option<KeypairPath>,
+}
+
+#[derive(StructOpt)]
+#[structopt(rename_all = "kebab-case")]
+pub struct AlterWideCurveArgs {
+    /// WideCurve account
+    #[structopt(long)]
+    pub curve: Pubkey,
+    /// Curve name
+    #[structopt(long)]
+    pub name: Option<String>,
+    /// Human-readable formula
+    #[structopt(long)]
+    pub formula: Option<String>,
+    #[structopt(long)]
+    pub decimals: Option<u8>,
+    /// Source file (data in CSV with `x,f_x` columns)
+    #[structopt(long, parse(from_os_str))]
+    pub csv: Option<PathBuf>,
+    /// Send the transaction even if the curve is not owned by the authority
+    #[structopt(long)]
+    pub force: bool,
+}
+
+#[derive(StructOpt)]
+#[structopt(rename_all = "kebab-case")]
+pub struct CreateGridCurveArgs {
+    /// Curve name
+    #[structopt(long)]
+    pub name: String,
+    /// Human-readable formula
+    #[structopt(long)]
+    pub formula: String,
+    #[structopt(long, default_value = "6")]
+    pub decimals: u8,
+    /// Source file (data in CSV with `x,f_x` columns, `x` strictly increasing)
+    #[structopt(long, parse(from_os_str))]
+    pub csv: PathBuf,
+    /// Keypair of the curve account to create. Fresh keypair is generated when omitted.
+    #[structopt(long)]
+    pub curve_keypair: Option<KeypairPath>,
+}
+
+#[derive(StructOpt)]
+#[structopt(rename_all = "kebab-case")]
+pub struct AlterGridCurveArgs {
+    /// GridCurve account
+    #[structopt(long)]
+    pub curve: Pubkey,
+    /// Curve name
+    #[structopt(long)]
+    pub name: Option<String>,
+    /// Human-readable formula
+    #[structopt(long)]
+    pub formula: Option<String>,
+    #[structopt(long)]
+    pub decimals: Option<u8>,
+    /// Source file (data in CSV with `x,f_x` columns, `x` strictly increasing)
+    #[structopt(long, parse(from_os_str))]
+    pub csv: Option<PathBuf>,
+    /// Send the transaction even if the curve is not owned by the authority
+    #[structopt(long)]
+    pub force: bool,
+}
+
+/// Curve data in CSV (`x,f_x` columns) from a local file or URL. Optional columns after `f_x`
+/// are additional series and per-sample `confidence` in basis points of `f_x`.
+#[derive(StructOpt)]
+#[structopt(rename_all = "kebab-case")]
+pub struct CsvSource {
+    /// Source file (data in CSV)
+    #[structopt(long, parse(from_os_str))]
+    pub csv: Option<PathBuf>,
+    /// Source URL (data in CSV), e.g. a spreadsheet published as CSV
+    #[structopt(long, conflicts_with = "csv")]
+    pub url: Option<String>,
+    /// Expected SHA-256 of the CSV data (hex). Fails when the data differs.
+    #[structopt(long)]
+    pub sha256: Option<String>,
+}
+
+#[derive(StructOpt)]
+#[structopt(rename_all = "kebab-case")]
+pub struct SetAssetArgs {
+    /// Curve account
+    #[structopt(long)]
+    pub curve: Pubkey,
+    /// SPL Token or Token-2022 mint
+    #[structopt(long)]
+    pub mint: Pubkey,
+    /// Kind of the association, e.g. to tell borrow and supply rate curves of the mint apart
+    #[structopt(long, default_value = "0")]
+    pub kind: u8,
+    /// Send the transaction even if the curve is not owned by the authority
+    #[structopt(long)]
+    pub force: bool,
+}
+
+#[derive(StructOpt)]
+#[structopt(rename_all = "kebab-case")]
+pub struct ProposeOwnerArgs {
+    /// Curve account
+    #[structopt(long)]
+    pub curve: Pubkey,
+    /// Proposed owner. Omit to cancel the pending proposal.
+    #[structopt(long)]
+    pub new_owner: Option<Pubkey>,
+    /// Send the transaction even if the curve is not owned by the authority
+    #[structopt(long)]
+    pub force: bool,
+}
+
+#[derive(StructOpt)]
+#[structopt(rename_all = "kebab-case")]
+pub struct AcceptOwnerArgs {
+    /// Curve account
+    #[structopt(long)]
+    pub curve: Pubkey,
+    /// Send the transaction even if the authority is not the proposed owner
+    #[structopt(long)]
+    pub force: bool,
+}
+
+#[derive(StructOpt)]
+#[structopt(rename_all = "kebab-case")]
+pub struct ResolveCurveArgs {
+    /// Curve name
+    #[structopt(long)]
+    pub name: String,
+    /// Curve owner, the authority by default
+    #[structopt(long)]
+    pub owner: Option<Pubkey>,
+}
+
+#[derive(StructOpt)]
+#[structopt(rename_all = "kebab-case")]
+pub struct CurveForMintArgs {
+    /// SPL mint
+    #[structopt(long)]
+    pub mint: Pubkey,
+    #[structopt(long, default_value = "0")]
+    pub kind: u8,
+    /// Curve owner, the authority by default
+    #[structopt(long)]
+    pub owner: Option<Pubkey>,
+}
+
+#[derive(StructOpt)]
+#[structopt(rename_all = "kebab-case")]
+pub struct DeleteArgs {
+    /// Curve account
+    #[structopt(long)]
+    pub curve: Pubkey,
+    /// Account receiving rent of the curve account, e.g. a treasury. The authority by default.
+    #[structopt(long)]
+    pub rent_destination: Option<Pubkey>,
+    /// Send the transaction even if the curve is not owned by the authority
+    #[structopt(long)]
+    pub force: bool,
+}
+
+#[derive(StructOpt)]
+#[structopt(rename_all = "kebab-case")]
+pub struct DeleteCurveArgs {
+    /// Curve account
+    #[structopt(long)]
+    pub curve: Pubkey,
+    /// Send the transaction even if the curve is not owned by the authority
+    #[structopt(long)]
+    pub force: bool,
+}
+
+#[derive(StructOpt)]
+#[structopt(rename_all = "kebab-case")]
+pub struct SetAlterDelayArgs {
+    /// Curve account
+    #[structopt(long)]
+    pub curve: Pubkey,
+    /// Slots between propose-alter and apply-alter
+    #[structopt(long)]
+    pub slots: u64,
+    /// Send the transaction even if the curve is not owned by the authority or the delay
+    /// decreases
+    #[structopt(long)]
+    pub force: bool,
+}
+
+#[derive(StructOpt)]
+#[structopt(rename_all = "kebab-case")]
+pub struct SetCurveExpiryArgs {
+    /// Curve account
+    #[structopt(long)]
+    pub curve: Pubkey,
+    /// Unix timestamp the curve is stale from. Never expires when omitted.
+    #[structopt(long)]
+    pub valid_until: Option<i64>,
+    /// Seconds alter-curve, update-data and patch-y move the expiry past their time, zero
+    /// keeps it
+    #[structopt(long, default_value = "0")]
+    pub validity_period: u64,
+    /// Send the transaction even if the curve is not owned by the authority
+    #[structopt(long)]
+    pub force: bool,
+}
+
+#[derive(StructOpt)]
+#[structopt(rename_all = "kebab-case")]
+pub struct DelegateArgs {
+    /// Curve account
+    #[structopt(long)]
+    pub curve: Pubkey,
+    /// Delegate key
+    #[structopt(long)]
+    pub delegate: Pubkey,
+    /// Send the transaction even if the curve is not owned by the authority
+    #[structopt(long)]
+    pub force: bool,
+}
+
+#[derive(StructOpt)]
+#[structopt(rename_all = "kebab-case")]
+pub struct InitConfigArgs {
+    /// Key allowed to create curves, repeat for several keys
+    #[structopt(long = "creator")]
+    pub creators: Vec<Pubkey>,
+}
+
+#[derive(StructOpt)]
+#[structopt(rename_all = "kebab-case")]
+pub struct SetConfigArgs {
+    /// New config admin, the current one when omitted
+    #[structopt(long)]
+    pub admin: Option<Pubkey>,
+    /// Key allowed to create curves, repeat for several keys. Creators not listed lose the
+    /// permission, no creators lets nobody create curves.
+    #[structopt(long = "creator")]
+    pub creators: Vec<Pubkey>,
+    /// Send the transaction even if the config is not administered by the authority
+    #[structopt(long)]
+    pub force: bool,
+}
+
+#[derive(StructOpt)]
+#[structopt(rename_all = "kebab-case")]
+pub struct SetCreationFrozenArgs {
+    /// Unfreeze creation of curves
+    #[structopt(long)]
+    pub unfreeze: bool,
+    /// Send the transaction even if the config is not administered by the authority
+    #[structopt(long)]
+    pub force: bool,
+}
+
+#[derive(StructOpt)]
+#[structopt(rename_all = "kebab-case")]
+pub struct SetCurveOwnersArgs {
+    /// Curve account
+    #[structopt(long)]
+    pub curve: Pubkey,
+    /// Number of owners which must sign
+    #[structopt(long)]
+    pub threshold: u8,
+    /// Owner key, repeat for several owners
+    #[structopt(long = "owner")]
+    pub owners: Vec<Pubkey>,
+    /// Send the transaction even if the authority and co-signers are not the owners
+    #[structopt(long)]
+    pub force: bool,
+}
+
+#[derive(StructOpt)]
+#[structopt(rename_all = "kebab-case")]
+pub struct SetCurveExtensionArgs {
+    /// Curve account
+    #[structopt(long)]
+    pub curve: Pubkey,
+    /// Comma-separated labels, e.g. `borrow-rate,usdc`
+    #[structopt(long)]
+    pub tags: Option<String>,
+    /// Guardrails lower bound of `y`, raw units
+    #[structopt(long, allow_hyphen_values = true, requires = "max-y")]
+    pub min_y: Option<i64>,
+    /// Guardrails upper bound of `y`, raw units
+    #[structopt(long, allow_hyphen_values = true, requires = "min-y")]
+    pub max_y: Option<i64>,
+    /// Schedule start, unix timestamp, 0 for none
+    #[structopt(long, requires = "valid-until")]
+    pub valid_from: Option<i64>,
+    /// Schedule end, unix timestamp, 0 for none
+    #[structopt(long, requires = "valid-from")]
+    pub valid_until: Option<i64>,
+    /// Removes the extension: tags, guardrails or schedule
+    #[structopt(long)]
+    pub remove: Option<ExtensionType>,
+    /// Send the transaction even if the curve is not owned by the authority or the value is
+    /// invalid
+    #[structopt(long)]
+    pub force: bool,
+}
+
+#[derive(StructOpt)]
+#[structopt(rename_all = "kebab-case")]
+pub struct SetCurveStatusArgs {
+    /// Curve account
+    #[structopt(long)]
+    pub curve: Pubkey,
+    /// New status: draft, active, deprecated, retired or paused
+    #[structopt(long)]
+    pub status: CurveStatus,
+    /// Send the transaction even if the curve is not owned by the authority or the transition
+    /// is not allowed
+    #[structopt(long)]
+    pub force: bool,
+}
+
+#[derive(StructOpt)]
+#[structopt(rename_all = "kebab-case")]
+pub struct PatchYArgs {
+    /// Curve account
+    #[structopt(long)]
+    pub curve: Pubkey,
+    /// Index of the first sample to overwrite
+    #[structopt(long)]
+    pub start: u8,
+    /// New `y` value with the curve decimals, e.g. `0.25`. Repeat for consecutive samples.
+    #[structopt(long = "y", required = true)]
+    pub values: Vec<rust_decimal::Decimal>,
+    /// Send the transaction even if the curve is not owned by the authority
+    #[structopt(long)]
+    pub force: bool,
+}
+
+#[derive(StructOpt)]
+#[structopt(rename_all = "kebab-case")]
+pub struct SetSeriesArgs {
+    /// Curve account
+    #[structopt(long)]
+    pub curve: Pubkey,
+    #[structopt(flatten)]
+    pub source: CsvSource,
+    /// Send the transactions even if the curve is not owned by the authority
+    #[structopt(long)]
+    pub force: bool,
+}
+
+#[derive(StructOpt)]
+#[structopt(rename_all = "kebab-case")]
+pub struct CurveArgs {
+    /// Curve account
+    #[structopt(long)]
+    pub curve: Pubkey,
+}
+
+#[derive(StructOpt)]
+#[structopt(rename_all = "kebab-case")]
+pub struct CurvesArgs {
+    /// Include only this many evenly spaced points of each curve instead of the full `y`
+    /// array (JSON output only)
+    #[structopt(long)]
+    pub preview: Option<usize>,
+    /// Include only curves with this status: draft, active, deprecated, retired or paused
+    #[structopt(long)]
+    pub status: Option<CurveStatus>,
+    /// Include only curves marked for deletion by mark-for-deletion
+    #[structopt(long)]
+    pub pending_deletion: bool,
+    /// Include only curves tagged with this label, see set-curve-extension --tags
+    #[structopt(long)]
+    pub tag: Option<String>,
+}
+
+#[derive(StructOpt)]
+#[structopt(rename_all = "kebab-case")]
+pub struct SnapshotArgs {
+    /// Output file. Snapshot is printed to stdout when omitted.
+    #[structopt(long, parse(from_os_str))]
+    pub out: Option<PathBuf>,
+    /// Previous snapshot to compare with
+    #[structopt(long, parse(from_os_str))]
+    pub compare: Option<PathBuf>,
+}
+
+#[derive(StructOpt)]
+#[structopt(rename_all = "kebab-case")]
+pub struct PackArgs {
+    /// Output `.curvypack` file, replaced when exists
+    #[structopt(long, parse(from_os_str))]
+    pub out: PathBuf,
+    /// Owner of the packed curves, the authority by default
+    #[structopt(long)]
+    pub owner: Option<Pubkey>,
+    /// Release keypair signing the pack, restore checks the signature by --release-key
+    #[structopt(long)]
+    pub sign: Option<KeypairPath>,
+}
+
+#[derive(StructOpt)]
+#[structopt(rename_all = "kebab-case")]
+pub struct PlanArgs {
+    /// Expected state document (JSON), see curvy_client::ExpectedState
+    #[structopt(parse(from_os_str))]
+    pub state: PathBuf,
+    /// Plan file for apply, replaced when exists
+    #[structopt(long, parse(from_os_str))]
+    pub out: Option<PathBuf>,
+}
+
+#[derive(StructOpt)]
+#[structopt(rename_all = "kebab-case")]
+pub struct ApplyArgs {
+    /// Plan file saved by plan --out, applied only when not edited since
+    #[structopt(parse(from_os_str))]
+    pub plan: PathBuf,
+    /// Apply all actions without asking (protected curves still require typing the curve name)
+    #[structopt(long)]
+    pub auto_approve: bool,
+}
+
+#[derive(StructOpt)]
+#[structopt(rename_all = "kebab-case")]
+pub struct RestoreArgs {
+    /// `.curvypack` file written by pack
+    #[structopt(parse(from_os_str))]
+    pub pack: PathBuf,
+    /// Only print what would be created and altered
+    #[structopt(long)]
+    pub dry_run: bool,
+    /// Refuse the pack unless it is signed by this release key
+    #[structopt(long)]
+    pub release_key: Option<Pubkey>,
+}
+
+#[derive(StructOpt)]
+#[structopt(rename_all = "kebab-case")]
+pub struct RecordFixtureArgs {
+    pub curve: Pubkey,
+    /// What the fixture covers, e.g. `v1 curve created by 0.1.0`
+    #[structopt(long)]
+    pub description: String,
+    /// Fixtures file, created when missing. Fixture of the same account is replaced.
+    #[structopt(long, parse(from_os_str))]
+    pub out: PathBuf,
+}
+
+#[derive(StructOpt)]
+#[structopt(rename_all = "kebab-case")]
+pub struct ExportAllArgs {
+    /// Output file format: parquet or sqlite
+    #[structopt(long)]
+    pub format: ExportFormat,
+    /// Output file, replaced when exists
+    #[structopt(long, parse(from_os_str))]
+    pub out: PathBuf,
+}
+
+#[derive(StructOpt)]
+#[structopt(rename_all = "kebab-case")]
+pub struct DecodeAccountArgs {
+    /// Account data in base64 (e.g. copied from an explorer)
+    #[structopt(long)]
+    pub base64: String,
+}
+
+#[derive(StructOpt)]
+#[structopt(rename_all = "kebab-case")]
+pub struct LintFilesArgs {
+    /// Glob pattern of files to check, e.g. `curves/**/*.csv`. Files with `.curvypack`
+    /// extension are checked as packs.
+    pub pattern: String,
+    /// Expected number of decimals in `f_x` values
+    #[structopt(long, default_value = "6")]
+    pub decimals: u8,
+}
+
+#[derive(StructOpt)]
+#[structopt(rename_all = "kebab-case")]
+pub struct BootstrapArgs {
+    /// `devnet` or `localnet`
+    #[structopt(long, default_value = "devnet")]
+    pub cluster: Cluster,
+    /// Airdrop when the authority has less SOL than this
+    #[structopt(long, default_value = "1")]
+    pub min_balance: f64,
+    /// SOL to airdrop
+    #[structopt(long, default_value = "2")]
+    pub airdrop: f64,
+}
+
+#[derive(StructOpt)]
+#[structopt(rename_all = "kebab-case")]
+pub struct FitArgs {
+    /// Dense samples (CSV with `x,f_x` columns, any number of rows and decimals, `x` in curve
+    /// units)
+    #[structopt(long, parse(from_os_str))]
+    pub csv: PathBuf,
+    #[structopt(long, default_value = "6")]
+    pub decimals: u8,
+    /// Use the fewest points keeping max interpolation error within this value. The least
+    /// error possible is searched for when omitted.
+    #[structopt(long)]
+    pub target_error: Option<rust_decimal::Decimal>,
+    /// Output curve CSV for `create-curve --csv`
+    #[structopt(long, parse(from_os_str))]
+    pub out: PathBuf,
+}
+
+#[derive(StructOpt)]
+#[structopt(rename_all = "kebab-case")]
+pub struct SmoothArgs {
+    /// Curve CSV with `x,f_x` columns, additional columns are dropped
+    #[structopt(long, parse(from_os_str))]
+    pub csv: PathBuf,
+    #[structopt(long, default_value = "6")]
+    pub decimals: u8,
+    /// `median` or `moving-average`
+    #[structopt(long, default_value = "median")]
+    pub method: SmoothMethod,
+    /// Odd number of samples around each one taken into account
+    #[structopt(long, default_value = "3")]
+    pub window: usize,
+    /// Report samples changed by more than this value, e.g. `0.5`
+    #[structopt(long, default_value = "0")]
+    pub threshold: rust_decimal::Decimal,
+    /// Output curve CSV for `create-curve --csv`
+    #[structopt(long, parse(from_os_str))]
+    pub out: PathBuf,
+}
+
+#[derive(StructOpt)]
+#[structopt(rename_all = "kebab-case")]
+pub struct CalcYArgs {
+    /// Curve account
+    #[structopt(long)]
+    pub curve: Pubkey,
+    /// X coordinate
+    #[structopt(long)]
+    pub x: f64,
+    /// Series to use, 0 is the curve itself, additional series start from 1
+    #[structopt(long, default_value = "0")]
+    pub series: u8,
+    /// Also print Y as an integer with this many decimals rounded down, e.g. 18 for WAD
+    #[structopt(long)]
+    pub scaled: Option<u32>,
+}
+
+#[derive(StructOpt)]
+#[structopt(rename_all = "kebab-case")]
+pub struct EvaluateCurveArgs {
+    /// Curve account
+    #[structopt(long)]
+    pub curve: Pubkey,
+    /// X coordinate with the curve decimals, e.g. `0.25`
+    #[structopt(long)]
+    pub x: rust_decimal::Decimal,
+    /// Keypair of the result account. Fresh keypair is generated when omitted.
+    #[structopt(long)]
+    pub result_keypair: Option<KeypairPath>,
+}
+
+#[derive(StructOpt)]
+#[structopt(rename_all = "kebab-case")]
+pub struct TwaArgs {
+    /// Curve account
+    #[structopt(long)]
+    pub curve: Pubkey,
+    /// Averaging window ending now, e.g. `7d` or `12h`
+    #[structopt(long, default_value = "7d")]
+    pub window: humantime::Duration,
+}
+
+#[derive(StructOpt)]
+#[structopt(rename_all = "kebab-case")]
+pub struct SummarizeArgs {
+    /// Curve account
+    #[structopt(long)]
+    pub curve: Pubkey,
+    /// Proposed samples, the change from the current curve to them is described
+    #[structopt(flatten)]
+    pub source: CsvSource,
+    /// Decimals of the proposed samples, those of the curve by default
+    #[structopt(long)]
+    pub decimals: Option<u8>,
+    /// What `y` is, e.g. `Borrow APR`. The curve name by default.
+    #[structopt(long)]
+    pub subject: Option<String>,
+    /// What `x` is, e.g. `utilization`
+    #[structopt(long)]
+    pub x_label: Option<String>,
+    /// Show `x` fractions in percent
+    #[structopt(long)]
+    pub x_percent: bool,
+    /// Show `y` fractions in percent
+    #[structopt(long)]
+    pub y_percent: bool,
+    /// Max decimals of shown values
+    #[structopt(long, default_value = "2")]
+    pub precision: u32,
+    /// Reference `x` point, may be repeated. The first, middle and last samples by default.
+    #[structopt(long = "at")]
+    pub points: Vec<rust_decimal::Decimal>,
+}
+
+#[derive(StructOpt)]
+#[structopt(rename_all = "kebab-case")]
+pub struct CheckConsumerArgs {
+    /// Curve account
+    #[structopt(long)]
+    pub curve: Pubkey,
+    /// Consumer program layout (JSON), see `ConsumerLayout` of curvy-client
+    #[structopt(long, parse(from_os_str))]
+    pub layout: PathBuf,
+    /// Points sampled in every segment between curve samples
+    #[structopt(long, default_value = "4")]
+    pub per_segment: u32,
+    /// Allowed difference of `y`, one unit of the consumer `y` precision by default
+    #[structopt(long)]
+    pub tolerance: Option<rust_decimal::Decimal>,
+}
+
+#[derive(StructOpt)]
+#[structopt(rename_all = "kebab-case")]
+pub struct ErrorsArgs {
+    /// Print only this code, decimal or hex with `0x` prefix
+    #[structopt(parse(try_from_str = parse_error_code))]
+    pub code: Option<u32>,
+}
+
+fn parse_error_code(s: &str) -> Result<u32, std::num::ParseIntError> {
+    match s.strip_prefix("0x") {
+        Some(hex) => u32::from_str_radix(hex, 16),
+        None => s.parse(),
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum ConfirmationMode {
+    Auto,
+    Spinner,
+    Polling,
+}
+
+impl std::str::FromStr for ConfirmationMode {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "auto" => Ok(Self::Auto),
+            "spinner" => Ok(Self::Spinner),
+            "polling" => Ok(Self::Polling),
+            _ => Err(format!(
+                "unknown confirmation mode `{s}`, expected auto, spinner or polling"
+            )),
+        }
+    }
+}
+
+/// Cluster with a faucet, see [Command::Bootstrap]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Cluster {
+    Devnet,
+    Localnet,
+}
+
+impl Cluster {
+    pub fn url(&self) -> &'static str {
+        match self {
+            Self::Devnet => "https://api.devnet.solana.com",
+            Self::Localnet => "http://localhost:8899",
+        }
+    }
+}
+
+impl std::str::FromStr for Cluster {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "devnet" => Ok(Self::Devnet),
+            "localnet" => Ok(Self::Localnet),
+            _ => Err(format!(
+                "unknown cluster `{s}`, expected devnet or localnet (clusters with a faucet)"
+            )),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OutputFormat {
+    Text,
+    Json,
+}
+
+impl std::str::FromStr for OutputFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(Self::Text),
+            "json" => Ok(Self::Json),
+            _ => Err(format!(
+                "unknown output format `{s}`, expected text or json"
+            )),
+        }
+    }
 }
 
 #[derive(FromStr)]
@@ -106,3 +1117,37 @@ impl ToString for KeypairPath {
         self.0.to_str().expect("non unicode").to_string()
     }
 }
+
+#[derive(FromStr)]
+pub struct ConfigPath(pub PathBuf);
+
+impl Default for ConfigPath {
+    fn default() -> Self {
+        let mut path = dirs_next::home_dir().expect("home dir");
+        path.extend([".config", "curvy", "config.json"]);
+        Self(path)
+    }
+}
+
+impl fmt::Display for ConfigPath {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.display().fmt(f)
+    }
+}
+
+#[derive(FromStr)]
+pub struct AlterHistoryPath(pub PathBuf);
+
+impl Default for AlterHistoryPath {
+    fn default() -> Self {
+        let mut path = dirs_next::home_dir().expect("home dir");
+        path.extend([".config", "curvy", "alter-history.json"]);
+        Self(path)
+    }
+}
+
+impl fmt::Display for AlterHistoryPath {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.display().fmt(f)
+    }
+}
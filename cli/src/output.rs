@@ -0,0 +1,1098 @@
+use std::fmt::{Display, Formatter};
+use std::path::PathBuf;
+
+use solana_sdk::clock::Slot;
+use solana_sdk::hash::Hash;
+use solana_sdk::packet::PACKET_DATA_SIZE;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::Signature;
+use texture_common::account::PodAccount;
+use texture_common::math::Decimal;
+
+use curvy::error::ErrorCode;
+use curvy::state::curve::{Curve, CurveParams, CurveX};
+use curvy::state::grid_curve::GridCurveParams;
+use curvy::state::utils::bytes_to_cow;
+use curvy::state::wide_curve::WideCurveParams;
+use curvy::state::AccountKind;
+use curvy_client::{
+    ChangeAction, ConsistencyReport, CurveChange, CurveDataView, CurveSignatureView, CurveView,
+    FieldChange, OwnerView, Profile, ReconcileAction, ReconcilePlan, Snapshot, SnapshotDiff,
+    TransactionCost, MAX_COMPUTE_UNIT_LIMIT,
+};
+use curvy_utils::{ApproximationError, ChangedPoint, SmoothMethod};
+
+use crate::export::{ExportFormat, ExportStats};
+use crate::lint::LintReport;
+
+const SEPARATOR: &str = "======================================";
+
+/// Result of a CLI command. Human-readable text (`Display`), JSON (`Serialize`) and process
+/// exit code are all derived from it.
+#[serde_with::serde_as]
+#[serde_with::skip_serializing_none]
+#[derive(serde::Serialize)]
+#[serde(tag = "result", rename_all = "snake_case")]
+pub enum CommandOutput {
+    Created {
+        #[serde(flatten)]
+        view: Box<CurveSignatureView>,
+        /// Interpolation error against original samples, see `create-curve --reference`
+        approximation: Option<ApproximationError>,
+        /// Signatures of additional series uploads, in series order
+        #[serde_as(as = "Vec<serde_with::DisplayFromStr>")]
+        #[serde(skip_serializing_if = "Vec::is_empty")]
+        series: Vec<Signature>,
+        /// Signature of the per-sample confidence upload
+        #[serde_as(as = "Option<serde_with::DisplayFromStr>")]
+        confidence: Option<Signature>,
+        /// Signature of setting negative `x0`
+        #[serde_as(as = "Option<serde_with::DisplayFromStr>")]
+        x_sign: Option<Signature>,
+        /// Signature of setting signed `y`
+        #[serde_as(as = "Option<serde_with::DisplayFromStr>")]
+        y_sign: Option<Signature>,
+        /// Signature of setting the `y` transform, see `create-curve --y-transform`
+        #[serde_as(as = "Option<serde_with::DisplayFromStr>")]
+        y_transform: Option<Signature>,
+        /// Signature of tagging the curve, see `create-curve --tags`
+        #[serde_as(as = "Option<serde_with::DisplayFromStr>")]
+        tags: Option<Signature>,
+        /// Signature of timelocking the curve, see `create-curve --alter-delay`
+        #[serde_as(as = "Option<serde_with::DisplayFromStr>")]
+        alter_delay: Option<Signature>,
+    },
+    Altered {
+        #[serde_as(as = "serde_with::DisplayFromStr")]
+        curve: Pubkey,
+        name: String,
+        #[serde_as(as = "serde_with::DisplayFromStr")]
+        signature: Signature,
+        cost: Option<TransactionCost>,
+        /// Changed params, empty when the curve was sent unchanged
+        diff: Vec<FieldChange>,
+        /// Signature of the per-sample confidence update sent after the alter
+        #[serde_as(as = "Option<serde_with::DisplayFromStr>")]
+        confidence: Option<Signature>,
+        /// Signature of the `x0` sign update sent after the alter
+        #[serde_as(as = "Option<serde_with::DisplayFromStr>")]
+        x_sign: Option<Signature>,
+        /// Signature of the `y` sign update sent after the alter
+        #[serde_as(as = "Option<serde_with::DisplayFromStr>")]
+        y_sign: Option<Signature>,
+        /// Signature of the `y` transform update sent after the alter
+        #[serde_as(as = "Option<serde_with::DisplayFromStr>")]
+        y_transform: Option<Signature>,
+        /// Signature of the tags update sent after the alter
+        #[serde_as(as = "Option<serde_with::DisplayFromStr>")]
+        tags: Option<Signature>,
+    },
+    AlterProposed {
+        #[serde_as(as = "serde_with::DisplayFromStr")]
+        curve: Pubkey,
+        name: String,
+        /// First slot apply-alter accepts the proposal in
+        apply_slot: Option<u64>,
+        #[serde_as(as = "serde_with::DisplayFromStr")]
+        signature: Signature,
+        cost: Option<TransactionCost>,
+        /// Proposed changes of params
+        diff: Vec<FieldChange>,
+    },
+    SeriesSet {
+        #[serde_as(as = "serde_with::DisplayFromStr")]
+        curve: Pubkey,
+        name: String,
+        /// One per series, in series order
+        #[serde_as(as = "Vec<serde_with::DisplayFromStr>")]
+        signatures: Vec<Signature>,
+        diff: Vec<FieldChange>,
+    },
+    Deleted {
+        #[serde_as(as = "serde_with::DisplayFromStr")]
+        curve: Pubkey,
+        name: String,
+        #[serde_as(as = "serde_with::DisplayFromStr")]
+        signature: Signature,
+        cost: Option<TransactionCost>,
+    },
+    Migrated {
+        #[serde_as(as = "serde_with::DisplayFromStr")]
+        curve: Pubkey,
+        #[serde_as(as = "serde_with::DisplayFromStr")]
+        signature: Signature,
+        cost: Option<TransactionCost>,
+    },
+    ToppedUp {
+        #[serde_as(as = "serde_with::DisplayFromStr")]
+        curve: Pubkey,
+        /// Lamports transferred to the curve account
+        lamports: u64,
+        /// Absent when the curve was rent exempt already
+        #[serde_as(as = "Option<serde_with::DisplayFromStr>")]
+        signature: Option<Signature>,
+        cost: Option<TransactionCost>,
+    },
+    Curve(Box<CurveView>),
+    WideCurve {
+        #[serde_as(as = "serde_with::DisplayFromStr")]
+        curve: Pubkey,
+        #[serde_as(as = "serde_with::DisplayFromStr")]
+        owner: Pubkey,
+        params: Box<WideCurveParams>,
+    },
+    GridCurve {
+        #[serde_as(as = "serde_with::DisplayFromStr")]
+        curve: Pubkey,
+        #[serde_as(as = "serde_with::DisplayFromStr")]
+        owner: Pubkey,
+        params: Box<GridCurveParams>,
+    },
+    Listed {
+        n: usize,
+        curves: CurveList,
+    },
+    Snapshot {
+        /// Present when the snapshot is not written to a file
+        snapshot: Option<Snapshot>,
+        out: Option<PathBuf>,
+        diff: Option<SnapshotDiff>,
+    },
+    Packed {
+        out: PathBuf,
+        #[serde_as(as = "serde_with::DisplayFromStr")]
+        owner: Pubkey,
+        /// Number of packed curves
+        curves: usize,
+        /// Release key which signed the pack
+        #[serde_as(as = "Option<serde_with::DisplayFromStr>")]
+        signer: Option<Pubkey>,
+    },
+    Restored {
+        pack: PathBuf,
+        /// Release key which signed the pack, checked by `restore --release-key`
+        #[serde_as(as = "Option<serde_with::DisplayFromStr>")]
+        signer: Option<Pubkey>,
+        /// Whether the changes were only planned, see `restore --dry-run`
+        dry_run: bool,
+        curves: Vec<RestoredCurve>,
+    },
+    Planned {
+        plan: ReconcilePlan,
+        /// SHA-256 of the plan file, shown by apply as well
+        #[serde_as(as = "serde_with::DisplayFromStr")]
+        hash: Hash,
+        /// Slot the curves were compared in
+        slot: Slot,
+        out: Option<PathBuf>,
+    },
+    Applied {
+        plan: PathBuf,
+        #[serde_as(as = "serde_with::DisplayFromStr")]
+        hash: Hash,
+        actions: Vec<AppliedAction>,
+    },
+    Bootstrapped {
+        rpc_url: String,
+        #[serde_as(as = "serde_with::DisplayFromStr")]
+        authority: Pubkey,
+        /// Balance after the airdrop, SOL
+        balance: f64,
+        #[serde_as(as = "Option<serde_with::DisplayFromStr>")]
+        airdrop: Option<Signature>,
+        /// Sample curves, `unchanged` ones were created by an earlier bootstrap
+        curves: Vec<RestoredCurve>,
+        /// Config snippet for downstream services
+        config: serde_json::Value,
+    },
+    Exported {
+        format: ExportFormat,
+        out: PathBuf,
+        slot: Slot,
+        #[serde(flatten)]
+        stats: ExportStats,
+    },
+    Owners {
+        owners: Vec<OwnerView>,
+    },
+    Config {
+        #[serde_as(as = "serde_with::DisplayFromStr")]
+        config: Pubkey,
+        /// `None` until the config is created
+        #[serde_as(as = "Option<serde_with::DisplayFromStr>")]
+        admin: Option<Pubkey>,
+        #[serde_as(as = "Vec<serde_with::DisplayFromStr>")]
+        creators: Vec<Pubkey>,
+        /// Creation of curves is frozen by the admin
+        creation_frozen: bool,
+        /// Signature of creating or updating the config
+        #[serde_as(as = "Option<serde_with::DisplayFromStr>")]
+        signature: Option<Signature>,
+    },
+    FixtureRecorded {
+        #[serde_as(as = "serde_with::DisplayFromStr")]
+        curve: Pubkey,
+        slot: Slot,
+        out: PathBuf,
+    },
+    Decoded {
+        kind: String,
+        params: Box<CurveParams>,
+        #[serde_as(as = "serde_with::DisplayFromStr")]
+        owner: Pubkey,
+        #[serde(skip)]
+        curve: Box<Curve>,
+    },
+    Lint(LintReport),
+    ConsumerCheck(ConsistencyReport),
+    Fitted {
+        x0: CurveX,
+        x_step: CurveX,
+        y_count: u8,
+        /// Error of the fitted curve against the dense samples
+        error: ApproximationError,
+        out: PathBuf,
+    },
+    Smoothed {
+        #[serde_as(as = "serde_with::DisplayFromStr")]
+        method: SmoothMethod,
+        window: usize,
+        /// Samples changed by more than the threshold, raw units
+        changed: Vec<ChangedPoint>,
+        out: PathBuf,
+    },
+    CalcY {
+        #[serde_as(as = "serde_with::DisplayFromStr")]
+        curve: Pubkey,
+        x: f64,
+        #[serde_as(as = "serde_with::DisplayFromStr")]
+        y: Decimal,
+        /// Confidence of `y` in basis points, when the curve has it
+        #[serde_as(as = "Option<serde_with::DisplayFromStr>")]
+        confidence: Option<Decimal>,
+        /// `y` with `--scaled` decimals rounded down
+        #[serde_as(as = "Option<serde_with::DisplayFromStr>")]
+        scaled: Option<i128>,
+    },
+    Evaluated {
+        #[serde_as(as = "serde_with::DisplayFromStr")]
+        curve: Pubkey,
+        /// Account storing the result
+        #[serde_as(as = "serde_with::DisplayFromStr")]
+        evaluation: Pubkey,
+        #[serde_as(as = "serde_with::DisplayFromStr")]
+        x: Decimal,
+        #[serde_as(as = "serde_with::DisplayFromStr")]
+        y: Decimal,
+        /// Slot of the evaluation
+        slot: Slot,
+        #[serde_as(as = "serde_with::DisplayFromStr")]
+        signature: Signature,
+        cost: Option<TransactionCost>,
+    },
+    Twa {
+        #[serde_as(as = "serde_with::DisplayFromStr")]
+        curve: Pubkey,
+        /// Window bounds, unix timestamps
+        from: i64,
+        to: i64,
+        /// Number of curve versions active within the window
+        versions: usize,
+        params: Box<CurveParams>,
+        x0_negative: bool,
+    },
+    Summary {
+        #[serde_as(as = "serde_with::DisplayFromStr")]
+        curve: Pubkey,
+        /// Whether proposed samples are compared with the curve
+        change: bool,
+        lines: Vec<String>,
+    },
+    Errors {
+        errors: Vec<ErrorCode>,
+    },
+    Profile {
+        profile: Profile,
+        /// Number of the profiled instructions fitting in one transaction
+        fit: Option<usize>,
+    },
+}
+
+impl CommandOutput {
+    /// Decoded curve account without known address
+    pub fn decoded(curve: Curve) -> Self {
+        CommandOutput::Decoded {
+            kind: AccountKind::Curve {
+                version: curve.version,
+            }
+            .to_string(),
+            params: Box::new(CurveParams::from(&curve)),
+            owner: curve.owner,
+            curve: Box::new(curve),
+        }
+    }
+
+    /// Process exit code: non-zero when a check command found problems
+    pub fn exit_code(&self) -> i32 {
+        match self {
+            CommandOutput::Snapshot {
+                diff: Some(diff), ..
+            } if !diff.is_empty() => 1,
+            CommandOutput::Planned { plan, .. } if !plan.is_empty() => 1,
+            CommandOutput::Lint(report) if !report.is_ok() => 1,
+            CommandOutput::ConsumerCheck(report) if !report.is_ok() => 1,
+            CommandOutput::Profile { profile, .. } if profile.error.is_some() => 1,
+            _ => 0,
+        }
+    }
+
+    /// Change to notify about, for mutating commands
+    pub fn change(&self) -> Option<CurveChange> {
+        let change = match self {
+            CommandOutput::Created { view, .. } => CurveChange {
+                action: ChangeAction::Created,
+                curve: view.curve,
+                name: view
+                    .params
+                    .map(|params| bytes_to_cow(&params.name).into_owned())
+                    .unwrap_or_default(),
+                diff: vec![],
+                signature: view.signature,
+            },
+            CommandOutput::Altered {
+                curve,
+                name,
+                signature,
+                diff,
+                ..
+            } => CurveChange {
+                action: ChangeAction::Altered,
+                curve: *curve,
+                name: name.clone(),
+                diff: diff.clone(),
+                signature: Some(*signature),
+            },
+            CommandOutput::SeriesSet {
+                curve,
+                name,
+                signatures,
+                diff,
+            } => CurveChange {
+                action: ChangeAction::Altered,
+                curve: *curve,
+                name: name.clone(),
+                diff: diff.clone(),
+                signature: signatures.last().copied(),
+            },
+            CommandOutput::Deleted {
+                curve,
+                name,
+                signature,
+                ..
+            } => CurveChange {
+                action: ChangeAction::Deleted,
+                curve: *curve,
+                name: name.clone(),
+                diff: vec![],
+                signature: Some(*signature),
+            },
+            _ => return None,
+        };
+
+        Some(change)
+    }
+
+    pub fn to_json(&self) -> serde_json::Result<String> {
+        serde_json::to_string_pretty(self)
+    }
+}
+
+impl Display for CommandOutput {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CommandOutput::Created {
+                view,
+                approximation,
+                series,
+                confidence,
+                x_sign,
+                y_sign,
+                y_transform,
+                tags,
+                alter_delay,
+            } => {
+                write!(f, "{view}")?;
+                if let Some(signature) = x_sign {
+                    write!(f, "\nx sign signature: {signature}")?;
+                }
+                if let Some(signature) = y_sign {
+                    write!(f, "\ny sign signature: {signature}")?;
+                }
+                if let Some(signature) = y_transform {
+                    write!(f, "\ny transform signature: {signature}")?;
+                }
+                if let Some(signature) = tags {
+                    write!(f, "\ntags signature: {signature}")?;
+                }
+                if let Some(signature) = alter_delay {
+                    write!(f, "\nalter delay signature: {signature}")?;
+                }
+                for (idx, signature) in series.iter().enumerate() {
+                    write!(f, "\nseries {} signature: {signature}", idx + 1)?;
+                }
+                if let Some(signature) = confidence {
+                    write!(f, "\nconfidence signature: {signature}")?;
+                }
+                if let Some(error) = approximation {
+                    write!(
+                        f,
+                        "\napproximation error: max {} at x={}, mean {} over {} samples",
+                        error.max,
+                        error.max_at.unwrap_or_default(),
+                        error.mean,
+                        error.samples
+                    )?;
+                    if error.out_of_range > 0 {
+                        write!(f, " ({} out of curve range)", error.out_of_range)?;
+                    }
+                }
+                Ok(())
+            }
+            CommandOutput::Altered {
+                curve,
+                signature,
+                cost,
+                diff,
+                confidence,
+                x_sign,
+                y_sign,
+                y_transform,
+                tags,
+                ..
+            } => {
+                writeln!(f, "signature: {signature}")?;
+                write_cost(f, cost)?;
+                if let Some(signature) = x_sign {
+                    writeln!(f, "x sign signature: {signature}")?;
+                }
+                if let Some(signature) = y_sign {
+                    writeln!(f, "y sign signature: {signature}")?;
+                }
+                if let Some(signature) = y_transform {
+                    writeln!(f, "y transform signature: {signature}")?;
+                }
+                if let Some(signature) = tags {
+                    writeln!(f, "tags signature: {signature}")?;
+                }
+                if let Some(signature) = confidence {
+                    writeln!(f, "confidence signature: {signature}")?;
+                }
+                write!(f, "altered curve: {curve}")?;
+                for change in diff {
+                    write!(f, "\n  {change}")?;
+                }
+                Ok(())
+            }
+            CommandOutput::AlterProposed {
+                curve,
+                apply_slot,
+                signature,
+                cost,
+                diff,
+                ..
+            } => {
+                writeln!(f, "signature: {signature}")?;
+                write_cost(f, cost)?;
+                write!(f, "proposed alteration of curve: {curve}")?;
+                if let Some(apply_slot) = apply_slot {
+                    write!(f, ", apply from slot {apply_slot}")?;
+                }
+                for change in diff {
+                    write!(f, "\n  {change}")?;
+                }
+                Ok(())
+            }
+            CommandOutput::SeriesSet {
+                curve, signatures, ..
+            } => {
+                for (idx, signature) in signatures.iter().enumerate() {
+                    writeln!(f, "series {} signature: {signature}", idx + 1)?;
+                }
+                write!(f, "set {} series of curve: {curve}", signatures.len())
+            }
+            CommandOutput::Deleted {
+                curve,
+                signature,
+                cost,
+                ..
+            } => {
+                writeln!(f, "signature: {signature}")?;
+                write_cost(f, cost)?;
+                write!(f, "deleted curve: {curve}")
+            }
+            CommandOutput::Migrated {
+                curve,
+                signature,
+                cost,
+            } => {
+                writeln!(f, "signature: {signature}")?;
+                write_cost(f, cost)?;
+                write!(f, "migrated curve: {curve}")
+            }
+            CommandOutput::ToppedUp {
+                curve,
+                lamports,
+                signature,
+                cost,
+            } => match signature {
+                Some(signature) => {
+                    writeln!(f, "signature: {signature}")?;
+                    write_cost(f, cost)?;
+                    write!(f, "topped up curve {curve} by {lamports} lamports")
+                }
+                None => write!(f, "curve {curve} is rent exempt already"),
+            },
+            CommandOutput::Curve(view) => write!(f, "{view}"),
+            CommandOutput::WideCurve {
+                curve,
+                owner,
+                params,
+            } => {
+                writeln!(f, "Address : {curve} (wide)")?;
+                writeln!(f, "Name    : {}", bytes_to_cow(&params.name))?;
+                writeln!(f, "Formula : {}", bytes_to_cow(&params.formula))?;
+                writeln!(f, "Owner   : {owner}")?;
+                writeln!(f, "decimals: {}", params.decimals)?;
+                writeln!(f, "x0      : {}", params.x0)?;
+                writeln!(f, "x_step  : {}", params.x_step)?;
+                writeln!(f, "y_count : {}", params.y_count)?;
+                write!(f, "y       :")?;
+                for y in params.y.iter().take(params.y_count as usize) {
+                    write!(f, " {y}")?;
+                }
+                Ok(())
+            }
+            CommandOutput::GridCurve {
+                curve,
+                owner,
+                params,
+            } => {
+                writeln!(f, "Address : {curve} (grid)")?;
+                writeln!(f, "Name    : {}", bytes_to_cow(&params.name))?;
+                writeln!(f, "Formula : {}", bytes_to_cow(&params.formula))?;
+                writeln!(f, "Owner   : {owner}")?;
+                writeln!(f, "decimals: {}", params.decimals)?;
+                writeln!(f, "y_count : {}", params.y_count)?;
+                write!(f, "x       :")?;
+                for x in params.x.iter().take(params.y_count as usize) {
+                    write!(f, " {x}")?;
+                }
+                writeln!(f)?;
+                write!(f, "y       :")?;
+                for y in params.y.iter().take(params.y_count as usize) {
+                    write!(f, " {y}")?;
+                }
+                Ok(())
+            }
+            CommandOutput::Listed { curves, .. } => {
+                for (idx, view) in curves.curves.iter().enumerate() {
+                    if idx > 0 {
+                        writeln!(f)?;
+                    }
+                    writeln!(f, "{view}")?;
+                    write!(f, "{}", XyView(&view.curve))?;
+                    write!(f, "{SEPARATOR}")?;
+                }
+                Ok(())
+            }
+            CommandOutput::Snapshot { snapshot, .. } => match snapshot {
+                Some(snapshot) => match serde_json::to_string_pretty(snapshot) {
+                    Ok(json) => write!(f, "{json}"),
+                    Err(_) => Err(std::fmt::Error),
+                },
+                None => Ok(()),
+            },
+            CommandOutput::Exported {
+                out, slot, stats, ..
+            } => write!(
+                f,
+                "exported {} curves ({} points) at slot {slot} to {}",
+                stats.curves,
+                stats.points,
+                out.display()
+            ),
+            CommandOutput::Packed {
+                out,
+                owner,
+                curves,
+                signer,
+            } => {
+                write!(f, "packed {curves} curves of {owner} to {}", out.display())?;
+                if let Some(signer) = signer {
+                    write!(f, ", signed by {signer}")?;
+                }
+                Ok(())
+            }
+            CommandOutput::Restored {
+                pack,
+                signer,
+                dry_run,
+                curves,
+            } => {
+                let verb = if *dry_run {
+                    "would restore"
+                } else {
+                    "restored"
+                };
+                write!(f, "{verb} {} curves from {}", curves.len(), pack.display())?;
+                if let Some(signer) = signer {
+                    write!(f, " signed by {signer}")?;
+                }
+                for curve in curves {
+                    write!(f, "\n  {curve}")?;
+                }
+                Ok(())
+            }
+            CommandOutput::Planned {
+                plan,
+                hash,
+                slot,
+                out,
+            } => {
+                writeln!(f, "{plan}")?;
+                write!(f, "Plan {hash} at slot {slot}")?;
+                if let Some(out) = out {
+                    write!(f, ", saved to {}", out.display())?;
+                }
+                Ok(())
+            }
+            CommandOutput::Applied {
+                plan,
+                hash,
+                actions,
+            } => {
+                let applied = actions.iter().filter(|action| action.applied).count();
+                write!(
+                    f,
+                    "applied {applied} of {} actions of plan {} ({hash})",
+                    actions.len(),
+                    plan.display()
+                )?;
+                for action in actions {
+                    write!(f, "\n  {action}")?;
+                }
+                Ok(())
+            }
+            CommandOutput::Bootstrapped {
+                rpc_url,
+                authority,
+                balance,
+                airdrop,
+                curves,
+                config,
+            } => {
+                write!(f, "authority {authority} on {rpc_url}: {balance} SOL")?;
+                if let Some(airdrop) = airdrop {
+                    write!(f, " (airdropped, {airdrop})")?;
+                }
+                writeln!(f)?;
+                writeln!(f, "sample curves:")?;
+                for curve in curves {
+                    writeln!(f, "  {curve}")?;
+                }
+                writeln!(f, "config:")?;
+                write!(
+                    f,
+                    "{}",
+                    serde_json::to_string_pretty(config).map_err(|_| std::fmt::Error)?
+                )
+            }
+            CommandOutput::Owners { owners } => {
+                for (idx, owner) in owners.iter().enumerate() {
+                    if idx > 0 {
+                        writeln!(f)?;
+                    }
+                    writeln!(f, "{owner}")?;
+                    write!(f, "{SEPARATOR}")?;
+                }
+                Ok(())
+            }
+            CommandOutput::Config {
+                config,
+                admin,
+                creators,
+                creation_frozen,
+                signature,
+            } => {
+                if let Some(signature) = signature {
+                    writeln!(f, "signature: {signature}")?;
+                }
+                let Some(admin) = admin else {
+                    return write!(
+                        f,
+                        "config {config} is not created, anyone can create curves"
+                    );
+                };
+                writeln!(f, "Config: {config}")?;
+                write!(f, "Admin: {admin}")?;
+                if *creation_frozen {
+                    write!(f, "\ncurve creation is frozen")?;
+                }
+                if creators.is_empty() {
+                    write!(f, "\nnobody can create curves")?;
+                }
+                for creator in creators {
+                    write!(f, "\nCreator: {creator}")?;
+                }
+                Ok(())
+            }
+            CommandOutput::FixtureRecorded { curve, slot, out } => write!(
+                f,
+                "recorded curve {curve} at slot {slot} to {}",
+                out.display()
+            ),
+            CommandOutput::Decoded { kind, curve, .. } => {
+                writeln!(f, "Kind: {kind}")?;
+                writeln!(f, "{}", CurveDataView(curve))?;
+                write!(f, "{}", XyView(curve))
+            }
+            CommandOutput::Lint(report) => write!(f, "{report}"),
+            CommandOutput::ConsumerCheck(report) => {
+                writeln!(f, "Curve   : {}", report.curve)?;
+                writeln!(f, "Program : {}", report.program)?;
+                writeln!(
+                    f,
+                    "{} points, max difference {} (tolerance {})",
+                    report.samples, report.max_diff, report.tolerance
+                )?;
+                if report.is_ok() {
+                    return write!(f, "consistent");
+                }
+
+                writeln!(f, "{} divergences:", report.divergences.len())?;
+                for divergence in &report.divergences {
+                    let value = |value: Option<Decimal>| {
+                        value.map_or_else(|| "-".to_string(), |value| value.to_string())
+                    };
+                    write!(
+                        f,
+                        "  x={}: ours {}, theirs {}",
+                        divergence.x,
+                        value(divergence.ours),
+                        value(divergence.theirs)
+                    )?;
+                    if let Some(error) = &divergence.error {
+                        write!(f, " ({error})")?;
+                    }
+                    writeln!(f)?;
+                }
+                Ok(())
+            }
+            CommandOutput::Fitted {
+                x0,
+                x_step,
+                y_count,
+                error,
+                out,
+            } => {
+                writeln!(f, "x0: {x0}, x_step: {x_step}, points: {y_count}")?;
+                writeln!(
+                    f,
+                    "max error: {} at x={}, mean error: {}",
+                    error.max,
+                    error.max_at.unwrap_or_default(),
+                    error.mean
+                )?;
+                write!(f, "written to {}", out.display())
+            }
+            CommandOutput::Smoothed {
+                method,
+                window,
+                changed,
+                out,
+            } => {
+                writeln!(
+                    f,
+                    "{method} over {window} samples changed {} points beyond the threshold",
+                    changed.len()
+                )?;
+                for point in changed {
+                    writeln!(f, "  y[{}]: {} -> {}", point.idx, point.before, point.after)?;
+                }
+                write!(f, "written to {}", out.display())
+            }
+            CommandOutput::CalcY {
+                y,
+                confidence,
+                scaled,
+                ..
+            } => {
+                write!(f, "y = {y}")?;
+                if let Some(confidence) = confidence {
+                    write!(f, " ± {confidence} bps")?;
+                }
+                if let Some(scaled) = scaled {
+                    write!(f, "\nscaled y = {scaled}")?;
+                }
+                Ok(())
+            }
+            CommandOutput::Evaluated {
+                evaluation,
+                x,
+                y,
+                slot,
+                signature,
+                cost,
+                ..
+            } => {
+                writeln!(f, "signature: {signature}")?;
+                write_cost(f, cost)?;
+                writeln!(f, "result account: {evaluation}")?;
+                write!(f, "y = {y} at x = {x} in slot {slot}")
+            }
+            CommandOutput::Twa {
+                curve,
+                from,
+                to,
+                versions,
+                params,
+                x0_negative,
+            } => {
+                let mut twa = Curve::from_init_params((**params, Pubkey::default()));
+                twa.x0_negative = *x0_negative as u8;
+
+                writeln!(f, "Curve   : {curve}")?;
+                writeln!(f, "Window  : {from}..{to} ({versions} versions)")?;
+                write!(f, "{}", XyView(&twa))
+            }
+            CommandOutput::Summary { lines, .. } => write!(f, "{}", lines.join("\n")),
+            CommandOutput::Profile { profile, fit } => {
+                for instruction in &profile.instructions {
+                    write!(f, "#{} {}: ", instruction.index, instruction.program)?;
+                    match instruction.units {
+                        Some(units) => writeln!(f, "{units} CU")?,
+                        None => writeln!(f, "-")?,
+                    }
+                }
+                if let Some(units) = profile.units_consumed {
+                    writeln!(f, "total: {units} of {MAX_COMPUTE_UNIT_LIMIT} CU")?;
+                }
+                write!(
+                    f,
+                    "tx size: {} of {PACKET_DATA_SIZE} bytes",
+                    profile.tx_size
+                )?;
+                if let Some(fit) = fit {
+                    write!(f, "\nfits per transaction: {fit}")?;
+                }
+                if let Some(error) = &profile.error {
+                    write!(f, "\nerror: {error}")?;
+                    for log in &profile.logs {
+                        write!(f, "\n    {log}")?;
+                    }
+                }
+                Ok(())
+            }
+            CommandOutput::Errors { errors } => {
+                for (idx, error) in errors.iter().enumerate() {
+                    if idx > 0 {
+                        writeln!(f)?;
+                    }
+                    write!(
+                        f,
+                        "{:>4} 0x{:<4x} {:<28} {}",
+                        error.code, error.code, error.name, error.description
+                    )?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+/// Line with what the transaction cost, when the RPC node returned it
+fn write_cost(f: &mut Formatter<'_>, cost: &Option<TransactionCost>) -> std::fmt::Result {
+    match cost {
+        Some(cost) => writeln!(f, "fee: {cost}"),
+        None => Ok(()),
+    }
+}
+
+/// Curves listing, serialized with optional preview instead of full `y` arrays
+pub struct CurveList {
+    pub curves: Vec<CurveView>,
+    pub preview: Option<usize>,
+}
+
+impl serde::Serialize for CurveList {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.curves.iter().map(|curve| curve.json(self.preview)))
+    }
+}
+
+/// What restore does to a curve of the pack
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RestoreAction {
+    Created,
+    Altered,
+    Unchanged,
+}
+
+/// Curve of a pack restored, see [CommandOutput::Restored]
+#[serde_with::serde_as]
+#[serde_with::skip_serializing_none]
+#[derive(Debug, serde::Serialize)]
+pub struct RestoredCurve {
+    pub name: String,
+    pub action: RestoreAction,
+    /// Absent for curves a dry run would create
+    #[serde_as(as = "Option<serde_with::DisplayFromStr>")]
+    pub curve: Option<Pubkey>,
+    #[serde_as(as = "Option<serde_with::DisplayFromStr>")]
+    pub signature: Option<Signature>,
+}
+
+impl Display for RestoredCurve {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let action = match self.action {
+            RestoreAction::Created => "created",
+            RestoreAction::Altered => "altered",
+            RestoreAction::Unchanged => "unchanged",
+        };
+        write!(f, "{}: {action}", self.name)?;
+        if let Some(curve) = self.curve {
+            write!(f, " {curve}")?;
+        }
+        if let Some(signature) = self.signature {
+            write!(f, " ({signature})")?;
+        }
+        Ok(())
+    }
+}
+
+/// Action of a plan file, see [CommandOutput::Applied]
+#[serde_with::serde_as]
+#[derive(Debug, serde::Serialize)]
+pub struct AppliedAction {
+    pub action: ReconcileAction,
+    /// Whether the action was confirmed and executed, declined ones are skipped
+    pub applied: bool,
+    #[serde_as(as = "Vec<serde_with::DisplayFromStr>")]
+    pub signatures: Vec<Signature>,
+}
+
+impl Display for AppliedAction {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let status = if self.applied { "applied" } else { "skipped" };
+        write!(f, "{status}: {}", self.action)?;
+        for signature in &self.signatures {
+            write!(f, " {signature}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Curve points table in human-readable numbers
+pub struct XyView<'a>(pub &'a Curve);
+
+impl Display for XyView<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let curve = self.0;
+        let x_scale = 10_u32.pow(curve.x_decimals as u32) as f32;
+        let y_scale = 10_u32.pow(curve.y_decimals as u32) as f32;
+
+        writeln!(f, "  X  :  f(x)")?;
+        let mut x = curve.x0_signed();
+        for idx in 0..curve.y_count {
+            writeln!(
+                f,
+                "  {}  :  {}",
+                x as f32 / x_scale,
+                curve.y_value(curve.y[idx as usize]) as f32 / y_scale
+            )?;
+            x += curve.x_step as i64;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use curvy::state::curve::MAX_Y_CNT;
+    use curvy_client::SnapshotDiff;
+
+    use super::*;
+    use crate::lint::LintedFile;
+
+    #[test]
+    fn exit_code() {
+        let lint = |violations: Vec<String>| {
+            CommandOutput::Lint(LintReport {
+                pattern: "*.csv".to_string(),
+                files: vec![LintedFile {
+                    path: "a.csv".into(),
+                    violations,
+                    error: None,
+                }],
+            })
+        };
+        assert_eq!(lint(vec![]).exit_code(), 0);
+        assert_eq!(lint(vec!["bad".to_string()]).exit_code(), 1);
+
+        let snapshot = |diff: SnapshotDiff| CommandOutput::Snapshot {
+            snapshot: None,
+            out: None,
+            diff: Some(diff),
+        };
+        assert_eq!(snapshot(SnapshotDiff::default()).exit_code(), 0);
+        let changed = SnapshotDiff {
+            changed: vec![Pubkey::new_unique()],
+            ..Default::default()
+        };
+        let output = snapshot(changed);
+        assert_eq!(output.exit_code(), 1);
+
+        let json: serde_json::Value = serde_json::from_str(&output.to_json().unwrap()).unwrap();
+        assert_eq!(json["result"], "snapshot");
+        assert_eq!(json["diff"]["changed"].as_array().unwrap().len(), 1);
+
+        let planned = |actions: Vec<ReconcileAction>| {
+            let plan = ReconcilePlan { actions };
+            CommandOutput::Planned {
+                hash: plan.hash().unwrap(),
+                plan,
+                slot: 1,
+                out: None,
+            }
+        };
+        assert_eq!(planned(vec![]).exit_code(), 0);
+        let output = planned(vec![ReconcileAction::Delete {
+            curve: Pubkey::new_unique(),
+            name: "old".to_string(),
+            owner: Pubkey::new_unique(),
+        }]);
+        assert_eq!(output.exit_code(), 1);
+
+        let json: serde_json::Value = serde_json::from_str(&output.to_json().unwrap()).unwrap();
+        assert_eq!(json["plan"]["actions"][0]["action"], "delete");
+    }
+
+    #[test]
+    fn field_changes() {
+        let mut y = [0; MAX_Y_CNT];
+        y[..3].copy_from_slice(&[1, 2, 3]);
+        let from = CurveParams::new("curve", "y=x", 0, 1, 3, 6, y);
+
+        y[1] = 5;
+        let to = CurveParams::new("curve", "y=x", 0, 1, 2, 6, y);
+
+        let names = FieldChange::diff(&from, &to)
+            .into_iter()
+            .map(|change| change.field)
+            .collect::<Vec<_>>();
+        assert_eq!(names, ["y_count", "y[1]", "y[2]"]);
+        assert!(FieldChange::diff(&from, &from).is_empty());
+    }
+}
@@ -0,0 +1,139 @@
+//! Curve data hosted over HTTP(S), e.g. by a model service driving curve updates.
+
+use std::time::Duration;
+
+use anyhow::{bail, Context, Result};
+use reqwest::header::{HeaderName, ETAG, IF_MODIFIED_SINCE, IF_NONE_MATCH, LAST_MODIFIED};
+use reqwest::StatusCode;
+
+use crate::commands::{parse_samples, verify_sha256, Samples};
+use crate::lint::lint_csv;
+
+/// Retry policy of transient failures (connection errors, timeouts, 429 and 5xx responses)
+#[derive(Debug, Clone)]
+pub struct Retry {
+    /// Total number of attempts including the first one
+    pub attempts: u32,
+    pub initial_backoff: Duration,
+    pub max_backoff: Duration,
+}
+
+impl Default for Retry {
+    fn default() -> Self {
+        Self {
+            attempts: 4,
+            initial_backoff: Duration::from_millis(500),
+            max_backoff: Duration::from_secs(10),
+        }
+    }
+}
+
+/// CSV curve data (`x,f_x` columns) at the URL. Remembers `ETag` / `Last-Modified` of the last
+/// valid document, so polling it only downloads and validates changed data.
+pub struct HttpSource {
+    pub url: String,
+    /// Expected SHA-256 of the document (hex)
+    pub sha256: Option<String>,
+    /// Expected number of `f_x` decimals, not checked when unknown
+    pub decimals: Option<u8>,
+    pub retry: Retry,
+    etag: Option<String>,
+    last_modified: Option<String>,
+    client: reqwest::Client,
+}
+
+impl HttpSource {
+    pub fn new(url: impl Into<String>) -> Self {
+        Self {
+            url: url.into(),
+            sha256: None,
+            decimals: None,
+            retry: Retry::default(),
+            etag: None,
+            last_modified: None,
+            client: reqwest::Client::new(),
+        }
+    }
+
+    /// Fetches the document if it changed since the last successful call, `None` means it is
+    /// not modified. Invalid documents are rejected and fetched again next time.
+    pub async fn fetch(&mut self) -> Result<Option<Samples>> {
+        let response = self.request().await?;
+        if response.status() == StatusCode::NOT_MODIFIED {
+            return Ok(None);
+        }
+
+        let header = |name: HeaderName| {
+            response
+                .headers()
+                .get(name)
+                .and_then(|value| value.to_str().ok())
+                .map(str::to_string)
+        };
+        let etag = header(ETAG);
+        let last_modified = header(LAST_MODIFIED);
+
+        let data = response
+            .bytes()
+            .await
+            .with_context(|| format!("reading {}", self.url))?;
+
+        if let Some(expected) = &self.sha256 {
+            verify_sha256(&data, expected)?;
+        }
+
+        let violations = lint_csv(csv::Reader::from_reader(&data[..]), self.decimals)?;
+        if !violations.is_empty() {
+            bail!(
+                "invalid curve data at {}:\n{}",
+                self.url,
+                violations.join("\n")
+            );
+        }
+
+        let samples = parse_samples(csv::Reader::from_reader(&data[..]))?;
+
+        self.etag = etag;
+        self.last_modified = last_modified;
+
+        Ok(Some(samples))
+    }
+
+    async fn request(&self) -> Result<reqwest::Response> {
+        let mut backoff = self.retry.initial_backoff;
+        let mut attempt = 1;
+
+        loop {
+            let mut request = self.client.get(&self.url);
+            if let Some(etag) = &self.etag {
+                request = request.header(IF_NONE_MATCH, etag);
+            }
+            if let Some(last_modified) = &self.last_modified {
+                request = request.header(IF_MODIFIED_SINCE, last_modified);
+            }
+
+            let result = request
+                .send()
+                .await
+                .and_then(reqwest::Response::error_for_status);
+
+            match result {
+                Ok(response) => return Ok(response),
+                Err(err) if attempt < self.retry.attempts && is_transient(&err) => {
+                    tracing::warn!("fetching {} (attempt {attempt}): {err}", self.url);
+                    tokio::time::sleep(backoff).await;
+                    backoff = (backoff * 2).min(self.retry.max_backoff);
+                    attempt += 1;
+                }
+                Err(err) => return Err(err).with_context(|| format!("fetching {}", self.url)),
+            }
+        }
+    }
+}
+
+fn is_transient(err: &reqwest::Error) -> bool {
+    match err.status() {
+        Some(status) => status.is_server_error() || status == StatusCode::TOO_MANY_REQUESTS,
+        None => err.is_timeout() || err.is_connect() || err.is_request(),
+    }
+}
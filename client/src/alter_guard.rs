@@ -0,0 +1,129 @@
+//! Client side guard against rapid repeated alters of the same curve, e.g. a misbehaving bot or
+//! a script run in a loop.
+
+use std::collections::{HashMap, VecDeque};
+use std::path::PathBuf;
+use std::str::FromStr;
+use std::sync::Mutex;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use anyhow::{anyhow, Context, Result};
+use solana_sdk::pubkey::Pubkey;
+
+use crate::CurvyClientError;
+
+/// Tracks alter times per curve and detects more than `max_alters` alters in `window`
+pub struct AlterGuard {
+    pub max_alters: usize,
+    pub window: Duration,
+    /// Fail with [CurvyClientError::TooManyAlters] unless forced. Only warn otherwise.
+    pub enforce: bool,
+    /// File to keep the history in between runs (JSON). In-process only when `None`.
+    pub path: Option<PathBuf>,
+    history: Mutex<HashMap<Pubkey, VecDeque<u64>>>,
+}
+
+impl AlterGuard {
+    pub fn new(max_alters: usize, window: Duration) -> Self {
+        Self {
+            max_alters,
+            window,
+            enforce: false,
+            path: None,
+            history: Mutex::default(),
+        }
+    }
+
+    /// Guard with history persisted in `path`. Missing file means empty history.
+    pub fn persisted(max_alters: usize, window: Duration, path: PathBuf) -> Result<Self> {
+        let mut history = HashMap::new();
+
+        if path.exists() {
+            let data = std::fs::read_to_string(&path)
+                .with_context(|| format!("reading alter history {}", path.display()))?;
+            let stored: HashMap<String, VecDeque<u64>> = serde_json::from_str(&data)
+                .with_context(|| format!("parsing alter history {}", path.display()))?;
+
+            for (curve, times) in stored {
+                let curve = Pubkey::from_str(&curve)
+                    .map_err(|err| anyhow!("alter history curve `{curve}`: {err}"))?;
+                history.insert(curve, times);
+            }
+        }
+
+        Ok(Self {
+            path: Some(path),
+            history: Mutex::new(history),
+            ..Self::new(max_alters, window)
+        })
+    }
+
+    /// Checks one more alter of `curve` is within the limit
+    pub fn check(&self, curve: &Pubkey, force: bool) -> Result<()> {
+        let count = self.recent(curve, now());
+        if count < self.max_alters {
+            return Ok(());
+        }
+
+        let err = CurvyClientError::TooManyAlters {
+            curve: *curve,
+            count,
+            window: self.window,
+        };
+
+        if self.enforce && !force {
+            return Err(err.into());
+        }
+
+        tracing::warn!("{err}");
+        Ok(())
+    }
+
+    /// Records alter of `curve` made now
+    pub fn record(&self, curve: &Pubkey) -> Result<()> {
+        let now = now();
+        let mut history = self.history.lock().expect("alter history lock");
+
+        let times = history.entry(*curve).or_default();
+        times.push_back(now);
+        prune(times, now, self.window);
+
+        let Some(path) = &self.path else {
+            return Ok(());
+        };
+
+        let stored = history
+            .iter()
+            .map(|(curve, times)| (curve.to_string(), times))
+            .collect::<HashMap<_, _>>();
+
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir)?;
+        }
+        std::fs::write(path, serde_json::to_string(&stored)?)
+            .with_context(|| format!("writing alter history {}", path.display()))
+    }
+
+    /// Number of alters of `curve` within the window
+    fn recent(&self, curve: &Pubkey, now: u64) -> usize {
+        let mut history = self.history.lock().expect("alter history lock");
+
+        history.get_mut(curve).map_or(0, |times| {
+            prune(times, now, self.window);
+            times.len()
+        })
+    }
+}
+
+fn prune(times: &mut VecDeque<u64>, now: u64, window: Duration) {
+    let since = now.saturating_sub(window.as_secs());
+    while times.front().is_some_and(|time| *time <= since) {
+        times.pop_front();
+    }
+}
+
+fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |since| since.as_secs())
+}
@@ -0,0 +1,192 @@
+//! Policy gate invoked before any mutating transaction is sent.
+
+use std::future::Future;
+use std::io::{BufRead, Write};
+use std::pin::Pin;
+
+use solana_sdk::instruction::Instruction;
+use solana_sdk::pubkey::Pubkey;
+
+use curvy::state::curve::CurveParams;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum OperationKind {
+    CreateCurve,
+    ReserveCurve,
+    AlterCurve,
+    UpdateCurveMetadata,
+    UpdateCurveData,
+    DeleteCurve,
+    TopUpRent,
+    SetCurveAsset,
+    ClearCurveAsset,
+    MigrateCurve,
+    SetCurveSeries,
+    DeleteCurveSeries,
+    SetCurveConfidence,
+    DeleteCurveConfidence,
+    CreateWideCurve,
+    AlterWideCurve,
+    SetCurveXSign,
+    SetCurveYSign,
+    ProposeOwner,
+    AcceptOwner,
+    FreezeCurve,
+    SetCurveTail,
+    CreateGridCurve,
+    AlterGridCurve,
+    SetCurveStatus,
+    PatchY,
+    MarkForDeletion,
+    CancelDeletion,
+    EvaluateCurve,
+    SetAlterDelay,
+    ProposeAlter,
+    ApplyAlter,
+    CancelAlter,
+    AddDelegate,
+    RemoveDelegate,
+    InitConfig,
+    SetConfig,
+    SetCurveOwners,
+    SetCurveExtension,
+    SetCurveYTransform,
+    SetCurveExpiry,
+    BatchAlter,
+    CreateReferenceCurve,
+    SetCreationFrozen,
+}
+
+impl OperationKind {
+    /// Operations creating curves, refused while creation is frozen by the config admin
+    pub fn is_creation(self) -> bool {
+        matches!(
+            self,
+            Self::CreateCurve
+                | Self::ReserveCurve
+                | Self::CreateWideCurve
+                | Self::CreateGridCurve
+                | Self::CreateReferenceCurve
+        )
+    }
+}
+
+/// Mutating operation about to be sent
+#[serde_with::serde_as]
+#[derive(Debug, serde::Serialize)]
+pub struct PreparedOperation {
+    pub kind: OperationKind,
+    #[serde_as(as = "serde_with::DisplayFromStr")]
+    pub curve: Pubkey,
+    #[serde_as(as = "serde_with::DisplayFromStr")]
+    pub authority: Pubkey,
+    /// New curve params for create and alter operations
+    pub params: Option<CurveParams>,
+    #[serde(skip)]
+    pub instructions: Vec<Instruction>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Approval {
+    Approved,
+    Rejected { reason: String },
+}
+
+pub type ApprovalFuture<'a> = Pin<Box<dyn Future<Output = Approval> + Send + 'a>>;
+
+/// Decides whether prepared operation may be sent
+pub trait ApprovalHook: Send + Sync {
+    fn approve<'a>(&'a self, operation: &'a PreparedOperation) -> ApprovalFuture<'a>;
+}
+
+impl<F> ApprovalHook for F
+where
+    F: Fn(&PreparedOperation) -> Approval + Send + Sync,
+{
+    fn approve<'a>(&'a self, operation: &'a PreparedOperation) -> ApprovalFuture<'a> {
+        let approval = self(operation);
+        Box::pin(async move { approval })
+    }
+}
+
+/// Asks operator in the terminal. The prompt blocks on stdin, so it runs on the blocking pool
+pub struct TerminalApproval;
+
+impl ApprovalHook for TerminalApproval {
+    fn approve<'a>(&'a self, operation: &'a PreparedOperation) -> ApprovalFuture<'a> {
+        let (kind, curve, authority) = (operation.kind, operation.curve, operation.authority);
+        Box::pin(async move {
+            tokio::task::spawn_blocking(move || -> std::io::Result<Approval> {
+                eprintln!("Approve {kind:?} of curve {curve} by {authority}? [y/N]: ");
+                std::io::stderr().flush()?;
+
+                let mut answer = String::new();
+                std::io::stdin().lock().read_line(&mut answer)?;
+
+                Ok(match answer.trim().to_lowercase().as_str() {
+                    "y" | "yes" => Approval::Approved,
+                    _ => Approval::Rejected {
+                        reason: "rejected by operator".to_string(),
+                    },
+                })
+            })
+            .await
+            .map_err(std::io::Error::other)
+            .and_then(|answer| answer)
+            .unwrap_or_else(|err| Approval::Rejected {
+                reason: format!("terminal: {err}"),
+            })
+        })
+    }
+}
+
+/// Posts the operation as JSON to `url` and waits for the decision `{"approved": bool,
+/// "reason": "..."}`. Intended for approval bots (e.g. Slack apps) which reply once a reviewer
+/// decides.
+pub struct WebhookApproval {
+    pub url: String,
+    pub client: reqwest::Client,
+}
+
+#[derive(serde::Deserialize)]
+struct WebhookDecision {
+    approved: bool,
+    reason: Option<String>,
+}
+
+impl WebhookApproval {
+    pub fn new(url: impl Into<String>) -> Self {
+        Self {
+            url: url.into(),
+            client: reqwest::Client::new(),
+        }
+    }
+
+    async fn request(&self, operation: &PreparedOperation) -> reqwest::Result<WebhookDecision> {
+        self.client
+            .post(&self.url)
+            .json(operation)
+            .send()
+            .await?
+            .error_for_status()?
+            .json()
+            .await
+    }
+}
+
+impl ApprovalHook for WebhookApproval {
+    fn approve<'a>(&'a self, operation: &'a PreparedOperation) -> ApprovalFuture<'a> {
+        Box::pin(async move {
+            match self.request(operation).await {
+                Ok(WebhookDecision { approved: true, .. }) => Approval::Approved,
+                Ok(WebhookDecision { reason, .. }) => Approval::Rejected {
+                    reason: reason.unwrap_or_else(|| "rejected by approver".to_string()),
+                },
+                Err(err) => Approval::Rejected {
+                    reason: format!("approval webhook: {err}"),
+                },
+            }
+        })
+    }
+}
@@ -0,0 +1,85 @@
+use std::io::IsTerminal;
+use std::time::{Duration, Instant};
+
+use anyhow::Result;
+use solana_client::nonblocking::rpc_client::RpcClient;
+use solana_sdk::commitment_config::CommitmentConfig;
+use solana_sdk::signature::Signature;
+
+use crate::CurvyClientError;
+
+/// How sent transactions are confirmed
+#[derive(Debug, Clone, Copy, Default)]
+pub enum Confirmation {
+    /// Spinner when stderr is a terminal, polling with default settings otherwise
+    #[default]
+    Auto,
+    /// Interactive spinner on stderr
+    Spinner,
+    /// Plain signature status polling without any output
+    Polling {
+        interval: Duration,
+        timeout: Duration,
+    },
+}
+
+impl Confirmation {
+    pub const DEFAULT_POLL_INTERVAL: Duration = Duration::from_millis(500);
+    pub const DEFAULT_TIMEOUT: Duration = Duration::from_secs(90);
+
+    pub fn polling() -> Self {
+        Self::Polling {
+            interval: Self::DEFAULT_POLL_INTERVAL,
+            timeout: Self::DEFAULT_TIMEOUT,
+        }
+    }
+
+    /// Resolves [Confirmation::Auto] for current process
+    pub fn resolve(self) -> Self {
+        match self {
+            Self::Auto if std::io::stderr().is_terminal() => Self::Spinner,
+            Self::Auto => Self::polling(),
+            other => other,
+        }
+    }
+}
+
+/// Polls signature status until it reaches `commitment`, fails or `timeout` elapses
+pub async fn poll_confirmation(
+    rpc: &RpcClient,
+    signature: &Signature,
+    commitment: CommitmentConfig,
+    interval: Duration,
+    timeout: Duration,
+) -> Result<()> {
+    let started = Instant::now();
+
+    loop {
+        let status = rpc
+            .get_signature_statuses(&[*signature])
+            .await?
+            .value
+            .pop()
+            .flatten();
+
+        if let Some(status) = status {
+            if let Some(err) = status.err {
+                return Err(err.into());
+            }
+
+            if status.satisfies_commitment(commitment) {
+                return Ok(());
+            }
+        }
+
+        if started.elapsed() >= timeout {
+            return Err(CurvyClientError::ConfirmationTimeout {
+                signature: *signature,
+                timeout,
+            }
+            .into());
+        }
+
+        tokio::time::sleep(interval).await;
+    }
+}
@@ -0,0 +1,338 @@
+//! Certification of consumer programs embedding their own curve evaluator: the consumer
+//! instruction is simulated in sampled `x` points and its `y` is compared with the one
+//! calculated locally ([crate::CurveView::calc_y]).
+
+use anyhow::{anyhow, bail, Context, Result};
+use solana_account_decoder::UiAccountEncoding;
+use solana_client::rpc_config::{
+    RpcSimulateTransactionAccountsConfig, RpcSimulateTransactionConfig,
+};
+use solana_sdk::account::Account;
+use solana_sdk::instruction::{AccountMeta, Instruction};
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signer::Signer;
+use solana_sdk::transaction::Transaction;
+use texture_common::math::{CheckedSub, Decimal};
+
+use crate::CurvyClient;
+
+/// How to call the consumer evaluator and where to find its result
+pub trait ConsumerAdapter {
+    /// Decimals of `x` passed to the program, sampled points are rounded down to them
+    fn x_decimals(&self) -> u32;
+
+    /// Instruction evaluating `curve` in `x` (with [ConsumerAdapter::x_decimals])
+    fn instruction(&self, curve: &Pubkey, x: u64) -> Result<Instruction>;
+
+    /// Account holding the result once the instruction is executed
+    fn result_account(&self) -> Pubkey;
+
+    /// Reads `y` from the result account data
+    fn decode_y(&self, data: &[u8]) -> Result<Decimal>;
+}
+
+/// Account of the consumer instruction
+#[serde_with::serde_as]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct ConsumerAccount {
+    /// `None` (`null`) for the curve being checked
+    #[serde_as(as = "Option<serde_with::DisplayFromStr>")]
+    pub pubkey: Option<Pubkey>,
+    #[serde(default)]
+    pub writable: bool,
+}
+
+/// Adapter described by the instruction and result layouts, kept in JSON. Instruction data is
+/// `data_prefix` followed by `x` as u64 LE, `y` is u64 LE at `y_offset` of the result account.
+///
+/// Layout of `examples/consumer` with an existing evaluation account:
+/// `{"program": "3SLoYkDBzi3R2GbM7vTdvDmsQjecFohnbJc8HfYZsqXG", "data_prefix": "00",
+/// "x_decimals": 9, "accounts": [{"pubkey": null}, {"pubkey": "<evaluation>", "writable": true}],
+/// "result_account": "<evaluation>", "y_offset": 40, "y_decimals": 9}`
+#[serde_with::serde_as]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct ConsumerLayout {
+    #[serde_as(as = "serde_with::DisplayFromStr")]
+    pub program: Pubkey,
+    /// Hex encoded instruction data preceding `x`, e.g. borsh enum tag
+    #[serde(default)]
+    pub data_prefix: String,
+    pub x_decimals: u32,
+    pub accounts: Vec<ConsumerAccount>,
+    #[serde_as(as = "serde_with::DisplayFromStr")]
+    pub result_account: Pubkey,
+    pub y_offset: usize,
+    pub y_decimals: u32,
+}
+
+impl ConsumerAdapter for ConsumerLayout {
+    fn x_decimals(&self) -> u32 {
+        self.x_decimals
+    }
+
+    fn instruction(&self, curve: &Pubkey, x: u64) -> Result<Instruction> {
+        if self.data_prefix.len() & 1 != 0 {
+            bail!("data prefix `{}` is not hex", self.data_prefix);
+        }
+        let mut data = (0..self.data_prefix.len())
+            .step_by(2)
+            .map(|idx| u8::from_str_radix(&self.data_prefix[idx..idx + 2], 16))
+            .collect::<Result<Vec<_>, _>>()
+            .with_context(|| format!("data prefix `{}` is not hex", self.data_prefix))?;
+        data.extend_from_slice(&x.to_le_bytes());
+
+        let accounts = self
+            .accounts
+            .iter()
+            .map(|account| {
+                let pubkey = account.pubkey.unwrap_or(*curve);
+                if account.writable {
+                    AccountMeta::new(pubkey, false)
+                } else {
+                    AccountMeta::new_readonly(pubkey, false)
+                }
+            })
+            .collect();
+
+        Ok(Instruction::new_with_bytes(self.program, &data, accounts))
+    }
+
+    fn result_account(&self) -> Pubkey {
+        self.result_account
+    }
+
+    fn decode_y(&self, data: &[u8]) -> Result<Decimal> {
+        let bytes = data
+            .get(self.y_offset..self.y_offset + 8)
+            .ok_or_else(|| anyhow!("result account is too short: {} bytes", data.len()))?;
+        let y = u64::from_le_bytes(bytes.try_into()?);
+
+        Ok(Decimal::from_i128_with_scale(y as i128, self.y_decimals)?)
+    }
+}
+
+/// Point where evaluations differ by more than the tolerance or one of them failed
+#[serde_with::serde_as]
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct Divergence {
+    #[serde_as(as = "serde_with::DisplayFromStr")]
+    pub x: Decimal,
+    #[serde_as(as = "Option<serde_with::DisplayFromStr>")]
+    pub ours: Option<Decimal>,
+    #[serde_as(as = "Option<serde_with::DisplayFromStr>")]
+    pub theirs: Option<Decimal>,
+    pub error: Option<String>,
+}
+
+#[serde_with::serde_as]
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct ConsistencyReport {
+    #[serde_as(as = "serde_with::DisplayFromStr")]
+    pub curve: Pubkey,
+    #[serde_as(as = "serde_with::DisplayFromStr")]
+    pub program: Pubkey,
+    pub samples: usize,
+    #[serde_as(as = "serde_with::DisplayFromStr")]
+    pub tolerance: Decimal,
+    /// Max difference over points evaluated by both
+    #[serde_as(as = "serde_with::DisplayFromStr")]
+    pub max_diff: Decimal,
+    pub divergences: Vec<Divergence>,
+}
+
+impl ConsistencyReport {
+    pub fn is_ok(&self) -> bool {
+        self.divergences.is_empty()
+    }
+}
+
+/// Sampled `x` points in curve units: `per_segment` evenly spaced points in every segment
+/// between `count` samples starting at `x0`, and the last sample
+pub fn sample_points(x0: i64, x_step: u32, count: usize, per_segment: u32) -> Vec<i64> {
+    let per_segment = per_segment.max(1) as i64;
+    let x_step = x_step as i64;
+
+    let mut points = (0..count.saturating_sub(1) as i64)
+        .flat_map(|segment| {
+            (0..per_segment).map(move |idx| x0 + segment * x_step + idx * x_step / per_segment)
+        })
+        .collect::<Vec<_>>();
+    if count > 0 {
+        points.push(x0 + (count as i64 - 1) * x_step);
+    }
+    points.dedup();
+
+    points
+}
+
+fn abs_diff(a: Decimal, b: Decimal) -> Result<Decimal> {
+    Ok(if a > b {
+        a.checked_sub(b)?
+    } else {
+        b.checked_sub(a)?
+    })
+}
+
+impl CurvyClient {
+    /// Evaluates `curve` by the consumer program behind `adapter` in `per_segment` points of
+    /// every segment (see [sample_points]) and reports points where its `y` differs from ours
+    /// by more than `tolerance`. Transactions are simulated only.
+    pub async fn check_consumer(
+        &self,
+        curve: &Pubkey,
+        adapter: &dyn ConsumerAdapter,
+        per_segment: u32,
+        tolerance: Decimal,
+    ) -> Result<ConsistencyReport> {
+        let view = self.curve(curve).await?;
+        let points = sample_points(
+            view.curve.x0_signed(),
+            view.curve.x_step,
+            view.samples().len(),
+            per_segment,
+        );
+        let program = adapter.instruction(curve, 0)?.program_id;
+
+        let mut report = ConsistencyReport {
+            curve: *curve,
+            program,
+            samples: 0,
+            tolerance,
+            max_diff: Decimal::ZERO,
+            divergences: vec![],
+        };
+
+        let scale = |decimals: u32| 10_i128.pow(decimals);
+        for x_raw in points {
+            if x_raw < 0 {
+                // consumers take unsigned `x`
+                continue;
+            }
+            let x_int =
+                x_raw as i128 * scale(adapter.x_decimals()) / scale(view.curve.x_decimals as u32);
+            let x = Decimal::from_i128_with_scale(x_int, adapter.x_decimals())?;
+            report.samples += 1;
+
+            let ours = view.calc_y(x).map_err(|err| err.to_string());
+            let theirs = self
+                .simulate_consumer(curve, adapter, x_int as u64)
+                .await
+                .map_err(|err| err.to_string());
+
+            let divergence = |error: Option<String>| Divergence {
+                x,
+                ours: ours.clone().ok(),
+                theirs: theirs.clone().ok(),
+                error,
+            };
+            match (&ours, &theirs) {
+                (Ok(ours), Ok(theirs)) => {
+                    let diff = abs_diff(*ours, *theirs)?;
+                    if diff > report.max_diff {
+                        report.max_diff = diff;
+                    }
+                    if diff > tolerance {
+                        report.divergences.push(divergence(None));
+                    }
+                }
+                // both reject points out of the curve range
+                (Err(_), Err(_)) => {}
+                (Err(err), _) => report
+                    .divergences
+                    .push(divergence(Some(format!("ours: {err}")))),
+                (_, Err(err)) => report
+                    .divergences
+                    .push(divergence(Some(format!("theirs: {err}")))),
+            }
+        }
+
+        Ok(report)
+    }
+
+    async fn simulate_consumer(
+        &self,
+        curve: &Pubkey,
+        adapter: &dyn ConsumerAdapter,
+        x: u64,
+    ) -> Result<Decimal> {
+        let ix = adapter.instruction(curve, x)?;
+        let tx = Transaction::new_with_payer(&[ix], Some(&self.authority.pubkey()));
+        let result_account = adapter.result_account();
+
+        let result = self
+            .rpc
+            .simulate_transaction_with_config(
+                &tx,
+                RpcSimulateTransactionConfig {
+                    sig_verify: false,
+                    replace_recent_blockhash: true,
+                    commitment: Some(self.rpc.commitment()),
+                    accounts: Some(RpcSimulateTransactionAccountsConfig {
+                        encoding: Some(UiAccountEncoding::Base64),
+                        addresses: vec![result_account.to_string()],
+                    }),
+                    ..Default::default()
+                },
+            )
+            .await?
+            .value;
+
+        if let Some(err) = result.err {
+            let log = result
+                .logs
+                .unwrap_or_default()
+                .into_iter()
+                .rev()
+                .find(|log| log.starts_with("Program log: "))
+                .unwrap_or_default();
+            bail!("{err} {log}");
+        }
+
+        let account: Account = result
+            .accounts
+            .and_then(|accounts| accounts.into_iter().next().flatten())
+            .and_then(|account| account.decode())
+            .ok_or_else(|| anyhow!("result account {result_account} is not returned"))?;
+
+        adapter.decode_y(&account.data)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn points_and_layout() {
+        assert_eq!(sample_points(0, 10, 3, 2), vec![0, 5, 10, 15, 20]);
+        assert_eq!(sample_points(-10, 10, 2, 1), vec![-10, 0]);
+        // steps finer than the split give repeated points
+        assert_eq!(sample_points(0, 1, 2, 4), vec![0, 1]);
+
+        let curve = Pubkey::new_unique();
+        let evaluation = Pubkey::new_unique();
+        let layout: ConsumerLayout = serde_json::from_value(serde_json::json!({
+            "program": Pubkey::new_unique().to_string(),
+            "data_prefix": "00",
+            "x_decimals": 9,
+            "accounts": [{"pubkey": null}, {"pubkey": evaluation.to_string(), "writable": true}],
+            "result_account": evaluation.to_string(),
+            "y_offset": 40,
+            "y_decimals": 9,
+        }))
+        .unwrap();
+
+        let ix = layout.instruction(&curve, 5).unwrap();
+        assert_eq!(ix.data, [0, 5, 0, 0, 0, 0, 0, 0, 0]);
+        assert_eq!(ix.accounts[0], AccountMeta::new_readonly(curve, false));
+        assert_eq!(ix.accounts[1], AccountMeta::new(evaluation, false));
+
+        let mut data = vec![0; 48];
+        data[40..].copy_from_slice(&1_500_000_000_u64.to_le_bytes());
+        assert_eq!(
+            layout.decode_y(&data).unwrap(),
+            Decimal::from_i128_with_scale(15, 1).unwrap()
+        );
+        assert!(layout.decode_y(&data[..44]).is_err());
+    }
+}
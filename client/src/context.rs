@@ -0,0 +1,48 @@
+//! Read results together with the chain state they were observed in, so consumers can apply
+//! their own freshness rules.
+
+use std::ops::Deref;
+
+use anyhow::Result;
+use solana_sdk::clock::Slot;
+use solana_sdk::commitment_config::CommitmentLevel;
+
+/// `value` read in `slot` with `commitment`
+#[derive(Debug, Clone, PartialEq)]
+pub struct WithContext<T> {
+    pub value: T,
+    pub slot: Slot,
+    pub commitment: CommitmentLevel,
+}
+
+impl<T> WithContext<T> {
+    pub fn new(value: T, slot: Slot, commitment: CommitmentLevel) -> Self {
+        Self {
+            value,
+            slot,
+            commitment,
+        }
+    }
+
+    pub fn into_inner(self) -> T {
+        self.value
+    }
+
+    /// Same context with the value mapped by `f`
+    pub fn map<U>(self, f: impl FnOnce(T) -> U) -> WithContext<U> {
+        WithContext::new(f(self.value), self.slot, self.commitment)
+    }
+
+    /// Same as [WithContext::map] for fallible `f`
+    pub fn try_map<U>(self, f: impl FnOnce(T) -> Result<U>) -> Result<WithContext<U>> {
+        Ok(WithContext::new(f(self.value)?, self.slot, self.commitment))
+    }
+}
+
+impl<T> Deref for WithContext<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.value
+    }
+}
@@ -0,0 +1,83 @@
+//! Decoding of Curve accounts delivered by generic infrastructure (getProgramAccounts results,
+//! webhooks, account streams) without refetching raw data.
+
+use std::str::FromStr;
+
+use anyhow::{anyhow, bail, Result};
+use solana_account_decoder::{UiAccount, UiAccountData, UiAccountEncoding};
+use solana_client::rpc_response::RpcKeyedAccount;
+use solana_sdk::account::Account;
+use solana_sdk::pubkey::Pubkey;
+
+use curvy::state::curve::Curve;
+use curvy_utils::curve_from_bytes;
+
+use crate::CurveView;
+
+/// Decodes Curve from raw account checking it is owned by Curvy program.
+pub fn curve_from_account(account: &Account) -> Result<Curve> {
+    if account.owner != curvy::ID {
+        bail!("account is owned by {} not by Curvy program", account.owner);
+    }
+
+    curve_from_bytes(&account.data)
+}
+
+/// Decodes Curve from RPC account in any binary encoding. `jsonParsed` requests return Curvy
+/// accounts base64 encoded, so they are supported as well.
+pub fn curve_from_ui_account(account: &UiAccount) -> Result<Curve> {
+    let account: Account = account
+        .decode()
+        .ok_or_else(|| anyhow!("unsupported account data encoding"))?;
+
+    curve_from_account(&account)
+}
+
+/// Decodes Curve from encoded account data string (without owner check).
+pub fn curve_from_encoded(data: &str, encoding: UiAccountEncoding) -> Result<Curve> {
+    let bytes = UiAccountData::Binary(data.to_string(), encoding)
+        .decode()
+        .ok_or_else(|| anyhow!("can not decode account data as {:?}", encoding))?;
+
+    curve_from_bytes(&bytes)
+}
+
+impl CurveView {
+    /// Decodes curve keeping account level state (balance, rent epoch etc.)
+    pub fn from_account(key: Pubkey, account: &Account) -> Result<Self> {
+        let curve = curve_from_account(account)?;
+
+        Ok(Self {
+            key,
+            tail: curve.tail_y(&account.data),
+            extensions: curve.extensions(&account.data).to_vec(),
+            curve,
+            account: Some(account.into()),
+        })
+    }
+
+    pub fn try_from_ui_account(key: Pubkey, account: &UiAccount) -> Result<Self> {
+        let account: Account = account
+            .decode()
+            .ok_or_else(|| anyhow!("unsupported account data encoding"))?;
+
+        Self::from_account(key, &account)
+    }
+}
+
+impl TryFrom<&RpcKeyedAccount> for CurveView {
+    type Error = anyhow::Error;
+
+    fn try_from(keyed: &RpcKeyedAccount) -> Result<Self> {
+        let key = Pubkey::from_str(&keyed.pubkey)?;
+        Self::try_from_ui_account(key, &keyed.account)
+    }
+}
+
+impl TryFrom<RpcKeyedAccount> for CurveView {
+    type Error = anyhow::Error;
+
+    fn try_from(keyed: RpcKeyedAccount) -> Result<Self> {
+        Self::try_from(&keyed)
+    }
+}
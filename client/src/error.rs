@@ -0,0 +1,149 @@
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+use solana_sdk::instruction::InstructionError;
+use std::time::Duration;
+
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::Signature;
+use solana_sdk::transaction::TransactionError;
+
+use curvy::error::{CurvyError, ErrorCode, ERROR_CODES};
+
+use crate::approval::OperationKind;
+
+/// Errors detected by the client itself rather than returned by RPC
+#[derive(Debug, thiserror::Error)]
+pub enum CurvyClientError {
+    #[error("curve {curve} is owned by {owner}, not by authority {authority}")]
+    NotOwner {
+        curve: Pubkey,
+        owner: Pubkey,
+        authority: Pubkey,
+    },
+
+    #[error("authority {authority} is not the proposed owner of curve {curve}")]
+    NotPendingOwner { curve: Pubkey, authority: Pubkey },
+
+    #[error("{kind:?} of curve {curve} rejected: {reason}")]
+    Rejected {
+        kind: OperationKind,
+        curve: Pubkey,
+        reason: String,
+    },
+
+    #[error("curve {curve} was altered {count} times in the last {window:?}, use force to alter it anyway")]
+    TooManyAlters {
+        curve: Pubkey,
+        count: usize,
+        window: Duration,
+    },
+
+    #[error("asset {asset_mint} kind {kind} is associated with curve {curve} already")]
+    AssetTaken {
+        asset_mint: Pubkey,
+        kind: u8,
+        curve: Pubkey,
+    },
+
+    #[error("curve {curve} is at revision {actual}, not {expected}: it was altered concurrently")]
+    RevisionMismatch {
+        curve: Pubkey,
+        expected: u64,
+        actual: u64,
+    },
+
+    #[error(
+        "curve creation is frozen by the config admin, {kind:?} is not sent until it is unfrozen"
+    )]
+    CreationFrozen { kind: OperationKind },
+
+    #[error("transaction {signature} is not confirmed in {timeout:?}")]
+    ConfirmationTimeout {
+        signature: Signature,
+        timeout: Duration,
+    },
+}
+
+/// Custom error codes of the Curvy program with variant names and descriptions, ordered by code
+pub fn error_table() -> &'static [ErrorCode] {
+    ERROR_CODES
+}
+
+/// Decoded transaction simulation failure, attached as context to errors returned by
+/// [crate::with_logs]. Use `error.downcast_ref::<PreflightFailure>()` to inspect it.
+#[derive(Debug, Clone)]
+pub struct PreflightFailure {
+    /// Program which failed (the last `Program <id> failed` log line)
+    pub program: Option<Pubkey>,
+    /// Index of the failed instruction in the transaction
+    pub instruction: Option<u8>,
+    pub error: Option<TransactionError>,
+    /// Custom program error code
+    pub code: Option<u32>,
+    /// [CurvyError] variant name when the failing program is Curvy
+    pub curvy_error: Option<&'static str>,
+    pub logs: Vec<String>,
+}
+
+impl PreflightFailure {
+    pub fn new(error: Option<TransactionError>, logs: Vec<String>) -> Self {
+        let (instruction, code) = match &error {
+            Some(TransactionError::InstructionError(idx, InstructionError::Custom(code))) => {
+                (Some(*idx), Some(*code))
+            }
+            Some(TransactionError::InstructionError(idx, _)) => (Some(*idx), None),
+            _ => (None, None),
+        };
+
+        let program = logs.iter().rev().find_map(|log| {
+            let rest = log.strip_prefix("Program ")?;
+            let (program, _) = rest.split_once(" failed")?;
+            Pubkey::from_str(program).ok()
+        });
+
+        let curvy_error = code
+            .filter(|_| program == Some(curvy::ID))
+            .and_then(CurvyError::name_by_code);
+
+        Self {
+            program,
+            instruction,
+            error,
+            code,
+            curvy_error,
+            logs,
+        }
+    }
+
+    /// Curve account has a layout version the program does not support, it has to be migrated
+    pub fn is_unsupported_version(&self) -> bool {
+        self.curvy_error == Some("UnsupportedVersion")
+    }
+}
+
+impl Display for PreflightFailure {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "preflight failure")?;
+
+        if let Some(instruction) = self.instruction {
+            write!(f, " in instruction #{instruction}")?;
+        }
+        if let Some(program) = self.program {
+            write!(f, " of program {program}")?;
+        }
+        match (self.curvy_error, self.code, &self.error) {
+            (Some(name), Some(code), _) => write!(f, ": {name} ({code})")?,
+            (None, Some(code), _) => write!(f, ": custom error {code}")?,
+            (_, None, Some(error)) => write!(f, ": {error}")?,
+            _ => {}
+        }
+
+        writeln!(f, "\nLogs:")?;
+        for (i, log) in self.logs.iter().enumerate() {
+            writeln!(f, "    {:>3}: {}", i + 1, log)?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,203 @@
+//! Curve versions rebuilt from Curvy instructions of the curve account transactions, the source
+//! of time-weighted averages, see [curvy_utils::twa_curve].
+
+use solana_sdk::clock::UnixTimestamp;
+use texture_common::account::PodAccount;
+
+use curvy::instruction::CurvyInstruction;
+use curvy::state::curve::{Curve, CurveParams};
+use curvy_utils::CurveVersion;
+
+/// Whether `instruction` sets all data of the curve, so versions can be rebuilt from it on
+pub fn sets_curve_data(instruction: &CurvyInstruction) -> bool {
+    matches!(
+        instruction,
+        CurvyInstruction::CreateCurve { .. }
+            | CurvyInstruction::CreateCurvePda { .. }
+            | CurvyInstruction::CreateReferenceCurve { .. }
+            | CurvyInstruction::AlterCurve { .. }
+            | CurvyInstruction::UpdateCurveData { .. }
+    )
+}
+
+/// Replays `operations` (block time and instruction on the curve, oldest first) into curve
+/// versions. Versions start at the first operation setting all curve data (see
+/// [sets_curve_data]), others before it are skipped. Fields not carried by instructions (owner,
+/// `x0` and `y` signs, `y` transform and expiry until SetCurveXSign, SetCurveYSign,
+/// SetCurveYTransform and SetCurveExpiry) are taken from the `current` curve. Sample updates
+/// move `valid_until` as on chain, see [Curve::refresh_validity].
+pub fn replay_history(
+    current: &Curve,
+    operations: &[(UnixTimestamp, CurvyInstruction)],
+) -> Vec<CurveVersion> {
+    let mut versions: Vec<CurveVersion> = vec![];
+
+    for (since, instruction) in operations {
+        let previous = versions.last().map(|version| version.curve);
+
+        let curve = match (instruction, previous) {
+            (
+                CurvyInstruction::CreateCurve { params }
+                | CurvyInstruction::CreateCurvePda { params }
+                | CurvyInstruction::CreateReferenceCurve { params, .. },
+                _,
+            ) => Curve::from_init_params((*params, current.owner)),
+            (CurvyInstruction::AlterCurve { params }, previous) => {
+                let mut curve = previous.unwrap_or(*current);
+                curve.set_params(*params);
+                curve.refresh_validity(*since);
+                curve
+            }
+            (
+                CurvyInstruction::UpdateCurveData {
+                    x0,
+                    x_step,
+                    y_count,
+                    decimals,
+                    y,
+                },
+                previous,
+            ) => {
+                let mut curve = previous.unwrap_or(*current);
+                curve.set_params(CurveParams {
+                    x0: *x0,
+                    x_step: *x_step,
+                    y_count: *y_count,
+                    x_decimals: *decimals,
+                    y_decimals: *decimals,
+                    y: *y,
+                    ..CurveParams::from(&curve)
+                });
+                curve.refresh_validity(*since);
+                curve
+            }
+            (CurvyInstruction::SetCurveXSign { x0_negative }, Some(mut curve)) => {
+                curve.x0_negative = *x0_negative as u8;
+                curve
+            }
+            (CurvyInstruction::SetCurveYSign { y_signed }, Some(mut curve)) => {
+                curve.y_signed = *y_signed as u8;
+                curve
+            }
+            (CurvyInstruction::SetCurveYTransform { y_transform }, Some(mut curve)) => {
+                curve.y_transform = *y_transform;
+                curve
+            }
+            (
+                CurvyInstruction::SetCurveExpiry {
+                    valid_until,
+                    validity_period,
+                },
+                Some(mut curve),
+            ) => {
+                // failed on chain as well
+                if curve.set_expiry(*valid_until, *validity_period).is_err() {
+                    continue;
+                }
+                curve
+            }
+            (
+                CurvyInstruction::PatchY {
+                    start_index,
+                    values,
+                },
+                Some(mut curve),
+            ) => {
+                // failed on chain as well
+                if curve.patch_y(*start_index as usize, values).is_err() {
+                    continue;
+                }
+                curve.refresh_validity(*since);
+                curve
+            }
+            _ => continue,
+        };
+
+        versions.push(CurveVersion {
+            since: *since,
+            curve,
+        });
+    }
+
+    versions
+}
+
+#[cfg(test)]
+mod tests {
+    use solana_sdk::pubkey::Pubkey;
+
+    use curvy::state::curve::MAX_Y_CNT;
+
+    use super::*;
+
+    #[test]
+    fn versions_start_at_full_data() {
+        let params = |y| CurveParams::new("SOL-borrow", "y=x", 10, 10, 3, 2, [y; MAX_Y_CNT]);
+        let mut current = Curve::from_init_params((params(3), Pubkey::new_unique()));
+        current.x0_negative = 1;
+
+        let operations = [
+            (5, CurvyInstruction::SetCurveXSign { x0_negative: false }),
+            (10, CurvyInstruction::AlterCurve { params: params(1) }),
+            (20, CurvyInstruction::TopUpRent),
+            (30, CurvyInstruction::SetCurveXSign { x0_negative: false }),
+            (
+                40,
+                CurvyInstruction::UpdateCurveData {
+                    x0: 0,
+                    x_step: 20,
+                    y_count: 2,
+                    decimals: 2,
+                    y: [2; MAX_Y_CNT],
+                },
+            ),
+            (
+                50,
+                CurvyInstruction::PatchY {
+                    start_index: 1,
+                    values: vec![5],
+                },
+            ),
+            (
+                55,
+                CurvyInstruction::SetCurveExpiry {
+                    valid_until: 100,
+                    validity_period: 30,
+                },
+            ),
+            (
+                58,
+                CurvyInstruction::PatchY {
+                    start_index: 0,
+                    values: vec![4],
+                },
+            ),
+            // out of the table, failed on chain
+            (
+                60,
+                CurvyInstruction::PatchY {
+                    start_index: 2,
+                    values: vec![5],
+                },
+            ),
+        ];
+
+        let versions = replay_history(&current, &operations);
+        let since = versions
+            .iter()
+            .map(|version| version.since)
+            .collect::<Vec<_>>();
+        assert_eq!(since, vec![10, 30, 40, 50, 55, 58]);
+
+        assert_eq!(versions[0].curve.owner, current.owner);
+        assert_eq!(versions[0].curve.x0_signed(), -10);
+        assert_eq!(versions[1].curve.x0_signed(), 10);
+        assert_eq!(versions[2].curve.x_step, 20);
+        assert_eq!(versions[2].curve.y[0], 2);
+        assert_eq!(&versions[2].curve.name, &current.name);
+        assert_eq!(&versions[3].curve.y[..2], &[2, 5]);
+        assert_eq!(versions[3].curve.valid_until(), None);
+        assert_eq!(versions[4].curve.valid_until(), Some(100));
+        assert_eq!(versions[5].curve.valid_until(), Some(88));
+    }
+}
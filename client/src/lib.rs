@@ -1,34 +1,524 @@
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+use std::sync::Arc;
 
 use anyhow::Result;
+use solana_account_decoder::{UiAccountEncoding, UiDataSliceConfig};
 use solana_client::client_error::{ClientError, ClientErrorKind};
 use solana_client::nonblocking::rpc_client::RpcClient;
-use solana_client::rpc_request::{RpcError, RpcResponseErrorData};
-use solana_client::rpc_response::RpcSimulateTransactionResult;
+use solana_client::rpc_client::GetConfirmedSignaturesForAddress2Config;
+use solana_client::rpc_config::{
+    RpcAccountInfoConfig, RpcProgramAccountsConfig, RpcSendTransactionConfig, RpcTransactionConfig,
+};
+use solana_client::rpc_filter::{Memcmp, RpcFilterType};
+use solana_client::rpc_request::{
+    RpcError, RpcRequest, RpcResponseErrorData, MAX_MULTIPLE_ACCOUNTS,
+};
+use solana_client::rpc_response::{OptionalContext, RpcKeyedAccount, RpcSimulateTransactionResult};
 use solana_sdk::account::Account;
-use solana_sdk::clock::Slot;
-use solana_sdk::compute_budget::ComputeBudgetInstruction;
-use solana_sdk::instruction::Instruction;
+use solana_sdk::clock::{Epoch, Slot, UnixTimestamp};
+use solana_sdk::compute_budget::{self, ComputeBudgetInstruction};
+use solana_sdk::instruction::{AccountMeta, Instruction, InstructionError};
+use solana_sdk::native_token::lamports_to_sol;
 use solana_sdk::pubkey::Pubkey;
+use solana_sdk::rent::Rent;
 use solana_sdk::signature::{Keypair, Signature};
 use solana_sdk::signer::Signer;
 use solana_sdk::signers::Signers;
-use solana_sdk::transaction::Transaction;
+use solana_sdk::system_instruction::{self, SystemError};
+use solana_sdk::transaction::{Transaction, TransactionError};
 
 use texture_common::account::loaders::load_accounts;
 use texture_common::account::PodAccount;
+use texture_common::math::Decimal;
 
-use curvy::instruction::{AlterCurve, CreateCurve, DeleteCurve};
-use curvy::state::curve::{Curve, CurveParams, CurveX, CurveY, MAX_Y_CNT};
+use curvy::instruction::{
+    AcceptOwner, AddDelegate, AlterCurve, AlterGridCurve, AlterWideCurve, ApplyAlter, BatchAlter,
+    CancelAlter, CancelDeletion, ClearCurveAsset, CreateCurve, CreateCurvePda, CreateGridCurve,
+    CreateReferenceCurve, CreateWideCurve, DeleteCurve, DeleteCurveConfidence, DeleteCurveSeries,
+    EvaluateCurve, FreezeCurve, InitConfig, MarkForDeletion, MigrateCurve, PatchY, ProposeAlter,
+    ProposeOwner, RemoveDelegate, ReserveCurve, ResizeCurve, SetAlterDelay, SetConfig,
+    SetCreationFrozen, SetCurveAsset, SetCurveConfidence, SetCurveExpiry, SetCurveExtension,
+    SetCurveOwners, SetCurveSeries, SetCurveStatus, SetCurveTail, SetCurveXSign, SetCurveYSign,
+    SetCurveYTransform, TopUpRent, UpdateCurveData, UpdateCurveMetadata,
+};
+use curvy::state::asset_link::AssetLink;
+use curvy::state::config::Config;
+use curvy::state::curve::{
+    find_reference_curve_address, Curve, CurveParams, CurveStatus, CurveX, CurveY, YTransform,
+    DELETION_GRACE_SLOTS, MAX_TOTAL_Y_CNT, MAX_Y_CNT, SYMBOL_MAX_SIZE,
+};
+use curvy::state::curve_confidence::{CurveConfidence, CurveConfidenceBps};
+use curvy::state::curve_evaluation::CurveEvaluation;
+use curvy::state::curve_extension::{
+    self, CurveExtension, CurveGuardrails, CurveSchedule, CurveTags, ExtensionType,
+};
+use curvy::state::curve_owners::CurveOwners;
+use curvy::state::curve_series::{CurveSeries, MAX_EXTRA_SERIES};
+use curvy::state::grid_curve::{GridCurve, GridCurveParams, MAX_GRID_Y_CNT};
+use curvy::state::name_link::NameLink;
+use curvy::state::pending_alter::PendingAlter;
+use curvy::state::utils::bytes_to_cow;
+use curvy::state::wide_curve::{WideCurve, WideCurveParams, WideCurveY, MAX_WIDE_Y_CNT};
+use curvy_utils::{AnyCurve, CurveVersion};
 
+pub use alter_guard::AlterGuard;
+pub use approval::{
+    Approval, ApprovalFuture, ApprovalHook, OperationKind, PreparedOperation, TerminalApproval,
+    WebhookApproval,
+};
+pub use confirmation::{poll_confirmation, Confirmation};
+pub use consistency::{
+    sample_points, ConsistencyReport, ConsumerAccount, ConsumerAdapter, ConsumerLayout, Divergence,
+};
+pub use context::WithContext;
+pub use decode::{curve_from_account, curve_from_encoded, curve_from_ui_account};
+pub use error::{error_table, CurvyClientError, PreflightFailure};
+pub use history::{replay_history, sets_curve_data};
+pub use notify::{ChangeAction, CurveChange, Notifier, NotifyTarget};
+pub use profile::{
+    fit_per_transaction, parse_compute_units, InstructionProfile, Profile, MAX_COMPUTE_UNIT_LIMIT,
+};
+pub use read_limiter::{ReadBudget, ReadLimiter, ReadStats};
+pub use reconcile::{plan, ExpectedCurve, ExpectedState, ReconcileAction, ReconcilePlan};
+pub use snapshot::{CurveSnapshot, Snapshot, SnapshotDiff};
+pub use state_store::{
+    open_state_store, FeeStats, FileStateStore, MemoryStateStore, StateStore, UpdaterState,
+};
+pub use subscription::{watch_curve, CurveEvent, Resubscribe};
+pub use webhook::{decode_webhook_payload, DecodedOperation};
+
+mod alter_guard;
+mod approval;
+mod confirmation;
+mod consistency;
+mod context;
+mod decode;
+mod error;
+mod history;
+mod notify;
+mod profile;
+mod read_limiter;
+mod reconcile;
+mod snapshot;
+mod state_store;
+mod subscription;
+mod webhook;
+
+/// Picks the smallest instructions covering the difference between `curve` and new `params`:
+/// metadata-only and data-only changes don't resend (or race with) the other half of the curve.
+/// Renames go through UpdateCurveMetadata, the only instruction moving the name link, so a rename
+/// with new data takes it followed by the data instruction. Co-owned and reference curves can not
+/// be renamed.
+fn alter_instructions(
+    curve_key: Pubkey,
+    owner: Pubkey,
+    curve: &Curve,
+    params: CurveParams,
+) -> Result<(OperationKind, Vec<Instruction>)> {
+    let renamed = params.name != curve.name;
+    let metadata_changed = renamed || params.formula != curve.formula;
+    let data_changed = params.x0 != curve.x0
+        || params.x_step != curve.x_step
+        || params.y_count != curve.y_count
+        || params.x_decimals != curve.x_decimals
+        || params.y_decimals != curve.y_decimals
+        || params.y != curve.y;
+    // UpdateCurveData has common decimals of both axes, the curve must have them as well
+    let same_decimals =
+        params.x_decimals == params.y_decimals && curve.x_decimals == curve.y_decimals;
+    // co-owned and reference curves are altered by AlterCurve only
+    let single_owner = curve.owner_threshold == 0 && !curve.is_reference();
+
+    if renamed && !single_owner {
+        anyhow::bail!("curve {curve_key} is co-owned or a reference curve and can not be renamed");
+    }
+
+    let metadata_ix = || {
+        UpdateCurveMetadata {
+            curve: curve_key,
+            owner,
+            name_link: NameLink::find_address(&owner, &curve.name).0,
+            new_name_link: NameLink::find_address(&owner, &params.name).0,
+            name: params.name,
+            formula: params.formula,
+        }
+        .into_instruction()
+    };
+    let alter_ix = || {
+        let mut ix = AlterCurve {
+            curve: curve_key,
+            owner,
+            params,
+        }
+        .into_instruction();
+        // signed by the config admin, see [Curve::is_reference]
+        if curve.is_reference() {
+            ix.accounts
+                .push(AccountMeta::new_readonly(Config::find_address().0, false));
+        }
+        ix
+    };
+
+    Ok(match (metadata_changed, data_changed) {
+        (true, false) if single_owner => (OperationKind::UpdateCurveMetadata, vec![metadata_ix()]),
+        (false, true) if same_decimals && single_owner => (
+            OperationKind::UpdateCurveData,
+            vec![UpdateCurveData {
+                curve: curve_key,
+                owner,
+                x0: params.x0,
+                x_step: params.x_step,
+                y_count: params.y_count,
+                decimals: params.x_decimals,
+                y: params.y,
+            }
+            .into_instruction()],
+        ),
+        // AlterCurve keeps the name, it is already the new one after UpdateCurveMetadata
+        _ if renamed => (OperationKind::AlterCurve, vec![metadata_ix(), alter_ix()]),
+        _ => (OperationKind::AlterCurve, vec![alter_ix()]),
+    })
+}
+
+/// ClearCurveAsset of associated `curve`
+fn clear_asset_instruction(curve_key: Pubkey, owner: Pubkey, curve: &Curve) -> Option<Instruction> {
+    let (mint, kind) = curve.asset()?;
+    let (asset_link, _) = AssetLink::find_address(&curve.owner, &mint, kind);
+
+    Some(
+        ClearCurveAsset {
+            curve: curve_key,
+            owner,
+            asset_link,
+        }
+        .into_instruction(),
+    )
+}
+
+/// DeleteCurveSeries of `curve` having additional series
+fn delete_series_instruction(
+    curve_key: Pubkey,
+    owner: Pubkey,
+    curve: &Curve,
+) -> Option<Instruction> {
+    (curve.series_count > 0).then(|| {
+        DeleteCurveSeries {
+            curve: curve_key,
+            owner,
+            curve_series: CurveSeries::find_address(&curve_key).0,
+        }
+        .into_instruction()
+    })
+}
+
+/// DeleteCurveConfidence of `curve` having confidence
+fn delete_confidence_instruction(
+    curve_key: Pubkey,
+    owner: Pubkey,
+    curve: &Curve,
+) -> Option<Instruction> {
+    (curve.has_confidence != 0).then(|| {
+        DeleteCurveConfidence {
+            curve: curve_key,
+            owner,
+            curve_confidence: CurveConfidence::find_address(&curve_key).0,
+        }
+        .into_instruction()
+    })
+}
+
+/// All curves of the program and the slot they are loaded at. Curves not yet migrated by
+/// MigrateCurve are read as upgraded, see [Curve::read].
 pub async fn load_curves(rpc: &RpcClient) -> Result<(HashMap<Pubkey, Curve>, Slot)> {
-    Ok(load_accounts(rpc, &curvy::ID).await?)
+    let (accounts, slot) = load_curve_accounts(rpc).await?;
+
+    let curves = accounts
+        .into_iter()
+        .map(|(key, curve, _)| (key, curve))
+        .collect();
+
+    Ok((curves, slot))
+}
+
+/// Same as [load_curves] keeping the lamports of every curve account
+async fn load_curve_accounts(rpc: &RpcClient) -> Result<(Vec<(Pubkey, Curve, u64)>, Slot)> {
+    let config = RpcProgramAccountsConfig {
+        filters: Some(vec![RpcFilterType::Memcmp(Memcmp::new_raw_bytes(
+            0,
+            Curve::DISCRIMINATOR.to_vec(),
+        ))]),
+        account_config: RpcAccountInfoConfig {
+            encoding: Some(UiAccountEncoding::Base64),
+            commitment: Some(rpc.commitment()),
+            ..RpcAccountInfoConfig::default()
+        },
+        with_context: Some(true),
+    };
+    let response: OptionalContext<Vec<RpcKeyedAccount>> = rpc
+        .send(
+            RpcRequest::GetProgramAccounts,
+            serde_json::json!([curvy::ID.to_string(), config]),
+        )
+        .await?;
+    let OptionalContext::Context(response) = response else {
+        anyhow::bail!("getProgramAccounts response has no context");
+    };
+
+    let curves = response
+        .value
+        .iter()
+        .map(|keyed| {
+            let key = Pubkey::from_str(&keyed.pubkey)?;
+            let curve = curve_from_ui_account(&keyed.account)
+                .map_err(|err| err.context(format!("curve {key}")))?;
+            Ok((key, curve, keyed.account.lamports))
+        })
+        .collect::<Result<_>>()?;
+
+    Ok((curves, response.context.slot))
+}
+
+/// Optional changes of curve params, unset fields keep current values
+#[derive(Debug, Clone, Default)]
+pub struct CurveUpdate {
+    pub name: Option<String>,
+    pub formula: Option<String>,
+    pub x_decimals: Option<u8>,
+    pub y_decimals: Option<u8>,
+    pub x0: Option<CurveX>,
+    pub x_step: Option<CurveX>,
+    pub y_count: Option<u8>,
+    pub y: Option<[CurveY; MAX_Y_CNT]>,
+}
+
+impl CurveUpdate {
+    /// Params of `curve` with the changes applied
+    pub fn apply(&self, curve: &Curve) -> CurveParams {
+        let mut params = CurveParams::from(curve);
+
+        if let Some(name) = &self.name {
+            params.name = curvy::state::utils::str_to_array(name);
+        }
+
+        if let Some(formula) = &self.formula {
+            params.formula = curvy::state::utils::str_to_array(formula);
+        }
+
+        if let Some(x_decimals) = self.x_decimals {
+            params.x_decimals = x_decimals;
+        }
+        if let Some(y_decimals) = self.y_decimals {
+            params.y_decimals = y_decimals;
+        }
+
+        if let Some(x0) = self.x0 {
+            params.x0 = x0;
+        }
+        if let Some(x_step) = self.x_step {
+            params.x_step = x_step;
+        }
+
+        if let Some(y_count) = self.y_count {
+            params.y_count = y_count;
+        }
+
+        if let Some(y) = self.y {
+            params.y = y;
+        }
+
+        params
+    }
+}
+
+/// Single changed param of an altered curve
+#[derive(Debug, Clone, PartialEq, serde::Serialize)]
+pub struct FieldChange {
+    pub field: String,
+    pub from: String,
+    pub to: String,
+}
+
+/// Params of any curve layout compared by [FieldChange::diff]
+struct DiffParams<'a, Y> {
+    name: &'a [u8],
+    formula: &'a [u8],
+    x_decimals: u8,
+    y_decimals: u8,
+    x0: CurveX,
+    x_step: CurveX,
+    /// First `y_count` values of explicit X grid, empty for uniform ones
+    x: &'a [CurveX],
+    /// First `y_count` values
+    y: &'a [Y],
+}
+
+impl<'a> From<&'a CurveParams> for DiffParams<'a, CurveY> {
+    fn from(params: &'a CurveParams) -> Self {
+        Self {
+            name: &params.name,
+            formula: &params.formula,
+            x_decimals: params.x_decimals,
+            y_decimals: params.y_decimals,
+            x0: params.x0,
+            x_step: params.x_step,
+            x: &[],
+            y: &params.y[..(params.y_count as usize).min(MAX_Y_CNT)],
+        }
+    }
+}
+
+impl<'a> From<&'a WideCurveParams> for DiffParams<'a, WideCurveY> {
+    fn from(params: &'a WideCurveParams) -> Self {
+        Self {
+            name: &params.name,
+            formula: &params.formula,
+            x_decimals: params.decimals,
+            y_decimals: params.decimals,
+            x0: params.x0,
+            x_step: params.x_step,
+            x: &[],
+            y: &params.y[..(params.y_count as usize).min(MAX_WIDE_Y_CNT)],
+        }
+    }
+}
+
+impl<'a> From<&'a GridCurveParams> for DiffParams<'a, CurveY> {
+    fn from(params: &'a GridCurveParams) -> Self {
+        let count = (params.y_count as usize).min(MAX_GRID_Y_CNT);
+        Self {
+            name: &params.name,
+            formula: &params.formula,
+            x_decimals: params.decimals,
+            y_decimals: params.decimals,
+            x0: 0,
+            x_step: 0,
+            x: &params.x[..count],
+            y: &params.y[..count],
+        }
+    }
+}
+
+impl FieldChange {
+    /// Differences between current and new params
+    pub fn diff(from: &CurveParams, to: &CurveParams) -> Vec<FieldChange> {
+        Self::diff_params(from.into(), to.into())
+    }
+
+    /// Same as [FieldChange::diff] for wide curves
+    pub fn diff_wide(from: &WideCurveParams, to: &WideCurveParams) -> Vec<FieldChange> {
+        Self::diff_params(from.into(), to.into())
+    }
+
+    /// Same as [FieldChange::diff] for grid curves
+    pub fn diff_grid(from: &GridCurveParams, to: &GridCurveParams) -> Vec<FieldChange> {
+        Self::diff_params(from.into(), to.into())
+    }
+
+    fn diff_params<Y: Display>(from: DiffParams<Y>, to: DiffParams<Y>) -> Vec<FieldChange> {
+        let mut changes = vec![];
+        let mut push = |field: String, from: String, to: String| {
+            if from != to {
+                changes.push(FieldChange { field, from, to });
+            }
+        };
+
+        push(
+            "name".into(),
+            bytes_to_cow(from.name).into_owned(),
+            bytes_to_cow(to.name).into_owned(),
+        );
+        push(
+            "formula".into(),
+            bytes_to_cow(from.formula).into_owned(),
+            bytes_to_cow(to.formula).into_owned(),
+        );
+        push(
+            "decimals".into(),
+            from.x_decimals.to_string(),
+            to.x_decimals.to_string(),
+        );
+        push(
+            "y_decimals".into(),
+            from.y_decimals.to_string(),
+            to.y_decimals.to_string(),
+        );
+        push("x0".into(), from.x0.to_string(), to.x0.to_string());
+        push(
+            "x_step".into(),
+            from.x_step.to_string(),
+            to.x_step.to_string(),
+        );
+        push(
+            "y_count".into(),
+            from.y.len().to_string(),
+            to.y.len().to_string(),
+        );
+
+        let x = |params: &DiffParams<Y>, idx: usize| match params.x.get(idx) {
+            Some(x) => x.to_string(),
+            None => "-".to_string(),
+        };
+        for idx in 0..from.x.len().max(to.x.len()) {
+            push(format!("x[{idx}]"), x(&from, idx), x(&to, idx));
+        }
+
+        let y = |params: &DiffParams<Y>, idx: usize| match params.y.get(idx) {
+            Some(y) => y.to_string(),
+            None => "-".to_string(),
+        };
+        for idx in 0..from.y.len().max(to.y.len()) {
+            push(format!("y[{idx}]"), y(&from, idx), y(&to, idx));
+        }
+
+        changes
+    }
+}
+
+impl Display for FieldChange {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}: {} -> {}", self.field, self.from, self.to)
+    }
 }
 
 #[derive(Debug)]
 pub struct SignatureView {
     pub signature: Signature,
+    /// Absent when the RPC node did not return the transaction meta
+    pub cost: Option<TransactionCost>,
+}
+
+/// What a landed transaction cost, from its meta
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct TransactionCost {
+    /// Total fee in lamports, signature fees and priority fee
+    pub fee: u64,
+    /// Absent when the RPC node does not report consumed compute units
+    pub compute_units: Option<u64>,
+}
+
+impl Display for TransactionCost {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} lamports", self.fee)?;
+        if let Some(compute_units) = self.compute_units {
+            write!(f, ", {compute_units} CU")?;
+        }
+        Ok(())
+    }
+}
+
+/// Origin of the address a curve was created at
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CurveKeySource {
+    /// Fresh keypair generated by the client
+    Generated,
+    /// Keypair supplied by the caller (vanity or pre-registered address)
+    Provided,
+    /// Program derived address of the owner and the curve name, see
+    /// [curvy::state::curve::find_curve_address]
+    Derived,
 }
 
 #[derive(Debug)]
@@ -41,6 +531,18 @@ pub struct CurveSignatureView {
     #[serde_as(as = "Option<serde_with::DisplayFromStr>")]
     pub signature: Option<Signature>,
     pub error: Option<String>,
+    pub key_source: Option<CurveKeySource>,
+    /// Params the curve was created with
+    pub params: Option<CurveParams>,
+    /// Slot the transaction landed in
+    pub slot: Option<Slot>,
+    /// Lamports paid to make the curve account rent exempt
+    pub rent: Option<u64>,
+    /// Curve account already existed with the same owner and params, e.g. a retried create
+    /// whose first attempt had landed
+    pub existing: Option<bool>,
+    /// Fee and compute units of the transaction
+    pub cost: Option<TransactionCost>,
 }
 impl CurveSignatureView {
     pub fn success(curve: Pubkey, signature: Signature) -> Self {
@@ -48,6 +550,12 @@ impl CurveSignatureView {
             curve,
             signature: Some(signature),
             error: None,
+            key_source: None,
+            params: None,
+            slot: None,
+            rent: None,
+            existing: None,
+            cost: None,
         }
     }
 
@@ -56,124 +564,3215 @@ impl CurveSignatureView {
             curve,
             signature: None,
             error: Some(error.to_string()),
+            key_source: None,
+            params: None,
+            slot: None,
+            rent: None,
+            existing: None,
+            cost: None,
         }
     }
+
+    pub fn with_key_source(mut self, key_source: CurveKeySource) -> Self {
+        self.key_source = Some(key_source);
+        self
+    }
 }
 
 #[derive(Debug)]
 pub struct CurveView {
     pub key: Pubkey,
     pub curve: Curve,
+    /// Samples following the `y` table, see [Curve::tail_y]
+    pub tail: Vec<CurveY>,
+    /// Extension region of the account, see [curve_extension]
+    pub extensions: Vec<u8>,
+    /// Raw account state, present when the curve was fetched individually
+    pub account: Option<CurveAccountView>,
 }
 impl From<(Pubkey, Curve)> for CurveView {
     fn from((key, curve): (Pubkey, Curve)) -> Self {
-        Self { key, curve }
+        Self {
+            key,
+            curve,
+            tail: vec![],
+            extensions: vec![],
+            account: None,
+        }
     }
 }
 
 impl Display for CurveView {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         writeln!(f, "Address : {}", self.key)?;
-        writeln!(f, "Name    : {}", String::from_utf8_lossy(&self.curve.name))?;
+        if let Some(account) = &self.account {
+            write!(f, "{}", account)?;
+        }
+        write!(f, "{}", CurveDataView(&self.curve))?;
+        if !self.tail.is_empty() {
+            writeln!(f, "Tail    : {} more samples", self.tail.len())?;
+        }
+        if let Ok(Some(tags)) = self.extension::<CurveTags>() {
+            writeln!(f, "Tags    : {}", bytes_to_cow(&tags.tags))?;
+        }
+        if let Ok(Some(guardrails)) = self.extension::<CurveGuardrails>() {
+            writeln!(
+                f,
+                "Bounds  : {}..{}",
+                guardrails.min_y(),
+                guardrails.max_y()
+            )?;
+        }
+        if let Ok(Some(schedule)) = self.extension::<CurveSchedule>() {
+            writeln!(
+                f,
+                "Valid   : {}..{}",
+                schedule.valid_from(),
+                schedule.valid_until()
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// Max number of tail samples sent in one SetCurveTail transaction
+pub const MAX_TAIL_CHUNK: usize = 200;
+
+/// Lamports to add to `lamports` balance to reach `rent_exempt_minimum`
+pub fn rent_top_up(rent_exempt_minimum: u64, lamports: u64) -> u64 {
+    rent_exempt_minimum.saturating_sub(lamports)
+}
+
+/// Account level state of the curve: program owner, balance and rent status
+#[serde_with::serde_as]
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct CurveAccountView {
+    #[serde_as(as = "serde_with::DisplayFromStr")]
+    pub owner: Pubkey,
+    pub lamports: u64,
+    pub rent_epoch: Epoch,
+    pub data_len: usize,
+    /// Minimum balance for rent exemption with default rent parameters
+    pub rent_exempt_minimum: u64,
+}
+
+impl CurveAccountView {
+    pub fn is_rent_exempt(&self) -> bool {
+        self.lamports >= self.rent_exempt_minimum
+    }
+
+    /// Lamports missing for rent exemption, see [CurvyClient::top_up_rent]
+    pub fn rent_top_up(&self) -> u64 {
+        rent_top_up(self.rent_exempt_minimum, self.lamports)
+    }
+}
+
+impl From<&Account> for CurveAccountView {
+    fn from(account: &Account) -> Self {
+        Self {
+            owner: account.owner,
+            lamports: account.lamports,
+            rent_epoch: account.rent_epoch,
+            data_len: account.data.len(),
+            rent_exempt_minimum: Rent::default().minimum_balance(account.data.len()),
+        }
+    }
+}
+
+impl Display for CurveAccountView {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "Program : {}", self.owner)?;
         writeln!(
             f,
-            "Formula : {}",
-            String::from_utf8_lossy(&self.curve.formula)
+            "Balance : {} SOL ({})",
+            lamports_to_sol(self.lamports),
+            if self.is_rent_exempt() {
+                "rent exempt"
+            } else {
+                "NOT rent exempt"
+            }
         )?;
-        writeln!(f, "decimals: {}", self.curve.decimals)?;
-        writeln!(f, "x0      : {}", self.curve.x0)?;
-        writeln!(f, "x_step  : {}", self.curve.x_step)?;
-        writeln!(f, "y_count : {}", self.curve.y_count)?;
+        writeln!(f, "Rent ep.: {}", self.rent_epoch)?;
+        writeln!(f, "Data len: {}", self.data_len)
+    }
+}
+
+impl CurveView {
+    /// JSON representation of the curve. With `preview` set only that many evenly spaced points
+    /// (first and last included) are returned instead of the full `y` array.
+    pub fn json(&self, preview: Option<usize>) -> CurveJsonView {
+        let curve = &self.curve;
+        let y = self
+            .samples()
+            .into_iter()
+            .map(|y| curve.y_value(y))
+            .collect::<Vec<_>>();
+
+        let (y, preview) = match preview {
+            None => (Some(y), None),
+            Some(points) => {
+                let preview = downsample(y.len(), points)
+                    .map(|idx| PreviewPoint {
+                        x: curve.x0_signed() + curve.x_step as i64 * idx as i64,
+                        y: y[idx],
+                    })
+                    .collect();
+                (None, Some(preview))
+            }
+        };
+
+        CurveJsonView {
+            address: self.key,
+            owner: curve.owner,
+            pending_owner: curve.pending_owner(),
+            name: bytes_to_cow(&curve.name).into_owned(),
+            formula: bytes_to_cow(&curve.formula).into_owned(),
+            asset_mint: curve.asset().map(|(mint, _)| mint),
+            asset_kind: curve.asset().map(|(_, kind)| kind),
+            series_count: curve.series_count,
+            has_confidence: curve.has_confidence != 0,
+            is_frozen: curve.is_frozen != 0,
+            status: curve.status(),
+            deletion_slot: curve.deletion_slot(),
+            deletable_from_slot: curve.deletable_from_slot(),
+            alter_delay: curve.alter_delay(),
+            delegates: curve.delegates().copied().collect(),
+            owner_threshold: curve.owner_threshold,
+            y_signed: curve.y_signed != 0,
+            y_transform: curve.y_transform(),
+            valid_until: curve.valid_until(),
+            validity_period: curve.validity_period(),
+            last_update_slot: curve.last_update_slot(),
+            last_update_unix: curve.last_update_unix(),
+            revision: curve.revision(),
+            decimals: curve.x_decimals,
+            y_decimals: curve.y_decimals,
+            x0: curve.x0_signed(),
+            x_step: curve.x_step,
+            y_count: curve.y_count,
+            tail_y_count: (!self.tail.is_empty()).then_some(self.tail.len()),
+            tags: self
+                .extension::<CurveTags>()
+                .ok()
+                .flatten()
+                .map(|tags| bytes_to_cow(&tags.tags).into_owned()),
+            guardrails: self
+                .extension::<CurveGuardrails>()
+                .ok()
+                .flatten()
+                .map(|guardrails| GuardrailsView {
+                    min_y: guardrails.min_y(),
+                    max_y: guardrails.max_y(),
+                }),
+            schedule: self
+                .extension::<CurveSchedule>()
+                .ok()
+                .flatten()
+                .map(|schedule| ScheduleView {
+                    valid_from: schedule.valid_from(),
+                    valid_until: schedule.valid_until(),
+                }),
+            y,
+            preview,
+            account: self.account.clone(),
+        }
+    }
+
+    /// All samples, `y` table and its tail
+    pub fn samples(&self) -> Vec<CurveY> {
+        let curve = &self.curve;
+        let mut samples = curve.y[..(curve.y_count as usize).min(MAX_Y_CNT)].to_vec();
+        samples.extend_from_slice(&self.tail);
+        samples
+    }
+
+    /// Value of extension `E`, `None` when the curve does not have it
+    pub fn extension<E: CurveExtension>(&self) -> Result<Option<E>> {
+        Ok(curve_extension::get(&self.extensions)?)
+    }
+
+    /// Y in given X point taking the tail into account, see [curvy_utils::calc_y_extended]
+    pub fn calc_y(&self, x: Decimal) -> texture_common::math::MathResult<Decimal> {
+        curvy_utils::calc_y_extended(x, &self.curve, &self.tail)
+    }
+}
+
+impl serde::Serialize for CurveView {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        self.json(None).serialize(serializer)
+    }
+}
+
+/// Indexes of `points` evenly spaced samples out of `len` including first and last ones
+fn downsample(len: usize, points: usize) -> impl Iterator<Item = usize> {
+    let points = points.min(len);
+    let mut last = None;
+
+    (0..points).filter_map(move |i| {
+        let idx = if points == 1 {
+            0
+        } else {
+            (i * (len - 1) + (points - 1) / 2) / (points - 1)
+        };
+        (last.replace(idx) != Some(idx)).then_some(idx)
+    })
+}
+
+#[serde_with::serde_as]
+#[serde_with::skip_serializing_none]
+#[derive(Debug, serde::Serialize)]
+pub struct CurveJsonView {
+    #[serde_as(as = "serde_with::DisplayFromStr")]
+    pub address: Pubkey,
+    #[serde_as(as = "serde_with::DisplayFromStr")]
+    pub owner: Pubkey,
+    /// Proposed owner who has not accepted the ownership yet
+    #[serde_as(as = "Option<serde_with::DisplayFromStr>")]
+    pub pending_owner: Option<Pubkey>,
+    pub name: String,
+    pub formula: String,
+    #[serde_as(as = "Option<serde_with::DisplayFromStr>")]
+    pub asset_mint: Option<Pubkey>,
+    pub asset_kind: Option<u8>,
+    /// Number of additional `y` series
+    pub series_count: u8,
+    /// Whether the curve has per-sample confidence
+    pub has_confidence: bool,
+    /// Whether the curve is frozen and can not be changed
+    pub is_frozen: bool,
+    /// Lifecycle stage, see [CurveStatus]
+    #[serde_as(as = "serde_with::DisplayFromStr")]
+    pub status: CurveStatus,
+    /// Slot the curve was marked for deletion in, see [Curve::deletion_slot]
+    pub deletion_slot: Option<u64>,
+    /// First slot the curve marked for deletion can be deleted in
+    pub deletable_from_slot: Option<u64>,
+    /// Slots between ProposeAlter and ApplyAlter, see [Curve::alter_delay]
+    pub alter_delay: u64,
+    /// Keys allowed to update samples besides the owner, see [Curve::delegates]
+    #[serde_as(as = "Vec<serde_with::DisplayFromStr>")]
+    pub delegates: Vec<Pubkey>,
+    /// Owners which must sign changes of co-owned curve, zero for single owner, see
+    /// [Curve::owner_threshold]
+    pub owner_threshold: u8,
+    /// Whether `y` values may be negative, see [Curve::y_signed]
+    pub y_signed: bool,
+    /// Transform of stored `y` values, see [YTransform]
+    #[serde_as(as = "serde_with::DisplayFromStr")]
+    pub y_transform: YTransform,
+    /// Unix timestamp the curve is stale from, see [Curve::valid_until]
+    pub valid_until: Option<i64>,
+    /// Seconds sample updates move `valid_until` ahead, see [Curve::validity_period]
+    pub validity_period: u64,
+    /// Slot of the creation or the last sample update, see [Curve::last_update_slot]
+    pub last_update_slot: Option<u64>,
+    /// Unix timestamp of `last_update_slot`
+    pub last_update_unix: Option<i64>,
+    /// Number of alterations, see [Curve::revision]
+    pub revision: u64,
+    /// Decimals of `x0` and `x_step`
+    pub decimals: u8,
+    /// Decimals of `y`
+    pub y_decimals: u8,
+    /// Signed, see [Curve::x0_negative]
+    pub x0: i64,
+    pub x_step: CurveX,
+    pub y_count: u8,
+    /// Number of samples following the `y` table, see [Curve::tail_y]
+    pub tail_y_count: Option<usize>,
+    /// Tags extension, see [CurveTags]
+    pub tags: Option<String>,
+    /// Guardrails extension, see [CurveGuardrails]
+    pub guardrails: Option<GuardrailsView>,
+    /// Schedule extension, see [CurveSchedule]
+    pub schedule: Option<ScheduleView>,
+    /// Full `y` array (`y_count` values and the tail), signed, see [Curve::y_value]
+    pub y: Option<Vec<i64>>,
+    /// Downsampled points, present instead of `y` in listings
+    pub preview: Option<Vec<PreviewPoint>>,
+    pub account: Option<CurveAccountView>,
+}
+
+#[derive(Debug, serde::Serialize)]
+pub struct PreviewPoint {
+    pub x: i64,
+    pub y: i64,
+}
+
+#[derive(Debug, serde::Serialize)]
+pub struct GuardrailsView {
+    pub min_y: i64,
+    pub max_y: i64,
+}
+
+#[derive(Debug, serde::Serialize)]
+pub struct ScheduleView {
+    pub valid_from: i64,
+    pub valid_until: i64,
+}
+
+/// Displays decoded Curve account data which has no known address
+pub struct CurveDataView<'a>(pub &'a Curve);
+
+impl Display for CurveDataView<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let curve = self.0;
+
+        writeln!(f, "Name    : {}", String::from_utf8_lossy(&curve.name))?;
+        writeln!(f, "Formula : {}", String::from_utf8_lossy(&curve.formula))?;
+        writeln!(f, "Owner   : {}", curve.owner)?;
+        if let Some(pending_owner) = curve.pending_owner() {
+            writeln!(f, "Proposed: {pending_owner} (not accepted)")?;
+        }
+        for delegate in curve.delegates() {
+            writeln!(f, "Delegate: {delegate}")?;
+        }
+        if curve.owner_threshold > 0 {
+            writeln!(f, "Co-owned: {} owners must sign", curve.owner_threshold)?;
+        }
+        if let Some((mint, kind)) = curve.asset() {
+            writeln!(f, "Asset   : {mint} (kind {kind})")?;
+        }
+        if curve.series_count > 0 {
+            writeln!(f, "Series  : {} additional", curve.series_count)?;
+        }
+        if curve.has_confidence != 0 {
+            writeln!(f, "Conf.   : per sample, bps of y")?;
+        }
+        if curve.is_frozen != 0 {
+            writeln!(f, "Frozen  : yes, can not be changed")?;
+        }
+        if curve.status() != CurveStatus::Active {
+            writeln!(f, "Status  : {}", curve.status())?;
+        }
+        if let (Some(slot), Some(from)) = (curve.deletion_slot(), curve.deletable_from_slot()) {
+            writeln!(
+                f,
+                "Deletion: marked in slot {slot}, can be deleted from slot {from}"
+            )?;
+        }
+        if curve.alter_delay() > 0 {
+            writeln!(
+                f,
+                "Timelock: alterations wait {} slots",
+                curve.alter_delay()
+            )?;
+        }
+        if curve.y_signed != 0 {
+            writeln!(f, "Y sign  : signed")?;
+        }
+        if curve.y_transform() != YTransform::Linear {
+            writeln!(
+                f,
+                "Y scale : {}, y[] holds stored values",
+                curve.y_transform()
+            )?;
+        }
+        match (curve.valid_until(), curve.validity_period()) {
+            (Some(valid_until), 0) => writeln!(f, "Expires : at {valid_until}")?,
+            (Some(valid_until), period) => writeln!(
+                f,
+                "Expires : at {valid_until}, updates extend by {period} s"
+            )?,
+            (None, 0) => {}
+            (None, period) => writeln!(f, "Expires : {period} s after the next update")?,
+        }
+        if let (Some(slot), Some(unix)) = (curve.last_update_slot(), curve.last_update_unix()) {
+            writeln!(f, "Updated : slot {slot}, unix time {unix}")?;
+        }
+        writeln!(f, "Revision: {}", curve.revision())?;
+        if curve.y_decimals != curve.x_decimals {
+            writeln!(
+                f,
+                "decimals: {} x, {} y",
+                curve.x_decimals, curve.y_decimals
+            )?;
+        } else {
+            writeln!(f, "decimals: {}", curve.x_decimals)?;
+        }
+        writeln!(f, "x0      : {}", curve.x0_signed())?;
+        writeln!(f, "x_step  : {}", curve.x_step)?;
+        writeln!(f, "y_count : {}", curve.y_count)?;
         write!(f, "y[]     : \n          ")?;
 
-        let mut cnt = 0;
+        let mut cnt = 0;
+
+        for y_value in curve.y.iter().take(curve.y_count as usize) {
+            write!(f, "{}, ", curve.y_value(*y_value))?;
+
+            cnt += 1;
+
+            if cnt == 11 {
+                write!(f, "\n          ")?;
+                cnt = 0;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Debug)]
+pub struct CurvesView {
+    pub curves: Vec<CurveView>,
+}
+
+/// Curves controlled by a single owner
+#[serde_with::serde_as]
+#[derive(Debug, serde::Serialize)]
+pub struct OwnerView {
+    #[serde_as(as = "serde_with::DisplayFromStr")]
+    pub owner: Pubkey,
+    #[serde_as(as = "Vec<serde_with::DisplayFromStr>")]
+    pub curves: Vec<Pubkey>,
+    /// Total lamports locked in owned curve accounts
+    pub rent_locked: u64,
+    /// Latest creation or sample update of owned curves, see [Curve::last_update_unix]. `None`
+    /// when none of them recorded it.
+    pub last_update: Option<UnixTimestamp>,
+}
+
+impl Display for OwnerView {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "Owner       : {}", self.owner)?;
+        writeln!(f, "Curves      : {}", self.curves.len())?;
+        writeln!(f, "Rent locked : {} SOL", lamports_to_sol(self.rent_locked))?;
+        match self.last_update {
+            Some(last_update) => write!(f, "Last update : {}", last_update),
+            None => write!(f, "Last update : unknown"),
+        }
+    }
+}
+
+pub struct CurvyClient {
+    pub rpc: RpcClient,
+    pub authority: Keypair,
+    pub priority_fee: Option<u64>,
+    /// Options of `sendTransaction` used by all send methods
+    pub send_config: RpcSendTransactionConfig,
+    pub confirmation: Confirmation,
+    /// Invoked before sending any mutating transaction
+    pub approval: Option<Arc<dyn ApprovalHook>>,
+    /// Limits how often the same curve may be altered
+    pub alter_guard: Option<AlterGuard>,
+    /// Other owners signing AlterCurve and SetCurveOwners of co-owned curves together with the
+    /// authority, see [Curve::owner_threshold]
+    pub co_signers: Vec<Keypair>,
+    /// Coalesces concurrent account reads and holds them to a budget, see [ReadLimiter]
+    pub read_limiter: Option<ReadLimiter>,
+}
+
+impl CurvyClient {
+    /// Sends the operation on `curve` once [Self::approve] lets it through. Every mutating
+    /// transaction goes through here, there is no way to send one around the approval hook.
+    async fn send_operation(
+        &self,
+        kind: OperationKind,
+        curve: Pubkey,
+        params: Option<CurveParams>,
+        ixs: Vec<Instruction>,
+        signers: &impl Signers,
+    ) -> Result<Signature> {
+        self.send_operations(kind, &[(curve, params)], ixs, signers)
+            .await
+    }
+
+    /// Same as [Self::send_operation] for a transaction touching several curves, each of them
+    /// is approved separately
+    async fn send_operations(
+        &self,
+        kind: OperationKind,
+        targets: &[(Pubkey, Option<CurveParams>)],
+        ixs: Vec<Instruction>,
+        signers: &impl Signers,
+    ) -> Result<Signature> {
+        for (curve, params) in targets {
+            self.approve(kind, *curve, *params, &ixs).await?;
+        }
+
+        self.send_transaction(ixs, signers).await
+    }
+
+    /// Fails with [CurvyClientError::CreationFrozen] for operations creating curves while
+    /// creation is frozen, and passes the operation through the approval hook (if any)
+    async fn approve(
+        &self,
+        kind: OperationKind,
+        curve: Pubkey,
+        params: Option<CurveParams>,
+        instructions: &[Instruction],
+    ) -> Result<()> {
+        if kind.is_creation() && self.is_creation_frozen().await? {
+            return Err(CurvyClientError::CreationFrozen { kind }.into());
+        }
+
+        let Some(approval) = &self.approval else {
+            return Ok(());
+        };
+
+        let operation = PreparedOperation {
+            kind,
+            curve,
+            authority: self.authority.pubkey(),
+            params,
+            instructions: instructions.to_vec(),
+        };
+
+        match approval.approve(&operation).await {
+            Approval::Approved => Ok(()),
+            Approval::Rejected { reason } => Err(CurvyClientError::Rejected {
+                kind,
+                curve,
+                reason,
+            }
+            .into()),
+        }
+    }
+
+    /// Signs and sends the transaction with the priority fee added. Unguarded, callers go
+    /// through [Self::send_operation].
+    async fn send_transaction(
+        &self,
+        mut ixs: Vec<Instruction>,
+        signers: &impl Signers,
+    ) -> Result<Signature> {
+        let config = self.send_config;
+        // operations add the price themselves, a duplicate compute budget instruction fails the
+        // whole transaction
+        let price_tag = ComputeBudgetInstruction::set_compute_unit_price(0).data[0];
+        let has_price = ixs
+            .iter()
+            .any(|ix| ix.program_id == compute_budget::ID && ix.data.first() == Some(&price_tag));
+
+        if let (Some(priority_fee), false) = (self.priority_fee, has_price) {
+            let priority_fee_ix = ComputeBudgetInstruction::set_compute_unit_price(priority_fee);
+            ixs.push(priority_fee_ix);
+        }
+
+        let mut tx = Transaction::new_with_payer(ixs.as_ref(), Some(&self.authority.pubkey()));
+        let blockhash = self.rpc.get_latest_blockhash().await?;
+        tx.sign(signers, blockhash);
+
+        let signature = match self.confirmation.resolve() {
+            Confirmation::Polling { interval, timeout } => {
+                let signature = self
+                    .rpc
+                    .send_transaction_with_config(&tx, config)
+                    .await
+                    .map_err(with_logs)?;
+
+                poll_confirmation(
+                    &self.rpc,
+                    &signature,
+                    self.rpc.commitment(),
+                    interval,
+                    timeout,
+                )
+                .await?;
+
+                signature
+            }
+            Confirmation::Auto | Confirmation::Spinner => self
+                .rpc
+                .send_and_confirm_transaction_with_spinner_and_config(
+                    &tx,
+                    self.rpc.commitment(),
+                    config,
+                )
+                .await
+                .map_err(with_logs)?,
+        };
+
+        Ok(signature)
+    }
+
+    /// Airdrops `amount` lamports to the authority when its balance is below `min_balance` and
+    /// waits for the airdrop. Works on devnet, testnet and local validators only.
+    pub async fn airdrop_if_below(
+        &self,
+        min_balance: u64,
+        amount: u64,
+    ) -> Result<Option<Signature>> {
+        let authority = self.authority.pubkey();
+        let balance = self
+            .rpc
+            .get_balance_with_commitment(&authority, self.rpc.commitment())
+            .await?
+            .value;
+        if balance >= min_balance {
+            return Ok(None);
+        }
+
+        let signature = self.rpc.request_airdrop(&authority, amount).await?;
+        let (interval, timeout) = match self.confirmation.resolve() {
+            Confirmation::Polling { interval, timeout } => (interval, timeout),
+            Confirmation::Auto | Confirmation::Spinner => (
+                Confirmation::DEFAULT_POLL_INTERVAL,
+                Confirmation::DEFAULT_TIMEOUT,
+            ),
+        };
+        poll_confirmation(
+            &self.rpc,
+            &signature,
+            self.rpc.commitment(),
+            interval,
+            timeout,
+        )
+        .await?;
+
+        Ok(Some(signature))
+    }
+
+    pub async fn account_exists(&self, key: &Pubkey) -> Result<bool> {
+        match self.rpc.get_account(key).await {
+            Ok(_) => Ok(true),
+            Err(ClientError {
+                kind: ClientErrorKind::RpcError(RpcError::ForUser(msg)),
+                ..
+            }) if msg.starts_with("AccountNotFound") => Ok(false),
+            Err(err) => Err(err.into()),
+        }
+    }
+
+    /// `value` observed in `slot` with the commitment of the client
+    fn with_context<T>(&self, value: T, slot: Slot) -> WithContext<T> {
+        WithContext::new(value, slot, self.rpc.commitment().commitment)
+    }
+
+    /// Reads the account at `key`, through [CurvyClient::read_limiter] if any
+    pub async fn get_account(&self, key: &Pubkey) -> Result<WithContext<Account>> {
+        let fetch = async {
+            let resp = self
+                .rpc
+                .get_account_with_commitment(key, self.rpc.commitment())
+                .await?;
+            Ok((resp.value, resp.context.slot))
+        };
+        let (account, slot) = match &self.read_limiter {
+            Some(limiter) => limiter.read(key, fetch).await?,
+            None => fetch.await?,
+        };
+
+        let account =
+            account.ok_or_else(|| RpcError::ForUser(format!("AccountNotFound: pubkey={key}")))?;
+        Ok(self.with_context(account, slot))
+    }
+
+    pub async fn get_pod_account<A: PodAccount>(&self, key: &Pubkey) -> Result<WithContext<A>> {
+        self.get_account(key)
+            .await?
+            .try_map(|account| Ok(*A::try_from_bytes(&account.data)?))
+    }
+
+    pub async fn create_curve(
+        &self,
+        params: CurveParams,
+        priority_rate: Option<u64>,
+    ) -> Result<CurveSignatureView> {
+        let curve_keypair = Keypair::new();
+
+        self.create_curve_at(params, Some(&curve_keypair), priority_rate)
+            .await
+            .map(|view| view.with_key_source(CurveKeySource::Generated))
+    }
+
+    /// Creates curve at the address of externally generated `curve_keypair`
+    /// (e.g. vanity or pre-registered address).
+    pub async fn create_curve_with_keypair(
+        &self,
+        params: CurveParams,
+        curve_keypair: &Keypair,
+        priority_rate: Option<u64>,
+    ) -> Result<CurveSignatureView> {
+        self.create_curve_at(params, Some(curve_keypair), priority_rate)
+            .await
+            .map(|view| view.with_key_source(CurveKeySource::Provided))
+    }
+
+    /// Creates curve at the address derived from the authority and the curve name, so consumers
+    /// can compute it with [curvy::state::curve::find_curve_address]
+    pub async fn create_curve_pda(
+        &self,
+        params: CurveParams,
+        priority_rate: Option<u64>,
+    ) -> Result<CurveSignatureView> {
+        self.create_curve_at(params, None, priority_rate)
+            .await
+            .map(|view| view.with_key_source(CurveKeySource::Derived))
+    }
+
+    /// Creates the canonical reference curve of `kind` for `asset_mint`, see
+    /// [CurvyClient::reference_curve]. The authority must be the program config admin.
+    pub async fn create_reference_curve(
+        &self,
+        params: CurveParams,
+        kind: u8,
+        asset_mint: Pubkey,
+        priority_rate: Option<u64>,
+        force: bool,
+    ) -> Result<CurveSignatureView> {
+        let (config, _) = Config::find_address();
+        let admin = self.authority.pubkey();
+        let (curve, _) = find_reference_curve_address(kind, &asset_mint);
+
+        if !force {
+            self.check_config_admin().await?;
+        }
+
+        let mut ixs = vec![];
+
+        if let Some(priority_rate) = priority_rate {
+            let priority_fee_ix = ComputeBudgetInstruction::set_compute_unit_price(priority_rate);
+            ixs.push(priority_fee_ix);
+        }
+
+        ixs.push(
+            CreateReferenceCurve {
+                curve,
+                admin,
+                config,
+                asset_mint,
+                name_link: NameLink::find_address(&config, &params.name).0,
+                kind,
+                params,
+            }
+            .into_instruction(),
+        );
+
+        let signature = self
+            .send_operation(
+                OperationKind::CreateReferenceCurve,
+                curve,
+                Some(params),
+                ixs,
+                &[&self.authority],
+            )
+            .await?;
+
+        let mut view = CurveSignatureView::success(curve, signature);
+        view.params = Some(params);
+        view.slot = self.signature_slot(&signature).await?;
+        view.cost = self.transaction_cost(&signature).await;
+
+        Ok(view)
+    }
+
+    /// Creates curve at the address of `curve_keypair`, or at the derived address without it
+    async fn create_curve_at(
+        &self,
+        params: CurveParams,
+        curve_keypair: Option<&Keypair>,
+        priority_rate: Option<u64>,
+    ) -> Result<CurveSignatureView> {
+        let owner = self.authority.pubkey();
+        let curve = match curve_keypair {
+            Some(curve_keypair) => curve_keypair.pubkey(),
+            None => Curve::find_address(&owner, &params.name).0,
+        };
+
+        let rent = self
+            .rpc
+            .get_minimum_balance_for_rent_exemption(Curve::SIZE)
+            .await?;
+
+        let mut ixs = vec![];
+
+        if let Some(priority_rate) = priority_rate {
+            let priority_fee_ix = ComputeBudgetInstruction::set_compute_unit_price(priority_rate);
+            ixs.push(priority_fee_ix);
+        }
+
+        ixs.push(match curve_keypair {
+            Some(_) => CreateCurve {
+                curve,
+                owner,
+                config: Config::find_address().0,
+                name_link: NameLink::find_address(&owner, &params.name).0,
+                params,
+            }
+            .into_instruction(),
+            None => CreateCurvePda {
+                curve,
+                owner,
+                config: Config::find_address().0,
+                name_link: NameLink::find_address(&owner, &params.name).0,
+                params,
+            }
+            .into_instruction(),
+        });
+
+        let mut signers = vec![&self.authority];
+        signers.extend(curve_keypair);
+
+        let signature = match self
+            .send_operation(
+                OperationKind::CreateCurve,
+                curve,
+                Some(params),
+                ixs,
+                &signers,
+            )
+            .await
+        {
+            Ok(signature) => signature,
+            Err(err) if is_account_in_use(&err) => {
+                return self.existing_curve(curve, params, err).await;
+            }
+            Err(err) => return Err(err),
+        };
+
+        let slot = self.signature_slot(&signature).await?;
+
+        let mut view = CurveSignatureView::success(curve, signature);
+        view.params = Some(params);
+        view.slot = slot;
+        view.rent = Some(rent);
+        view.cost = self.transaction_cost(&signature).await;
+
+        Ok(view)
+    }
+
+    /// Creates curve with name and formula only, so its address can be used before the data
+    /// exists. Samples are set later by [CurvyClient::alter_curve].
+    pub async fn reserve_curve(
+        &self,
+        name: &str,
+        formula: &str,
+        curve_keypair: &Keypair,
+        priority_rate: Option<u64>,
+    ) -> Result<CurveSignatureView> {
+        let owner = self.authority.pubkey();
+        let curve = curve_keypair.pubkey();
+
+        let rent = self
+            .rpc
+            .get_minimum_balance_for_rent_exemption(Curve::SIZE)
+            .await?;
+
+        let mut ixs = vec![];
+
+        if let Some(priority_rate) = priority_rate {
+            let priority_fee_ix = ComputeBudgetInstruction::set_compute_unit_price(priority_rate);
+            ixs.push(priority_fee_ix);
+        }
+
+        let name = curvy::state::utils::str_to_array(name);
+        ixs.push(
+            ReserveCurve {
+                curve,
+                owner,
+                config: Config::find_address().0,
+                name_link: NameLink::find_address(&owner, &name).0,
+                name,
+                formula: curvy::state::utils::str_to_array(formula),
+            }
+            .into_instruction(),
+        );
+
+        let signature = self
+            .send_operation(
+                OperationKind::ReserveCurve,
+                curve,
+                None,
+                ixs,
+                &[&self.authority, curve_keypair],
+            )
+            .await?;
+
+        let slot = self.signature_slot(&signature).await?;
+
+        let mut view = CurveSignatureView::success(curve, signature);
+        view.slot = slot;
+        view.rent = Some(rent);
+        view.cost = self.transaction_cost(&signature).await;
+
+        Ok(view)
+    }
+
+    /// Fee and compute units of the landed transaction, `None` when the RPC node does not
+    /// return its meta (e.g. the client commitment is `processed`)
+    pub async fn transaction_cost(&self, signature: &Signature) -> Option<TransactionCost> {
+        let config = RpcTransactionConfig {
+            encoding: None,
+            commitment: Some(self.rpc.commitment()),
+            max_supported_transaction_version: Some(0),
+        };
+
+        match self
+            .rpc
+            .get_transaction_with_config(signature, config)
+            .await
+        {
+            Ok(transaction) => transaction.transaction.meta.map(|meta| TransactionCost {
+                fee: meta.fee,
+                compute_units: meta.compute_units_consumed.into(),
+            }),
+            Err(err) => {
+                tracing::warn!("meta of transaction {signature} is not available: {err}");
+                None
+            }
+        }
+    }
+
+    async fn signature_view(&self, signature: Signature) -> SignatureView {
+        SignatureView {
+            signature,
+            cost: self.transaction_cost(&signature).await,
+        }
+    }
+
+    /// Returns slot the transaction with given `signature` landed in
+    pub async fn signature_slot(&self, signature: &Signature) -> Result<Option<Slot>> {
+        let statuses = self.rpc.get_signature_statuses(&[*signature]).await?;
+        Ok(statuses
+            .value
+            .into_iter()
+            .next()
+            .flatten()
+            .map(|status| status.slot))
+    }
+
+    /// Fails with [CurvyClientError::NotOwner] when `curve` is not owned by the client authority
+    pub fn check_owner(&self, curve_key: &Pubkey, curve: &Curve) -> Result<()> {
+        self.check_owner_key(curve_key, curve.owner)
+    }
+
+    /// Same as [CurvyClient::check_owner], delegates of the curve pass as well. For operations
+    /// updating samples, see [Curve::delegates].
+    pub fn check_updater(&self, curve_key: &Pubkey, curve: &Curve) -> Result<()> {
+        if curve.is_delegate(&self.authority.pubkey()) {
+            return Ok(());
+        }
+
+        self.check_owner(curve_key, curve)
+    }
+
+    /// Fails unless the authority and [CurvyClient::co_signers] are owners of co-owned `curve`
+    /// and at least its threshold of them, see [CurveOwners]
+    pub async fn check_co_owners(&self, curve_key: &Pubkey, curve: &Curve) -> Result<()> {
+        let Some(owners) = self.curve_owners(curve_key).await? else {
+            anyhow::bail!("owners of co-owned curve {curve_key} are not found");
+        };
+
+        let signers = std::iter::once(&self.authority)
+            .chain(&self.co_signers)
+            .map(|signer| signer.pubkey())
+            .collect::<Vec<_>>();
+        if let Some(stranger) = signers.iter().find(|signer| !owners.is_owner(signer)) {
+            anyhow::bail!("{stranger} is not an owner of curve {curve_key}");
+        }
+
+        let mut distinct = signers.clone();
+        distinct.sort();
+        distinct.dedup();
+        if distinct.len() < curve.owner_threshold as usize {
+            anyhow::bail!(
+                "curve {curve_key} takes {} owner signatures, {} given, add co-signers",
+                curve.owner_threshold,
+                distinct.len()
+            );
+        }
+
+        Ok(())
+    }
+
+    /// Signers of owner instructions of `curve`: the authority, and co-signers for co-owned
+    /// curve
+    fn owner_signers(&self, curve: &Curve) -> Vec<&dyn Signer> {
+        let mut signers: Vec<&dyn Signer> = vec![&self.authority];
+        if curve.owner_threshold > 0 {
+            signers.extend(self.co_signers.iter().map(|signer| signer as &dyn Signer));
+        }
+        signers
+    }
+
+    /// Accounts following AlterCurve and SetCurveOwners of co-owned curve: its curve owners
+    /// account and [CurvyClient::co_signers]
+    fn co_owner_metas(&self, curve_key: &Pubkey) -> Vec<AccountMeta> {
+        let mut metas = vec![AccountMeta::new_readonly(
+            CurveOwners::find_address(curve_key).0,
+            false,
+        )];
+        metas.extend(
+            self.co_signers
+                .iter()
+                .map(|signer| AccountMeta::new_readonly(signer.pubkey(), true)),
+        );
+        metas
+    }
+
+    /// Fails with [CurvyClientError::RevisionMismatch] when `curve` was altered since it was
+    /// read at revision `expected`, see [Curve::revision]
+    pub fn check_revision(&self, curve_key: &Pubkey, curve: &Curve, expected: u64) -> Result<()> {
+        if curve.revision() != expected {
+            return Err(CurvyClientError::RevisionMismatch {
+                curve: *curve_key,
+                expected,
+                actual: curve.revision(),
+            }
+            .into());
+        }
+
+        Ok(())
+    }
+
+    /// Same as [CurvyClient::check_owner] for any curve layout
+    pub fn check_owner_key(&self, curve_key: &Pubkey, owner: Pubkey) -> Result<()> {
+        let authority = self.authority.pubkey();
+
+        if owner != authority {
+            return Err(CurvyClientError::NotOwner {
+                curve: *curve_key,
+                owner,
+                authority,
+            }
+            .into());
+        }
+
+        Ok(())
+    }
+
+    /// Fails unless the authority is the program config admin, who creates and manages reference
+    /// curves, see [CurvyClient::create_reference_curve]
+    pub async fn check_config_admin(&self) -> Result<()> {
+        let admin = self.authority.pubkey();
+        let Some(config) = self.config().await? else {
+            anyhow::bail!("program config {} does not exist", Config::find_address().0);
+        };
+        if config.admin != admin {
+            anyhow::bail!(
+                "program config is administered by {}, not by {admin}",
+                config.admin
+            );
+        }
+
+        Ok(())
+    }
+
+    /// Reports success when the curve create failed because the account is in use but the
+    /// existing curve matches submitted owner and params. Returns `error` otherwise.
+    async fn existing_curve(
+        &self,
+        curve: Pubkey,
+        params: CurveParams,
+        error: anyhow::Error,
+    ) -> Result<CurveSignatureView> {
+        let Ok(existing) = self.get_pod_account::<Curve>(&curve).await else {
+            return Err(error);
+        };
+
+        if existing.owner != self.authority.pubkey()
+            || CurveParams::from(&existing.value).hash() != params.hash()
+        {
+            return Err(error.context(format!(
+                "curve {curve} already exists with different owner or params"
+            )));
+        }
+
+        // the only transaction of a just created curve is the create one
+        let signature = self
+            .rpc
+            .get_signatures_for_address(&curve)
+            .await?
+            .last()
+            .and_then(|status| status.signature.parse().ok());
+
+        Ok(CurveSignatureView {
+            curve,
+            signature,
+            error: None,
+            key_source: None,
+            params: Some(params),
+            slot: Some(existing.slot),
+            rent: None,
+            existing: Some(true),
+            cost: None,
+        })
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub async fn alter_curve(
+        &self,
+        curve_key: Pubkey,
+        name: Option<String>,
+        formula: Option<String>,
+        x_decimals: Option<u8>,
+        y_decimals: Option<u8>,
+        x0: Option<CurveX>,
+        x_step: Option<CurveX>,
+        y_count: Option<u8>,
+        y: Option<[CurveY; MAX_Y_CNT]>,
+        priority_rate: Option<u64>,
+        force: bool,
+    ) -> Result<SignatureView> {
+        let owner = self.authority.pubkey();
+
+        let curve_view = self.curve(&curve_key).await?;
+        let curve = curve_view.curve;
+
+        if !force {
+            if curve.is_reference() {
+                self.check_config_admin().await?;
+            } else if curve.owner_threshold > 0 {
+                self.check_co_owners(&curve_key, &curve).await?;
+            } else {
+                self.check_updater(&curve_key, &curve)?;
+            }
+            let delay = curve.alter_delay();
+            if delay > 0 {
+                anyhow::bail!(
+                    "curve {curve_key} is timelocked, propose the alteration and apply it \
+                     {delay} slots later"
+                );
+            }
+        }
+
+        if let Some(guard) = &self.alter_guard {
+            guard.check(&curve_key, force)?;
+        }
+
+        let params = CurveUpdate {
+            name,
+            formula,
+            x_decimals,
+            y_decimals,
+            x0,
+            x_step,
+            y_count,
+            y,
+        }
+        .apply(&curve);
+
+        let mut ixs = vec![];
+
+        if let Some(priority_rate) = priority_rate {
+            let priority_fee_ix = ComputeBudgetInstruction::set_compute_unit_price(priority_rate);
+            ixs.push(priority_fee_ix);
+        }
+
+        let (kind, mut alter_ixs) = alter_instructions(curve_key, owner, &curve, params)?;
+        let signers = self.owner_signers(&curve);
+        if curve.owner_threshold > 0 {
+            let ix = alter_ixs.last_mut().expect("alter instruction");
+            ix.accounts.extend(self.co_owner_metas(&curve_key));
+        }
+        ixs.extend(alter_ixs);
+
+        let signature = self
+            .send_operation(kind, curve_key, Some(params), ixs, &signers)
+            .await?;
+
+        if let Some(guard) = &self.alter_guard {
+            guard.record(&curve_key)?;
+        }
+
+        Ok(self.signature_view(signature).await)
+    }
+
+    /// Sets params of several single-owner curves in one transaction, either all of them or none.
+    /// The number of curves is limited by the transaction size, which depends on their samples.
+    pub async fn batch_alter(
+        &self,
+        updates: Vec<(Pubkey, CurveParams)>,
+        priority_rate: Option<u64>,
+        force: bool,
+    ) -> Result<SignatureView> {
+        let owner = self.authority.pubkey();
+
+        if updates.is_empty() {
+            anyhow::bail!("no curves to alter");
+        }
+
+        let mut ixs = vec![];
+
+        if let Some(priority_rate) = priority_rate {
+            let priority_fee_ix = ComputeBudgetInstruction::set_compute_unit_price(priority_rate);
+            ixs.push(priority_fee_ix);
+        }
+
+        let mut ix = BatchAlter {
+            owner,
+            params: updates.iter().map(|(_, params)| params.into()).collect(),
+        }
+        .into_instruction();
+
+        for (idx, (curve_key, params)) in updates.iter().enumerate() {
+            if updates[..idx].iter().any(|(other, _)| other == curve_key) {
+                anyhow::bail!("curve {curve_key} is listed more than once");
+            }
+
+            let curve = self.curve(curve_key).await?.curve;
+            // co-owned curves are altered by AlterCurve only
+            if curve.owner_threshold > 0 {
+                anyhow::bail!("curve {curve_key} is co-owned, alter it separately");
+            }
+            if params.name != curve.name {
+                anyhow::bail!("curve {curve_key} can not be renamed in a batch, rename it alone");
+            }
+            if !force {
+                self.check_updater(curve_key, &curve)?;
+                let delay = curve.alter_delay();
+                if delay > 0 {
+                    anyhow::bail!(
+                        "curve {curve_key} is timelocked, propose the alteration and apply it \
+                         {delay} slots later"
+                    );
+                }
+            }
+            if let Some(guard) = &self.alter_guard {
+                guard.check(curve_key, force)?;
+            }
+
+            ix.accounts.push(AccountMeta::new(*curve_key, false));
+        }
+        ixs.push(ix);
+
+        let targets = updates
+            .iter()
+            .map(|(curve_key, params)| (*curve_key, Some(*params)))
+            .collect::<Vec<_>>();
+        let signature = self
+            .send_operations(OperationKind::BatchAlter, &targets, ixs, &[&self.authority])
+            .await?;
+
+        if let Some(guard) = &self.alter_guard {
+            for (curve_key, _) in &updates {
+                guard.record(curve_key)?;
+            }
+        }
+
+        Ok(self.signature_view(signature).await)
+    }
+
+    /// Updates name and/or formula without resending `y` samples
+    pub async fn update_curve_metadata(
+        &self,
+        curve_key: Pubkey,
+        name: Option<String>,
+        formula: Option<String>,
+        priority_rate: Option<u64>,
+        force: bool,
+    ) -> Result<SignatureView> {
+        let owner = self.authority.pubkey();
+
+        let curve = self.curve(&curve_key).await?.curve;
+
+        if !force {
+            self.check_owner(&curve_key, &curve)?;
+        }
+
+        if let Some(guard) = &self.alter_guard {
+            guard.check(&curve_key, force)?;
+        }
+
+        let name = name.map_or(curve.name, |name| curvy::state::utils::str_to_array(&name));
+        let formula = formula.map_or(curve.formula, |formula| {
+            curvy::state::utils::str_to_array(&formula)
+        });
+
+        let mut ixs = vec![];
+
+        if let Some(priority_rate) = priority_rate {
+            let priority_fee_ix = ComputeBudgetInstruction::set_compute_unit_price(priority_rate);
+            ixs.push(priority_fee_ix);
+        }
+
+        ixs.push(
+            UpdateCurveMetadata {
+                curve: curve_key,
+                owner,
+                name_link: NameLink::find_address(&owner, &curve.name).0,
+                new_name_link: NameLink::find_address(&owner, &name).0,
+                name,
+                formula,
+            }
+            .into_instruction(),
+        );
+
+        let signature = self
+            .send_operation(
+                OperationKind::UpdateCurveMetadata,
+                curve_key,
+                None,
+                ixs,
+                &[&self.authority],
+            )
+            .await?;
+
+        if let Some(guard) = &self.alter_guard {
+            guard.record(&curve_key)?;
+        }
+
+        Ok(self.signature_view(signature).await)
+    }
+
+    /// Deletes the curve, its rent goes to `rent_destination`, the authority by default
+    pub async fn delete_curve(
+        &self,
+        curve: Pubkey,
+        rent_destination: Option<Pubkey>,
+        priority_rate: Option<u64>,
+        force: bool,
+    ) -> Result<SignatureView> {
+        let owner = self.authority.pubkey();
+
+        let any_curve = self.any_curve(&curve).await?.value;
+        let reference = matches!(&any_curve, AnyCurve::Curve(unpacked) if unpacked.is_reference());
+        if !force {
+            if reference {
+                self.check_config_admin().await?;
+            } else {
+                self.check_owner_key(&curve, any_curve.owner())?;
+            }
+        }
+
+        let mut ixs = vec![];
+
+        if let Some(priority_rate) = priority_rate {
+            let priority_fee_ix = ComputeBudgetInstruction::set_compute_unit_price(priority_rate);
+            ixs.push(priority_fee_ix);
+        }
+
+        if any_curve.is_frozen() {
+            anyhow::bail!("curve {curve} is frozen and can not be deleted");
+        }
+        if !any_curve.status().is_deletable() {
+            anyhow::bail!(
+                "curve {curve} is {}, only draft and retired curves can be deleted",
+                any_curve.status()
+            );
+        }
+        if let Some(from) = any_curve.deletable_from_slot() {
+            let slot = self.rpc.get_slot().await?;
+            if slot < from {
+                anyhow::bail!(
+                    "curve {curve} is marked for deletion, it can be deleted from slot {from} \
+                     ({} slots left)",
+                    from - slot
+                );
+            }
+        }
+
+        // wide and grid curves have neither alter delay nor associated accounts
+        if let AnyCurve::Curve(unpacked) = &any_curve {
+            if unpacked.deletion_slot().is_none() && unpacked.alter_delay() > 0 {
+                anyhow::bail!(
+                    "curve {curve} is timelocked, mark it for deletion and delete it {} slots \
+                     later",
+                    DELETION_GRACE_SLOTS.max(unpacked.alter_delay())
+                );
+            }
+            // cancelling it here would let the curve be created again with new params at once
+            if unpacked.has_pending_alter != 0 {
+                anyhow::bail!("curve {curve} has a pending alteration, cancel it first");
+            }
+            // associated curves can not be deleted, the association goes away with them
+            if let Some(ix) = clear_asset_instruction(curve, owner, unpacked) {
+                ixs.push(ix);
+            }
+            // same for additional series
+            if let Some(ix) = delete_series_instruction(curve, owner, unpacked) {
+                ixs.push(ix);
+            }
+            if let Some(ix) = delete_confidence_instruction(curve, owner, unpacked) {
+                ixs.push(ix);
+            }
+        }
+
+        let name_link = NameLink::find_address(&any_curve.owner(), any_curve.name()).0;
+        if rent_destination.is_some_and(|destination| [curve, name_link].contains(&destination)) {
+            anyhow::bail!("rent destination must differ from the curve and its name link");
+        }
+
+        let mut delete_ix = DeleteCurve {
+            curve,
+            owner,
+            name_link,
+        }
+        .into_instruction();
+        if reference {
+            delete_ix
+                .accounts
+                .push(AccountMeta::new_readonly(Config::find_address().0, false));
+        }
+        // the owner receives the rent without the extra account
+        if let Some(destination) = rent_destination.filter(|destination| *destination != owner) {
+            delete_ix
+                .accounts
+                .push(AccountMeta::new(destination, false));
+        }
+        ixs.push(delete_ix);
+
+        let signature = self
+            .send_operation(
+                OperationKind::DeleteCurve,
+                curve,
+                None,
+                ixs,
+                &[&self.authority],
+            )
+            .await?;
+
+        Ok(self.signature_view(signature).await)
+    }
+
+    /// Lamports missing for rent exemption of the curve account with its current data size
+    pub async fn rent_top_up(&self, curve: &Pubkey) -> Result<u64> {
+        let account = self.get_account(curve).await?;
+        let rent_exempt_minimum = self
+            .rpc
+            .get_minimum_balance_for_rent_exemption(account.data.len())
+            .await?;
+
+        Ok(rent_top_up(rent_exempt_minimum, account.lamports))
+    }
+
+    /// Transfers lamports missing for rent exemption of the curve account from the authority.
+    /// Returns `None` without sending a transaction when nothing is missing.
+    pub async fn top_up_rent(
+        &self,
+        curve: Pubkey,
+        priority_rate: Option<u64>,
+    ) -> Result<Option<SignatureView>> {
+        if self.rent_top_up(&curve).await? == 0 {
+            return Ok(None);
+        }
+
+        let mut ixs = vec![];
+
+        if let Some(priority_rate) = priority_rate {
+            let priority_fee_ix = ComputeBudgetInstruction::set_compute_unit_price(priority_rate);
+            ixs.push(priority_fee_ix);
+        }
+
+        ixs.push(
+            TopUpRent {
+                curve,
+                payer: self.authority.pubkey(),
+            }
+            .into_instruction(),
+        );
+
+        let signature = self
+            .send_operation(
+                OperationKind::TopUpRent,
+                curve,
+                None,
+                ixs,
+                &[&self.authority],
+            )
+            .await?;
+
+        Ok(Some(self.signature_view(signature).await))
+    }
+
+    /// Curve of `owner` associated with `asset_mint` as `kind`
+    pub async fn curve_for_mint(
+        &self,
+        owner: &Pubkey,
+        asset_mint: &Pubkey,
+        kind: u8,
+    ) -> Result<Option<Pubkey>> {
+        let (asset_link, _) = AssetLink::find_address(owner, asset_mint, kind);
+        if !self.account_exists(&asset_link).await? {
+            return Ok(None);
+        }
+
+        let link = self.get_pod_account::<AssetLink>(&asset_link).await?;
+
+        Ok(Some(link.curve))
+    }
+
+    /// Canonical reference curve of `kind` for `asset_mint` created by the program config
+    /// admin. The address is derived from `kind` and `asset_mint` only, so every integrator
+    /// resolves the same curve without exchanging configuration.
+    pub async fn reference_curve(&self, kind: u8, asset_mint: &Pubkey) -> Result<Option<Pubkey>> {
+        let (curve, _) = find_reference_curve_address(kind, asset_mint);
+
+        Ok(self.account_exists(&curve).await?.then_some(curve))
+    }
+
+    /// Number of asset mints the curve is associated with, i.e. how many consumers resolve it
+    /// through [Self::curve_for_mint]
+    pub async fn curve_consumers(&self, curve: &Pubkey) -> Result<usize> {
+        // `curve` is the last field of the link
+        let curve_offset = AssetLink::SIZE - std::mem::size_of::<Pubkey>();
+        let config = RpcProgramAccountsConfig {
+            filters: Some(vec![
+                RpcFilterType::Memcmp(Memcmp::new_raw_bytes(0, AssetLink::DISCRIMINATOR.to_vec())),
+                RpcFilterType::Memcmp(Memcmp::new_raw_bytes(
+                    curve_offset,
+                    curve.to_bytes().to_vec(),
+                )),
+            ]),
+            account_config: RpcAccountInfoConfig {
+                encoding: Some(UiAccountEncoding::Base64),
+                data_slice: Some(UiDataSliceConfig {
+                    offset: 0,
+                    length: 0,
+                }),
+                commitment: Some(self.rpc.commitment()),
+                ..RpcAccountInfoConfig::default()
+            },
+            ..RpcProgramAccountsConfig::default()
+        };
+        let links = self
+            .rpc
+            .get_program_accounts_with_config(&curvy::ID, config)
+            .await?;
+
+        Ok(links.len())
+    }
+
+    /// Curve `owner` created last under `name`, resolved by a single read of its name link.
+    /// Names are registered at creation and follow UpdateCurveMetadata renames and transfers.
+    /// Curves created before the registry are not found.
+    pub async fn resolve_curve(&self, owner: &Pubkey, name: &str) -> Result<Option<Pubkey>> {
+        if name.len() > SYMBOL_MAX_SIZE {
+            anyhow::bail!("name {name} is longer than {SYMBOL_MAX_SIZE} bytes");
+        }
+
+        let name = curvy::state::utils::str_to_array(name);
+        let (name_link, _) = NameLink::find_address(owner, &name);
+        let Some(account) = self
+            .rpc
+            .get_account_with_commitment(&name_link, self.rpc.commitment())
+            .await?
+            .value
+        else {
+            return Ok(None);
+        };
+
+        Ok(Some(NameLink::try_from_bytes(&account.data)?.curve))
+    }
+
+    /// Associates the curve with `asset_mint` as `kind`. Fails when the authority has another
+    /// curve associated with the mint as the same kind.
+    pub async fn set_curve_asset(
+        &self,
+        curve: Pubkey,
+        asset_mint: Pubkey,
+        kind: u8,
+        priority_rate: Option<u64>,
+        force: bool,
+    ) -> Result<SignatureView> {
+        let owner = self.authority.pubkey();
+
+        if !force {
+            let curve_view = self.curve(&curve).await?;
+            self.check_owner(&curve, &curve_view.curve)?;
+        }
+
+        if let Some(existing) = self.curve_for_mint(&owner, &asset_mint, kind).await? {
+            return Err(CurvyClientError::AssetTaken {
+                asset_mint,
+                kind,
+                curve: existing,
+            }
+            .into());
+        }
+
+        let mut ixs = vec![];
+
+        if let Some(priority_rate) = priority_rate {
+            let priority_fee_ix = ComputeBudgetInstruction::set_compute_unit_price(priority_rate);
+            ixs.push(priority_fee_ix);
+        }
+
+        ixs.push(
+            SetCurveAsset {
+                curve,
+                owner,
+                asset_mint,
+                asset_link: AssetLink::find_address(&owner, &asset_mint, kind).0,
+                kind,
+            }
+            .into_instruction(),
+        );
+
+        let signature = self
+            .send_operation(
+                OperationKind::SetCurveAsset,
+                curve,
+                None,
+                ixs,
+                &[&self.authority],
+            )
+            .await?;
+
+        Ok(self.signature_view(signature).await)
+    }
+
+    /// Removes association of the curve with its asset mint
+    pub async fn clear_curve_asset(
+        &self,
+        curve: Pubkey,
+        priority_rate: Option<u64>,
+        force: bool,
+    ) -> Result<SignatureView> {
+        let owner = self.authority.pubkey();
+
+        let curve_view = self.curve(&curve).await?;
+        if !force {
+            self.check_owner(&curve, &curve_view.curve)?;
+        }
+
+        let ix = clear_asset_instruction(curve, owner, &curve_view.curve)
+            .ok_or_else(|| anyhow::anyhow!("curve {curve} is not associated with asset"))?;
+
+        let mut ixs = vec![];
+
+        if let Some(priority_rate) = priority_rate {
+            let priority_fee_ix = ComputeBudgetInstruction::set_compute_unit_price(priority_rate);
+            ixs.push(priority_fee_ix);
+        }
+
+        ixs.push(ix);
+
+        let signature = self
+            .send_operation(
+                OperationKind::ClearCurveAsset,
+                curve,
+                None,
+                ixs,
+                &[&self.authority],
+            )
+            .await?;
+
+        Ok(self.signature_view(signature).await)
+    }
+
+    /// Upgrades the curve account to the current layout, the authority pays for the grown
+    /// account. Works for curves of any owner.
+    pub async fn migrate_curve(
+        &self,
+        curve: Pubkey,
+        priority_rate: Option<u64>,
+    ) -> Result<SignatureView> {
+        let mut ixs = vec![];
+
+        if let Some(priority_rate) = priority_rate {
+            let priority_fee_ix = ComputeBudgetInstruction::set_compute_unit_price(priority_rate);
+            ixs.push(priority_fee_ix);
+        }
+
+        ixs.push(
+            MigrateCurve {
+                curve,
+                payer: self.authority.pubkey(),
+            }
+            .into_instruction(),
+        );
+
+        let signature = self
+            .send_operation(
+                OperationKind::MigrateCurve,
+                curve,
+                None,
+                ixs,
+                &[&self.authority],
+            )
+            .await?;
+
+        Ok(self.signature_view(signature).await)
+    }
+
+    /// Additional `y` series of the curve, `None` when it has none
+    pub async fn curve_series(&self, curve: &Pubkey) -> Result<Option<WithContext<CurveSeries>>> {
+        let (curve_series, _) = CurveSeries::find_address(curve);
+        if !self.account_exists(&curve_series).await? {
+            return Ok(None);
+        }
+
+        Ok(Some(self.get_pod_account(&curve_series).await?))
+    }
+
+    /// Sets `y` table of additional series `series` (from 1) of the curve. The table shares X
+    /// grid of the curve, values past its `y_count` are ignored.
+    pub async fn set_curve_series(
+        &self,
+        curve: Pubkey,
+        series: u8,
+        y: [CurveY; MAX_Y_CNT],
+        priority_rate: Option<u64>,
+        force: bool,
+    ) -> Result<SignatureView> {
+        let owner = self.authority.pubkey();
+
+        let curve_view = self.curve(&curve).await?;
+        if !force {
+            self.check_owner(&curve, &curve_view.curve)?;
+        }
+
+        if series == 0 || series as usize > MAX_EXTRA_SERIES {
+            anyhow::bail!("series must be in range 1..={MAX_EXTRA_SERIES}, got {series}");
+        }
+        if series > curve_view.curve.series_count + 1 {
+            anyhow::bail!(
+                "series {} must be set before series {series}",
+                curve_view.curve.series_count + 1
+            );
+        }
+
+        let mut ixs = vec![];
+
+        if let Some(priority_rate) = priority_rate {
+            let priority_fee_ix = ComputeBudgetInstruction::set_compute_unit_price(priority_rate);
+            ixs.push(priority_fee_ix);
+        }
+
+        ixs.push(
+            SetCurveSeries {
+                curve,
+                owner,
+                curve_series: CurveSeries::find_address(&curve).0,
+                series,
+                y,
+            }
+            .into_instruction(),
+        );
+
+        let signature = self
+            .send_operation(
+                OperationKind::SetCurveSeries,
+                curve,
+                None,
+                ixs,
+                &[&self.authority],
+            )
+            .await?;
+
+        Ok(self.signature_view(signature).await)
+    }
+
+    /// Removes all additional series of the curve
+    pub async fn delete_curve_series(
+        &self,
+        curve: Pubkey,
+        priority_rate: Option<u64>,
+        force: bool,
+    ) -> Result<SignatureView> {
+        let owner = self.authority.pubkey();
+
+        let curve_view = self.curve(&curve).await?;
+        if !force {
+            self.check_owner(&curve, &curve_view.curve)?;
+        }
+
+        let ix = delete_series_instruction(curve, owner, &curve_view.curve)
+            .ok_or_else(|| anyhow::anyhow!("curve {curve} has no additional series"))?;
+
+        let mut ixs = vec![];
+
+        if let Some(priority_rate) = priority_rate {
+            let priority_fee_ix = ComputeBudgetInstruction::set_compute_unit_price(priority_rate);
+            ixs.push(priority_fee_ix);
+        }
+
+        ixs.push(ix);
+
+        let signature = self
+            .send_operation(
+                OperationKind::DeleteCurveSeries,
+                curve,
+                None,
+                ixs,
+                &[&self.authority],
+            )
+            .await?;
+
+        Ok(self.signature_view(signature).await)
+    }
+
+    /// Per-sample confidence of the curve, `None` when it has none
+    pub async fn curve_confidence(
+        &self,
+        curve: &Pubkey,
+    ) -> Result<Option<WithContext<CurveConfidence>>> {
+        let (curve_confidence, _) = CurveConfidence::find_address(curve);
+        if !self.account_exists(&curve_confidence).await? {
+            return Ok(None);
+        }
+
+        Ok(Some(self.get_pod_account(&curve_confidence).await?))
+    }
+
+    /// Sets per-sample confidence of the curve in basis points of its `y`. Values past its
+    /// `y_count` are ignored.
+    pub async fn set_curve_confidence(
+        &self,
+        curve: Pubkey,
+        confidence: [CurveConfidenceBps; MAX_Y_CNT],
+        priority_rate: Option<u64>,
+        force: bool,
+    ) -> Result<SignatureView> {
+        let owner = self.authority.pubkey();
+
+        if !force {
+            let curve_view = self.curve(&curve).await?;
+            self.check_owner(&curve, &curve_view.curve)?;
+        }
+
+        let mut ixs = vec![];
+
+        if let Some(priority_rate) = priority_rate {
+            let priority_fee_ix = ComputeBudgetInstruction::set_compute_unit_price(priority_rate);
+            ixs.push(priority_fee_ix);
+        }
+
+        ixs.push(
+            SetCurveConfidence {
+                curve,
+                owner,
+                curve_confidence: CurveConfidence::find_address(&curve).0,
+                confidence,
+            }
+            .into_instruction(),
+        );
+
+        let signature = self
+            .send_operation(
+                OperationKind::SetCurveConfidence,
+                curve,
+                None,
+                ixs,
+                &[&self.authority],
+            )
+            .await?;
+
+        Ok(self.signature_view(signature).await)
+    }
+
+    /// Removes per-sample confidence of the curve
+    pub async fn delete_curve_confidence(
+        &self,
+        curve: Pubkey,
+        priority_rate: Option<u64>,
+        force: bool,
+    ) -> Result<SignatureView> {
+        let owner = self.authority.pubkey();
+
+        let curve_view = self.curve(&curve).await?;
+        if !force {
+            self.check_owner(&curve, &curve_view.curve)?;
+        }
+
+        let ix = delete_confidence_instruction(curve, owner, &curve_view.curve)
+            .ok_or_else(|| anyhow::anyhow!("curve {curve} has no confidence"))?;
+
+        let mut ixs = vec![];
+
+        if let Some(priority_rate) = priority_rate {
+            let priority_fee_ix = ComputeBudgetInstruction::set_compute_unit_price(priority_rate);
+            ixs.push(priority_fee_ix);
+        }
+
+        ixs.push(ix);
+
+        let signature = self
+            .send_operation(
+                OperationKind::DeleteCurveConfidence,
+                curve,
+                None,
+                ixs,
+                &[&self.authority],
+            )
+            .await?;
+
+        Ok(self.signature_view(signature).await)
+    }
+
+    /// Curve of any layout, see [AnyCurve]
+    pub async fn any_curve(&self, key: &Pubkey) -> Result<WithContext<AnyCurve>> {
+        self.get_account(key).await?.try_map(|account| {
+            if account.owner != curvy::ID {
+                anyhow::bail!("account is owned by {} not by Curvy program", account.owner);
+            }
+
+            AnyCurve::from_bytes(&account.data)
+        })
+    }
+
+    pub async fn wide_curve(&self, key: &Pubkey) -> Result<WithContext<WideCurve>> {
+        self.any_curve(key).await?.try_map(|curve| match curve {
+            AnyCurve::Wide(curve) => Ok(curve),
+            AnyCurve::Curve(_) => anyhow::bail!("{key} is a Curve, not a WideCurve"),
+            AnyCurve::Grid(_) => anyhow::bail!("{key} is a GridCurve, not a WideCurve"),
+        })
+    }
+
+    pub async fn grid_curve(&self, key: &Pubkey) -> Result<WithContext<GridCurve>> {
+        self.any_curve(key).await?.try_map(|curve| match curve {
+            AnyCurve::Grid(curve) => Ok(curve),
+            AnyCurve::Curve(_) => anyhow::bail!("{key} is a Curve, not a GridCurve"),
+            AnyCurve::Wide(_) => anyhow::bail!("{key} is a WideCurve, not a GridCurve"),
+        })
+    }
+
+    /// All wide curves of the program
+    pub async fn wide_curves(&self) -> Result<WithContext<HashMap<Pubkey, WideCurve>>> {
+        let (curves, slot) = load_accounts(&self.rpc, &curvy::ID).await?;
+        Ok(self.with_context(curves, slot))
+    }
+
+    /// Creates wide curve at the address of `curve_keypair`, a fresh one when omitted
+    pub async fn create_wide_curve(
+        &self,
+        params: WideCurveParams,
+        curve_keypair: Option<&Keypair>,
+        priority_rate: Option<u64>,
+    ) -> Result<CurveSignatureView> {
+        let generated = Keypair::new();
+        let (curve_keypair, key_source) = match curve_keypair {
+            Some(curve_keypair) => (curve_keypair, CurveKeySource::Provided),
+            None => (&generated, CurveKeySource::Generated),
+        };
+        let owner = self.authority.pubkey();
+        let curve = curve_keypair.pubkey();
+
+        let rent = self
+            .rpc
+            .get_minimum_balance_for_rent_exemption(WideCurve::SIZE)
+            .await?;
+
+        let mut ixs = vec![];
+
+        if let Some(priority_rate) = priority_rate {
+            let priority_fee_ix = ComputeBudgetInstruction::set_compute_unit_price(priority_rate);
+            ixs.push(priority_fee_ix);
+        }
+
+        ixs.push(
+            CreateWideCurve {
+                curve,
+                owner,
+                config: Config::find_address().0,
+                name_link: NameLink::find_address(&owner, &params.name).0,
+                params,
+            }
+            .into_instruction(),
+        );
+
+        let signature = self
+            .send_operation(
+                OperationKind::CreateWideCurve,
+                curve,
+                None,
+                ixs,
+                &[&self.authority, curve_keypair],
+            )
+            .await?;
+
+        let slot = self.signature_slot(&signature).await?;
+
+        let mut view = CurveSignatureView::success(curve, signature).with_key_source(key_source);
+        view.slot = slot;
+        view.rent = Some(rent);
+        view.cost = self.transaction_cost(&signature).await;
+
+        Ok(view)
+    }
+
+    /// Replaces all params of the wide curve
+    pub async fn alter_wide_curve(
+        &self,
+        curve: Pubkey,
+        params: WideCurveParams,
+        priority_rate: Option<u64>,
+        force: bool,
+    ) -> Result<SignatureView> {
+        let owner = self.authority.pubkey();
+
+        if !force {
+            let current = self.wide_curve(&curve).await?;
+            self.check_owner_key(&curve, current.owner)?;
+            if params.name != current.name {
+                anyhow::bail!("wide curve {curve} can not be renamed");
+            }
+        }
+
+        let mut ixs = vec![];
+
+        if let Some(priority_rate) = priority_rate {
+            let priority_fee_ix = ComputeBudgetInstruction::set_compute_unit_price(priority_rate);
+            ixs.push(priority_fee_ix);
+        }
+
+        ixs.push(
+            AlterWideCurve {
+                curve,
+                owner,
+                params,
+            }
+            .into_instruction(),
+        );
+
+        let signature = self
+            .send_operation(
+                OperationKind::AlterWideCurve,
+                curve,
+                None,
+                ixs,
+                &[&self.authority],
+            )
+            .await?;
+
+        Ok(self.signature_view(signature).await)
+    }
+
+    /// Creates grid curve at the address of `curve_keypair`, a fresh one when omitted
+    pub async fn create_grid_curve(
+        &self,
+        params: GridCurveParams,
+        curve_keypair: Option<&Keypair>,
+        priority_rate: Option<u64>,
+    ) -> Result<CurveSignatureView> {
+        let generated = Keypair::new();
+        let (curve_keypair, key_source) = match curve_keypair {
+            Some(curve_keypair) => (curve_keypair, CurveKeySource::Provided),
+            None => (&generated, CurveKeySource::Generated),
+        };
+        let owner = self.authority.pubkey();
+        let curve = curve_keypair.pubkey();
+
+        let rent = self
+            .rpc
+            .get_minimum_balance_for_rent_exemption(GridCurve::SIZE)
+            .await?;
+
+        let mut ixs = vec![];
+
+        if let Some(priority_rate) = priority_rate {
+            let priority_fee_ix = ComputeBudgetInstruction::set_compute_unit_price(priority_rate);
+            ixs.push(priority_fee_ix);
+        }
+
+        ixs.push(
+            CreateGridCurve {
+                curve,
+                owner,
+                config: Config::find_address().0,
+                name_link: NameLink::find_address(&owner, &params.name).0,
+                params,
+            }
+            .into_instruction(),
+        );
+
+        let signature = self
+            .send_operation(
+                OperationKind::CreateGridCurve,
+                curve,
+                None,
+                ixs,
+                &[&self.authority, curve_keypair],
+            )
+            .await?;
+
+        let slot = self.signature_slot(&signature).await?;
+
+        let mut view = CurveSignatureView::success(curve, signature).with_key_source(key_source);
+        view.slot = slot;
+        view.rent = Some(rent);
+        view.cost = self.transaction_cost(&signature).await;
+
+        Ok(view)
+    }
+
+    /// Replaces all params of the grid curve
+    pub async fn alter_grid_curve(
+        &self,
+        curve: Pubkey,
+        params: GridCurveParams,
+        priority_rate: Option<u64>,
+        force: bool,
+    ) -> Result<SignatureView> {
+        let owner = self.authority.pubkey();
+
+        if !force {
+            let current = self.grid_curve(&curve).await?;
+            self.check_owner_key(&curve, current.owner)?;
+            if params.name != current.name {
+                anyhow::bail!("grid curve {curve} can not be renamed");
+            }
+        }
+
+        let mut ixs = vec![];
+
+        if let Some(priority_rate) = priority_rate {
+            let priority_fee_ix = ComputeBudgetInstruction::set_compute_unit_price(priority_rate);
+            ixs.push(priority_fee_ix);
+        }
+
+        ixs.push(
+            AlterGridCurve {
+                curve,
+                owner,
+                params,
+            }
+            .into_instruction(),
+        );
+
+        let signature = self
+            .send_operation(
+                OperationKind::AlterGridCurve,
+                curve,
+                None,
+                ixs,
+                &[&self.authority],
+            )
+            .await?;
+
+        Ok(self.signature_view(signature).await)
+    }
+
+    /// Sets sign of `x0` of the curve, see [Curve::x0_negative]
+    pub async fn set_curve_x_sign(
+        &self,
+        curve: Pubkey,
+        x0_negative: bool,
+        priority_rate: Option<u64>,
+        force: bool,
+    ) -> Result<SignatureView> {
+        let owner = self.authority.pubkey();
+
+        if !force {
+            let curve_view = self.curve(&curve).await?;
+            self.check_owner(&curve, &curve_view.curve)?;
+        }
+
+        let mut ixs = vec![];
+
+        if let Some(priority_rate) = priority_rate {
+            let priority_fee_ix = ComputeBudgetInstruction::set_compute_unit_price(priority_rate);
+            ixs.push(priority_fee_ix);
+        }
+
+        ixs.push(
+            SetCurveXSign {
+                curve,
+                owner,
+                x0_negative,
+            }
+            .into_instruction(),
+        );
+
+        let signature = self
+            .send_operation(
+                OperationKind::SetCurveXSign,
+                curve,
+                None,
+                ixs,
+                &[&self.authority],
+            )
+            .await?;
+
+        Ok(self.signature_view(signature).await)
+    }
+
+    /// Sets whether `y` values of the curve are signed, see [Curve::y_signed]
+    pub async fn set_curve_y_sign(
+        &self,
+        curve: Pubkey,
+        y_signed: bool,
+        priority_rate: Option<u64>,
+        force: bool,
+    ) -> Result<SignatureView> {
+        let owner = self.authority.pubkey();
+
+        if !force {
+            let curve_view = self.curve(&curve).await?;
+            self.check_owner(&curve, &curve_view.curve)?;
+        }
+
+        let mut ixs = vec![];
+
+        if let Some(priority_rate) = priority_rate {
+            let priority_fee_ix = ComputeBudgetInstruction::set_compute_unit_price(priority_rate);
+            ixs.push(priority_fee_ix);
+        }
+
+        ixs.push(
+            SetCurveYSign {
+                curve,
+                owner,
+                y_signed,
+            }
+            .into_instruction(),
+        );
+
+        let signature = self
+            .send_operation(
+                OperationKind::SetCurveYSign,
+                curve,
+                None,
+                ixs,
+                &[&self.authority],
+            )
+            .await?;
+
+        Ok(self.signature_view(signature).await)
+    }
+
+    /// Sets how stored `y` values map to the values of the curve, see [YTransform]. Samples
+    /// are not converted.
+    pub async fn set_curve_y_transform(
+        &self,
+        curve: Pubkey,
+        y_transform: YTransform,
+        priority_rate: Option<u64>,
+        force: bool,
+    ) -> Result<SignatureView> {
+        let owner = self.authority.pubkey();
+
+        if !force {
+            let curve_view = self.curve(&curve).await?;
+            self.check_owner(&curve, &curve_view.curve)?;
+        }
+
+        let mut ixs = vec![];
+
+        if let Some(priority_rate) = priority_rate {
+            let priority_fee_ix = ComputeBudgetInstruction::set_compute_unit_price(priority_rate);
+            ixs.push(priority_fee_ix);
+        }
+
+        ixs.push(
+            SetCurveYTransform {
+                curve,
+                owner,
+                y_transform: y_transform as u8,
+            }
+            .into_instruction(),
+        );
+
+        let signature = self
+            .send_operation(
+                OperationKind::SetCurveYTransform,
+                curve,
+                None,
+                ixs,
+                &[&self.authority],
+            )
+            .await?;
+
+        Ok(self.signature_view(signature).await)
+    }
+
+    /// Sets the unix timestamp the curve is stale from (`None` to never expire) and the
+    /// seconds sample updates move it ahead (zero to keep it), see [Curve::valid_until]
+    pub async fn set_curve_expiry(
+        &self,
+        curve: Pubkey,
+        valid_until: Option<i64>,
+        validity_period: u64,
+        priority_rate: Option<u64>,
+        force: bool,
+    ) -> Result<SignatureView> {
+        let owner = self.authority.pubkey();
+
+        if !force {
+            let curve_view = self.curve(&curve).await?;
+            self.check_owner(&curve, &curve_view.curve)?;
+        }
+
+        let mut ixs = vec![];
+
+        if let Some(priority_rate) = priority_rate {
+            let priority_fee_ix = ComputeBudgetInstruction::set_compute_unit_price(priority_rate);
+            ixs.push(priority_fee_ix);
+        }
+
+        ixs.push(
+            SetCurveExpiry {
+                curve,
+                owner,
+                valid_until: valid_until.unwrap_or_default(),
+                validity_period,
+            }
+            .into_instruction(),
+        );
+
+        let signature = self
+            .send_operation(
+                OperationKind::SetCurveExpiry,
+                curve,
+                None,
+                ixs,
+                &[&self.authority],
+            )
+            .await?;
+
+        Ok(self.signature_view(signature).await)
+    }
+
+    /// Moves the curve to another lifecycle stage, see [CurveStatus]
+    pub async fn set_curve_status(
+        &self,
+        curve: Pubkey,
+        status: CurveStatus,
+        priority_rate: Option<u64>,
+        force: bool,
+    ) -> Result<SignatureView> {
+        let owner = self.authority.pubkey();
+
+        let any_curve = self.any_curve(&curve).await?.value;
+        let reference = matches!(&any_curve, AnyCurve::Curve(unpacked) if unpacked.is_reference());
+        if !force {
+            if reference {
+                self.check_config_admin().await?;
+            } else {
+                self.check_owner_key(&curve, any_curve.owner())?;
+            }
+
+            let current = any_curve.status();
+            if !current.can_become(status) {
+                anyhow::bail!("curve {curve} status can not change from {current} to {status}");
+            }
+        }
+
+        let mut ixs = vec![];
+
+        if let Some(priority_rate) = priority_rate {
+            let priority_fee_ix = ComputeBudgetInstruction::set_compute_unit_price(priority_rate);
+            ixs.push(priority_fee_ix);
+        }
+
+        let mut status_ix = SetCurveStatus {
+            curve,
+            owner,
+            status: status as u8,
+        }
+        .into_instruction();
+        if reference {
+            status_ix
+                .accounts
+                .push(AccountMeta::new_readonly(Config::find_address().0, false));
+        }
+        ixs.push(status_ix);
+
+        let signature = self
+            .send_operation(
+                OperationKind::SetCurveStatus,
+                curve,
+                None,
+                ixs,
+                &[&self.authority],
+            )
+            .await?;
+
+        Ok(self.signature_view(signature).await)
+    }
+
+    /// Freezes the curve, so it can never be changed or deleted
+    pub async fn freeze_curve(
+        &self,
+        curve: Pubkey,
+        priority_rate: Option<u64>,
+        force: bool,
+    ) -> Result<SignatureView> {
+        let owner = self.authority.pubkey();
+
+        if !force {
+            let any_curve = self.any_curve(&curve).await?.value;
+            self.check_owner_key(&curve, any_curve.owner())?;
+        }
+
+        let mut ixs = vec![];
+
+        if let Some(priority_rate) = priority_rate {
+            let priority_fee_ix = ComputeBudgetInstruction::set_compute_unit_price(priority_rate);
+            ixs.push(priority_fee_ix);
+        }
+
+        ixs.push(FreezeCurve { curve, owner }.into_instruction());
+
+        let signature = self
+            .send_operation(
+                OperationKind::FreezeCurve,
+                curve,
+                None,
+                ixs,
+                &[&self.authority],
+            )
+            .await?;
+
+        Ok(self.signature_view(signature).await)
+    }
+
+    /// Marks the curve for deletion, DeleteCurve accepts it
+    /// [DELETION_GRACE_SLOTS](curvy::state::curve::DELETION_GRACE_SLOTS) or its alter delay
+    /// later
+    pub async fn mark_for_deletion(
+        &self,
+        curve: Pubkey,
+        priority_rate: Option<u64>,
+        force: bool,
+    ) -> Result<SignatureView> {
+        let owner = self.authority.pubkey();
+
+        if !force {
+            let any_curve = self.any_curve(&curve).await?.value;
+            self.check_owner_key(&curve, any_curve.owner())?;
+            if let Some(slot) = any_curve.deletion_slot() {
+                anyhow::bail!("curve {curve} is already marked for deletion in slot {slot}");
+            }
+        }
+
+        let mut ixs = vec![];
+
+        if let Some(priority_rate) = priority_rate {
+            let priority_fee_ix = ComputeBudgetInstruction::set_compute_unit_price(priority_rate);
+            ixs.push(priority_fee_ix);
+        }
+
+        ixs.push(MarkForDeletion { curve, owner }.into_instruction());
+
+        let signature = self
+            .send_operation(
+                OperationKind::MarkForDeletion,
+                curve,
+                None,
+                ixs,
+                &[&self.authority],
+            )
+            .await?;
+
+        Ok(self.signature_view(signature).await)
+    }
+
+    /// Removes the mark set by [CurvyClient::mark_for_deletion]
+    pub async fn cancel_deletion(
+        &self,
+        curve: Pubkey,
+        priority_rate: Option<u64>,
+        force: bool,
+    ) -> Result<SignatureView> {
+        let owner = self.authority.pubkey();
+
+        if !force {
+            let any_curve = self.any_curve(&curve).await?.value;
+            self.check_owner_key(&curve, any_curve.owner())?;
+            if any_curve.deletion_slot().is_none() {
+                anyhow::bail!("curve {curve} is not marked for deletion");
+            }
+        }
+
+        let mut ixs = vec![];
+
+        if let Some(priority_rate) = priority_rate {
+            let priority_fee_ix = ComputeBudgetInstruction::set_compute_unit_price(priority_rate);
+            ixs.push(priority_fee_ix);
+        }
+
+        ixs.push(CancelDeletion { curve, owner }.into_instruction());
+
+        let signature = self
+            .send_operation(
+                OperationKind::CancelDeletion,
+                curve,
+                None,
+                ixs,
+                &[&self.authority],
+            )
+            .await?;
+
+        Ok(self.signature_view(signature).await)
+    }
+
+    /// Overwrites `y` samples of the curve starting at `start_index` (raw values, see
+    /// [Curve::y_value]), all of them within `y_count`
+    pub async fn patch_y(
+        &self,
+        curve: Pubkey,
+        start_index: u8,
+        values: &[CurveY],
+        priority_rate: Option<u64>,
+        force: bool,
+    ) -> Result<SignatureView> {
+        let owner = self.authority.pubkey();
+
+        if !force {
+            let curve_view = self.curve(&curve).await?;
+            self.check_updater(&curve, &curve_view.curve)?;
+
+            let end = start_index as usize + values.len();
+            if values.is_empty() || end > curve_view.curve.y_count as usize {
+                anyhow::bail!(
+                    "samples {start_index}..{end} are out of y table of {} samples",
+                    curve_view.curve.y_count
+                );
+            }
+        }
+
+        let mut ixs = vec![];
+
+        if let Some(priority_rate) = priority_rate {
+            let priority_fee_ix = ComputeBudgetInstruction::set_compute_unit_price(priority_rate);
+            ixs.push(priority_fee_ix);
+        }
+
+        ixs.push(
+            PatchY {
+                curve,
+                owner,
+                start_index,
+                values: values.to_vec(),
+            }
+            .into_instruction(),
+        );
+
+        let signature = self
+            .send_operation(OperationKind::PatchY, curve, None, ixs, &[&self.authority])
+            .await?;
+
+        Ok(self.signature_view(signature).await)
+    }
+
+    /// Sets samples following the full `y` table of the curve, growing the account by
+    /// ResizeCurve first when it is too small. Samples are sent in chunks of
+    /// [MAX_TAIL_CHUNK], one transaction each, empty `tail` removes the tail.
+    pub async fn set_curve_tail(
+        &self,
+        curve: Pubkey,
+        tail: &[CurveY],
+        priority_rate: Option<u64>,
+        force: bool,
+    ) -> Result<Vec<SignatureView>> {
+        let owner = self.authority.pubkey();
+
+        let curve_view = self.curve(&curve).await?;
+        if !force {
+            self.check_owner(&curve, &curve_view.curve)?;
+        }
+
+        let y_capacity = MAX_Y_CNT + tail.len();
+        if y_capacity > MAX_TOTAL_Y_CNT {
+            anyhow::bail!(
+                "curve can hold at most {MAX_TOTAL_Y_CNT} samples, got {y_capacity} with the tail"
+            );
+        }
+        if !tail.is_empty() && curve_view.curve.y_count as usize != MAX_Y_CNT {
+            anyhow::bail!(
+                "y table must be full ({MAX_Y_CNT} samples) to have a tail, it has {}",
+                curve_view.curve.y_count
+            );
+        }
+
+        let data_len = curve_view
+            .account
+            .as_ref()
+            .map(|account| account.data_len)
+            .unwrap_or(Curve::SIZE);
+        let data_len = data_len - curve_view.curve.extensions_len();
+        let mut resize = (Curve::y_capacity(data_len) < y_capacity).then_some(ResizeCurve {
+            curve,
+            owner,
+            y_capacity: y_capacity as u16,
+        });
+
+        let chunks = tail.chunks(MAX_TAIL_CHUNK).map(|chunk| chunk.to_vec());
+        let chunks = if tail.is_empty() {
+            vec![vec![]]
+        } else {
+            chunks.collect()
+        };
+
+        let mut signatures = vec![];
+
+        for (idx, y) in chunks.into_iter().enumerate() {
+            let mut ixs = vec![];
+
+            if let Some(priority_rate) = priority_rate {
+                let priority_fee_ix =
+                    ComputeBudgetInstruction::set_compute_unit_price(priority_rate);
+                ixs.push(priority_fee_ix);
+            }
+
+            if let Some(resize) = resize.take() {
+                ixs.push(resize.into_instruction());
+            }
+
+            ixs.push(
+                SetCurveTail {
+                    curve,
+                    owner,
+                    start: (idx * MAX_TAIL_CHUNK) as u16,
+                    y,
+                }
+                .into_instruction(),
+            );
+
+            let signature = self
+                .send_operation(
+                    OperationKind::SetCurveTail,
+                    curve,
+                    None,
+                    ixs,
+                    &[&self.authority],
+                )
+                .await?;
+            signatures.push(self.signature_view(signature).await);
+        }
+
+        Ok(signatures)
+    }
+
+    /// Evaluates the curve at `x` (scaled by its `x_decimals`) on chain storing the result in
+    /// the `result` account, which is created by the authority when it does not exist yet
+    pub async fn evaluate_curve(
+        &self,
+        curve: Pubkey,
+        result: &Keypair,
+        x: i64,
+        priority_rate: Option<u64>,
+    ) -> Result<SignatureView> {
+        let authority = self.authority.pubkey();
+
+        let mut ixs = vec![];
+
+        if let Some(priority_rate) = priority_rate {
+            let priority_fee_ix = ComputeBudgetInstruction::set_compute_unit_price(priority_rate);
+            ixs.push(priority_fee_ix);
+        }
+
+        let mut signers = vec![&self.authority];
+        if !self.account_exists(&result.pubkey()).await? {
+            let rent = self
+                .rpc
+                .get_minimum_balance_for_rent_exemption(CurveEvaluation::SIZE)
+                .await?;
+            ixs.push(system_instruction::create_account(
+                &authority,
+                &result.pubkey(),
+                rent,
+                CurveEvaluation::SIZE as u64,
+                &curvy::ID,
+            ));
+            signers.push(result);
+        }
+
+        ixs.push(
+            EvaluateCurve {
+                curve,
+                result: result.pubkey(),
+                authority,
+                x,
+            }
+            .into_instruction(),
+        );
+
+        let signature = self
+            .send_operation(OperationKind::EvaluateCurve, curve, None, ixs, &signers)
+            .await?;
+
+        Ok(self.signature_view(signature).await)
+    }
+
+    /// Result written by [CurvyClient::evaluate_curve]
+    pub async fn curve_evaluation(&self, result: &Pubkey) -> Result<WithContext<CurveEvaluation>> {
+        self.get_pod_account(result).await
+    }
+
+    /// Proposes `new_owner` of the curve, who takes it over by [CurvyClient::accept_owner].
+    /// Proposing [Pubkey::default] cancels the proposal.
+    pub async fn propose_owner(
+        &self,
+        curve: Pubkey,
+        new_owner: Pubkey,
+        priority_rate: Option<u64>,
+        force: bool,
+    ) -> Result<SignatureView> {
+        let owner = self.authority.pubkey();
+
+        if !force {
+            let curve_view = self.curve(&curve).await?;
+            self.check_owner(&curve, &curve_view.curve)?;
+        }
+
+        let mut ixs = vec![];
+
+        if let Some(priority_rate) = priority_rate {
+            let priority_fee_ix = ComputeBudgetInstruction::set_compute_unit_price(priority_rate);
+            ixs.push(priority_fee_ix);
+        }
+
+        ixs.push(
+            ProposeOwner {
+                curve,
+                owner,
+                new_owner,
+            }
+            .into_instruction(),
+        );
+
+        let signature = self
+            .send_operation(
+                OperationKind::ProposeOwner,
+                curve,
+                None,
+                ixs,
+                &[&self.authority],
+            )
+            .await?;
+
+        Ok(self.signature_view(signature).await)
+    }
+
+    /// Takes over the curve proposed to the client authority by [CurvyClient::propose_owner]
+    pub async fn accept_owner(
+        &self,
+        curve: Pubkey,
+        priority_rate: Option<u64>,
+        force: bool,
+    ) -> Result<SignatureView> {
+        let new_owner = self.authority.pubkey();
+
+        let curve_view = self.curve(&curve).await?;
+        if !force && curve_view.curve.pending_owner() != Some(new_owner) {
+            return Err(CurvyClientError::NotPendingOwner {
+                curve,
+                authority: new_owner,
+            }
+            .into());
+        }
+        if !force && curve_view.curve.owner_threshold > 0 {
+            anyhow::bail!("curve {curve} is co-owned and can not be transferred");
+        }
+
+        let mut ixs = vec![];
+
+        if let Some(priority_rate) = priority_rate {
+            let priority_fee_ix = ComputeBudgetInstruction::set_compute_unit_price(priority_rate);
+            ixs.push(priority_fee_ix);
+        }
+
+        let owner = curve_view.curve.owner;
+        let name = curve_view.curve.name;
+        ixs.push(
+            AcceptOwner {
+                curve,
+                new_owner,
+                owner,
+                name_link: NameLink::find_address(&owner, &name).0,
+                new_name_link: NameLink::find_address(&new_owner, &name).0,
+            }
+            .into_instruction(),
+        );
+
+        let signature = self
+            .send_operation(
+                OperationKind::AcceptOwner,
+                curve,
+                None,
+                ixs,
+                &[&self.authority],
+            )
+            .await?;
+
+        Ok(self.signature_view(signature).await)
+    }
+
+    /// Timelocks alterations of the curve: its params change only by
+    /// [CurvyClient::propose_alter] and [CurvyClient::apply_alter] `slots` later. The delay can
+    /// only grow.
+    pub async fn set_alter_delay(
+        &self,
+        curve: Pubkey,
+        slots: u64,
+        priority_rate: Option<u64>,
+        force: bool,
+    ) -> Result<SignatureView> {
+        let owner = self.authority.pubkey();
+
+        if !force {
+            let curve_view = self.curve(&curve).await?;
+            self.check_owner(&curve, &curve_view.curve)?;
+            let delay = curve_view.curve.alter_delay();
+            if slots < delay {
+                anyhow::bail!("curve {curve} alter delay {delay} slots can not be decreased");
+            }
+        }
+
+        let mut ixs = vec![];
+
+        if let Some(priority_rate) = priority_rate {
+            let priority_fee_ix = ComputeBudgetInstruction::set_compute_unit_price(priority_rate);
+            ixs.push(priority_fee_ix);
+        }
+
+        ixs.push(
+            SetAlterDelay {
+                curve,
+                owner,
+                slots,
+            }
+            .into_instruction(),
+        );
+
+        let signature = self
+            .send_operation(
+                OperationKind::SetAlterDelay,
+                curve,
+                None,
+                ixs,
+                &[&self.authority],
+            )
+            .await?;
+
+        Ok(self.signature_view(signature).await)
+    }
+
+    /// Alteration proposed by [CurvyClient::propose_alter], `None` when there is none
+    pub async fn pending_alter(&self, curve: &Pubkey) -> Result<Option<WithContext<PendingAlter>>> {
+        let (pending_alter, _) = PendingAlter::find_address(curve);
+        if !self.account_exists(&pending_alter).await? {
+            return Ok(None);
+        }
+
+        Ok(Some(self.get_pod_account(&pending_alter).await?))
+    }
+
+    /// Proposes new params of the timelocked curve, [CurvyClient::apply_alter] sets them once
+    /// its alter delay passes. Replaces the pending proposal.
+    pub async fn propose_alter(
+        &self,
+        curve: Pubkey,
+        params: CurveParams,
+        priority_rate: Option<u64>,
+        force: bool,
+    ) -> Result<SignatureView> {
+        let owner = self.authority.pubkey();
+
+        if !force {
+            let curve_view = self.curve(&curve).await?;
+            self.check_owner(&curve, &curve_view.curve)?;
+            if params.name != curve_view.curve.name {
+                anyhow::bail!("proposals keep the name, rename curve {curve} before proposing");
+            }
+        }
+
+        let mut ixs = vec![];
+
+        if let Some(priority_rate) = priority_rate {
+            let priority_fee_ix = ComputeBudgetInstruction::set_compute_unit_price(priority_rate);
+            ixs.push(priority_fee_ix);
+        }
+
+        ixs.push(
+            ProposeAlter {
+                curve,
+                owner,
+                pending_alter: PendingAlter::find_address(&curve).0,
+                params,
+            }
+            .into_instruction(),
+        );
+
+        let signature = self
+            .send_operation(
+                OperationKind::ProposeAlter,
+                curve,
+                Some(params),
+                ixs,
+                &[&self.authority],
+            )
+            .await?;
+
+        Ok(self.signature_view(signature).await)
+    }
+
+    /// Sets params proposed by [CurvyClient::propose_alter] once the alter delay of the curve
+    /// passed
+    pub async fn apply_alter(
+        &self,
+        curve: Pubkey,
+        priority_rate: Option<u64>,
+        force: bool,
+    ) -> Result<SignatureView> {
+        let owner = self.authority.pubkey();
+
+        let pending = self
+            .pending_alter(&curve)
+            .await?
+            .ok_or_else(|| anyhow::anyhow!("curve {curve} has no proposed alteration"))?;
+
+        if !force {
+            let curve_view = self.curve(&curve).await?;
+            self.check_owner(&curve, &curve_view.curve)?;
+            if pending.slot < pending.apply_slot {
+                anyhow::bail!(
+                    "proposed alteration of curve {curve} can be applied from slot {}, current \
+                     slot is {}",
+                    pending.apply_slot,
+                    pending.slot
+                );
+            }
+        }
+
+        if let Some(guard) = &self.alter_guard {
+            guard.check(&curve, force)?;
+        }
+
+        let mut ixs = vec![];
+
+        if let Some(priority_rate) = priority_rate {
+            let priority_fee_ix = ComputeBudgetInstruction::set_compute_unit_price(priority_rate);
+            ixs.push(priority_fee_ix);
+        }
+
+        ixs.push(
+            ApplyAlter {
+                curve,
+                owner,
+                pending_alter: PendingAlter::find_address(&curve).0,
+            }
+            .into_instruction(),
+        );
+
+        let params = CurveParams::from(&pending.params);
+        let signature = self
+            .send_operation(
+                OperationKind::ApplyAlter,
+                curve,
+                Some(params),
+                ixs,
+                &[&self.authority],
+            )
+            .await?;
+
+        if let Some(guard) = &self.alter_guard {
+            guard.record(&curve)?;
+        }
+
+        Ok(self.signature_view(signature).await)
+    }
+
+    /// Drops params proposed by [CurvyClient::propose_alter]
+    pub async fn cancel_alter(
+        &self,
+        curve: Pubkey,
+        priority_rate: Option<u64>,
+        force: bool,
+    ) -> Result<SignatureView> {
+        let owner = self.authority.pubkey();
+
+        if !force {
+            let curve_view = self.curve(&curve).await?;
+            self.check_owner(&curve, &curve_view.curve)?;
+            if self.pending_alter(&curve).await?.is_none() {
+                anyhow::bail!("curve {curve} has no proposed alteration");
+            }
+        }
+
+        let mut ixs = vec![];
+
+        if let Some(priority_rate) = priority_rate {
+            let priority_fee_ix = ComputeBudgetInstruction::set_compute_unit_price(priority_rate);
+            ixs.push(priority_fee_ix);
+        }
+
+        ixs.push(
+            CancelAlter {
+                curve,
+                owner,
+                pending_alter: PendingAlter::find_address(&curve).0,
+            }
+            .into_instruction(),
+        );
+
+        let signature = self
+            .send_operation(
+                OperationKind::CancelAlter,
+                curve,
+                None,
+                ixs,
+                &[&self.authority],
+            )
+            .await?;
+
+        Ok(self.signature_view(signature).await)
+    }
+
+    /// Allows `delegate` to update samples of the curve, see [Curve::delegates]
+    pub async fn add_delegate(
+        &self,
+        curve: Pubkey,
+        delegate: Pubkey,
+        priority_rate: Option<u64>,
+        force: bool,
+    ) -> Result<SignatureView> {
+        let owner = self.authority.pubkey();
+
+        if !force {
+            let curve_view = self.curve(&curve).await?;
+            self.check_owner(&curve, &curve_view.curve)?;
+            if curve_view.curve.is_delegate(&delegate) {
+                anyhow::bail!("{delegate} is a delegate of curve {curve} already");
+            }
+        }
 
-        for y_value in self.curve.y.iter().take(self.curve.y_count as usize) {
-            write!(f, "{}, ", y_value)?;
+        let mut ixs = vec![];
 
-            cnt += 1;
+        if let Some(priority_rate) = priority_rate {
+            let priority_fee_ix = ComputeBudgetInstruction::set_compute_unit_price(priority_rate);
+            ixs.push(priority_fee_ix);
+        }
 
-            if cnt == 11 {
-                write!(f, "\n          ")?;
-                cnt = 0;
+        ixs.push(
+            AddDelegate {
+                curve,
+                owner,
+                delegate,
             }
-        }
+            .into_instruction(),
+        );
 
-        Ok(())
+        let signature = self
+            .send_operation(
+                OperationKind::AddDelegate,
+                curve,
+                None,
+                ixs,
+                &[&self.authority],
+            )
+            .await?;
+
+        Ok(self.signature_view(signature).await)
     }
-}
 
-#[derive(Debug)]
-pub struct CurvesView {
-    pub curves: Vec<CurveView>,
-}
+    /// Revokes `delegate` added by [CurvyClient::add_delegate]
+    pub async fn remove_delegate(
+        &self,
+        curve: Pubkey,
+        delegate: Pubkey,
+        priority_rate: Option<u64>,
+        force: bool,
+    ) -> Result<SignatureView> {
+        let owner = self.authority.pubkey();
 
-pub struct CurvyClient {
-    pub rpc: RpcClient,
-    pub authority: Keypair,
-    pub priority_fee: Option<u64>,
-}
+        if !force {
+            let curve_view = self.curve(&curve).await?;
+            self.check_owner(&curve, &curve_view.curve)?;
+            if !curve_view.curve.is_delegate(&delegate) {
+                anyhow::bail!("{delegate} is not a delegate of curve {curve}");
+            }
+        }
 
-impl CurvyClient {
-    pub async fn send_transaction_by(
-        &self,
-        mut ixs: Vec<Instruction>,
-        signers: &impl Signers,
-    ) -> Result<Signature> {
-        if let Some(priority_fee) = self.priority_fee {
-            let priority_fee_ix = ComputeBudgetInstruction::set_compute_unit_price(priority_fee);
+        let mut ixs = vec![];
+
+        if let Some(priority_rate) = priority_rate {
+            let priority_fee_ix = ComputeBudgetInstruction::set_compute_unit_price(priority_rate);
             ixs.push(priority_fee_ix);
         }
 
-        let mut tx = Transaction::new_with_payer(ixs.as_ref(), Some(&self.authority.pubkey()));
-        let blockhash = self.rpc.get_latest_blockhash().await?;
-        tx.sign(signers, blockhash);
+        ixs.push(
+            RemoveDelegate {
+                curve,
+                owner,
+                delegate,
+            }
+            .into_instruction(),
+        );
 
         let signature = self
-            .rpc
-            .send_and_confirm_transaction_with_spinner(&tx)
-            .await
-            .map_err(with_logs)?;
+            .send_operation(
+                OperationKind::RemoveDelegate,
+                curve,
+                None,
+                ixs,
+                &[&self.authority],
+            )
+            .await?;
 
-        Ok(signature)
+        Ok(self.signature_view(signature).await)
     }
 
-    pub async fn account_exists(&self, key: &Pubkey) -> Result<bool> {
-        match self.rpc.get_account(key).await {
-            Ok(_) => Ok(true),
-            Err(ClientError {
-                kind: ClientErrorKind::RpcError(RpcError::ForUser(msg)),
-                ..
-            }) if msg.starts_with("AccountNotFound") => Ok(false),
-            Err(err) => Err(err.into()),
+    /// Owners of co-owned curve, `None` for single owner
+    pub async fn curve_owners(&self, curve: &Pubkey) -> Result<Option<WithContext<CurveOwners>>> {
+        let (curve_owners, _) = CurveOwners::find_address(curve);
+        if !self.account_exists(&curve_owners).await? {
+            return Ok(None);
         }
+
+        Ok(Some(self.get_pod_account(&curve_owners).await?))
     }
 
-    pub async fn get_account_with_slot(&self, key: &Pubkey) -> Result<(Account, Slot)> {
-        let resp = self
-            .rpc
-            .get_account_with_commitment(key, self.rpc.commitment())
+    /// Makes the curve co-owned by `owners`, `threshold` of them must sign its changes. Zero
+    /// `threshold` and no `owners` make it single-owned again. Changing owners of co-owned
+    /// curve takes the threshold of its current owners, see [CurvyClient::co_signers].
+    pub async fn set_curve_owners(
+        &self,
+        curve: Pubkey,
+        threshold: u8,
+        owners: Vec<Pubkey>,
+        priority_rate: Option<u64>,
+        force: bool,
+    ) -> Result<SignatureView> {
+        let curve_view = self.curve(&curve).await?;
+
+        if !force {
+            if curve_view.curve.owner_threshold > 0 {
+                self.check_co_owners(&curve, &curve_view.curve).await?;
+            } else {
+                self.check_owner(&curve, &curve_view.curve)?;
+            }
+            if threshold > 0 && !owners.contains(&curve_view.curve.owner) {
+                anyhow::bail!(
+                    "curve owner {} must be among the owners",
+                    curve_view.curve.owner
+                );
+            }
+        }
+
+        let mut ixs = vec![];
+
+        if let Some(priority_rate) = priority_rate {
+            let priority_fee_ix = ComputeBudgetInstruction::set_compute_unit_price(priority_rate);
+            ixs.push(priority_fee_ix);
+        }
+
+        let mut ix = SetCurveOwners {
+            curve,
+            owner: self.authority.pubkey(),
+            curve_owners: CurveOwners::find_address(&curve).0,
+            threshold,
+            owners,
+        }
+        .into_instruction();
+        if curve_view.curve.owner_threshold > 0 {
+            // the curve owners account is listed already
+            ix.accounts
+                .extend(self.co_owner_metas(&curve).into_iter().skip(1));
+        }
+        ixs.push(ix);
+
+        let signers = self.owner_signers(&curve_view.curve);
+        let signature = self
+            .send_operation(OperationKind::SetCurveOwners, curve, None, ixs, &signers)
             .await?;
-        let account = resp
-            .value
-            .ok_or_else(|| RpcError::ForUser(format!("AccountNotFound: pubkey={key}")))?;
-        Ok((account, resp.context.slot))
-    }
 
-    pub async fn get_pod_account<A: PodAccount>(&self, key: &Pubkey) -> Result<(A, Slot)> {
-        let (account, slot) = self.get_account_with_slot(key).await?;
-        Ok((*A::try_from_bytes(&account.data)?, slot))
+        Ok(self.signature_view(signature).await)
     }
 
-    pub async fn create_curve(
+    /// Sets extension `extension_type` of the curve to `value`, empty `value` removes it. The
+    /// authority pays rent of the grown account.
+    pub async fn set_curve_extension(
         &self,
-        params: CurveParams,
+        curve: Pubkey,
+        extension_type: ExtensionType,
+        value: Vec<u8>,
         priority_rate: Option<u64>,
-    ) -> Result<CurveSignatureView> {
-        let owner = self.authority.pubkey();
+        force: bool,
+    ) -> Result<SignatureView> {
+        let curve_view = self.curve(&curve).await?;
 
-        let curve_keypair = Keypair::new();
-        let curve = curve_keypair.pubkey();
+        if !force {
+            self.check_owner(&curve, &curve_view.curve)?;
+            curve_extension::with_value(&curve_view.extensions, extension_type as u16, &value)?;
+        }
 
         let mut ixs = vec![];
 
@@ -183,75 +3782,110 @@ impl CurvyClient {
         }
 
         ixs.push(
-            CreateCurve {
+            SetCurveExtension {
                 curve,
-                owner,
-                params,
+                owner: self.authority.pubkey(),
+                extension_type: extension_type as u16,
+                value,
             }
             .into_instruction(),
         );
 
         let signature = self
-            .send_transaction_by(ixs, &[&self.authority, &curve_keypair])
+            .send_operation(
+                OperationKind::SetCurveExtension,
+                curve,
+                None,
+                ixs,
+                &[&self.authority],
+            )
             .await?;
 
-        Ok(CurveSignatureView::success(curve, signature))
+        Ok(self.signature_view(signature).await)
     }
 
-    #[allow(clippy::too_many_arguments)]
-    pub async fn alter_curve(
+    /// Program config, `None` until [CurvyClient::init_config]
+    pub async fn config(&self) -> Result<Option<WithContext<Config>>> {
+        let (config, _) = Config::find_address();
+        if !self.account_exists(&config).await? {
+            return Ok(None);
+        }
+
+        Ok(Some(self.get_pod_account(&config).await?))
+    }
+
+    /// Whether creation of curves is frozen by the config admin, see [Config::creation_frozen]
+    pub async fn is_creation_frozen(&self) -> Result<bool> {
+        Ok(self
+            .config()
+            .await?
+            .is_some_and(|config| config.is_creation_frozen()))
+    }
+
+    /// Freezes or unfreezes creation of curves. The authority must be the program config admin.
+    pub async fn set_creation_frozen(
         &self,
-        curve_key: Pubkey,
-        name: Option<String>,
-        formula: Option<String>,
-        decimals: Option<u8>,
-        x0: Option<CurveX>,
-        x_step: Option<CurveX>,
-        y_count: Option<u8>,
-        y: Option<[CurveY; MAX_Y_CNT]>,
+        frozen: bool,
         priority_rate: Option<u64>,
+        force: bool,
     ) -> Result<SignatureView> {
-        let owner = self.authority.pubkey();
-
-        let curve_view = self.curve(&curve_key).await.expect("get curve");
-        let curve = curve_view.curve;
-
-        let mut params = CurveParams {
-            name: curve.name,
-            formula: curve.formula,
-            x0: curve.x0,
-            x_step: curve.x_step,
-            y_count: curve.y_count,
-            decimals: curve.decimals,
-            y: curve.y,
-        };
+        let (config, _) = Config::find_address();
+        let admin = self.authority.pubkey();
 
-        if let Some(name) = name {
-            params.name = curvy::state::utils::str_to_array(&name);
+        if !force {
+            let Some(current) = self.config().await? else {
+                anyhow::bail!("program config {config} does not exist");
+            };
+            if current.admin != admin {
+                anyhow::bail!(
+                    "program config is administered by {}, not by {admin}",
+                    current.admin
+                );
+            }
         }
 
-        if let Some(formula) = formula {
-            params.formula = curvy::state::utils::str_to_array(&formula);
-        }
+        let mut ixs = vec![];
 
-        if let Some(decimals) = decimals {
-            params.decimals = decimals;
+        if let Some(priority_rate) = priority_rate {
+            let priority_fee_ix = ComputeBudgetInstruction::set_compute_unit_price(priority_rate);
+            ixs.push(priority_fee_ix);
         }
 
-        if let Some(x0) = x0 {
-            params.x0 = x0;
-        }
-        if let Some(x_step) = x_step {
-            params.x_step = x_step;
-        }
+        ixs.push(
+            SetCreationFrozen {
+                config,
+                admin,
+                frozen,
+            }
+            .into_instruction(),
+        );
 
-        if let Some(y_count) = y_count {
-            params.y_count = y_count;
-        }
+        let signature = self
+            .send_operation(
+                OperationKind::SetCreationFrozen,
+                config,
+                None,
+                ixs,
+                &[&self.authority],
+            )
+            .await?;
 
-        if let Some(y) = y {
-            params.y = y;
-        }
+        Ok(self.signature_view(signature).await)
+    }
+
+    /// Creates the program config with the creator allow-list, after it only `creators` can
+    /// create curves. The authority must be the upgrade authority of the program and becomes
+    /// the config admin.
+    pub async fn init_config(
+        &self,
+        creators: Vec<Pubkey>,
+        priority_rate: Option<u64>,
+    ) -> Result<SignatureView> {
+        let (config, _) = Config::find_address();
+        let (program_data, _) = Pubkey::find_program_address(
+            &[curvy::ID.as_ref()],
+            &solana_sdk::bpf_loader_upgradeable::ID,
+        );
 
         let mut ixs = vec![];
 
@@ -261,25 +3895,51 @@ impl CurvyClient {
         }
 
         ixs.push(
-            AlterCurve {
-                curve: curve_key,
-                owner,
-                params,
+            InitConfig {
+                config,
+                admin: self.authority.pubkey(),
+                program_data,
+                creators,
             }
             .into_instruction(),
         );
 
-        let signature = self.send_transaction_by(ixs, &[&self.authority]).await?;
+        let signature = self
+            .send_operation(
+                OperationKind::InitConfig,
+                config,
+                None,
+                ixs,
+                &[&self.authority],
+            )
+            .await?;
 
-        Ok(SignatureView { signature })
+        Ok(self.signature_view(signature).await)
     }
 
-    pub async fn delete_curve(
+    /// Sets admin and creator allow-list of the program config. Unless `force`, the authority
+    /// must be the current admin.
+    pub async fn set_config(
         &self,
-        curve: Pubkey,
+        new_admin: Pubkey,
+        creators: Vec<Pubkey>,
         priority_rate: Option<u64>,
+        force: bool,
     ) -> Result<SignatureView> {
-        let owner = self.authority.pubkey();
+        let (config, _) = Config::find_address();
+        let admin = self.authority.pubkey();
+
+        if !force {
+            let Some(current) = self.config().await? else {
+                anyhow::bail!("program config {config} does not exist");
+            };
+            if current.admin != admin {
+                anyhow::bail!(
+                    "program config is administered by {}, not by {admin}",
+                    current.admin
+                );
+            }
+        }
 
         let mut ixs = vec![];
 
@@ -288,59 +3948,443 @@ impl CurvyClient {
             ixs.push(priority_fee_ix);
         }
 
-        ixs.push(DeleteCurve { curve, owner }.into_instruction());
+        ixs.push(
+            SetConfig {
+                config,
+                admin,
+                new_admin,
+                creators,
+            }
+            .into_instruction(),
+        );
+
+        let signature = self
+            .send_operation(
+                OperationKind::SetConfig,
+                config,
+                None,
+                ixs,
+                &[&self.authority],
+            )
+            .await?;
 
-        let signature = self.send_transaction_by(ixs, &[&self.authority]).await?;
+        Ok(self.signature_view(signature).await)
+    }
 
-        Ok(SignatureView { signature })
+    pub async fn curve(&self, key: &Pubkey) -> Result<WithContext<CurveView>> {
+        self.get_account(key)
+            .await?
+            .try_map(|account| CurveView::from_account(*key, &account))
     }
 
-    pub async fn curve(&self, key: &Pubkey) -> Result<CurveView> {
-        self.get_pod_account::<Curve>(key)
-            .await
-            .map(|(curve, _slot)| (*key, curve))
-            .map(Into::into)
+    /// Loads curves at `keys` with multi-account requests. Fails when any of them is missing
+    /// or not a Curve. Result is in the order of `keys`.
+    pub async fn curves_by_keys(&self, keys: &[Pubkey]) -> Result<Vec<Curve>> {
+        let mut curves = Vec::with_capacity(keys.len());
+
+        for chunk in keys.chunks(MAX_MULTIPLE_ACCOUNTS) {
+            let accounts = self
+                .rpc
+                .get_multiple_accounts_with_commitment(chunk, self.rpc.commitment())
+                .await?
+                .value;
+
+            for (key, account) in chunk.iter().zip(accounts) {
+                let account = account
+                    .ok_or_else(|| RpcError::ForUser(format!("AccountNotFound: pubkey={key}")))?;
+                let curve = curve_from_account(&account)
+                    .map_err(|err| err.context(format!("curve {key}")))?;
+                curves.push(curve);
+            }
+        }
+
+        Ok(curves)
     }
 
-    pub async fn curves(&self) -> Result<CurvesView> {
-        let curves: Vec<CurveView> = load_curves(&self.rpc)
-            .await?
-            .0
+    /// Evaluates curves at `keys` in points `xs`, see [curvy_utils::eval_matrix]. Each curve is
+    /// fetched and decoded once, duplicate keys share the same row values.
+    pub async fn eval_matrix(
+        &self,
+        keys: &[Pubkey],
+        xs: &[Decimal],
+    ) -> Result<Vec<Vec<Option<Decimal>>>> {
+        let mut unique = keys.to_vec();
+        unique.sort();
+        unique.dedup();
+
+        let curves = self.curves_by_keys(&unique).await?;
+        let rows = curvy_utils::eval_matrix(&curves, xs);
+        let rows = unique.iter().zip(rows).collect::<HashMap<_, _>>();
+
+        Ok(keys.iter().map(|key| rows[key].clone()).collect())
+    }
+
+    /// All curves of the program, only those tagged `tag` (one of the comma-separated labels of
+    /// [CurveTags]) when given
+    pub async fn curves(&self, tag: Option<&str>) -> Result<WithContext<CurvesView>> {
+        let (curves, slot) = load_curves(&self.rpc).await?;
+        let Some(tag) = tag else {
+            let curves = curves
+                .iter()
+                .map(|(key, curve)| CurveView::from((*key, *curve)))
+                .collect();
+            return Ok(self.with_context(CurvesView { curves }, slot));
+        };
+
+        // tags are kept in the extension region, loaded only for curves having one
+        let keys = curves
             .iter()
-            .map(|(key, curve)| CurveView::from((*key, *curve)))
-            .collect();
+            .filter(|(_, curve)| curve.extensions_len() > 0)
+            .map(|(key, _)| *key)
+            .collect::<Vec<_>>();
+        let mut curves = self.curve_views_by_keys(&keys).await?;
+        curves.retain(|view| {
+            view.extension::<CurveTags>()
+                .ok()
+                .flatten()
+                .is_some_and(|tags| tags.contains(tag))
+        });
 
-        Ok(CurvesView { curves })
+        Ok(self.with_context(CurvesView { curves }, slot))
     }
-}
 
-struct Logs(Vec<String>);
+    /// Loads curves at `keys` with their tails and extensions, skipping accounts which are
+    /// gone or not curves by now
+    async fn curve_views_by_keys(&self, keys: &[Pubkey]) -> Result<Vec<CurveView>> {
+        let mut views = Vec::with_capacity(keys.len());
 
-impl std::fmt::Display for Logs {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        writeln!(f, "\nLogs:")?;
+        for chunk in keys.chunks(MAX_MULTIPLE_ACCOUNTS) {
+            let accounts = self
+                .rpc
+                .get_multiple_accounts_with_commitment(chunk, self.rpc.commitment())
+                .await?
+                .value;
 
-        for (i, log) in self.0.iter().enumerate() {
-            writeln!(f, "    {:>3}: {}", i + 1, log)?;
+            for (key, account) in chunk.iter().zip(accounts) {
+                if let Some(view) =
+                    account.and_then(|account| CurveView::from_account(*key, &account).ok())
+                {
+                    views.push(view);
+                }
+            }
         }
-        Ok(())
+
+        Ok(views)
+    }
+
+    /// Captures all curves of the program
+    pub async fn snapshot(&self) -> Result<Snapshot> {
+        let (curves, slot) = load_curves(&self.rpc).await?;
+        Ok(Snapshot::new(slot, curves))
+    }
+
+    /// Compares curves of the program with the `expected` state, see [reconcile::plan]
+    pub async fn reconcile(&self, expected: &ExpectedState) -> Result<WithContext<ReconcilePlan>> {
+        let (curves, slot) = load_curves(&self.rpc).await?;
+
+        let owners = expected.managed_owners();
+        let pinned = expected
+            .curves
+            .iter()
+            .filter_map(|curve| curve.curve)
+            .collect::<HashSet<_>>();
+        let keys = curves
+            .iter()
+            .filter(|(key, curve)| owners.contains(&curve.owner) || pinned.contains(key))
+            .map(|(key, _)| *key)
+            .collect::<Vec<_>>();
+        // tags are kept in the extension region
+        let views = self.curve_views_by_keys(&keys).await?;
+
+        Ok(self.with_context(plan(expected, &views)?, slot))
+    }
+
+    /// Executes `action` of a [ReconcilePlan] as the authority, once the curve is checked to be
+    /// still as planned. Curves are created at derived addresses, deleted ones are retired
+    /// first. Returns signatures of the sent transactions in order.
+    pub async fn apply_reconcile_action(&self, action: &ReconcileAction) -> Result<Vec<Signature>> {
+        let authority = self.authority.pubkey();
+        let changed = |what: String| anyhow::anyhow!("{what} since the plan was made, plan again");
+
+        let mut signatures = vec![];
+        match action {
+            ReconcileAction::Create {
+                name,
+                owner,
+                params,
+                tags,
+                ..
+            } => {
+                if *owner != authority {
+                    anyhow::bail!("curve {name} is to be created by {owner}, not by {authority}");
+                }
+                if let Some(curve) = self.resolve_curve(owner, name).await? {
+                    return Err(changed(format!("curve {name} is created at {curve}")));
+                }
+
+                let view = self.create_curve_pda(*params, self.priority_fee).await?;
+                if let Some(error) = view.error {
+                    anyhow::bail!("creating curve {name}: {error}");
+                }
+                signatures.extend(view.signature);
+
+                if let Some(tags) = tags {
+                    let tags = CurveTags::new(tags)?.to_value();
+                    let view = self
+                        .set_curve_extension(
+                            view.curve,
+                            ExtensionType::Tags,
+                            tags,
+                            self.priority_fee,
+                            false,
+                        )
+                        .await?;
+                    signatures.push(view.signature);
+                }
+            }
+            ReconcileAction::Alter {
+                curve,
+                name,
+                from_hash,
+                params,
+                ..
+            } => {
+                let current = self.curve(curve).await?.curve;
+                if CurveParams::from(&current).hash() != *from_hash {
+                    return Err(changed(format!("curve {name} is altered")));
+                }
+
+                let view = self
+                    .alter_curve(
+                        *curve,
+                        Some(bytes_to_cow(&params.name).into_owned()),
+                        Some(bytes_to_cow(&params.formula).into_owned()),
+                        Some(params.x_decimals),
+                        Some(params.y_decimals),
+                        Some(params.x0),
+                        Some(params.x_step),
+                        Some(params.y_count),
+                        Some(params.y),
+                        self.priority_fee,
+                        false,
+                    )
+                    .await?;
+                signatures.push(view.signature);
+            }
+            ReconcileAction::Retag {
+                curve,
+                name,
+                from,
+                to,
+            } => {
+                let current = self
+                    .curve(curve)
+                    .await?
+                    .extension::<CurveTags>()?
+                    .map(|tags| bytes_to_cow(&tags.tags).into_owned());
+                if current != *from {
+                    return Err(changed(format!("curve {name} is retagged")));
+                }
+
+                let view = self
+                    .set_curve_extension(
+                        *curve,
+                        ExtensionType::Tags,
+                        CurveTags::new(to)?.to_value(),
+                        self.priority_fee,
+                        false,
+                    )
+                    .await?;
+                signatures.push(view.signature);
+            }
+            ReconcileAction::Transfer {
+                curve,
+                name,
+                from,
+                to,
+            } => {
+                let current = self.curve(curve).await?.curve;
+                if current.owner != *from {
+                    return Err(changed(format!("curve {name} is transferred")));
+                }
+
+                let view = self
+                    .propose_owner(*curve, *to, self.priority_fee, false)
+                    .await?;
+                signatures.push(view.signature);
+            }
+            ReconcileAction::Delete { curve, name, owner } => {
+                let current = self.curve(curve).await?.curve;
+                if current.owner != *owner {
+                    return Err(changed(format!("curve {name} is transferred")));
+                }
+
+                // only draft and retired curves are deleted
+                let steps: &[CurveStatus] = match current.status() {
+                    CurveStatus::Active | CurveStatus::Paused => {
+                        &[CurveStatus::Deprecated, CurveStatus::Retired]
+                    }
+                    CurveStatus::Deprecated => &[CurveStatus::Retired],
+                    CurveStatus::Draft | CurveStatus::Retired => &[],
+                };
+                for status in steps {
+                    let view = self
+                        .set_curve_status(*curve, *status, self.priority_fee, false)
+                        .await?;
+                    signatures.push(view.signature);
+                }
+
+                let view = self
+                    .delete_curve(*curve, None, self.priority_fee, false)
+                    .await?;
+                signatures.push(view.signature);
+            }
+        }
+
+        Ok(signatures)
+    }
+
+    /// Summarizes curves by owner. Sorted by number of curves, descending.
+    pub async fn owners(&self) -> Result<Vec<OwnerView>> {
+        let mut owners: HashMap<Pubkey, OwnerView> = HashMap::new();
+
+        for (key, curve, lamports) in load_curve_accounts(&self.rpc).await?.0 {
+            let view = owners.entry(curve.owner).or_insert_with(|| OwnerView {
+                owner: curve.owner,
+                curves: vec![],
+                rent_locked: 0,
+                last_update: None,
+            });
+            view.curves.push(key);
+            view.rent_locked += lamports;
+            view.last_update = view.last_update.max(curve.last_update_unix());
+        }
+
+        let mut owners: Vec<_> = owners.into_values().collect();
+        owners.sort_by_key(|view| std::cmp::Reverse(view.curves.len()));
+
+        Ok(owners)
+    }
+
+    /// Versions of the curve active since `since`, including the one active at `since`, rebuilt
+    /// from the curve transactions (see [replay_history]). The RPC node must keep transaction
+    /// history that far back, otherwise versions start later.
+    pub async fn curve_history(
+        &self,
+        key: &Pubkey,
+        since: UnixTimestamp,
+    ) -> Result<Vec<CurveVersion>> {
+        let current = self.curve(key).await?.curve;
+
+        // newest first
+        let mut operations = vec![];
+        let mut before = None;
+        'pages: loop {
+            let signatures = self
+                .rpc
+                .get_signatures_for_address_with_config(
+                    key,
+                    GetConfirmedSignaturesForAddress2Config {
+                        before,
+                        ..Default::default()
+                    },
+                )
+                .await?;
+            let Some(last) = signatures.last() else {
+                break;
+            };
+            before = Some(Signature::from_str(&last.signature)?);
+
+            for status in signatures {
+                let (None, Some(block_time)) = (status.err, status.block_time) else {
+                    continue;
+                };
+
+                let transaction: serde_json::Value = self
+                    .rpc
+                    .send(
+                        RpcRequest::GetTransaction,
+                        serde_json::json!([
+                            status.signature,
+                            {
+                                "encoding": "jsonParsed",
+                                "commitment": self.rpc.commitment().commitment,
+                                "maxSupportedTransactionVersion": 0,
+                            }
+                        ]),
+                    )
+                    .await?;
+
+                let found = decode_webhook_payload(&transaction)
+                    .into_iter()
+                    .flat_map(DecodedOperation::split_batch)
+                    .filter(|operation| operation.curve() == Some(*key))
+                    .filter_map(|operation| match operation.instruction {
+                        Ok(instruction) => Some((block_time, instruction)),
+                        Err(err) => {
+                            tracing::warn!(
+                                "skipping instruction of transaction {}: {err}",
+                                status.signature
+                            );
+                            None
+                        }
+                    })
+                    .collect::<Vec<_>>();
+                let sets_data = found
+                    .iter()
+                    .any(|(_, instruction)| sets_curve_data(instruction));
+                operations.extend(found.into_iter().rev());
+
+                if sets_data && block_time <= since {
+                    break 'pages;
+                }
+            }
+        }
+
+        operations.reverse();
+        Ok(replay_history(&current, &operations))
+    }
+}
+
+/// Checks whether send error is System program `AccountAlreadyInUse` returned from curve creation
+fn is_account_in_use(error: &anyhow::Error) -> bool {
+    let in_use = |code: u32| code == SystemError::AccountAlreadyInUse as u32;
+
+    if let Some(failure) = error.downcast_ref::<PreflightFailure>() {
+        return failure.code.is_some_and(in_use);
     }
+
+    let transaction_error = error
+        .downcast_ref::<ClientError>()
+        .and_then(ClientError::get_transaction_error)
+        .or_else(|| error.downcast_ref::<TransactionError>().cloned());
+
+    matches!(
+        transaction_error,
+        Some(TransactionError::InstructionError(_, InstructionError::Custom(code))) if in_use(code)
+    )
 }
+
+/// Attaches decoded [PreflightFailure] (failed program, instruction, error and logs) to
+/// preflight errors.
 pub fn with_logs(mut error: ClientError) -> anyhow::Error {
-    let logs = match error.kind {
+    let failure = match error.kind {
         ClientErrorKind::RpcError(RpcError::RpcResponseError {
             data:
                 RpcResponseErrorData::SendTransactionPreflightFailure(RpcSimulateTransactionResult {
+                    ref err,
                     ref mut logs,
                     ..
                 }),
             ..
-        }) => logs.take().map(Logs),
+        }) => logs
+            .take()
+            .map(|logs| PreflightFailure::new(err.clone(), logs)),
         _ => None,
     };
 
-    if let Some(logs) = logs {
-        anyhow::Error::from(error).context(logs)
+    if let Some(failure) = failure {
+        anyhow::Error::from(error).context(failure)
     } else {
         error.into()
     }
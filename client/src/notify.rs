@@ -0,0 +1,174 @@
+//! Curve change alerts posted to Slack incoming webhooks or generic JSON webhooks.
+
+use anyhow::{Context, Result};
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::Signature;
+
+use crate::FieldChange;
+
+/// Message template used when none is configured
+pub const DEFAULT_TEMPLATE: &str = "Curve *{name}* `{curve}` {action}\n{diff}{link}";
+
+/// Default transaction link, `{signature}` is replaced with the transaction signature
+pub const DEFAULT_EXPLORER: &str = "https://explorer.solana.com/tx/{signature}";
+
+/// Number of changed fields listed in the message
+const DIFF_PREVIEW: usize = 10;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ChangeAction {
+    Created,
+    Altered,
+    Deleted,
+}
+
+impl ChangeAction {
+    fn as_str(&self) -> &'static str {
+        match self {
+            ChangeAction::Created => "created",
+            ChangeAction::Altered => "altered",
+            ChangeAction::Deleted => "deleted",
+        }
+    }
+}
+
+/// Change of a single curve
+#[serde_with::serde_as]
+#[serde_with::skip_serializing_none]
+#[derive(Debug, serde::Serialize)]
+pub struct CurveChange {
+    pub action: ChangeAction,
+    #[serde_as(as = "serde_with::DisplayFromStr")]
+    pub curve: Pubkey,
+    pub name: String,
+    pub diff: Vec<FieldChange>,
+    #[serde_as(as = "Option<serde_with::DisplayFromStr>")]
+    pub signature: Option<Signature>,
+}
+
+#[derive(Debug, Clone)]
+pub enum NotifyTarget {
+    /// Slack incoming webhook, receives `{"text": <message>}`
+    Slack(String),
+    /// Generic webhook, receives the change as JSON with rendered message in `text`
+    Webhook(String),
+}
+
+/// Posts rendered curve changes to all targets.
+///
+/// Template placeholders: `{action}`, `{name}`, `{curve}`, `{diff}` (preview of changed fields,
+/// one per line), `{signature}` and `{link}` (transaction explorer link).
+pub struct Notifier {
+    pub targets: Vec<NotifyTarget>,
+    pub template: String,
+    /// Transaction link template with `{signature}` placeholder
+    pub explorer: String,
+    client: reqwest::Client,
+}
+
+impl Notifier {
+    pub fn new(targets: Vec<NotifyTarget>) -> Self {
+        Self {
+            targets,
+            template: DEFAULT_TEMPLATE.to_string(),
+            explorer: DEFAULT_EXPLORER.to_string(),
+            client: reqwest::Client::new(),
+        }
+    }
+
+    pub fn render(&self, change: &CurveChange) -> String {
+        let mut diff = change
+            .diff
+            .iter()
+            .take(DIFF_PREVIEW)
+            .map(|field| format!("• {field}\n"))
+            .collect::<String>();
+        if change.diff.len() > DIFF_PREVIEW {
+            diff += &format!("… and {} more\n", change.diff.len() - DIFF_PREVIEW);
+        }
+
+        let signature = change
+            .signature
+            .map(|signature| signature.to_string())
+            .unwrap_or_default();
+        let link = match change.signature {
+            Some(_) => self.explorer.replace("{signature}", &signature),
+            None => String::new(),
+        };
+
+        self.template
+            .replace("{action}", change.action.as_str())
+            .replace("{name}", &change.name)
+            .replace("{curve}", &change.curve.to_string())
+            .replace("{diff}", &diff)
+            .replace("{signature}", &signature)
+            .replace("{link}", &link)
+    }
+
+    /// Posts the change to every target. All targets are tried, the first failure is returned.
+    pub async fn notify(&self, change: &CurveChange) -> Result<()> {
+        let text = self.render(change);
+        let mut result = Ok(());
+
+        for target in &self.targets {
+            let (url, body) = match target {
+                NotifyTarget::Slack(url) => (url, serde_json::json!({ "text": text })),
+                NotifyTarget::Webhook(url) => {
+                    let mut body = serde_json::to_value(change)?;
+                    body["text"] = text.clone().into();
+                    (url, body)
+                }
+            };
+
+            let posted = self
+                .client
+                .post(url)
+                .json(&body)
+                .send()
+                .await
+                .and_then(reqwest::Response::error_for_status)
+                .with_context(|| format!("posting curve change to {url}"));
+
+            if let Err(err) = posted {
+                tracing::warn!("{err:#}");
+                if result.is_ok() {
+                    result = Err(err);
+                }
+            }
+        }
+
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn render() {
+        let change = CurveChange {
+            action: ChangeAction::Altered,
+            curve: Pubkey::new_unique(),
+            name: "SOL-borrow".to_string(),
+            diff: (0..12)
+                .map(|idx| FieldChange {
+                    field: format!("y[{idx}]"),
+                    from: "1".to_string(),
+                    to: "2".to_string(),
+                })
+                .collect(),
+            signature: Some(Signature::default()),
+        };
+
+        let text = Notifier::new(vec![]).render(&change);
+
+        assert!(text.starts_with(&format!("Curve *SOL-borrow* `{}` altered\n", change.curve)));
+        assert!(text.contains("• y[9]: 1 -> 2\n… and 2 more\n"));
+        assert!(text.ends_with(&format!(
+            "https://explorer.solana.com/tx/{}",
+            Signature::default()
+        )));
+    }
+}
@@ -0,0 +1,187 @@
+//! Compute unit usage of individual instructions, measured by transaction simulation.
+
+use std::str::FromStr;
+
+use anyhow::Result;
+use solana_client::rpc_config::RpcSimulateTransactionConfig;
+use solana_sdk::instruction::Instruction;
+use solana_sdk::packet::PACKET_DATA_SIZE;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signer::Signer;
+use solana_sdk::transaction::{Transaction, TransactionError};
+
+use crate::CurvyClient;
+
+/// Max compute units a transaction may request
+pub const MAX_COMPUTE_UNIT_LIMIT: u64 = 1_400_000;
+
+/// Compute units consumed by a top level instruction
+#[serde_with::serde_as]
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize)]
+pub struct InstructionProfile {
+    pub index: usize,
+    #[serde_as(as = "serde_with::DisplayFromStr")]
+    pub program: Pubkey,
+    /// `None` for programs not reporting consumption (e.g. Compute Budget)
+    pub units: Option<u64>,
+}
+
+/// Simulation result of a transaction
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct Profile {
+    pub instructions: Vec<InstructionProfile>,
+    /// Total compute units consumed by the transaction
+    pub units_consumed: Option<u64>,
+    /// Serialized size of the signed transaction in bytes
+    pub tx_size: usize,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<TransactionError>,
+    #[serde(skip)]
+    pub logs: Vec<String>,
+}
+
+/// Parses per-instruction compute unit consumption from transaction logs. Units of inner
+/// instructions (CPI) are included in their top level instruction.
+pub fn parse_compute_units(logs: &[String]) -> Vec<InstructionProfile> {
+    let mut instructions: Vec<InstructionProfile> = vec![];
+    let mut depth: usize = 0;
+
+    for log in logs {
+        let Some(rest) = log.strip_prefix("Program ") else {
+            continue;
+        };
+        let Some((program, event)) = rest.split_once(' ') else {
+            continue;
+        };
+
+        if let Some(level) = event
+            .strip_prefix("invoke [")
+            .and_then(|level| level.strip_suffix(']'))
+        {
+            depth = level.parse().unwrap_or(depth + 1);
+            if depth == 1 {
+                let Ok(program) = Pubkey::from_str(program) else {
+                    continue;
+                };
+                instructions.push(InstructionProfile {
+                    index: instructions.len(),
+                    program,
+                    units: None,
+                });
+            }
+        } else if let Some(consumed) = event.strip_prefix("consumed ") {
+            if depth == 1 {
+                let units = consumed
+                    .split_once(' ')
+                    .and_then(|(units, _)| units.parse().ok());
+                if let Some(last) = instructions.last_mut() {
+                    last.units = units;
+                }
+            }
+        } else if event == "success" || event.starts_with("failed") {
+            depth = depth.saturating_sub(1);
+        }
+    }
+
+    instructions
+}
+
+/// Number of copies of `ix` fitting in one transaction paid by `payer`, limited by both
+/// transaction size and compute units when each copy consumes `units`
+pub fn fit_per_transaction(ix: &Instruction, payer: &Pubkey, units: u64) -> usize {
+    let by_units = (MAX_COMPUTE_UNIT_LIMIT / units.max(1)) as usize;
+
+    let mut count = 0;
+    while count < by_units {
+        let ixs = vec![ix.clone(); count + 1];
+        if signed_size(&ixs, payer) > PACKET_DATA_SIZE {
+            break;
+        }
+        count += 1;
+    }
+
+    count
+}
+
+/// Size of the transaction with all signatures present
+fn signed_size(ixs: &[Instruction], payer: &Pubkey) -> usize {
+    let mut tx = Transaction::new_with_payer(ixs, Some(payer));
+    tx.signatures.resize(
+        tx.message.header.num_required_signatures as usize,
+        Default::default(),
+    );
+    bincode::serialized_size(&tx).map_or(usize::MAX, |size| size as usize)
+}
+
+impl CurvyClient {
+    /// Simulates `ixs` paid by the authority and returns compute units consumed by each of
+    /// them. Signatures are not verified, so no signer except the authority is needed.
+    pub async fn profile(&self, ixs: Vec<Instruction>) -> Result<Profile> {
+        let payer = self.authority.pubkey();
+        let tx = Transaction::new_with_payer(&ixs, Some(&payer));
+        let tx_size = signed_size(&ixs, &payer);
+
+        let result = self
+            .rpc
+            .simulate_transaction_with_config(
+                &tx,
+                RpcSimulateTransactionConfig {
+                    sig_verify: false,
+                    replace_recent_blockhash: true,
+                    commitment: Some(self.rpc.commitment()),
+                    ..Default::default()
+                },
+            )
+            .await?
+            .value;
+
+        let logs = result.logs.unwrap_or_default();
+
+        Ok(Profile {
+            instructions: parse_compute_units(&logs),
+            units_consumed: result.units_consumed,
+            tx_size,
+            error: result.err,
+            logs,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn compute_units() {
+        let budget = solana_sdk::compute_budget::id();
+        let logs = [
+            format!("Program {budget} invoke [1]"),
+            format!("Program {budget} success"),
+            format!("Program {} invoke [1]", curvy::ID),
+            "Program log: alter_curve ix".to_string(),
+            "Program 11111111111111111111111111111111 invoke [2]".to_string(),
+            "Program 11111111111111111111111111111111 success".to_string(),
+            format!(
+                "Program {} consumed 4521 of 1399850 compute units",
+                curvy::ID
+            ),
+            format!("Program {} success", curvy::ID),
+        ];
+
+        assert_eq!(
+            parse_compute_units(&logs),
+            vec![
+                InstructionProfile {
+                    index: 0,
+                    program: budget,
+                    units: None,
+                },
+                InstructionProfile {
+                    index: 1,
+                    program: curvy::ID,
+                    units: Some(4521),
+                },
+            ]
+        );
+    }
+}
@@ -0,0 +1,221 @@
+//! Client side limiter of account reads, protecting RPC quotas shared by many tasks of one
+//! process. Concurrent reads of the same account wait for a single in-flight request
+//! (singleflight) and requests may be held to a budget per time window.
+
+use std::collections::{HashMap, VecDeque};
+use std::future::Future;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use anyhow::{anyhow, Result};
+use solana_sdk::account::Account;
+use solana_sdk::clock::Slot;
+use solana_sdk::pubkey::Pubkey;
+use tokio::sync::OnceCell;
+
+/// Account (if any) read in the slot, shared by coalesced reads. Errors are shared as text.
+type SharedRead = Arc<OnceCell<Result<(Option<Account>, Slot), Arc<anyhow::Error>>>>;
+
+/// At most `requests` RPC requests in any `window`, reads over the budget wait
+#[derive(Debug, Clone, Copy)]
+pub struct ReadBudget {
+    pub requests: usize,
+    pub window: Duration,
+}
+
+/// Counters of [ReadLimiter], also reported as `metrics` counters with the `metrics` feature
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, serde::Serialize)]
+pub struct ReadStats {
+    /// Reads sent to RPC
+    pub requests: u64,
+    /// Reads served by a request already in flight for the same account
+    pub coalesced: u64,
+    /// Reads delayed by the budget
+    pub throttled: u64,
+}
+
+/// Coalesces concurrent reads of the same account and applies the optional [ReadBudget]
+#[derive(Default)]
+pub struct ReadLimiter {
+    pub budget: Option<ReadBudget>,
+    in_flight: Mutex<HashMap<Pubkey, SharedRead>>,
+    /// Times of requests within the budget window, oldest first
+    sent: Mutex<VecDeque<Instant>>,
+    requests: AtomicU64,
+    coalesced: AtomicU64,
+    throttled: AtomicU64,
+}
+
+impl ReadLimiter {
+    pub fn new(budget: Option<ReadBudget>) -> Self {
+        Self {
+            budget,
+            ..Self::default()
+        }
+    }
+
+    pub fn stats(&self) -> ReadStats {
+        ReadStats {
+            requests: self.requests.load(Ordering::Relaxed),
+            coalesced: self.coalesced.load(Ordering::Relaxed),
+            throttled: self.throttled.load(Ordering::Relaxed),
+        }
+    }
+
+    /// Reads `key` by `fetch` unless a read of it is in flight already, then waits for that
+    /// one. `fetch` is awaited only by the first reader, or by another one if it was dropped.
+    pub async fn read<F>(&self, key: &Pubkey, fetch: F) -> Result<(Option<Account>, Slot)>
+    where
+        F: Future<Output = Result<(Option<Account>, Slot)>>,
+    {
+        let (read, leader) = {
+            let mut in_flight = self.in_flight.lock().expect("read limiter lock");
+            match in_flight.get(key) {
+                Some(read) => (read.clone(), false),
+                None => {
+                    let read = SharedRead::default();
+                    in_flight.insert(*key, read.clone());
+                    (read, true)
+                }
+            }
+        };
+        if !leader {
+            self.count(&self.coalesced, "curvy_client_reads_coalesced");
+        }
+
+        let result = read
+            .get_or_init(|| async {
+                self.acquire().await;
+                self.count(&self.requests, "curvy_client_read_requests");
+                fetch.await.map_err(Arc::new)
+            })
+            .await
+            .clone();
+
+        // later reads must see fresh data
+        {
+            let mut in_flight = self.in_flight.lock().expect("read limiter lock");
+            if in_flight
+                .get(key)
+                .is_some_and(|current| Arc::ptr_eq(current, &read))
+            {
+                in_flight.remove(key);
+            }
+        }
+
+        result.map_err(|err| anyhow!("{err:#}"))
+    }
+
+    /// Waits until one more request fits the budget and takes it
+    async fn acquire(&self) {
+        let Some(budget) = self.budget else {
+            return;
+        };
+
+        let mut throttled = false;
+        loop {
+            let wait = {
+                let mut sent = self.sent.lock().expect("read limiter lock");
+                let now = Instant::now();
+                while sent
+                    .front()
+                    .is_some_and(|time| now.duration_since(*time) >= budget.window)
+                {
+                    sent.pop_front();
+                }
+
+                match sent.front() {
+                    Some(oldest) if sent.len() >= budget.requests.max(1) => {
+                        budget.window - now.duration_since(*oldest)
+                    }
+                    _ => {
+                        sent.push_back(now);
+                        return;
+                    }
+                }
+            };
+
+            if !throttled {
+                throttled = true;
+                self.count(&self.throttled, "curvy_client_reads_throttled");
+            }
+            tokio::time::sleep(wait).await;
+        }
+    }
+
+    #[cfg_attr(not(feature = "metrics"), allow(unused_variables))]
+    fn count(&self, counter: &AtomicU64, metric: &'static str) {
+        counter.fetch_add(1, Ordering::Relaxed);
+        #[cfg(feature = "metrics")]
+        metrics::counter!(metric).increment(1);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::task::Poll;
+
+    use futures::executor::block_on;
+
+    use super::*;
+
+    fn account(lamports: u64) -> Account {
+        Account {
+            lamports,
+            ..Account::default()
+        }
+    }
+
+    #[test]
+    fn concurrent_reads_are_coalesced() {
+        let limiter = ReadLimiter::new(Some(ReadBudget {
+            requests: 10,
+            window: Duration::from_secs(60),
+        }));
+        let key = Pubkey::new_unique();
+        let other = Pubkey::new_unique();
+        let fetched = &AtomicU64::new(0);
+        let fetch = |lamports| async move {
+            fetched.fetch_add(1, Ordering::Relaxed);
+            // let other readers join before the response
+            let mut yielded = false;
+            futures::future::poll_fn(|cx| {
+                if std::mem::replace(&mut yielded, true) {
+                    return Poll::Ready(());
+                }
+                cx.waker().wake_by_ref();
+                Poll::Pending
+            })
+            .await;
+            Ok((Some(account(lamports)), 7))
+        };
+
+        let (first, second, third) = block_on(async {
+            futures::join!(
+                limiter.read(&key, fetch(1)),
+                limiter.read(&key, fetch(2)),
+                limiter.read(&other, fetch(3)),
+            )
+        });
+        assert_eq!(first.unwrap(), (Some(account(1)), 7));
+        assert_eq!(second.unwrap(), (Some(account(1)), 7));
+        assert_eq!(third.unwrap(), (Some(account(3)), 7));
+        assert_eq!(fetched.load(Ordering::Relaxed), 2);
+
+        // finished reads are not reused
+        let fourth = block_on(limiter.read(&key, fetch(4))).unwrap();
+        assert_eq!(fourth, (Some(account(4)), 7));
+        assert_eq!(
+            limiter.stats(),
+            ReadStats {
+                requests: 3,
+                coalesced: 1,
+                throttled: 0,
+            }
+        );
+
+        let failed = block_on(limiter.read(&key, async { Err(anyhow!("rpc is down")) }));
+        assert_eq!(failed.unwrap_err().to_string(), "rpc is down");
+    }
+}
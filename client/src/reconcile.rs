@@ -0,0 +1,510 @@
+//! Reconciliation of curves with a declarative expected state: the document lists curves with
+//! their owners, params hashes and tags, [plan] compares it with the curves on chain and lists
+//! actions converging them, like a terraform plan.
+//!
+//! Curves of the document are matched by name among curves of the managed owners (the
+//! [ExpectedState::owners] and owners of the listed curves), or by address when pinned with
+//! [ExpectedCurve::curve]. Curves of managed owners missing from the document are deleted.
+
+use std::collections::HashSet;
+use std::fmt::{Display, Formatter};
+
+use anyhow::{bail, Result};
+use solana_sdk::hash::{hash, Hash};
+use solana_sdk::pubkey::Pubkey;
+
+use curvy::state::curve::CurveParams;
+use curvy::state::curve_extension::CurveTags;
+use curvy::state::utils::bytes_to_cow;
+
+use crate::CurveView;
+
+/// Curves as they should be on chain
+#[serde_with::serde_as]
+#[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize)]
+pub struct ExpectedState {
+    /// Owners whose curves are managed in addition to the owners of [ExpectedState::curves]
+    #[serde(default)]
+    #[serde_as(as = "Vec<serde_with::DisplayFromStr>")]
+    pub owners: Vec<Pubkey>,
+    pub curves: Vec<ExpectedCurve>,
+}
+
+#[serde_with::serde_as]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct ExpectedCurve {
+    pub name: String,
+    #[serde_as(as = "serde_with::DisplayFromStr")]
+    pub owner: Pubkey,
+    /// Address of the curve, when it is not to be matched by name
+    #[serde(default)]
+    #[serde_as(as = "Option<serde_with::DisplayFromStr>")]
+    pub curve: Option<Pubkey>,
+    /// [CurveParams::hash] of the curve
+    #[serde_as(as = "serde_with::DisplayFromStr")]
+    pub params_hash: Hash,
+    /// Params hashed to `params_hash`, needed to create or alter the curve
+    #[serde(default)]
+    pub params: Option<CurveParams>,
+    /// Comma-separated labels of [CurveTags], unmanaged when unset
+    #[serde(default)]
+    pub tags: Option<String>,
+}
+
+/// Change converging a curve to its expected state
+#[serde_with::serde_as]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+#[serde(tag = "action", rename_all = "snake_case")]
+pub enum ReconcileAction {
+    Create {
+        name: String,
+        #[serde_as(as = "serde_with::DisplayFromStr")]
+        owner: Pubkey,
+        #[serde_as(as = "serde_with::DisplayFromStr")]
+        params_hash: Hash,
+        params: CurveParams,
+        tags: Option<String>,
+    },
+    Alter {
+        #[serde_as(as = "serde_with::DisplayFromStr")]
+        curve: Pubkey,
+        name: String,
+        #[serde_as(as = "serde_with::DisplayFromStr")]
+        from_hash: Hash,
+        #[serde_as(as = "serde_with::DisplayFromStr")]
+        to_hash: Hash,
+        params: CurveParams,
+    },
+    Retag {
+        #[serde_as(as = "serde_with::DisplayFromStr")]
+        curve: Pubkey,
+        name: String,
+        from: Option<String>,
+        to: String,
+    },
+    /// Proposal of the new owner, who accepts it separately
+    Transfer {
+        #[serde_as(as = "serde_with::DisplayFromStr")]
+        curve: Pubkey,
+        name: String,
+        #[serde_as(as = "serde_with::DisplayFromStr")]
+        from: Pubkey,
+        #[serde_as(as = "serde_with::DisplayFromStr")]
+        to: Pubkey,
+    },
+    Delete {
+        #[serde_as(as = "serde_with::DisplayFromStr")]
+        curve: Pubkey,
+        name: String,
+        #[serde_as(as = "serde_with::DisplayFromStr")]
+        owner: Pubkey,
+    },
+}
+
+impl ReconcileAction {
+    /// Curve changed by the action, `None` for curves to be created
+    pub fn curve(&self) -> Option<&Pubkey> {
+        match self {
+            Self::Create { .. } => None,
+            Self::Alter { curve, .. }
+            | Self::Retag { curve, .. }
+            | Self::Transfer { curve, .. }
+            | Self::Delete { curve, .. } => Some(curve),
+        }
+    }
+}
+
+impl Display for ReconcileAction {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Create {
+                name,
+                owner,
+                params_hash,
+                ..
+            } => write!(f, "+ create {name} owned by {owner} ({params_hash})"),
+            Self::Alter {
+                curve,
+                name,
+                from_hash,
+                to_hash,
+                ..
+            } => write!(f, "~ alter {name} {curve} ({from_hash} -> {to_hash})"),
+            Self::Retag {
+                curve,
+                name,
+                from,
+                to,
+            } => write!(
+                f,
+                "~ retag {name} {curve} ({} -> {to})",
+                from.as_deref().unwrap_or("none")
+            ),
+            Self::Transfer {
+                curve,
+                name,
+                from,
+                to,
+            } => write!(f, "~ transfer {name} {curve} ({from} -> {to})"),
+            Self::Delete { curve, name, owner } => {
+                write!(f, "- delete {name} {curve} owned by {owner}")
+            }
+        }
+    }
+}
+
+/// Actions converging the chain to an [ExpectedState], empty when it is converged
+#[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize)]
+pub struct ReconcilePlan {
+    pub actions: Vec<ReconcileAction>,
+}
+
+impl ReconcilePlan {
+    pub fn is_empty(&self) -> bool {
+        self.actions.is_empty()
+    }
+
+    /// Plan file contents: pretty JSON ending with a newline, the same bytes for the same plan
+    pub fn to_bytes(&self) -> Result<Vec<u8>> {
+        let mut bytes = serde_json::to_vec_pretty(self)?;
+        bytes.push(b'\n');
+        Ok(bytes)
+    }
+
+    /// Plan of a file written by [ReconcilePlan::to_bytes]. Edited files are rejected, so the
+    /// applied plan is byte for byte the reviewed one.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self> {
+        let plan: Self = serde_json::from_slice(bytes)?;
+        if plan.to_bytes()? != bytes {
+            bail!("plan is edited after it was made, make it again");
+        }
+        Ok(plan)
+    }
+
+    /// SHA-256 of [ReconcilePlan::to_bytes], identifies the reviewed plan
+    pub fn hash(&self) -> Result<Hash> {
+        Ok(hash(&self.to_bytes()?))
+    }
+}
+
+impl Display for ReconcilePlan {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if self.actions.is_empty() {
+            return write!(f, "No changes, curves match the expected state");
+        }
+
+        writeln!(f, "{} action(s):", self.actions.len())?;
+        for action in &self.actions {
+            writeln!(f, "  {action}")?;
+        }
+        Ok(())
+    }
+}
+
+impl ExpectedState {
+    /// Owners whose curves are compared with the document
+    pub fn managed_owners(&self) -> HashSet<Pubkey> {
+        self.owners
+            .iter()
+            .chain(self.curves.iter().map(|curve| &curve.owner))
+            .copied()
+            .collect()
+    }
+
+    /// Checks names are unique and params match their hashes
+    pub fn validate(&self) -> Result<()> {
+        let mut names = HashSet::new();
+        for expected in &self.curves {
+            if !names.insert(expected.name.as_str()) {
+                bail!("curve {} is listed more than once", expected.name);
+            }
+
+            if let Some(params) = &expected.params {
+                let name = bytes_to_cow(&params.name);
+                if name != expected.name {
+                    bail!("curve {} has params named {name}", expected.name);
+                }
+                let hash = params.hash();
+                if hash != expected.params_hash {
+                    bail!(
+                        "curve {} params hash to {hash}, not to {}",
+                        expected.name,
+                        expected.params_hash
+                    );
+                }
+            }
+
+            if let Some(tags) = &expected.tags {
+                CurveTags::new(tags)
+                    .map_err(|_| anyhow::anyhow!("curve {} has too long tags", expected.name))?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Actions converging `actual` curves, loaded with their extensions, to `expected`.
+/// Curves of other owners are ignored unless pinned by address.
+pub fn plan(expected: &ExpectedState, actual: &[CurveView]) -> Result<ReconcilePlan> {
+    expected.validate()?;
+
+    let owners = expected.managed_owners();
+    let mut unmatched = actual
+        .iter()
+        .filter(|view| owners.contains(&view.curve.owner))
+        .map(|view| view.key)
+        .collect::<HashSet<_>>();
+
+    let mut actions = vec![];
+    for expected in &expected.curves {
+        let found = match expected.curve {
+            Some(key) => actual.iter().find(|view| view.key == key),
+            None => {
+                let mut found = actual.iter().filter(|view| {
+                    unmatched.contains(&view.key) && bytes_to_cow(&view.curve.name) == expected.name
+                });
+                let first = found.next();
+                if let (Some(first), Some(second)) = (first, found.next()) {
+                    bail!(
+                        "curve {} matches {} and {}, pin its address",
+                        expected.name,
+                        first.key,
+                        second.key
+                    );
+                }
+                first
+            }
+        };
+
+        let Some(view) = found else {
+            if let Some(key) = expected.curve {
+                bail!("curve {} is missing at {key}", expected.name);
+            }
+            let Some(params) = expected.params else {
+                bail!("curve {} is missing, its params are needed", expected.name);
+            };
+            actions.push(ReconcileAction::Create {
+                name: expected.name.clone(),
+                owner: expected.owner,
+                params_hash: expected.params_hash,
+                params,
+                tags: expected.tags.clone(),
+            });
+            continue;
+        };
+        unmatched.remove(&view.key);
+
+        // alter while the current owner still controls the curve
+        let hash = CurveParams::from(&view.curve).hash();
+        if hash != expected.params_hash {
+            let Some(params) = expected.params else {
+                bail!(
+                    "curve {} differs from {}, its params are needed",
+                    expected.name,
+                    expected.params_hash
+                );
+            };
+            actions.push(ReconcileAction::Alter {
+                curve: view.key,
+                name: expected.name.clone(),
+                from_hash: hash,
+                to_hash: expected.params_hash,
+                params,
+            });
+        }
+
+        if let Some(tags) = &expected.tags {
+            let current = view.extension::<CurveTags>()?;
+            let labels = |tags: &CurveTags| tags.labels().map(str::to_owned).collect::<Vec<_>>();
+            let expected_labels = labels(&CurveTags::new(tags)?);
+            if current.as_ref().map(labels).unwrap_or_default() != expected_labels {
+                actions.push(ReconcileAction::Retag {
+                    curve: view.key,
+                    name: expected.name.clone(),
+                    from: current.map(|tags| bytes_to_cow(&tags.tags).into_owned()),
+                    to: tags.clone(),
+                });
+            }
+        }
+
+        if view.curve.owner != expected.owner {
+            actions.push(ReconcileAction::Transfer {
+                curve: view.key,
+                name: expected.name.clone(),
+                from: view.curve.owner,
+                to: expected.owner,
+            });
+        }
+    }
+
+    let mut deleted = actual
+        .iter()
+        .filter(|view| unmatched.contains(&view.key))
+        .map(|view| ReconcileAction::Delete {
+            curve: view.key,
+            name: bytes_to_cow(&view.curve.name).into_owned(),
+            owner: view.curve.owner,
+        })
+        .collect::<Vec<_>>();
+    deleted.sort_by_key(|action| action.curve().copied());
+    actions.extend(deleted);
+
+    Ok(ReconcilePlan { actions })
+}
+
+#[cfg(test)]
+mod tests {
+    use texture_common::account::PodAccount;
+
+    use curvy::state::curve::{Curve, MAX_Y_CNT};
+    use curvy::state::curve_extension;
+
+    use super::*;
+
+    fn params(name: &str, y: u32) -> CurveParams {
+        CurveParams::new(name, "y=x", 0, 10, 2, 2, [y; MAX_Y_CNT])
+    }
+
+    fn view(owner: Pubkey, params: CurveParams, tags: Option<&str>) -> CurveView {
+        let mut view = CurveView::from((
+            Pubkey::new_unique(),
+            Curve::from_init_params((params, owner)),
+        ));
+        if let Some(tags) = tags {
+            view.extensions = curve_extension::with(&[], &CurveTags::new(tags).unwrap()).unwrap();
+        }
+        view
+    }
+
+    fn expected(owner: Pubkey, params: CurveParams, tags: Option<&str>) -> ExpectedCurve {
+        ExpectedCurve {
+            name: bytes_to_cow(&params.name).into_owned(),
+            owner,
+            curve: None,
+            params_hash: params.hash(),
+            params: Some(params),
+            tags: tags.map(str::to_owned),
+        }
+    }
+
+    #[test]
+    fn plan_converges_curves() {
+        let owner = Pubkey::new_unique();
+        let new_owner = Pubkey::new_unique();
+        let stranger = Pubkey::new_unique();
+
+        let actual = vec![
+            view(owner, params("same", 1), Some("rate,usdc")),
+            view(owner, params("altered", 1), None),
+            view(owner, params("moved", 1), Some("rate")),
+            view(owner, params("deleted", 1), None),
+            view(stranger, params("foreign", 1), None),
+        ];
+        let state = ExpectedState {
+            owners: vec![],
+            curves: vec![
+                expected(owner, params("same", 1), Some("rate, usdc")),
+                expected(owner, params("altered", 2), None),
+                expected(new_owner, params("moved", 1), Some("rate,sol")),
+                expected(owner, params("created", 1), Some("rate")),
+            ],
+        };
+
+        let actions = plan(&state, &actual).unwrap().actions;
+        assert_eq!(actions.len(), 5, "{actions:?}");
+        assert!(matches!(
+            &actions[0],
+            ReconcileAction::Alter { curve, to_hash, .. }
+                if *curve == actual[1].key && *to_hash == params("altered", 2).hash()
+        ));
+        assert!(matches!(
+            &actions[1],
+            ReconcileAction::Retag { curve, from: Some(from), to, .. }
+                if *curve == actual[2].key && from == "rate" && to == "rate,sol"
+        ));
+        assert!(matches!(
+            &actions[2],
+            ReconcileAction::Transfer { curve, from, to, .. }
+                if *curve == actual[2].key && *from == owner && *to == new_owner
+        ));
+        assert!(matches!(
+            &actions[3],
+            ReconcileAction::Create { name, owner: created_by, tags: Some(tags), .. }
+                if name == "created" && *created_by == owner && tags == "rate"
+        ));
+        assert!(matches!(
+            &actions[4],
+            ReconcileAction::Delete { curve, .. } if *curve == actual[3].key
+        ));
+
+        // converged state plans nothing
+        let converged = vec![
+            view(owner, params("same", 1), Some("rate,usdc")),
+            view(owner, params("altered", 2), None),
+            view(new_owner, params("moved", 1), Some("rate,sol")),
+            view(owner, params("created", 1), Some("rate")),
+        ];
+        assert!(plan(&state, &converged).unwrap().is_empty());
+    }
+
+    #[test]
+    fn plan_file_is_applied_as_reviewed() {
+        let owner = Pubkey::new_unique();
+        let actual = vec![
+            view(owner, params("altered", 1), None),
+            view(owner, params("deleted", 1), None),
+        ];
+        let state = ExpectedState {
+            owners: vec![],
+            curves: vec![
+                expected(owner, params("altered", 2), Some("rate")),
+                expected(Pubkey::new_unique(), params("created", 1), None),
+            ],
+        };
+        let plan = plan(&state, &actual).unwrap();
+
+        let bytes = plan.to_bytes().unwrap();
+        let read = ReconcilePlan::from_bytes(&bytes).unwrap();
+        assert_eq!(read.to_bytes().unwrap(), bytes);
+        assert_eq!(read.hash().unwrap(), plan.hash().unwrap());
+        assert_eq!(read.actions.len(), 4);
+
+        let edited = String::from_utf8(bytes).unwrap().replace("  ", " ");
+        assert!(ReconcilePlan::from_bytes(edited.as_bytes()).is_err());
+    }
+
+    #[test]
+    fn plan_rejects_ambiguous_and_incomplete_documents() {
+        let owner = Pubkey::new_unique();
+        let actual = vec![
+            view(owner, params("twin", 1), None),
+            view(owner, params("twin", 2), None),
+        ];
+
+        let mut state = ExpectedState {
+            owners: vec![],
+            curves: vec![expected(owner, params("twin", 1), None)],
+        };
+        assert!(plan(&state, &actual)
+            .unwrap_err()
+            .to_string()
+            .contains("pin its address"));
+
+        state.curves[0].curve = Some(actual[0].key);
+        let actions = plan(&state, &actual).unwrap().actions;
+        assert!(matches!(
+            &actions[..],
+            [ReconcileAction::Delete { curve, .. }] if *curve == actual[1].key
+        ));
+
+        state.curves[0].params_hash = params("twin", 3).hash();
+        assert!(plan(&state, &actual).is_err());
+
+        state.curves[0].params = None;
+        assert!(plan(&state, &actual)
+            .unwrap_err()
+            .to_string()
+            .contains("params are needed"));
+    }
+}
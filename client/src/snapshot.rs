@@ -0,0 +1,121 @@
+use std::collections::{BTreeMap, HashMap};
+use std::fmt::{Display, Formatter};
+
+use solana_sdk::clock::Slot;
+use solana_sdk::hash::Hash;
+use solana_sdk::pubkey::Pubkey;
+
+use curvy::state::curve::{Curve, CurveParams};
+use curvy::state::utils::bytes_to_cow;
+
+/// State of all curves at some slot. Serialized to JSON to be compared with later runs.
+#[serde_with::serde_as]
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+pub struct Snapshot {
+    pub slot: Slot,
+    #[serde_as(as = "BTreeMap<serde_with::DisplayFromStr, _>")]
+    pub curves: BTreeMap<Pubkey, CurveSnapshot>,
+}
+
+#[serde_with::serde_as]
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+pub struct CurveSnapshot {
+    #[serde_as(as = "serde_with::DisplayFromStr")]
+    pub owner: Pubkey,
+    pub name: String,
+    #[serde_as(as = "serde_with::DisplayFromStr")]
+    pub params_hash: Hash,
+    pub params: CurveParams,
+}
+
+impl Snapshot {
+    pub fn new(slot: Slot, curves: HashMap<Pubkey, Curve>) -> Self {
+        let curves = curves
+            .into_iter()
+            .map(|(key, curve)| {
+                let params = CurveParams::from(&curve);
+                let snapshot = CurveSnapshot {
+                    owner: curve.owner,
+                    name: bytes_to_cow(&curve.name).into_owned(),
+                    params_hash: params.hash(),
+                    params,
+                };
+                (key, snapshot)
+            })
+            .collect();
+
+        Self { slot, curves }
+    }
+
+    /// Changes made since the `previous` snapshot
+    pub fn diff(&self, previous: &Snapshot) -> SnapshotDiff {
+        let mut diff = SnapshotDiff {
+            from_slot: previous.slot,
+            to_slot: self.slot,
+            ..Default::default()
+        };
+
+        for (key, curve) in &self.curves {
+            match previous.curves.get(key) {
+                None => diff.added.push(*key),
+                Some(prev)
+                    if prev.params_hash != curve.params_hash || prev.owner != curve.owner =>
+                {
+                    diff.changed.push(*key)
+                }
+                Some(_) => {}
+            }
+        }
+
+        diff.removed = previous
+            .curves
+            .keys()
+            .filter(|key| !self.curves.contains_key(key))
+            .copied()
+            .collect();
+
+        diff
+    }
+}
+
+#[serde_with::serde_as]
+#[derive(Debug, Default, serde::Serialize)]
+pub struct SnapshotDiff {
+    pub from_slot: Slot,
+    pub to_slot: Slot,
+    #[serde_as(as = "Vec<serde_with::DisplayFromStr>")]
+    pub added: Vec<Pubkey>,
+    #[serde_as(as = "Vec<serde_with::DisplayFromStr>")]
+    pub removed: Vec<Pubkey>,
+    #[serde_as(as = "Vec<serde_with::DisplayFromStr>")]
+    pub changed: Vec<Pubkey>,
+}
+
+impl SnapshotDiff {
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.changed.is_empty()
+    }
+}
+
+impl Display for SnapshotDiff {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        writeln!(
+            f,
+            "Changes from slot {} to {}:",
+            self.from_slot, self.to_slot
+        )?;
+
+        for (title, keys) in [
+            ("added", &self.added),
+            ("removed", &self.removed),
+            ("changed", &self.changed),
+        ] {
+            writeln!(f, "  {title}: {}", keys.len())?;
+            for key in keys {
+                writeln!(f, "    {key}")?;
+            }
+        }
+
+        Ok(())
+    }
+}
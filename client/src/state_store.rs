@@ -0,0 +1,157 @@
+//! Local state of long-running updaters: params last applied to each curve, keys of operations
+//! already sent and fee totals. Kept behind [StateStore], so deployments pick where it lives.
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::path::PathBuf;
+
+use anyhow::Result;
+use solana_sdk::hash::Hash;
+use solana_sdk::pubkey::Pubkey;
+
+use crate::TransactionCost;
+
+/// Fees paid by the updater
+#[derive(Debug, Clone, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct FeeStats {
+    pub transactions: u64,
+    /// Lamports
+    pub fee: u64,
+    /// Sum over transactions which reported consumed units
+    pub compute_units: u64,
+}
+
+#[serde_with::serde_as]
+#[derive(Debug, Clone, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct UpdaterState {
+    /// Hash of params last applied to the curve, see [curvy::state::curve::CurveParams::hash]
+    #[serde_as(as = "BTreeMap<serde_with::DisplayFromStr, serde_with::DisplayFromStr>")]
+    pub applied: BTreeMap<Pubkey, Hash>,
+    /// Idempotency keys of operations already sent
+    pub sent: BTreeSet<String>,
+    pub fees: FeeStats,
+}
+
+impl UpdaterState {
+    /// Whether params with `hash` are the last ones applied to `curve`
+    pub fn is_applied(&self, curve: &Pubkey, hash: &Hash) -> bool {
+        self.applied.get(curve) == Some(hash)
+    }
+
+    /// Records params with `hash` applied to `curve` by the operation `key` costing `cost`
+    pub fn record_applied(
+        &mut self,
+        curve: Pubkey,
+        hash: Hash,
+        key: impl Into<String>,
+        cost: Option<&TransactionCost>,
+    ) {
+        self.applied.insert(curve, hash);
+        self.sent.insert(key.into());
+        self.fees.transactions += 1;
+        if let Some(cost) = cost {
+            self.fees.fee += cost.fee;
+            self.fees.compute_units += cost.compute_units.unwrap_or_default();
+        }
+    }
+
+    pub fn is_sent(&self, key: &str) -> bool {
+        self.sent.contains(key)
+    }
+}
+
+/// Storage of [UpdaterState]
+pub trait StateStore {
+    /// Stored state, default when nothing is stored yet
+    fn load(&self) -> Result<UpdaterState>;
+
+    fn save(&mut self, state: &UpdaterState) -> Result<()>;
+}
+
+/// State in a JSON file. The file is replaced at once, so an interrupted save keeps the
+/// previous state.
+#[derive(Debug, Clone)]
+pub struct FileStateStore {
+    pub path: PathBuf,
+}
+
+impl FileStateStore {
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self { path: path.into() }
+    }
+}
+
+impl StateStore for FileStateStore {
+    fn load(&self) -> Result<UpdaterState> {
+        match std::fs::read_to_string(&self.path) {
+            Ok(content) => Ok(serde_json::from_str(&content)?),
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(UpdaterState::default()),
+            Err(err) => Err(err.into()),
+        }
+    }
+
+    fn save(&mut self, state: &UpdaterState) -> Result<()> {
+        let mut tmp = self.path.clone().into_os_string();
+        tmp.push(".tmp");
+
+        std::fs::write(&tmp, serde_json::to_string_pretty(state)?)?;
+        std::fs::rename(&tmp, &self.path)?;
+
+        Ok(())
+    }
+}
+
+/// State kept in memory only, for dry runs and tests
+#[derive(Debug, Clone, Default)]
+pub struct MemoryStateStore {
+    pub state: UpdaterState,
+}
+
+impl StateStore for MemoryStateStore {
+    fn load(&self) -> Result<UpdaterState> {
+        Ok(self.state.clone())
+    }
+
+    fn save(&mut self, state: &UpdaterState) -> Result<()> {
+        self.state = state.clone();
+        Ok(())
+    }
+}
+
+/// Store at `location`: `memory` or path of the JSON file
+pub fn open_state_store(location: &str) -> Box<dyn StateStore + Send> {
+    match location {
+        "memory" => Box::<MemoryStateStore>::default(),
+        path => Box::new(FileStateStore::new(path)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn file_store_keeps_state() {
+        let path = std::env::temp_dir().join(format!("curvy-state-{}.json", Pubkey::new_unique()));
+        let mut store = open_state_store(path.to_str().unwrap());
+        assert_eq!(store.load().unwrap(), UpdaterState::default());
+
+        let curve = Pubkey::new_unique();
+        let hash = Hash::new_unique();
+        let cost = TransactionCost {
+            fee: 5000,
+            compute_units: Some(1200),
+        };
+        let mut state = UpdaterState::default();
+        state.record_applied(curve, hash, "op-1", Some(&cost));
+        store.save(&state).unwrap();
+
+        let loaded = FileStateStore::new(&path).load().unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        assert!(loaded.is_applied(&curve, &hash));
+        assert!(!loaded.is_applied(&curve, &Hash::new_unique()));
+        assert!(loaded.is_sent("op-1"));
+        assert_eq!(loaded.fees.fee, 5000);
+        assert_eq!(loaded, state);
+    }
+}
@@ -0,0 +1,163 @@
+//! Curve account subscription surviving websocket drops. Plain pubsub subscriptions end
+//! silently on reconnects and updates sent in between are lost, here the current state is
+//! re-fetched every time the subscription is re-established.
+
+use std::ops::ControlFlow;
+use std::time::Duration;
+
+use anyhow::{anyhow, Result};
+use futures::StreamExt;
+use solana_account_decoder::UiAccountEncoding;
+use solana_client::nonblocking::pubsub_client::PubsubClient;
+use solana_client::nonblocking::rpc_client::RpcClient;
+use solana_client::rpc_config::RpcAccountInfoConfig;
+use solana_sdk::clock::Slot;
+use solana_sdk::pubkey::Pubkey;
+
+use crate::{CurveView, WithContext};
+
+#[derive(Debug)]
+pub enum CurveEvent {
+    /// Current state fetched once subscribed for the first time
+    Initial(WithContext<CurveView>),
+    /// Account update received over the subscription
+    Updated(WithContext<CurveView>),
+    /// Current state fetched after the subscription was re-established. Updates sent while it
+    /// was down are not delivered, this state replaces them.
+    Resynced(WithContext<CurveView>),
+}
+
+impl CurveEvent {
+    /// Curve state of any event
+    pub fn into_state(self) -> WithContext<CurveView> {
+        match self {
+            Self::Initial(state) | Self::Updated(state) | Self::Resynced(state) => state,
+        }
+    }
+}
+
+/// Reconnection backoff of [watch_curve]
+#[derive(Debug, Clone, Copy)]
+pub struct Resubscribe {
+    /// Delay before resubscribing, doubled after every failed attempt
+    pub delay: Duration,
+    pub max_delay: Duration,
+}
+
+impl Default for Resubscribe {
+    fn default() -> Self {
+        Self {
+            delay: Duration::from_millis(500),
+            max_delay: Duration::from_secs(30),
+        }
+    }
+}
+
+struct WatchState {
+    resubscribe: Resubscribe,
+    /// Slot of the last delivered state, none before the first one
+    slot: Option<Slot>,
+    /// Delay before the next attempt
+    delay: Duration,
+}
+
+/// Watches `curve` over the websocket at `ws_url` passing its states to `on_event` until it
+/// returns [ControlFlow::Break]. The subscription is re-established whenever it drops or fails
+/// and the current state is fetched by `rpc` each time, after subscribing so no update falls in
+/// between (see [CurveEvent::Resynced]). Updates from slots older than the delivered state are
+/// skipped. Never returns otherwise: failures, including the deleted curve, are retried.
+pub async fn watch_curve<F>(
+    rpc: &RpcClient,
+    ws_url: &str,
+    curve: Pubkey,
+    resubscribe: Resubscribe,
+    mut on_event: F,
+) -> Result<()>
+where
+    F: FnMut(CurveEvent) -> ControlFlow<()>,
+{
+    let mut state = WatchState {
+        resubscribe,
+        slot: None,
+        delay: resubscribe.delay,
+    };
+
+    loop {
+        match watch_once(rpc, ws_url, curve, &mut state, &mut on_event).await {
+            Ok(ControlFlow::Break(())) => return Ok(()),
+            Ok(ControlFlow::Continue(())) => {
+                tracing::warn!("curve {curve} subscription closed, resubscribing");
+            }
+            Err(err) => {
+                tracing::warn!(
+                    "curve {curve} subscription failed, resubscribing in {:?}: {err:#}",
+                    state.delay
+                );
+                tokio::time::sleep(state.delay).await;
+                state.delay = (state.delay * 2).min(resubscribe.max_delay);
+            }
+        }
+    }
+}
+
+/// Subscribes, delivers the current state and then updates until the subscription ends
+async fn watch_once<F>(
+    rpc: &RpcClient,
+    ws_url: &str,
+    curve: Pubkey,
+    state: &mut WatchState,
+    on_event: &mut F,
+) -> Result<ControlFlow<()>>
+where
+    F: FnMut(CurveEvent) -> ControlFlow<()>,
+{
+    let pubsub = PubsubClient::new(ws_url).await?;
+    let (mut updates, _unsubscribe) = pubsub
+        .account_subscribe(
+            &curve,
+            Some(RpcAccountInfoConfig {
+                encoding: Some(UiAccountEncoding::Base64),
+                commitment: Some(rpc.commitment()),
+                ..Default::default()
+            }),
+        )
+        .await?;
+
+    let response = rpc
+        .get_account_with_commitment(&curve, rpc.commitment())
+        .await?;
+    let account = response
+        .value
+        .ok_or_else(|| anyhow!("curve account {curve} not found"))?;
+    let commitment = rpc.commitment().commitment;
+    let current = WithContext::new(
+        CurveView::from_account(curve, &account)?,
+        response.context.slot,
+        commitment,
+    );
+
+    let event = match state.slot.replace(current.slot) {
+        None => CurveEvent::Initial(current),
+        Some(_) => CurveEvent::Resynced(current),
+    };
+    if on_event(event).is_break() {
+        return Ok(ControlFlow::Break(()));
+    }
+    // subscribed and in sync again
+    state.delay = state.resubscribe.delay;
+
+    while let Some(update) = updates.next().await {
+        if state.slot.is_some_and(|slot| update.context.slot < slot) {
+            continue;
+        }
+        state.slot = Some(update.context.slot);
+
+        let view = CurveView::try_from_ui_account(curve, &update.value)?;
+        let event = CurveEvent::Updated(WithContext::new(view, update.context.slot, commitment));
+        if on_event(event).is_break() {
+            return Ok(ControlFlow::Break(()));
+        }
+    }
+
+    Ok(ControlFlow::Continue(()))
+}
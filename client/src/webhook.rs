@@ -0,0 +1,290 @@
+//! Decoder of enhanced/parsed transaction payloads delivered by webhook providers
+//! (Helius enhanced transactions, `jsonParsed` RPC transactions and similar).
+
+use std::str::FromStr;
+
+use anyhow::{anyhow, Context, Result};
+use borsh::BorshDeserialize;
+use serde_json::Value;
+use solana_sdk::bs58;
+use solana_sdk::pubkey::Pubkey;
+
+use curvy::instruction::CurvyInstruction;
+
+/// Curvy instruction found in a transaction payload
+#[derive(Debug)]
+pub struct DecodedOperation {
+    /// Signature of the transaction containing the instruction (if present in the payload)
+    pub signature: Option<String>,
+    /// Decoding error for instructions this version does not know (e.g. added by a newer
+    /// program) or malformed ones, the rest of the payload is decoded anyway
+    pub instruction: Result<CurvyInstruction, String>,
+    pub accounts: Vec<Pubkey>,
+}
+
+impl DecodedOperation {
+    /// Curve account the instruction operates on, the first account of curve instructions.
+    /// None for config instructions and for BatchAlter operating on several curves, see
+    /// [DecodedOperation::split_batch]. For undecoded instructions it is the first account too.
+    pub fn curve(&self) -> Option<Pubkey> {
+        match self.instruction {
+            Ok(CurvyInstruction::InitConfig { .. })
+            | Ok(CurvyInstruction::SetConfig { .. })
+            | Ok(CurvyInstruction::SetCreationFrozen { .. })
+            | Ok(CurvyInstruction::BatchAlter { .. }) => None,
+            _ => self.accounts.first().copied(),
+        }
+    }
+
+    /// Splits BatchAlter into AlterCurve operations of its curves (following the owner), so
+    /// each of them has the curve first. Other operations are returned as is.
+    pub fn split_batch(self) -> Vec<DecodedOperation> {
+        let Ok(CurvyInstruction::BatchAlter { params }) = self.instruction else {
+            return vec![self];
+        };
+        let Some((owner, curves)) = self.accounts.split_first() else {
+            return vec![];
+        };
+
+        curves
+            .iter()
+            .zip(params)
+            .filter_map(|(curve, params)| {
+                Some(DecodedOperation {
+                    signature: self.signature.clone(),
+                    instruction: Ok(CurvyInstruction::AlterCurve {
+                        params: params.to_params().ok()?,
+                    }),
+                    accounts: vec![*curve, *owner],
+                })
+            })
+            .collect()
+    }
+}
+
+/// Finds and decodes all Curvy instructions (including inner ones) in the webhook payload.
+/// Payload is either a single transaction or an array of transactions. Instructions failing to
+/// decode are returned with the error, see [DecodedOperation::instruction].
+pub fn decode_webhook_payload(payload: &Value) -> Vec<DecodedOperation> {
+    let transactions = match payload {
+        Value::Array(transactions) => transactions.iter().collect(),
+        transaction => vec![transaction],
+    };
+
+    let mut operations = vec![];
+
+    for transaction in transactions {
+        let signature = transaction_signature(transaction);
+        collect_instructions(transaction, &signature, &mut operations);
+    }
+
+    operations
+}
+
+fn transaction_signature(transaction: &Value) -> Option<String> {
+    transaction
+        .get("signature")
+        .or_else(|| transaction.pointer("/transaction/signatures/0"))
+        .and_then(Value::as_str)
+        .map(ToString::to_string)
+}
+
+fn collect_instructions(
+    value: &Value,
+    signature: &Option<String>,
+    operations: &mut Vec<DecodedOperation>,
+) {
+    match value {
+        Value::Object(object) => {
+            let program_id = object.get("programId").and_then(Value::as_str);
+
+            if program_id == Some(curvy::ID.to_string().as_str()) {
+                operations.push(decode_instruction(value, signature.clone()));
+            }
+
+            for value in object.values() {
+                collect_instructions(value, signature, operations);
+            }
+        }
+        Value::Array(values) => {
+            for value in values {
+                collect_instructions(value, signature, operations);
+            }
+        }
+        _ => {}
+    }
+}
+
+fn decode_instruction(value: &Value, signature: Option<String>) -> DecodedOperation {
+    let (instruction, accounts) = match decode_accounts(value) {
+        Ok(accounts) => (decode_data(value), accounts),
+        Err(err) => (Err(err), vec![]),
+    };
+
+    DecodedOperation {
+        signature,
+        instruction: instruction.map_err(|err| format!("{err:#}")),
+        accounts,
+    }
+}
+
+fn decode_data(value: &Value) -> Result<CurvyInstruction> {
+    let data = value
+        .get("data")
+        .and_then(Value::as_str)
+        .ok_or_else(|| anyhow!("curvy instruction without data"))?;
+    let data = bs58::decode(data)
+        .into_vec()
+        .context("curvy instruction data is not base58")?;
+    CurvyInstruction::try_from_slice(&data).context("decoding curvy instruction")
+}
+
+fn decode_accounts(value: &Value) -> Result<Vec<Pubkey>> {
+    let accounts = value
+        .get("accounts")
+        .and_then(Value::as_array)
+        .map(|accounts| {
+            accounts
+                .iter()
+                .map(|account| {
+                    account
+                        .as_str()
+                        .ok_or_else(|| anyhow!("account is not a string"))
+                        .and_then(|key| Ok(Pubkey::from_str(key)?))
+                })
+                .collect::<Result<Vec<_>>>()
+        })
+        .transpose()?
+        .unwrap_or_default();
+
+    Ok(accounts)
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use curvy::state::curve::{CompactCurveParams, CurveParams, CurveX, MAX_Y_CNT};
+
+    use super::*;
+
+    #[test]
+    fn decode_enhanced_and_parsed_payloads() {
+        let curve = Pubkey::new_unique();
+        let owner = Pubkey::new_unique();
+        let data =
+            bs58::encode(borsh::to_vec(&CurvyInstruction::DeleteCurve).unwrap()).into_string();
+
+        let enhanced = json!([{
+            "signature": "sig1",
+            "instructions": [
+                { "programId": "11111111111111111111111111111111", "accounts": [], "data": "" },
+                {
+                    "programId": "ComputeBudget111111111111111111111111111111",
+                    "accounts": [],
+                    "data": "3",
+                    "innerInstructions": [{
+                        "programId": curvy::ID.to_string(),
+                        "accounts": [curve.to_string(), owner.to_string()],
+                        "data": data,
+                    }],
+                },
+            ],
+        }]);
+
+        let operations = decode_webhook_payload(&enhanced);
+        assert_eq!(operations.len(), 1);
+        assert_eq!(operations[0].signature.as_deref(), Some("sig1"));
+        assert_eq!(operations[0].curve(), Some(curve));
+        assert!(matches!(
+            operations[0].instruction,
+            Ok(CurvyInstruction::DeleteCurve)
+        ));
+
+        let parsed = json!({
+            "transaction": {
+                "signatures": ["sig2"],
+                "message": {
+                    "instructions": [{
+                        "programId": curvy::ID.to_string(),
+                        "accounts": [curve.to_string(), owner.to_string()],
+                        "data": data,
+                    }],
+                },
+            },
+            "meta": { "innerInstructions": [] },
+        });
+
+        let operations = decode_webhook_payload(&parsed);
+        assert_eq!(operations.len(), 1);
+        assert_eq!(operations[0].signature.as_deref(), Some("sig2"));
+        assert_eq!(operations[0].accounts, vec![curve, owner]);
+    }
+
+    #[test]
+    fn undecoded_instructions_do_not_abort_payload() {
+        let curve = Pubkey::new_unique();
+        let data =
+            bs58::encode(borsh::to_vec(&CurvyInstruction::DeleteCurve).unwrap()).into_string();
+
+        let payload = json!([{
+            "signature": "sig",
+            "instructions": [
+                {
+                    "programId": curvy::ID.to_string(),
+                    "accounts": [curve.to_string()],
+                    "data": bs58::encode([u8::MAX]).into_string(),
+                },
+                { "programId": curvy::ID.to_string(), "accounts": [42], "data": data },
+                {
+                    "programId": curvy::ID.to_string(),
+                    "accounts": [curve.to_string()],
+                    "data": data,
+                },
+            ],
+        }]);
+
+        let operations = decode_webhook_payload(&payload);
+        assert_eq!(operations.len(), 3);
+        assert!(operations[0].instruction.is_err());
+        assert_eq!(operations[0].curve(), Some(curve));
+        assert!(operations[1].instruction.is_err());
+        assert!(operations[1].accounts.is_empty());
+        assert!(matches!(
+            operations[2].instruction,
+            Ok(CurvyInstruction::DeleteCurve)
+        ));
+    }
+
+    #[test]
+    fn split_batch_alter() {
+        let owner = Pubkey::new_unique();
+        let curves = [Pubkey::new_unique(), Pubkey::new_unique()];
+        let params = |x0| {
+            let mut y = [0; MAX_Y_CNT];
+            y[..2].copy_from_slice(&[100, 200]);
+            CompactCurveParams::from(&CurveParams::new("batch", "", x0, 10, 2, 2, y))
+        };
+
+        let batch = DecodedOperation {
+            signature: Some("sig".to_string()),
+            instruction: Ok(CurvyInstruction::BatchAlter {
+                params: vec![params(1), params(2)],
+            }),
+            accounts: vec![owner, curves[0], curves[1]],
+        };
+        assert_eq!(batch.curve(), None);
+
+        let operations = batch.split_batch();
+        assert_eq!(operations.len(), 2);
+        for (idx, operation) in operations.iter().enumerate() {
+            assert_eq!(operation.curve(), Some(curves[idx]));
+            assert_eq!(operation.accounts[1], owner);
+            let Ok(CurvyInstruction::AlterCurve { params }) = &operation.instruction else {
+                panic!("unexpected {:?}", operation.instruction);
+            };
+            assert_eq!(params.x0, idx as CurveX + 1);
+            assert_eq!(params.y_count, 2);
+        }
+    }
+}
@@ -0,0 +1,115 @@
+//! Minimal on-chain program consuming Curvy curves.
+//!
+//! `Evaluate { x }` reads Curve account, rejects draft and retired ones (see
+//! [Curve::check_consumable]), calculates `y` in the point `x` with the same
+//! interpolation as off-chain tools ([curvy_utils::calc_y]) and stores the result in
+//! [Evaluation] account owned by this program.
+
+use borsh::{BorshDeserialize, BorshSerialize};
+use bytemuck::{Pod, Zeroable};
+use solana_program::account_info::{next_account_info, AccountInfo};
+use solana_program::entrypoint::ProgramResult;
+use solana_program::instruction::{AccountMeta, Instruction};
+use solana_program::msg;
+use solana_program::program_error::ProgramError;
+use solana_program::pubkey::Pubkey;
+use texture_common::math::{CheckedMul, Decimal};
+
+use curvy::state::curve::Curve;
+
+#[cfg(not(feature = "no-entrypoint"))]
+solana_program::entrypoint!(process_instruction);
+
+solana_program::declare_id!("3SLoYkDBzi3R2GbM7vTdvDmsQjecFohnbJc8HfYZsqXG");
+
+/// Number of decimals in `x` passed to the program and `y` stored in [Evaluation]
+pub const DECIMALS: u32 = 9;
+
+#[derive(BorshSerialize, BorshDeserialize, Debug)]
+pub enum ConsumerInstruction {
+    /// Evaluate curve in point `x`
+    ///
+    /// Accounts:
+    /// 0. `[]` Curve account
+    /// 1. `[writable]` Evaluation account (owned by this program, [Evaluation::SIZE] bytes)
+    Evaluate { x: u64 },
+}
+
+/// Result of the last evaluation
+#[derive(Debug, Copy, Clone, Pod, Zeroable)]
+#[repr(C)]
+pub struct Evaluation {
+    pub curve: Pubkey,
+    /// `x` with [DECIMALS] decimals
+    pub x: u64,
+    /// `y` with [DECIMALS] decimals
+    pub y: u64,
+}
+
+impl Evaluation {
+    pub const SIZE: usize = std::mem::size_of::<Self>();
+}
+
+pub fn evaluate(curve: Pubkey, evaluation: Pubkey, x: u64) -> Instruction {
+    Instruction::new_with_borsh(
+        ID,
+        &ConsumerInstruction::Evaluate { x },
+        vec![
+            AccountMeta::new_readonly(curve, false),
+            AccountMeta::new(evaluation, false),
+        ],
+    )
+}
+
+pub fn process_instruction(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo<'_>],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let ConsumerInstruction::Evaluate { x } = ConsumerInstruction::try_from_slice(instruction_data)
+        .map_err(|_| ProgramError::InvalidInstructionData)?;
+
+    let accounts = &mut accounts.iter();
+    let curve_info = next_account_info(accounts)?;
+    let evaluation_info = next_account_info(accounts)?;
+
+    if curve_info.owner != &curvy::ID {
+        msg!("curve is not owned by Curvy program");
+        return Err(ProgramError::IllegalOwner);
+    }
+
+    if evaluation_info.owner != program_id || !evaluation_info.is_writable {
+        msg!("evaluation must be writable and owned by consumer program");
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    let y = {
+        let curve_data = curve_info.try_borrow_data()?;
+        let curve = Curve::load(&curve_data).map_err(|_| ProgramError::InvalidAccountData)?;
+        curve.check_consumable()?;
+
+        let y = Decimal::from_i128_with_scale(x as i128, DECIMALS)
+            .and_then(|x| curvy_utils::calc_y_extended(x, curve, &curve.tail_y(&curve_data)))
+            .and_then(|y| y.checked_mul(Decimal::from_i128_with_scale(10_i128.pow(DECIMALS), 0)?))
+            .and_then(|y| y.floor());
+
+        y.map_err(|err| {
+            msg!("evaluation failed: {}", err);
+            ProgramError::InvalidArgument
+        })?
+    };
+
+    let mut evaluation_data = evaluation_info.try_borrow_mut_data()?;
+    let evaluation: &mut Evaluation = bytemuck::try_from_bytes_mut(&mut evaluation_data)
+        .map_err(|_| ProgramError::InvalidAccountData)?;
+
+    *evaluation = Evaluation {
+        curve: *curve_info.key,
+        x,
+        y,
+    };
+
+    msg!("y({}) = {}", x, y);
+
+    Ok(())
+}
@@ -0,0 +1,107 @@
+use solana_program::account_info::AccountInfo;
+use solana_program::program_error::ProgramError;
+use solana_program::pubkey::Pubkey;
+use texture_common::account::PodAccount;
+
+use curvy::error::CurvyError;
+use curvy::state::curve::{Curve, CurveParams, CurveStatus, MAX_Y_CNT};
+use curvy_consumer_example::{evaluate, process_instruction, Evaluation, ID};
+
+fn curve_data(status: CurveStatus) -> Vec<u8> {
+    let mut y = [0; MAX_Y_CNT];
+    y[..3].copy_from_slice(&[1_000, 2_000, 4_000]);
+
+    // points (0.00; 10.00), (0.10; 20.00), (0.20; 40.00)
+    let params = CurveParams::new("test", "y=f(x)", 0, 10, 3, 2, y);
+    let mut curve = Curve::from_init_params((params, Pubkey::new_unique()));
+    curve.status = status as u8;
+
+    bytemuck::bytes_of(&curve).to_vec()
+}
+
+fn run(curve_owner: Pubkey, x: u64) -> Result<Evaluation, ProgramError> {
+    run_with_status(curve_owner, CurveStatus::Active, x)
+}
+
+fn run_with_status(
+    curve_owner: Pubkey,
+    status: CurveStatus,
+    x: u64,
+) -> Result<Evaluation, ProgramError> {
+    let curve_key = Pubkey::new_unique();
+    let evaluation_key = Pubkey::new_unique();
+    let ix = evaluate(curve_key, evaluation_key, x);
+
+    let mut curve_lamports = 0;
+    let mut curve_data = curve_data(status);
+    let mut evaluation_lamports = 0;
+    let mut evaluation_data = vec![0; Evaluation::SIZE];
+
+    let accounts = [
+        AccountInfo::new(
+            &curve_key,
+            false,
+            false,
+            &mut curve_lamports,
+            &mut curve_data,
+            &curve_owner,
+            false,
+            0,
+        ),
+        AccountInfo::new(
+            &evaluation_key,
+            false,
+            true,
+            &mut evaluation_lamports,
+            &mut evaluation_data,
+            &ID,
+            false,
+            0,
+        ),
+    ];
+
+    process_instruction(&ID, &accounts, &ix.data)?;
+
+    drop(accounts);
+    Ok(*bytemuck::from_bytes(&evaluation_data))
+}
+
+#[test]
+fn evaluates_curve() {
+    // x = 0.15 is in the middle between 20 and 40
+    let evaluation = run(curvy::ID, 150_000_000).unwrap();
+    assert_eq!(evaluation.x, 150_000_000);
+    assert_eq!(evaluation.y, 30_000_000_000);
+
+    // exact sample
+    let evaluation = run(curvy::ID, 100_000_000).unwrap();
+    assert_eq!(evaluation.y, 20_000_000_000);
+
+    // out of curve range
+    assert_eq!(
+        run(curvy::ID, 300_000_000).unwrap_err(),
+        ProgramError::InvalidArgument
+    );
+
+    // curve of another program
+    assert_eq!(
+        run(Pubkey::new_unique(), 150_000_000).unwrap_err(),
+        ProgramError::IllegalOwner
+    );
+}
+
+#[test]
+fn checks_curve_status() {
+    let evaluation = run_with_status(curvy::ID, CurveStatus::Deprecated, 150_000_000).unwrap();
+    assert_eq!(evaluation.y, 30_000_000_000);
+
+    for status in [CurveStatus::Draft, CurveStatus::Retired] {
+        assert_eq!(
+            run_with_status(curvy::ID, status, 150_000_000).unwrap_err(),
+            CurvyError::CurveNotActive {
+                status: status as u8
+            }
+            .into()
+        );
+    }
+}
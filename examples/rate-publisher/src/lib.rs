@@ -0,0 +1,129 @@
+//! Off-chain service publishing rates derived from a Curvy curve.
+//!
+//! The binary keeps the curve cached and up to date by an account subscription, reads
+//! utilization values from stdin or an HTTP endpoint, evaluates the curve in every point and
+//! publishes the result to stdout or a webhook. [Publisher] holds the part which does not
+//! depend on I/O: the cached curve, staleness guard and evaluation.
+
+use std::time::{Duration, Instant};
+
+use solana_sdk::clock::Slot;
+use solana_sdk::commitment_config::CommitmentLevel;
+use solana_sdk::pubkey::Pubkey;
+use texture_common::math::Decimal;
+
+use curvy_client::{CurveView, WithContext};
+
+/// Number of decimals kept from utilization values
+pub const DECIMALS: u32 = 9;
+
+#[derive(Debug, thiserror::Error)]
+pub enum PublishError {
+    #[error("curve is not loaded yet")]
+    NoCurve,
+
+    #[error("cached curve is {age:?} old, max allowed age is {max_age:?}")]
+    Stale { age: Duration, max_age: Duration },
+
+    #[error("invalid utilization `{input}`: {reason}")]
+    InvalidInput { input: String, reason: String },
+
+    #[error("evaluation failed: {0}")]
+    Eval(String),
+}
+
+/// Published rate
+#[serde_with::serde_as]
+#[derive(Debug, serde::Serialize)]
+pub struct Rate {
+    #[serde_as(as = "serde_with::DisplayFromStr")]
+    pub curve: Pubkey,
+    /// Slot of the curve state used for evaluation
+    pub slot: Slot,
+    /// Commitment the curve state was read with
+    pub commitment: CommitmentLevel,
+    #[serde_as(as = "serde_with::DisplayFromStr")]
+    pub utilization: Decimal,
+    #[serde_as(as = "serde_with::DisplayFromStr")]
+    pub rate: Decimal,
+}
+
+struct CachedCurve {
+    state: WithContext<CurveView>,
+    /// When the cached state was last confirmed to be current
+    confirmed_at: Instant,
+}
+
+/// Cached curve with staleness guard
+pub struct Publisher {
+    curve: Option<CachedCurve>,
+    max_age: Duration,
+}
+
+impl Publisher {
+    pub fn new(max_age: Duration) -> Self {
+        Self {
+            curve: None,
+            max_age,
+        }
+    }
+
+    /// Stores curve state. Updates from older slots than the cached one only confirm the cache
+    /// is still current.
+    pub fn update(&mut self, state: WithContext<CurveView>, now: Instant) {
+        match &mut self.curve {
+            Some(cached) if cached.state.slot > state.slot => cached.confirmed_at = now,
+            _ => {
+                self.curve = Some(CachedCurve {
+                    state,
+                    confirmed_at: now,
+                })
+            }
+        }
+    }
+
+    /// Evaluates cached curve refusing to use it when it was not confirmed for too long
+    pub fn evaluate(&self, utilization: Decimal, now: Instant) -> Result<Rate, PublishError> {
+        let cached = self.curve.as_ref().ok_or(PublishError::NoCurve)?;
+
+        let age = now.saturating_duration_since(cached.confirmed_at);
+        if age > self.max_age {
+            return Err(PublishError::Stale {
+                age,
+                max_age: self.max_age,
+            });
+        }
+
+        let rate = cached
+            .state
+            .calc_y(utilization)
+            .map_err(|err| PublishError::Eval(err.to_string()))?;
+
+        Ok(Rate {
+            curve: cached.state.key,
+            slot: cached.state.slot,
+            commitment: cached.state.commitment,
+            utilization,
+            rate,
+        })
+    }
+}
+
+/// Parses utilization like `0.75`
+pub fn parse_utilization(input: &str) -> Result<Decimal, PublishError> {
+    let invalid = |reason: String| PublishError::InvalidInput {
+        input: input.to_string(),
+        reason,
+    };
+
+    let value = input
+        .trim()
+        .parse::<f64>()
+        .map_err(|err| invalid(err.to_string()))?;
+    if !value.is_finite() {
+        return Err(invalid("not a finite number".to_string()));
+    }
+
+    Decimal::from_i128_with_scale((value * 10_f64.powi(DECIMALS as i32)) as i128, DECIMALS)
+        .map_err(|err| invalid(err.to_string()))
+}
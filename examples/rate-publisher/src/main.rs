@@ -0,0 +1,208 @@
+use std::ops::ControlFlow;
+use std::sync::{Arc, Mutex};
+use std::time::Instant;
+
+use anyhow::{anyhow, Result};
+use solana_client::nonblocking::rpc_client::RpcClient;
+use solana_sdk::commitment_config::{CommitmentConfig, CommitmentLevel};
+use solana_sdk::pubkey::Pubkey;
+use structopt::StructOpt;
+use tokio::io::AsyncBufReadExt;
+
+use curvy_client::{watch_curve, CurveEvent, CurveView, Resubscribe, WithContext};
+use curvy_rate_publisher_example::{parse_utilization, Publisher, Rate};
+
+#[derive(StructOpt)]
+#[structopt(rename_all = "kebab-case")]
+struct Opts {
+    /// URL of RPC Solana interface.
+    #[structopt(long, short, default_value = "http://localhost:8899")]
+    url: String,
+
+    /// URL of RPC Solana websocket interface.
+    #[structopt(long, default_value = "ws://localhost:8900")]
+    ws_url: String,
+
+    #[structopt(long, default_value = "confirmed")]
+    commitment: CommitmentLevel,
+
+    /// Curve account
+    #[structopt(long)]
+    curve: Pubkey,
+
+    /// Do not publish rates when the cached curve was not confirmed for this long
+    #[structopt(long, default_value = "2m")]
+    max_age: humantime::Duration,
+
+    /// Refetch the curve this often to confirm the cache when there are no updates
+    #[structopt(long, default_value = "30s")]
+    refresh: humantime::Duration,
+
+    /// Poll utilization (plain text number) from this URL. Read from stdin line by line when
+    /// omitted.
+    #[structopt(long)]
+    utilization_url: Option<String>,
+
+    /// Utilization polling interval
+    #[structopt(long, default_value = "10s")]
+    poll_interval: humantime::Duration,
+
+    /// POST published rates as JSON to this URL. Printed to stdout when omitted.
+    #[structopt(long)]
+    webhook: Option<String>,
+}
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    let opts = Opts::from_args();
+    let publisher = Arc::new(Mutex::new(Publisher::new(opts.max_age.into())));
+
+    let rpc = Arc::new(RpcClient::new_with_commitment(
+        opts.url.clone(),
+        CommitmentConfig {
+            commitment: opts.commitment,
+        },
+    ));
+
+    // fail fast on wrong address, then keep the cache updated in background
+    let state = fetch_curve(&rpc, opts.curve).await?;
+    publisher.lock().unwrap().update(state, Instant::now());
+
+    {
+        let rpc = rpc.clone();
+        let publisher = publisher.clone();
+        let ws_url = opts.ws_url.clone();
+        let curve = opts.curve;
+        tokio::spawn(async move {
+            // resubscribes on its own, publishing stops by staleness guard meanwhile
+            if let Err(err) = follow_curve(&rpc, &ws_url, curve, &publisher).await {
+                eprintln!("curve subscription failed: {err:?}");
+            }
+        });
+    }
+    {
+        let publisher = publisher.clone();
+        let curve = opts.curve;
+        let refresh = opts.refresh;
+        tokio::spawn(async move { refresh_curve(&rpc, curve, refresh, &publisher).await });
+    }
+
+    let http = reqwest::Client::new();
+
+    let publish = |input: String| {
+        let rate = parse_utilization(&input).and_then(|utilization| {
+            publisher
+                .lock()
+                .unwrap()
+                .evaluate(utilization, Instant::now())
+        });
+        let http = &http;
+        let webhook = opts.webhook.as_deref();
+        async move {
+            match rate {
+                Ok(rate) => {
+                    if let Err(err) = publish_rate(http, webhook, &rate).await {
+                        eprintln!("publishing failed: {err:?}");
+                    }
+                }
+                Err(err) => eprintln!("skipped: {err}"),
+            }
+        }
+    };
+
+    match &opts.utilization_url {
+        Some(url) => {
+            let mut interval = tokio::time::interval(opts.poll_interval.into());
+            loop {
+                interval.tick().await;
+                match fetch_utilization(&http, url).await {
+                    Ok(input) => publish(input).await,
+                    Err(err) => eprintln!("fetching utilization failed: {err:?}"),
+                }
+            }
+        }
+        None => {
+            let mut lines = tokio::io::BufReader::new(tokio::io::stdin()).lines();
+            while let Some(line) = lines.next_line().await? {
+                if !line.trim().is_empty() {
+                    publish(line).await;
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
+async fn fetch_curve(rpc: &RpcClient, curve: Pubkey) -> Result<WithContext<CurveView>> {
+    let response = rpc
+        .get_account_with_commitment(&curve, rpc.commitment())
+        .await?;
+    let account = response
+        .value
+        .ok_or_else(|| anyhow!("curve account {curve} not found"))?;
+
+    Ok(WithContext::new(
+        CurveView::from_account(curve, &account)?,
+        response.context.slot,
+        rpc.commitment().commitment,
+    ))
+}
+
+/// Keeps the cache updated from the resilient curve subscription, see [watch_curve]
+async fn follow_curve(
+    rpc: &RpcClient,
+    ws_url: &str,
+    curve: Pubkey,
+    publisher: &Mutex<Publisher>,
+) -> Result<()> {
+    watch_curve(rpc, ws_url, curve, Resubscribe::default(), |event| {
+        if let CurveEvent::Resynced(state) = &event {
+            eprintln!("curve subscription restored at slot {}", state.slot);
+        }
+        publisher
+            .lock()
+            .unwrap()
+            .update(event.into_state(), Instant::now());
+        ControlFlow::Continue(())
+    })
+    .await
+}
+
+/// Refetches the curve to confirm the cache when there are no updates
+async fn refresh_curve(
+    rpc: &RpcClient,
+    curve: Pubkey,
+    refresh: humantime::Duration,
+    publisher: &Mutex<Publisher>,
+) {
+    let mut refresh = tokio::time::interval(refresh.into());
+    loop {
+        refresh.tick().await;
+        match fetch_curve(rpc, curve).await {
+            Ok(state) => publisher.lock().unwrap().update(state, Instant::now()),
+            Err(err) => eprintln!("refreshing curve failed: {err:?}"),
+        }
+    }
+}
+
+async fn fetch_utilization(http: &reqwest::Client, url: &str) -> Result<String> {
+    Ok(http
+        .get(url)
+        .send()
+        .await?
+        .error_for_status()?
+        .text()
+        .await?)
+}
+
+async fn publish_rate(http: &reqwest::Client, webhook: Option<&str>, rate: &Rate) -> Result<()> {
+    match webhook {
+        Some(url) => {
+            http.post(url).json(rate).send().await?.error_for_status()?;
+        }
+        None => println!("{}", serde_json::to_string(rate)?),
+    }
+
+    Ok(())
+}
@@ -0,0 +1,68 @@
+use std::time::{Duration, Instant};
+
+use solana_sdk::clock::Slot;
+use solana_sdk::commitment_config::CommitmentLevel;
+use solana_sdk::pubkey::Pubkey;
+use texture_common::account::PodAccount;
+use texture_common::math::Decimal;
+
+use curvy::state::curve::{Curve, CurveParams, MAX_Y_CNT};
+use curvy_client::{CurveView, WithContext};
+use curvy_rate_publisher_example::{parse_utilization, PublishError, Publisher};
+
+const MAX_AGE: Duration = Duration::from_secs(60);
+
+fn curve_state(y0: u32, slot: Slot) -> WithContext<CurveView> {
+    let mut y = [0; MAX_Y_CNT];
+    y[..3].copy_from_slice(&[y0, 2_000, 4_000]);
+
+    // points (0.00; y0), (0.10; 20.00), (0.20; 40.00)
+    let params = CurveParams::new("test", "y=f(x)", 0, 10, 3, 2, y);
+
+    let view = CurveView {
+        key: Pubkey::new_unique(),
+        curve: Curve::from_init_params((params, Pubkey::new_unique())),
+        tail: vec![],
+        extensions: vec![],
+        account: None,
+    };
+    WithContext::new(view, slot, CommitmentLevel::Confirmed)
+}
+
+#[test]
+fn publish() {
+    let now = Instant::now();
+    let mut publisher = Publisher::new(MAX_AGE);
+    let utilization = parse_utilization("0.15").unwrap();
+
+    assert!(matches!(
+        publisher.evaluate(utilization, now),
+        Err(PublishError::NoCurve)
+    ));
+
+    publisher.update(curve_state(1_000, 10), now);
+    let rate = publisher.evaluate(utilization, now).unwrap();
+    assert_eq!(rate.slot, 10);
+    assert_eq!(rate.commitment, CommitmentLevel::Confirmed);
+    assert_eq!(rate.rate, Decimal::from_i128_with_scale(3_000, 2).unwrap());
+
+    // older state only confirms the cache
+    publisher.update(curve_state(0, 9), now + MAX_AGE);
+    let rate = publisher
+        .evaluate(parse_utilization("0").unwrap(), now + MAX_AGE * 2)
+        .unwrap();
+    assert_eq!(rate.rate, Decimal::from_i128_with_scale(1_000, 2).unwrap());
+
+    assert!(matches!(
+        publisher.evaluate(utilization, now + MAX_AGE * 3),
+        Err(PublishError::Stale { .. })
+    ));
+    assert!(matches!(
+        publisher.evaluate(parse_utilization("0.5").unwrap(), now),
+        Err(PublishError::Eval(_))
+    ));
+    assert!(matches!(
+        parse_utilization("high"),
+        Err(PublishError::InvalidInput { .. })
+    ));
+}
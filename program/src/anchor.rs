@@ -0,0 +1,1193 @@
+//! Anchor compatibility layer for Anchor-based consumer programs.
+//!
+//! Allows to use `Account<'info, Curve>` (or `WideCurve`, `GridCurve`, `CurveEvaluation`) in
+//! `#[derive(Accounts)]` structs and to call Curvy instructions through `CpiContext`.
+
+use anchor_lang::error::ErrorCode;
+use anchor_lang::prelude::{AccountInfo, Pubkey};
+use anchor_lang::{AccountDeserialize, AccountSerialize, Owner};
+use texture_common::account::PodAccount;
+
+use crate::state::curve::Curve;
+use crate::state::curve_evaluation::CurveEvaluation;
+use crate::state::grid_curve::GridCurve;
+use crate::state::wide_curve::WideCurve;
+
+macro_rules! read_only_account {
+    ($name:ident) => {
+        impl Owner for $name {
+            fn owner() -> Pubkey {
+                crate::ID
+            }
+        }
+
+        impl AccountDeserialize for $name {
+            fn try_deserialize(buf: &mut &[u8]) -> anchor_lang::Result<Self> {
+                if buf.len() < Self::DISCRIMINATOR.len()
+                    || &buf[..Self::DISCRIMINATOR.len()] != Self::DISCRIMINATOR
+                {
+                    return Err(ErrorCode::AccountDiscriminatorMismatch.into());
+                }
+
+                Self::try_deserialize_unchecked(buf)
+            }
+
+            fn try_deserialize_unchecked(buf: &mut &[u8]) -> anchor_lang::Result<Self> {
+                let curve = $name::try_from_bytes(buf.get(..Self::SIZE).unwrap_or(buf))
+                    .map_err(|_| ErrorCode::AccountDidNotDeserialize)?;
+
+                Ok(*curve)
+            }
+        }
+
+        /// Curve accounts are read-only for consumers, so nothing is written back on exit.
+        impl AccountSerialize for $name {}
+    };
+}
+
+read_only_account!(Curve);
+read_only_account!(WideCurve);
+read_only_account!(GridCurve);
+read_only_account!(CurveEvaluation);
+
+/// CPI helpers mirroring [crate::instruction::CurvyInstruction].
+pub mod cpi {
+    use anchor_lang::prelude::{AccountInfo, CpiContext, Pubkey};
+    use anchor_lang::solana_program::program::invoke_signed;
+
+    use crate::state::curve::{
+        CompactCurveParams, CurveParams, CurveStatus, CurveX, CurveY, MAX_Y_CNT, SYMBOL_MAX_SIZE,
+    };
+    use crate::state::curve_confidence::CurveConfidenceBps;
+    use crate::state::grid_curve::GridCurveParams;
+    use crate::state::wide_curve::WideCurveParams;
+
+    pub fn create_curve<'info>(
+        ctx: CpiContext<'_, '_, '_, 'info, accounts::CreateCurve<'info>>,
+        params: CurveParams,
+    ) -> anchor_lang::Result<()> {
+        let ix = crate::instruction::CreateCurve {
+            #[cfg(feature = "program-id-manually")]
+            program_id: *ctx.program.key,
+            curve: *ctx.accounts.curve.key,
+            owner: *ctx.accounts.owner.key,
+            config: *ctx.accounts.config.key,
+            name_link: *ctx.accounts.name_link.key,
+            params,
+        }
+        .into_instruction();
+
+        invoke(ctx, ix)
+    }
+
+    /// Curve owners account and other signing owners of co-owned curve are passed as
+    /// remaining accounts
+    pub fn alter_curve<'info>(
+        ctx: CpiContext<'_, '_, '_, 'info, accounts::AlterCurve<'info>>,
+        params: CurveParams,
+    ) -> anchor_lang::Result<()> {
+        let mut ix = crate::instruction::AlterCurve {
+            #[cfg(feature = "program-id-manually")]
+            program_id: *ctx.program.key,
+            curve: *ctx.accounts.curve.key,
+            owner: *ctx.accounts.owner.key,
+            params,
+        }
+        .into_instruction();
+        ix.accounts.extend(remaining_metas(&ctx.remaining_accounts));
+
+        invoke(ctx, ix)
+    }
+
+    pub fn update_curve_metadata<'info>(
+        ctx: CpiContext<'_, '_, '_, 'info, accounts::UpdateCurveMetadata<'info>>,
+        name: [u8; SYMBOL_MAX_SIZE],
+        formula: [u8; SYMBOL_MAX_SIZE],
+    ) -> anchor_lang::Result<()> {
+        let ix = crate::instruction::UpdateCurveMetadata {
+            #[cfg(feature = "program-id-manually")]
+            program_id: *ctx.program.key,
+            curve: *ctx.accounts.curve.key,
+            owner: *ctx.accounts.owner.key,
+            name_link: *ctx.accounts.name_link.key,
+            new_name_link: *ctx.accounts.new_name_link.key,
+            name,
+            formula,
+        }
+        .into_instruction();
+
+        invoke(ctx, ix)
+    }
+
+    pub fn update_curve_data<'info>(
+        ctx: CpiContext<'_, '_, '_, 'info, accounts::UpdateCurveData<'info>>,
+        x0: CurveX,
+        x_step: CurveX,
+        y_count: u8,
+        decimals: u8,
+        y: [CurveY; MAX_Y_CNT],
+    ) -> anchor_lang::Result<()> {
+        let ix = crate::instruction::UpdateCurveData {
+            #[cfg(feature = "program-id-manually")]
+            program_id: *ctx.program.key,
+            curve: *ctx.accounts.curve.key,
+            owner: *ctx.accounts.owner.key,
+            x0,
+            x_step,
+            y_count,
+            decimals,
+            y,
+        }
+        .into_instruction();
+
+        invoke(ctx, ix)
+    }
+
+    pub fn reserve_curve<'info>(
+        ctx: CpiContext<'_, '_, '_, 'info, accounts::ReserveCurve<'info>>,
+        name: [u8; SYMBOL_MAX_SIZE],
+        formula: [u8; SYMBOL_MAX_SIZE],
+    ) -> anchor_lang::Result<()> {
+        let ix = crate::instruction::ReserveCurve {
+            #[cfg(feature = "program-id-manually")]
+            program_id: *ctx.program.key,
+            curve: *ctx.accounts.curve.key,
+            owner: *ctx.accounts.owner.key,
+            config: *ctx.accounts.config.key,
+            name_link: *ctx.accounts.name_link.key,
+            name,
+            formula,
+        }
+        .into_instruction();
+
+        invoke(ctx, ix)
+    }
+
+    /// Rent destination other than the owner is passed as remaining account
+    pub fn delete_curve<'info>(
+        ctx: CpiContext<'_, '_, '_, 'info, accounts::DeleteCurve<'info>>,
+    ) -> anchor_lang::Result<()> {
+        let mut ix = crate::instruction::DeleteCurve {
+            #[cfg(feature = "program-id-manually")]
+            program_id: *ctx.program.key,
+            curve: *ctx.accounts.curve.key,
+            owner: *ctx.accounts.owner.key,
+            name_link: *ctx.accounts.name_link.key,
+        }
+        .into_instruction();
+        ix.accounts.extend(remaining_metas(&ctx.remaining_accounts));
+
+        invoke(ctx, ix)
+    }
+
+    pub fn top_up_rent<'info>(
+        ctx: CpiContext<'_, '_, '_, 'info, accounts::TopUpRent<'info>>,
+    ) -> anchor_lang::Result<()> {
+        let ix = crate::instruction::TopUpRent {
+            #[cfg(feature = "program-id-manually")]
+            program_id: *ctx.program.key,
+            curve: *ctx.accounts.curve.key,
+            payer: *ctx.accounts.payer.key,
+        }
+        .into_instruction();
+
+        invoke(ctx, ix)
+    }
+
+    pub fn set_curve_asset<'info>(
+        ctx: CpiContext<'_, '_, '_, 'info, accounts::SetCurveAsset<'info>>,
+        kind: u8,
+    ) -> anchor_lang::Result<()> {
+        let ix = crate::instruction::SetCurveAsset {
+            #[cfg(feature = "program-id-manually")]
+            program_id: *ctx.program.key,
+            curve: *ctx.accounts.curve.key,
+            owner: *ctx.accounts.owner.key,
+            asset_mint: *ctx.accounts.asset_mint.key,
+            asset_link: *ctx.accounts.asset_link.key,
+            kind,
+        }
+        .into_instruction();
+
+        invoke(ctx, ix)
+    }
+
+    pub fn clear_curve_asset<'info>(
+        ctx: CpiContext<'_, '_, '_, 'info, accounts::ClearCurveAsset<'info>>,
+    ) -> anchor_lang::Result<()> {
+        let ix = crate::instruction::ClearCurveAsset {
+            #[cfg(feature = "program-id-manually")]
+            program_id: *ctx.program.key,
+            curve: *ctx.accounts.curve.key,
+            owner: *ctx.accounts.owner.key,
+            asset_link: *ctx.accounts.asset_link.key,
+        }
+        .into_instruction();
+
+        invoke(ctx, ix)
+    }
+
+    pub fn migrate_curve<'info>(
+        ctx: CpiContext<'_, '_, '_, 'info, accounts::MigrateCurve<'info>>,
+    ) -> anchor_lang::Result<()> {
+        let ix = crate::instruction::MigrateCurve {
+            #[cfg(feature = "program-id-manually")]
+            program_id: *ctx.program.key,
+            curve: *ctx.accounts.curve.key,
+            payer: *ctx.accounts.payer.key,
+        }
+        .into_instruction();
+
+        invoke(ctx, ix)
+    }
+
+    pub fn set_curve_series<'info>(
+        ctx: CpiContext<'_, '_, '_, 'info, accounts::SetCurveSeries<'info>>,
+        series: u8,
+        y: [CurveY; MAX_Y_CNT],
+    ) -> anchor_lang::Result<()> {
+        let ix = crate::instruction::SetCurveSeries {
+            #[cfg(feature = "program-id-manually")]
+            program_id: *ctx.program.key,
+            curve: *ctx.accounts.curve.key,
+            owner: *ctx.accounts.owner.key,
+            curve_series: *ctx.accounts.curve_series.key,
+            series,
+            y,
+        }
+        .into_instruction();
+
+        invoke(ctx, ix)
+    }
+
+    pub fn delete_curve_series<'info>(
+        ctx: CpiContext<'_, '_, '_, 'info, accounts::DeleteCurveSeries<'info>>,
+    ) -> anchor_lang::Result<()> {
+        let ix = crate::instruction::DeleteCurveSeries {
+            #[cfg(feature = "program-id-manually")]
+            program_id: *ctx.program.key,
+            curve: *ctx.accounts.curve.key,
+            owner: *ctx.accounts.owner.key,
+            curve_series: *ctx.accounts.curve_series.key,
+        }
+        .into_instruction();
+
+        invoke(ctx, ix)
+    }
+
+    pub fn set_curve_confidence<'info>(
+        ctx: CpiContext<'_, '_, '_, 'info, accounts::SetCurveConfidence<'info>>,
+        confidence: [CurveConfidenceBps; MAX_Y_CNT],
+    ) -> anchor_lang::Result<()> {
+        let ix = crate::instruction::SetCurveConfidence {
+            #[cfg(feature = "program-id-manually")]
+            program_id: *ctx.program.key,
+            curve: *ctx.accounts.curve.key,
+            owner: *ctx.accounts.owner.key,
+            curve_confidence: *ctx.accounts.curve_confidence.key,
+            confidence,
+        }
+        .into_instruction();
+
+        invoke(ctx, ix)
+    }
+
+    pub fn delete_curve_confidence<'info>(
+        ctx: CpiContext<'_, '_, '_, 'info, accounts::DeleteCurveConfidence<'info>>,
+    ) -> anchor_lang::Result<()> {
+        let ix = crate::instruction::DeleteCurveConfidence {
+            #[cfg(feature = "program-id-manually")]
+            program_id: *ctx.program.key,
+            curve: *ctx.accounts.curve.key,
+            owner: *ctx.accounts.owner.key,
+            curve_confidence: *ctx.accounts.curve_confidence.key,
+        }
+        .into_instruction();
+
+        invoke(ctx, ix)
+    }
+
+    pub fn create_wide_curve<'info>(
+        ctx: CpiContext<'_, '_, '_, 'info, accounts::CreateWideCurve<'info>>,
+        params: WideCurveParams,
+    ) -> anchor_lang::Result<()> {
+        let ix = crate::instruction::CreateWideCurve {
+            #[cfg(feature = "program-id-manually")]
+            program_id: *ctx.program.key,
+            curve: *ctx.accounts.curve.key,
+            owner: *ctx.accounts.owner.key,
+            config: *ctx.accounts.config.key,
+            name_link: *ctx.accounts.name_link.key,
+            params,
+        }
+        .into_instruction();
+
+        invoke(ctx, ix)
+    }
+
+    pub fn alter_wide_curve<'info>(
+        ctx: CpiContext<'_, '_, '_, 'info, accounts::AlterWideCurve<'info>>,
+        params: WideCurveParams,
+    ) -> anchor_lang::Result<()> {
+        let ix = crate::instruction::AlterWideCurve {
+            #[cfg(feature = "program-id-manually")]
+            program_id: *ctx.program.key,
+            curve: *ctx.accounts.curve.key,
+            owner: *ctx.accounts.owner.key,
+            params,
+        }
+        .into_instruction();
+
+        invoke(ctx, ix)
+    }
+
+    pub fn set_curve_x_sign<'info>(
+        ctx: CpiContext<'_, '_, '_, 'info, accounts::SetCurveXSign<'info>>,
+        x0_negative: bool,
+    ) -> anchor_lang::Result<()> {
+        let ix = crate::instruction::SetCurveXSign {
+            #[cfg(feature = "program-id-manually")]
+            program_id: *ctx.program.key,
+            curve: *ctx.accounts.curve.key,
+            owner: *ctx.accounts.owner.key,
+            x0_negative,
+        }
+        .into_instruction();
+
+        invoke(ctx, ix)
+    }
+
+    pub fn set_curve_y_sign<'info>(
+        ctx: CpiContext<'_, '_, '_, 'info, accounts::SetCurveYSign<'info>>,
+        y_signed: bool,
+    ) -> anchor_lang::Result<()> {
+        let ix = crate::instruction::SetCurveYSign {
+            #[cfg(feature = "program-id-manually")]
+            program_id: *ctx.program.key,
+            curve: *ctx.accounts.curve.key,
+            owner: *ctx.accounts.owner.key,
+            y_signed,
+        }
+        .into_instruction();
+
+        invoke(ctx, ix)
+    }
+
+    pub fn set_curve_y_transform<'info>(
+        ctx: CpiContext<'_, '_, '_, 'info, accounts::SetCurveYTransform<'info>>,
+        y_transform: u8,
+    ) -> anchor_lang::Result<()> {
+        let ix = crate::instruction::SetCurveYTransform {
+            #[cfg(feature = "program-id-manually")]
+            program_id: *ctx.program.key,
+            curve: *ctx.accounts.curve.key,
+            owner: *ctx.accounts.owner.key,
+            y_transform,
+        }
+        .into_instruction();
+
+        invoke(ctx, ix)
+    }
+
+    pub fn set_curve_expiry<'info>(
+        ctx: CpiContext<'_, '_, '_, 'info, accounts::SetCurveExpiry<'info>>,
+        valid_until: i64,
+        validity_period: u64,
+    ) -> anchor_lang::Result<()> {
+        let ix = crate::instruction::SetCurveExpiry {
+            #[cfg(feature = "program-id-manually")]
+            program_id: *ctx.program.key,
+            curve: *ctx.accounts.curve.key,
+            owner: *ctx.accounts.owner.key,
+            valid_until,
+            validity_period,
+        }
+        .into_instruction();
+
+        invoke(ctx, ix)
+    }
+
+    /// Altered curves are passed as writable remaining accounts in the order of `params`
+    pub fn batch_alter<'info>(
+        ctx: CpiContext<'_, '_, '_, 'info, accounts::BatchAlter<'info>>,
+        params: Vec<CompactCurveParams>,
+    ) -> anchor_lang::Result<()> {
+        let mut ix = crate::instruction::BatchAlter {
+            #[cfg(feature = "program-id-manually")]
+            program_id: *ctx.program.key,
+            owner: *ctx.accounts.owner.key,
+            params,
+        }
+        .into_instruction();
+        ix.accounts.extend(remaining_metas(&ctx.remaining_accounts));
+
+        invoke(ctx, ix)
+    }
+
+    pub fn set_curve_status<'info>(
+        ctx: CpiContext<'_, '_, '_, 'info, accounts::SetCurveStatus<'info>>,
+        status: CurveStatus,
+    ) -> anchor_lang::Result<()> {
+        let ix = crate::instruction::SetCurveStatus {
+            #[cfg(feature = "program-id-manually")]
+            program_id: *ctx.program.key,
+            curve: *ctx.accounts.curve.key,
+            owner: *ctx.accounts.owner.key,
+            status: status as u8,
+        }
+        .into_instruction();
+
+        invoke(ctx, ix)
+    }
+
+    pub fn evaluate_curve<'info>(
+        ctx: CpiContext<'_, '_, '_, 'info, accounts::EvaluateCurve<'info>>,
+        x: i64,
+    ) -> anchor_lang::Result<()> {
+        let ix = crate::instruction::EvaluateCurve {
+            #[cfg(feature = "program-id-manually")]
+            program_id: *ctx.program.key,
+            curve: *ctx.accounts.curve.key,
+            result: *ctx.accounts.result.key,
+            authority: *ctx.accounts.authority.key,
+            x,
+        }
+        .into_instruction();
+
+        invoke(ctx, ix)
+    }
+
+    pub fn set_alter_delay<'info>(
+        ctx: CpiContext<'_, '_, '_, 'info, accounts::SetAlterDelay<'info>>,
+        slots: u64,
+    ) -> anchor_lang::Result<()> {
+        let ix = crate::instruction::SetAlterDelay {
+            #[cfg(feature = "program-id-manually")]
+            program_id: *ctx.program.key,
+            curve: *ctx.accounts.curve.key,
+            owner: *ctx.accounts.owner.key,
+            slots,
+        }
+        .into_instruction();
+
+        invoke(ctx, ix)
+    }
+
+    pub fn propose_alter<'info>(
+        ctx: CpiContext<'_, '_, '_, 'info, accounts::ProposeAlter<'info>>,
+        params: CurveParams,
+    ) -> anchor_lang::Result<()> {
+        let ix = crate::instruction::ProposeAlter {
+            #[cfg(feature = "program-id-manually")]
+            program_id: *ctx.program.key,
+            curve: *ctx.accounts.curve.key,
+            owner: *ctx.accounts.owner.key,
+            pending_alter: *ctx.accounts.pending_alter.key,
+            params,
+        }
+        .into_instruction();
+
+        invoke(ctx, ix)
+    }
+
+    pub fn apply_alter<'info>(
+        ctx: CpiContext<'_, '_, '_, 'info, accounts::ApplyAlter<'info>>,
+    ) -> anchor_lang::Result<()> {
+        let ix = crate::instruction::ApplyAlter {
+            #[cfg(feature = "program-id-manually")]
+            program_id: *ctx.program.key,
+            curve: *ctx.accounts.curve.key,
+            owner: *ctx.accounts.owner.key,
+            pending_alter: *ctx.accounts.pending_alter.key,
+        }
+        .into_instruction();
+
+        invoke(ctx, ix)
+    }
+
+    pub fn cancel_alter<'info>(
+        ctx: CpiContext<'_, '_, '_, 'info, accounts::CancelAlter<'info>>,
+    ) -> anchor_lang::Result<()> {
+        let ix = crate::instruction::CancelAlter {
+            #[cfg(feature = "program-id-manually")]
+            program_id: *ctx.program.key,
+            curve: *ctx.accounts.curve.key,
+            owner: *ctx.accounts.owner.key,
+            pending_alter: *ctx.accounts.pending_alter.key,
+        }
+        .into_instruction();
+
+        invoke(ctx, ix)
+    }
+
+    pub fn add_delegate<'info>(
+        ctx: CpiContext<'_, '_, '_, 'info, accounts::AddDelegate<'info>>,
+        delegate: Pubkey,
+    ) -> anchor_lang::Result<()> {
+        let ix = crate::instruction::AddDelegate {
+            #[cfg(feature = "program-id-manually")]
+            program_id: *ctx.program.key,
+            curve: *ctx.accounts.curve.key,
+            owner: *ctx.accounts.owner.key,
+            delegate,
+        }
+        .into_instruction();
+
+        invoke(ctx, ix)
+    }
+
+    pub fn remove_delegate<'info>(
+        ctx: CpiContext<'_, '_, '_, 'info, accounts::RemoveDelegate<'info>>,
+        delegate: Pubkey,
+    ) -> anchor_lang::Result<()> {
+        let ix = crate::instruction::RemoveDelegate {
+            #[cfg(feature = "program-id-manually")]
+            program_id: *ctx.program.key,
+            curve: *ctx.accounts.curve.key,
+            owner: *ctx.accounts.owner.key,
+            delegate,
+        }
+        .into_instruction();
+
+        invoke(ctx, ix)
+    }
+
+    pub fn init_config<'info>(
+        ctx: CpiContext<'_, '_, '_, 'info, accounts::InitConfig<'info>>,
+        creators: Vec<Pubkey>,
+    ) -> anchor_lang::Result<()> {
+        let ix = crate::instruction::InitConfig {
+            #[cfg(feature = "program-id-manually")]
+            program_id: *ctx.program.key,
+            config: *ctx.accounts.config.key,
+            admin: *ctx.accounts.admin.key,
+            program_data: *ctx.accounts.program_data.key,
+            creators,
+        }
+        .into_instruction();
+
+        invoke(ctx, ix)
+    }
+
+    pub fn set_config<'info>(
+        ctx: CpiContext<'_, '_, '_, 'info, accounts::SetConfig<'info>>,
+        new_admin: Pubkey,
+        creators: Vec<Pubkey>,
+    ) -> anchor_lang::Result<()> {
+        let ix = crate::instruction::SetConfig {
+            #[cfg(feature = "program-id-manually")]
+            program_id: *ctx.program.key,
+            config: *ctx.accounts.config.key,
+            admin: *ctx.accounts.admin.key,
+            new_admin,
+            creators,
+        }
+        .into_instruction();
+
+        invoke(ctx, ix)
+    }
+
+    pub fn set_creation_frozen<'info>(
+        ctx: CpiContext<'_, '_, '_, 'info, accounts::SetCreationFrozen<'info>>,
+        frozen: bool,
+    ) -> anchor_lang::Result<()> {
+        let ix = crate::instruction::SetCreationFrozen {
+            #[cfg(feature = "program-id-manually")]
+            program_id: *ctx.program.key,
+            config: *ctx.accounts.config.key,
+            admin: *ctx.accounts.admin.key,
+            frozen,
+        }
+        .into_instruction();
+
+        invoke(ctx, ix)
+    }
+
+    pub fn mark_for_deletion<'info>(
+        ctx: CpiContext<'_, '_, '_, 'info, accounts::MarkForDeletion<'info>>,
+    ) -> anchor_lang::Result<()> {
+        let ix = crate::instruction::MarkForDeletion {
+            #[cfg(feature = "program-id-manually")]
+            program_id: *ctx.program.key,
+            curve: *ctx.accounts.curve.key,
+            owner: *ctx.accounts.owner.key,
+        }
+        .into_instruction();
+
+        invoke(ctx, ix)
+    }
+
+    pub fn cancel_deletion<'info>(
+        ctx: CpiContext<'_, '_, '_, 'info, accounts::CancelDeletion<'info>>,
+    ) -> anchor_lang::Result<()> {
+        let ix = crate::instruction::CancelDeletion {
+            #[cfg(feature = "program-id-manually")]
+            program_id: *ctx.program.key,
+            curve: *ctx.accounts.curve.key,
+            owner: *ctx.accounts.owner.key,
+        }
+        .into_instruction();
+
+        invoke(ctx, ix)
+    }
+
+    pub fn patch_y<'info>(
+        ctx: CpiContext<'_, '_, '_, 'info, accounts::PatchY<'info>>,
+        start_index: u8,
+        values: Vec<CurveY>,
+    ) -> anchor_lang::Result<()> {
+        let ix = crate::instruction::PatchY {
+            #[cfg(feature = "program-id-manually")]
+            program_id: *ctx.program.key,
+            curve: *ctx.accounts.curve.key,
+            owner: *ctx.accounts.owner.key,
+            start_index,
+            values,
+        }
+        .into_instruction();
+
+        invoke(ctx, ix)
+    }
+
+    pub fn propose_owner<'info>(
+        ctx: CpiContext<'_, '_, '_, 'info, accounts::ProposeOwner<'info>>,
+        new_owner: Pubkey,
+    ) -> anchor_lang::Result<()> {
+        let ix = crate::instruction::ProposeOwner {
+            #[cfg(feature = "program-id-manually")]
+            program_id: *ctx.program.key,
+            curve: *ctx.accounts.curve.key,
+            owner: *ctx.accounts.owner.key,
+            new_owner,
+        }
+        .into_instruction();
+
+        invoke(ctx, ix)
+    }
+
+    pub fn accept_owner<'info>(
+        ctx: CpiContext<'_, '_, '_, 'info, accounts::AcceptOwner<'info>>,
+    ) -> anchor_lang::Result<()> {
+        let ix = crate::instruction::AcceptOwner {
+            #[cfg(feature = "program-id-manually")]
+            program_id: *ctx.program.key,
+            curve: *ctx.accounts.curve.key,
+            new_owner: *ctx.accounts.new_owner.key,
+            owner: *ctx.accounts.owner.key,
+            name_link: *ctx.accounts.name_link.key,
+            new_name_link: *ctx.accounts.new_name_link.key,
+        }
+        .into_instruction();
+
+        invoke(ctx, ix)
+    }
+
+    pub fn create_curve_pda<'info>(
+        ctx: CpiContext<'_, '_, '_, 'info, accounts::CreateCurvePda<'info>>,
+        params: CurveParams,
+    ) -> anchor_lang::Result<()> {
+        let ix = crate::instruction::CreateCurvePda {
+            #[cfg(feature = "program-id-manually")]
+            program_id: *ctx.program.key,
+            curve: *ctx.accounts.curve.key,
+            owner: *ctx.accounts.owner.key,
+            config: *ctx.accounts.config.key,
+            name_link: *ctx.accounts.name_link.key,
+            params,
+        }
+        .into_instruction();
+
+        invoke(ctx, ix)
+    }
+
+    pub fn create_reference_curve<'info>(
+        ctx: CpiContext<'_, '_, '_, 'info, accounts::CreateReferenceCurve<'info>>,
+        kind: u8,
+        params: CurveParams,
+    ) -> anchor_lang::Result<()> {
+        let ix = crate::instruction::CreateReferenceCurve {
+            #[cfg(feature = "program-id-manually")]
+            program_id: *ctx.program.key,
+            curve: *ctx.accounts.curve.key,
+            admin: *ctx.accounts.admin.key,
+            config: *ctx.accounts.config.key,
+            asset_mint: *ctx.accounts.asset_mint.key,
+            name_link: *ctx.accounts.name_link.key,
+            kind,
+            params,
+        }
+        .into_instruction();
+
+        invoke(ctx, ix)
+    }
+
+    pub fn freeze_curve<'info>(
+        ctx: CpiContext<'_, '_, '_, 'info, accounts::FreezeCurve<'info>>,
+    ) -> anchor_lang::Result<()> {
+        let ix = crate::instruction::FreezeCurve {
+            #[cfg(feature = "program-id-manually")]
+            program_id: *ctx.program.key,
+            curve: *ctx.accounts.curve.key,
+            owner: *ctx.accounts.owner.key,
+        }
+        .into_instruction();
+
+        invoke(ctx, ix)
+    }
+
+    pub fn resize_curve<'info>(
+        ctx: CpiContext<'_, '_, '_, 'info, accounts::ResizeCurve<'info>>,
+        y_capacity: u16,
+    ) -> anchor_lang::Result<()> {
+        let ix = crate::instruction::ResizeCurve {
+            #[cfg(feature = "program-id-manually")]
+            program_id: *ctx.program.key,
+            curve: *ctx.accounts.curve.key,
+            owner: *ctx.accounts.owner.key,
+            y_capacity,
+        }
+        .into_instruction();
+
+        invoke(ctx, ix)
+    }
+
+    pub fn set_curve_tail<'info>(
+        ctx: CpiContext<'_, '_, '_, 'info, accounts::SetCurveTail<'info>>,
+        start: u16,
+        y: Vec<CurveY>,
+    ) -> anchor_lang::Result<()> {
+        let ix = crate::instruction::SetCurveTail {
+            #[cfg(feature = "program-id-manually")]
+            program_id: *ctx.program.key,
+            curve: *ctx.accounts.curve.key,
+            owner: *ctx.accounts.owner.key,
+            start,
+            y,
+        }
+        .into_instruction();
+
+        invoke(ctx, ix)
+    }
+
+    pub fn create_grid_curve<'info>(
+        ctx: CpiContext<'_, '_, '_, 'info, accounts::CreateGridCurve<'info>>,
+        params: GridCurveParams,
+    ) -> anchor_lang::Result<()> {
+        let ix = crate::instruction::CreateGridCurve {
+            #[cfg(feature = "program-id-manually")]
+            program_id: *ctx.program.key,
+            curve: *ctx.accounts.curve.key,
+            owner: *ctx.accounts.owner.key,
+            config: *ctx.accounts.config.key,
+            name_link: *ctx.accounts.name_link.key,
+            params,
+        }
+        .into_instruction();
+
+        invoke(ctx, ix)
+    }
+
+    pub fn alter_grid_curve<'info>(
+        ctx: CpiContext<'_, '_, '_, 'info, accounts::AlterGridCurve<'info>>,
+        params: GridCurveParams,
+    ) -> anchor_lang::Result<()> {
+        let ix = crate::instruction::AlterGridCurve {
+            #[cfg(feature = "program-id-manually")]
+            program_id: *ctx.program.key,
+            curve: *ctx.accounts.curve.key,
+            owner: *ctx.accounts.owner.key,
+            params,
+        }
+        .into_instruction();
+
+        invoke(ctx, ix)
+    }
+
+    /// Other signing owners of co-owned curve are passed as remaining accounts
+    pub fn set_curve_owners<'info>(
+        ctx: CpiContext<'_, '_, '_, 'info, accounts::SetCurveOwners<'info>>,
+        threshold: u8,
+        owners: Vec<Pubkey>,
+    ) -> anchor_lang::Result<()> {
+        let mut ix = crate::instruction::SetCurveOwners {
+            #[cfg(feature = "program-id-manually")]
+            program_id: *ctx.program.key,
+            curve: *ctx.accounts.curve.key,
+            owner: *ctx.accounts.owner.key,
+            curve_owners: *ctx.accounts.curve_owners.key,
+            threshold,
+            owners,
+        }
+        .into_instruction();
+        ix.accounts.extend(remaining_metas(&ctx.remaining_accounts));
+
+        invoke(ctx, ix)
+    }
+
+    pub fn set_curve_extension<'info>(
+        ctx: CpiContext<'_, '_, '_, 'info, accounts::SetCurveExtension<'info>>,
+        extension_type: u16,
+        value: Vec<u8>,
+    ) -> anchor_lang::Result<()> {
+        let ix = crate::instruction::SetCurveExtension {
+            #[cfg(feature = "program-id-manually")]
+            program_id: *ctx.program.key,
+            curve: *ctx.accounts.curve.key,
+            owner: *ctx.accounts.owner.key,
+            extension_type,
+            value,
+        }
+        .into_instruction();
+
+        invoke(ctx, ix)
+    }
+
+    fn remaining_metas(accounts: &[AccountInfo<'_>]) -> Vec<anchor_lang::prelude::AccountMeta> {
+        accounts
+            .iter()
+            .map(|account| anchor_lang::prelude::AccountMeta {
+                pubkey: *account.key,
+                is_signer: account.is_signer,
+                is_writable: account.is_writable,
+            })
+            .collect()
+    }
+
+    fn invoke<'info, T>(
+        ctx: CpiContext<'_, '_, '_, 'info, T>,
+        ix: anchor_lang::solana_program::instruction::Instruction,
+    ) -> anchor_lang::Result<()>
+    where
+        T: anchor_lang::ToAccountMetas + anchor_lang::ToAccountInfos<'info>,
+    {
+        let account_infos: Vec<AccountInfo<'info>> =
+            anchor_lang::ToAccountInfos::to_account_infos(&ctx);
+
+        invoke_signed(&ix, &account_infos, ctx.signer_seeds).map_err(Into::into)
+    }
+
+    pub mod accounts {
+        use anchor_lang::prelude::{AccountInfo, AccountMeta};
+        use anchor_lang::{ToAccountInfos, ToAccountMetas};
+
+        macro_rules! cpi_accounts {
+            ($name:ident { $($field:ident: $writable:literal, $signer:literal),* $(,)? }) => {
+                #[derive(Clone)]
+                pub struct $name<'info> {
+                    $(pub $field: AccountInfo<'info>,)*
+                }
+
+                impl ToAccountMetas for $name<'_> {
+                    fn to_account_metas(&self, is_signer: Option<bool>) -> Vec<AccountMeta> {
+                        vec![$(
+                            if $writable {
+                                AccountMeta::new(*self.$field.key, is_signer.unwrap_or($signer))
+                            } else {
+                                AccountMeta::new_readonly(
+                                    *self.$field.key,
+                                    is_signer.unwrap_or($signer),
+                                )
+                            },
+                        )*]
+                    }
+                }
+
+                impl<'info> ToAccountInfos<'info> for $name<'info> {
+                    fn to_account_infos(&self) -> Vec<AccountInfo<'info>> {
+                        vec![$(self.$field.clone(),)*]
+                    }
+                }
+            };
+        }
+
+        cpi_accounts!(CreateCurve {
+            curve: true, true,
+            owner: true, true,
+            config: false, false,
+            name_link: true, false,
+            system_program: false, false,
+        });
+
+        cpi_accounts!(AlterCurve {
+            curve: true, false,
+            owner: false, true,
+        });
+
+        cpi_accounts!(UpdateCurveMetadata {
+            curve: true, false,
+            owner: true, true,
+            name_link: true, false,
+            new_name_link: true, false,
+            system_program: false, false,
+        });
+
+        cpi_accounts!(UpdateCurveData {
+            curve: true, false,
+            owner: false, true,
+        });
+
+        cpi_accounts!(ReserveCurve {
+            curve: true, true,
+            owner: true, true,
+            config: false, false,
+            name_link: true, false,
+            system_program: false, false,
+        });
+
+        cpi_accounts!(DeleteCurve {
+            curve: true, false,
+            owner: true, true,
+            name_link: true, false,
+        });
+
+        cpi_accounts!(TopUpRent {
+            curve: true, false,
+            payer: true, true,
+            system_program: false, false,
+        });
+
+        cpi_accounts!(SetCurveAsset {
+            curve: true, false,
+            owner: true, true,
+            asset_mint: false, false,
+            asset_link: true, false,
+            system_program: false, false,
+        });
+
+        cpi_accounts!(ClearCurveAsset {
+            curve: true, false,
+            owner: true, true,
+            asset_link: true, false,
+        });
+
+        cpi_accounts!(MigrateCurve {
+            curve: true, false,
+            payer: true, true,
+            system_program: false, false,
+        });
+
+        cpi_accounts!(SetCurveSeries {
+            curve: true, false,
+            owner: true, true,
+            curve_series: true, false,
+            system_program: false, false,
+        });
+
+        cpi_accounts!(DeleteCurveSeries {
+            curve: true, false,
+            owner: true, true,
+            curve_series: true, false,
+        });
+
+        cpi_accounts!(SetCurveConfidence {
+            curve: true, false,
+            owner: true, true,
+            curve_confidence: true, false,
+            system_program: false, false,
+        });
+
+        cpi_accounts!(DeleteCurveConfidence {
+            curve: true, false,
+            owner: true, true,
+            curve_confidence: true, false,
+        });
+
+        cpi_accounts!(CreateWideCurve {
+            curve: true, true,
+            owner: true, true,
+            config: false, false,
+            name_link: true, false,
+            system_program: false, false,
+        });
+
+        cpi_accounts!(AlterWideCurve {
+            curve: true, false,
+            owner: false, true,
+        });
+
+        cpi_accounts!(SetCurveXSign {
+            curve: true, false,
+            owner: false, true,
+        });
+
+        cpi_accounts!(SetCurveYSign {
+            curve: true, false,
+            owner: false, true,
+        });
+
+        cpi_accounts!(SetCurveYTransform {
+            curve: true, false,
+            owner: false, true,
+        });
+
+        cpi_accounts!(SetCurveExpiry {
+            curve: true, false,
+            owner: false, true,
+        });
+
+        cpi_accounts!(BatchAlter {
+            owner: false, true,
+        });
+
+        cpi_accounts!(ProposeOwner {
+            curve: true, false,
+            owner: false, true,
+        });
+
+        cpi_accounts!(AcceptOwner {
+            curve: true, false,
+            new_owner: true, true,
+            owner: true, false,
+            name_link: true, false,
+            new_name_link: true, false,
+            system_program: false, false,
+        });
+
+        cpi_accounts!(FreezeCurve {
+            curve: true, false,
+            owner: false, true,
+        });
+
+        cpi_accounts!(ResizeCurve {
+            curve: true, false,
+            owner: true, true,
+            system_program: false, false,
+        });
+
+        cpi_accounts!(SetCurveTail {
+            curve: true, false,
+            owner: false, true,
+        });
+
+        cpi_accounts!(CreateGridCurve {
+            curve: true, true,
+            owner: true, true,
+            config: false, false,
+            name_link: true, false,
+            system_program: false, false,
+        });
+
+        cpi_accounts!(AlterGridCurve {
+            curve: true, false,
+            owner: false, true,
+        });
+
+        cpi_accounts!(SetCurveStatus {
+            curve: true, false,
+            owner: false, true,
+        });
+
+        cpi_accounts!(PatchY {
+            curve: true, false,
+            owner: false, true,
+        });
+
+        cpi_accounts!(MarkForDeletion {
+            curve: true, false,
+            owner: false, true,
+        });
+
+        cpi_accounts!(CancelDeletion {
+            curve: true, false,
+            owner: false, true,
+        });
+
+        cpi_accounts!(EvaluateCurve {
+            curve: false, false,
+            result: true, false,
+            authority: false, true,
+        });
+
+        cpi_accounts!(SetAlterDelay {
+            curve: true, false,
+            owner: false, true,
+        });
+
+        cpi_accounts!(ProposeAlter {
+            curve: true, false,
+            owner: true, true,
+            pending_alter: true, false,
+            system_program: false, false,
+        });
+
+        cpi_accounts!(ApplyAlter {
+            curve: true, false,
+            owner: true, true,
+            pending_alter: true, false,
+        });
+
+        cpi_accounts!(CancelAlter {
+            curve: true, false,
+            owner: true, true,
+            pending_alter: true, false,
+        });
+
+        cpi_accounts!(AddDelegate {
+            curve: true, false,
+            owner: false, true,
+        });
+
+        cpi_accounts!(RemoveDelegate {
+            curve: true, false,
+            owner: false, true,
+        });
+
+        cpi_accounts!(SetCurveOwners {
+            curve: true, false,
+            owner: true, true,
+            curve_owners: true, false,
+            system_program: false, false,
+        });
+
+        cpi_accounts!(InitConfig {
+            config: true, false,
+            admin: true, true,
+            program_data: false, false,
+            system_program: false, false,
+        });
+
+        cpi_accounts!(SetConfig {
+            config: true, false,
+            admin: false, true,
+        });
+
+        cpi_accounts!(CreateCurvePda {
+            curve: true, false,
+            owner: true, true,
+            config: false, false,
+            name_link: true, false,
+            system_program: false, false,
+        });
+
+        cpi_accounts!(SetCurveExtension {
+            curve: true, false,
+            owner: true, true,
+            system_program: false, false,
+        });
+
+        cpi_accounts!(CreateReferenceCurve {
+            curve: true, false,
+            admin: true, true,
+            config: false, false,
+            asset_mint: false, false,
+            name_link: true, false,
+            system_program: false, false,
+        });
+
+        cpi_accounts!(SetCreationFrozen {
+            config: true, false,
+            admin: false, true,
+        });
+    }
+}
+
+/// Returns `Curve` from account info checking owner and discriminator, for consumers who don't
+/// use `Account<'info, Curve>`.
+pub fn load_curve(account: &AccountInfo<'_>) -> anchor_lang::Result<Curve> {
+    if account.owner != &crate::ID {
+        return Err(ErrorCode::AccountOwnedByWrongProgram.into());
+    }
+
+    let data = account.try_borrow_data()?;
+    Curve::try_deserialize(&mut &data[..])
+}
@@ -0,0 +1,86 @@
+//! Test-only instructions for integration environments, built with `devnet-utils` feature only.
+//! They skip ownership checks to construct edge-case states (e.g. curves owned by keys nobody
+//! holds, accounts of unsupported versions) without custom forks of the program, thus must never
+//! be deployed to mainnet, see `mainnet` feature.
+
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::account_info::AccountInfo;
+use solana_program::instruction::{AccountMeta, Instruction};
+use solana_program::msg;
+use solana_program::pubkey::Pubkey;
+use texture_common::error::{InvalidAccount, MissingSignature};
+use texture_common::utils::next_account_info;
+
+use crate::error::{AccountContext, CurvyError};
+use crate::processor::{check_no_accounts_left, DEVNET_TAG};
+use crate::state::curve::Curve;
+use crate::CurvyResult;
+
+/// Instruction data follows [DEVNET_TAG].
+///
+/// Accounts of every instruction:
+/// 0. `[writable]` Program account to update.
+/// 1. `[signer]` Any authority, not checked against the account.
+#[derive(BorshSerialize, BorshDeserialize, Debug)]
+pub enum DevnetInstruction {
+    /// Replace owner of Curve without signature of the current one
+    ForceSetOwner { owner: Pubkey },
+    /// Overwrite account data at `offset`, e.g. version or flags
+    ForceWriteData { offset: u32, data: Vec<u8> },
+}
+
+impl DevnetInstruction {
+    pub fn into_instruction(self, account: Pubkey, authority: Pubkey) -> Instruction {
+        let mut data = vec![DEVNET_TAG];
+        borsh::to_writer(&mut data, &self).expect("serialization into vec");
+
+        Instruction {
+            program_id: crate::ID,
+            accounts: vec![
+                AccountMeta::new(account, false),
+                AccountMeta::new_readonly(authority, true),
+            ],
+            data,
+        }
+    }
+}
+
+/// Processes instruction `input` following [DEVNET_TAG]
+pub fn process(program_id: &Pubkey, accounts: &[AccountInfo<'_>], input: &[u8]) -> CurvyResult<()> {
+    let iter = &mut accounts.iter();
+    let account = next_account_info(iter)?;
+    let authority = next_account_info(iter)?;
+    check_no_accounts_left(iter)?;
+
+    if !authority.is_signer {
+        return Err(MissingSignature(*authority.key).into());
+    }
+    if account.owner != program_id || !account.is_writable {
+        msg!("account is not writable or not owned by the program");
+        return Err(InvalidAccount(*account.key).into());
+    }
+
+    match DevnetInstruction::try_from_slice(input)? {
+        DevnetInstruction::ForceSetOwner { owner } => {
+            msg!("force_set_owner devnet ix");
+
+            let mut data = account.data.borrow_mut();
+            Curve::load_mut(&mut data).account(account.key)?.owner = owner;
+        }
+        DevnetInstruction::ForceWriteData {
+            offset,
+            data: bytes,
+        } => {
+            msg!("force_write_data devnet ix");
+
+            let mut data = account.data.borrow_mut();
+            let start = offset as usize;
+            data.get_mut(start..start.saturating_add(bytes.len()))
+                .ok_or(CurvyError::InvalidParams)
+                .account(account.key)?
+                .copy_from_slice(&bytes);
+        }
+    }
+
+    Ok(())
+}
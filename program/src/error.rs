@@ -94,41 +94,205 @@ pub enum CurvyError {
     #[error("curve parameters provided are not valid")]
     InvalidParams,
 
+    #[error(
+        "wrong account discriminator `{}`, expected `{}`",
+        String::from_utf8_lossy(.found),
+        String::from_utf8_lossy(.expected)
+    )]
+    WrongDiscriminator { expected: [u8; 8], found: [u8; 8] },
+
+    #[error("unsupported account version {found}, max supported version is {max_supported}")]
+    UnsupportedVersion { found: u8, max_supported: u8 },
+
+    #[error("account balance {lamports} is below rent exempt minimum {required}")]
+    NotRentExempt { lamports: u64, required: u64 },
+
+    #[error("curve is frozen and can not be changed")]
+    CurveFrozen,
+
+    #[error("curve status can not change from {from} to {to}")]
+    InvalidStatusTransition { from: u8, to: u8 },
+
+    #[error("curve is not active, its status is {status}")]
+    CurveNotActive { status: u8 },
+
+    #[error("curve is marked for deletion and can be deleted from slot {from}")]
+    DeletionGracePeriod { from: u64 },
+
+    #[error("curve alterations are timelocked for {delay} slots, propose them by ProposeAlter")]
+    AlterTimelocked { delay: u64 },
+
+    #[error("proposed alteration can be applied from slot {from}")]
+    AlterNotReady { from: u64 },
+
+    #[error("{count} unexpected accounts after the instruction accounts")]
+    UnexpectedAccounts { count: usize },
+
+    #[error("{creator} is not allowed to create curves")]
+    CreatorNotAllowed { creator: Pubkey },
+
+    #[error("curve is co-owned, {threshold} of its owners must sign")]
+    CoOwnersRequired { threshold: u8 },
+
+    #[error("curve expired at {valid_until}, it must be altered again")]
+    CurveExpired { valid_until: i64 },
+
+    #[error("curve is paused and must not be consumed until it is resumed")]
+    CurveInactive,
+
+    #[error("curve creation is frozen by the config admin")]
+    CreationFrozen,
+
     // NaN
     #[error("system program error: {0}")]
     SystemProgram(#[from] RemoteError<SystemError>),
+
+    /// Error caused by specific account. Reported with the code of the wrapped error.
+    #[error("account {account}: {error}")]
+    Account {
+        account: Pubkey,
+        error: Box<CurvyError>,
+    },
+}
+
+/// Attaches offending account address to errors, see [CurvyError::Account]
+pub trait AccountContext<T> {
+    fn account(self, account: &Pubkey) -> Result<T, CurvyError>;
+}
+
+impl<T, E: Into<CurvyError>> AccountContext<T> for Result<T, E> {
+    fn account(self, account: &Pubkey) -> Result<T, CurvyError> {
+        self.map_err(|error| match error.into() {
+            // keep the innermost account, it is the most specific one
+            error @ CurvyError::Account { .. } => error,
+            error => CurvyError::Account {
+                account: *account,
+                error: Box::new(error),
+            },
+        })
+    }
 }
 
 texture_common::from_account_parse_error!(CurvyError);
 
+pub use curvy_spec::{ErrorCode, ERROR_CODES};
+
+/// Generates custom program error codes of [CurvyError] variants and checks at compile time
+/// that [ERROR_CODES] of `curvy-spec` lists the same codes and names in the same order, so the
+/// table can not drift from the codes actually returned
+macro_rules! error_codes {
+    ($($code:literal => $variant:ident $(($tuple:tt))? $({$named:tt})?,)*) => {
+        impl CurvyError {
+            /// Custom program error code of own variants, `None` for errors reported with the
+            /// code of the wrapped error. Exhaustive, so new variants must get a code.
+            fn own_code(&self) -> Option<u32> {
+                match self {
+                    $(CurvyError::$variant $(($tuple))? $({$named})? => Some($code),)*
+                    CurvyError::SystemProgram(..) | CurvyError::Account { .. } => None,
+                }
+            }
+        }
+
+        const _: () = {
+            let mut idx = 0;
+            $(
+                assert!(
+                    ERROR_CODES[idx].code == $code
+                        && str_eq(ERROR_CODES[idx].name, stringify!($variant)),
+                    concat!("ERROR_CODES differ at ", stringify!($variant))
+                );
+                idx += 1;
+            )*
+            assert!(idx == ERROR_CODES.len(), "ERROR_CODES has extra entries");
+        };
+    };
+}
+
+const fn str_eq(a: &str, b: &str) -> bool {
+    let (a, b) = (a.as_bytes(), b.as_bytes());
+    if a.len() != b.len() {
+        return false;
+    }
+
+    let mut idx = 0;
+    while idx < a.len() {
+        if a[idx] != b[idx] {
+            return false;
+        }
+        idx += 1;
+    }
+
+    true
+}
+
+error_codes! {
+    3 => MathError(..),
+    4 => Borsh(..),
+    5 => Serialize(..),
+    6 => PodAccount(..),
+    6 => PodAccountExt(..),
+    8 => InvalidKey(..),
+    9 => InvalidAccount(..),
+    10 => NotEnoughAccountKeys(..),
+    11 => MissingSignature(..),
+    12 => Unimplemented,
+    13 => UninitializedAccount(..),
+    14 => AddressCreation(..),
+    15 => AccountUnpackError(..),
+    23 => Internal(..),
+    24 => InvalidAccountData,
+    25 => OperationCanNotBePerformed,
+    27 => InvalidRealloc,
+    28 => OwnerMismatch,
+    29 => InvalidParams,
+    30 => WrongDiscriminator { .. },
+    31 => UnsupportedVersion { .. },
+    32 => NotRentExempt { .. },
+    33 => CurveFrozen,
+    34 => InvalidStatusTransition { .. },
+    35 => CurveNotActive { .. },
+    36 => DeletionGracePeriod { .. },
+    37 => AlterTimelocked { .. },
+    38 => AlterNotReady { .. },
+    39 => UnexpectedAccounts { .. },
+    40 => CreatorNotAllowed { .. },
+    41 => CoOwnersRequired { .. },
+    42 => CurveExpired { .. },
+    43 => CurveInactive,
+    44 => CreationFrozen,
+}
+
 impl From<CurvyError> for ProgramError {
     fn from(error: CurvyError) -> Self {
-        match error {
-            CurvyError::MathError(..) => Custom(3),
-            CurvyError::Borsh(..) => Custom(4),
-            CurvyError::Serialize(..) => Custom(5),
-            CurvyError::PodAccount(..) | CurvyError::PodAccountExt(..) => Custom(6),
-            CurvyError::InvalidKey { .. } => Custom(8),
-            CurvyError::InvalidAccount(..) => Custom(9),
-            CurvyError::NotEnoughAccountKeys(..) => Custom(10),
-            CurvyError::MissingSignature(..) => Custom(11),
-            CurvyError::Unimplemented => Custom(12),
-            CurvyError::UninitializedAccount(..) => Custom(13),
-            CurvyError::AddressCreation(..) => Custom(14),
-            CurvyError::AccountUnpackError(..) => Custom(15),
-            CurvyError::Internal(..) => Custom(23),
-            CurvyError::InvalidAccountData => Custom(24),
-            CurvyError::OperationCanNotBePerformed => Custom(25),
-            CurvyError::InvalidRealloc => Custom(27),
-            CurvyError::OwnerMismatch => Custom(28),
-            CurvyError::InvalidParams => Custom(29),
+        if let Some(code) = error.own_code() {
+            return Custom(code);
+        }
 
+        match error {
             CurvyError::SystemProgram(RemoteError::Unrecognized(err)) => err,
             CurvyError::SystemProgram(RemoteError::Recognized(err)) => Custom(err as u32),
+
+            CurvyError::Account { error, .. } => (*error).into(),
+
+            // all other variants have own codes
+            _ => unreachable!("{error}"),
         }
     }
 }
 
+impl CurvyError {
+    /// Name of the variant reported with given custom program error code. Codes below 3 are
+    /// shared with System program errors returned from CPI and are not resolved.
+    pub fn name_by_code(code: u32) -> Option<&'static str> {
+        Self::describe_code(code).map(|error| error.name)
+    }
+
+    /// Entry of [ERROR_CODES] with given code
+    pub fn describe_code(code: u32) -> Option<&'static ErrorCode> {
+        curvy_spec::describe_code(code)
+    }
+}
+
 texture_common::convert_remote_err!(
     system_err,
     texture_common::remote::system::SystemError,
@@ -0,0 +1,142 @@
+//! Curve evaluation for consumer programs reading a [Curve] account passed to them. Only integer
+//! arithmetic, no `Decimal` and no allocations, so it is cheap in compute units.
+//!
+//! Supported ranges, any curve within them is evaluated without overflow:
+//! - `x` is any `u64`, `x0` and `x_step` any `u32`, `y` samples any `u32`, decimals any `u8`
+//! - [calc_y_u128] scales results up by at most [MAX_UPSCALE_DECIMALS] decimals, so the result
+//!   is below `2^32 * 10^19` and the interpolation product below `2^128`
+
+use solana_program::msg;
+
+use crate::error::CurvyError;
+use crate::state::curve::Curve;
+use crate::CurvyResult;
+
+/// Max difference between requested and curve `y` decimals of [calc_y_u128]
+pub const MAX_UPSCALE_DECIMALS: u8 = 19;
+
+/// `y` of `curve` in `x`, both in curve units (scaled by decimals). Linear interpolation between
+/// neighbouring samples of the `y` table rounded down, same as [Curve::evaluate].
+///
+/// Samples after the table (see [Curve::tail_y]) are not visible through `&Curve`, `x` past
+/// the table is out of range then. Curves with signed `y` are rejected, use [Curve::evaluate]
+/// for them, as well as curves storing transformed `y` (see [Curve::check_linear_y]). Curve
+/// status is not checked, see [Curve::check_consumable].
+pub fn calc_y_fixed(curve: &Curve, x: u64) -> CurvyResult<u64> {
+    if curve.y_signed != 0 {
+        msg!("curve has signed y");
+        return Err(CurvyError::InvalidParams);
+    }
+    curve.check_linear_y()?;
+
+    let Segment {
+        y_left,
+        y_right,
+        rem,
+        x_step,
+    } = segment(curve, x)?;
+
+    // samples and `rem` < `x_step` fit in 32 bits, so their products fit in 64
+    let y = if y_right >= y_left {
+        y_left + (y_right - y_left) * rem / x_step
+    } else {
+        y_left - ((y_left - y_right) * rem).div_ceil(x_step)
+    };
+
+    Ok(y)
+}
+
+/// Same as [calc_y_fixed] with the result scaled to `decimals` instead of the curve
+/// `y_decimals`, rounded down. Interpolates in `u128`, so it costs more compute units.
+/// `decimals` may exceed `y_decimals` by at most [MAX_UPSCALE_DECIMALS].
+pub fn calc_y_u128(curve: &Curve, x: u64, decimals: u8) -> CurvyResult<u128> {
+    if curve.y_signed != 0 {
+        msg!("curve has signed y");
+        return Err(CurvyError::InvalidParams);
+    }
+    curve.check_linear_y()?;
+    if decimals > curve.y_decimals.saturating_add(MAX_UPSCALE_DECIMALS) {
+        msg!(
+            "{} decimals exceed curve y decimals {} by more than {}",
+            decimals,
+            curve.y_decimals,
+            MAX_UPSCALE_DECIMALS
+        );
+        return Err(CurvyError::InvalidParams);
+    }
+
+    let Segment {
+        y_left,
+        y_right,
+        rem,
+        x_step,
+    } = segment(curve, x)?;
+
+    // scaled down results are interpolated at the curve decimals and divided afterwards
+    let up = 10_u128.pow(decimals.saturating_sub(curve.y_decimals) as u32);
+    // results are below 10^39, so they are scaled down to zero by anything not fitting u128
+    let Some(down) = 10_u128.checked_pow(curve.y_decimals.saturating_sub(decimals) as u32) else {
+        return Ok(0);
+    };
+    let (y_left, y_right) = (y_left as u128 * up, y_right as u128 * up);
+    let (rem, x_step) = (rem as u128, x_step as u128);
+
+    // `|y_right - y_left| < 2^32 * 10^19` and `rem < 2^32`, the product is below 2^128
+    let y = if y_right >= y_left {
+        y_left + (y_right - y_left) * rem / x_step
+    } else {
+        y_left - ((y_left - y_right) * rem).div_ceil(x_step)
+    };
+
+    Ok(y / down)
+}
+
+/// Samples around `x` and its offset from the left one
+struct Segment {
+    y_left: u64,
+    /// Same as `y_left` when `x` is at a sample
+    y_right: u64,
+    rem: u64,
+    x_step: u64,
+}
+
+fn segment(curve: &Curve, x: u64) -> CurvyResult<Segment> {
+    let x_step = curve.x_step as u64;
+    let y_count = (curve.y_count as usize).min(curve.y.len());
+    // the grid starts at `-x0` for negative `x0`
+    let offset = if curve.x0_negative != 0 {
+        x.checked_add(curve.x0 as u64)
+    } else {
+        x.checked_sub(curve.x0 as u64)
+    };
+
+    let out_of_range = || {
+        msg!("x {} is out of curve range", x);
+        CurvyError::InvalidParams
+    };
+
+    let offset = match offset {
+        Some(offset) if x_step != 0 && y_count != 0 => offset,
+        _ => return Err(out_of_range()),
+    };
+
+    let idx = offset / x_step;
+    let rem = offset % x_step;
+    let sample = |idx: u64| {
+        usize::try_from(idx)
+            .ok()
+            .and_then(|idx| curve.y[..y_count].get(idx))
+            .map(|&y| y as u64)
+            .ok_or_else(out_of_range)
+    };
+
+    let y_left = sample(idx)?;
+    let y_right = if rem == 0 { y_left } else { sample(idx + 1)? };
+
+    Ok(Segment {
+        y_left,
+        y_right,
+        rem,
+        x_step,
+    })
+}
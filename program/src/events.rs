@@ -0,0 +1,88 @@
+//! Events of curve creation, alteration and deletion for indexers. Each event is logged with
+//! `sol_log_data` as two fields: [EVENT_DISCRIMINATOR] and borsh of [CurveEvent]. Transaction
+//! logs show them as `Program data: <base64> <base64>` lines, [CurveEvent::decode] takes the
+//! fields once base64 is decoded.
+//!
+//! Samples are identified by `y_hash`, SHA-256 of the first `y_count` little-endian `y` values
+//! of the table, see [y_hash]. Tails of curves resized past the table are not hashed.
+
+use borsh::{BorshDeserialize, BorshSerialize};
+use bytemuck::Pod;
+use solana_program::hash::hashv;
+use solana_program::log::sol_log_data;
+use solana_program::pubkey::Pubkey;
+
+use crate::state::curve::SYMBOL_MAX_SIZE;
+
+/// First field of every event logged by the program
+pub const EVENT_DISCRIMINATOR: &[u8; 8] = b"curvyevt";
+
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub struct CurveCreated {
+    pub curve: Pubkey,
+    pub owner: Pubkey,
+    pub name: [u8; SYMBOL_MAX_SIZE],
+    pub y_hash: [u8; 32],
+}
+
+/// Samples or params of the curve changed. `old_y_hash` and `new_y_hash` identify the revisions
+/// before and after, they are equal when only other params changed.
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub struct CurveAltered {
+    pub curve: Pubkey,
+    pub owner: Pubkey,
+    /// Signer of the alteration: the owner, one of co-owners or a delegate
+    pub authority: Pubkey,
+    pub name: [u8; SYMBOL_MAX_SIZE],
+    pub old_y_hash: [u8; 32],
+    pub new_y_hash: [u8; 32],
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub struct CurveDeleted {
+    pub curve: Pubkey,
+    pub owner: Pubkey,
+    pub name: [u8; SYMBOL_MAX_SIZE],
+    /// Samples at the time of deletion
+    pub y_hash: [u8; 32],
+}
+
+/// Event logged by the program, see the [module docs](self)
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub enum CurveEvent {
+    Created(CurveCreated),
+    Altered(CurveAltered),
+    Deleted(CurveDeleted),
+}
+
+impl CurveEvent {
+    pub fn curve(&self) -> &Pubkey {
+        match self {
+            Self::Created(event) => &event.curve,
+            Self::Altered(event) => &event.curve,
+            Self::Deleted(event) => &event.curve,
+        }
+    }
+
+    /// Logs the event with `sol_log_data`
+    pub fn emit(&self) {
+        let data = borsh::to_vec(self).expect("event serializes");
+        sol_log_data(&[EVENT_DISCRIMINATOR, &data]);
+    }
+
+    /// Event of `sol_log_data` fields. `None` for data logged by other programs or events of
+    /// later program versions.
+    pub fn decode(fields: &[&[u8]]) -> Option<Self> {
+        match fields {
+            [discriminator, data] if *discriminator == EVENT_DISCRIMINATOR => {
+                Self::try_from_slice(data).ok()
+            }
+            _ => None,
+        }
+    }
+}
+
+/// SHA-256 of `y` little-endian bytes
+pub fn y_hash<Y: Pod>(y: &[Y]) -> [u8; 32] {
+    hashv(&[bytemuck::cast_slice(y)]).to_bytes()
+}
@@ -1,7 +1,13 @@
 use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::pubkey::Pubkey;
 use texture_common::macros::Instruction;
 
-use crate::state::curve::CurveParams;
+use crate::state::curve::{
+    CompactCurveParams, CurveParams, CurveX, CurveY, MAX_Y_CNT, SYMBOL_MAX_SIZE,
+};
+use crate::state::curve_confidence::CurveConfidenceBps;
+use crate::state::grid_curve::GridCurveParams;
+use crate::state::wide_curve::WideCurveParams;
 
 #[derive(Instruction, BorshSerialize, BorshDeserialize, Debug)]
 #[instruction(
@@ -26,10 +32,22 @@ pub enum CurvyInstruction {
             flags(writable, signer),
             docs = ["Curve owner."],
         ),
+        account(
+            name = "config",
+            docs = ["Program config, its creator allow-list is checked when it exists."],
+        ),
+        account(
+            name = "name_link",
+            flags(writable),
+            docs = ["Name link of the owner and the curve name, created or pointed to the curve."],
+        ),
         program(id = "system", docs = ["System program"])
     )]
     CreateCurve { params: CurveParams },
-    /// Alter existing Curve
+    /// Alter existing Curve, its name is kept (see UpdateCurveMetadata). Co-owned curves (see
+    /// SetCurveOwners) take the curve owners account after the listed accounts, followed by
+    /// other signing owners. Reference curves (see CreateReferenceCurve) take the program config
+    /// account there, the owner must be its current admin.
     ///
     #[doc = ix_docs::alter_curve!()]
     #[accounts(
@@ -42,11 +60,14 @@ pub enum CurvyInstruction {
         account(
             name = "owner",
             flags(signer),
-            docs = ["Curve owner."],
+            docs = ["Curve owner or its delegate, any owner of co-owned curve."],
         ),
     )]
     AlterCurve { params: CurveParams },
-    /// Delete existing Curve
+    /// Delete existing Curve, WideCurve or GridCurve. Rent of the curve account goes to the owner
+    /// unless a writable rent destination account (e.g. a treasury) follows the listed accounts,
+    /// rent of the name link always returns to the owner. Reference curves take the program
+    /// config account before the rent destination, the owner must be its current admin.
     ///
     #[doc = ix_docs::delete_curve!()]
     #[accounts(
@@ -56,11 +77,987 @@ pub enum CurvyInstruction {
             docs = ["Curve account to delete."],
             checks(owner = "self"),
         ),
+        account(
+            name = "owner",
+            flags(writable, signer),
+            docs = ["Curve owner, receives the rent unless the rent destination is given."],
+        ),
+        account(
+            name = "name_link",
+            flags(writable),
+            docs = ["Name link of the owner and the curve name, closed if it points to the curve."],
+        ),
+    )]
+    DeleteCurve,
+    /// Update only name and formula of existing Curve leaving `y` table untouched. Renaming moves
+    /// the name link of the curve to the new name.
+    ///
+    #[doc = ix_docs::update_curve_metadata!()]
+    #[accounts(
+        account(
+            name = "curve",
+            flags(writable),
+            docs = ["Curve account to update."],
+            checks(owner = "self"),
+        ),
+        account(
+            name = "owner",
+            flags(writable, signer),
+            docs = ["Curve owner, pays for the name link of the new name."],
+        ),
+        account(
+            name = "name_link",
+            flags(writable),
+            docs = ["Name link of the owner and the current name, closed if it points to the curve."],
+        ),
+        account(
+            name = "new_name_link",
+            flags(writable),
+            docs = ["Name link of the owner and the new name, created or pointed to the curve."],
+        ),
+        program(id = "system", docs = ["System program"])
+    )]
+    UpdateCurveMetadata {
+        name: [u8; SYMBOL_MAX_SIZE],
+        formula: [u8; SYMBOL_MAX_SIZE],
+    },
+    /// Update only `y` table and its X scale of existing Curve leaving name and formula untouched.
+    /// `decimals` are set on both axes, use AlterCurve for different `x` and `y` decimals. Fails
+    /// for curves already having different ones.
+    ///
+    #[doc = ix_docs::update_curve_data!()]
+    #[accounts(
+        account(
+            name = "curve",
+            flags(writable),
+            docs = ["Curve account to update."],
+            checks(owner = "self"),
+        ),
         account(
             name = "owner",
             flags(signer),
+            docs = ["Curve owner or its delegate."],
+        ),
+    )]
+    UpdateCurveData {
+        x0: CurveX,
+        x_step: CurveX,
+        y_count: u8,
+        decimals: u8,
+        y: [CurveY; MAX_Y_CNT],
+    },
+    /// Create Curve account with name, formula and owner but without `y` table, so its address
+    /// can be used before the data exists. Fill it later with UpdateCurveData.
+    ///
+    #[doc = ix_docs::reserve_curve!()]
+    #[accounts(
+        account(
+            name = "curve",
+            flags(writable, signer),
+            docs = ["Curve account to create."],
+            checks(owner = "system", size = 0),
+        ),
+        account(
+            name = "owner",
+            flags(writable, signer),
             docs = ["Curve owner."],
         ),
+        account(
+            name = "config",
+            docs = ["Program config, its creator allow-list is checked when it exists."],
+        ),
+        account(
+            name = "name_link",
+            flags(writable),
+            docs = ["Name link of the owner and the curve name, created or pointed to the curve."],
+        ),
+        program(id = "system", docs = ["System program"])
     )]
-    DeleteCurve,
+    ReserveCurve {
+        name: [u8; SYMBOL_MAX_SIZE],
+        formula: [u8; SYMBOL_MAX_SIZE],
+    },
+    /// Transfer lamports missing for rent exemption of Curve account from the payer, e.g. after
+    /// the account data grew. Does nothing when the account is rent exempt already.
+    ///
+    #[doc = ix_docs::top_up_rent!()]
+    #[accounts(
+        account(
+            name = "curve",
+            flags(writable),
+            docs = ["Curve account to top up."],
+            checks(owner = "self"),
+        ),
+        account(
+            name = "payer",
+            flags(writable, signer),
+            docs = ["Account paying the missing lamports."],
+        ),
+        program(id = "system", docs = ["System program"])
+    )]
+    TopUpRent,
+    /// Associate Curve with SPL mint. Fails when the owner has another curve of the same kind
+    /// associated with the mint, or when the curve is associated already.
+    ///
+    #[doc = ix_docs::set_curve_asset!()]
+    #[accounts(
+        account(
+            name = "curve",
+            flags(writable),
+            docs = ["Curve account to associate."],
+            checks(owner = "self"),
+        ),
+        account(
+            name = "owner",
+            flags(writable, signer),
+            docs = ["Curve owner, pays for the asset link."],
+        ),
+        account(
+            name = "asset_mint",
+            docs = ["SPL Token or Token-2022 mint."],
+        ),
+        account(
+            name = "asset_link",
+            flags(writable),
+            docs = ["Asset link to create, PDA of owner, mint and kind."],
+            checks(owner = "system", size = 0),
+        ),
+        program(id = "system", docs = ["System program"])
+    )]
+    SetCurveAsset { kind: u8 },
+    /// Remove association of Curve with SPL mint closing its asset link
+    ///
+    #[doc = ix_docs::clear_curve_asset!()]
+    #[accounts(
+        account(
+            name = "curve",
+            flags(writable),
+            docs = ["Curve account to update."],
+            checks(owner = "self"),
+        ),
+        account(
+            name = "owner",
+            flags(writable, signer),
+            docs = ["Curve owner, receives asset link rent."],
+        ),
+        account(
+            name = "asset_link",
+            flags(writable),
+            docs = ["Asset link of the curve."],
+            checks(owner = "self"),
+        ),
+    )]
+    ClearCurveAsset,
+    /// Upgrade Curve account to the current layout version, resizing it. Anyone can pay for it.
+    ///
+    #[doc = ix_docs::migrate_curve!()]
+    #[accounts(
+        account(
+            name = "curve",
+            flags(writable),
+            docs = ["Curve account to migrate."],
+            checks(owner = "self"),
+        ),
+        account(
+            name = "payer",
+            flags(writable, signer),
+            docs = ["Account paying rent of the grown account."],
+        ),
+        program(id = "system", docs = ["System program"])
+    )]
+    MigrateCurve,
+    /// Set `y` table of an additional series of Curve (from 1, series must be set in order).
+    /// Creates the curve series account on first use.
+    ///
+    #[doc = ix_docs::set_curve_series!()]
+    #[accounts(
+        account(
+            name = "curve",
+            flags(writable),
+            docs = ["Curve account to update."],
+            checks(owner = "self"),
+        ),
+        account(
+            name = "owner",
+            flags(writable, signer),
+            docs = ["Curve owner, pays for the curve series account."],
+        ),
+        account(
+            name = "curve_series",
+            flags(writable),
+            docs = ["Curve series account, PDA of the curve."],
+        ),
+        program(id = "system", docs = ["System program"])
+    )]
+    SetCurveSeries { series: u8, y: [CurveY; MAX_Y_CNT] },
+    /// Remove all additional series of Curve closing its curve series account
+    ///
+    #[doc = ix_docs::delete_curve_series!()]
+    #[accounts(
+        account(
+            name = "curve",
+            flags(writable),
+            docs = ["Curve account to update."],
+            checks(owner = "self"),
+        ),
+        account(
+            name = "owner",
+            flags(writable, signer),
+            docs = ["Curve owner, receives curve series account rent."],
+        ),
+        account(
+            name = "curve_series",
+            flags(writable),
+            docs = ["Curve series account of the curve."],
+            checks(owner = "self"),
+        ),
+    )]
+    DeleteCurveSeries,
+    /// Set per-sample confidence of Curve. Creates the curve confidence account on first use.
+    ///
+    #[doc = ix_docs::set_curve_confidence!()]
+    #[accounts(
+        account(
+            name = "curve",
+            flags(writable),
+            docs = ["Curve account to update."],
+            checks(owner = "self"),
+        ),
+        account(
+            name = "owner",
+            flags(writable, signer),
+            docs = ["Curve owner, pays for the curve confidence account."],
+        ),
+        account(
+            name = "curve_confidence",
+            flags(writable),
+            docs = ["Curve confidence account, PDA of the curve."],
+        ),
+        program(id = "system", docs = ["System program"])
+    )]
+    SetCurveConfidence {
+        confidence: [CurveConfidenceBps; MAX_Y_CNT],
+    },
+    /// Remove per-sample confidence of Curve closing its curve confidence account
+    ///
+    #[doc = ix_docs::delete_curve_confidence!()]
+    #[accounts(
+        account(
+            name = "curve",
+            flags(writable),
+            docs = ["Curve account to update."],
+            checks(owner = "self"),
+        ),
+        account(
+            name = "owner",
+            flags(writable, signer),
+            docs = ["Curve owner, receives curve confidence account rent."],
+        ),
+        account(
+            name = "curve_confidence",
+            flags(writable),
+            docs = ["Curve confidence account of the curve."],
+            checks(owner = "self"),
+        ),
+    )]
+    DeleteCurveConfidence,
+    /// Create WideCurve account, a curve with u64 `y` samples
+    ///
+    #[doc = ix_docs::create_wide_curve!()]
+    #[accounts(
+        account(
+            name = "curve",
+            flags(writable, signer),
+            docs = ["WideCurve account to create."],
+            checks(owner = "system", size = 0),
+        ),
+        account(
+            name = "owner",
+            flags(writable, signer),
+            docs = ["Curve owner."],
+        ),
+        account(
+            name = "config",
+            docs = ["Program config, its creator allow-list is checked when it exists."],
+        ),
+        account(
+            name = "name_link",
+            flags(writable),
+            docs = ["Name link of the owner and the curve name, created or pointed to the curve."],
+        ),
+        program(id = "system", docs = ["System program"])
+    )]
+    CreateWideCurve { params: WideCurveParams },
+    /// Alter existing WideCurve, its name is kept
+    ///
+    #[doc = ix_docs::alter_wide_curve!()]
+    #[accounts(
+        account(
+            name = "curve",
+            flags(writable),
+            docs = ["WideCurve account to update."],
+            checks(owner = "self"),
+        ),
+        account(
+            name = "owner",
+            flags(signer),
+            docs = ["Curve owner."],
+        ),
+    )]
+    AlterWideCurve { params: WideCurveParams },
+    /// Set sign of `x0` of existing Curve, so the curve can cover negative X (e.g. basis in bps)
+    ///
+    #[doc = ix_docs::set_curve_x_sign!()]
+    #[accounts(
+        account(
+            name = "curve",
+            flags(writable),
+            docs = ["Curve account to update."],
+            checks(owner = "self"),
+        ),
+        account(
+            name = "owner",
+            flags(signer),
+            docs = ["Curve owner."],
+        ),
+    )]
+    SetCurveXSign { x0_negative: bool },
+    /// Propose new owner of Curve. Ownership changes only when the new owner signs
+    /// AcceptOwner, so a mistyped key can not take the curve. Proposing zero key cancels the
+    /// proposal. Fails when the curve is associated with asset, as asset links are derived from
+    /// the owner.
+    ///
+    #[doc = ix_docs::propose_owner!()]
+    #[accounts(
+        account(
+            name = "curve",
+            flags(writable),
+            docs = ["Curve account to transfer."],
+            checks(owner = "self"),
+        ),
+        account(
+            name = "owner",
+            flags(signer),
+            docs = ["Current curve owner."],
+        ),
+    )]
+    ProposeOwner { new_owner: Pubkey },
+    /// Accept ownership of Curve proposed by ProposeOwner. Delegates of the previous owner are
+    /// removed and the name link of the curve moves to the new owner. Co-owned curves are not
+    /// transferred, SetCurveOwners drops the proposal.
+    ///
+    #[doc = ix_docs::accept_owner!()]
+    #[accounts(
+        account(
+            name = "curve",
+            flags(writable),
+            docs = ["Curve account to accept."],
+            checks(owner = "self"),
+        ),
+        account(
+            name = "new_owner",
+            flags(writable, signer),
+            docs = ["Proposed owner, pays for its name link."],
+        ),
+        account(
+            name = "owner",
+            flags(writable),
+            docs = ["Current curve owner, receives the rent of its name link."],
+        ),
+        account(
+            name = "name_link",
+            flags(writable),
+            docs = ["Name link of the current owner and the curve name, closed if it points to the curve."],
+        ),
+        account(
+            name = "new_name_link",
+            flags(writable),
+            docs = ["Name link of the new owner and the curve name, created or pointed to the curve."],
+        ),
+        program(id = "system", docs = ["System program"])
+    )]
+    AcceptOwner,
+    /// Create Curve account at the address derived from the owner and the curve name, see
+    /// [crate::state::curve::find_curve_address]
+    ///
+    #[doc = ix_docs::create_curve_pda!()]
+    #[accounts(
+        account(
+            name = "curve",
+            flags(writable),
+            docs = ["Curve account to create, PDA of owner and name."],
+            checks(owner = "system", size = 0),
+        ),
+        account(
+            name = "owner",
+            flags(writable, signer),
+            docs = ["Curve owner."],
+        ),
+        account(
+            name = "config",
+            docs = ["Program config, its creator allow-list is checked when it exists."],
+        ),
+        account(
+            name = "name_link",
+            flags(writable),
+            docs = ["Name link of the owner and the curve name, created or pointed to the curve."],
+        ),
+        program(id = "system", docs = ["System program"])
+    )]
+    CreateCurvePda { params: CurveParams },
+    /// Freeze Curve, WideCurve or GridCurve, so its samples, metadata, series and confidence can
+    /// never be changed and it can not be deleted. Irreversible. Reserved curves can not be
+    /// frozen.
+    ///
+    #[doc = ix_docs::freeze_curve!()]
+    #[accounts(
+        account(
+            name = "curve",
+            flags(writable),
+            docs = ["Curve account to freeze."],
+            checks(owner = "self"),
+        ),
+        account(
+            name = "owner",
+            flags(signer),
+            docs = ["Curve owner."],
+        ),
+    )]
+    FreezeCurve,
+    /// Grow Curve account to hold `y_capacity` samples in total, `y` table included. Samples
+    /// after the `y` table are set by SetCurveTail. Accounts can not shrink.
+    ///
+    #[doc = ix_docs::resize_curve!()]
+    #[accounts(
+        account(
+            name = "curve",
+            flags(writable),
+            docs = ["Curve account to resize."],
+            checks(owner = "self"),
+        ),
+        account(
+            name = "owner",
+            flags(writable, signer),
+            docs = ["Curve owner, pays rent of the grown account."],
+        ),
+        program(id = "system", docs = ["System program"])
+    )]
+    ResizeCurve { y_capacity: u16 },
+    /// Set `y` samples following the full `y` table of Curve grown by ResizeCurve, starting at
+    /// tail sample `start`. The tail is cut after the last of them, so empty `y` cuts it at
+    /// `start`.
+    ///
+    #[doc = ix_docs::set_curve_tail!()]
+    #[accounts(
+        account(
+            name = "curve",
+            flags(writable),
+            docs = ["Curve account to update."],
+            checks(owner = "self"),
+        ),
+        account(
+            name = "owner",
+            flags(signer),
+            docs = ["Curve owner."],
+        ),
+    )]
+    SetCurveTail { start: u16, y: Vec<CurveY> },
+    /// Create GridCurve account, a curve sampled in explicit `x` points
+    ///
+    #[doc = ix_docs::create_grid_curve!()]
+    #[accounts(
+        account(
+            name = "curve",
+            flags(writable, signer),
+            docs = ["GridCurve account to create."],
+            checks(owner = "system", size = 0),
+        ),
+        account(
+            name = "owner",
+            flags(writable, signer),
+            docs = ["Curve owner."],
+        ),
+        account(
+            name = "config",
+            docs = ["Program config, its creator allow-list is checked when it exists."],
+        ),
+        account(
+            name = "name_link",
+            flags(writable),
+            docs = ["Name link of the owner and the curve name, created or pointed to the curve."],
+        ),
+        program(id = "system", docs = ["System program"])
+    )]
+    CreateGridCurve { params: GridCurveParams },
+    /// Alter existing GridCurve, its name is kept
+    ///
+    #[doc = ix_docs::alter_grid_curve!()]
+    #[accounts(
+        account(
+            name = "curve",
+            flags(writable),
+            docs = ["GridCurve account to update."],
+            checks(owner = "self"),
+        ),
+        account(
+            name = "owner",
+            flags(signer),
+            docs = ["Curve owner."],
+        ),
+    )]
+    AlterGridCurve { params: GridCurveParams },
+    /// Set whether `y` values of Curve are signed, so values set by other instructions as
+    /// `u32` are read as `i32` bits. Sent after the values for curves with negative `y`.
+    ///
+    #[doc = ix_docs::set_curve_y_sign!()]
+    #[accounts(
+        account(
+            name = "curve",
+            flags(writable),
+            docs = ["Curve account to update."],
+            checks(owner = "self"),
+        ),
+        account(
+            name = "owner",
+            flags(signer),
+            docs = ["Curve owner."],
+        ),
+    )]
+    SetCurveYSign { y_signed: bool },
+    /// Move Curve, WideCurve or GridCurve to another lifecycle stage, see
+    /// [CurveStatus](crate::state::curve::CurveStatus) for allowed transitions. Reserved curves
+    /// can not be activated before their data is set. Allowed for frozen curves. Reference
+    /// curves take the program config account after the listed accounts, the owner must be its
+    /// current admin.
+    ///
+    #[doc = ix_docs::set_curve_status!()]
+    #[accounts(
+        account(
+            name = "curve",
+            flags(writable),
+            docs = ["Curve account to update."],
+            checks(owner = "self"),
+        ),
+        account(
+            name = "owner",
+            flags(signer),
+            docs = ["Curve owner."],
+        ),
+    )]
+    SetCurveStatus { status: u8 },
+    /// Overwrite `y` samples of Curve starting at `start_index`, e.g. to correct a few points
+    /// without sending the whole table. Samples must lie within `y_count`, the tail is set by
+    /// SetCurveTail.
+    ///
+    #[doc = ix_docs::patch_y!()]
+    #[accounts(
+        account(
+            name = "curve",
+            flags(writable),
+            docs = ["Curve account to update."],
+            checks(owner = "self"),
+        ),
+        account(
+            name = "owner",
+            flags(signer),
+            docs = ["Curve owner or its delegate."],
+        ),
+    )]
+    PatchY {
+        start_index: u8,
+        values: Vec<CurveY>,
+    },
+    /// Mark Curve, WideCurve or GridCurve for deletion in the current slot. DeleteCurve accepts
+    /// the marked curve only [DELETION_GRACE_SLOTS](crate::state::curve::DELETION_GRACE_SLOTS)
+    /// or its alter delay later, whichever is longer, giving its consumers time to migrate. Curves which are not
+    /// marked can be deleted at once, unless their alterations are timelocked.
+    ///
+    #[doc = ix_docs::mark_for_deletion!()]
+    #[accounts(
+        account(
+            name = "curve",
+            flags(writable),
+            docs = ["Curve account to mark."],
+            checks(owner = "self"),
+        ),
+        account(
+            name = "owner",
+            flags(signer),
+            docs = ["Curve owner."],
+        ),
+    )]
+    MarkForDeletion,
+    /// Remove the mark set by MarkForDeletion from Curve, WideCurve or GridCurve
+    ///
+    #[doc = ix_docs::cancel_deletion!()]
+    #[accounts(
+        account(
+            name = "curve",
+            flags(writable),
+            docs = ["Curve account to unmark."],
+            checks(owner = "self"),
+        ),
+        account(
+            name = "owner",
+            flags(signer),
+            docs = ["Curve owner."],
+        ),
+    )]
+    CancelDeletion,
+    /// Evaluate Curve in `x` (scaled by its decimals, the tail included) and store the point
+    /// with the current slot in CurveEvaluation account, for consumers which CPI instead of
+    /// interpolating themselves. The result account is created by the caller as a zeroed
+    /// account of [CurveEvaluation::SIZE](crate::state::curve_evaluation::CurveEvaluation)
+    /// owned by the program, `authority` of the first evaluation must sign the next ones.
+    /// Draft and retired curves are not evaluated, nor curves storing transformed `y`.
+    ///
+    #[doc = ix_docs::evaluate_curve!()]
+    #[accounts(
+        account(
+            name = "curve",
+            docs = ["Curve account to evaluate."],
+            checks(owner = "self"),
+        ),
+        account(
+            name = "result",
+            flags(writable),
+            docs = ["CurveEvaluation account to store the result in."],
+            checks(owner = "self"),
+        ),
+        account(
+            name = "authority",
+            flags(signer),
+            docs = ["Authority of the result account."],
+        ),
+    )]
+    EvaluateCurve { x: i64 },
+    /// Set the number of slots alterations of Curve wait for. Curves with non-zero delay are
+    /// altered only by ProposeAlter and ApplyAlter. The delay can only grow, up to
+    /// [MAX_ALTER_DELAY_SLOTS](crate::state::curve::MAX_ALTER_DELAY_SLOTS), send it together
+    /// with CreateCurve to timelock the curve from its creation.
+    ///
+    #[doc = ix_docs::set_alter_delay!()]
+    #[accounts(
+        account(
+            name = "curve",
+            flags(writable),
+            docs = ["Curve account to timelock."],
+            checks(owner = "self"),
+        ),
+        account(
+            name = "owner",
+            flags(signer),
+            docs = ["Curve owner."],
+        ),
+    )]
+    SetAlterDelay { slots: u64 },
+    /// Propose new params of Curve keeping its name, ApplyAlter sets them once the alter delay of
+    /// the curve passes. Creates the pending alter account on first use, a new proposal replaces the
+    /// pending one and waits the whole delay again.
+    ///
+    #[doc = ix_docs::propose_alter!()]
+    #[accounts(
+        account(
+            name = "curve",
+            flags(writable),
+            docs = ["Curve account to alter."],
+            checks(owner = "self"),
+        ),
+        account(
+            name = "owner",
+            flags(writable, signer),
+            docs = ["Curve owner, pays for the pending alter account."],
+        ),
+        account(
+            name = "pending_alter",
+            flags(writable),
+            docs = ["Pending alter account, PDA of the curve."],
+        ),
+        program(id = "system", docs = ["System program"])
+    )]
+    ProposeAlter { params: CurveParams },
+    /// Set params proposed by ProposeAlter once the alter delay of Curve passed, closing the
+    /// pending alter account
+    ///
+    #[doc = ix_docs::apply_alter!()]
+    #[accounts(
+        account(
+            name = "curve",
+            flags(writable),
+            docs = ["Curve account to alter."],
+            checks(owner = "self"),
+        ),
+        account(
+            name = "owner",
+            flags(writable, signer),
+            docs = ["Curve owner, receives lamports of the pending alter account."],
+        ),
+        account(
+            name = "pending_alter",
+            flags(writable),
+            docs = ["Pending alter account of the curve."],
+            checks(owner = "self"),
+        ),
+    )]
+    ApplyAlter,
+    /// Drop params proposed by ProposeAlter closing the pending alter account
+    ///
+    #[doc = ix_docs::cancel_alter!()]
+    #[accounts(
+        account(
+            name = "curve",
+            flags(writable),
+            docs = ["Curve account of the proposal."],
+            checks(owner = "self"),
+        ),
+        account(
+            name = "owner",
+            flags(writable, signer),
+            docs = ["Curve owner, receives lamports of the pending alter account."],
+        ),
+        account(
+            name = "pending_alter",
+            flags(writable),
+            docs = ["Pending alter account of the curve."],
+            checks(owner = "self"),
+        ),
+    )]
+    CancelAlter,
+    /// Allow `delegate` to update samples of Curve by AlterCurve, BatchAlter, UpdateCurveData and
+    /// PatchY, e.g. a hot key of a rate updater while the cold owner keeps other rights. Other
+    /// params, such as the name, formula, decimals and x axis, are changed by the owner. Up to
+    /// [MAX_CURVE_DELEGATES](crate::state::curve::MAX_CURVE_DELEGATES) delegates.
+    ///
+    #[doc = ix_docs::add_delegate!()]
+    #[accounts(
+        account(
+            name = "curve",
+            flags(writable),
+            docs = ["Curve account to delegate."],
+            checks(owner = "self"),
+        ),
+        account(
+            name = "owner",
+            flags(signer),
+            docs = ["Curve owner."],
+        ),
+    )]
+    AddDelegate { delegate: Pubkey },
+    /// Revoke `delegate` added by AddDelegate
+    ///
+    #[doc = ix_docs::remove_delegate!()]
+    #[accounts(
+        account(
+            name = "curve",
+            flags(writable),
+            docs = ["Curve account of the delegate."],
+            checks(owner = "self"),
+        ),
+        account(
+            name = "owner",
+            flags(signer),
+            docs = ["Curve owner."],
+        ),
+    )]
+    RemoveDelegate { delegate: Pubkey },
+    /// Create the program config with the creator allow-list enforced by curve creation
+    /// instructions. Signed by the upgrade authority of the program, which becomes the config
+    /// admin.
+    ///
+    #[doc = ix_docs::init_config!()]
+    #[accounts(
+        account(
+            name = "config",
+            flags(writable),
+            docs = ["Config account to create, PDA of the config seed."],
+            checks(owner = "system", size = 0),
+        ),
+        account(
+            name = "admin",
+            flags(writable, signer),
+            docs = ["Upgrade authority of the program, pays for the config account."],
+        ),
+        account(
+            name = "program_data",
+            docs = ["ProgramData account of the program."],
+        ),
+        program(id = "system", docs = ["System program"])
+    )]
+    InitConfig { creators: Vec<Pubkey> },
+    /// Set `new_admin` and the creator allow-list of the program config. An empty allow-list lets
+    /// nobody create curves.
+    ///
+    #[doc = ix_docs::set_config!()]
+    #[accounts(
+        account(
+            name = "config",
+            flags(writable),
+            docs = ["Config account to update."],
+            checks(owner = "self"),
+        ),
+        account(
+            name = "admin",
+            flags(signer),
+            docs = ["Current config admin."],
+        ),
+    )]
+    SetConfig {
+        new_admin: Pubkey,
+        creators: Vec<Pubkey>,
+    },
+    /// Make Curve co-owned: `threshold` of `owners`, the curve owner among them, must sign its
+    /// AlterCurve and SetCurveOwners, other instructions fail until the owners set zero
+    /// threshold and no owners back. Creates the curve owners account on first use and closes
+    /// it then. Changing owners of co-owned curve takes the threshold of its current owners,
+    /// other signing owners follow the listed accounts.
+    ///
+    #[doc = ix_docs::set_curve_owners!()]
+    #[accounts(
+        account(
+            name = "curve",
+            flags(writable),
+            docs = ["Curve account to update."],
+            checks(owner = "self"),
+        ),
+        account(
+            name = "owner",
+            flags(writable, signer),
+            docs = ["Curve owner, any owner of co-owned curve. Pays for the curve owners account."],
+        ),
+        account(
+            name = "curve_owners",
+            flags(writable),
+            docs = ["Curve owners account, PDA of the curve."],
+        ),
+        program(id = "system", docs = ["System program"])
+    )]
+    SetCurveOwners { threshold: u8, owners: Vec<Pubkey> },
+    /// Set the value of a known extension of Curve (see [crate::state::curve_extension]), or
+    /// remove the extension with empty `value`. The account grows or shrinks by the change of its
+    /// extension region.
+    ///
+    #[doc = ix_docs::set_curve_extension!()]
+    #[accounts(
+        account(
+            name = "curve",
+            flags(writable),
+            docs = ["Curve account to update."],
+            checks(owner = "self"),
+        ),
+        account(
+            name = "owner",
+            flags(writable, signer),
+            docs = ["Curve owner, pays rent of the grown account."],
+        ),
+        program(id = "system", docs = ["System program"])
+    )]
+    SetCurveExtension { extension_type: u16, value: Vec<u8> },
+    /// Set the transform of stored `y` values of Curve, see
+    /// [YTransform](crate::state::curve::YTransform). Sent after the values, which are set by
+    /// other instructions as stored.
+    ///
+    #[doc = ix_docs::set_curve_y_transform!()]
+    #[accounts(
+        account(
+            name = "curve",
+            flags(writable),
+            docs = ["Curve account to update."],
+            checks(owner = "self"),
+        ),
+        account(
+            name = "owner",
+            flags(signer),
+            docs = ["Curve owner."],
+        ),
+    )]
+    SetCurveYTransform { y_transform: u8 },
+    /// Set the unix timestamp Curve is stale from and the period sample updates move it past
+    /// their time, see [Curve::valid_until](crate::state::curve::Curve::valid_until). Zero
+    /// `valid_until` never expires, zero `validity_period` keeps it on updates.
+    ///
+    #[doc = ix_docs::set_curve_expiry!()]
+    #[accounts(
+        account(
+            name = "curve",
+            flags(writable),
+            docs = ["Curve account to update."],
+            checks(owner = "self"),
+        ),
+        account(
+            name = "owner",
+            flags(signer),
+            docs = ["Curve owner."],
+        ),
+    )]
+    SetCurveExpiry {
+        valid_until: i64,
+        validity_period: u64,
+    },
+    /// Alter several Curves of one owner in one transaction, all of them or none. Curve
+    /// accounts (writable) follow the listed accounts in the order of `params`, names of the
+    /// curves are kept. Co-owned curves are altered by AlterCurve.
+    ///
+    #[doc = ix_docs::batch_alter!()]
+    #[accounts(
+        account(
+            name = "owner",
+            flags(signer),
+            docs = ["Owner or delegate of every altered curve."],
+        ),
+    )]
+    BatchAlter { params: Vec<CompactCurveParams> },
+    /// Create the canonical reference Curve of `kind` for the asset mint at the address derived
+    /// from them, see [crate::state::curve::find_reference_curve_address]. Signed by the config
+    /// admin. The curve is owned by the config address, so AlterCurve, DeleteCurve and
+    /// SetCurveStatus of it are signed by whoever is the config admin at the time. Fails while
+    /// creation of curves is frozen.
+    ///
+    #[doc = ix_docs::create_reference_curve!()]
+    #[accounts(
+        account(
+            name = "curve",
+            flags(writable),
+            docs = ["Curve account to create, PDA of kind and mint."],
+            checks(owner = "system", size = 0),
+        ),
+        account(
+            name = "admin",
+            flags(writable, signer),
+            docs = ["Config admin, pays for the curve."],
+        ),
+        account(
+            name = "config",
+            docs = ["Program config."],
+            checks(owner = "self"),
+        ),
+        account(
+            name = "asset_mint",
+            docs = ["SPL Token or Token-2022 mint."],
+        ),
+        account(
+            name = "name_link",
+            flags(writable),
+            docs = ["Name link of the config and the curve name, created or pointed to the curve."],
+        ),
+        program(id = "system", docs = ["System program"])
+    )]
+    CreateReferenceCurve { kind: u8, params: CurveParams },
+    /// Freeze or unfreeze creation of curves, see
+    /// [crate::state::config::Config::creation_frozen]
+    ///
+    #[doc = ix_docs::set_creation_frozen!()]
+    #[accounts(
+        account(
+            name = "config",
+            flags(writable),
+            docs = ["Config account to update."],
+            checks(owner = "self"),
+        ),
+        account(
+            name = "admin",
+            flags(signer),
+            docs = ["Config admin."],
+        ),
+    )]
+    SetCreationFrozen { frozen: bool },
 }
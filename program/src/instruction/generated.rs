@@ -9,6 +9,10 @@ pub struct CreateCurve {
     pub curve: solana_program::pubkey::Pubkey,
     ///Curve owner.
     pub owner: solana_program::pubkey::Pubkey,
+    ///Program config, its creator allow-list is checked when it exists.
+    pub config: solana_program::pubkey::Pubkey,
+    ///Name link of the owner and the curve name, created or pointed to the curve.
+    pub name_link: solana_program::pubkey::Pubkey,
     pub params: CurveParams,
 }
 impl CreateCurve {
@@ -19,6 +23,8 @@ impl CreateCurve {
             program_id,
             curve,
             owner,
+            config,
+            name_link,
             params,
         } = self;
         #[cfg(not(feature = "program-id-manually"))]
@@ -27,6 +33,12 @@ impl CreateCurve {
         let mut accounts = vec![];
         accounts.extend([solana_program::instruction::AccountMeta::new(curve, true)]);
         accounts.extend([solana_program::instruction::AccountMeta::new(owner, true)]);
+        accounts
+            .extend([
+                solana_program::instruction::AccountMeta::new_readonly(config, false),
+            ]);
+        accounts
+            .extend([solana_program::instruction::AccountMeta::new(name_link, false)]);
         accounts
             .extend([
                 solana_program::instruction::AccountMeta::new_readonly(
@@ -51,7 +63,7 @@ pub struct AlterCurve {
     pub program_id: solana_program::pubkey::Pubkey,
     ///Curve account to update.
     pub curve: solana_program::pubkey::Pubkey,
-    ///Curve owner.
+    ///Curve owner or its delegate, any owner of co-owned curve.
     pub owner: solana_program::pubkey::Pubkey,
     pub params: CurveParams,
 }
@@ -91,22 +103,29 @@ pub struct DeleteCurve {
     pub program_id: solana_program::pubkey::Pubkey,
     ///Curve account to delete.
     pub curve: solana_program::pubkey::Pubkey,
-    ///Curve owner.
+    ///Curve owner, receives the rent unless the rent destination is given.
     pub owner: solana_program::pubkey::Pubkey,
+    ///Name link of the owner and the curve name, closed if it points to the curve.
+    pub name_link: solana_program::pubkey::Pubkey,
 }
 impl DeleteCurve {
     #[track_caller]
     pub fn into_instruction(self) -> solana_program::instruction::Instruction {
-        let Self { #[cfg(feature = "program-id-manually")] program_id, curve, owner } = self;
+        let Self {
+            #[cfg(feature = "program-id-manually")]
+            program_id,
+            curve,
+            owner,
+            name_link,
+        } = self;
         #[cfg(not(feature = "program-id-manually"))]
         let program_id = crate::ID;
         #[allow(unused_mut)]
         let mut accounts = vec![];
         accounts.extend([solana_program::instruction::AccountMeta::new(curve, false)]);
+        accounts.extend([solana_program::instruction::AccountMeta::new(owner, true)]);
         accounts
-            .extend([
-                solana_program::instruction::AccountMeta::new_readonly(owner, true),
-            ]);
+            .extend([solana_program::instruction::AccountMeta::new(name_link, false)]);
         let ix = CurvyInstruction::DeleteCurve {};
         solana_program::instruction::Instruction::new_with_borsh(
             program_id,
@@ -115,384 +134,8345 @@ impl DeleteCurve {
         )
     }
 }
-/// [CurvyInstruction::CreateCurve] instruction account indexes helper
-#[derive(Debug, PartialEq)]
-pub struct CreateCurveAccountIndexes {
-    pub curve: usize,
-    pub owner: usize,
-    pub system_program: usize,
-}
-impl CreateCurveAccountIndexes {
-    pub const COUNT: usize = 3usize;
-    pub const CURVE: usize = 0usize;
-    pub const OWNER: usize = 1usize;
-    pub const SYSTEM_PROGRAM: usize = 2usize;
-    pub fn new_direct_order() -> Self {
-        let mut iter = std::iter::repeat(()).enumerate().map(|(idx, ())| idx);
-        Self {
-            curve: iter.next().unwrap(),
-            owner: iter.next().unwrap(),
-            system_program: iter.next().unwrap(),
-        }
-    }
-    pub fn try_from_indexes<'a>(
-        indexes: impl IntoIterator<Item = &'a u8>,
-    ) -> Result<Self, usize> {
-        let mut iter = indexes.into_iter().map(|idx| (*idx) as usize);
-        let mut idx = 0_usize;
-        Ok(Self {
-            curve: {
-                idx += 1;
-                iter.next().ok_or(idx - 1)?
-            },
-            owner: {
-                idx += 1;
-                iter.next().ok_or(idx - 1)?
-            },
-            system_program: {
-                idx += 1;
-                iter.next().ok_or(idx - 1)?
-            },
-        })
-    }
-}
-impl<'a> TryFrom<&'a [u8]> for CreateCurveAccountIndexes {
-    type Error = usize;
-    fn try_from(indexes: &'a [u8]) -> Result<Self, Self::Error> {
-        Self::try_from_indexes(indexes)
-    }
+///[CurvyInstruction::UpdateCurveMetadata] Builder struct
+pub struct UpdateCurveMetadata {
+    #[cfg(feature = "program-id-manually")]
+    /// Current program ID
+    pub program_id: solana_program::pubkey::Pubkey,
+    ///Curve account to update.
+    pub curve: solana_program::pubkey::Pubkey,
+    ///Curve owner, pays for the name link of the new name.
+    pub owner: solana_program::pubkey::Pubkey,
+    ///Name link of the owner and the current name, closed if it points to the curve.
+    pub name_link: solana_program::pubkey::Pubkey,
+    ///Name link of the owner and the new name, created or pointed to the curve.
+    pub new_name_link: solana_program::pubkey::Pubkey,
+    pub name: [u8; SYMBOL_MAX_SIZE],
+    pub formula: [u8; SYMBOL_MAX_SIZE],
 }
-impl<'a, const N: usize> TryFrom<&'a [u8; N]> for CreateCurveAccountIndexes {
-    type Error = usize;
-    fn try_from(indexes: &'a [u8; N]) -> Result<Self, Self::Error> {
-        Self::try_from_indexes(indexes)
+impl UpdateCurveMetadata {
+    #[track_caller]
+    pub fn into_instruction(self) -> solana_program::instruction::Instruction {
+        let Self {
+            #[cfg(feature = "program-id-manually")]
+            program_id,
+            curve,
+            owner,
+            name_link,
+            new_name_link,
+            name,
+            formula,
+        } = self;
+        #[cfg(not(feature = "program-id-manually"))]
+        let program_id = crate::ID;
+        #[allow(unused_mut)]
+        let mut accounts = vec![];
+        accounts.extend([solana_program::instruction::AccountMeta::new(curve, false)]);
+        accounts.extend([solana_program::instruction::AccountMeta::new(owner, true)]);
+        accounts
+            .extend([solana_program::instruction::AccountMeta::new(name_link, false)]);
+        accounts
+            .extend([
+                solana_program::instruction::AccountMeta::new(new_name_link, false),
+            ]);
+        accounts
+            .extend([
+                solana_program::instruction::AccountMeta::new_readonly(
+                    solana_program::system_program::ID,
+                    false,
+                ),
+            ]);
+        let ix = CurvyInstruction::UpdateCurveMetadata {
+            name,
+            formula,
+        };
+        solana_program::instruction::Instruction::new_with_borsh(
+            program_id,
+            &ix,
+            accounts,
+        )
     }
 }
-impl<const N: usize> TryFrom<[u8; N]> for CreateCurveAccountIndexes {
-    type Error = usize;
-    fn try_from(indexes: [u8; N]) -> Result<Self, Self::Error> {
-        Self::try_from_indexes(&indexes)
-    }
+///[CurvyInstruction::UpdateCurveData] Builder struct
+pub struct UpdateCurveData {
+    #[cfg(feature = "program-id-manually")]
+    /// Current program ID
+    pub program_id: solana_program::pubkey::Pubkey,
+    ///Curve account to update.
+    pub curve: solana_program::pubkey::Pubkey,
+    ///Curve owner or its delegate.
+    pub owner: solana_program::pubkey::Pubkey,
+    pub x0: CurveX,
+    pub x_step: CurveX,
+    pub y_count: u8,
+    pub decimals: u8,
+    pub y: [CurveY; MAX_Y_CNT],
 }
-impl TryFrom<Vec<u8>> for CreateCurveAccountIndexes {
-    type Error = usize;
-    fn try_from(indexes: Vec<u8>) -> Result<Self, Self::Error> {
-        Self::try_from_indexes(&indexes)
+impl UpdateCurveData {
+    #[track_caller]
+    pub fn into_instruction(self) -> solana_program::instruction::Instruction {
+        let Self {
+            #[cfg(feature = "program-id-manually")]
+            program_id,
+            curve,
+            owner,
+            x0,
+            x_step,
+            y_count,
+            decimals,
+            y,
+        } = self;
+        #[cfg(not(feature = "program-id-manually"))]
+        let program_id = crate::ID;
+        #[allow(unused_mut)]
+        let mut accounts = vec![];
+        accounts.extend([solana_program::instruction::AccountMeta::new(curve, false)]);
+        accounts
+            .extend([
+                solana_program::instruction::AccountMeta::new_readonly(owner, true),
+            ]);
+        let ix = CurvyInstruction::UpdateCurveData {
+            x0,
+            x_step,
+            y_count,
+            decimals,
+            y,
+        };
+        solana_program::instruction::Instruction::new_with_borsh(
+            program_id,
+            &ix,
+            accounts,
+        )
     }
 }
-/// [CurvyInstruction::AlterCurve] instruction account indexes helper
-#[derive(Debug, PartialEq)]
-pub struct AlterCurveAccountIndexes {
-    pub curve: usize,
-    pub owner: usize,
+///[CurvyInstruction::ReserveCurve] Builder struct
+pub struct ReserveCurve {
+    #[cfg(feature = "program-id-manually")]
+    /// Current program ID
+    pub program_id: solana_program::pubkey::Pubkey,
+    ///Curve account to create.
+    pub curve: solana_program::pubkey::Pubkey,
+    ///Curve owner.
+    pub owner: solana_program::pubkey::Pubkey,
+    ///Program config, its creator allow-list is checked when it exists.
+    pub config: solana_program::pubkey::Pubkey,
+    ///Name link of the owner and the curve name, created or pointed to the curve.
+    pub name_link: solana_program::pubkey::Pubkey,
+    pub name: [u8; SYMBOL_MAX_SIZE],
+    pub formula: [u8; SYMBOL_MAX_SIZE],
 }
-impl AlterCurveAccountIndexes {
-    pub const COUNT: usize = 2usize;
-    pub const CURVE: usize = 0usize;
-    pub const OWNER: usize = 1usize;
-    pub fn new_direct_order() -> Self {
-        let mut iter = std::iter::repeat(()).enumerate().map(|(idx, ())| idx);
-        Self {
-            curve: iter.next().unwrap(),
-            owner: iter.next().unwrap(),
-        }
-    }
-    pub fn try_from_indexes<'a>(
-        indexes: impl IntoIterator<Item = &'a u8>,
-    ) -> Result<Self, usize> {
-        let mut iter = indexes.into_iter().map(|idx| (*idx) as usize);
-        let mut idx = 0_usize;
-        Ok(Self {
-            curve: {
-                idx += 1;
-                iter.next().ok_or(idx - 1)?
-            },
-            owner: {
-                idx += 1;
-                iter.next().ok_or(idx - 1)?
-            },
-        })
+impl ReserveCurve {
+    #[track_caller]
+    pub fn into_instruction(self) -> solana_program::instruction::Instruction {
+        let Self {
+            #[cfg(feature = "program-id-manually")]
+            program_id,
+            curve,
+            owner,
+            config,
+            name_link,
+            name,
+            formula,
+        } = self;
+        #[cfg(not(feature = "program-id-manually"))]
+        let program_id = crate::ID;
+        #[allow(unused_mut)]
+        let mut accounts = vec![];
+        accounts.extend([solana_program::instruction::AccountMeta::new(curve, true)]);
+        accounts.extend([solana_program::instruction::AccountMeta::new(owner, true)]);
+        accounts
+            .extend([
+                solana_program::instruction::AccountMeta::new_readonly(config, false),
+            ]);
+        accounts
+            .extend([solana_program::instruction::AccountMeta::new(name_link, false)]);
+        accounts
+            .extend([
+                solana_program::instruction::AccountMeta::new_readonly(
+                    solana_program::system_program::ID,
+                    false,
+                ),
+            ]);
+        let ix = CurvyInstruction::ReserveCurve {
+            name,
+            formula,
+        };
+        solana_program::instruction::Instruction::new_with_borsh(
+            program_id,
+            &ix,
+            accounts,
+        )
     }
 }
-impl<'a> TryFrom<&'a [u8]> for AlterCurveAccountIndexes {
-    type Error = usize;
-    fn try_from(indexes: &'a [u8]) -> Result<Self, Self::Error> {
-        Self::try_from_indexes(indexes)
-    }
+///[CurvyInstruction::TopUpRent] Builder struct
+pub struct TopUpRent {
+    #[cfg(feature = "program-id-manually")]
+    /// Current program ID
+    pub program_id: solana_program::pubkey::Pubkey,
+    ///Curve account to top up.
+    pub curve: solana_program::pubkey::Pubkey,
+    ///Account paying the missing lamports.
+    pub payer: solana_program::pubkey::Pubkey,
 }
-impl<'a, const N: usize> TryFrom<&'a [u8; N]> for AlterCurveAccountIndexes {
-    type Error = usize;
-    fn try_from(indexes: &'a [u8; N]) -> Result<Self, Self::Error> {
-        Self::try_from_indexes(indexes)
+impl TopUpRent {
+    #[track_caller]
+    pub fn into_instruction(self) -> solana_program::instruction::Instruction {
+        let Self { #[cfg(feature = "program-id-manually")] program_id, curve, payer } = self;
+        #[cfg(not(feature = "program-id-manually"))]
+        let program_id = crate::ID;
+        #[allow(unused_mut)]
+        let mut accounts = vec![];
+        accounts.extend([solana_program::instruction::AccountMeta::new(curve, false)]);
+        accounts.extend([solana_program::instruction::AccountMeta::new(payer, true)]);
+        accounts
+            .extend([
+                solana_program::instruction::AccountMeta::new_readonly(
+                    solana_program::system_program::ID,
+                    false,
+                ),
+            ]);
+        let ix = CurvyInstruction::TopUpRent {};
+        solana_program::instruction::Instruction::new_with_borsh(
+            program_id,
+            &ix,
+            accounts,
+        )
     }
 }
-impl<const N: usize> TryFrom<[u8; N]> for AlterCurveAccountIndexes {
-    type Error = usize;
-    fn try_from(indexes: [u8; N]) -> Result<Self, Self::Error> {
-        Self::try_from_indexes(&indexes)
-    }
+///[CurvyInstruction::SetCurveAsset] Builder struct
+pub struct SetCurveAsset {
+    #[cfg(feature = "program-id-manually")]
+    /// Current program ID
+    pub program_id: solana_program::pubkey::Pubkey,
+    ///Curve account to associate.
+    pub curve: solana_program::pubkey::Pubkey,
+    ///Curve owner, pays for the asset link.
+    pub owner: solana_program::pubkey::Pubkey,
+    ///SPL Token or Token-2022 mint.
+    pub asset_mint: solana_program::pubkey::Pubkey,
+    ///Asset link to create, PDA of owner, mint and kind.
+    pub asset_link: solana_program::pubkey::Pubkey,
+    pub kind: u8,
 }
-impl TryFrom<Vec<u8>> for AlterCurveAccountIndexes {
-    type Error = usize;
-    fn try_from(indexes: Vec<u8>) -> Result<Self, Self::Error> {
-        Self::try_from_indexes(&indexes)
+impl SetCurveAsset {
+    #[track_caller]
+    pub fn into_instruction(self) -> solana_program::instruction::Instruction {
+        let Self {
+            #[cfg(feature = "program-id-manually")]
+            program_id,
+            curve,
+            owner,
+            asset_mint,
+            asset_link,
+            kind,
+        } = self;
+        #[cfg(not(feature = "program-id-manually"))]
+        let program_id = crate::ID;
+        #[allow(unused_mut)]
+        let mut accounts = vec![];
+        accounts.extend([solana_program::instruction::AccountMeta::new(curve, false)]);
+        accounts.extend([solana_program::instruction::AccountMeta::new(owner, true)]);
+        accounts
+            .extend([
+                solana_program::instruction::AccountMeta::new_readonly(asset_mint, false),
+            ]);
+        accounts
+            .extend([solana_program::instruction::AccountMeta::new(asset_link, false)]);
+        accounts
+            .extend([
+                solana_program::instruction::AccountMeta::new_readonly(
+                    solana_program::system_program::ID,
+                    false,
+                ),
+            ]);
+        let ix = CurvyInstruction::SetCurveAsset {
+            kind,
+        };
+        solana_program::instruction::Instruction::new_with_borsh(
+            program_id,
+            &ix,
+            accounts,
+        )
     }
 }
-/// [CurvyInstruction::DeleteCurve] instruction account indexes helper
-#[derive(Debug, PartialEq)]
-pub struct DeleteCurveAccountIndexes {
-    pub curve: usize,
-    pub owner: usize,
+///[CurvyInstruction::ClearCurveAsset] Builder struct
+pub struct ClearCurveAsset {
+    #[cfg(feature = "program-id-manually")]
+    /// Current program ID
+    pub program_id: solana_program::pubkey::Pubkey,
+    ///Curve account to update.
+    pub curve: solana_program::pubkey::Pubkey,
+    ///Curve owner, receives asset link rent.
+    pub owner: solana_program::pubkey::Pubkey,
+    ///Asset link of the curve.
+    pub asset_link: solana_program::pubkey::Pubkey,
 }
-impl DeleteCurveAccountIndexes {
-    pub const COUNT: usize = 2usize;
-    pub const CURVE: usize = 0usize;
-    pub const OWNER: usize = 1usize;
-    pub fn new_direct_order() -> Self {
-        let mut iter = std::iter::repeat(()).enumerate().map(|(idx, ())| idx);
-        Self {
-            curve: iter.next().unwrap(),
-            owner: iter.next().unwrap(),
-        }
+impl ClearCurveAsset {
+    #[track_caller]
+    pub fn into_instruction(self) -> solana_program::instruction::Instruction {
+        let Self {
+            #[cfg(feature = "program-id-manually")]
+            program_id,
+            curve,
+            owner,
+            asset_link,
+        } = self;
+        #[cfg(not(feature = "program-id-manually"))]
+        let program_id = crate::ID;
+        #[allow(unused_mut)]
+        let mut accounts = vec![];
+        accounts.extend([solana_program::instruction::AccountMeta::new(curve, false)]);
+        accounts.extend([solana_program::instruction::AccountMeta::new(owner, true)]);
+        accounts
+            .extend([solana_program::instruction::AccountMeta::new(asset_link, false)]);
+        let ix = CurvyInstruction::ClearCurveAsset {
+        };
+        solana_program::instruction::Instruction::new_with_borsh(
+            program_id,
+            &ix,
+            accounts,
+        )
     }
-    pub fn try_from_indexes<'a>(
-        indexes: impl IntoIterator<Item = &'a u8>,
-    ) -> Result<Self, usize> {
-        let mut iter = indexes.into_iter().map(|idx| (*idx) as usize);
-        let mut idx = 0_usize;
-        Ok(Self {
-            curve: {
-                idx += 1;
-                iter.next().ok_or(idx - 1)?
-            },
-            owner: {
-                idx += 1;
-                iter.next().ok_or(idx - 1)?
-            },
-        })
+}
+///[CurvyInstruction::MigrateCurve] Builder struct
+pub struct MigrateCurve {
+    #[cfg(feature = "program-id-manually")]
+    /// Current program ID
+    pub program_id: solana_program::pubkey::Pubkey,
+    ///Curve account to migrate.
+    pub curve: solana_program::pubkey::Pubkey,
+    ///Account paying rent of the grown account.
+    pub payer: solana_program::pubkey::Pubkey,
+}
+impl MigrateCurve {
+    #[track_caller]
+    pub fn into_instruction(self) -> solana_program::instruction::Instruction {
+        let Self { #[cfg(feature = "program-id-manually")] program_id, curve, payer } = self;
+        #[cfg(not(feature = "program-id-manually"))]
+        let program_id = crate::ID;
+        #[allow(unused_mut)]
+        let mut accounts = vec![];
+        accounts.extend([solana_program::instruction::AccountMeta::new(curve, false)]);
+        accounts.extend([solana_program::instruction::AccountMeta::new(payer, true)]);
+        accounts
+            .extend([
+                solana_program::instruction::AccountMeta::new_readonly(
+                    solana_program::system_program::ID,
+                    false,
+                ),
+            ]);
+        let ix = CurvyInstruction::MigrateCurve {};
+        solana_program::instruction::Instruction::new_with_borsh(
+            program_id,
+            &ix,
+            accounts,
+        )
     }
 }
-impl<'a> TryFrom<&'a [u8]> for DeleteCurveAccountIndexes {
-    type Error = usize;
-    fn try_from(indexes: &'a [u8]) -> Result<Self, Self::Error> {
-        Self::try_from_indexes(indexes)
+///[CurvyInstruction::SetCurveSeries] Builder struct
+pub struct SetCurveSeries {
+    #[cfg(feature = "program-id-manually")]
+    /// Current program ID
+    pub program_id: solana_program::pubkey::Pubkey,
+    ///Curve account to update.
+    pub curve: solana_program::pubkey::Pubkey,
+    ///Curve owner, pays for the curve series account.
+    pub owner: solana_program::pubkey::Pubkey,
+    ///Curve series account, PDA of the curve.
+    pub curve_series: solana_program::pubkey::Pubkey,
+    pub series: u8,
+    pub y: [CurveY; MAX_Y_CNT],
+}
+impl SetCurveSeries {
+    #[track_caller]
+    pub fn into_instruction(self) -> solana_program::instruction::Instruction {
+        let Self {
+            #[cfg(feature = "program-id-manually")]
+            program_id,
+            curve,
+            owner,
+            curve_series,
+            series,
+            y,
+        } = self;
+        #[cfg(not(feature = "program-id-manually"))]
+        let program_id = crate::ID;
+        #[allow(unused_mut)]
+        let mut accounts = vec![];
+        accounts.extend([solana_program::instruction::AccountMeta::new(curve, false)]);
+        accounts.extend([solana_program::instruction::AccountMeta::new(owner, true)]);
+        accounts
+            .extend([
+                solana_program::instruction::AccountMeta::new(curve_series, false),
+            ]);
+        accounts
+            .extend([
+                solana_program::instruction::AccountMeta::new_readonly(
+                    solana_program::system_program::ID,
+                    false,
+                ),
+            ]);
+        let ix = CurvyInstruction::SetCurveSeries {
+            series,
+            y,
+        };
+        solana_program::instruction::Instruction::new_with_borsh(
+            program_id,
+            &ix,
+            accounts,
+        )
+    }
+}
+///[CurvyInstruction::DeleteCurveSeries] Builder struct
+pub struct DeleteCurveSeries {
+    #[cfg(feature = "program-id-manually")]
+    /// Current program ID
+    pub program_id: solana_program::pubkey::Pubkey,
+    ///Curve account to update.
+    pub curve: solana_program::pubkey::Pubkey,
+    ///Curve owner, receives curve series account rent.
+    pub owner: solana_program::pubkey::Pubkey,
+    ///Curve series account of the curve.
+    pub curve_series: solana_program::pubkey::Pubkey,
+}
+impl DeleteCurveSeries {
+    #[track_caller]
+    pub fn into_instruction(self) -> solana_program::instruction::Instruction {
+        let Self {
+            #[cfg(feature = "program-id-manually")]
+            program_id,
+            curve,
+            owner,
+            curve_series,
+        } = self;
+        #[cfg(not(feature = "program-id-manually"))]
+        let program_id = crate::ID;
+        #[allow(unused_mut)]
+        let mut accounts = vec![];
+        accounts.extend([solana_program::instruction::AccountMeta::new(curve, false)]);
+        accounts.extend([solana_program::instruction::AccountMeta::new(owner, true)]);
+        accounts
+            .extend([
+                solana_program::instruction::AccountMeta::new(curve_series, false),
+            ]);
+        let ix = CurvyInstruction::DeleteCurveSeries {
+        };
+        solana_program::instruction::Instruction::new_with_borsh(
+            program_id,
+            &ix,
+            accounts,
+        )
+    }
+}
+///[CurvyInstruction::SetCurveConfidence] Builder struct
+pub struct SetCurveConfidence {
+    #[cfg(feature = "program-id-manually")]
+    /// Current program ID
+    pub program_id: solana_program::pubkey::Pubkey,
+    ///Curve account to update.
+    pub curve: solana_program::pubkey::Pubkey,
+    ///Curve owner, pays for the curve confidence account.
+    pub owner: solana_program::pubkey::Pubkey,
+    ///Curve confidence account, PDA of the curve.
+    pub curve_confidence: solana_program::pubkey::Pubkey,
+    pub confidence: [CurveConfidenceBps; MAX_Y_CNT],
+}
+impl SetCurveConfidence {
+    #[track_caller]
+    pub fn into_instruction(self) -> solana_program::instruction::Instruction {
+        let Self {
+            #[cfg(feature = "program-id-manually")]
+            program_id,
+            curve,
+            owner,
+            curve_confidence,
+            confidence,
+        } = self;
+        #[cfg(not(feature = "program-id-manually"))]
+        let program_id = crate::ID;
+        #[allow(unused_mut)]
+        let mut accounts = vec![];
+        accounts.extend([solana_program::instruction::AccountMeta::new(curve, false)]);
+        accounts.extend([solana_program::instruction::AccountMeta::new(owner, true)]);
+        accounts
+            .extend([
+                solana_program::instruction::AccountMeta::new(curve_confidence, false),
+            ]);
+        accounts
+            .extend([
+                solana_program::instruction::AccountMeta::new_readonly(
+                    solana_program::system_program::ID,
+                    false,
+                ),
+            ]);
+        let ix = CurvyInstruction::SetCurveConfidence {
+            confidence,
+        };
+        solana_program::instruction::Instruction::new_with_borsh(
+            program_id,
+            &ix,
+            accounts,
+        )
+    }
+}
+///[CurvyInstruction::DeleteCurveConfidence] Builder struct
+pub struct DeleteCurveConfidence {
+    #[cfg(feature = "program-id-manually")]
+    /// Current program ID
+    pub program_id: solana_program::pubkey::Pubkey,
+    ///Curve account to update.
+    pub curve: solana_program::pubkey::Pubkey,
+    ///Curve owner, receives curve confidence account rent.
+    pub owner: solana_program::pubkey::Pubkey,
+    ///Curve confidence account of the curve.
+    pub curve_confidence: solana_program::pubkey::Pubkey,
+}
+impl DeleteCurveConfidence {
+    #[track_caller]
+    pub fn into_instruction(self) -> solana_program::instruction::Instruction {
+        let Self {
+            #[cfg(feature = "program-id-manually")]
+            program_id,
+            curve,
+            owner,
+            curve_confidence,
+        } = self;
+        #[cfg(not(feature = "program-id-manually"))]
+        let program_id = crate::ID;
+        #[allow(unused_mut)]
+        let mut accounts = vec![];
+        accounts.extend([solana_program::instruction::AccountMeta::new(curve, false)]);
+        accounts.extend([solana_program::instruction::AccountMeta::new(owner, true)]);
+        accounts
+            .extend([
+                solana_program::instruction::AccountMeta::new(curve_confidence, false),
+            ]);
+        let ix = CurvyInstruction::DeleteCurveConfidence {
+        };
+        solana_program::instruction::Instruction::new_with_borsh(
+            program_id,
+            &ix,
+            accounts,
+        )
+    }
+}
+///[CurvyInstruction::CreateWideCurve] Builder struct
+pub struct CreateWideCurve {
+    #[cfg(feature = "program-id-manually")]
+    /// Current program ID
+    pub program_id: solana_program::pubkey::Pubkey,
+    ///WideCurve account to create.
+    pub curve: solana_program::pubkey::Pubkey,
+    ///Curve owner.
+    pub owner: solana_program::pubkey::Pubkey,
+    ///Program config, its creator allow-list is checked when it exists.
+    pub config: solana_program::pubkey::Pubkey,
+    ///Name link of the owner and the curve name, created or pointed to the curve.
+    pub name_link: solana_program::pubkey::Pubkey,
+    pub params: WideCurveParams,
+}
+impl CreateWideCurve {
+    #[track_caller]
+    pub fn into_instruction(self) -> solana_program::instruction::Instruction {
+        let Self {
+            #[cfg(feature = "program-id-manually")]
+            program_id,
+            curve,
+            owner,
+            config,
+            name_link,
+            params,
+        } = self;
+        #[cfg(not(feature = "program-id-manually"))]
+        let program_id = crate::ID;
+        #[allow(unused_mut)]
+        let mut accounts = vec![];
+        accounts.extend([solana_program::instruction::AccountMeta::new(curve, true)]);
+        accounts.extend([solana_program::instruction::AccountMeta::new(owner, true)]);
+        accounts
+            .extend([
+                solana_program::instruction::AccountMeta::new_readonly(config, false),
+            ]);
+        accounts
+            .extend([solana_program::instruction::AccountMeta::new(name_link, false)]);
+        accounts
+            .extend([
+                solana_program::instruction::AccountMeta::new_readonly(
+                    solana_program::system_program::ID,
+                    false,
+                ),
+            ]);
+        let ix = CurvyInstruction::CreateWideCurve {
+            params,
+        };
+        solana_program::instruction::Instruction::new_with_borsh(
+            program_id,
+            &ix,
+            accounts,
+        )
+    }
+}
+///[CurvyInstruction::AlterWideCurve] Builder struct
+pub struct AlterWideCurve {
+    #[cfg(feature = "program-id-manually")]
+    /// Current program ID
+    pub program_id: solana_program::pubkey::Pubkey,
+    ///WideCurve account to update.
+    pub curve: solana_program::pubkey::Pubkey,
+    ///Curve owner.
+    pub owner: solana_program::pubkey::Pubkey,
+    pub params: WideCurveParams,
+}
+impl AlterWideCurve {
+    #[track_caller]
+    pub fn into_instruction(self) -> solana_program::instruction::Instruction {
+        let Self {
+            #[cfg(feature = "program-id-manually")]
+            program_id,
+            curve,
+            owner,
+            params,
+        } = self;
+        #[cfg(not(feature = "program-id-manually"))]
+        let program_id = crate::ID;
+        #[allow(unused_mut)]
+        let mut accounts = vec![];
+        accounts.extend([solana_program::instruction::AccountMeta::new(curve, false)]);
+        accounts
+            .extend([
+                solana_program::instruction::AccountMeta::new_readonly(owner, true),
+            ]);
+        let ix = CurvyInstruction::AlterWideCurve {
+            params,
+        };
+        solana_program::instruction::Instruction::new_with_borsh(
+            program_id,
+            &ix,
+            accounts,
+        )
+    }
+}
+///[CurvyInstruction::SetCurveXSign] Builder struct
+pub struct SetCurveXSign {
+    #[cfg(feature = "program-id-manually")]
+    /// Current program ID
+    pub program_id: solana_program::pubkey::Pubkey,
+    ///Curve account to update.
+    pub curve: solana_program::pubkey::Pubkey,
+    ///Curve owner.
+    pub owner: solana_program::pubkey::Pubkey,
+    pub x0_negative: bool,
+}
+impl SetCurveXSign {
+    #[track_caller]
+    pub fn into_instruction(self) -> solana_program::instruction::Instruction {
+        let Self {
+            #[cfg(feature = "program-id-manually")]
+            program_id,
+            curve,
+            owner,
+            x0_negative,
+        } = self;
+        #[cfg(not(feature = "program-id-manually"))]
+        let program_id = crate::ID;
+        #[allow(unused_mut)]
+        let mut accounts = vec![];
+        accounts.extend([solana_program::instruction::AccountMeta::new(curve, false)]);
+        accounts
+            .extend([
+                solana_program::instruction::AccountMeta::new_readonly(owner, true),
+            ]);
+        let ix = CurvyInstruction::SetCurveXSign {
+            x0_negative,
+        };
+        solana_program::instruction::Instruction::new_with_borsh(
+            program_id,
+            &ix,
+            accounts,
+        )
+    }
+}
+///[CurvyInstruction::ProposeOwner] Builder struct
+pub struct ProposeOwner {
+    #[cfg(feature = "program-id-manually")]
+    /// Current program ID
+    pub program_id: solana_program::pubkey::Pubkey,
+    ///Curve account to transfer.
+    pub curve: solana_program::pubkey::Pubkey,
+    ///Current curve owner.
+    pub owner: solana_program::pubkey::Pubkey,
+    pub new_owner: Pubkey,
+}
+impl ProposeOwner {
+    #[track_caller]
+    pub fn into_instruction(self) -> solana_program::instruction::Instruction {
+        let Self {
+            #[cfg(feature = "program-id-manually")]
+            program_id,
+            curve,
+            owner,
+            new_owner,
+        } = self;
+        #[cfg(not(feature = "program-id-manually"))]
+        let program_id = crate::ID;
+        #[allow(unused_mut)]
+        let mut accounts = vec![];
+        accounts.extend([solana_program::instruction::AccountMeta::new(curve, false)]);
+        accounts
+            .extend([
+                solana_program::instruction::AccountMeta::new_readonly(owner, true),
+            ]);
+        let ix = CurvyInstruction::ProposeOwner {
+            new_owner,
+        };
+        solana_program::instruction::Instruction::new_with_borsh(
+            program_id,
+            &ix,
+            accounts,
+        )
+    }
+}
+///[CurvyInstruction::AcceptOwner] Builder struct
+pub struct AcceptOwner {
+    #[cfg(feature = "program-id-manually")]
+    /// Current program ID
+    pub program_id: solana_program::pubkey::Pubkey,
+    ///Curve account to accept.
+    pub curve: solana_program::pubkey::Pubkey,
+    ///Proposed owner, pays for its name link.
+    pub new_owner: solana_program::pubkey::Pubkey,
+    ///Current curve owner, receives the rent of its name link.
+    pub owner: solana_program::pubkey::Pubkey,
+    ///Name link of the current owner and the curve name, closed if it points to the curve.
+    pub name_link: solana_program::pubkey::Pubkey,
+    ///Name link of the new owner and the curve name, created or pointed to the curve.
+    pub new_name_link: solana_program::pubkey::Pubkey,
+}
+impl AcceptOwner {
+    #[track_caller]
+    pub fn into_instruction(self) -> solana_program::instruction::Instruction {
+        let Self {
+            #[cfg(feature = "program-id-manually")]
+            program_id,
+            curve,
+            new_owner,
+            owner,
+            name_link,
+            new_name_link,
+        } = self;
+        #[cfg(not(feature = "program-id-manually"))]
+        let program_id = crate::ID;
+        #[allow(unused_mut)]
+        let mut accounts = vec![];
+        accounts.extend([solana_program::instruction::AccountMeta::new(curve, false)]);
+        accounts
+            .extend([solana_program::instruction::AccountMeta::new(new_owner, true)]);
+        accounts.extend([solana_program::instruction::AccountMeta::new(owner, false)]);
+        accounts
+            .extend([solana_program::instruction::AccountMeta::new(name_link, false)]);
+        accounts
+            .extend([
+                solana_program::instruction::AccountMeta::new(new_name_link, false),
+            ]);
+        accounts
+            .extend([
+                solana_program::instruction::AccountMeta::new_readonly(
+                    solana_program::system_program::ID,
+                    false,
+                ),
+            ]);
+        let ix = CurvyInstruction::AcceptOwner {};
+        solana_program::instruction::Instruction::new_with_borsh(
+            program_id,
+            &ix,
+            accounts,
+        )
+    }
+}
+///[CurvyInstruction::CreateCurvePda] Builder struct
+pub struct CreateCurvePda {
+    #[cfg(feature = "program-id-manually")]
+    /// Current program ID
+    pub program_id: solana_program::pubkey::Pubkey,
+    ///Curve account to create, PDA of owner and name.
+    pub curve: solana_program::pubkey::Pubkey,
+    ///Curve owner.
+    pub owner: solana_program::pubkey::Pubkey,
+    ///Program config, its creator allow-list is checked when it exists.
+    pub config: solana_program::pubkey::Pubkey,
+    ///Name link of the owner and the curve name, created or pointed to the curve.
+    pub name_link: solana_program::pubkey::Pubkey,
+    pub params: CurveParams,
+}
+impl CreateCurvePda {
+    #[track_caller]
+    pub fn into_instruction(self) -> solana_program::instruction::Instruction {
+        let Self {
+            #[cfg(feature = "program-id-manually")]
+            program_id,
+            curve,
+            owner,
+            config,
+            name_link,
+            params,
+        } = self;
+        #[cfg(not(feature = "program-id-manually"))]
+        let program_id = crate::ID;
+        #[allow(unused_mut)]
+        let mut accounts = vec![];
+        accounts.extend([solana_program::instruction::AccountMeta::new(curve, false)]);
+        accounts.extend([solana_program::instruction::AccountMeta::new(owner, true)]);
+        accounts
+            .extend([
+                solana_program::instruction::AccountMeta::new_readonly(config, false),
+            ]);
+        accounts
+            .extend([solana_program::instruction::AccountMeta::new(name_link, false)]);
+        accounts
+            .extend([
+                solana_program::instruction::AccountMeta::new_readonly(
+                    solana_program::system_program::ID,
+                    false,
+                ),
+            ]);
+        let ix = CurvyInstruction::CreateCurvePda {
+            params,
+        };
+        solana_program::instruction::Instruction::new_with_borsh(
+            program_id,
+            &ix,
+            accounts,
+        )
+    }
+}
+///[CurvyInstruction::FreezeCurve] Builder struct
+pub struct FreezeCurve {
+    #[cfg(feature = "program-id-manually")]
+    /// Current program ID
+    pub program_id: solana_program::pubkey::Pubkey,
+    ///Curve account to freeze.
+    pub curve: solana_program::pubkey::Pubkey,
+    ///Curve owner.
+    pub owner: solana_program::pubkey::Pubkey,
+}
+impl FreezeCurve {
+    #[track_caller]
+    pub fn into_instruction(self) -> solana_program::instruction::Instruction {
+        let Self { #[cfg(feature = "program-id-manually")] program_id, curve, owner } = self;
+        #[cfg(not(feature = "program-id-manually"))]
+        let program_id = crate::ID;
+        #[allow(unused_mut)]
+        let mut accounts = vec![];
+        accounts.extend([solana_program::instruction::AccountMeta::new(curve, false)]);
+        accounts
+            .extend([
+                solana_program::instruction::AccountMeta::new_readonly(owner, true),
+            ]);
+        let ix = CurvyInstruction::FreezeCurve {};
+        solana_program::instruction::Instruction::new_with_borsh(
+            program_id,
+            &ix,
+            accounts,
+        )
+    }
+}
+///[CurvyInstruction::ResizeCurve] Builder struct
+pub struct ResizeCurve {
+    #[cfg(feature = "program-id-manually")]
+    /// Current program ID
+    pub program_id: solana_program::pubkey::Pubkey,
+    ///Curve account to resize.
+    pub curve: solana_program::pubkey::Pubkey,
+    ///Curve owner, pays rent of the grown account.
+    pub owner: solana_program::pubkey::Pubkey,
+    pub y_capacity: u16,
+}
+impl ResizeCurve {
+    #[track_caller]
+    pub fn into_instruction(self) -> solana_program::instruction::Instruction {
+        let Self {
+            #[cfg(feature = "program-id-manually")]
+            program_id,
+            curve,
+            owner,
+            y_capacity,
+        } = self;
+        #[cfg(not(feature = "program-id-manually"))]
+        let program_id = crate::ID;
+        #[allow(unused_mut)]
+        let mut accounts = vec![];
+        accounts.extend([solana_program::instruction::AccountMeta::new(curve, false)]);
+        accounts.extend([solana_program::instruction::AccountMeta::new(owner, true)]);
+        accounts
+            .extend([
+                solana_program::instruction::AccountMeta::new_readonly(
+                    solana_program::system_program::ID,
+                    false,
+                ),
+            ]);
+        let ix = CurvyInstruction::ResizeCurve {
+            y_capacity,
+        };
+        solana_program::instruction::Instruction::new_with_borsh(
+            program_id,
+            &ix,
+            accounts,
+        )
+    }
+}
+///[CurvyInstruction::SetCurveTail] Builder struct
+pub struct SetCurveTail {
+    #[cfg(feature = "program-id-manually")]
+    /// Current program ID
+    pub program_id: solana_program::pubkey::Pubkey,
+    ///Curve account to update.
+    pub curve: solana_program::pubkey::Pubkey,
+    ///Curve owner.
+    pub owner: solana_program::pubkey::Pubkey,
+    pub start: u16,
+    pub y: Vec<CurveY>,
+}
+impl SetCurveTail {
+    #[track_caller]
+    pub fn into_instruction(self) -> solana_program::instruction::Instruction {
+        let Self {
+            #[cfg(feature = "program-id-manually")]
+            program_id,
+            curve,
+            owner,
+            start,
+            y,
+        } = self;
+        #[cfg(not(feature = "program-id-manually"))]
+        let program_id = crate::ID;
+        #[allow(unused_mut)]
+        let mut accounts = vec![];
+        accounts.extend([solana_program::instruction::AccountMeta::new(curve, false)]);
+        accounts
+            .extend([
+                solana_program::instruction::AccountMeta::new_readonly(owner, true),
+            ]);
+        let ix = CurvyInstruction::SetCurveTail {
+            start,
+            y,
+        };
+        solana_program::instruction::Instruction::new_with_borsh(
+            program_id,
+            &ix,
+            accounts,
+        )
+    }
+}
+///[CurvyInstruction::CreateGridCurve] Builder struct
+pub struct CreateGridCurve {
+    #[cfg(feature = "program-id-manually")]
+    /// Current program ID
+    pub program_id: solana_program::pubkey::Pubkey,
+    ///GridCurve account to create.
+    pub curve: solana_program::pubkey::Pubkey,
+    ///Curve owner.
+    pub owner: solana_program::pubkey::Pubkey,
+    ///Program config, its creator allow-list is checked when it exists.
+    pub config: solana_program::pubkey::Pubkey,
+    ///Name link of the owner and the curve name, created or pointed to the curve.
+    pub name_link: solana_program::pubkey::Pubkey,
+    pub params: GridCurveParams,
+}
+impl CreateGridCurve {
+    #[track_caller]
+    pub fn into_instruction(self) -> solana_program::instruction::Instruction {
+        let Self {
+            #[cfg(feature = "program-id-manually")]
+            program_id,
+            curve,
+            owner,
+            config,
+            name_link,
+            params,
+        } = self;
+        #[cfg(not(feature = "program-id-manually"))]
+        let program_id = crate::ID;
+        #[allow(unused_mut)]
+        let mut accounts = vec![];
+        accounts.extend([solana_program::instruction::AccountMeta::new(curve, true)]);
+        accounts.extend([solana_program::instruction::AccountMeta::new(owner, true)]);
+        accounts
+            .extend([
+                solana_program::instruction::AccountMeta::new_readonly(config, false),
+            ]);
+        accounts
+            .extend([solana_program::instruction::AccountMeta::new(name_link, false)]);
+        accounts
+            .extend([
+                solana_program::instruction::AccountMeta::new_readonly(
+                    solana_program::system_program::ID,
+                    false,
+                ),
+            ]);
+        let ix = CurvyInstruction::CreateGridCurve {
+            params,
+        };
+        solana_program::instruction::Instruction::new_with_borsh(
+            program_id,
+            &ix,
+            accounts,
+        )
+    }
+}
+///[CurvyInstruction::AlterGridCurve] Builder struct
+pub struct AlterGridCurve {
+    #[cfg(feature = "program-id-manually")]
+    /// Current program ID
+    pub program_id: solana_program::pubkey::Pubkey,
+    ///GridCurve account to update.
+    pub curve: solana_program::pubkey::Pubkey,
+    ///Curve owner.
+    pub owner: solana_program::pubkey::Pubkey,
+    pub params: GridCurveParams,
+}
+impl AlterGridCurve {
+    #[track_caller]
+    pub fn into_instruction(self) -> solana_program::instruction::Instruction {
+        let Self {
+            #[cfg(feature = "program-id-manually")]
+            program_id,
+            curve,
+            owner,
+            params,
+        } = self;
+        #[cfg(not(feature = "program-id-manually"))]
+        let program_id = crate::ID;
+        #[allow(unused_mut)]
+        let mut accounts = vec![];
+        accounts.extend([solana_program::instruction::AccountMeta::new(curve, false)]);
+        accounts
+            .extend([
+                solana_program::instruction::AccountMeta::new_readonly(owner, true),
+            ]);
+        let ix = CurvyInstruction::AlterGridCurve {
+            params,
+        };
+        solana_program::instruction::Instruction::new_with_borsh(
+            program_id,
+            &ix,
+            accounts,
+        )
+    }
+}
+///[CurvyInstruction::SetCurveYSign] Builder struct
+pub struct SetCurveYSign {
+    #[cfg(feature = "program-id-manually")]
+    /// Current program ID
+    pub program_id: solana_program::pubkey::Pubkey,
+    ///Curve account to update.
+    pub curve: solana_program::pubkey::Pubkey,
+    ///Curve owner.
+    pub owner: solana_program::pubkey::Pubkey,
+    pub y_signed: bool,
+}
+impl SetCurveYSign {
+    #[track_caller]
+    pub fn into_instruction(self) -> solana_program::instruction::Instruction {
+        let Self {
+            #[cfg(feature = "program-id-manually")]
+            program_id,
+            curve,
+            owner,
+            y_signed,
+        } = self;
+        #[cfg(not(feature = "program-id-manually"))]
+        let program_id = crate::ID;
+        #[allow(unused_mut)]
+        let mut accounts = vec![];
+        accounts.extend([solana_program::instruction::AccountMeta::new(curve, false)]);
+        accounts
+            .extend([
+                solana_program::instruction::AccountMeta::new_readonly(owner, true),
+            ]);
+        let ix = CurvyInstruction::SetCurveYSign {
+            y_signed,
+        };
+        solana_program::instruction::Instruction::new_with_borsh(
+            program_id,
+            &ix,
+            accounts,
+        )
+    }
+}
+///[CurvyInstruction::SetCurveStatus] Builder struct
+pub struct SetCurveStatus {
+    #[cfg(feature = "program-id-manually")]
+    /// Current program ID
+    pub program_id: solana_program::pubkey::Pubkey,
+    ///Curve account to update.
+    pub curve: solana_program::pubkey::Pubkey,
+    ///Curve owner.
+    pub owner: solana_program::pubkey::Pubkey,
+    pub status: u8,
+}
+impl SetCurveStatus {
+    #[track_caller]
+    pub fn into_instruction(self) -> solana_program::instruction::Instruction {
+        let Self {
+            #[cfg(feature = "program-id-manually")]
+            program_id,
+            curve,
+            owner,
+            status,
+        } = self;
+        #[cfg(not(feature = "program-id-manually"))]
+        let program_id = crate::ID;
+        #[allow(unused_mut)]
+        let mut accounts = vec![];
+        accounts.extend([solana_program::instruction::AccountMeta::new(curve, false)]);
+        accounts
+            .extend([
+                solana_program::instruction::AccountMeta::new_readonly(owner, true),
+            ]);
+        let ix = CurvyInstruction::SetCurveStatus {
+            status,
+        };
+        solana_program::instruction::Instruction::new_with_borsh(
+            program_id,
+            &ix,
+            accounts,
+        )
+    }
+}
+///[CurvyInstruction::PatchY] Builder struct
+pub struct PatchY {
+    #[cfg(feature = "program-id-manually")]
+    /// Current program ID
+    pub program_id: solana_program::pubkey::Pubkey,
+    ///Curve account to update.
+    pub curve: solana_program::pubkey::Pubkey,
+    ///Curve owner or its delegate.
+    pub owner: solana_program::pubkey::Pubkey,
+    pub start_index: u8,
+    pub values: Vec<CurveY>,
+}
+impl PatchY {
+    #[track_caller]
+    pub fn into_instruction(self) -> solana_program::instruction::Instruction {
+        let Self {
+            #[cfg(feature = "program-id-manually")]
+            program_id,
+            curve,
+            owner,
+            start_index,
+            values,
+        } = self;
+        #[cfg(not(feature = "program-id-manually"))]
+        let program_id = crate::ID;
+        #[allow(unused_mut)]
+        let mut accounts = vec![];
+        accounts.extend([solana_program::instruction::AccountMeta::new(curve, false)]);
+        accounts
+            .extend([
+                solana_program::instruction::AccountMeta::new_readonly(owner, true),
+            ]);
+        let ix = CurvyInstruction::PatchY {
+            start_index,
+            values,
+        };
+        solana_program::instruction::Instruction::new_with_borsh(
+            program_id,
+            &ix,
+            accounts,
+        )
+    }
+}
+///[CurvyInstruction::MarkForDeletion] Builder struct
+pub struct MarkForDeletion {
+    #[cfg(feature = "program-id-manually")]
+    /// Current program ID
+    pub program_id: solana_program::pubkey::Pubkey,
+    ///Curve account to mark.
+    pub curve: solana_program::pubkey::Pubkey,
+    ///Curve owner.
+    pub owner: solana_program::pubkey::Pubkey,
+}
+impl MarkForDeletion {
+    #[track_caller]
+    pub fn into_instruction(self) -> solana_program::instruction::Instruction {
+        let Self { #[cfg(feature = "program-id-manually")] program_id, curve, owner } = self;
+        #[cfg(not(feature = "program-id-manually"))]
+        let program_id = crate::ID;
+        #[allow(unused_mut)]
+        let mut accounts = vec![];
+        accounts.extend([solana_program::instruction::AccountMeta::new(curve, false)]);
+        accounts
+            .extend([
+                solana_program::instruction::AccountMeta::new_readonly(owner, true),
+            ]);
+        let ix = CurvyInstruction::MarkForDeletion {
+        };
+        solana_program::instruction::Instruction::new_with_borsh(
+            program_id,
+            &ix,
+            accounts,
+        )
+    }
+}
+///[CurvyInstruction::CancelDeletion] Builder struct
+pub struct CancelDeletion {
+    #[cfg(feature = "program-id-manually")]
+    /// Current program ID
+    pub program_id: solana_program::pubkey::Pubkey,
+    ///Curve account to unmark.
+    pub curve: solana_program::pubkey::Pubkey,
+    ///Curve owner.
+    pub owner: solana_program::pubkey::Pubkey,
+}
+impl CancelDeletion {
+    #[track_caller]
+    pub fn into_instruction(self) -> solana_program::instruction::Instruction {
+        let Self { #[cfg(feature = "program-id-manually")] program_id, curve, owner } = self;
+        #[cfg(not(feature = "program-id-manually"))]
+        let program_id = crate::ID;
+        #[allow(unused_mut)]
+        let mut accounts = vec![];
+        accounts.extend([solana_program::instruction::AccountMeta::new(curve, false)]);
+        accounts
+            .extend([
+                solana_program::instruction::AccountMeta::new_readonly(owner, true),
+            ]);
+        let ix = CurvyInstruction::CancelDeletion {
+        };
+        solana_program::instruction::Instruction::new_with_borsh(
+            program_id,
+            &ix,
+            accounts,
+        )
+    }
+}
+///[CurvyInstruction::EvaluateCurve] Builder struct
+pub struct EvaluateCurve {
+    #[cfg(feature = "program-id-manually")]
+    /// Current program ID
+    pub program_id: solana_program::pubkey::Pubkey,
+    ///Curve account to evaluate.
+    pub curve: solana_program::pubkey::Pubkey,
+    ///CurveEvaluation account to store the result in.
+    pub result: solana_program::pubkey::Pubkey,
+    ///Authority of the result account.
+    pub authority: solana_program::pubkey::Pubkey,
+    pub x: i64,
+}
+impl EvaluateCurve {
+    #[track_caller]
+    pub fn into_instruction(self) -> solana_program::instruction::Instruction {
+        let Self {
+            #[cfg(feature = "program-id-manually")]
+            program_id,
+            curve,
+            result,
+            authority,
+            x,
+        } = self;
+        #[cfg(not(feature = "program-id-manually"))]
+        let program_id = crate::ID;
+        #[allow(unused_mut)]
+        let mut accounts = vec![];
+        accounts
+            .extend([
+                solana_program::instruction::AccountMeta::new_readonly(curve, false),
+            ]);
+        accounts.extend([solana_program::instruction::AccountMeta::new(result, false)]);
+        accounts
+            .extend([
+                solana_program::instruction::AccountMeta::new_readonly(authority, true),
+            ]);
+        let ix = CurvyInstruction::EvaluateCurve {
+            x,
+        };
+        solana_program::instruction::Instruction::new_with_borsh(
+            program_id,
+            &ix,
+            accounts,
+        )
+    }
+}
+///[CurvyInstruction::SetAlterDelay] Builder struct
+pub struct SetAlterDelay {
+    #[cfg(feature = "program-id-manually")]
+    /// Current program ID
+    pub program_id: solana_program::pubkey::Pubkey,
+    ///Curve account to timelock.
+    pub curve: solana_program::pubkey::Pubkey,
+    ///Curve owner.
+    pub owner: solana_program::pubkey::Pubkey,
+    pub slots: u64,
+}
+impl SetAlterDelay {
+    #[track_caller]
+    pub fn into_instruction(self) -> solana_program::instruction::Instruction {
+        let Self {
+            #[cfg(feature = "program-id-manually")]
+            program_id,
+            curve,
+            owner,
+            slots,
+        } = self;
+        #[cfg(not(feature = "program-id-manually"))]
+        let program_id = crate::ID;
+        #[allow(unused_mut)]
+        let mut accounts = vec![];
+        accounts.extend([solana_program::instruction::AccountMeta::new(curve, false)]);
+        accounts
+            .extend([
+                solana_program::instruction::AccountMeta::new_readonly(owner, true),
+            ]);
+        let ix = CurvyInstruction::SetAlterDelay {
+            slots,
+        };
+        solana_program::instruction::Instruction::new_with_borsh(
+            program_id,
+            &ix,
+            accounts,
+        )
+    }
+}
+///[CurvyInstruction::ProposeAlter] Builder struct
+pub struct ProposeAlter {
+    #[cfg(feature = "program-id-manually")]
+    /// Current program ID
+    pub program_id: solana_program::pubkey::Pubkey,
+    ///Curve account to alter.
+    pub curve: solana_program::pubkey::Pubkey,
+    ///Curve owner, pays for the pending alter account.
+    pub owner: solana_program::pubkey::Pubkey,
+    ///Pending alter account, PDA of the curve.
+    pub pending_alter: solana_program::pubkey::Pubkey,
+    pub params: CurveParams,
+}
+impl ProposeAlter {
+    #[track_caller]
+    pub fn into_instruction(self) -> solana_program::instruction::Instruction {
+        let Self {
+            #[cfg(feature = "program-id-manually")]
+            program_id,
+            curve,
+            owner,
+            pending_alter,
+            params,
+        } = self;
+        #[cfg(not(feature = "program-id-manually"))]
+        let program_id = crate::ID;
+        #[allow(unused_mut)]
+        let mut accounts = vec![];
+        accounts
+            .extend([
+                solana_program::instruction::AccountMeta::new(curve, false),
+            ]);
+        accounts.extend([solana_program::instruction::AccountMeta::new(owner, true)]);
+        accounts
+            .extend([
+                solana_program::instruction::AccountMeta::new(pending_alter, false),
+            ]);
+        accounts
+            .extend([
+                solana_program::instruction::AccountMeta::new_readonly(
+                    solana_program::system_program::ID,
+                    false,
+                ),
+            ]);
+        let ix = CurvyInstruction::ProposeAlter {
+            params,
+        };
+        solana_program::instruction::Instruction::new_with_borsh(
+            program_id,
+            &ix,
+            accounts,
+        )
+    }
+}
+///[CurvyInstruction::ApplyAlter] Builder struct
+pub struct ApplyAlter {
+    #[cfg(feature = "program-id-manually")]
+    /// Current program ID
+    pub program_id: solana_program::pubkey::Pubkey,
+    ///Curve account to alter.
+    pub curve: solana_program::pubkey::Pubkey,
+    ///Curve owner, receives lamports of the pending alter account.
+    pub owner: solana_program::pubkey::Pubkey,
+    ///Pending alter account of the curve.
+    pub pending_alter: solana_program::pubkey::Pubkey,
+}
+impl ApplyAlter {
+    #[track_caller]
+    pub fn into_instruction(self) -> solana_program::instruction::Instruction {
+        let Self {
+            #[cfg(feature = "program-id-manually")]
+            program_id,
+            curve,
+            owner,
+            pending_alter,
+        } = self;
+        #[cfg(not(feature = "program-id-manually"))]
+        let program_id = crate::ID;
+        #[allow(unused_mut)]
+        let mut accounts = vec![];
+        accounts.extend([solana_program::instruction::AccountMeta::new(curve, false)]);
+        accounts.extend([solana_program::instruction::AccountMeta::new(owner, true)]);
+        accounts
+            .extend([
+                solana_program::instruction::AccountMeta::new(pending_alter, false),
+            ]);
+        let ix = CurvyInstruction::ApplyAlter {};
+        solana_program::instruction::Instruction::new_with_borsh(
+            program_id,
+            &ix,
+            accounts,
+        )
+    }
+}
+///[CurvyInstruction::CancelAlter] Builder struct
+pub struct CancelAlter {
+    #[cfg(feature = "program-id-manually")]
+    /// Current program ID
+    pub program_id: solana_program::pubkey::Pubkey,
+    ///Curve account of the proposal.
+    pub curve: solana_program::pubkey::Pubkey,
+    ///Curve owner, receives lamports of the pending alter account.
+    pub owner: solana_program::pubkey::Pubkey,
+    ///Pending alter account of the curve.
+    pub pending_alter: solana_program::pubkey::Pubkey,
+}
+impl CancelAlter {
+    #[track_caller]
+    pub fn into_instruction(self) -> solana_program::instruction::Instruction {
+        let Self {
+            #[cfg(feature = "program-id-manually")]
+            program_id,
+            curve,
+            owner,
+            pending_alter,
+        } = self;
+        #[cfg(not(feature = "program-id-manually"))]
+        let program_id = crate::ID;
+        #[allow(unused_mut)]
+        let mut accounts = vec![];
+        accounts
+            .extend([
+                solana_program::instruction::AccountMeta::new(curve, false),
+            ]);
+        accounts.extend([solana_program::instruction::AccountMeta::new(owner, true)]);
+        accounts
+            .extend([
+                solana_program::instruction::AccountMeta::new(pending_alter, false),
+            ]);
+        let ix = CurvyInstruction::CancelAlter {};
+        solana_program::instruction::Instruction::new_with_borsh(
+            program_id,
+            &ix,
+            accounts,
+        )
+    }
+}
+///[CurvyInstruction::AddDelegate] Builder struct
+pub struct AddDelegate {
+    #[cfg(feature = "program-id-manually")]
+    /// Current program ID
+    pub program_id: solana_program::pubkey::Pubkey,
+    ///Curve account to delegate.
+    pub curve: solana_program::pubkey::Pubkey,
+    ///Curve owner.
+    pub owner: solana_program::pubkey::Pubkey,
+    pub delegate: Pubkey,
+}
+impl AddDelegate {
+    #[track_caller]
+    pub fn into_instruction(self) -> solana_program::instruction::Instruction {
+        let Self {
+            #[cfg(feature = "program-id-manually")]
+            program_id,
+            curve,
+            owner,
+            delegate,
+        } = self;
+        #[cfg(not(feature = "program-id-manually"))]
+        let program_id = crate::ID;
+        #[allow(unused_mut)]
+        let mut accounts = vec![];
+        accounts.extend([solana_program::instruction::AccountMeta::new(curve, false)]);
+        accounts
+            .extend([
+                solana_program::instruction::AccountMeta::new_readonly(owner, true),
+            ]);
+        let ix = CurvyInstruction::AddDelegate {
+            delegate,
+        };
+        solana_program::instruction::Instruction::new_with_borsh(
+            program_id,
+            &ix,
+            accounts,
+        )
+    }
+}
+///[CurvyInstruction::RemoveDelegate] Builder struct
+pub struct RemoveDelegate {
+    #[cfg(feature = "program-id-manually")]
+    /// Current program ID
+    pub program_id: solana_program::pubkey::Pubkey,
+    ///Curve account of the delegate.
+    pub curve: solana_program::pubkey::Pubkey,
+    ///Curve owner.
+    pub owner: solana_program::pubkey::Pubkey,
+    pub delegate: Pubkey,
+}
+impl RemoveDelegate {
+    #[track_caller]
+    pub fn into_instruction(self) -> solana_program::instruction::Instruction {
+        let Self {
+            #[cfg(feature = "program-id-manually")]
+            program_id,
+            curve,
+            owner,
+            delegate,
+        } = self;
+        #[cfg(not(feature = "program-id-manually"))]
+        let program_id = crate::ID;
+        #[allow(unused_mut)]
+        let mut accounts = vec![];
+        accounts.extend([solana_program::instruction::AccountMeta::new(curve, false)]);
+        accounts
+            .extend([
+                solana_program::instruction::AccountMeta::new_readonly(owner, true),
+            ]);
+        let ix = CurvyInstruction::RemoveDelegate {
+            delegate,
+        };
+        solana_program::instruction::Instruction::new_with_borsh(
+            program_id,
+            &ix,
+            accounts,
+        )
+    }
+}
+///[CurvyInstruction::InitConfig] Builder struct
+pub struct InitConfig {
+    #[cfg(feature = "program-id-manually")]
+    /// Current program ID
+    pub program_id: solana_program::pubkey::Pubkey,
+    ///Config account to create, PDA of the config seed.
+    pub config: solana_program::pubkey::Pubkey,
+    ///Upgrade authority of the program, pays for the config account.
+    pub admin: solana_program::pubkey::Pubkey,
+    ///ProgramData account of the program.
+    pub program_data: solana_program::pubkey::Pubkey,
+    pub creators: Vec<Pubkey>,
+}
+impl InitConfig {
+    #[track_caller]
+    pub fn into_instruction(self) -> solana_program::instruction::Instruction {
+        let Self {
+            #[cfg(feature = "program-id-manually")]
+            program_id,
+            config,
+            admin,
+            program_data,
+            creators,
+        } = self;
+        #[cfg(not(feature = "program-id-manually"))]
+        let program_id = crate::ID;
+        #[allow(unused_mut)]
+        let mut accounts = vec![];
+        accounts.extend([solana_program::instruction::AccountMeta::new(config, false)]);
+        accounts.extend([solana_program::instruction::AccountMeta::new(admin, true)]);
+        accounts
+            .extend([
+                solana_program::instruction::AccountMeta::new_readonly(
+                    program_data,
+                    false,
+                ),
+            ]);
+        accounts
+            .extend([
+                solana_program::instruction::AccountMeta::new_readonly(
+                    solana_program::system_program::ID,
+                    false,
+                ),
+            ]);
+        let ix = CurvyInstruction::InitConfig {
+            creators,
+        };
+        solana_program::instruction::Instruction::new_with_borsh(
+            program_id,
+            &ix,
+            accounts,
+        )
+    }
+}
+///[CurvyInstruction::SetConfig] Builder struct
+pub struct SetConfig {
+    #[cfg(feature = "program-id-manually")]
+    /// Current program ID
+    pub program_id: solana_program::pubkey::Pubkey,
+    ///Config account to update.
+    pub config: solana_program::pubkey::Pubkey,
+    ///Current config admin.
+    pub admin: solana_program::pubkey::Pubkey,
+    pub new_admin: Pubkey,
+    pub creators: Vec<Pubkey>,
+}
+impl SetConfig {
+    #[track_caller]
+    pub fn into_instruction(self) -> solana_program::instruction::Instruction {
+        let Self {
+            #[cfg(feature = "program-id-manually")]
+            program_id,
+            config,
+            admin,
+            new_admin,
+            creators,
+        } = self;
+        #[cfg(not(feature = "program-id-manually"))]
+        let program_id = crate::ID;
+        #[allow(unused_mut)]
+        let mut accounts = vec![];
+        accounts.extend([solana_program::instruction::AccountMeta::new(config, false)]);
+        accounts
+            .extend([
+                solana_program::instruction::AccountMeta::new_readonly(admin, true),
+            ]);
+        let ix = CurvyInstruction::SetConfig {
+            new_admin,
+            creators,
+        };
+        solana_program::instruction::Instruction::new_with_borsh(
+            program_id,
+            &ix,
+            accounts,
+        )
+    }
+}
+///[CurvyInstruction::SetCurveOwners] Builder struct
+pub struct SetCurveOwners {
+    #[cfg(feature = "program-id-manually")]
+    /// Current program ID
+    pub program_id: solana_program::pubkey::Pubkey,
+    ///Curve account to update.
+    pub curve: solana_program::pubkey::Pubkey,
+    ///Curve owner, any owner of co-owned curve. Pays for the curve owners account.
+    pub owner: solana_program::pubkey::Pubkey,
+    ///Curve owners account, PDA of the curve.
+    pub curve_owners: solana_program::pubkey::Pubkey,
+    pub threshold: u8,
+    pub owners: Vec<Pubkey>,
+}
+impl SetCurveOwners {
+    #[track_caller]
+    pub fn into_instruction(self) -> solana_program::instruction::Instruction {
+        let Self {
+            #[cfg(feature = "program-id-manually")]
+            program_id,
+            curve,
+            owner,
+            curve_owners,
+            threshold,
+            owners,
+        } = self;
+        #[cfg(not(feature = "program-id-manually"))]
+        let program_id = crate::ID;
+        #[allow(unused_mut)]
+        let mut accounts = vec![];
+        accounts.extend([solana_program::instruction::AccountMeta::new(curve, false)]);
+        accounts.extend([solana_program::instruction::AccountMeta::new(owner, true)]);
+        accounts
+            .extend([
+                solana_program::instruction::AccountMeta::new(curve_owners, false),
+            ]);
+        accounts
+            .extend([
+                solana_program::instruction::AccountMeta::new_readonly(
+                    solana_program::system_program::ID,
+                    false,
+                ),
+            ]);
+        let ix = CurvyInstruction::SetCurveOwners {
+            threshold,
+            owners,
+        };
+        solana_program::instruction::Instruction::new_with_borsh(
+            program_id,
+            &ix,
+            accounts,
+        )
+    }
+}
+///[CurvyInstruction::SetCurveExtension] Builder struct
+pub struct SetCurveExtension {
+    #[cfg(feature = "program-id-manually")]
+    /// Current program ID
+    pub program_id: solana_program::pubkey::Pubkey,
+    ///Curve account to update.
+    pub curve: solana_program::pubkey::Pubkey,
+    ///Curve owner, pays rent of the grown account.
+    pub owner: solana_program::pubkey::Pubkey,
+    pub extension_type: u16,
+    pub value: Vec<u8>,
+}
+impl SetCurveExtension {
+    #[track_caller]
+    pub fn into_instruction(self) -> solana_program::instruction::Instruction {
+        let Self {
+            #[cfg(feature = "program-id-manually")]
+            program_id,
+            curve,
+            owner,
+            extension_type,
+            value,
+        } = self;
+        #[cfg(not(feature = "program-id-manually"))]
+        let program_id = crate::ID;
+        #[allow(unused_mut)]
+        let mut accounts = vec![];
+        accounts.extend([solana_program::instruction::AccountMeta::new(curve, false)]);
+        accounts.extend([solana_program::instruction::AccountMeta::new(owner, true)]);
+        accounts
+            .extend([
+                solana_program::instruction::AccountMeta::new_readonly(
+                    solana_program::system_program::ID,
+                    false,
+                ),
+            ]);
+        let ix = CurvyInstruction::SetCurveExtension {
+            extension_type,
+            value,
+        };
+        solana_program::instruction::Instruction::new_with_borsh(
+            program_id,
+            &ix,
+            accounts,
+        )
+    }
+}
+///[CurvyInstruction::SetCurveYTransform] Builder struct
+pub struct SetCurveYTransform {
+    #[cfg(feature = "program-id-manually")]
+    /// Current program ID
+    pub program_id: solana_program::pubkey::Pubkey,
+    ///Curve account to update.
+    pub curve: solana_program::pubkey::Pubkey,
+    ///Curve owner.
+    pub owner: solana_program::pubkey::Pubkey,
+    pub y_transform: u8,
+}
+impl SetCurveYTransform {
+    #[track_caller]
+    pub fn into_instruction(self) -> solana_program::instruction::Instruction {
+        let Self {
+            #[cfg(feature = "program-id-manually")]
+            program_id,
+            curve,
+            owner,
+            y_transform,
+        } = self;
+        #[cfg(not(feature = "program-id-manually"))]
+        let program_id = crate::ID;
+        #[allow(unused_mut)]
+        let mut accounts = vec![];
+        accounts.extend([solana_program::instruction::AccountMeta::new(curve, false)]);
+        accounts
+            .extend([
+                solana_program::instruction::AccountMeta::new_readonly(owner, true),
+            ]);
+        let ix = CurvyInstruction::SetCurveYTransform {
+            y_transform,
+        };
+        solana_program::instruction::Instruction::new_with_borsh(
+            program_id,
+            &ix,
+            accounts,
+        )
+    }
+}
+///[CurvyInstruction::SetCurveExpiry] Builder struct
+pub struct SetCurveExpiry {
+    #[cfg(feature = "program-id-manually")]
+    /// Current program ID
+    pub program_id: solana_program::pubkey::Pubkey,
+    ///Curve account to update.
+    pub curve: solana_program::pubkey::Pubkey,
+    ///Curve owner.
+    pub owner: solana_program::pubkey::Pubkey,
+    pub valid_until: i64,
+    pub validity_period: u64,
+}
+impl SetCurveExpiry {
+    #[track_caller]
+    pub fn into_instruction(self) -> solana_program::instruction::Instruction {
+        let Self {
+            #[cfg(feature = "program-id-manually")]
+            program_id,
+            curve,
+            owner,
+            valid_until,
+            validity_period,
+        } = self;
+        #[cfg(not(feature = "program-id-manually"))]
+        let program_id = crate::ID;
+        #[allow(unused_mut)]
+        let mut accounts = vec![];
+        accounts.extend([solana_program::instruction::AccountMeta::new(curve, false)]);
+        accounts
+            .extend([
+                solana_program::instruction::AccountMeta::new_readonly(owner, true),
+            ]);
+        let ix = CurvyInstruction::SetCurveExpiry {
+            valid_until,
+            validity_period,
+        };
+        solana_program::instruction::Instruction::new_with_borsh(
+            program_id,
+            &ix,
+            accounts,
+        )
+    }
+}
+///[CurvyInstruction::BatchAlter] Builder struct
+pub struct BatchAlter {
+    #[cfg(feature = "program-id-manually")]
+    /// Current program ID
+    pub program_id: solana_program::pubkey::Pubkey,
+    ///Owner or delegate of every altered curve.
+    pub owner: solana_program::pubkey::Pubkey,
+    pub params: Vec<CompactCurveParams>,
+}
+impl BatchAlter {
+    #[track_caller]
+    pub fn into_instruction(self) -> solana_program::instruction::Instruction {
+        let Self { #[cfg(feature = "program-id-manually")] program_id, owner, params } = self;
+        #[cfg(not(feature = "program-id-manually"))]
+        let program_id = crate::ID;
+        #[allow(unused_mut)]
+        let mut accounts = vec![];
+        accounts
+            .extend([
+                solana_program::instruction::AccountMeta::new_readonly(owner, true),
+            ]);
+        let ix = CurvyInstruction::BatchAlter {
+            params,
+        };
+        solana_program::instruction::Instruction::new_with_borsh(
+            program_id,
+            &ix,
+            accounts,
+        )
+    }
+}
+///[CurvyInstruction::CreateReferenceCurve] Builder struct
+pub struct CreateReferenceCurve {
+    #[cfg(feature = "program-id-manually")]
+    /// Current program ID
+    pub program_id: solana_program::pubkey::Pubkey,
+    ///Curve account to create, PDA of kind and mint.
+    pub curve: solana_program::pubkey::Pubkey,
+    ///Config admin, pays for the curve.
+    pub admin: solana_program::pubkey::Pubkey,
+    ///Program config.
+    pub config: solana_program::pubkey::Pubkey,
+    ///SPL Token or Token-2022 mint.
+    pub asset_mint: solana_program::pubkey::Pubkey,
+    ///Name link of the config and the curve name, created or pointed to the curve.
+    pub name_link: solana_program::pubkey::Pubkey,
+    pub kind: u8,
+    pub params: CurveParams,
+}
+impl CreateReferenceCurve {
+    #[track_caller]
+    pub fn into_instruction(self) -> solana_program::instruction::Instruction {
+        let Self {
+            #[cfg(feature = "program-id-manually")]
+            program_id,
+            curve,
+            admin,
+            config,
+            asset_mint,
+            name_link,
+            kind,
+            params,
+        } = self;
+        #[cfg(not(feature = "program-id-manually"))]
+        let program_id = crate::ID;
+        #[allow(unused_mut)]
+        let mut accounts = vec![];
+        accounts.extend([solana_program::instruction::AccountMeta::new(curve, false)]);
+        accounts.extend([solana_program::instruction::AccountMeta::new(admin, true)]);
+        accounts
+            .extend([
+                solana_program::instruction::AccountMeta::new_readonly(config, false),
+            ]);
+        accounts
+            .extend([
+                solana_program::instruction::AccountMeta::new_readonly(asset_mint, false),
+            ]);
+        accounts
+            .extend([solana_program::instruction::AccountMeta::new(name_link, false)]);
+        accounts
+            .extend([
+                solana_program::instruction::AccountMeta::new_readonly(
+                    solana_program::system_program::ID,
+                    false,
+                ),
+            ]);
+        let ix = CurvyInstruction::CreateReferenceCurve {
+            kind,
+            params,
+        };
+        solana_program::instruction::Instruction::new_with_borsh(
+            program_id,
+            &ix,
+            accounts,
+        )
+    }
+}
+///[CurvyInstruction::SetCreationFrozen] Builder struct
+pub struct SetCreationFrozen {
+    #[cfg(feature = "program-id-manually")]
+    /// Current program ID
+    pub program_id: solana_program::pubkey::Pubkey,
+    ///Config account to update.
+    pub config: solana_program::pubkey::Pubkey,
+    ///Config admin.
+    pub admin: solana_program::pubkey::Pubkey,
+    pub frozen: bool,
+}
+impl SetCreationFrozen {
+    #[track_caller]
+    pub fn into_instruction(self) -> solana_program::instruction::Instruction {
+        let Self { #[cfg(feature = "program-id-manually")] program_id, config, admin, frozen } = self;
+        #[cfg(not(feature = "program-id-manually"))]
+        let program_id = crate::ID;
+        #[allow(unused_mut)]
+        let mut accounts = vec![];
+        accounts.extend([solana_program::instruction::AccountMeta::new(config, false)]);
+        accounts
+            .extend([
+                solana_program::instruction::AccountMeta::new_readonly(admin, true),
+            ]);
+        let ix = CurvyInstruction::SetCreationFrozen {
+            frozen,
+        };
+        solana_program::instruction::Instruction::new_with_borsh(
+            program_id,
+            &ix,
+            accounts,
+        )
+    }
+}
+/// [CurvyInstruction::CreateCurve] instruction account indexes helper
+#[derive(Debug, PartialEq)]
+pub struct CreateCurveAccountIndexes {
+    pub curve: usize,
+    pub owner: usize,
+    pub config: usize,
+    pub name_link: usize,
+    pub system_program: usize,
+}
+impl CreateCurveAccountIndexes {
+    pub const COUNT: usize = 5usize;
+    pub const CURVE: usize = 0usize;
+    pub const OWNER: usize = 1usize;
+    pub const CONFIG: usize = 2usize;
+    pub const NAME_LINK: usize = 3usize;
+    pub const SYSTEM_PROGRAM: usize = 4usize;
+    pub fn new_direct_order() -> Self {
+        let mut iter = std::iter::repeat(()).enumerate().map(|(idx, ())| idx);
+        Self {
+            curve: iter.next().unwrap(),
+            owner: iter.next().unwrap(),
+            config: iter.next().unwrap(),
+            name_link: iter.next().unwrap(),
+            system_program: iter.next().unwrap(),
+        }
+    }
+    pub fn try_from_indexes<'a>(
+        indexes: impl IntoIterator<Item = &'a u8>,
+    ) -> Result<Self, usize> {
+        let mut iter = indexes.into_iter().map(|idx| (*idx) as usize);
+        let mut idx = 0_usize;
+        Ok(Self {
+            curve: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            owner: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            config: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            name_link: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            system_program: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+        })
+    }
+}
+impl<'a> TryFrom<&'a [u8]> for CreateCurveAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: &'a [u8]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(indexes)
+    }
+}
+impl<'a, const N: usize> TryFrom<&'a [u8; N]> for CreateCurveAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: &'a [u8; N]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(indexes)
+    }
+}
+impl<const N: usize> TryFrom<[u8; N]> for CreateCurveAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: [u8; N]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(&indexes)
+    }
+}
+impl TryFrom<Vec<u8>> for CreateCurveAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: Vec<u8>) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(&indexes)
+    }
+}
+/// [CurvyInstruction::AlterCurve] instruction account indexes helper
+#[derive(Debug, PartialEq)]
+pub struct AlterCurveAccountIndexes {
+    pub curve: usize,
+    pub owner: usize,
+}
+impl AlterCurveAccountIndexes {
+    pub const COUNT: usize = 2usize;
+    pub const CURVE: usize = 0usize;
+    pub const OWNER: usize = 1usize;
+    pub fn new_direct_order() -> Self {
+        let mut iter = std::iter::repeat(()).enumerate().map(|(idx, ())| idx);
+        Self {
+            curve: iter.next().unwrap(),
+            owner: iter.next().unwrap(),
+        }
+    }
+    pub fn try_from_indexes<'a>(
+        indexes: impl IntoIterator<Item = &'a u8>,
+    ) -> Result<Self, usize> {
+        let mut iter = indexes.into_iter().map(|idx| (*idx) as usize);
+        let mut idx = 0_usize;
+        Ok(Self {
+            curve: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            owner: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+        })
+    }
+}
+impl<'a> TryFrom<&'a [u8]> for AlterCurveAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: &'a [u8]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(indexes)
+    }
+}
+impl<'a, const N: usize> TryFrom<&'a [u8; N]> for AlterCurveAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: &'a [u8; N]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(indexes)
+    }
+}
+impl<const N: usize> TryFrom<[u8; N]> for AlterCurveAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: [u8; N]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(&indexes)
+    }
+}
+impl TryFrom<Vec<u8>> for AlterCurveAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: Vec<u8>) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(&indexes)
+    }
+}
+/// [CurvyInstruction::DeleteCurve] instruction account indexes helper
+#[derive(Debug, PartialEq)]
+pub struct DeleteCurveAccountIndexes {
+    pub curve: usize,
+    pub owner: usize,
+    pub name_link: usize,
+}
+impl DeleteCurveAccountIndexes {
+    pub const COUNT: usize = 3usize;
+    pub const CURVE: usize = 0usize;
+    pub const OWNER: usize = 1usize;
+    pub const NAME_LINK: usize = 2usize;
+    pub fn new_direct_order() -> Self {
+        let mut iter = std::iter::repeat(()).enumerate().map(|(idx, ())| idx);
+        Self {
+            curve: iter.next().unwrap(),
+            owner: iter.next().unwrap(),
+            name_link: iter.next().unwrap(),
+        }
+    }
+    pub fn try_from_indexes<'a>(
+        indexes: impl IntoIterator<Item = &'a u8>,
+    ) -> Result<Self, usize> {
+        let mut iter = indexes.into_iter().map(|idx| (*idx) as usize);
+        let mut idx = 0_usize;
+        Ok(Self {
+            curve: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            owner: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            name_link: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+        })
+    }
+}
+impl<'a> TryFrom<&'a [u8]> for DeleteCurveAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: &'a [u8]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(indexes)
+    }
+}
+impl<'a, const N: usize> TryFrom<&'a [u8; N]> for DeleteCurveAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: &'a [u8; N]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(indexes)
+    }
+}
+impl<const N: usize> TryFrom<[u8; N]> for DeleteCurveAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: [u8; N]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(&indexes)
+    }
+}
+impl TryFrom<Vec<u8>> for DeleteCurveAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: Vec<u8>) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(&indexes)
+    }
+}
+/// [CurvyInstruction::UpdateCurveMetadata] instruction account indexes helper
+#[derive(Debug, PartialEq)]
+pub struct UpdateCurveMetadataAccountIndexes {
+    pub curve: usize,
+    pub owner: usize,
+    pub name_link: usize,
+    pub new_name_link: usize,
+    pub system_program: usize,
+}
+impl UpdateCurveMetadataAccountIndexes {
+    pub const COUNT: usize = 5usize;
+    pub const CURVE: usize = 0usize;
+    pub const OWNER: usize = 1usize;
+    pub const NAME_LINK: usize = 2usize;
+    pub const NEW_NAME_LINK: usize = 3usize;
+    pub const SYSTEM_PROGRAM: usize = 4usize;
+    pub fn new_direct_order() -> Self {
+        let mut iter = std::iter::repeat(()).enumerate().map(|(idx, ())| idx);
+        Self {
+            curve: iter.next().unwrap(),
+            owner: iter.next().unwrap(),
+            name_link: iter.next().unwrap(),
+            new_name_link: iter.next().unwrap(),
+            system_program: iter.next().unwrap(),
+        }
+    }
+    pub fn try_from_indexes<'a>(
+        indexes: impl IntoIterator<Item = &'a u8>,
+    ) -> Result<Self, usize> {
+        let mut iter = indexes.into_iter().map(|idx| (*idx) as usize);
+        let mut idx = 0_usize;
+        Ok(Self {
+            curve: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            owner: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            name_link: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            new_name_link: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            system_program: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+        })
+    }
+}
+impl<'a> TryFrom<&'a [u8]> for UpdateCurveMetadataAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: &'a [u8]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(indexes)
+    }
+}
+impl<'a, const N: usize> TryFrom<&'a [u8; N]> for UpdateCurveMetadataAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: &'a [u8; N]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(indexes)
+    }
+}
+impl<const N: usize> TryFrom<[u8; N]> for UpdateCurveMetadataAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: [u8; N]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(&indexes)
+    }
+}
+impl TryFrom<Vec<u8>> for UpdateCurveMetadataAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: Vec<u8>) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(&indexes)
+    }
+}
+/// [CurvyInstruction::UpdateCurveData] instruction account indexes helper
+#[derive(Debug, PartialEq)]
+pub struct UpdateCurveDataAccountIndexes {
+    pub curve: usize,
+    pub owner: usize,
+}
+impl UpdateCurveDataAccountIndexes {
+    pub const COUNT: usize = 2usize;
+    pub const CURVE: usize = 0usize;
+    pub const OWNER: usize = 1usize;
+    pub fn new_direct_order() -> Self {
+        let mut iter = std::iter::repeat(()).enumerate().map(|(idx, ())| idx);
+        Self {
+            curve: iter.next().unwrap(),
+            owner: iter.next().unwrap(),
+        }
+    }
+    pub fn try_from_indexes<'a>(
+        indexes: impl IntoIterator<Item = &'a u8>,
+    ) -> Result<Self, usize> {
+        let mut iter = indexes.into_iter().map(|idx| (*idx) as usize);
+        let mut idx = 0_usize;
+        Ok(Self {
+            curve: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            owner: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+        })
+    }
+}
+impl<'a> TryFrom<&'a [u8]> for UpdateCurveDataAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: &'a [u8]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(indexes)
+    }
+}
+impl<'a, const N: usize> TryFrom<&'a [u8; N]> for UpdateCurveDataAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: &'a [u8; N]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(indexes)
+    }
+}
+impl<const N: usize> TryFrom<[u8; N]> for UpdateCurveDataAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: [u8; N]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(&indexes)
+    }
+}
+impl TryFrom<Vec<u8>> for UpdateCurveDataAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: Vec<u8>) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(&indexes)
+    }
+}
+/// [CurvyInstruction::ReserveCurve] instruction account indexes helper
+#[derive(Debug, PartialEq)]
+pub struct ReserveCurveAccountIndexes {
+    pub curve: usize,
+    pub owner: usize,
+    pub config: usize,
+    pub name_link: usize,
+    pub system_program: usize,
+}
+impl ReserveCurveAccountIndexes {
+    pub const COUNT: usize = 5usize;
+    pub const CURVE: usize = 0usize;
+    pub const OWNER: usize = 1usize;
+    pub const CONFIG: usize = 2usize;
+    pub const NAME_LINK: usize = 3usize;
+    pub const SYSTEM_PROGRAM: usize = 4usize;
+    pub fn new_direct_order() -> Self {
+        let mut iter = std::iter::repeat(()).enumerate().map(|(idx, ())| idx);
+        Self {
+            curve: iter.next().unwrap(),
+            owner: iter.next().unwrap(),
+            config: iter.next().unwrap(),
+            name_link: iter.next().unwrap(),
+            system_program: iter.next().unwrap(),
+        }
+    }
+    pub fn try_from_indexes<'a>(
+        indexes: impl IntoIterator<Item = &'a u8>,
+    ) -> Result<Self, usize> {
+        let mut iter = indexes.into_iter().map(|idx| (*idx) as usize);
+        let mut idx = 0_usize;
+        Ok(Self {
+            curve: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            owner: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            config: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            name_link: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            system_program: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+        })
+    }
+}
+impl<'a> TryFrom<&'a [u8]> for ReserveCurveAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: &'a [u8]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(indexes)
+    }
+}
+impl<'a, const N: usize> TryFrom<&'a [u8; N]> for ReserveCurveAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: &'a [u8; N]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(indexes)
+    }
+}
+impl<const N: usize> TryFrom<[u8; N]> for ReserveCurveAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: [u8; N]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(&indexes)
+    }
+}
+impl TryFrom<Vec<u8>> for ReserveCurveAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: Vec<u8>) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(&indexes)
+    }
+}
+/// [CurvyInstruction::TopUpRent] instruction account indexes helper
+#[derive(Debug, PartialEq)]
+pub struct TopUpRentAccountIndexes {
+    pub curve: usize,
+    pub payer: usize,
+    pub system_program: usize,
+}
+impl TopUpRentAccountIndexes {
+    pub const COUNT: usize = 3usize;
+    pub const CURVE: usize = 0usize;
+    pub const PAYER: usize = 1usize;
+    pub const SYSTEM_PROGRAM: usize = 2usize;
+    pub fn new_direct_order() -> Self {
+        let mut iter = std::iter::repeat(()).enumerate().map(|(idx, ())| idx);
+        Self {
+            curve: iter.next().unwrap(),
+            payer: iter.next().unwrap(),
+            system_program: iter.next().unwrap(),
+        }
+    }
+    pub fn try_from_indexes<'a>(
+        indexes: impl IntoIterator<Item = &'a u8>,
+    ) -> Result<Self, usize> {
+        let mut iter = indexes.into_iter().map(|idx| (*idx) as usize);
+        let mut idx = 0_usize;
+        Ok(Self {
+            curve: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            payer: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            system_program: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+        })
+    }
+}
+impl<'a> TryFrom<&'a [u8]> for TopUpRentAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: &'a [u8]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(indexes)
+    }
+}
+impl<'a, const N: usize> TryFrom<&'a [u8; N]> for TopUpRentAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: &'a [u8; N]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(indexes)
+    }
+}
+impl<const N: usize> TryFrom<[u8; N]> for TopUpRentAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: [u8; N]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(&indexes)
+    }
+}
+impl TryFrom<Vec<u8>> for TopUpRentAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: Vec<u8>) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(&indexes)
+    }
+}
+/// [CurvyInstruction::SetCurveAsset] instruction account indexes helper
+#[derive(Debug, PartialEq)]
+pub struct SetCurveAssetAccountIndexes {
+    pub curve: usize,
+    pub owner: usize,
+    pub asset_mint: usize,
+    pub asset_link: usize,
+    pub system_program: usize,
+}
+impl SetCurveAssetAccountIndexes {
+    pub const COUNT: usize = 5usize;
+    pub const CURVE: usize = 0usize;
+    pub const OWNER: usize = 1usize;
+    pub const ASSET_MINT: usize = 2usize;
+    pub const ASSET_LINK: usize = 3usize;
+    pub const SYSTEM_PROGRAM: usize = 4usize;
+    pub fn new_direct_order() -> Self {
+        let mut iter = std::iter::repeat(()).enumerate().map(|(idx, ())| idx);
+        Self {
+            curve: iter.next().unwrap(),
+            owner: iter.next().unwrap(),
+            asset_mint: iter.next().unwrap(),
+            asset_link: iter.next().unwrap(),
+            system_program: iter.next().unwrap(),
+        }
+    }
+    pub fn try_from_indexes<'a>(
+        indexes: impl IntoIterator<Item = &'a u8>,
+    ) -> Result<Self, usize> {
+        let mut iter = indexes.into_iter().map(|idx| (*idx) as usize);
+        let mut idx = 0_usize;
+        Ok(Self {
+            curve: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            owner: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            asset_mint: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            asset_link: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            system_program: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+        })
+    }
+}
+impl<'a> TryFrom<&'a [u8]> for SetCurveAssetAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: &'a [u8]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(indexes)
+    }
+}
+impl<'a, const N: usize> TryFrom<&'a [u8; N]> for SetCurveAssetAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: &'a [u8; N]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(indexes)
+    }
+}
+impl<const N: usize> TryFrom<[u8; N]> for SetCurveAssetAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: [u8; N]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(&indexes)
+    }
+}
+impl TryFrom<Vec<u8>> for SetCurveAssetAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: Vec<u8>) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(&indexes)
+    }
+}
+/// [CurvyInstruction::ClearCurveAsset] instruction account indexes helper
+#[derive(Debug, PartialEq)]
+pub struct ClearCurveAssetAccountIndexes {
+    pub curve: usize,
+    pub owner: usize,
+    pub asset_link: usize,
+}
+impl ClearCurveAssetAccountIndexes {
+    pub const COUNT: usize = 3usize;
+    pub const CURVE: usize = 0usize;
+    pub const OWNER: usize = 1usize;
+    pub const ASSET_LINK: usize = 2usize;
+    pub fn new_direct_order() -> Self {
+        let mut iter = std::iter::repeat(()).enumerate().map(|(idx, ())| idx);
+        Self {
+            curve: iter.next().unwrap(),
+            owner: iter.next().unwrap(),
+            asset_link: iter.next().unwrap(),
+        }
+    }
+    pub fn try_from_indexes<'a>(
+        indexes: impl IntoIterator<Item = &'a u8>,
+    ) -> Result<Self, usize> {
+        let mut iter = indexes.into_iter().map(|idx| (*idx) as usize);
+        let mut idx = 0_usize;
+        Ok(Self {
+            curve: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            owner: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            asset_link: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+        })
+    }
+}
+impl<'a> TryFrom<&'a [u8]> for ClearCurveAssetAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: &'a [u8]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(indexes)
+    }
+}
+impl<'a, const N: usize> TryFrom<&'a [u8; N]> for ClearCurveAssetAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: &'a [u8; N]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(indexes)
+    }
+}
+impl<const N: usize> TryFrom<[u8; N]> for ClearCurveAssetAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: [u8; N]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(&indexes)
+    }
+}
+impl TryFrom<Vec<u8>> for ClearCurveAssetAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: Vec<u8>) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(&indexes)
+    }
+}
+/// [CurvyInstruction::MigrateCurve] instruction account indexes helper
+#[derive(Debug, PartialEq)]
+pub struct MigrateCurveAccountIndexes {
+    pub curve: usize,
+    pub payer: usize,
+    pub system_program: usize,
+}
+impl MigrateCurveAccountIndexes {
+    pub const COUNT: usize = 3usize;
+    pub const CURVE: usize = 0usize;
+    pub const PAYER: usize = 1usize;
+    pub const SYSTEM_PROGRAM: usize = 2usize;
+    pub fn new_direct_order() -> Self {
+        let mut iter = std::iter::repeat(()).enumerate().map(|(idx, ())| idx);
+        Self {
+            curve: iter.next().unwrap(),
+            payer: iter.next().unwrap(),
+            system_program: iter.next().unwrap(),
+        }
+    }
+    pub fn try_from_indexes<'a>(
+        indexes: impl IntoIterator<Item = &'a u8>,
+    ) -> Result<Self, usize> {
+        let mut iter = indexes.into_iter().map(|idx| (*idx) as usize);
+        let mut idx = 0_usize;
+        Ok(Self {
+            curve: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            payer: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            system_program: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+        })
+    }
+}
+impl<'a> TryFrom<&'a [u8]> for MigrateCurveAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: &'a [u8]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(indexes)
+    }
+}
+impl<'a, const N: usize> TryFrom<&'a [u8; N]> for MigrateCurveAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: &'a [u8; N]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(indexes)
+    }
+}
+impl<const N: usize> TryFrom<[u8; N]> for MigrateCurveAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: [u8; N]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(&indexes)
+    }
+}
+impl TryFrom<Vec<u8>> for MigrateCurveAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: Vec<u8>) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(&indexes)
+    }
+}
+/// [CurvyInstruction::SetCurveSeries] instruction account indexes helper
+#[derive(Debug, PartialEq)]
+pub struct SetCurveSeriesAccountIndexes {
+    pub curve: usize,
+    pub owner: usize,
+    pub curve_series: usize,
+    pub system_program: usize,
+}
+impl SetCurveSeriesAccountIndexes {
+    pub const COUNT: usize = 4usize;
+    pub const CURVE: usize = 0usize;
+    pub const OWNER: usize = 1usize;
+    pub const CURVE_SERIES: usize = 2usize;
+    pub const SYSTEM_PROGRAM: usize = 3usize;
+    pub fn new_direct_order() -> Self {
+        let mut iter = std::iter::repeat(()).enumerate().map(|(idx, ())| idx);
+        Self {
+            curve: iter.next().unwrap(),
+            owner: iter.next().unwrap(),
+            curve_series: iter.next().unwrap(),
+            system_program: iter.next().unwrap(),
+        }
+    }
+    pub fn try_from_indexes<'a>(
+        indexes: impl IntoIterator<Item = &'a u8>,
+    ) -> Result<Self, usize> {
+        let mut iter = indexes.into_iter().map(|idx| (*idx) as usize);
+        let mut idx = 0_usize;
+        Ok(Self {
+            curve: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            owner: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            curve_series: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            system_program: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+        })
+    }
+}
+impl<'a> TryFrom<&'a [u8]> for SetCurveSeriesAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: &'a [u8]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(indexes)
+    }
+}
+impl<'a, const N: usize> TryFrom<&'a [u8; N]> for SetCurveSeriesAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: &'a [u8; N]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(indexes)
+    }
+}
+impl<const N: usize> TryFrom<[u8; N]> for SetCurveSeriesAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: [u8; N]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(&indexes)
+    }
+}
+impl TryFrom<Vec<u8>> for SetCurveSeriesAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: Vec<u8>) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(&indexes)
+    }
+}
+/// [CurvyInstruction::DeleteCurveSeries] instruction account indexes helper
+#[derive(Debug, PartialEq)]
+pub struct DeleteCurveSeriesAccountIndexes {
+    pub curve: usize,
+    pub owner: usize,
+    pub curve_series: usize,
+}
+impl DeleteCurveSeriesAccountIndexes {
+    pub const COUNT: usize = 3usize;
+    pub const CURVE: usize = 0usize;
+    pub const OWNER: usize = 1usize;
+    pub const CURVE_SERIES: usize = 2usize;
+    pub fn new_direct_order() -> Self {
+        let mut iter = std::iter::repeat(()).enumerate().map(|(idx, ())| idx);
+        Self {
+            curve: iter.next().unwrap(),
+            owner: iter.next().unwrap(),
+            curve_series: iter.next().unwrap(),
+        }
+    }
+    pub fn try_from_indexes<'a>(
+        indexes: impl IntoIterator<Item = &'a u8>,
+    ) -> Result<Self, usize> {
+        let mut iter = indexes.into_iter().map(|idx| (*idx) as usize);
+        let mut idx = 0_usize;
+        Ok(Self {
+            curve: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            owner: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            curve_series: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+        })
+    }
+}
+impl<'a> TryFrom<&'a [u8]> for DeleteCurveSeriesAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: &'a [u8]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(indexes)
+    }
+}
+impl<'a, const N: usize> TryFrom<&'a [u8; N]> for DeleteCurveSeriesAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: &'a [u8; N]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(indexes)
+    }
+}
+impl<const N: usize> TryFrom<[u8; N]> for DeleteCurveSeriesAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: [u8; N]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(&indexes)
+    }
+}
+impl TryFrom<Vec<u8>> for DeleteCurveSeriesAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: Vec<u8>) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(&indexes)
+    }
+}
+/// [CurvyInstruction::SetCurveConfidence] instruction account indexes helper
+#[derive(Debug, PartialEq)]
+pub struct SetCurveConfidenceAccountIndexes {
+    pub curve: usize,
+    pub owner: usize,
+    pub curve_confidence: usize,
+    pub system_program: usize,
+}
+impl SetCurveConfidenceAccountIndexes {
+    pub const COUNT: usize = 4usize;
+    pub const CURVE: usize = 0usize;
+    pub const OWNER: usize = 1usize;
+    pub const CURVE_CONFIDENCE: usize = 2usize;
+    pub const SYSTEM_PROGRAM: usize = 3usize;
+    pub fn new_direct_order() -> Self {
+        let mut iter = std::iter::repeat(()).enumerate().map(|(idx, ())| idx);
+        Self {
+            curve: iter.next().unwrap(),
+            owner: iter.next().unwrap(),
+            curve_confidence: iter.next().unwrap(),
+            system_program: iter.next().unwrap(),
+        }
+    }
+    pub fn try_from_indexes<'a>(
+        indexes: impl IntoIterator<Item = &'a u8>,
+    ) -> Result<Self, usize> {
+        let mut iter = indexes.into_iter().map(|idx| (*idx) as usize);
+        let mut idx = 0_usize;
+        Ok(Self {
+            curve: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            owner: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            curve_confidence: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            system_program: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+        })
+    }
+}
+impl<'a> TryFrom<&'a [u8]> for SetCurveConfidenceAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: &'a [u8]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(indexes)
+    }
+}
+impl<'a, const N: usize> TryFrom<&'a [u8; N]> for SetCurveConfidenceAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: &'a [u8; N]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(indexes)
+    }
+}
+impl<const N: usize> TryFrom<[u8; N]> for SetCurveConfidenceAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: [u8; N]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(&indexes)
+    }
+}
+impl TryFrom<Vec<u8>> for SetCurveConfidenceAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: Vec<u8>) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(&indexes)
+    }
+}
+/// [CurvyInstruction::DeleteCurveConfidence] instruction account indexes helper
+#[derive(Debug, PartialEq)]
+pub struct DeleteCurveConfidenceAccountIndexes {
+    pub curve: usize,
+    pub owner: usize,
+    pub curve_confidence: usize,
+}
+impl DeleteCurveConfidenceAccountIndexes {
+    pub const COUNT: usize = 3usize;
+    pub const CURVE: usize = 0usize;
+    pub const OWNER: usize = 1usize;
+    pub const CURVE_CONFIDENCE: usize = 2usize;
+    pub fn new_direct_order() -> Self {
+        let mut iter = std::iter::repeat(()).enumerate().map(|(idx, ())| idx);
+        Self {
+            curve: iter.next().unwrap(),
+            owner: iter.next().unwrap(),
+            curve_confidence: iter.next().unwrap(),
+        }
+    }
+    pub fn try_from_indexes<'a>(
+        indexes: impl IntoIterator<Item = &'a u8>,
+    ) -> Result<Self, usize> {
+        let mut iter = indexes.into_iter().map(|idx| (*idx) as usize);
+        let mut idx = 0_usize;
+        Ok(Self {
+            curve: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            owner: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            curve_confidence: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+        })
+    }
+}
+impl<'a> TryFrom<&'a [u8]> for DeleteCurveConfidenceAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: &'a [u8]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(indexes)
+    }
+}
+impl<'a, const N: usize> TryFrom<&'a [u8; N]> for DeleteCurveConfidenceAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: &'a [u8; N]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(indexes)
+    }
+}
+impl<const N: usize> TryFrom<[u8; N]> for DeleteCurveConfidenceAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: [u8; N]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(&indexes)
+    }
+}
+impl TryFrom<Vec<u8>> for DeleteCurveConfidenceAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: Vec<u8>) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(&indexes)
+    }
+}
+/// [CurvyInstruction::CreateWideCurve] instruction account indexes helper
+#[derive(Debug, PartialEq)]
+pub struct CreateWideCurveAccountIndexes {
+    pub curve: usize,
+    pub owner: usize,
+    pub config: usize,
+    pub name_link: usize,
+    pub system_program: usize,
+}
+impl CreateWideCurveAccountIndexes {
+    pub const COUNT: usize = 5usize;
+    pub const CURVE: usize = 0usize;
+    pub const OWNER: usize = 1usize;
+    pub const CONFIG: usize = 2usize;
+    pub const NAME_LINK: usize = 3usize;
+    pub const SYSTEM_PROGRAM: usize = 4usize;
+    pub fn new_direct_order() -> Self {
+        let mut iter = std::iter::repeat(()).enumerate().map(|(idx, ())| idx);
+        Self {
+            curve: iter.next().unwrap(),
+            owner: iter.next().unwrap(),
+            config: iter.next().unwrap(),
+            name_link: iter.next().unwrap(),
+            system_program: iter.next().unwrap(),
+        }
+    }
+    pub fn try_from_indexes<'a>(
+        indexes: impl IntoIterator<Item = &'a u8>,
+    ) -> Result<Self, usize> {
+        let mut iter = indexes.into_iter().map(|idx| (*idx) as usize);
+        let mut idx = 0_usize;
+        Ok(Self {
+            curve: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            owner: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            config: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            name_link: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            system_program: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+        })
+    }
+}
+impl<'a> TryFrom<&'a [u8]> for CreateWideCurveAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: &'a [u8]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(indexes)
+    }
+}
+impl<'a, const N: usize> TryFrom<&'a [u8; N]> for CreateWideCurveAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: &'a [u8; N]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(indexes)
+    }
+}
+impl<const N: usize> TryFrom<[u8; N]> for CreateWideCurveAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: [u8; N]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(&indexes)
+    }
+}
+impl TryFrom<Vec<u8>> for CreateWideCurveAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: Vec<u8>) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(&indexes)
+    }
+}
+/// [CurvyInstruction::AlterWideCurve] instruction account indexes helper
+#[derive(Debug, PartialEq)]
+pub struct AlterWideCurveAccountIndexes {
+    pub curve: usize,
+    pub owner: usize,
+}
+impl AlterWideCurveAccountIndexes {
+    pub const COUNT: usize = 2usize;
+    pub const CURVE: usize = 0usize;
+    pub const OWNER: usize = 1usize;
+    pub fn new_direct_order() -> Self {
+        let mut iter = std::iter::repeat(()).enumerate().map(|(idx, ())| idx);
+        Self {
+            curve: iter.next().unwrap(),
+            owner: iter.next().unwrap(),
+        }
+    }
+    pub fn try_from_indexes<'a>(
+        indexes: impl IntoIterator<Item = &'a u8>,
+    ) -> Result<Self, usize> {
+        let mut iter = indexes.into_iter().map(|idx| (*idx) as usize);
+        let mut idx = 0_usize;
+        Ok(Self {
+            curve: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            owner: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+        })
+    }
+}
+impl<'a> TryFrom<&'a [u8]> for AlterWideCurveAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: &'a [u8]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(indexes)
+    }
+}
+impl<'a, const N: usize> TryFrom<&'a [u8; N]> for AlterWideCurveAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: &'a [u8; N]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(indexes)
+    }
+}
+impl<const N: usize> TryFrom<[u8; N]> for AlterWideCurveAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: [u8; N]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(&indexes)
+    }
+}
+impl TryFrom<Vec<u8>> for AlterWideCurveAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: Vec<u8>) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(&indexes)
+    }
+}
+/// [CurvyInstruction::SetCurveXSign] instruction account indexes helper
+#[derive(Debug, PartialEq)]
+pub struct SetCurveXSignAccountIndexes {
+    pub curve: usize,
+    pub owner: usize,
+}
+impl SetCurveXSignAccountIndexes {
+    pub const COUNT: usize = 2usize;
+    pub const CURVE: usize = 0usize;
+    pub const OWNER: usize = 1usize;
+    pub fn new_direct_order() -> Self {
+        let mut iter = std::iter::repeat(()).enumerate().map(|(idx, ())| idx);
+        Self {
+            curve: iter.next().unwrap(),
+            owner: iter.next().unwrap(),
+        }
+    }
+    pub fn try_from_indexes<'a>(
+        indexes: impl IntoIterator<Item = &'a u8>,
+    ) -> Result<Self, usize> {
+        let mut iter = indexes.into_iter().map(|idx| (*idx) as usize);
+        let mut idx = 0_usize;
+        Ok(Self {
+            curve: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            owner: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+        })
+    }
+}
+impl<'a> TryFrom<&'a [u8]> for SetCurveXSignAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: &'a [u8]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(indexes)
+    }
+}
+impl<'a, const N: usize> TryFrom<&'a [u8; N]> for SetCurveXSignAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: &'a [u8; N]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(indexes)
+    }
+}
+impl<const N: usize> TryFrom<[u8; N]> for SetCurveXSignAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: [u8; N]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(&indexes)
+    }
+}
+impl TryFrom<Vec<u8>> for SetCurveXSignAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: Vec<u8>) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(&indexes)
+    }
+}
+/// [CurvyInstruction::ProposeOwner] instruction account indexes helper
+#[derive(Debug, PartialEq)]
+pub struct ProposeOwnerAccountIndexes {
+    pub curve: usize,
+    pub owner: usize,
+}
+impl ProposeOwnerAccountIndexes {
+    pub const COUNT: usize = 2usize;
+    pub const CURVE: usize = 0usize;
+    pub const OWNER: usize = 1usize;
+    pub fn new_direct_order() -> Self {
+        let mut iter = std::iter::repeat(()).enumerate().map(|(idx, ())| idx);
+        Self {
+            curve: iter.next().unwrap(),
+            owner: iter.next().unwrap(),
+        }
+    }
+    pub fn try_from_indexes<'a>(
+        indexes: impl IntoIterator<Item = &'a u8>,
+    ) -> Result<Self, usize> {
+        let mut iter = indexes.into_iter().map(|idx| (*idx) as usize);
+        let mut idx = 0_usize;
+        Ok(Self {
+            curve: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            owner: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+        })
+    }
+}
+impl<'a> TryFrom<&'a [u8]> for ProposeOwnerAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: &'a [u8]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(indexes)
+    }
+}
+impl<'a, const N: usize> TryFrom<&'a [u8; N]> for ProposeOwnerAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: &'a [u8; N]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(indexes)
+    }
+}
+impl<const N: usize> TryFrom<[u8; N]> for ProposeOwnerAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: [u8; N]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(&indexes)
+    }
+}
+impl TryFrom<Vec<u8>> for ProposeOwnerAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: Vec<u8>) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(&indexes)
+    }
+}
+/// [CurvyInstruction::AcceptOwner] instruction account indexes helper
+#[derive(Debug, PartialEq)]
+pub struct AcceptOwnerAccountIndexes {
+    pub curve: usize,
+    pub new_owner: usize,
+    pub owner: usize,
+    pub name_link: usize,
+    pub new_name_link: usize,
+    pub system_program: usize,
+}
+impl AcceptOwnerAccountIndexes {
+    pub const COUNT: usize = 6usize;
+    pub const CURVE: usize = 0usize;
+    pub const NEW_OWNER: usize = 1usize;
+    pub const OWNER: usize = 2usize;
+    pub const NAME_LINK: usize = 3usize;
+    pub const NEW_NAME_LINK: usize = 4usize;
+    pub const SYSTEM_PROGRAM: usize = 5usize;
+    pub fn new_direct_order() -> Self {
+        let mut iter = std::iter::repeat(()).enumerate().map(|(idx, ())| idx);
+        Self {
+            curve: iter.next().unwrap(),
+            new_owner: iter.next().unwrap(),
+            owner: iter.next().unwrap(),
+            name_link: iter.next().unwrap(),
+            new_name_link: iter.next().unwrap(),
+            system_program: iter.next().unwrap(),
+        }
+    }
+    pub fn try_from_indexes<'a>(
+        indexes: impl IntoIterator<Item = &'a u8>,
+    ) -> Result<Self, usize> {
+        let mut iter = indexes.into_iter().map(|idx| (*idx) as usize);
+        let mut idx = 0_usize;
+        Ok(Self {
+            curve: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            new_owner: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            owner: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            name_link: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            new_name_link: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            system_program: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+        })
+    }
+}
+impl<'a> TryFrom<&'a [u8]> for AcceptOwnerAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: &'a [u8]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(indexes)
+    }
+}
+impl<'a, const N: usize> TryFrom<&'a [u8; N]> for AcceptOwnerAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: &'a [u8; N]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(indexes)
+    }
+}
+impl<const N: usize> TryFrom<[u8; N]> for AcceptOwnerAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: [u8; N]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(&indexes)
+    }
+}
+impl TryFrom<Vec<u8>> for AcceptOwnerAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: Vec<u8>) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(&indexes)
+    }
+}
+/// [CurvyInstruction::CreateCurvePda] instruction account indexes helper
+#[derive(Debug, PartialEq)]
+pub struct CreateCurvePdaAccountIndexes {
+    pub curve: usize,
+    pub owner: usize,
+    pub config: usize,
+    pub name_link: usize,
+    pub system_program: usize,
+}
+impl CreateCurvePdaAccountIndexes {
+    pub const COUNT: usize = 5usize;
+    pub const CURVE: usize = 0usize;
+    pub const OWNER: usize = 1usize;
+    pub const CONFIG: usize = 2usize;
+    pub const NAME_LINK: usize = 3usize;
+    pub const SYSTEM_PROGRAM: usize = 4usize;
+    pub fn new_direct_order() -> Self {
+        let mut iter = std::iter::repeat(()).enumerate().map(|(idx, ())| idx);
+        Self {
+            curve: iter.next().unwrap(),
+            owner: iter.next().unwrap(),
+            config: iter.next().unwrap(),
+            name_link: iter.next().unwrap(),
+            system_program: iter.next().unwrap(),
+        }
+    }
+    pub fn try_from_indexes<'a>(
+        indexes: impl IntoIterator<Item = &'a u8>,
+    ) -> Result<Self, usize> {
+        let mut iter = indexes.into_iter().map(|idx| (*idx) as usize);
+        let mut idx = 0_usize;
+        Ok(Self {
+            curve: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            owner: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            config: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            name_link: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            system_program: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+        })
+    }
+}
+impl<'a> TryFrom<&'a [u8]> for CreateCurvePdaAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: &'a [u8]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(indexes)
+    }
+}
+impl<'a, const N: usize> TryFrom<&'a [u8; N]> for CreateCurvePdaAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: &'a [u8; N]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(indexes)
+    }
+}
+impl<const N: usize> TryFrom<[u8; N]> for CreateCurvePdaAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: [u8; N]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(&indexes)
+    }
+}
+impl TryFrom<Vec<u8>> for CreateCurvePdaAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: Vec<u8>) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(&indexes)
+    }
+}
+/// [CurvyInstruction::FreezeCurve] instruction account indexes helper
+#[derive(Debug, PartialEq)]
+pub struct FreezeCurveAccountIndexes {
+    pub curve: usize,
+    pub owner: usize,
+}
+impl FreezeCurveAccountIndexes {
+    pub const COUNT: usize = 2usize;
+    pub const CURVE: usize = 0usize;
+    pub const OWNER: usize = 1usize;
+    pub fn new_direct_order() -> Self {
+        let mut iter = std::iter::repeat(()).enumerate().map(|(idx, ())| idx);
+        Self {
+            curve: iter.next().unwrap(),
+            owner: iter.next().unwrap(),
+        }
+    }
+    pub fn try_from_indexes<'a>(
+        indexes: impl IntoIterator<Item = &'a u8>,
+    ) -> Result<Self, usize> {
+        let mut iter = indexes.into_iter().map(|idx| (*idx) as usize);
+        let mut idx = 0_usize;
+        Ok(Self {
+            curve: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            owner: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+        })
+    }
+}
+impl<'a> TryFrom<&'a [u8]> for FreezeCurveAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: &'a [u8]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(indexes)
+    }
+}
+impl<'a, const N: usize> TryFrom<&'a [u8; N]> for FreezeCurveAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: &'a [u8; N]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(indexes)
+    }
+}
+impl<const N: usize> TryFrom<[u8; N]> for FreezeCurveAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: [u8; N]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(&indexes)
+    }
+}
+impl TryFrom<Vec<u8>> for FreezeCurveAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: Vec<u8>) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(&indexes)
+    }
+}
+/// [CurvyInstruction::ResizeCurve] instruction account indexes helper
+#[derive(Debug, PartialEq)]
+pub struct ResizeCurveAccountIndexes {
+    pub curve: usize,
+    pub owner: usize,
+    pub system_program: usize,
+}
+impl ResizeCurveAccountIndexes {
+    pub const COUNT: usize = 3usize;
+    pub const CURVE: usize = 0usize;
+    pub const OWNER: usize = 1usize;
+    pub const SYSTEM_PROGRAM: usize = 2usize;
+    pub fn new_direct_order() -> Self {
+        let mut iter = std::iter::repeat(()).enumerate().map(|(idx, ())| idx);
+        Self {
+            curve: iter.next().unwrap(),
+            owner: iter.next().unwrap(),
+            system_program: iter.next().unwrap(),
+        }
+    }
+    pub fn try_from_indexes<'a>(
+        indexes: impl IntoIterator<Item = &'a u8>,
+    ) -> Result<Self, usize> {
+        let mut iter = indexes.into_iter().map(|idx| (*idx) as usize);
+        let mut idx = 0_usize;
+        Ok(Self {
+            curve: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            owner: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            system_program: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+        })
+    }
+}
+impl<'a> TryFrom<&'a [u8]> for ResizeCurveAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: &'a [u8]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(indexes)
+    }
+}
+impl<'a, const N: usize> TryFrom<&'a [u8; N]> for ResizeCurveAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: &'a [u8; N]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(indexes)
+    }
+}
+impl<const N: usize> TryFrom<[u8; N]> for ResizeCurveAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: [u8; N]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(&indexes)
+    }
+}
+impl TryFrom<Vec<u8>> for ResizeCurveAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: Vec<u8>) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(&indexes)
+    }
+}
+/// [CurvyInstruction::SetCurveTail] instruction account indexes helper
+#[derive(Debug, PartialEq)]
+pub struct SetCurveTailAccountIndexes {
+    pub curve: usize,
+    pub owner: usize,
+}
+impl SetCurveTailAccountIndexes {
+    pub const COUNT: usize = 2usize;
+    pub const CURVE: usize = 0usize;
+    pub const OWNER: usize = 1usize;
+    pub fn new_direct_order() -> Self {
+        let mut iter = std::iter::repeat(()).enumerate().map(|(idx, ())| idx);
+        Self {
+            curve: iter.next().unwrap(),
+            owner: iter.next().unwrap(),
+        }
+    }
+    pub fn try_from_indexes<'a>(
+        indexes: impl IntoIterator<Item = &'a u8>,
+    ) -> Result<Self, usize> {
+        let mut iter = indexes.into_iter().map(|idx| (*idx) as usize);
+        let mut idx = 0_usize;
+        Ok(Self {
+            curve: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            owner: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+        })
+    }
+}
+impl<'a> TryFrom<&'a [u8]> for SetCurveTailAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: &'a [u8]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(indexes)
+    }
+}
+impl<'a, const N: usize> TryFrom<&'a [u8; N]> for SetCurveTailAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: &'a [u8; N]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(indexes)
+    }
+}
+impl<const N: usize> TryFrom<[u8; N]> for SetCurveTailAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: [u8; N]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(&indexes)
+    }
+}
+impl TryFrom<Vec<u8>> for SetCurveTailAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: Vec<u8>) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(&indexes)
+    }
+}
+/// [CurvyInstruction::CreateGridCurve] instruction account indexes helper
+#[derive(Debug, PartialEq)]
+pub struct CreateGridCurveAccountIndexes {
+    pub curve: usize,
+    pub owner: usize,
+    pub config: usize,
+    pub name_link: usize,
+    pub system_program: usize,
+}
+impl CreateGridCurveAccountIndexes {
+    pub const COUNT: usize = 5usize;
+    pub const CURVE: usize = 0usize;
+    pub const OWNER: usize = 1usize;
+    pub const CONFIG: usize = 2usize;
+    pub const NAME_LINK: usize = 3usize;
+    pub const SYSTEM_PROGRAM: usize = 4usize;
+    pub fn new_direct_order() -> Self {
+        let mut iter = std::iter::repeat(()).enumerate().map(|(idx, ())| idx);
+        Self {
+            curve: iter.next().unwrap(),
+            owner: iter.next().unwrap(),
+            config: iter.next().unwrap(),
+            name_link: iter.next().unwrap(),
+            system_program: iter.next().unwrap(),
+        }
+    }
+    pub fn try_from_indexes<'a>(
+        indexes: impl IntoIterator<Item = &'a u8>,
+    ) -> Result<Self, usize> {
+        let mut iter = indexes.into_iter().map(|idx| (*idx) as usize);
+        let mut idx = 0_usize;
+        Ok(Self {
+            curve: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            owner: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            config: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            name_link: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            system_program: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+        })
+    }
+}
+impl<'a> TryFrom<&'a [u8]> for CreateGridCurveAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: &'a [u8]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(indexes)
+    }
+}
+impl<'a, const N: usize> TryFrom<&'a [u8; N]> for CreateGridCurveAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: &'a [u8; N]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(indexes)
+    }
+}
+impl<const N: usize> TryFrom<[u8; N]> for CreateGridCurveAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: [u8; N]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(&indexes)
+    }
+}
+impl TryFrom<Vec<u8>> for CreateGridCurveAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: Vec<u8>) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(&indexes)
+    }
+}
+/// [CurvyInstruction::AlterGridCurve] instruction account indexes helper
+#[derive(Debug, PartialEq)]
+pub struct AlterGridCurveAccountIndexes {
+    pub curve: usize,
+    pub owner: usize,
+}
+impl AlterGridCurveAccountIndexes {
+    pub const COUNT: usize = 2usize;
+    pub const CURVE: usize = 0usize;
+    pub const OWNER: usize = 1usize;
+    pub fn new_direct_order() -> Self {
+        let mut iter = std::iter::repeat(()).enumerate().map(|(idx, ())| idx);
+        Self {
+            curve: iter.next().unwrap(),
+            owner: iter.next().unwrap(),
+        }
+    }
+    pub fn try_from_indexes<'a>(
+        indexes: impl IntoIterator<Item = &'a u8>,
+    ) -> Result<Self, usize> {
+        let mut iter = indexes.into_iter().map(|idx| (*idx) as usize);
+        let mut idx = 0_usize;
+        Ok(Self {
+            curve: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            owner: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+        })
+    }
+}
+impl<'a> TryFrom<&'a [u8]> for AlterGridCurveAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: &'a [u8]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(indexes)
+    }
+}
+impl<'a, const N: usize> TryFrom<&'a [u8; N]> for AlterGridCurveAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: &'a [u8; N]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(indexes)
+    }
+}
+impl<const N: usize> TryFrom<[u8; N]> for AlterGridCurveAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: [u8; N]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(&indexes)
+    }
+}
+impl TryFrom<Vec<u8>> for AlterGridCurveAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: Vec<u8>) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(&indexes)
+    }
+}
+/// [CurvyInstruction::SetCurveYSign] instruction account indexes helper
+#[derive(Debug, PartialEq)]
+pub struct SetCurveYSignAccountIndexes {
+    pub curve: usize,
+    pub owner: usize,
+}
+impl SetCurveYSignAccountIndexes {
+    pub const COUNT: usize = 2usize;
+    pub const CURVE: usize = 0usize;
+    pub const OWNER: usize = 1usize;
+    pub fn new_direct_order() -> Self {
+        let mut iter = std::iter::repeat(()).enumerate().map(|(idx, ())| idx);
+        Self {
+            curve: iter.next().unwrap(),
+            owner: iter.next().unwrap(),
+        }
+    }
+    pub fn try_from_indexes<'a>(
+        indexes: impl IntoIterator<Item = &'a u8>,
+    ) -> Result<Self, usize> {
+        let mut iter = indexes.into_iter().map(|idx| (*idx) as usize);
+        let mut idx = 0_usize;
+        Ok(Self {
+            curve: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            owner: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+        })
+    }
+}
+impl<'a> TryFrom<&'a [u8]> for SetCurveYSignAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: &'a [u8]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(indexes)
+    }
+}
+impl<'a, const N: usize> TryFrom<&'a [u8; N]> for SetCurveYSignAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: &'a [u8; N]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(indexes)
+    }
+}
+impl<const N: usize> TryFrom<[u8; N]> for SetCurveYSignAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: [u8; N]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(&indexes)
+    }
+}
+impl TryFrom<Vec<u8>> for SetCurveYSignAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: Vec<u8>) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(&indexes)
+    }
+}
+/// [CurvyInstruction::SetCurveStatus] instruction account indexes helper
+#[derive(Debug, PartialEq)]
+pub struct SetCurveStatusAccountIndexes {
+    pub curve: usize,
+    pub owner: usize,
+}
+impl SetCurveStatusAccountIndexes {
+    pub const COUNT: usize = 2usize;
+    pub const CURVE: usize = 0usize;
+    pub const OWNER: usize = 1usize;
+    pub fn new_direct_order() -> Self {
+        let mut iter = std::iter::repeat(()).enumerate().map(|(idx, ())| idx);
+        Self {
+            curve: iter.next().unwrap(),
+            owner: iter.next().unwrap(),
+        }
+    }
+    pub fn try_from_indexes<'a>(
+        indexes: impl IntoIterator<Item = &'a u8>,
+    ) -> Result<Self, usize> {
+        let mut iter = indexes.into_iter().map(|idx| (*idx) as usize);
+        let mut idx = 0_usize;
+        Ok(Self {
+            curve: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            owner: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+        })
+    }
+}
+impl<'a> TryFrom<&'a [u8]> for SetCurveStatusAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: &'a [u8]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(indexes)
+    }
+}
+impl<'a, const N: usize> TryFrom<&'a [u8; N]> for SetCurveStatusAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: &'a [u8; N]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(indexes)
+    }
+}
+impl<const N: usize> TryFrom<[u8; N]> for SetCurveStatusAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: [u8; N]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(&indexes)
+    }
+}
+impl TryFrom<Vec<u8>> for SetCurveStatusAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: Vec<u8>) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(&indexes)
+    }
+}
+/// [CurvyInstruction::PatchY] instruction account indexes helper
+#[derive(Debug, PartialEq)]
+pub struct PatchYAccountIndexes {
+    pub curve: usize,
+    pub owner: usize,
+}
+impl PatchYAccountIndexes {
+    pub const COUNT: usize = 2usize;
+    pub const CURVE: usize = 0usize;
+    pub const OWNER: usize = 1usize;
+    pub fn new_direct_order() -> Self {
+        let mut iter = std::iter::repeat(()).enumerate().map(|(idx, ())| idx);
+        Self {
+            curve: iter.next().unwrap(),
+            owner: iter.next().unwrap(),
+        }
+    }
+    pub fn try_from_indexes<'a>(
+        indexes: impl IntoIterator<Item = &'a u8>,
+    ) -> Result<Self, usize> {
+        let mut iter = indexes.into_iter().map(|idx| (*idx) as usize);
+        let mut idx = 0_usize;
+        Ok(Self {
+            curve: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            owner: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+        })
+    }
+}
+impl<'a> TryFrom<&'a [u8]> for PatchYAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: &'a [u8]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(indexes)
+    }
+}
+impl<'a, const N: usize> TryFrom<&'a [u8; N]> for PatchYAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: &'a [u8; N]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(indexes)
+    }
+}
+impl<const N: usize> TryFrom<[u8; N]> for PatchYAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: [u8; N]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(&indexes)
+    }
+}
+impl TryFrom<Vec<u8>> for PatchYAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: Vec<u8>) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(&indexes)
+    }
+}
+/// [CurvyInstruction::MarkForDeletion] instruction account indexes helper
+#[derive(Debug, PartialEq)]
+pub struct MarkForDeletionAccountIndexes {
+    pub curve: usize,
+    pub owner: usize,
+}
+impl MarkForDeletionAccountIndexes {
+    pub const COUNT: usize = 2usize;
+    pub const CURVE: usize = 0usize;
+    pub const OWNER: usize = 1usize;
+    pub fn new_direct_order() -> Self {
+        let mut iter = std::iter::repeat(()).enumerate().map(|(idx, ())| idx);
+        Self {
+            curve: iter.next().unwrap(),
+            owner: iter.next().unwrap(),
+        }
+    }
+    pub fn try_from_indexes<'a>(
+        indexes: impl IntoIterator<Item = &'a u8>,
+    ) -> Result<Self, usize> {
+        let mut iter = indexes.into_iter().map(|idx| (*idx) as usize);
+        let mut idx = 0_usize;
+        Ok(Self {
+            curve: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            owner: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+        })
+    }
+}
+impl<'a> TryFrom<&'a [u8]> for MarkForDeletionAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: &'a [u8]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(indexes)
+    }
+}
+impl<'a, const N: usize> TryFrom<&'a [u8; N]> for MarkForDeletionAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: &'a [u8; N]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(indexes)
+    }
+}
+impl<const N: usize> TryFrom<[u8; N]> for MarkForDeletionAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: [u8; N]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(&indexes)
+    }
+}
+impl TryFrom<Vec<u8>> for MarkForDeletionAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: Vec<u8>) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(&indexes)
+    }
+}
+/// [CurvyInstruction::CancelDeletion] instruction account indexes helper
+#[derive(Debug, PartialEq)]
+pub struct CancelDeletionAccountIndexes {
+    pub curve: usize,
+    pub owner: usize,
+}
+impl CancelDeletionAccountIndexes {
+    pub const COUNT: usize = 2usize;
+    pub const CURVE: usize = 0usize;
+    pub const OWNER: usize = 1usize;
+    pub fn new_direct_order() -> Self {
+        let mut iter = std::iter::repeat(()).enumerate().map(|(idx, ())| idx);
+        Self {
+            curve: iter.next().unwrap(),
+            owner: iter.next().unwrap(),
+        }
+    }
+    pub fn try_from_indexes<'a>(
+        indexes: impl IntoIterator<Item = &'a u8>,
+    ) -> Result<Self, usize> {
+        let mut iter = indexes.into_iter().map(|idx| (*idx) as usize);
+        let mut idx = 0_usize;
+        Ok(Self {
+            curve: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            owner: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+        })
+    }
+}
+impl<'a> TryFrom<&'a [u8]> for CancelDeletionAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: &'a [u8]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(indexes)
+    }
+}
+impl<'a, const N: usize> TryFrom<&'a [u8; N]> for CancelDeletionAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: &'a [u8; N]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(indexes)
+    }
+}
+impl<const N: usize> TryFrom<[u8; N]> for CancelDeletionAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: [u8; N]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(&indexes)
+    }
+}
+impl TryFrom<Vec<u8>> for CancelDeletionAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: Vec<u8>) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(&indexes)
+    }
+}
+/// [CurvyInstruction::EvaluateCurve] instruction account indexes helper
+#[derive(Debug, PartialEq)]
+pub struct EvaluateCurveAccountIndexes {
+    pub curve: usize,
+    pub result: usize,
+    pub authority: usize,
+}
+impl EvaluateCurveAccountIndexes {
+    pub const COUNT: usize = 3usize;
+    pub const CURVE: usize = 0usize;
+    pub const RESULT: usize = 1usize;
+    pub const AUTHORITY: usize = 2usize;
+    pub fn new_direct_order() -> Self {
+        let mut iter = std::iter::repeat(()).enumerate().map(|(idx, ())| idx);
+        Self {
+            curve: iter.next().unwrap(),
+            result: iter.next().unwrap(),
+            authority: iter.next().unwrap(),
+        }
+    }
+    pub fn try_from_indexes<'a>(
+        indexes: impl IntoIterator<Item = &'a u8>,
+    ) -> Result<Self, usize> {
+        let mut iter = indexes.into_iter().map(|idx| (*idx) as usize);
+        let mut idx = 0_usize;
+        Ok(Self {
+            curve: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            result: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            authority: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+        })
+    }
+}
+impl<'a> TryFrom<&'a [u8]> for EvaluateCurveAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: &'a [u8]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(indexes)
+    }
+}
+impl<'a, const N: usize> TryFrom<&'a [u8; N]> for EvaluateCurveAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: &'a [u8; N]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(indexes)
+    }
+}
+impl<const N: usize> TryFrom<[u8; N]> for EvaluateCurveAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: [u8; N]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(&indexes)
+    }
+}
+impl TryFrom<Vec<u8>> for EvaluateCurveAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: Vec<u8>) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(&indexes)
+    }
+}
+/// [CurvyInstruction::SetAlterDelay] instruction account indexes helper
+#[derive(Debug, PartialEq)]
+pub struct SetAlterDelayAccountIndexes {
+    pub curve: usize,
+    pub owner: usize,
+}
+impl SetAlterDelayAccountIndexes {
+    pub const COUNT: usize = 2usize;
+    pub const CURVE: usize = 0usize;
+    pub const OWNER: usize = 1usize;
+    pub fn new_direct_order() -> Self {
+        let mut iter = std::iter::repeat(()).enumerate().map(|(idx, ())| idx);
+        Self {
+            curve: iter.next().unwrap(),
+            owner: iter.next().unwrap(),
+        }
+    }
+    pub fn try_from_indexes<'a>(
+        indexes: impl IntoIterator<Item = &'a u8>,
+    ) -> Result<Self, usize> {
+        let mut iter = indexes.into_iter().map(|idx| (*idx) as usize);
+        let mut idx = 0_usize;
+        Ok(Self {
+            curve: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            owner: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+        })
+    }
+}
+impl<'a> TryFrom<&'a [u8]> for SetAlterDelayAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: &'a [u8]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(indexes)
+    }
+}
+impl<'a, const N: usize> TryFrom<&'a [u8; N]> for SetAlterDelayAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: &'a [u8; N]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(indexes)
+    }
+}
+impl<const N: usize> TryFrom<[u8; N]> for SetAlterDelayAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: [u8; N]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(&indexes)
+    }
+}
+impl TryFrom<Vec<u8>> for SetAlterDelayAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: Vec<u8>) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(&indexes)
+    }
+}
+/// [CurvyInstruction::ProposeAlter] instruction account indexes helper
+#[derive(Debug, PartialEq)]
+pub struct ProposeAlterAccountIndexes {
+    pub curve: usize,
+    pub owner: usize,
+    pub pending_alter: usize,
+    pub system_program: usize,
+}
+impl ProposeAlterAccountIndexes {
+    pub const COUNT: usize = 4usize;
+    pub const CURVE: usize = 0usize;
+    pub const OWNER: usize = 1usize;
+    pub const PENDING_ALTER: usize = 2usize;
+    pub const SYSTEM_PROGRAM: usize = 3usize;
+    pub fn new_direct_order() -> Self {
+        let mut iter = std::iter::repeat(()).enumerate().map(|(idx, ())| idx);
+        Self {
+            curve: iter.next().unwrap(),
+            owner: iter.next().unwrap(),
+            pending_alter: iter.next().unwrap(),
+            system_program: iter.next().unwrap(),
+        }
+    }
+    pub fn try_from_indexes<'a>(
+        indexes: impl IntoIterator<Item = &'a u8>,
+    ) -> Result<Self, usize> {
+        let mut iter = indexes.into_iter().map(|idx| (*idx) as usize);
+        let mut idx = 0_usize;
+        Ok(Self {
+            curve: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            owner: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            pending_alter: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            system_program: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+        })
+    }
+}
+impl<'a> TryFrom<&'a [u8]> for ProposeAlterAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: &'a [u8]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(indexes)
+    }
+}
+impl<'a, const N: usize> TryFrom<&'a [u8; N]> for ProposeAlterAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: &'a [u8; N]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(indexes)
+    }
+}
+impl<const N: usize> TryFrom<[u8; N]> for ProposeAlterAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: [u8; N]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(&indexes)
+    }
+}
+impl TryFrom<Vec<u8>> for ProposeAlterAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: Vec<u8>) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(&indexes)
+    }
+}
+/// [CurvyInstruction::ApplyAlter] instruction account indexes helper
+#[derive(Debug, PartialEq)]
+pub struct ApplyAlterAccountIndexes {
+    pub curve: usize,
+    pub owner: usize,
+    pub pending_alter: usize,
+}
+impl ApplyAlterAccountIndexes {
+    pub const COUNT: usize = 3usize;
+    pub const CURVE: usize = 0usize;
+    pub const OWNER: usize = 1usize;
+    pub const PENDING_ALTER: usize = 2usize;
+    pub fn new_direct_order() -> Self {
+        let mut iter = std::iter::repeat(()).enumerate().map(|(idx, ())| idx);
+        Self {
+            curve: iter.next().unwrap(),
+            owner: iter.next().unwrap(),
+            pending_alter: iter.next().unwrap(),
+        }
+    }
+    pub fn try_from_indexes<'a>(
+        indexes: impl IntoIterator<Item = &'a u8>,
+    ) -> Result<Self, usize> {
+        let mut iter = indexes.into_iter().map(|idx| (*idx) as usize);
+        let mut idx = 0_usize;
+        Ok(Self {
+            curve: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            owner: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            pending_alter: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+        })
+    }
+}
+impl<'a> TryFrom<&'a [u8]> for ApplyAlterAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: &'a [u8]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(indexes)
+    }
+}
+impl<'a, const N: usize> TryFrom<&'a [u8; N]> for ApplyAlterAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: &'a [u8; N]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(indexes)
+    }
+}
+impl<const N: usize> TryFrom<[u8; N]> for ApplyAlterAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: [u8; N]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(&indexes)
+    }
+}
+impl TryFrom<Vec<u8>> for ApplyAlterAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: Vec<u8>) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(&indexes)
+    }
+}
+/// [CurvyInstruction::CancelAlter] instruction account indexes helper
+#[derive(Debug, PartialEq)]
+pub struct CancelAlterAccountIndexes {
+    pub curve: usize,
+    pub owner: usize,
+    pub pending_alter: usize,
+}
+impl CancelAlterAccountIndexes {
+    pub const COUNT: usize = 3usize;
+    pub const CURVE: usize = 0usize;
+    pub const OWNER: usize = 1usize;
+    pub const PENDING_ALTER: usize = 2usize;
+    pub fn new_direct_order() -> Self {
+        let mut iter = std::iter::repeat(()).enumerate().map(|(idx, ())| idx);
+        Self {
+            curve: iter.next().unwrap(),
+            owner: iter.next().unwrap(),
+            pending_alter: iter.next().unwrap(),
+        }
+    }
+    pub fn try_from_indexes<'a>(
+        indexes: impl IntoIterator<Item = &'a u8>,
+    ) -> Result<Self, usize> {
+        let mut iter = indexes.into_iter().map(|idx| (*idx) as usize);
+        let mut idx = 0_usize;
+        Ok(Self {
+            curve: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            owner: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            pending_alter: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+        })
+    }
+}
+impl<'a> TryFrom<&'a [u8]> for CancelAlterAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: &'a [u8]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(indexes)
+    }
+}
+impl<'a, const N: usize> TryFrom<&'a [u8; N]> for CancelAlterAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: &'a [u8; N]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(indexes)
+    }
+}
+impl<const N: usize> TryFrom<[u8; N]> for CancelAlterAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: [u8; N]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(&indexes)
+    }
+}
+impl TryFrom<Vec<u8>> for CancelAlterAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: Vec<u8>) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(&indexes)
+    }
+}
+/// [CurvyInstruction::AddDelegate] instruction account indexes helper
+#[derive(Debug, PartialEq)]
+pub struct AddDelegateAccountIndexes {
+    pub curve: usize,
+    pub owner: usize,
+}
+impl AddDelegateAccountIndexes {
+    pub const COUNT: usize = 2usize;
+    pub const CURVE: usize = 0usize;
+    pub const OWNER: usize = 1usize;
+    pub fn new_direct_order() -> Self {
+        let mut iter = std::iter::repeat(()).enumerate().map(|(idx, ())| idx);
+        Self {
+            curve: iter.next().unwrap(),
+            owner: iter.next().unwrap(),
+        }
+    }
+    pub fn try_from_indexes<'a>(
+        indexes: impl IntoIterator<Item = &'a u8>,
+    ) -> Result<Self, usize> {
+        let mut iter = indexes.into_iter().map(|idx| (*idx) as usize);
+        let mut idx = 0_usize;
+        Ok(Self {
+            curve: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            owner: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+        })
+    }
+}
+impl<'a> TryFrom<&'a [u8]> for AddDelegateAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: &'a [u8]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(indexes)
+    }
+}
+impl<'a, const N: usize> TryFrom<&'a [u8; N]> for AddDelegateAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: &'a [u8; N]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(indexes)
+    }
+}
+impl<const N: usize> TryFrom<[u8; N]> for AddDelegateAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: [u8; N]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(&indexes)
+    }
+}
+impl TryFrom<Vec<u8>> for AddDelegateAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: Vec<u8>) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(&indexes)
+    }
+}
+/// [CurvyInstruction::RemoveDelegate] instruction account indexes helper
+#[derive(Debug, PartialEq)]
+pub struct RemoveDelegateAccountIndexes {
+    pub curve: usize,
+    pub owner: usize,
+}
+impl RemoveDelegateAccountIndexes {
+    pub const COUNT: usize = 2usize;
+    pub const CURVE: usize = 0usize;
+    pub const OWNER: usize = 1usize;
+    pub fn new_direct_order() -> Self {
+        let mut iter = std::iter::repeat(()).enumerate().map(|(idx, ())| idx);
+        Self {
+            curve: iter.next().unwrap(),
+            owner: iter.next().unwrap(),
+        }
+    }
+    pub fn try_from_indexes<'a>(
+        indexes: impl IntoIterator<Item = &'a u8>,
+    ) -> Result<Self, usize> {
+        let mut iter = indexes.into_iter().map(|idx| (*idx) as usize);
+        let mut idx = 0_usize;
+        Ok(Self {
+            curve: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            owner: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+        })
+    }
+}
+impl<'a> TryFrom<&'a [u8]> for RemoveDelegateAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: &'a [u8]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(indexes)
+    }
+}
+impl<'a, const N: usize> TryFrom<&'a [u8; N]> for RemoveDelegateAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: &'a [u8; N]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(indexes)
+    }
+}
+impl<const N: usize> TryFrom<[u8; N]> for RemoveDelegateAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: [u8; N]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(&indexes)
+    }
+}
+impl TryFrom<Vec<u8>> for RemoveDelegateAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: Vec<u8>) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(&indexes)
+    }
+}
+/// [CurvyInstruction::InitConfig] instruction account indexes helper
+#[derive(Debug, PartialEq)]
+pub struct InitConfigAccountIndexes {
+    pub config: usize,
+    pub admin: usize,
+    pub program_data: usize,
+    pub system_program: usize,
+}
+impl InitConfigAccountIndexes {
+    pub const COUNT: usize = 4usize;
+    pub const CONFIG: usize = 0usize;
+    pub const ADMIN: usize = 1usize;
+    pub const PROGRAM_DATA: usize = 2usize;
+    pub const SYSTEM_PROGRAM: usize = 3usize;
+    pub fn new_direct_order() -> Self {
+        let mut iter = std::iter::repeat(()).enumerate().map(|(idx, ())| idx);
+        Self {
+            config: iter.next().unwrap(),
+            admin: iter.next().unwrap(),
+            program_data: iter.next().unwrap(),
+            system_program: iter.next().unwrap(),
+        }
+    }
+    pub fn try_from_indexes<'a>(
+        indexes: impl IntoIterator<Item = &'a u8>,
+    ) -> Result<Self, usize> {
+        let mut iter = indexes.into_iter().map(|idx| (*idx) as usize);
+        let mut idx = 0_usize;
+        Ok(Self {
+            config: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            admin: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            program_data: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            system_program: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+        })
+    }
+}
+impl<'a> TryFrom<&'a [u8]> for InitConfigAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: &'a [u8]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(indexes)
+    }
+}
+impl<'a, const N: usize> TryFrom<&'a [u8; N]> for InitConfigAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: &'a [u8; N]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(indexes)
+    }
+}
+impl<const N: usize> TryFrom<[u8; N]> for InitConfigAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: [u8; N]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(&indexes)
+    }
+}
+impl TryFrom<Vec<u8>> for InitConfigAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: Vec<u8>) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(&indexes)
+    }
+}
+/// [CurvyInstruction::SetConfig] instruction account indexes helper
+#[derive(Debug, PartialEq)]
+pub struct SetConfigAccountIndexes {
+    pub config: usize,
+    pub admin: usize,
+}
+impl SetConfigAccountIndexes {
+    pub const COUNT: usize = 2usize;
+    pub const CONFIG: usize = 0usize;
+    pub const ADMIN: usize = 1usize;
+    pub fn new_direct_order() -> Self {
+        let mut iter = std::iter::repeat(()).enumerate().map(|(idx, ())| idx);
+        Self {
+            config: iter.next().unwrap(),
+            admin: iter.next().unwrap(),
+        }
+    }
+    pub fn try_from_indexes<'a>(
+        indexes: impl IntoIterator<Item = &'a u8>,
+    ) -> Result<Self, usize> {
+        let mut iter = indexes.into_iter().map(|idx| (*idx) as usize);
+        let mut idx = 0_usize;
+        Ok(Self {
+            config: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            admin: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+        })
+    }
+}
+impl<'a> TryFrom<&'a [u8]> for SetConfigAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: &'a [u8]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(indexes)
+    }
+}
+impl<'a, const N: usize> TryFrom<&'a [u8; N]> for SetConfigAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: &'a [u8; N]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(indexes)
+    }
+}
+impl<const N: usize> TryFrom<[u8; N]> for SetConfigAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: [u8; N]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(&indexes)
+    }
+}
+impl TryFrom<Vec<u8>> for SetConfigAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: Vec<u8>) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(&indexes)
+    }
+}
+/// [CurvyInstruction::SetCurveOwners] instruction account indexes helper
+#[derive(Debug, PartialEq)]
+pub struct SetCurveOwnersAccountIndexes {
+    pub curve: usize,
+    pub owner: usize,
+    pub curve_owners: usize,
+    pub system_program: usize,
+}
+impl SetCurveOwnersAccountIndexes {
+    pub const COUNT: usize = 4usize;
+    pub const CURVE: usize = 0usize;
+    pub const OWNER: usize = 1usize;
+    pub const CURVE_OWNERS: usize = 2usize;
+    pub const SYSTEM_PROGRAM: usize = 3usize;
+    pub fn new_direct_order() -> Self {
+        let mut iter = std::iter::repeat(()).enumerate().map(|(idx, ())| idx);
+        Self {
+            curve: iter.next().unwrap(),
+            owner: iter.next().unwrap(),
+            curve_owners: iter.next().unwrap(),
+            system_program: iter.next().unwrap(),
+        }
+    }
+    pub fn try_from_indexes<'a>(
+        indexes: impl IntoIterator<Item = &'a u8>,
+    ) -> Result<Self, usize> {
+        let mut iter = indexes.into_iter().map(|idx| (*idx) as usize);
+        let mut idx = 0_usize;
+        Ok(Self {
+            curve: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            owner: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            curve_owners: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            system_program: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+        })
+    }
+}
+impl<'a> TryFrom<&'a [u8]> for SetCurveOwnersAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: &'a [u8]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(indexes)
+    }
+}
+impl<'a, const N: usize> TryFrom<&'a [u8; N]> for SetCurveOwnersAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: &'a [u8; N]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(indexes)
+    }
+}
+impl<const N: usize> TryFrom<[u8; N]> for SetCurveOwnersAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: [u8; N]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(&indexes)
+    }
+}
+impl TryFrom<Vec<u8>> for SetCurveOwnersAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: Vec<u8>) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(&indexes)
+    }
+}
+/// [CurvyInstruction::SetCurveExtension] instruction account indexes helper
+#[derive(Debug, PartialEq)]
+pub struct SetCurveExtensionAccountIndexes {
+    pub curve: usize,
+    pub owner: usize,
+    pub system_program: usize,
+}
+impl SetCurveExtensionAccountIndexes {
+    pub const COUNT: usize = 3usize;
+    pub const CURVE: usize = 0usize;
+    pub const OWNER: usize = 1usize;
+    pub const SYSTEM_PROGRAM: usize = 2usize;
+    pub fn new_direct_order() -> Self {
+        let mut iter = std::iter::repeat(()).enumerate().map(|(idx, ())| idx);
+        Self {
+            curve: iter.next().unwrap(),
+            owner: iter.next().unwrap(),
+            system_program: iter.next().unwrap(),
+        }
+    }
+    pub fn try_from_indexes<'a>(
+        indexes: impl IntoIterator<Item = &'a u8>,
+    ) -> Result<Self, usize> {
+        let mut iter = indexes.into_iter().map(|idx| (*idx) as usize);
+        let mut idx = 0_usize;
+        Ok(Self {
+            curve: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            owner: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            system_program: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+        })
+    }
+}
+impl<'a> TryFrom<&'a [u8]> for SetCurveExtensionAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: &'a [u8]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(indexes)
+    }
+}
+impl<'a, const N: usize> TryFrom<&'a [u8; N]> for SetCurveExtensionAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: &'a [u8; N]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(indexes)
+    }
+}
+impl<const N: usize> TryFrom<[u8; N]> for SetCurveExtensionAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: [u8; N]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(&indexes)
+    }
+}
+impl TryFrom<Vec<u8>> for SetCurveExtensionAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: Vec<u8>) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(&indexes)
+    }
+}
+/// [CurvyInstruction::SetCurveYTransform] instruction account indexes helper
+#[derive(Debug, PartialEq)]
+pub struct SetCurveYTransformAccountIndexes {
+    pub curve: usize,
+    pub owner: usize,
+}
+impl SetCurveYTransformAccountIndexes {
+    pub const COUNT: usize = 2usize;
+    pub const CURVE: usize = 0usize;
+    pub const OWNER: usize = 1usize;
+    pub fn new_direct_order() -> Self {
+        let mut iter = std::iter::repeat(()).enumerate().map(|(idx, ())| idx);
+        Self {
+            curve: iter.next().unwrap(),
+            owner: iter.next().unwrap(),
+        }
+    }
+    pub fn try_from_indexes<'a>(
+        indexes: impl IntoIterator<Item = &'a u8>,
+    ) -> Result<Self, usize> {
+        let mut iter = indexes.into_iter().map(|idx| (*idx) as usize);
+        let mut idx = 0_usize;
+        Ok(Self {
+            curve: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            owner: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+        })
+    }
+}
+impl<'a> TryFrom<&'a [u8]> for SetCurveYTransformAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: &'a [u8]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(indexes)
+    }
+}
+impl<'a, const N: usize> TryFrom<&'a [u8; N]> for SetCurveYTransformAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: &'a [u8; N]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(indexes)
+    }
+}
+impl<const N: usize> TryFrom<[u8; N]> for SetCurveYTransformAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: [u8; N]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(&indexes)
+    }
+}
+impl TryFrom<Vec<u8>> for SetCurveYTransformAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: Vec<u8>) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(&indexes)
+    }
+}
+/// [CurvyInstruction::SetCurveExpiry] instruction account indexes helper
+#[derive(Debug, PartialEq)]
+pub struct SetCurveExpiryAccountIndexes {
+    pub curve: usize,
+    pub owner: usize,
+}
+impl SetCurveExpiryAccountIndexes {
+    pub const COUNT: usize = 2usize;
+    pub const CURVE: usize = 0usize;
+    pub const OWNER: usize = 1usize;
+    pub fn new_direct_order() -> Self {
+        let mut iter = std::iter::repeat(()).enumerate().map(|(idx, ())| idx);
+        Self {
+            curve: iter.next().unwrap(),
+            owner: iter.next().unwrap(),
+        }
+    }
+    pub fn try_from_indexes<'a>(
+        indexes: impl IntoIterator<Item = &'a u8>,
+    ) -> Result<Self, usize> {
+        let mut iter = indexes.into_iter().map(|idx| (*idx) as usize);
+        let mut idx = 0_usize;
+        Ok(Self {
+            curve: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            owner: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+        })
+    }
+}
+impl<'a> TryFrom<&'a [u8]> for SetCurveExpiryAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: &'a [u8]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(indexes)
+    }
+}
+impl<'a, const N: usize> TryFrom<&'a [u8; N]> for SetCurveExpiryAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: &'a [u8; N]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(indexes)
+    }
+}
+impl<const N: usize> TryFrom<[u8; N]> for SetCurveExpiryAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: [u8; N]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(&indexes)
+    }
+}
+impl TryFrom<Vec<u8>> for SetCurveExpiryAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: Vec<u8>) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(&indexes)
+    }
+}
+/// [CurvyInstruction::BatchAlter] instruction account indexes helper
+#[derive(Debug, PartialEq)]
+pub struct BatchAlterAccountIndexes {
+    pub owner: usize,
+}
+impl BatchAlterAccountIndexes {
+    pub const COUNT: usize = 1usize;
+    pub const OWNER: usize = 0usize;
+    pub fn new_direct_order() -> Self {
+        let mut iter = std::iter::repeat(()).enumerate().map(|(idx, ())| idx);
+        Self {
+            owner: iter.next().unwrap(),
+        }
+    }
+    pub fn try_from_indexes<'a>(
+        indexes: impl IntoIterator<Item = &'a u8>,
+    ) -> Result<Self, usize> {
+        let mut iter = indexes.into_iter().map(|idx| (*idx) as usize);
+        let mut idx = 0_usize;
+        Ok(Self {
+            owner: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+        })
+    }
+}
+impl<'a> TryFrom<&'a [u8]> for BatchAlterAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: &'a [u8]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(indexes)
+    }
+}
+impl<'a, const N: usize> TryFrom<&'a [u8; N]> for BatchAlterAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: &'a [u8; N]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(indexes)
+    }
+}
+impl<const N: usize> TryFrom<[u8; N]> for BatchAlterAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: [u8; N]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(&indexes)
+    }
+}
+impl TryFrom<Vec<u8>> for BatchAlterAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: Vec<u8>) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(&indexes)
+    }
+}
+/// [CurvyInstruction::CreateReferenceCurve] instruction account indexes helper
+#[derive(Debug, PartialEq)]
+pub struct CreateReferenceCurveAccountIndexes {
+    pub curve: usize,
+    pub admin: usize,
+    pub config: usize,
+    pub asset_mint: usize,
+    pub name_link: usize,
+    pub system_program: usize,
+}
+impl CreateReferenceCurveAccountIndexes {
+    pub const COUNT: usize = 6usize;
+    pub const CURVE: usize = 0usize;
+    pub const ADMIN: usize = 1usize;
+    pub const CONFIG: usize = 2usize;
+    pub const ASSET_MINT: usize = 3usize;
+    pub const NAME_LINK: usize = 4usize;
+    pub const SYSTEM_PROGRAM: usize = 5usize;
+    pub fn new_direct_order() -> Self {
+        let mut iter = std::iter::repeat(()).enumerate().map(|(idx, ())| idx);
+        Self {
+            curve: iter.next().unwrap(),
+            admin: iter.next().unwrap(),
+            config: iter.next().unwrap(),
+            asset_mint: iter.next().unwrap(),
+            name_link: iter.next().unwrap(),
+            system_program: iter.next().unwrap(),
+        }
+    }
+    pub fn try_from_indexes<'a>(
+        indexes: impl IntoIterator<Item = &'a u8>,
+    ) -> Result<Self, usize> {
+        let mut iter = indexes.into_iter().map(|idx| (*idx) as usize);
+        let mut idx = 0_usize;
+        Ok(Self {
+            curve: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            admin: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            config: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            asset_mint: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            name_link: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            system_program: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+        })
+    }
+}
+impl<'a> TryFrom<&'a [u8]> for CreateReferenceCurveAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: &'a [u8]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(indexes)
+    }
+}
+impl<'a, const N: usize> TryFrom<&'a [u8; N]> for CreateReferenceCurveAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: &'a [u8; N]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(indexes)
+    }
+}
+impl<const N: usize> TryFrom<[u8; N]> for CreateReferenceCurveAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: [u8; N]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(&indexes)
+    }
+}
+impl TryFrom<Vec<u8>> for CreateReferenceCurveAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: Vec<u8>) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(&indexes)
+    }
+}
+/// [CurvyInstruction::SetCreationFrozen] instruction account indexes helper
+#[derive(Debug, PartialEq)]
+pub struct SetCreationFrozenAccountIndexes {
+    pub config: usize,
+    pub admin: usize,
+}
+impl SetCreationFrozenAccountIndexes {
+    pub const COUNT: usize = 2usize;
+    pub const CONFIG: usize = 0usize;
+    pub const ADMIN: usize = 1usize;
+    pub fn new_direct_order() -> Self {
+        let mut iter = std::iter::repeat(()).enumerate().map(|(idx, ())| idx);
+        Self {
+            config: iter.next().unwrap(),
+            admin: iter.next().unwrap(),
+        }
+    }
+    pub fn try_from_indexes<'a>(
+        indexes: impl IntoIterator<Item = &'a u8>,
+    ) -> Result<Self, usize> {
+        let mut iter = indexes.into_iter().map(|idx| (*idx) as usize);
+        let mut idx = 0_usize;
+        Ok(Self {
+            config: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+            admin: {
+                idx += 1;
+                iter.next().ok_or(idx - 1)?
+            },
+        })
+    }
+}
+impl<'a> TryFrom<&'a [u8]> for SetCreationFrozenAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: &'a [u8]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(indexes)
+    }
+}
+impl<'a, const N: usize> TryFrom<&'a [u8; N]> for SetCreationFrozenAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: &'a [u8; N]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(indexes)
+    }
+}
+impl<const N: usize> TryFrom<[u8; N]> for SetCreationFrozenAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: [u8; N]) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(&indexes)
+    }
+}
+impl TryFrom<Vec<u8>> for SetCreationFrozenAccountIndexes {
+    type Error = usize;
+    fn try_from(indexes: Vec<u8>) -> Result<Self, Self::Error> {
+        Self::try_from_indexes(&indexes)
+    }
+}
+///[CurvyInstruction::CreateCurve] instruction account infos helper
+#[derive(Debug)]
+pub struct CreateCurveAccounts<'a, 'i> {
+    ///Curve account to create.
+    pub curve: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Curve owner.
+    pub owner: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Program config, its creator allow-list is checked when it exists.
+    pub config: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Name link of the owner and the curve name, created or pointed to the curve.
+    pub name_link: &'a solana_program::account_info::AccountInfo<'i>,
+    ///System program
+    pub system_program: &'a solana_program::account_info::AccountInfo<'i>,
+}
+impl<'a, 'i> CreateCurveAccounts<'a, 'i> {
+    pub fn from_iter<I>(
+        iter: &mut I,
+        program_id: &solana_program::pubkey::Pubkey,
+    ) -> std::result::Result<Self, texture_common::macros::accounts::AccountParseError>
+    where
+        I: Iterator<Item = &'a solana_program::account_info::AccountInfo<'i>>,
+    {
+        let __self_program_id__ = program_id;
+        let curve = texture_common::utils::next_account_info(iter)?;
+        let owner = texture_common::utils::next_account_info(iter)?;
+        let config = texture_common::utils::next_account_info(iter)?;
+        let name_link = texture_common::utils::next_account_info(iter)?;
+        let system_program = texture_common::utils::next_account_info(iter)?;
+        #[cfg(not(feature = "program-id-manually"))] #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            __self_program_id__,
+            &crate::ID,
+            "self_program_id",
+        )?;
+        if !curve.is_writable {
+            solana_program::msg!(concat!(stringify!(curve), " is not writable"));
+            return Err(texture_common::error::InvalidAccount(*curve.key).into());
+        }
+        if !curve.is_signer {
+            return Err(texture_common::error::MissingSignature(*curve.key).into());
+        }
+        #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            curve.owner,
+            &solana_program::system_program::ID,
+            concat!(stringify!(curve), " owner"),
+        )?;
+        if curve.data_len() != 0 {
+            solana_program::msg!(
+                concat!("invalid ", stringify!(curve), " account size")
+            );
+            return Err(texture_common::error::InvalidAccount(*curve.key).into());
+        }
+        if !owner.is_writable {
+            solana_program::msg!(concat!(stringify!(owner), " is not writable"));
+            return Err(texture_common::error::InvalidAccount(*owner.key).into());
+        }
+        if !owner.is_signer {
+            return Err(texture_common::error::MissingSignature(*owner.key).into());
+        }
+        if !name_link.is_writable {
+            solana_program::msg!(concat!(stringify!(name_link), " is not writable"));
+            return Err(texture_common::error::InvalidAccount(*name_link.key).into());
+        }
+        #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            system_program.key,
+            &solana_program::system_program::ID,
+            stringify!(system_program),
+        )?;
+        Ok(Self {
+            curve,
+            owner,
+            config,
+            name_link,
+            system_program,
+        })
+    }
+}
+///[CurvyInstruction::AlterCurve] instruction account infos helper
+#[derive(Debug)]
+pub struct AlterCurveAccounts<'a, 'i> {
+    ///Curve account to update.
+    pub curve: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Curve owner or its delegate, any owner of co-owned curve.
+    pub owner: &'a solana_program::account_info::AccountInfo<'i>,
+}
+impl<'a, 'i> AlterCurveAccounts<'a, 'i> {
+    pub fn from_iter<I>(
+        iter: &mut I,
+        program_id: &solana_program::pubkey::Pubkey,
+    ) -> std::result::Result<Self, texture_common::macros::accounts::AccountParseError>
+    where
+        I: Iterator<Item = &'a solana_program::account_info::AccountInfo<'i>>,
+    {
+        let __self_program_id__ = program_id;
+        let curve = texture_common::utils::next_account_info(iter)?;
+        let owner = texture_common::utils::next_account_info(iter)?;
+        #[cfg(not(feature = "program-id-manually"))] #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            __self_program_id__,
+            &crate::ID,
+            "self_program_id",
+        )?;
+        if !curve.is_writable {
+            solana_program::msg!(concat!(stringify!(curve), " is not writable"));
+            return Err(texture_common::error::InvalidAccount(*curve.key).into());
+        }
+        #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            curve.owner,
+            &__self_program_id__,
+            concat!(stringify!(curve), " owner"),
+        )?;
+        if !owner.is_signer {
+            return Err(texture_common::error::MissingSignature(*owner.key).into());
+        }
+        Ok(Self { curve, owner })
+    }
+}
+///[CurvyInstruction::DeleteCurve] instruction account infos helper
+#[derive(Debug)]
+pub struct DeleteCurveAccounts<'a, 'i> {
+    ///Curve account to delete.
+    pub curve: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Curve owner, receives the rent unless the rent destination is given.
+    pub owner: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Name link of the owner and the curve name, closed if it points to the curve.
+    pub name_link: &'a solana_program::account_info::AccountInfo<'i>,
+}
+impl<'a, 'i> DeleteCurveAccounts<'a, 'i> {
+    pub fn from_iter<I>(
+        iter: &mut I,
+        program_id: &solana_program::pubkey::Pubkey,
+    ) -> std::result::Result<Self, texture_common::macros::accounts::AccountParseError>
+    where
+        I: Iterator<Item = &'a solana_program::account_info::AccountInfo<'i>>,
+    {
+        let __self_program_id__ = program_id;
+        let curve = texture_common::utils::next_account_info(iter)?;
+        let owner = texture_common::utils::next_account_info(iter)?;
+        let name_link = texture_common::utils::next_account_info(iter)?;
+        #[cfg(not(feature = "program-id-manually"))] #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            __self_program_id__,
+            &crate::ID,
+            "self_program_id",
+        )?;
+        if !curve.is_writable {
+            solana_program::msg!(concat!(stringify!(curve), " is not writable"));
+            return Err(texture_common::error::InvalidAccount(*curve.key).into());
+        }
+        #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            curve.owner,
+            &__self_program_id__,
+            concat!(stringify!(curve), " owner"),
+        )?;
+        if !owner.is_writable {
+            solana_program::msg!(concat!(stringify!(owner), " is not writable"));
+            return Err(texture_common::error::InvalidAccount(*owner.key).into());
+        }
+        if !owner.is_signer {
+            return Err(texture_common::error::MissingSignature(*owner.key).into());
+        }
+        if !name_link.is_writable {
+            solana_program::msg!(concat!(stringify!(name_link), " is not writable"));
+            return Err(texture_common::error::InvalidAccount(*name_link.key).into());
+        }
+        Ok(Self { curve, owner, name_link })
+    }
+}
+///[CurvyInstruction::UpdateCurveMetadata] instruction account infos helper
+#[derive(Debug)]
+pub struct UpdateCurveMetadataAccounts<'a, 'i> {
+    ///Curve account to update.
+    pub curve: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Curve owner, pays for the name link of the new name.
+    pub owner: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Name link of the owner and the current name, closed if it points to the curve.
+    pub name_link: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Name link of the owner and the new name, created or pointed to the curve.
+    pub new_name_link: &'a solana_program::account_info::AccountInfo<'i>,
+    ///System program
+    pub system_program: &'a solana_program::account_info::AccountInfo<'i>,
+}
+impl<'a, 'i> UpdateCurveMetadataAccounts<'a, 'i> {
+    pub fn from_iter<I>(
+        iter: &mut I,
+        program_id: &solana_program::pubkey::Pubkey,
+    ) -> std::result::Result<Self, texture_common::macros::accounts::AccountParseError>
+    where
+        I: Iterator<Item = &'a solana_program::account_info::AccountInfo<'i>>,
+    {
+        let __self_program_id__ = program_id;
+        let curve = texture_common::utils::next_account_info(iter)?;
+        let owner = texture_common::utils::next_account_info(iter)?;
+        let name_link = texture_common::utils::next_account_info(iter)?;
+        let new_name_link = texture_common::utils::next_account_info(iter)?;
+        let system_program = texture_common::utils::next_account_info(iter)?;
+        #[cfg(not(feature = "program-id-manually"))] #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            __self_program_id__,
+            &crate::ID,
+            "self_program_id",
+        )?;
+        if !curve.is_writable {
+            solana_program::msg!(concat!(stringify!(curve), " is not writable"));
+            return Err(texture_common::error::InvalidAccount(*curve.key).into());
+        }
+        #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            curve.owner,
+            &__self_program_id__,
+            concat!(stringify!(curve), " owner"),
+        )?;
+        if !owner.is_writable {
+            solana_program::msg!(concat!(stringify!(owner), " is not writable"));
+            return Err(texture_common::error::InvalidAccount(*owner.key).into());
+        }
+        if !owner.is_signer {
+            return Err(texture_common::error::MissingSignature(*owner.key).into());
+        }
+        if !name_link.is_writable {
+            solana_program::msg!(concat!(stringify!(name_link), " is not writable"));
+            return Err(texture_common::error::InvalidAccount(*name_link.key).into());
+        }
+        if !new_name_link.is_writable {
+            solana_program::msg!(concat!(stringify!(new_name_link), " is not writable"));
+            return Err(texture_common::error::InvalidAccount(*new_name_link.key).into());
+        }
+        #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            system_program.key,
+            &solana_program::system_program::ID,
+            stringify!(system_program),
+        )?;
+        Ok(Self {
+            curve,
+            owner,
+            name_link,
+            new_name_link,
+            system_program,
+        })
+    }
+}
+///[CurvyInstruction::UpdateCurveData] instruction account infos helper
+#[derive(Debug)]
+pub struct UpdateCurveDataAccounts<'a, 'i> {
+    ///Curve account to update.
+    pub curve: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Curve owner or its delegate.
+    pub owner: &'a solana_program::account_info::AccountInfo<'i>,
+}
+impl<'a, 'i> UpdateCurveDataAccounts<'a, 'i> {
+    pub fn from_iter<I>(
+        iter: &mut I,
+        program_id: &solana_program::pubkey::Pubkey,
+    ) -> std::result::Result<Self, texture_common::macros::accounts::AccountParseError>
+    where
+        I: Iterator<Item = &'a solana_program::account_info::AccountInfo<'i>>,
+    {
+        let __self_program_id__ = program_id;
+        let curve = texture_common::utils::next_account_info(iter)?;
+        let owner = texture_common::utils::next_account_info(iter)?;
+        #[cfg(not(feature = "program-id-manually"))] #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            __self_program_id__,
+            &crate::ID,
+            "self_program_id",
+        )?;
+        if !curve.is_writable {
+            solana_program::msg!(concat!(stringify!(curve), " is not writable"));
+            return Err(texture_common::error::InvalidAccount(*curve.key).into());
+        }
+        #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            curve.owner,
+            &__self_program_id__,
+            concat!(stringify!(curve), " owner"),
+        )?;
+        if !owner.is_signer {
+            return Err(texture_common::error::MissingSignature(*owner.key).into());
+        }
+        Ok(Self { curve, owner })
+    }
+}
+///[CurvyInstruction::ReserveCurve] instruction account infos helper
+#[derive(Debug)]
+pub struct ReserveCurveAccounts<'a, 'i> {
+    ///Curve account to create.
+    pub curve: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Curve owner.
+    pub owner: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Program config, its creator allow-list is checked when it exists.
+    pub config: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Name link of the owner and the curve name, created or pointed to the curve.
+    pub name_link: &'a solana_program::account_info::AccountInfo<'i>,
+    ///System program
+    pub system_program: &'a solana_program::account_info::AccountInfo<'i>,
+}
+impl<'a, 'i> ReserveCurveAccounts<'a, 'i> {
+    pub fn from_iter<I>(
+        iter: &mut I,
+        program_id: &solana_program::pubkey::Pubkey,
+    ) -> std::result::Result<Self, texture_common::macros::accounts::AccountParseError>
+    where
+        I: Iterator<Item = &'a solana_program::account_info::AccountInfo<'i>>,
+    {
+        let __self_program_id__ = program_id;
+        let curve = texture_common::utils::next_account_info(iter)?;
+        let owner = texture_common::utils::next_account_info(iter)?;
+        let config = texture_common::utils::next_account_info(iter)?;
+        let name_link = texture_common::utils::next_account_info(iter)?;
+        let system_program = texture_common::utils::next_account_info(iter)?;
+        #[cfg(not(feature = "program-id-manually"))] #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            __self_program_id__,
+            &crate::ID,
+            "self_program_id",
+        )?;
+        if !curve.is_writable {
+            solana_program::msg!(concat!(stringify!(curve), " is not writable"));
+            return Err(texture_common::error::InvalidAccount(*curve.key).into());
+        }
+        if !curve.is_signer {
+            return Err(texture_common::error::MissingSignature(*curve.key).into());
+        }
+        #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            curve.owner,
+            &solana_program::system_program::ID,
+            concat!(stringify!(curve), " owner"),
+        )?;
+        if curve.data_len() != 0 {
+            solana_program::msg!(
+                concat!("invalid ", stringify!(curve), " account size")
+            );
+            return Err(texture_common::error::InvalidAccount(*curve.key).into());
+        }
+        if !owner.is_writable {
+            solana_program::msg!(concat!(stringify!(owner), " is not writable"));
+            return Err(texture_common::error::InvalidAccount(*owner.key).into());
+        }
+        if !owner.is_signer {
+            return Err(texture_common::error::MissingSignature(*owner.key).into());
+        }
+        if !name_link.is_writable {
+            solana_program::msg!(concat!(stringify!(name_link), " is not writable"));
+            return Err(texture_common::error::InvalidAccount(*name_link.key).into());
+        }
+        #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            system_program.key,
+            &solana_program::system_program::ID,
+            stringify!(system_program),
+        )?;
+        Ok(Self {
+            curve,
+            owner,
+            config,
+            name_link,
+            system_program,
+        })
+    }
+}
+///[CurvyInstruction::TopUpRent] instruction account infos helper
+#[derive(Debug)]
+pub struct TopUpRentAccounts<'a, 'i> {
+    ///Curve account to top up.
+    pub curve: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Account paying the missing lamports.
+    pub payer: &'a solana_program::account_info::AccountInfo<'i>,
+    ///System program
+    pub system_program: &'a solana_program::account_info::AccountInfo<'i>,
+}
+impl<'a, 'i> TopUpRentAccounts<'a, 'i> {
+    pub fn from_iter<I>(
+        iter: &mut I,
+        program_id: &solana_program::pubkey::Pubkey,
+    ) -> std::result::Result<Self, texture_common::macros::accounts::AccountParseError>
+    where
+        I: Iterator<Item = &'a solana_program::account_info::AccountInfo<'i>>,
+    {
+        let __self_program_id__ = program_id;
+        let curve = texture_common::utils::next_account_info(iter)?;
+        let payer = texture_common::utils::next_account_info(iter)?;
+        let system_program = texture_common::utils::next_account_info(iter)?;
+        #[cfg(not(feature = "program-id-manually"))] #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            __self_program_id__,
+            &crate::ID,
+            "self_program_id",
+        )?;
+        if !curve.is_writable {
+            solana_program::msg!(concat!(stringify!(curve), " is not writable"));
+            return Err(texture_common::error::InvalidAccount(*curve.key).into());
+        }
+        #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            curve.owner,
+            &__self_program_id__,
+            concat!(stringify!(curve), " owner"),
+        )?;
+        if !payer.is_writable {
+            solana_program::msg!(concat!(stringify!(payer), " is not writable"));
+            return Err(texture_common::error::InvalidAccount(*payer.key).into());
+        }
+        if !payer.is_signer {
+            return Err(texture_common::error::MissingSignature(*payer.key).into());
+        }
+        #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            system_program.key,
+            &solana_program::system_program::ID,
+            stringify!(system_program),
+        )?;
+        Ok(Self {
+            curve,
+            payer,
+            system_program,
+        })
+    }
+}
+///[CurvyInstruction::SetCurveAsset] instruction account infos helper
+#[derive(Debug)]
+pub struct SetCurveAssetAccounts<'a, 'i> {
+    ///Curve account to associate.
+    pub curve: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Curve owner, pays for the asset link.
+    pub owner: &'a solana_program::account_info::AccountInfo<'i>,
+    ///SPL Token or Token-2022 mint.
+    pub asset_mint: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Asset link to create, PDA of owner, mint and kind.
+    pub asset_link: &'a solana_program::account_info::AccountInfo<'i>,
+    ///System program
+    pub system_program: &'a solana_program::account_info::AccountInfo<'i>,
+}
+impl<'a, 'i> SetCurveAssetAccounts<'a, 'i> {
+    pub fn from_iter<I>(
+        iter: &mut I,
+        program_id: &solana_program::pubkey::Pubkey,
+    ) -> std::result::Result<Self, texture_common::macros::accounts::AccountParseError>
+    where
+        I: Iterator<Item = &'a solana_program::account_info::AccountInfo<'i>>,
+    {
+        let __self_program_id__ = program_id;
+        let curve = texture_common::utils::next_account_info(iter)?;
+        let owner = texture_common::utils::next_account_info(iter)?;
+        let asset_mint = texture_common::utils::next_account_info(iter)?;
+        let asset_link = texture_common::utils::next_account_info(iter)?;
+        let system_program = texture_common::utils::next_account_info(iter)?;
+        #[cfg(not(feature = "program-id-manually"))] #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            __self_program_id__,
+            &crate::ID,
+            "self_program_id",
+        )?;
+        if !curve.is_writable {
+            solana_program::msg!(concat!(stringify!(curve), " is not writable"));
+            return Err(texture_common::error::InvalidAccount(*curve.key).into());
+        }
+        #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            curve.owner,
+            &__self_program_id__,
+            concat!(stringify!(curve), " owner"),
+        )?;
+        if !owner.is_writable {
+            solana_program::msg!(concat!(stringify!(owner), " is not writable"));
+            return Err(texture_common::error::InvalidAccount(*owner.key).into());
+        }
+        if !owner.is_signer {
+            return Err(texture_common::error::MissingSignature(*owner.key).into());
+        }
+        if !asset_link.is_writable {
+            solana_program::msg!(concat!(stringify!(asset_link), " is not writable"));
+            return Err(texture_common::error::InvalidAccount(*asset_link.key).into());
+        }
+        #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            asset_link.owner,
+            &solana_program::system_program::ID,
+            concat!(stringify!(asset_link), " owner"),
+        )?;
+        if asset_link.data_len() != 0 {
+            solana_program::msg!(
+                concat!("invalid ", stringify!(asset_link), " account size")
+            );
+            return Err(texture_common::error::InvalidAccount(*asset_link.key).into());
+        }
+        #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            system_program.key,
+            &solana_program::system_program::ID,
+            stringify!(system_program),
+        )?;
+        Ok(Self {
+            curve,
+            owner,
+            asset_mint,
+            asset_link,
+            system_program,
+        })
+    }
+}
+///[CurvyInstruction::ClearCurveAsset] instruction account infos helper
+#[derive(Debug)]
+pub struct ClearCurveAssetAccounts<'a, 'i> {
+    ///Curve account to update.
+    pub curve: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Curve owner, receives asset link rent.
+    pub owner: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Asset link of the curve.
+    pub asset_link: &'a solana_program::account_info::AccountInfo<'i>,
+}
+impl<'a, 'i> ClearCurveAssetAccounts<'a, 'i> {
+    pub fn from_iter<I>(
+        iter: &mut I,
+        program_id: &solana_program::pubkey::Pubkey,
+    ) -> std::result::Result<Self, texture_common::macros::accounts::AccountParseError>
+    where
+        I: Iterator<Item = &'a solana_program::account_info::AccountInfo<'i>>,
+    {
+        let __self_program_id__ = program_id;
+        let curve = texture_common::utils::next_account_info(iter)?;
+        let owner = texture_common::utils::next_account_info(iter)?;
+        let asset_link = texture_common::utils::next_account_info(iter)?;
+        #[cfg(not(feature = "program-id-manually"))] #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            __self_program_id__,
+            &crate::ID,
+            "self_program_id",
+        )?;
+        if !curve.is_writable {
+            solana_program::msg!(concat!(stringify!(curve), " is not writable"));
+            return Err(texture_common::error::InvalidAccount(*curve.key).into());
+        }
+        #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            curve.owner,
+            &__self_program_id__,
+            concat!(stringify!(curve), " owner"),
+        )?;
+        if !owner.is_writable {
+            solana_program::msg!(concat!(stringify!(owner), " is not writable"));
+            return Err(texture_common::error::InvalidAccount(*owner.key).into());
+        }
+        if !owner.is_signer {
+            return Err(texture_common::error::MissingSignature(*owner.key).into());
+        }
+        if !asset_link.is_writable {
+            solana_program::msg!(concat!(stringify!(asset_link), " is not writable"));
+            return Err(texture_common::error::InvalidAccount(*asset_link.key).into());
+        }
+        #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            asset_link.owner,
+            &__self_program_id__,
+            concat!(stringify!(asset_link), " owner"),
+        )?;
+        Ok(Self { curve, owner, asset_link })
+    }
+}
+///[CurvyInstruction::MigrateCurve] instruction account infos helper
+#[derive(Debug)]
+pub struct MigrateCurveAccounts<'a, 'i> {
+    ///Curve account to migrate.
+    pub curve: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Account paying rent of the grown account.
+    pub payer: &'a solana_program::account_info::AccountInfo<'i>,
+    ///System program
+    pub system_program: &'a solana_program::account_info::AccountInfo<'i>,
+}
+impl<'a, 'i> MigrateCurveAccounts<'a, 'i> {
+    pub fn from_iter<I>(
+        iter: &mut I,
+        program_id: &solana_program::pubkey::Pubkey,
+    ) -> std::result::Result<Self, texture_common::macros::accounts::AccountParseError>
+    where
+        I: Iterator<Item = &'a solana_program::account_info::AccountInfo<'i>>,
+    {
+        let __self_program_id__ = program_id;
+        let curve = texture_common::utils::next_account_info(iter)?;
+        let payer = texture_common::utils::next_account_info(iter)?;
+        let system_program = texture_common::utils::next_account_info(iter)?;
+        #[cfg(not(feature = "program-id-manually"))] #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            __self_program_id__,
+            &crate::ID,
+            "self_program_id",
+        )?;
+        if !curve.is_writable {
+            solana_program::msg!(concat!(stringify!(curve), " is not writable"));
+            return Err(texture_common::error::InvalidAccount(*curve.key).into());
+        }
+        #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            curve.owner,
+            &__self_program_id__,
+            concat!(stringify!(curve), " owner"),
+        )?;
+        if !payer.is_writable {
+            solana_program::msg!(concat!(stringify!(payer), " is not writable"));
+            return Err(texture_common::error::InvalidAccount(*payer.key).into());
+        }
+        if !payer.is_signer {
+            return Err(texture_common::error::MissingSignature(*payer.key).into());
+        }
+        #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            system_program.key,
+            &solana_program::system_program::ID,
+            stringify!(system_program),
+        )?;
+        Ok(Self {
+            curve,
+            payer,
+            system_program,
+        })
+    }
+}
+///[CurvyInstruction::SetCurveSeries] instruction account infos helper
+#[derive(Debug)]
+pub struct SetCurveSeriesAccounts<'a, 'i> {
+    ///Curve account to update.
+    pub curve: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Curve owner, pays for the curve series account.
+    pub owner: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Curve series account, PDA of the curve.
+    pub curve_series: &'a solana_program::account_info::AccountInfo<'i>,
+    ///System program
+    pub system_program: &'a solana_program::account_info::AccountInfo<'i>,
+}
+impl<'a, 'i> SetCurveSeriesAccounts<'a, 'i> {
+    pub fn from_iter<I>(
+        iter: &mut I,
+        program_id: &solana_program::pubkey::Pubkey,
+    ) -> std::result::Result<Self, texture_common::macros::accounts::AccountParseError>
+    where
+        I: Iterator<Item = &'a solana_program::account_info::AccountInfo<'i>>,
+    {
+        let __self_program_id__ = program_id;
+        let curve = texture_common::utils::next_account_info(iter)?;
+        let owner = texture_common::utils::next_account_info(iter)?;
+        let curve_series = texture_common::utils::next_account_info(iter)?;
+        let system_program = texture_common::utils::next_account_info(iter)?;
+        #[cfg(not(feature = "program-id-manually"))] #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            __self_program_id__,
+            &crate::ID,
+            "self_program_id",
+        )?;
+        if !curve.is_writable {
+            solana_program::msg!(concat!(stringify!(curve), " is not writable"));
+            return Err(texture_common::error::InvalidAccount(*curve.key).into());
+        }
+        #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            curve.owner,
+            &__self_program_id__,
+            concat!(stringify!(curve), " owner"),
+        )?;
+        if !owner.is_writable {
+            solana_program::msg!(concat!(stringify!(owner), " is not writable"));
+            return Err(texture_common::error::InvalidAccount(*owner.key).into());
+        }
+        if !owner.is_signer {
+            return Err(texture_common::error::MissingSignature(*owner.key).into());
+        }
+        if !curve_series.is_writable {
+            solana_program::msg!(concat!(stringify!(curve_series), " is not writable"));
+            return Err(texture_common::error::InvalidAccount(*curve_series.key).into());
+        }
+        #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            system_program.key,
+            &solana_program::system_program::ID,
+            stringify!(system_program),
+        )?;
+        Ok(Self {
+            curve,
+            owner,
+            curve_series,
+            system_program,
+        })
+    }
+}
+///[CurvyInstruction::DeleteCurveSeries] instruction account infos helper
+#[derive(Debug)]
+pub struct DeleteCurveSeriesAccounts<'a, 'i> {
+    ///Curve account to update.
+    pub curve: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Curve owner, receives curve series account rent.
+    pub owner: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Curve series account of the curve.
+    pub curve_series: &'a solana_program::account_info::AccountInfo<'i>,
+}
+impl<'a, 'i> DeleteCurveSeriesAccounts<'a, 'i> {
+    pub fn from_iter<I>(
+        iter: &mut I,
+        program_id: &solana_program::pubkey::Pubkey,
+    ) -> std::result::Result<Self, texture_common::macros::accounts::AccountParseError>
+    where
+        I: Iterator<Item = &'a solana_program::account_info::AccountInfo<'i>>,
+    {
+        let __self_program_id__ = program_id;
+        let curve = texture_common::utils::next_account_info(iter)?;
+        let owner = texture_common::utils::next_account_info(iter)?;
+        let curve_series = texture_common::utils::next_account_info(iter)?;
+        #[cfg(not(feature = "program-id-manually"))] #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            __self_program_id__,
+            &crate::ID,
+            "self_program_id",
+        )?;
+        if !curve.is_writable {
+            solana_program::msg!(concat!(stringify!(curve), " is not writable"));
+            return Err(texture_common::error::InvalidAccount(*curve.key).into());
+        }
+        #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            curve.owner,
+            &__self_program_id__,
+            concat!(stringify!(curve), " owner"),
+        )?;
+        if !owner.is_writable {
+            solana_program::msg!(concat!(stringify!(owner), " is not writable"));
+            return Err(texture_common::error::InvalidAccount(*owner.key).into());
+        }
+        if !owner.is_signer {
+            return Err(texture_common::error::MissingSignature(*owner.key).into());
+        }
+        if !curve_series.is_writable {
+            solana_program::msg!(concat!(stringify!(curve_series), " is not writable"));
+            return Err(texture_common::error::InvalidAccount(*curve_series.key).into());
+        }
+        #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            curve_series.owner,
+            &__self_program_id__,
+            concat!(stringify!(curve_series), " owner"),
+        )?;
+        Ok(Self { curve, owner, curve_series })
+    }
+}
+///[CurvyInstruction::SetCurveConfidence] instruction account infos helper
+#[derive(Debug)]
+pub struct SetCurveConfidenceAccounts<'a, 'i> {
+    ///Curve account to update.
+    pub curve: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Curve owner, pays for the curve confidence account.
+    pub owner: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Curve confidence account, PDA of the curve.
+    pub curve_confidence: &'a solana_program::account_info::AccountInfo<'i>,
+    ///System program
+    pub system_program: &'a solana_program::account_info::AccountInfo<'i>,
+}
+impl<'a, 'i> SetCurveConfidenceAccounts<'a, 'i> {
+    pub fn from_iter<I>(
+        iter: &mut I,
+        program_id: &solana_program::pubkey::Pubkey,
+    ) -> std::result::Result<Self, texture_common::macros::accounts::AccountParseError>
+    where
+        I: Iterator<Item = &'a solana_program::account_info::AccountInfo<'i>>,
+    {
+        let __self_program_id__ = program_id;
+        let curve = texture_common::utils::next_account_info(iter)?;
+        let owner = texture_common::utils::next_account_info(iter)?;
+        let curve_confidence = texture_common::utils::next_account_info(iter)?;
+        let system_program = texture_common::utils::next_account_info(iter)?;
+        #[cfg(not(feature = "program-id-manually"))] #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            __self_program_id__,
+            &crate::ID,
+            "self_program_id",
+        )?;
+        if !curve.is_writable {
+            solana_program::msg!(concat!(stringify!(curve), " is not writable"));
+            return Err(texture_common::error::InvalidAccount(*curve.key).into());
+        }
+        #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            curve.owner,
+            &__self_program_id__,
+            concat!(stringify!(curve), " owner"),
+        )?;
+        if !owner.is_writable {
+            solana_program::msg!(concat!(stringify!(owner), " is not writable"));
+            return Err(texture_common::error::InvalidAccount(*owner.key).into());
+        }
+        if !owner.is_signer {
+            return Err(texture_common::error::MissingSignature(*owner.key).into());
+        }
+        if !curve_confidence.is_writable {
+            solana_program::msg!(
+                concat!(stringify!(curve_confidence), " is not writable")
+            );
+            return Err(
+                texture_common::error::InvalidAccount(*curve_confidence.key).into(),
+            );
+        }
+        #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            system_program.key,
+            &solana_program::system_program::ID,
+            stringify!(system_program),
+        )?;
+        Ok(Self {
+            curve,
+            owner,
+            curve_confidence,
+            system_program,
+        })
+    }
+}
+///[CurvyInstruction::DeleteCurveConfidence] instruction account infos helper
+#[derive(Debug)]
+pub struct DeleteCurveConfidenceAccounts<'a, 'i> {
+    ///Curve account to update.
+    pub curve: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Curve owner, receives curve confidence account rent.
+    pub owner: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Curve confidence account of the curve.
+    pub curve_confidence: &'a solana_program::account_info::AccountInfo<'i>,
+}
+impl<'a, 'i> DeleteCurveConfidenceAccounts<'a, 'i> {
+    pub fn from_iter<I>(
+        iter: &mut I,
+        program_id: &solana_program::pubkey::Pubkey,
+    ) -> std::result::Result<Self, texture_common::macros::accounts::AccountParseError>
+    where
+        I: Iterator<Item = &'a solana_program::account_info::AccountInfo<'i>>,
+    {
+        let __self_program_id__ = program_id;
+        let curve = texture_common::utils::next_account_info(iter)?;
+        let owner = texture_common::utils::next_account_info(iter)?;
+        let curve_confidence = texture_common::utils::next_account_info(iter)?;
+        #[cfg(not(feature = "program-id-manually"))] #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            __self_program_id__,
+            &crate::ID,
+            "self_program_id",
+        )?;
+        if !curve.is_writable {
+            solana_program::msg!(concat!(stringify!(curve), " is not writable"));
+            return Err(texture_common::error::InvalidAccount(*curve.key).into());
+        }
+        #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            curve.owner,
+            &__self_program_id__,
+            concat!(stringify!(curve), " owner"),
+        )?;
+        if !owner.is_writable {
+            solana_program::msg!(concat!(stringify!(owner), " is not writable"));
+            return Err(texture_common::error::InvalidAccount(*owner.key).into());
+        }
+        if !owner.is_signer {
+            return Err(texture_common::error::MissingSignature(*owner.key).into());
+        }
+        if !curve_confidence.is_writable {
+            solana_program::msg!(
+                concat!(stringify!(curve_confidence), " is not writable")
+            );
+            return Err(
+                texture_common::error::InvalidAccount(*curve_confidence.key).into(),
+            );
+        }
+        #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            curve_confidence.owner,
+            &__self_program_id__,
+            concat!(stringify!(curve_confidence), " owner"),
+        )?;
+        Ok(Self {
+            curve,
+            owner,
+            curve_confidence,
+        })
+    }
+}
+///[CurvyInstruction::CreateWideCurve] instruction account infos helper
+#[derive(Debug)]
+pub struct CreateWideCurveAccounts<'a, 'i> {
+    ///WideCurve account to create.
+    pub curve: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Curve owner.
+    pub owner: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Program config, its creator allow-list is checked when it exists.
+    pub config: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Name link of the owner and the curve name, created or pointed to the curve.
+    pub name_link: &'a solana_program::account_info::AccountInfo<'i>,
+    ///System program
+    pub system_program: &'a solana_program::account_info::AccountInfo<'i>,
+}
+impl<'a, 'i> CreateWideCurveAccounts<'a, 'i> {
+    pub fn from_iter<I>(
+        iter: &mut I,
+        program_id: &solana_program::pubkey::Pubkey,
+    ) -> std::result::Result<Self, texture_common::macros::accounts::AccountParseError>
+    where
+        I: Iterator<Item = &'a solana_program::account_info::AccountInfo<'i>>,
+    {
+        let __self_program_id__ = program_id;
+        let curve = texture_common::utils::next_account_info(iter)?;
+        let owner = texture_common::utils::next_account_info(iter)?;
+        let config = texture_common::utils::next_account_info(iter)?;
+        let name_link = texture_common::utils::next_account_info(iter)?;
+        let system_program = texture_common::utils::next_account_info(iter)?;
+        #[cfg(not(feature = "program-id-manually"))] #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            __self_program_id__,
+            &crate::ID,
+            "self_program_id",
+        )?;
+        if !curve.is_writable {
+            solana_program::msg!(concat!(stringify!(curve), " is not writable"));
+            return Err(texture_common::error::InvalidAccount(*curve.key).into());
+        }
+        if !curve.is_signer {
+            return Err(texture_common::error::MissingSignature(*curve.key).into());
+        }
+        #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            curve.owner,
+            &solana_program::system_program::ID,
+            concat!(stringify!(curve), " owner"),
+        )?;
+        if curve.data_len() != 0 {
+            solana_program::msg!(
+                concat!("invalid ", stringify!(curve), " account size")
+            );
+            return Err(texture_common::error::InvalidAccount(*curve.key).into());
+        }
+        if !owner.is_writable {
+            solana_program::msg!(concat!(stringify!(owner), " is not writable"));
+            return Err(texture_common::error::InvalidAccount(*owner.key).into());
+        }
+        if !owner.is_signer {
+            return Err(texture_common::error::MissingSignature(*owner.key).into());
+        }
+        if !name_link.is_writable {
+            solana_program::msg!(concat!(stringify!(name_link), " is not writable"));
+            return Err(texture_common::error::InvalidAccount(*name_link.key).into());
+        }
+        #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            system_program.key,
+            &solana_program::system_program::ID,
+            stringify!(system_program),
+        )?;
+        Ok(Self {
+            curve,
+            owner,
+            config,
+            name_link,
+            system_program,
+        })
+    }
+}
+///[CurvyInstruction::AlterWideCurve] instruction account infos helper
+#[derive(Debug)]
+pub struct AlterWideCurveAccounts<'a, 'i> {
+    ///WideCurve account to update.
+    pub curve: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Curve owner.
+    pub owner: &'a solana_program::account_info::AccountInfo<'i>,
+}
+impl<'a, 'i> AlterWideCurveAccounts<'a, 'i> {
+    pub fn from_iter<I>(
+        iter: &mut I,
+        program_id: &solana_program::pubkey::Pubkey,
+    ) -> std::result::Result<Self, texture_common::macros::accounts::AccountParseError>
+    where
+        I: Iterator<Item = &'a solana_program::account_info::AccountInfo<'i>>,
+    {
+        let __self_program_id__ = program_id;
+        let curve = texture_common::utils::next_account_info(iter)?;
+        let owner = texture_common::utils::next_account_info(iter)?;
+        #[cfg(not(feature = "program-id-manually"))] #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            __self_program_id__,
+            &crate::ID,
+            "self_program_id",
+        )?;
+        if !curve.is_writable {
+            solana_program::msg!(concat!(stringify!(curve), " is not writable"));
+            return Err(texture_common::error::InvalidAccount(*curve.key).into());
+        }
+        #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            curve.owner,
+            &__self_program_id__,
+            concat!(stringify!(curve), " owner"),
+        )?;
+        if !owner.is_signer {
+            return Err(texture_common::error::MissingSignature(*owner.key).into());
+        }
+        Ok(Self { curve, owner })
+    }
+}
+///[CurvyInstruction::SetCurveXSign] instruction account infos helper
+#[derive(Debug)]
+pub struct SetCurveXSignAccounts<'a, 'i> {
+    ///Curve account to update.
+    pub curve: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Curve owner.
+    pub owner: &'a solana_program::account_info::AccountInfo<'i>,
+}
+impl<'a, 'i> SetCurveXSignAccounts<'a, 'i> {
+    pub fn from_iter<I>(
+        iter: &mut I,
+        program_id: &solana_program::pubkey::Pubkey,
+    ) -> std::result::Result<Self, texture_common::macros::accounts::AccountParseError>
+    where
+        I: Iterator<Item = &'a solana_program::account_info::AccountInfo<'i>>,
+    {
+        let __self_program_id__ = program_id;
+        let curve = texture_common::utils::next_account_info(iter)?;
+        let owner = texture_common::utils::next_account_info(iter)?;
+        #[cfg(not(feature = "program-id-manually"))] #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            __self_program_id__,
+            &crate::ID,
+            "self_program_id",
+        )?;
+        if !curve.is_writable {
+            solana_program::msg!(concat!(stringify!(curve), " is not writable"));
+            return Err(texture_common::error::InvalidAccount(*curve.key).into());
+        }
+        #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            curve.owner,
+            &__self_program_id__,
+            concat!(stringify!(curve), " owner"),
+        )?;
+        if !owner.is_signer {
+            return Err(texture_common::error::MissingSignature(*owner.key).into());
+        }
+        Ok(Self { curve, owner })
+    }
+}
+///[CurvyInstruction::ProposeOwner] instruction account infos helper
+#[derive(Debug)]
+pub struct ProposeOwnerAccounts<'a, 'i> {
+    ///Curve account to transfer.
+    pub curve: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Current curve owner.
+    pub owner: &'a solana_program::account_info::AccountInfo<'i>,
+}
+impl<'a, 'i> ProposeOwnerAccounts<'a, 'i> {
+    pub fn from_iter<I>(
+        iter: &mut I,
+        program_id: &solana_program::pubkey::Pubkey,
+    ) -> std::result::Result<Self, texture_common::macros::accounts::AccountParseError>
+    where
+        I: Iterator<Item = &'a solana_program::account_info::AccountInfo<'i>>,
+    {
+        let __self_program_id__ = program_id;
+        let curve = texture_common::utils::next_account_info(iter)?;
+        let owner = texture_common::utils::next_account_info(iter)?;
+        #[cfg(not(feature = "program-id-manually"))] #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            __self_program_id__,
+            &crate::ID,
+            "self_program_id",
+        )?;
+        if !curve.is_writable {
+            solana_program::msg!(concat!(stringify!(curve), " is not writable"));
+            return Err(texture_common::error::InvalidAccount(*curve.key).into());
+        }
+        #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            curve.owner,
+            &__self_program_id__,
+            concat!(stringify!(curve), " owner"),
+        )?;
+        if !owner.is_signer {
+            return Err(texture_common::error::MissingSignature(*owner.key).into());
+        }
+        Ok(Self { curve, owner })
+    }
+}
+///[CurvyInstruction::AcceptOwner] instruction account infos helper
+#[derive(Debug)]
+pub struct AcceptOwnerAccounts<'a, 'i> {
+    ///Curve account to accept.
+    pub curve: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Proposed owner, pays for its name link.
+    pub new_owner: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Current curve owner, receives the rent of its name link.
+    pub owner: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Name link of the current owner and the curve name, closed if it points to the curve.
+    pub name_link: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Name link of the new owner and the curve name, created or pointed to the curve.
+    pub new_name_link: &'a solana_program::account_info::AccountInfo<'i>,
+    ///System program
+    pub system_program: &'a solana_program::account_info::AccountInfo<'i>,
+}
+impl<'a, 'i> AcceptOwnerAccounts<'a, 'i> {
+    pub fn from_iter<I>(
+        iter: &mut I,
+        program_id: &solana_program::pubkey::Pubkey,
+    ) -> std::result::Result<Self, texture_common::macros::accounts::AccountParseError>
+    where
+        I: Iterator<Item = &'a solana_program::account_info::AccountInfo<'i>>,
+    {
+        let __self_program_id__ = program_id;
+        let curve = texture_common::utils::next_account_info(iter)?;
+        let new_owner = texture_common::utils::next_account_info(iter)?;
+        let owner = texture_common::utils::next_account_info(iter)?;
+        let name_link = texture_common::utils::next_account_info(iter)?;
+        let new_name_link = texture_common::utils::next_account_info(iter)?;
+        let system_program = texture_common::utils::next_account_info(iter)?;
+        #[cfg(not(feature = "program-id-manually"))] #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            __self_program_id__,
+            &crate::ID,
+            "self_program_id",
+        )?;
+        if !curve.is_writable {
+            solana_program::msg!(concat!(stringify!(curve), " is not writable"));
+            return Err(texture_common::error::InvalidAccount(*curve.key).into());
+        }
+        #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            curve.owner,
+            &__self_program_id__,
+            concat!(stringify!(curve), " owner"),
+        )?;
+        if !new_owner.is_writable {
+            solana_program::msg!(concat!(stringify!(new_owner), " is not writable"));
+            return Err(texture_common::error::InvalidAccount(*new_owner.key).into());
+        }
+        if !new_owner.is_signer {
+            return Err(texture_common::error::MissingSignature(*new_owner.key).into());
+        }
+        if !owner.is_writable {
+            solana_program::msg!(concat!(stringify!(owner), " is not writable"));
+            return Err(texture_common::error::InvalidAccount(*owner.key).into());
+        }
+        if !name_link.is_writable {
+            solana_program::msg!(concat!(stringify!(name_link), " is not writable"));
+            return Err(texture_common::error::InvalidAccount(*name_link.key).into());
+        }
+        if !new_name_link.is_writable {
+            solana_program::msg!(concat!(stringify!(new_name_link), " is not writable"));
+            return Err(texture_common::error::InvalidAccount(*new_name_link.key).into());
+        }
+        #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            system_program.key,
+            &solana_program::system_program::ID,
+            stringify!(system_program),
+        )?;
+        Ok(Self {
+            curve,
+            new_owner,
+            owner,
+            name_link,
+            new_name_link,
+            system_program,
+        })
+    }
+}
+///[CurvyInstruction::CreateCurvePda] instruction account infos helper
+#[derive(Debug)]
+pub struct CreateCurvePdaAccounts<'a, 'i> {
+    ///Curve account to create, PDA of owner and name.
+    pub curve: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Curve owner.
+    pub owner: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Program config, its creator allow-list is checked when it exists.
+    pub config: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Name link of the owner and the curve name, created or pointed to the curve.
+    pub name_link: &'a solana_program::account_info::AccountInfo<'i>,
+    ///System program
+    pub system_program: &'a solana_program::account_info::AccountInfo<'i>,
+}
+impl<'a, 'i> CreateCurvePdaAccounts<'a, 'i> {
+    pub fn from_iter<I>(
+        iter: &mut I,
+        program_id: &solana_program::pubkey::Pubkey,
+    ) -> std::result::Result<Self, texture_common::macros::accounts::AccountParseError>
+    where
+        I: Iterator<Item = &'a solana_program::account_info::AccountInfo<'i>>,
+    {
+        let __self_program_id__ = program_id;
+        let curve = texture_common::utils::next_account_info(iter)?;
+        let owner = texture_common::utils::next_account_info(iter)?;
+        let config = texture_common::utils::next_account_info(iter)?;
+        let name_link = texture_common::utils::next_account_info(iter)?;
+        let system_program = texture_common::utils::next_account_info(iter)?;
+        #[cfg(not(feature = "program-id-manually"))] #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            __self_program_id__,
+            &crate::ID,
+            "self_program_id",
+        )?;
+        if !curve.is_writable {
+            solana_program::msg!(concat!(stringify!(curve), " is not writable"));
+            return Err(texture_common::error::InvalidAccount(*curve.key).into());
+        }
+        #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            curve.owner,
+            &solana_program::system_program::ID,
+            concat!(stringify!(curve), " owner"),
+        )?;
+        if curve.data_len() != 0 {
+            solana_program::msg!(
+                concat!("invalid ", stringify!(curve), " account size")
+            );
+            return Err(texture_common::error::InvalidAccount(*curve.key).into());
+        }
+        if !owner.is_writable {
+            solana_program::msg!(concat!(stringify!(owner), " is not writable"));
+            return Err(texture_common::error::InvalidAccount(*owner.key).into());
+        }
+        if !owner.is_signer {
+            return Err(texture_common::error::MissingSignature(*owner.key).into());
+        }
+        if !name_link.is_writable {
+            solana_program::msg!(concat!(stringify!(name_link), " is not writable"));
+            return Err(texture_common::error::InvalidAccount(*name_link.key).into());
+        }
+        #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            system_program.key,
+            &solana_program::system_program::ID,
+            stringify!(system_program),
+        )?;
+        Ok(Self {
+            curve,
+            owner,
+            config,
+            name_link,
+            system_program,
+        })
+    }
+}
+///[CurvyInstruction::FreezeCurve] instruction account infos helper
+#[derive(Debug)]
+pub struct FreezeCurveAccounts<'a, 'i> {
+    ///Curve account to freeze.
+    pub curve: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Curve owner.
+    pub owner: &'a solana_program::account_info::AccountInfo<'i>,
+}
+impl<'a, 'i> FreezeCurveAccounts<'a, 'i> {
+    pub fn from_iter<I>(
+        iter: &mut I,
+        program_id: &solana_program::pubkey::Pubkey,
+    ) -> std::result::Result<Self, texture_common::macros::accounts::AccountParseError>
+    where
+        I: Iterator<Item = &'a solana_program::account_info::AccountInfo<'i>>,
+    {
+        let __self_program_id__ = program_id;
+        let curve = texture_common::utils::next_account_info(iter)?;
+        let owner = texture_common::utils::next_account_info(iter)?;
+        #[cfg(not(feature = "program-id-manually"))] #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            __self_program_id__,
+            &crate::ID,
+            "self_program_id",
+        )?;
+        if !curve.is_writable {
+            solana_program::msg!(concat!(stringify!(curve), " is not writable"));
+            return Err(texture_common::error::InvalidAccount(*curve.key).into());
+        }
+        #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            curve.owner,
+            &__self_program_id__,
+            concat!(stringify!(curve), " owner"),
+        )?;
+        if !owner.is_signer {
+            return Err(texture_common::error::MissingSignature(*owner.key).into());
+        }
+        Ok(Self { curve, owner })
+    }
+}
+///[CurvyInstruction::ResizeCurve] instruction account infos helper
+#[derive(Debug)]
+pub struct ResizeCurveAccounts<'a, 'i> {
+    ///Curve account to resize.
+    pub curve: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Curve owner, pays rent of the grown account.
+    pub owner: &'a solana_program::account_info::AccountInfo<'i>,
+    ///System program
+    pub system_program: &'a solana_program::account_info::AccountInfo<'i>,
+}
+impl<'a, 'i> ResizeCurveAccounts<'a, 'i> {
+    pub fn from_iter<I>(
+        iter: &mut I,
+        program_id: &solana_program::pubkey::Pubkey,
+    ) -> std::result::Result<Self, texture_common::macros::accounts::AccountParseError>
+    where
+        I: Iterator<Item = &'a solana_program::account_info::AccountInfo<'i>>,
+    {
+        let __self_program_id__ = program_id;
+        let curve = texture_common::utils::next_account_info(iter)?;
+        let owner = texture_common::utils::next_account_info(iter)?;
+        let system_program = texture_common::utils::next_account_info(iter)?;
+        #[cfg(not(feature = "program-id-manually"))] #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            __self_program_id__,
+            &crate::ID,
+            "self_program_id",
+        )?;
+        if !curve.is_writable {
+            solana_program::msg!(concat!(stringify!(curve), " is not writable"));
+            return Err(texture_common::error::InvalidAccount(*curve.key).into());
+        }
+        #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            curve.owner,
+            &__self_program_id__,
+            concat!(stringify!(curve), " owner"),
+        )?;
+        if !owner.is_writable {
+            solana_program::msg!(concat!(stringify!(owner), " is not writable"));
+            return Err(texture_common::error::InvalidAccount(*owner.key).into());
+        }
+        if !owner.is_signer {
+            return Err(texture_common::error::MissingSignature(*owner.key).into());
+        }
+        #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            system_program.key,
+            &solana_program::system_program::ID,
+            stringify!(system_program),
+        )?;
+        Ok(Self {
+            curve,
+            owner,
+            system_program,
+        })
+    }
+}
+///[CurvyInstruction::SetCurveTail] instruction account infos helper
+#[derive(Debug)]
+pub struct SetCurveTailAccounts<'a, 'i> {
+    ///Curve account to update.
+    pub curve: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Curve owner.
+    pub owner: &'a solana_program::account_info::AccountInfo<'i>,
+}
+impl<'a, 'i> SetCurveTailAccounts<'a, 'i> {
+    pub fn from_iter<I>(
+        iter: &mut I,
+        program_id: &solana_program::pubkey::Pubkey,
+    ) -> std::result::Result<Self, texture_common::macros::accounts::AccountParseError>
+    where
+        I: Iterator<Item = &'a solana_program::account_info::AccountInfo<'i>>,
+    {
+        let __self_program_id__ = program_id;
+        let curve = texture_common::utils::next_account_info(iter)?;
+        let owner = texture_common::utils::next_account_info(iter)?;
+        #[cfg(not(feature = "program-id-manually"))] #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            __self_program_id__,
+            &crate::ID,
+            "self_program_id",
+        )?;
+        if !curve.is_writable {
+            solana_program::msg!(concat!(stringify!(curve), " is not writable"));
+            return Err(texture_common::error::InvalidAccount(*curve.key).into());
+        }
+        #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            curve.owner,
+            &__self_program_id__,
+            concat!(stringify!(curve), " owner"),
+        )?;
+        if !owner.is_signer {
+            return Err(texture_common::error::MissingSignature(*owner.key).into());
+        }
+        Ok(Self { curve, owner })
+    }
+}
+///[CurvyInstruction::CreateGridCurve] instruction account infos helper
+#[derive(Debug)]
+pub struct CreateGridCurveAccounts<'a, 'i> {
+    ///GridCurve account to create.
+    pub curve: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Curve owner.
+    pub owner: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Program config, its creator allow-list is checked when it exists.
+    pub config: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Name link of the owner and the curve name, created or pointed to the curve.
+    pub name_link: &'a solana_program::account_info::AccountInfo<'i>,
+    ///System program
+    pub system_program: &'a solana_program::account_info::AccountInfo<'i>,
+}
+impl<'a, 'i> CreateGridCurveAccounts<'a, 'i> {
+    pub fn from_iter<I>(
+        iter: &mut I,
+        program_id: &solana_program::pubkey::Pubkey,
+    ) -> std::result::Result<Self, texture_common::macros::accounts::AccountParseError>
+    where
+        I: Iterator<Item = &'a solana_program::account_info::AccountInfo<'i>>,
+    {
+        let __self_program_id__ = program_id;
+        let curve = texture_common::utils::next_account_info(iter)?;
+        let owner = texture_common::utils::next_account_info(iter)?;
+        let config = texture_common::utils::next_account_info(iter)?;
+        let name_link = texture_common::utils::next_account_info(iter)?;
+        let system_program = texture_common::utils::next_account_info(iter)?;
+        #[cfg(not(feature = "program-id-manually"))] #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            __self_program_id__,
+            &crate::ID,
+            "self_program_id",
+        )?;
+        if !curve.is_writable {
+            solana_program::msg!(concat!(stringify!(curve), " is not writable"));
+            return Err(texture_common::error::InvalidAccount(*curve.key).into());
+        }
+        if !curve.is_signer {
+            return Err(texture_common::error::MissingSignature(*curve.key).into());
+        }
+        #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            curve.owner,
+            &solana_program::system_program::ID,
+            concat!(stringify!(curve), " owner"),
+        )?;
+        if curve.data_len() != 0 {
+            solana_program::msg!(
+                concat!("invalid ", stringify!(curve), " account size")
+            );
+            return Err(texture_common::error::InvalidAccount(*curve.key).into());
+        }
+        if !owner.is_writable {
+            solana_program::msg!(concat!(stringify!(owner), " is not writable"));
+            return Err(texture_common::error::InvalidAccount(*owner.key).into());
+        }
+        if !owner.is_signer {
+            return Err(texture_common::error::MissingSignature(*owner.key).into());
+        }
+        if !name_link.is_writable {
+            solana_program::msg!(concat!(stringify!(name_link), " is not writable"));
+            return Err(texture_common::error::InvalidAccount(*name_link.key).into());
+        }
+        #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            system_program.key,
+            &solana_program::system_program::ID,
+            stringify!(system_program),
+        )?;
+        Ok(Self {
+            curve,
+            owner,
+            config,
+            name_link,
+            system_program,
+        })
+    }
+}
+///[CurvyInstruction::AlterGridCurve] instruction account infos helper
+#[derive(Debug)]
+pub struct AlterGridCurveAccounts<'a, 'i> {
+    ///GridCurve account to update.
+    pub curve: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Curve owner.
+    pub owner: &'a solana_program::account_info::AccountInfo<'i>,
+}
+impl<'a, 'i> AlterGridCurveAccounts<'a, 'i> {
+    pub fn from_iter<I>(
+        iter: &mut I,
+        program_id: &solana_program::pubkey::Pubkey,
+    ) -> std::result::Result<Self, texture_common::macros::accounts::AccountParseError>
+    where
+        I: Iterator<Item = &'a solana_program::account_info::AccountInfo<'i>>,
+    {
+        let __self_program_id__ = program_id;
+        let curve = texture_common::utils::next_account_info(iter)?;
+        let owner = texture_common::utils::next_account_info(iter)?;
+        #[cfg(not(feature = "program-id-manually"))] #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            __self_program_id__,
+            &crate::ID,
+            "self_program_id",
+        )?;
+        if !curve.is_writable {
+            solana_program::msg!(concat!(stringify!(curve), " is not writable"));
+            return Err(texture_common::error::InvalidAccount(*curve.key).into());
+        }
+        #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            curve.owner,
+            &__self_program_id__,
+            concat!(stringify!(curve), " owner"),
+        )?;
+        if !owner.is_signer {
+            return Err(texture_common::error::MissingSignature(*owner.key).into());
+        }
+        Ok(Self { curve, owner })
+    }
+}
+///[CurvyInstruction::SetCurveYSign] instruction account infos helper
+#[derive(Debug)]
+pub struct SetCurveYSignAccounts<'a, 'i> {
+    ///Curve account to update.
+    pub curve: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Curve owner.
+    pub owner: &'a solana_program::account_info::AccountInfo<'i>,
+}
+impl<'a, 'i> SetCurveYSignAccounts<'a, 'i> {
+    pub fn from_iter<I>(
+        iter: &mut I,
+        program_id: &solana_program::pubkey::Pubkey,
+    ) -> std::result::Result<Self, texture_common::macros::accounts::AccountParseError>
+    where
+        I: Iterator<Item = &'a solana_program::account_info::AccountInfo<'i>>,
+    {
+        let __self_program_id__ = program_id;
+        let curve = texture_common::utils::next_account_info(iter)?;
+        let owner = texture_common::utils::next_account_info(iter)?;
+        #[cfg(not(feature = "program-id-manually"))] #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            __self_program_id__,
+            &crate::ID,
+            "self_program_id",
+        )?;
+        if !curve.is_writable {
+            solana_program::msg!(concat!(stringify!(curve), " is not writable"));
+            return Err(texture_common::error::InvalidAccount(*curve.key).into());
+        }
+        #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            curve.owner,
+            &__self_program_id__,
+            concat!(stringify!(curve), " owner"),
+        )?;
+        if !owner.is_signer {
+            return Err(texture_common::error::MissingSignature(*owner.key).into());
+        }
+        Ok(Self { curve, owner })
+    }
+}
+///[CurvyInstruction::SetCurveStatus] instruction account infos helper
+#[derive(Debug)]
+pub struct SetCurveStatusAccounts<'a, 'i> {
+    ///Curve account to update.
+    pub curve: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Curve owner.
+    pub owner: &'a solana_program::account_info::AccountInfo<'i>,
+}
+impl<'a, 'i> SetCurveStatusAccounts<'a, 'i> {
+    pub fn from_iter<I>(
+        iter: &mut I,
+        program_id: &solana_program::pubkey::Pubkey,
+    ) -> std::result::Result<Self, texture_common::macros::accounts::AccountParseError>
+    where
+        I: Iterator<Item = &'a solana_program::account_info::AccountInfo<'i>>,
+    {
+        let __self_program_id__ = program_id;
+        let curve = texture_common::utils::next_account_info(iter)?;
+        let owner = texture_common::utils::next_account_info(iter)?;
+        #[cfg(not(feature = "program-id-manually"))] #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            __self_program_id__,
+            &crate::ID,
+            "self_program_id",
+        )?;
+        if !curve.is_writable {
+            solana_program::msg!(concat!(stringify!(curve), " is not writable"));
+            return Err(texture_common::error::InvalidAccount(*curve.key).into());
+        }
+        #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            curve.owner,
+            &__self_program_id__,
+            concat!(stringify!(curve), " owner"),
+        )?;
+        if !owner.is_signer {
+            return Err(texture_common::error::MissingSignature(*owner.key).into());
+        }
+        Ok(Self { curve, owner })
+    }
+}
+///[CurvyInstruction::PatchY] instruction account infos helper
+#[derive(Debug)]
+pub struct PatchYAccounts<'a, 'i> {
+    ///Curve account to update.
+    pub curve: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Curve owner or its delegate.
+    pub owner: &'a solana_program::account_info::AccountInfo<'i>,
+}
+impl<'a, 'i> PatchYAccounts<'a, 'i> {
+    pub fn from_iter<I>(
+        iter: &mut I,
+        program_id: &solana_program::pubkey::Pubkey,
+    ) -> std::result::Result<Self, texture_common::macros::accounts::AccountParseError>
+    where
+        I: Iterator<Item = &'a solana_program::account_info::AccountInfo<'i>>,
+    {
+        let __self_program_id__ = program_id;
+        let curve = texture_common::utils::next_account_info(iter)?;
+        let owner = texture_common::utils::next_account_info(iter)?;
+        #[cfg(not(feature = "program-id-manually"))] #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            __self_program_id__,
+            &crate::ID,
+            "self_program_id",
+        )?;
+        if !curve.is_writable {
+            solana_program::msg!(concat!(stringify!(curve), " is not writable"));
+            return Err(texture_common::error::InvalidAccount(*curve.key).into());
+        }
+        #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            curve.owner,
+            &__self_program_id__,
+            concat!(stringify!(curve), " owner"),
+        )?;
+        if !owner.is_signer {
+            return Err(texture_common::error::MissingSignature(*owner.key).into());
+        }
+        Ok(Self { curve, owner })
+    }
+}
+///[CurvyInstruction::MarkForDeletion] instruction account infos helper
+#[derive(Debug)]
+pub struct MarkForDeletionAccounts<'a, 'i> {
+    ///Curve account to mark.
+    pub curve: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Curve owner.
+    pub owner: &'a solana_program::account_info::AccountInfo<'i>,
+}
+impl<'a, 'i> MarkForDeletionAccounts<'a, 'i> {
+    pub fn from_iter<I>(
+        iter: &mut I,
+        program_id: &solana_program::pubkey::Pubkey,
+    ) -> std::result::Result<Self, texture_common::macros::accounts::AccountParseError>
+    where
+        I: Iterator<Item = &'a solana_program::account_info::AccountInfo<'i>>,
+    {
+        let __self_program_id__ = program_id;
+        let curve = texture_common::utils::next_account_info(iter)?;
+        let owner = texture_common::utils::next_account_info(iter)?;
+        #[cfg(not(feature = "program-id-manually"))] #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            __self_program_id__,
+            &crate::ID,
+            "self_program_id",
+        )?;
+        if !curve.is_writable {
+            solana_program::msg!(concat!(stringify!(curve), " is not writable"));
+            return Err(texture_common::error::InvalidAccount(*curve.key).into());
+        }
+        #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            curve.owner,
+            &__self_program_id__,
+            concat!(stringify!(curve), " owner"),
+        )?;
+        if !owner.is_signer {
+            return Err(texture_common::error::MissingSignature(*owner.key).into());
+        }
+        Ok(Self { curve, owner })
+    }
+}
+///[CurvyInstruction::CancelDeletion] instruction account infos helper
+#[derive(Debug)]
+pub struct CancelDeletionAccounts<'a, 'i> {
+    ///Curve account to unmark.
+    pub curve: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Curve owner.
+    pub owner: &'a solana_program::account_info::AccountInfo<'i>,
+}
+impl<'a, 'i> CancelDeletionAccounts<'a, 'i> {
+    pub fn from_iter<I>(
+        iter: &mut I,
+        program_id: &solana_program::pubkey::Pubkey,
+    ) -> std::result::Result<Self, texture_common::macros::accounts::AccountParseError>
+    where
+        I: Iterator<Item = &'a solana_program::account_info::AccountInfo<'i>>,
+    {
+        let __self_program_id__ = program_id;
+        let curve = texture_common::utils::next_account_info(iter)?;
+        let owner = texture_common::utils::next_account_info(iter)?;
+        #[cfg(not(feature = "program-id-manually"))] #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            __self_program_id__,
+            &crate::ID,
+            "self_program_id",
+        )?;
+        if !curve.is_writable {
+            solana_program::msg!(concat!(stringify!(curve), " is not writable"));
+            return Err(texture_common::error::InvalidAccount(*curve.key).into());
+        }
+        #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            curve.owner,
+            &__self_program_id__,
+            concat!(stringify!(curve), " owner"),
+        )?;
+        if !owner.is_signer {
+            return Err(texture_common::error::MissingSignature(*owner.key).into());
+        }
+        Ok(Self { curve, owner })
+    }
+}
+///[CurvyInstruction::EvaluateCurve] instruction account infos helper
+#[derive(Debug)]
+pub struct EvaluateCurveAccounts<'a, 'i> {
+    ///Curve account to evaluate.
+    pub curve: &'a solana_program::account_info::AccountInfo<'i>,
+    ///CurveEvaluation account to store the result in.
+    pub result: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Authority of the result account.
+    pub authority: &'a solana_program::account_info::AccountInfo<'i>,
+}
+impl<'a, 'i> EvaluateCurveAccounts<'a, 'i> {
+    pub fn from_iter<I>(
+        iter: &mut I,
+        program_id: &solana_program::pubkey::Pubkey,
+    ) -> std::result::Result<Self, texture_common::macros::accounts::AccountParseError>
+    where
+        I: Iterator<Item = &'a solana_program::account_info::AccountInfo<'i>>,
+    {
+        let __self_program_id__ = program_id;
+        let curve = texture_common::utils::next_account_info(iter)?;
+        let result = texture_common::utils::next_account_info(iter)?;
+        let authority = texture_common::utils::next_account_info(iter)?;
+        #[cfg(not(feature = "program-id-manually"))] #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            __self_program_id__,
+            &crate::ID,
+            "self_program_id",
+        )?;
+        #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            curve.owner,
+            &__self_program_id__,
+            concat!(stringify!(curve), " owner"),
+        )?;
+        if !result.is_writable {
+            solana_program::msg!(concat!(stringify!(result), " is not writable"));
+            return Err(texture_common::error::InvalidAccount(*result.key).into());
+        }
+        #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            result.owner,
+            &__self_program_id__,
+            concat!(stringify!(result), " owner"),
+        )?;
+        if !authority.is_signer {
+            return Err(texture_common::error::MissingSignature(*authority.key).into());
+        }
+        Ok(Self { curve, result, authority })
+    }
+}
+///[CurvyInstruction::SetAlterDelay] instruction account infos helper
+#[derive(Debug)]
+pub struct SetAlterDelayAccounts<'a, 'i> {
+    ///Curve account to timelock.
+    pub curve: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Curve owner.
+    pub owner: &'a solana_program::account_info::AccountInfo<'i>,
+}
+impl<'a, 'i> SetAlterDelayAccounts<'a, 'i> {
+    pub fn from_iter<I>(
+        iter: &mut I,
+        program_id: &solana_program::pubkey::Pubkey,
+    ) -> std::result::Result<Self, texture_common::macros::accounts::AccountParseError>
+    where
+        I: Iterator<Item = &'a solana_program::account_info::AccountInfo<'i>>,
+    {
+        let __self_program_id__ = program_id;
+        let curve = texture_common::utils::next_account_info(iter)?;
+        let owner = texture_common::utils::next_account_info(iter)?;
+        #[cfg(not(feature = "program-id-manually"))] #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            __self_program_id__,
+            &crate::ID,
+            "self_program_id",
+        )?;
+        if !curve.is_writable {
+            solana_program::msg!(concat!(stringify!(curve), " is not writable"));
+            return Err(texture_common::error::InvalidAccount(*curve.key).into());
+        }
+        #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            curve.owner,
+            &__self_program_id__,
+            concat!(stringify!(curve), " owner"),
+        )?;
+        if !owner.is_signer {
+            return Err(texture_common::error::MissingSignature(*owner.key).into());
+        }
+        Ok(Self { curve, owner })
+    }
+}
+///[CurvyInstruction::ProposeAlter] instruction account infos helper
+#[derive(Debug)]
+pub struct ProposeAlterAccounts<'a, 'i> {
+    ///Curve account to alter.
+    pub curve: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Curve owner, pays for the pending alter account.
+    pub owner: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Pending alter account, PDA of the curve.
+    pub pending_alter: &'a solana_program::account_info::AccountInfo<'i>,
+    ///System program
+    pub system_program: &'a solana_program::account_info::AccountInfo<'i>,
+}
+impl<'a, 'i> ProposeAlterAccounts<'a, 'i> {
+    pub fn from_iter<I>(
+        iter: &mut I,
+        program_id: &solana_program::pubkey::Pubkey,
+    ) -> std::result::Result<Self, texture_common::macros::accounts::AccountParseError>
+    where
+        I: Iterator<Item = &'a solana_program::account_info::AccountInfo<'i>>,
+    {
+        let __self_program_id__ = program_id;
+        let curve = texture_common::utils::next_account_info(iter)?;
+        let owner = texture_common::utils::next_account_info(iter)?;
+        let pending_alter = texture_common::utils::next_account_info(iter)?;
+        let system_program = texture_common::utils::next_account_info(iter)?;
+        #[cfg(not(feature = "program-id-manually"))] #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            __self_program_id__,
+            &crate::ID,
+            "self_program_id",
+        )?;
+        if !curve.is_writable {
+            solana_program::msg!(concat!(stringify!(curve), " is not writable"));
+            return Err(texture_common::error::InvalidAccount(*curve.key).into());
+        }
+        #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            curve.owner,
+            &__self_program_id__,
+            concat!(stringify!(curve), " owner"),
+        )?;
+        if !owner.is_writable {
+            solana_program::msg!(concat!(stringify!(owner), " is not writable"));
+            return Err(texture_common::error::InvalidAccount(*owner.key).into());
+        }
+        if !owner.is_signer {
+            return Err(texture_common::error::MissingSignature(*owner.key).into());
+        }
+        if !pending_alter.is_writable {
+            solana_program::msg!(concat!(stringify!(pending_alter), " is not writable"));
+            return Err(texture_common::error::InvalidAccount(*pending_alter.key).into());
+        }
+        #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            system_program.key,
+            &solana_program::system_program::ID,
+            stringify!(system_program),
+        )?;
+        Ok(Self {
+            curve,
+            owner,
+            pending_alter,
+            system_program,
+        })
+    }
+}
+///[CurvyInstruction::ApplyAlter] instruction account infos helper
+#[derive(Debug)]
+pub struct ApplyAlterAccounts<'a, 'i> {
+    ///Curve account to alter.
+    pub curve: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Curve owner, receives lamports of the pending alter account.
+    pub owner: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Pending alter account of the curve.
+    pub pending_alter: &'a solana_program::account_info::AccountInfo<'i>,
+}
+impl<'a, 'i> ApplyAlterAccounts<'a, 'i> {
+    pub fn from_iter<I>(
+        iter: &mut I,
+        program_id: &solana_program::pubkey::Pubkey,
+    ) -> std::result::Result<Self, texture_common::macros::accounts::AccountParseError>
+    where
+        I: Iterator<Item = &'a solana_program::account_info::AccountInfo<'i>>,
+    {
+        let __self_program_id__ = program_id;
+        let curve = texture_common::utils::next_account_info(iter)?;
+        let owner = texture_common::utils::next_account_info(iter)?;
+        let pending_alter = texture_common::utils::next_account_info(iter)?;
+        #[cfg(not(feature = "program-id-manually"))] #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            __self_program_id__,
+            &crate::ID,
+            "self_program_id",
+        )?;
+        if !curve.is_writable {
+            solana_program::msg!(concat!(stringify!(curve), " is not writable"));
+            return Err(texture_common::error::InvalidAccount(*curve.key).into());
+        }
+        #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            curve.owner,
+            &__self_program_id__,
+            concat!(stringify!(curve), " owner"),
+        )?;
+        if !owner.is_writable {
+            solana_program::msg!(concat!(stringify!(owner), " is not writable"));
+            return Err(texture_common::error::InvalidAccount(*owner.key).into());
+        }
+        if !owner.is_signer {
+            return Err(texture_common::error::MissingSignature(*owner.key).into());
+        }
+        if !pending_alter.is_writable {
+            solana_program::msg!(concat!(stringify!(pending_alter), " is not writable"));
+            return Err(texture_common::error::InvalidAccount(*pending_alter.key).into());
+        }
+        #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            pending_alter.owner,
+            &__self_program_id__,
+            concat!(stringify!(pending_alter), " owner"),
+        )?;
+        Ok(Self {
+            curve,
+            owner,
+            pending_alter,
+        })
+    }
+}
+///[CurvyInstruction::CancelAlter] instruction account infos helper
+#[derive(Debug)]
+pub struct CancelAlterAccounts<'a, 'i> {
+    ///Curve account of the proposal.
+    pub curve: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Curve owner, receives lamports of the pending alter account.
+    pub owner: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Pending alter account of the curve.
+    pub pending_alter: &'a solana_program::account_info::AccountInfo<'i>,
+}
+impl<'a, 'i> CancelAlterAccounts<'a, 'i> {
+    pub fn from_iter<I>(
+        iter: &mut I,
+        program_id: &solana_program::pubkey::Pubkey,
+    ) -> std::result::Result<Self, texture_common::macros::accounts::AccountParseError>
+    where
+        I: Iterator<Item = &'a solana_program::account_info::AccountInfo<'i>>,
+    {
+        let __self_program_id__ = program_id;
+        let curve = texture_common::utils::next_account_info(iter)?;
+        let owner = texture_common::utils::next_account_info(iter)?;
+        let pending_alter = texture_common::utils::next_account_info(iter)?;
+        #[cfg(not(feature = "program-id-manually"))] #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            __self_program_id__,
+            &crate::ID,
+            "self_program_id",
+        )?;
+        if !curve.is_writable {
+            solana_program::msg!(concat!(stringify!(curve), " is not writable"));
+            return Err(texture_common::error::InvalidAccount(*curve.key).into());
+        }
+        #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            curve.owner,
+            &__self_program_id__,
+            concat!(stringify!(curve), " owner"),
+        )?;
+        if !owner.is_writable {
+            solana_program::msg!(concat!(stringify!(owner), " is not writable"));
+            return Err(texture_common::error::InvalidAccount(*owner.key).into());
+        }
+        if !owner.is_signer {
+            return Err(texture_common::error::MissingSignature(*owner.key).into());
+        }
+        if !pending_alter.is_writable {
+            solana_program::msg!(concat!(stringify!(pending_alter), " is not writable"));
+            return Err(texture_common::error::InvalidAccount(*pending_alter.key).into());
+        }
+        #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            pending_alter.owner,
+            &__self_program_id__,
+            concat!(stringify!(pending_alter), " owner"),
+        )?;
+        Ok(Self {
+            curve,
+            owner,
+            pending_alter,
+        })
+    }
+}
+///[CurvyInstruction::AddDelegate] instruction account infos helper
+#[derive(Debug)]
+pub struct AddDelegateAccounts<'a, 'i> {
+    ///Curve account to delegate.
+    pub curve: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Curve owner.
+    pub owner: &'a solana_program::account_info::AccountInfo<'i>,
+}
+impl<'a, 'i> AddDelegateAccounts<'a, 'i> {
+    pub fn from_iter<I>(
+        iter: &mut I,
+        program_id: &solana_program::pubkey::Pubkey,
+    ) -> std::result::Result<Self, texture_common::macros::accounts::AccountParseError>
+    where
+        I: Iterator<Item = &'a solana_program::account_info::AccountInfo<'i>>,
+    {
+        let __self_program_id__ = program_id;
+        let curve = texture_common::utils::next_account_info(iter)?;
+        let owner = texture_common::utils::next_account_info(iter)?;
+        #[cfg(not(feature = "program-id-manually"))] #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            __self_program_id__,
+            &crate::ID,
+            "self_program_id",
+        )?;
+        if !curve.is_writable {
+            solana_program::msg!(concat!(stringify!(curve), " is not writable"));
+            return Err(texture_common::error::InvalidAccount(*curve.key).into());
+        }
+        #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            curve.owner,
+            &__self_program_id__,
+            concat!(stringify!(curve), " owner"),
+        )?;
+        if !owner.is_signer {
+            return Err(texture_common::error::MissingSignature(*owner.key).into());
+        }
+        Ok(Self { curve, owner })
+    }
+}
+///[CurvyInstruction::RemoveDelegate] instruction account infos helper
+#[derive(Debug)]
+pub struct RemoveDelegateAccounts<'a, 'i> {
+    ///Curve account of the delegate.
+    pub curve: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Curve owner.
+    pub owner: &'a solana_program::account_info::AccountInfo<'i>,
+}
+impl<'a, 'i> RemoveDelegateAccounts<'a, 'i> {
+    pub fn from_iter<I>(
+        iter: &mut I,
+        program_id: &solana_program::pubkey::Pubkey,
+    ) -> std::result::Result<Self, texture_common::macros::accounts::AccountParseError>
+    where
+        I: Iterator<Item = &'a solana_program::account_info::AccountInfo<'i>>,
+    {
+        let __self_program_id__ = program_id;
+        let curve = texture_common::utils::next_account_info(iter)?;
+        let owner = texture_common::utils::next_account_info(iter)?;
+        #[cfg(not(feature = "program-id-manually"))] #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            __self_program_id__,
+            &crate::ID,
+            "self_program_id",
+        )?;
+        if !curve.is_writable {
+            solana_program::msg!(concat!(stringify!(curve), " is not writable"));
+            return Err(texture_common::error::InvalidAccount(*curve.key).into());
+        }
+        #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            curve.owner,
+            &__self_program_id__,
+            concat!(stringify!(curve), " owner"),
+        )?;
+        if !owner.is_signer {
+            return Err(texture_common::error::MissingSignature(*owner.key).into());
+        }
+        Ok(Self { curve, owner })
+    }
+}
+///[CurvyInstruction::InitConfig] instruction account infos helper
+#[derive(Debug)]
+pub struct InitConfigAccounts<'a, 'i> {
+    ///Config account to create, PDA of the config seed.
+    pub config: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Upgrade authority of the program, pays for the config account.
+    pub admin: &'a solana_program::account_info::AccountInfo<'i>,
+    ///ProgramData account of the program.
+    pub program_data: &'a solana_program::account_info::AccountInfo<'i>,
+    ///System program
+    pub system_program: &'a solana_program::account_info::AccountInfo<'i>,
+}
+impl<'a, 'i> InitConfigAccounts<'a, 'i> {
+    pub fn from_iter<I>(
+        iter: &mut I,
+        program_id: &solana_program::pubkey::Pubkey,
+    ) -> std::result::Result<Self, texture_common::macros::accounts::AccountParseError>
+    where
+        I: Iterator<Item = &'a solana_program::account_info::AccountInfo<'i>>,
+    {
+        let __self_program_id__ = program_id;
+        let config = texture_common::utils::next_account_info(iter)?;
+        let admin = texture_common::utils::next_account_info(iter)?;
+        let program_data = texture_common::utils::next_account_info(iter)?;
+        let system_program = texture_common::utils::next_account_info(iter)?;
+        #[cfg(not(feature = "program-id-manually"))] #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            __self_program_id__,
+            &crate::ID,
+            "self_program_id",
+        )?;
+        if !config.is_writable {
+            solana_program::msg!(concat!(stringify!(config), " is not writable"));
+            return Err(texture_common::error::InvalidAccount(*config.key).into());
+        }
+        #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            config.owner,
+            &solana_program::system_program::ID,
+            concat!(stringify!(config), " owner"),
+        )?;
+        if config.data_len() != 0 {
+            solana_program::msg!(
+                concat!("invalid ", stringify!(config), " account size")
+            );
+            return Err(texture_common::error::InvalidAccount(*config.key).into());
+        }
+        if !admin.is_writable {
+            solana_program::msg!(concat!(stringify!(admin), " is not writable"));
+            return Err(texture_common::error::InvalidAccount(*admin.key).into());
+        }
+        if !admin.is_signer {
+            return Err(texture_common::error::MissingSignature(*admin.key).into());
+        }
+        #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            system_program.key,
+            &solana_program::system_program::ID,
+            stringify!(system_program),
+        )?;
+        Ok(Self {
+            config,
+            admin,
+            program_data,
+            system_program,
+        })
+    }
+}
+///[CurvyInstruction::SetConfig] instruction account infos helper
+#[derive(Debug)]
+pub struct SetConfigAccounts<'a, 'i> {
+    ///Config account to update.
+    pub config: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Current config admin.
+    pub admin: &'a solana_program::account_info::AccountInfo<'i>,
+}
+impl<'a, 'i> SetConfigAccounts<'a, 'i> {
+    pub fn from_iter<I>(
+        iter: &mut I,
+        program_id: &solana_program::pubkey::Pubkey,
+    ) -> std::result::Result<Self, texture_common::macros::accounts::AccountParseError>
+    where
+        I: Iterator<Item = &'a solana_program::account_info::AccountInfo<'i>>,
+    {
+        let __self_program_id__ = program_id;
+        let config = texture_common::utils::next_account_info(iter)?;
+        let admin = texture_common::utils::next_account_info(iter)?;
+        #[cfg(not(feature = "program-id-manually"))] #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            __self_program_id__,
+            &crate::ID,
+            "self_program_id",
+        )?;
+        if !config.is_writable {
+            solana_program::msg!(concat!(stringify!(config), " is not writable"));
+            return Err(texture_common::error::InvalidAccount(*config.key).into());
+        }
+        #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            config.owner,
+            &__self_program_id__,
+            concat!(stringify!(config), " owner"),
+        )?;
+        if !admin.is_signer {
+            return Err(texture_common::error::MissingSignature(*admin.key).into());
+        }
+        Ok(Self { config, admin })
+    }
+}
+///[CurvyInstruction::SetCurveOwners] instruction account infos helper
+#[derive(Debug)]
+pub struct SetCurveOwnersAccounts<'a, 'i> {
+    ///Curve account to update.
+    pub curve: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Curve owner, any owner of co-owned curve. Pays for the curve owners account.
+    pub owner: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Curve owners account, PDA of the curve.
+    pub curve_owners: &'a solana_program::account_info::AccountInfo<'i>,
+    ///System program
+    pub system_program: &'a solana_program::account_info::AccountInfo<'i>,
+}
+impl<'a, 'i> SetCurveOwnersAccounts<'a, 'i> {
+    pub fn from_iter<I>(
+        iter: &mut I,
+        program_id: &solana_program::pubkey::Pubkey,
+    ) -> std::result::Result<Self, texture_common::macros::accounts::AccountParseError>
+    where
+        I: Iterator<Item = &'a solana_program::account_info::AccountInfo<'i>>,
+    {
+        let __self_program_id__ = program_id;
+        let curve = texture_common::utils::next_account_info(iter)?;
+        let owner = texture_common::utils::next_account_info(iter)?;
+        let curve_owners = texture_common::utils::next_account_info(iter)?;
+        let system_program = texture_common::utils::next_account_info(iter)?;
+        #[cfg(not(feature = "program-id-manually"))] #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            __self_program_id__,
+            &crate::ID,
+            "self_program_id",
+        )?;
+        if !curve.is_writable {
+            solana_program::msg!(concat!(stringify!(curve), " is not writable"));
+            return Err(texture_common::error::InvalidAccount(*curve.key).into());
+        }
+        #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            curve.owner,
+            &__self_program_id__,
+            concat!(stringify!(curve), " owner"),
+        )?;
+        if !owner.is_writable {
+            solana_program::msg!(concat!(stringify!(owner), " is not writable"));
+            return Err(texture_common::error::InvalidAccount(*owner.key).into());
+        }
+        if !owner.is_signer {
+            return Err(texture_common::error::MissingSignature(*owner.key).into());
+        }
+        if !curve_owners.is_writable {
+            solana_program::msg!(concat!(stringify!(curve_owners), " is not writable"));
+            return Err(texture_common::error::InvalidAccount(*curve_owners.key).into());
+        }
+        #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            system_program.key,
+            &solana_program::system_program::ID,
+            stringify!(system_program),
+        )?;
+        Ok(Self {
+            curve,
+            owner,
+            curve_owners,
+            system_program,
+        })
+    }
+}
+///[CurvyInstruction::SetCurveExtension] instruction account infos helper
+#[derive(Debug)]
+pub struct SetCurveExtensionAccounts<'a, 'i> {
+    ///Curve account to update.
+    pub curve: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Curve owner, pays rent of the grown account.
+    pub owner: &'a solana_program::account_info::AccountInfo<'i>,
+    ///System program
+    pub system_program: &'a solana_program::account_info::AccountInfo<'i>,
+}
+impl<'a, 'i> SetCurveExtensionAccounts<'a, 'i> {
+    pub fn from_iter<I>(
+        iter: &mut I,
+        program_id: &solana_program::pubkey::Pubkey,
+    ) -> std::result::Result<Self, texture_common::macros::accounts::AccountParseError>
+    where
+        I: Iterator<Item = &'a solana_program::account_info::AccountInfo<'i>>,
+    {
+        let __self_program_id__ = program_id;
+        let curve = texture_common::utils::next_account_info(iter)?;
+        let owner = texture_common::utils::next_account_info(iter)?;
+        let system_program = texture_common::utils::next_account_info(iter)?;
+        #[cfg(not(feature = "program-id-manually"))] #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            __self_program_id__,
+            &crate::ID,
+            "self_program_id",
+        )?;
+        if !curve.is_writable {
+            solana_program::msg!(concat!(stringify!(curve), " is not writable"));
+            return Err(texture_common::error::InvalidAccount(*curve.key).into());
+        }
+        #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            curve.owner,
+            &__self_program_id__,
+            concat!(stringify!(curve), " owner"),
+        )?;
+        if !owner.is_writable {
+            solana_program::msg!(concat!(stringify!(owner), " is not writable"));
+            return Err(texture_common::error::InvalidAccount(*owner.key).into());
+        }
+        if !owner.is_signer {
+            return Err(texture_common::error::MissingSignature(*owner.key).into());
+        }
+        #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            system_program.key,
+            &solana_program::system_program::ID,
+            stringify!(system_program),
+        )?;
+        Ok(Self {
+            curve,
+            owner,
+            system_program,
+        })
+    }
+}
+///[CurvyInstruction::SetCurveYTransform] instruction account infos helper
+#[derive(Debug)]
+pub struct SetCurveYTransformAccounts<'a, 'i> {
+    ///Curve account to update.
+    pub curve: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Curve owner.
+    pub owner: &'a solana_program::account_info::AccountInfo<'i>,
+}
+impl<'a, 'i> SetCurveYTransformAccounts<'a, 'i> {
+    pub fn from_iter<I>(
+        iter: &mut I,
+        program_id: &solana_program::pubkey::Pubkey,
+    ) -> std::result::Result<Self, texture_common::macros::accounts::AccountParseError>
+    where
+        I: Iterator<Item = &'a solana_program::account_info::AccountInfo<'i>>,
+    {
+        let __self_program_id__ = program_id;
+        let curve = texture_common::utils::next_account_info(iter)?;
+        let owner = texture_common::utils::next_account_info(iter)?;
+        #[cfg(not(feature = "program-id-manually"))] #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            __self_program_id__,
+            &crate::ID,
+            "self_program_id",
+        )?;
+        if !curve.is_writable {
+            solana_program::msg!(concat!(stringify!(curve), " is not writable"));
+            return Err(texture_common::error::InvalidAccount(*curve.key).into());
+        }
+        #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            curve.owner,
+            &__self_program_id__,
+            concat!(stringify!(curve), " owner"),
+        )?;
+        if !owner.is_signer {
+            return Err(texture_common::error::MissingSignature(*owner.key).into());
+        }
+        Ok(Self { curve, owner })
+    }
+}
+///[CurvyInstruction::SetCurveExpiry] instruction account infos helper
+#[derive(Debug)]
+pub struct SetCurveExpiryAccounts<'a, 'i> {
+    ///Curve account to update.
+    pub curve: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Curve owner.
+    pub owner: &'a solana_program::account_info::AccountInfo<'i>,
+}
+impl<'a, 'i> SetCurveExpiryAccounts<'a, 'i> {
+    pub fn from_iter<I>(
+        iter: &mut I,
+        program_id: &solana_program::pubkey::Pubkey,
+    ) -> std::result::Result<Self, texture_common::macros::accounts::AccountParseError>
+    where
+        I: Iterator<Item = &'a solana_program::account_info::AccountInfo<'i>>,
+    {
+        let __self_program_id__ = program_id;
+        let curve = texture_common::utils::next_account_info(iter)?;
+        let owner = texture_common::utils::next_account_info(iter)?;
+        #[cfg(not(feature = "program-id-manually"))] #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            __self_program_id__,
+            &crate::ID,
+            "self_program_id",
+        )?;
+        if !curve.is_writable {
+            solana_program::msg!(concat!(stringify!(curve), " is not writable"));
+            return Err(texture_common::error::InvalidAccount(*curve.key).into());
+        }
+        #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            curve.owner,
+            &__self_program_id__,
+            concat!(stringify!(curve), " owner"),
+        )?;
+        if !owner.is_signer {
+            return Err(texture_common::error::MissingSignature(*owner.key).into());
+        }
+        Ok(Self { curve, owner })
+    }
+}
+///[CurvyInstruction::BatchAlter] instruction account infos helper
+#[derive(Debug)]
+pub struct BatchAlterAccounts<'a, 'i> {
+    ///Owner or delegate of every altered curve.
+    pub owner: &'a solana_program::account_info::AccountInfo<'i>,
+}
+impl<'a, 'i> BatchAlterAccounts<'a, 'i> {
+    pub fn from_iter<I>(
+        iter: &mut I,
+        program_id: &solana_program::pubkey::Pubkey,
+    ) -> std::result::Result<Self, texture_common::macros::accounts::AccountParseError>
+    where
+        I: Iterator<Item = &'a solana_program::account_info::AccountInfo<'i>>,
+    {
+        let __self_program_id__ = program_id;
+        let owner = texture_common::utils::next_account_info(iter)?;
+        #[cfg(not(feature = "program-id-manually"))] #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            __self_program_id__,
+            &crate::ID,
+            "self_program_id",
+        )?;
+        if !owner.is_signer {
+            return Err(texture_common::error::MissingSignature(*owner.key).into());
+        }
+        Ok(Self { owner })
+    }
+}
+///[CurvyInstruction::CreateReferenceCurve] instruction account infos helper
+#[derive(Debug)]
+pub struct CreateReferenceCurveAccounts<'a, 'i> {
+    ///Curve account to create, PDA of kind and mint.
+    pub curve: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Config admin, pays for the curve.
+    pub admin: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Program config.
+    pub config: &'a solana_program::account_info::AccountInfo<'i>,
+    ///SPL Token or Token-2022 mint.
+    pub asset_mint: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Name link of the config and the curve name, created or pointed to the curve.
+    pub name_link: &'a solana_program::account_info::AccountInfo<'i>,
+    ///System program
+    pub system_program: &'a solana_program::account_info::AccountInfo<'i>,
+}
+impl<'a, 'i> CreateReferenceCurveAccounts<'a, 'i> {
+    pub fn from_iter<I>(
+        iter: &mut I,
+        program_id: &solana_program::pubkey::Pubkey,
+    ) -> std::result::Result<Self, texture_common::macros::accounts::AccountParseError>
+    where
+        I: Iterator<Item = &'a solana_program::account_info::AccountInfo<'i>>,
+    {
+        let __self_program_id__ = program_id;
+        let curve = texture_common::utils::next_account_info(iter)?;
+        let admin = texture_common::utils::next_account_info(iter)?;
+        let config = texture_common::utils::next_account_info(iter)?;
+        let asset_mint = texture_common::utils::next_account_info(iter)?;
+        let name_link = texture_common::utils::next_account_info(iter)?;
+        let system_program = texture_common::utils::next_account_info(iter)?;
+        #[cfg(not(feature = "program-id-manually"))] #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            __self_program_id__,
+            &crate::ID,
+            "self_program_id",
+        )?;
+        if !curve.is_writable {
+            solana_program::msg!(concat!(stringify!(curve), " is not writable"));
+            return Err(texture_common::error::InvalidAccount(*curve.key).into());
+        }
+        #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            curve.owner,
+            &solana_program::system_program::ID,
+            concat!(stringify!(curve), " owner"),
+        )?;
+        if curve.data_len() != 0 {
+            solana_program::msg!(
+                concat!("invalid ", stringify!(curve), " account size")
+            );
+            return Err(texture_common::error::InvalidAccount(*curve.key).into());
+        }
+        if !admin.is_writable {
+            solana_program::msg!(concat!(stringify!(admin), " is not writable"));
+            return Err(texture_common::error::InvalidAccount(*admin.key).into());
+        }
+        if !admin.is_signer {
+            return Err(texture_common::error::MissingSignature(*admin.key).into());
+        }
+        #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            config.owner,
+            &__self_program_id__,
+            concat!(stringify!(config), " owner"),
+        )?;
+        if !name_link.is_writable {
+            solana_program::msg!(concat!(stringify!(name_link), " is not writable"));
+            return Err(texture_common::error::InvalidAccount(*name_link.key).into());
+        }
+        #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            system_program.key,
+            &solana_program::system_program::ID,
+            stringify!(system_program),
+        )?;
+        Ok(Self {
+            curve,
+            admin,
+            config,
+            asset_mint,
+            name_link,
+            system_program,
+        })
+    }
+}
+///[CurvyInstruction::SetCreationFrozen] instruction account infos helper
+#[derive(Debug)]
+pub struct SetCreationFrozenAccounts<'a, 'i> {
+    ///Config account to update.
+    pub config: &'a solana_program::account_info::AccountInfo<'i>,
+    ///Config admin.
+    pub admin: &'a solana_program::account_info::AccountInfo<'i>,
+}
+impl<'a, 'i> SetCreationFrozenAccounts<'a, 'i> {
+    pub fn from_iter<I>(
+        iter: &mut I,
+        program_id: &solana_program::pubkey::Pubkey,
+    ) -> std::result::Result<Self, texture_common::macros::accounts::AccountParseError>
+    where
+        I: Iterator<Item = &'a solana_program::account_info::AccountInfo<'i>>,
+    {
+        let __self_program_id__ = program_id;
+        let config = texture_common::utils::next_account_info(iter)?;
+        let admin = texture_common::utils::next_account_info(iter)?;
+        #[cfg(not(feature = "program-id-manually"))] #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            __self_program_id__,
+            &crate::ID,
+            "self_program_id",
+        )?;
+        if !config.is_writable {
+            solana_program::msg!(concat!(stringify!(config), " is not writable"));
+            return Err(texture_common::error::InvalidAccount(*config.key).into());
+        }
+        #[allow(clippy::needless_borrow)]
+        texture_common::utils::verify_key(
+            config.owner,
+            &__self_program_id__,
+            concat!(stringify!(config), " owner"),
+        )?;
+        if !admin.is_signer {
+            return Err(texture_common::error::MissingSignature(*admin.key).into());
+        }
+        Ok(Self { config, admin })
+    }
+}
+pub(crate) mod ix_docs {
+    macro_rules! create_curve {
+        () => {
+            concat! { " ## Accounts", "\n", " ", "\n", "<b><i>", "0", "</i></b>. <b>",
+            "\\[writable, signer\\]", "</b> ", "Curve account to create.", "\n", " ",
+            "\n", "<b><i>", "1", "</i></b>. <b>", "\\[writable, signer\\]", "</b> ",
+            "Curve owner.", "\n", " ", "\n", "<b><i>", "2", "</i></b>. <b>", "\\[\\]",
+            "</b> ", "Program config, its creator allow-list is checked when it exists.",
+            "\n", " ", "\n", "<b><i>", "3", "</i></b>. <b>", "\\[writable\\]", "</b> ",
+            "Name link of the owner and the curve name, created or pointed to the curve.",
+            "\n", " ", "\n", "<b><i>", "4", "</i></b>. <b>", "\\[\\]", "</b> ",
+            "System program", "\n", "\n", " ## Usage", "\n", " ",
+            "For create instruction use builder struct [CreateCurve]", " ",
+            "(method [into_instruction][CreateCurve::into_instruction]).", " ", "\n\n",
+            " ",
+            "For parse accounts infos from processor use struct [CreateCurveAccounts]",
+            " ", "(method [from_iter][CreateCurveAccounts::from_iter]).", " ", "\n\n",
+            " ", "For work with account indexes use struct [CreateCurveAccountIndexes].",
+            "\n", }
+        };
+    }
+    pub(crate) use create_curve;
+    macro_rules! alter_curve {
+        () => {
+            concat! { " ## Accounts", "\n", " ", "\n", "<b><i>", "0", "</i></b>. <b>",
+            "\\[writable\\]", "</b> ", "Curve account to update.", "\n", " ", "\n",
+            "<b><i>", "1", "</i></b>. <b>", "\\[signer\\]", "</b> ",
+            "Curve owner or its delegate, any owner of co-owned curve.", "\n", "\n",
+            " ## Usage", "\n", " ",
+            "For create instruction use builder struct [AlterCurve]", " ",
+            "(method [into_instruction][AlterCurve::into_instruction]).", " ", "\n\n",
+            " ",
+            "For parse accounts infos from processor use struct [AlterCurveAccounts]",
+            " ", "(method [from_iter][AlterCurveAccounts::from_iter]).", " ", "\n\n",
+            " ", "For work with account indexes use struct [AlterCurveAccountIndexes].",
+            "\n", }
+        };
+    }
+    pub(crate) use alter_curve;
+    macro_rules! delete_curve {
+        () => {
+            concat! { " ## Accounts", "\n", " ", "\n", "<b><i>", "0", "</i></b>. <b>",
+            "\\[writable\\]", "</b> ", "Curve account to delete.", "\n", " ", "\n",
+            "<b><i>", "1", "</i></b>. <b>", "\\[writable, signer\\]", "</b> ",
+            "Curve owner, receives the rent unless the rent destination is given.", "\n",
+            " ", "\n", "<b><i>", "2", "</i></b>. <b>", "\\[writable\\]", "</b> ",
+            "Name link of the owner and the curve name, closed if it points to the curve.",
+            "\n", "\n", " ## Usage", "\n", " ",
+            "For create instruction use builder struct [DeleteCurve]", " ",
+            "(method [into_instruction][DeleteCurve::into_instruction]).", " ", "\n\n",
+            " ",
+            "For parse accounts infos from processor use struct [DeleteCurveAccounts]",
+            " ", "(method [from_iter][DeleteCurveAccounts::from_iter]).", " ", "\n\n",
+            " ", "For work with account indexes use struct [DeleteCurveAccountIndexes].",
+            "\n", }
+        };
+    }
+    pub(crate) use delete_curve;
+    macro_rules! update_curve_metadata {
+        () => {
+            concat! { " ## Accounts", "\n", " ", "\n", "<b><i>", "0", "</i></b>. <b>",
+            "\\[writable\\]", "</b> ", "Curve account to update.", "\n", " ", "\n",
+            "<b><i>", "1", "</i></b>. <b>", "\\[writable, signer\\]", "</b> ",
+            "Curve owner, pays for the name link of the new name.", "\n", " ", "\n",
+            "<b><i>", "2", "</i></b>. <b>", "\\[writable\\]", "</b> ",
+            "Name link of the owner and the current name, closed if it points to the curve.",
+            "\n", " ", "\n", "<b><i>", "3", "</i></b>. <b>", "\\[writable\\]", "</b> ",
+            "Name link of the owner and the new name, created or pointed to the curve.",
+            "\n", " ", "\n", "<b><i>", "4", "</i></b>. <b>", "\\[\\]", "</b> ",
+            "System program", "\n", "\n", " ## Usage", "\n", " ",
+            "For create instruction use builder struct [UpdateCurveMetadata]", " ",
+            "(method [into_instruction][UpdateCurveMetadata::into_instruction]).", " ",
+            "\n\n", " ",
+            "For parse accounts infos from processor use struct [UpdateCurveMetadataAccounts]",
+            " ", "(method [from_iter][UpdateCurveMetadataAccounts::from_iter]).", " ",
+            "\n\n", " ",
+            "For work with account indexes use struct [UpdateCurveMetadataAccountIndexes].",
+            "\n", }
+        };
+    }
+    pub(crate) use update_curve_metadata;
+    macro_rules! update_curve_data {
+        () => {
+            concat! { " ## Accounts", "\n", " ", "\n", "<b><i>", "0", "</i></b>. <b>",
+            "\\[writable\\]", "</b> ", "Curve account to update.", "\n", " ", "\n",
+            "<b><i>", "1", "</i></b>. <b>", "\\[signer\\]", "</b> ",
+            "Curve owner or its delegate.", "\n", "\n", " ## Usage", "\n", " ",
+            "For create instruction use builder struct [UpdateCurveData]", " ",
+            "(method [into_instruction][UpdateCurveData::into_instruction]).", " ",
+            "\n\n", " ",
+            "For parse accounts infos from processor use struct [UpdateCurveDataAccounts]",
+            " ", "(method [from_iter][UpdateCurveDataAccounts::from_iter]).", " ",
+            "\n\n", " ",
+            "For work with account indexes use struct [UpdateCurveDataAccountIndexes].",
+            "\n", }
+        };
+    }
+    pub(crate) use update_curve_data;
+    macro_rules! reserve_curve {
+        () => {
+            concat! { " ## Accounts", "\n", " ", "\n", "<b><i>", "0", "</i></b>. <b>",
+            "\\[writable, signer\\]", "</b> ", "Curve account to create.", "\n", " ",
+            "\n", "<b><i>", "1", "</i></b>. <b>", "\\[writable, signer\\]", "</b> ",
+            "Curve owner.", "\n", " ", "\n", "<b><i>", "2", "</i></b>. <b>", "\\[\\]",
+            "</b> ", "Program config, its creator allow-list is checked when it exists.",
+            "\n", " ", "\n", "<b><i>", "3", "</i></b>. <b>", "\\[writable\\]", "</b> ",
+            "Name link of the owner and the curve name, created or pointed to the curve.",
+            "\n", " ", "\n", "<b><i>", "4", "</i></b>. <b>", "\\[\\]", "</b> ",
+            "System program", "\n", "\n", " ## Usage", "\n", " ",
+            "For create instruction use builder struct [ReserveCurve]", " ",
+            "(method [into_instruction][ReserveCurve::into_instruction]).", " ", "\n\n",
+            " ",
+            "For parse accounts infos from processor use struct [ReserveCurveAccounts]",
+            " ", "(method [from_iter][ReserveCurveAccounts::from_iter]).", " ", "\n\n",
+            " ",
+            "For work with account indexes use struct [ReserveCurveAccountIndexes].",
+            "\n", }
+        };
+    }
+    pub(crate) use reserve_curve;
+    macro_rules! top_up_rent {
+        () => {
+            concat! { " ## Accounts", "\n", " ", "\n", "<b><i>", "0", "</i></b>. <b>",
+            "\\[writable\\]", "</b> ", "Curve account to top up.", "\n", " ", "\n",
+            "<b><i>", "1", "</i></b>. <b>", "\\[writable, signer\\]", "</b> ",
+            "Account paying the missing lamports.", "\n", " ", "\n", "<b><i>", "2",
+            "</i></b>. <b>", "\\[\\]", "</b> ", "System program", "\n", "\n",
+            " ## Usage", "\n", " ",
+            "For create instruction use builder struct [TopUpRent]", " ",
+            "(method [into_instruction][TopUpRent::into_instruction]).", " ", "\n\n",
+            " ",
+            "For parse accounts infos from processor use struct [TopUpRentAccounts]",
+            " ", "(method [from_iter][TopUpRentAccounts::from_iter]).", " ", "\n\n", " ",
+            "For work with account indexes use struct [TopUpRentAccountIndexes].", "\n",
+            }
+        };
+    }
+    pub(crate) use top_up_rent;
+    macro_rules! set_curve_asset {
+        () => {
+            concat! { " ## Accounts", "\n", " ", "\n", "<b><i>", "0", "</i></b>. <b>",
+            "\\[writable\\]", "</b> ", "Curve account to associate.", "\n", " ", "\n",
+            "<b><i>", "1", "</i></b>. <b>", "\\[writable, signer\\]", "</b> ",
+            "Curve owner, pays for the asset link.", "\n", " ", "\n", "<b><i>", "2",
+            "</i></b>. <b>", "\\[\\]", "</b> ", "SPL Token or Token-2022 mint.", "\n",
+            " ", "\n", "<b><i>", "3", "</i></b>. <b>", "\\[writable\\]", "</b> ",
+            "Asset link to create, PDA of owner, mint and kind.", "\n", " ", "\n",
+            "<b><i>", "4", "</i></b>. <b>", "\\[\\]", "</b> ", "System program", "\n",
+            "\n", " ## Usage", "\n", " ",
+            "For create instruction use builder struct [SetCurveAsset]", " ",
+            "(method [into_instruction][SetCurveAsset::into_instruction]).", " ", "\n\n",
+            " ",
+            "For parse accounts infos from processor use struct [SetCurveAssetAccounts]",
+            " ", "(method [from_iter][SetCurveAssetAccounts::from_iter]).", " ", "\n\n",
+            " ",
+            "For work with account indexes use struct [SetCurveAssetAccountIndexes].",
+            "\n", }
+        };
+    }
+    pub(crate) use set_curve_asset;
+    macro_rules! clear_curve_asset {
+        () => {
+            concat! { " ## Accounts", "\n", " ", "\n", "<b><i>", "0", "</i></b>. <b>",
+            "\\[writable\\]", "</b> ", "Curve account to update.", "\n", " ", "\n",
+            "<b><i>", "1", "</i></b>. <b>", "\\[writable, signer\\]", "</b> ",
+            "Curve owner, receives asset link rent.", "\n", " ", "\n", "<b><i>", "2",
+            "</i></b>. <b>", "\\[writable\\]", "</b> ", "Asset link of the curve.", "\n",
+            "\n", " ## Usage", "\n", " ",
+            "For create instruction use builder struct [ClearCurveAsset]", " ",
+            "(method [into_instruction][ClearCurveAsset::into_instruction]).", " ",
+            "\n\n", " ",
+            "For parse accounts infos from processor use struct [ClearCurveAssetAccounts]",
+            " ", "(method [from_iter][ClearCurveAssetAccounts::from_iter]).", " ",
+            "\n\n", " ",
+            "For work with account indexes use struct [ClearCurveAssetAccountIndexes].",
+            "\n", }
+        };
+    }
+    pub(crate) use clear_curve_asset;
+    macro_rules! migrate_curve {
+        () => {
+            concat! { " ## Accounts", "\n", " ", "\n", "<b><i>", "0", "</i></b>. <b>",
+            "\\[writable\\]", "</b> ", "Curve account to migrate.", "\n", " ", "\n",
+            "<b><i>", "1", "</i></b>. <b>", "\\[writable, signer\\]", "</b> ",
+            "Account paying rent of the grown account.", "\n", " ", "\n", "<b><i>", "2",
+            "</i></b>. <b>", "\\[\\]", "</b> ", "System program", "\n", "\n",
+            " ## Usage", "\n", " ",
+            "For create instruction use builder struct [MigrateCurve]", " ",
+            "(method [into_instruction][MigrateCurve::into_instruction]).", " ", "\n\n",
+            " ",
+            "For parse accounts infos from processor use struct [MigrateCurveAccounts]",
+            " ", "(method [from_iter][MigrateCurveAccounts::from_iter]).", " ", "\n\n",
+            " ",
+            "For work with account indexes use struct [MigrateCurveAccountIndexes].",
+            "\n", }
+        };
+    }
+    pub(crate) use migrate_curve;
+    macro_rules! set_curve_series {
+        () => {
+            concat! { " ## Accounts", "\n", " ", "\n", "<b><i>", "0", "</i></b>. <b>",
+            "\\[writable\\]", "</b> ", "Curve account to update.", "\n", " ", "\n",
+            "<b><i>", "1", "</i></b>. <b>", "\\[writable, signer\\]", "</b> ",
+            "Curve owner, pays for the curve series account.", "\n", " ", "\n", "<b><i>",
+            "2", "</i></b>. <b>", "\\[writable\\]", "</b> ",
+            "Curve series account, PDA of the curve.", "\n", " ", "\n", "<b><i>", "3",
+            "</i></b>. <b>", "\\[\\]", "</b> ", "System program", "\n", "\n",
+            " ## Usage", "\n", " ",
+            "For create instruction use builder struct [SetCurveSeries]", " ",
+            "(method [into_instruction][SetCurveSeries::into_instruction]).", " ",
+            "\n\n", " ",
+            "For parse accounts infos from processor use struct [SetCurveSeriesAccounts]",
+            " ", "(method [from_iter][SetCurveSeriesAccounts::from_iter]).", " ", "\n\n",
+            " ",
+            "For work with account indexes use struct [SetCurveSeriesAccountIndexes].",
+            "\n", }
+        };
+    }
+    pub(crate) use set_curve_series;
+    macro_rules! delete_curve_series {
+        () => {
+            concat! { " ## Accounts", "\n", " ", "\n", "<b><i>", "0", "</i></b>. <b>",
+            "\\[writable\\]", "</b> ", "Curve account to update.", "\n", " ", "\n",
+            "<b><i>", "1", "</i></b>. <b>", "\\[writable, signer\\]", "</b> ",
+            "Curve owner, receives curve series account rent.", "\n", " ", "\n",
+            "<b><i>", "2", "</i></b>. <b>", "\\[writable\\]", "</b> ",
+            "Curve series account of the curve.", "\n", "\n", " ## Usage", "\n", " ",
+            "For create instruction use builder struct [DeleteCurveSeries]", " ",
+            "(method [into_instruction][DeleteCurveSeries::into_instruction]).", " ",
+            "\n\n", " ",
+            "For parse accounts infos from processor use struct [DeleteCurveSeriesAccounts]",
+            " ", "(method [from_iter][DeleteCurveSeriesAccounts::from_iter]).", " ",
+            "\n\n", " ",
+            "For work with account indexes use struct [DeleteCurveSeriesAccountIndexes].",
+            "\n", }
+        };
+    }
+    pub(crate) use delete_curve_series;
+    macro_rules! set_curve_confidence {
+        () => {
+            concat! { " ## Accounts", "\n", " ", "\n", "<b><i>", "0", "</i></b>. <b>",
+            "\\[writable\\]", "</b> ", "Curve account to update.", "\n", " ", "\n",
+            "<b><i>", "1", "</i></b>. <b>", "\\[writable, signer\\]", "</b> ",
+            "Curve owner, pays for the curve confidence account.", "\n", " ", "\n",
+            "<b><i>", "2", "</i></b>. <b>", "\\[writable\\]", "</b> ",
+            "Curve confidence account, PDA of the curve.", "\n", " ", "\n", "<b><i>",
+            "3", "</i></b>. <b>", "\\[\\]", "</b> ", "System program", "\n", "\n",
+            " ## Usage", "\n", " ",
+            "For create instruction use builder struct [SetCurveConfidence]", " ",
+            "(method [into_instruction][SetCurveConfidence::into_instruction]).", " ",
+            "\n\n", " ",
+            "For parse accounts infos from processor use struct [SetCurveConfidenceAccounts]",
+            " ", "(method [from_iter][SetCurveConfidenceAccounts::from_iter]).", " ",
+            "\n\n", " ",
+            "For work with account indexes use struct [SetCurveConfidenceAccountIndexes].",
+            "\n", }
+        };
+    }
+    pub(crate) use set_curve_confidence;
+    macro_rules! delete_curve_confidence {
+        () => {
+            concat! { " ## Accounts", "\n", " ", "\n", "<b><i>", "0", "</i></b>. <b>",
+            "\\[writable\\]", "</b> ", "Curve account to update.", "\n", " ", "\n",
+            "<b><i>", "1", "</i></b>. <b>", "\\[writable, signer\\]", "</b> ",
+            "Curve owner, receives curve confidence account rent.", "\n", " ", "\n",
+            "<b><i>", "2", "</i></b>. <b>", "\\[writable\\]", "</b> ",
+            "Curve confidence account of the curve.", "\n", "\n", " ## Usage", "\n", " ",
+            "For create instruction use builder struct [DeleteCurveConfidence]", " ",
+            "(method [into_instruction][DeleteCurveConfidence::into_instruction]).", " ",
+            "\n\n", " ",
+            "For parse accounts infos from processor use struct [DeleteCurveConfidenceAccounts]",
+            " ", "(method [from_iter][DeleteCurveConfidenceAccounts::from_iter]).", " ",
+            "\n\n", " ",
+            "For work with account indexes use struct [DeleteCurveConfidenceAccountIndexes].",
+            "\n", }
+        };
+    }
+    pub(crate) use delete_curve_confidence;
+    macro_rules! create_wide_curve {
+        () => {
+            concat! { " ## Accounts", "\n", " ", "\n", "<b><i>", "0", "</i></b>. <b>",
+            "\\[writable, signer\\]", "</b> ", "WideCurve account to create.", "\n", " ",
+            "\n", "<b><i>", "1", "</i></b>. <b>", "\\[writable, signer\\]", "</b> ",
+            "Curve owner.", "\n", " ", "\n", "<b><i>", "2", "</i></b>. <b>", "\\[\\]",
+            "</b> ", "Program config, its creator allow-list is checked when it exists.",
+            "\n", " ", "\n", "<b><i>", "3", "</i></b>. <b>", "\\[writable\\]", "</b> ",
+            "Name link of the owner and the curve name, created or pointed to the curve.",
+            "\n", " ", "\n", "<b><i>", "4", "</i></b>. <b>", "\\[\\]", "</b> ",
+            "System program", "\n", "\n", " ## Usage", "\n", " ",
+            "For create instruction use builder struct [CreateWideCurve]", " ",
+            "(method [into_instruction][CreateWideCurve::into_instruction]).", " ",
+            "\n\n", " ",
+            "For parse accounts infos from processor use struct [CreateWideCurveAccounts]",
+            " ", "(method [from_iter][CreateWideCurveAccounts::from_iter]).", " ",
+            "\n\n", " ",
+            "For work with account indexes use struct [CreateWideCurveAccountIndexes].",
+            "\n", }
+        };
+    }
+    pub(crate) use create_wide_curve;
+    macro_rules! alter_wide_curve {
+        () => {
+            concat! { " ## Accounts", "\n", " ", "\n", "<b><i>", "0", "</i></b>. <b>",
+            "\\[writable\\]", "</b> ", "WideCurve account to update.", "\n", " ", "\n",
+            "<b><i>", "1", "</i></b>. <b>", "\\[signer\\]", "</b> ", "Curve owner.",
+            "\n", "\n", " ## Usage", "\n", " ",
+            "For create instruction use builder struct [AlterWideCurve]", " ",
+            "(method [into_instruction][AlterWideCurve::into_instruction]).", " ",
+            "\n\n", " ",
+            "For parse accounts infos from processor use struct [AlterWideCurveAccounts]",
+            " ", "(method [from_iter][AlterWideCurveAccounts::from_iter]).", " ", "\n\n",
+            " ",
+            "For work with account indexes use struct [AlterWideCurveAccountIndexes].",
+            "\n", }
+        };
+    }
+    pub(crate) use alter_wide_curve;
+    macro_rules! set_curve_x_sign {
+        () => {
+            concat! { " ## Accounts", "\n", " ", "\n", "<b><i>", "0", "</i></b>. <b>",
+            "\\[writable\\]", "</b> ", "Curve account to update.", "\n", " ", "\n",
+            "<b><i>", "1", "</i></b>. <b>", "\\[signer\\]", "</b> ", "Curve owner.",
+            "\n", "\n", " ## Usage", "\n", " ",
+            "For create instruction use builder struct [SetCurveXSign]", " ",
+            "(method [into_instruction][SetCurveXSign::into_instruction]).", " ", "\n\n",
+            " ",
+            "For parse accounts infos from processor use struct [SetCurveXSignAccounts]",
+            " ", "(method [from_iter][SetCurveXSignAccounts::from_iter]).", " ", "\n\n",
+            " ",
+            "For work with account indexes use struct [SetCurveXSignAccountIndexes].",
+            "\n", }
+        };
+    }
+    pub(crate) use set_curve_x_sign;
+    macro_rules! propose_owner {
+        () => {
+            concat! { " ## Accounts", "\n", " ", "\n", "<b><i>", "0", "</i></b>. <b>",
+            "\\[writable\\]", "</b> ", "Curve account to transfer.", "\n", " ", "\n",
+            "<b><i>", "1", "</i></b>. <b>", "\\[signer\\]", "</b> ",
+            "Current curve owner.", "\n", "\n", " ## Usage", "\n", " ",
+            "For create instruction use builder struct [ProposeOwner]", " ",
+            "(method [into_instruction][ProposeOwner::into_instruction]).", " ", "\n\n",
+            " ",
+            "For parse accounts infos from processor use struct [ProposeOwnerAccounts]",
+            " ", "(method [from_iter][ProposeOwnerAccounts::from_iter]).", " ", "\n\n",
+            " ",
+            "For work with account indexes use struct [ProposeOwnerAccountIndexes].",
+            "\n", }
+        };
+    }
+    pub(crate) use propose_owner;
+    macro_rules! accept_owner {
+        () => {
+            concat! { " ## Accounts", "\n", " ", "\n", "<b><i>", "0", "</i></b>. <b>",
+            "\\[writable\\]", "</b> ", "Curve account to accept.", "\n", " ", "\n",
+            "<b><i>", "1", "</i></b>. <b>", "\\[writable, signer\\]", "</b> ",
+            "Proposed owner, pays for its name link.", "\n", " ", "\n", "<b><i>", "2",
+            "</i></b>. <b>", "\\[writable\\]", "</b> ",
+            "Current curve owner, receives the rent of its name link.", "\n", " ", "\n",
+            "<b><i>", "3", "</i></b>. <b>", "\\[writable\\]", "</b> ",
+            "Name link of the current owner and the curve name, closed if it points to the curve.",
+            "\n", " ", "\n", "<b><i>", "4", "</i></b>. <b>", "\\[writable\\]", "</b> ",
+            "Name link of the new owner and the curve name, created or pointed to the curve.",
+            "\n", " ", "\n", "<b><i>", "5", "</i></b>. <b>", "\\[\\]", "</b> ",
+            "System program", "\n", "\n", " ## Usage", "\n", " ",
+            "For create instruction use builder struct [AcceptOwner]", " ",
+            "(method [into_instruction][AcceptOwner::into_instruction]).", " ", "\n\n",
+            " ",
+            "For parse accounts infos from processor use struct [AcceptOwnerAccounts]",
+            " ", "(method [from_iter][AcceptOwnerAccounts::from_iter]).", " ", "\n\n",
+            " ", "For work with account indexes use struct [AcceptOwnerAccountIndexes].",
+            "\n", }
+        };
+    }
+    pub(crate) use accept_owner;
+    macro_rules! create_curve_pda {
+        () => {
+            concat! { " ## Accounts", "\n", " ", "\n", "<b><i>", "0", "</i></b>. <b>",
+            "\\[writable\\]", "</b> ", "Curve account to create, PDA of owner and name.",
+            "\n", " ", "\n", "<b><i>", "1", "</i></b>. <b>", "\\[writable, signer\\]",
+            "</b> ", "Curve owner.", "\n", " ", "\n", "<b><i>", "2", "</i></b>. <b>",
+            "\\[\\]", "</b> ",
+            "Program config, its creator allow-list is checked when it exists.", "\n",
+            " ", "\n", "<b><i>", "3", "</i></b>. <b>", "\\[writable\\]", "</b> ",
+            "Name link of the owner and the curve name, created or pointed to the curve.",
+            "\n", " ", "\n", "<b><i>", "4", "</i></b>. <b>", "\\[\\]", "</b> ",
+            "System program", "\n", "\n", " ## Usage", "\n", " ",
+            "For create instruction use builder struct [CreateCurvePda]", " ",
+            "(method [into_instruction][CreateCurvePda::into_instruction]).", " ",
+            "\n\n", " ",
+            "For parse accounts infos from processor use struct [CreateCurvePdaAccounts]",
+            " ", "(method [from_iter][CreateCurvePdaAccounts::from_iter]).", " ", "\n\n",
+            " ",
+            "For work with account indexes use struct [CreateCurvePdaAccountIndexes].",
+            "\n", }
+        };
+    }
+    pub(crate) use create_curve_pda;
+    macro_rules! freeze_curve {
+        () => {
+            concat! { " ## Accounts", "\n", " ", "\n", "<b><i>", "0", "</i></b>. <b>",
+            "\\[writable\\]", "</b> ", "Curve account to freeze.", "\n", " ", "\n",
+            "<b><i>", "1", "</i></b>. <b>", "\\[signer\\]", "</b> ", "Curve owner.",
+            "\n", "\n", " ## Usage", "\n", " ",
+            "For create instruction use builder struct [FreezeCurve]", " ",
+            "(method [into_instruction][FreezeCurve::into_instruction]).", " ", "\n\n",
+            " ",
+            "For parse accounts infos from processor use struct [FreezeCurveAccounts]",
+            " ", "(method [from_iter][FreezeCurveAccounts::from_iter]).", " ", "\n\n",
+            " ", "For work with account indexes use struct [FreezeCurveAccountIndexes].",
+            "\n", }
+        };
+    }
+    pub(crate) use freeze_curve;
+    macro_rules! resize_curve {
+        () => {
+            concat! { " ## Accounts", "\n", " ", "\n", "<b><i>", "0", "</i></b>. <b>",
+            "\\[writable\\]", "</b> ", "Curve account to resize.", "\n", " ", "\n",
+            "<b><i>", "1", "</i></b>. <b>", "\\[writable, signer\\]", "</b> ",
+            "Curve owner, pays rent of the grown account.", "\n", " ", "\n", "<b><i>",
+            "2", "</i></b>. <b>", "\\[\\]", "</b> ", "System program", "\n", "\n",
+            " ## Usage", "\n", " ",
+            "For create instruction use builder struct [ResizeCurve]", " ",
+            "(method [into_instruction][ResizeCurve::into_instruction]).", " ", "\n\n",
+            " ",
+            "For parse accounts infos from processor use struct [ResizeCurveAccounts]",
+            " ", "(method [from_iter][ResizeCurveAccounts::from_iter]).", " ", "\n\n",
+            " ", "For work with account indexes use struct [ResizeCurveAccountIndexes].",
+            "\n", }
+        };
+    }
+    pub(crate) use resize_curve;
+    macro_rules! set_curve_tail {
+        () => {
+            concat! { " ## Accounts", "\n", " ", "\n", "<b><i>", "0", "</i></b>. <b>",
+            "\\[writable\\]", "</b> ", "Curve account to update.", "\n", " ", "\n",
+            "<b><i>", "1", "</i></b>. <b>", "\\[signer\\]", "</b> ", "Curve owner.",
+            "\n", "\n", " ## Usage", "\n", " ",
+            "For create instruction use builder struct [SetCurveTail]", " ",
+            "(method [into_instruction][SetCurveTail::into_instruction]).", " ", "\n\n",
+            " ",
+            "For parse accounts infos from processor use struct [SetCurveTailAccounts]",
+            " ", "(method [from_iter][SetCurveTailAccounts::from_iter]).", " ", "\n\n",
+            " ",
+            "For work with account indexes use struct [SetCurveTailAccountIndexes].",
+            "\n", }
+        };
+    }
+    pub(crate) use set_curve_tail;
+    macro_rules! create_grid_curve {
+        () => {
+            concat! { " ## Accounts", "\n", " ", "\n", "<b><i>", "0", "</i></b>. <b>",
+            "\\[writable, signer\\]", "</b> ", "GridCurve account to create.", "\n", " ",
+            "\n", "<b><i>", "1", "</i></b>. <b>", "\\[writable, signer\\]", "</b> ",
+            "Curve owner.", "\n", " ", "\n", "<b><i>", "2", "</i></b>. <b>", "\\[\\]",
+            "</b> ", "Program config, its creator allow-list is checked when it exists.",
+            "\n", " ", "\n", "<b><i>", "3", "</i></b>. <b>", "\\[writable\\]", "</b> ",
+            "Name link of the owner and the curve name, created or pointed to the curve.",
+            "\n", " ", "\n", "<b><i>", "4", "</i></b>. <b>", "\\[\\]", "</b> ",
+            "System program", "\n", "\n", " ## Usage", "\n", " ",
+            "For create instruction use builder struct [CreateGridCurve]", " ",
+            "(method [into_instruction][CreateGridCurve::into_instruction]).", " ",
+            "\n\n", " ",
+            "For parse accounts infos from processor use struct [CreateGridCurveAccounts]",
+            " ", "(method [from_iter][CreateGridCurveAccounts::from_iter]).", " ",
+            "\n\n", " ",
+            "For work with account indexes use struct [CreateGridCurveAccountIndexes].",
+            "\n", }
+        };
+    }
+    pub(crate) use create_grid_curve;
+    macro_rules! alter_grid_curve {
+        () => {
+            concat! { " ## Accounts", "\n", " ", "\n", "<b><i>", "0", "</i></b>. <b>",
+            "\\[writable\\]", "</b> ", "GridCurve account to update.", "\n", " ", "\n",
+            "<b><i>", "1", "</i></b>. <b>", "\\[signer\\]", "</b> ", "Curve owner.",
+            "\n", "\n", " ## Usage", "\n", " ",
+            "For create instruction use builder struct [AlterGridCurve]", " ",
+            "(method [into_instruction][AlterGridCurve::into_instruction]).", " ",
+            "\n\n", " ",
+            "For parse accounts infos from processor use struct [AlterGridCurveAccounts]",
+            " ", "(method [from_iter][AlterGridCurveAccounts::from_iter]).", " ", "\n\n",
+            " ",
+            "For work with account indexes use struct [AlterGridCurveAccountIndexes].",
+            "\n", }
+        };
+    }
+    pub(crate) use alter_grid_curve;
+    macro_rules! set_curve_y_sign {
+        () => {
+            concat! { " ## Accounts", "\n", " ", "\n", "<b><i>", "0", "</i></b>. <b>",
+            "\\[writable\\]", "</b> ", "Curve account to update.", "\n", " ", "\n",
+            "<b><i>", "1", "</i></b>. <b>", "\\[signer\\]", "</b> ", "Curve owner.",
+            "\n", "\n", " ## Usage", "\n", " ",
+            "For create instruction use builder struct [SetCurveYSign]", " ",
+            "(method [into_instruction][SetCurveYSign::into_instruction]).", " ", "\n\n",
+            " ",
+            "For parse accounts infos from processor use struct [SetCurveYSignAccounts]",
+            " ", "(method [from_iter][SetCurveYSignAccounts::from_iter]).", " ", "\n\n",
+            " ",
+            "For work with account indexes use struct [SetCurveYSignAccountIndexes].",
+            "\n", }
+        };
+    }
+    pub(crate) use set_curve_y_sign;
+    macro_rules! set_curve_status {
+        () => {
+            concat! { " ## Accounts", "\n", " ", "\n", "<b><i>", "0", "</i></b>. <b>",
+            "\\[writable\\]", "</b> ", "Curve account to update.", "\n", " ", "\n",
+            "<b><i>", "1", "</i></b>. <b>", "\\[signer\\]", "</b> ", "Curve owner.",
+            "\n", "\n", " ## Usage", "\n", " ",
+            "For create instruction use builder struct [SetCurveStatus]", " ",
+            "(method [into_instruction][SetCurveStatus::into_instruction]).", " ",
+            "\n\n", " ",
+            "For parse accounts infos from processor use struct [SetCurveStatusAccounts]",
+            " ", "(method [from_iter][SetCurveStatusAccounts::from_iter]).", " ", "\n\n",
+            " ",
+            "For work with account indexes use struct [SetCurveStatusAccountIndexes].",
+            "\n", }
+        };
+    }
+    pub(crate) use set_curve_status;
+    macro_rules! patch_y {
+        () => {
+            concat! { " ## Accounts", "\n", " ", "\n", "<b><i>", "0", "</i></b>. <b>",
+            "\\[writable\\]", "</b> ", "Curve account to update.", "\n", " ", "\n",
+            "<b><i>", "1", "</i></b>. <b>", "\\[signer\\]", "</b> ",
+            "Curve owner or its delegate.", "\n", "\n", " ## Usage", "\n", " ",
+            "For create instruction use builder struct [PatchY]", " ",
+            "(method [into_instruction][PatchY::into_instruction]).", " ", "\n\n", " ",
+            "For parse accounts infos from processor use struct [PatchYAccounts]", " ",
+            "(method [from_iter][PatchYAccounts::from_iter]).", " ", "\n\n", " ",
+            "For work with account indexes use struct [PatchYAccountIndexes].", "\n", }
+        };
+    }
+    pub(crate) use patch_y;
+    macro_rules! mark_for_deletion {
+        () => {
+            concat! { " ## Accounts", "\n", " ", "\n", "<b><i>", "0", "</i></b>. <b>",
+            "\\[writable\\]", "</b> ", "Curve account to mark.", "\n", " ", "\n",
+            "<b><i>", "1", "</i></b>. <b>", "\\[signer\\]", "</b> ", "Curve owner.",
+            "\n", "\n", " ## Usage", "\n", " ",
+            "For create instruction use builder struct [MarkForDeletion]", " ",
+            "(method [into_instruction][MarkForDeletion::into_instruction]).", " ",
+            "\n\n", " ",
+            "For parse accounts infos from processor use struct [MarkForDeletionAccounts]",
+            " ", "(method [from_iter][MarkForDeletionAccounts::from_iter]).", " ",
+            "\n\n", " ",
+            "For work with account indexes use struct [MarkForDeletionAccountIndexes].",
+            "\n", }
+        };
+    }
+    pub(crate) use mark_for_deletion;
+    macro_rules! cancel_deletion {
+        () => {
+            concat! { " ## Accounts", "\n", " ", "\n", "<b><i>", "0", "</i></b>. <b>",
+            "\\[writable\\]", "</b> ", "Curve account to unmark.", "\n", " ", "\n",
+            "<b><i>", "1", "</i></b>. <b>", "\\[signer\\]", "</b> ", "Curve owner.",
+            "\n", "\n", " ## Usage", "\n", " ",
+            "For create instruction use builder struct [CancelDeletion]", " ",
+            "(method [into_instruction][CancelDeletion::into_instruction]).", " ",
+            "\n\n", " ",
+            "For parse accounts infos from processor use struct [CancelDeletionAccounts]",
+            " ", "(method [from_iter][CancelDeletionAccounts::from_iter]).", " ", "\n\n",
+            " ",
+            "For work with account indexes use struct [CancelDeletionAccountIndexes].",
+            "\n", }
+        };
+    }
+    pub(crate) use cancel_deletion;
+    macro_rules! evaluate_curve {
+        () => {
+            concat! { " ## Accounts", "\n", " ", "\n", "<b><i>", "0", "</i></b>. <b>",
+            "\\[\\]", "</b> ", "Curve account to evaluate.", "\n", " ", "\n", "<b><i>",
+            "1", "</i></b>. <b>", "\\[writable\\]", "</b> ",
+            "CurveEvaluation account to store the result in.", "\n", " ", "\n", "<b><i>",
+            "2", "</i></b>. <b>", "\\[signer\\]", "</b> ",
+            "Authority of the result account.", "\n", "\n", " ## Usage", "\n", " ",
+            "For create instruction use builder struct [EvaluateCurve]", " ",
+            "(method [into_instruction][EvaluateCurve::into_instruction]).", " ", "\n\n",
+            " ",
+            "For parse accounts infos from processor use struct [EvaluateCurveAccounts]",
+            " ", "(method [from_iter][EvaluateCurveAccounts::from_iter]).", " ", "\n\n",
+            " ",
+            "For work with account indexes use struct [EvaluateCurveAccountIndexes].",
+            "\n", }
+        };
+    }
+    pub(crate) use evaluate_curve;
+    macro_rules! set_alter_delay {
+        () => {
+            concat! { " ## Accounts", "\n", " ", "\n", "<b><i>", "0", "</i></b>. <b>",
+            "\\[writable\\]", "</b> ", "Curve account to timelock.", "\n", " ", "\n",
+            "<b><i>", "1", "</i></b>. <b>", "\\[signer\\]", "</b> ", "Curve owner.",
+            "\n", "\n", " ## Usage", "\n", " ",
+            "For create instruction use builder struct [SetAlterDelay]", " ",
+            "(method [into_instruction][SetAlterDelay::into_instruction]).", " ", "\n\n",
+            " ",
+            "For parse accounts infos from processor use struct [SetAlterDelayAccounts]",
+            " ", "(method [from_iter][SetAlterDelayAccounts::from_iter]).", " ", "\n\n",
+            " ",
+            "For work with account indexes use struct [SetAlterDelayAccountIndexes].",
+            "\n", }
+        };
     }
-}
-impl<'a, const N: usize> TryFrom<&'a [u8; N]> for DeleteCurveAccountIndexes {
-    type Error = usize;
-    fn try_from(indexes: &'a [u8; N]) -> Result<Self, Self::Error> {
-        Self::try_from_indexes(indexes)
+    pub(crate) use set_alter_delay;
+    macro_rules! propose_alter {
+        () => {
+            concat! { " ## Accounts", "\n", " ", "\n", "<b><i>", "0", "</i></b>. <b>",
+            "\\[writable\\]", "</b> ", "Curve account to alter.", "\n", " ", "\n", "<b><i>", "1",
+            "</i></b>. <b>", "\\[writable, signer\\]", "</b> ",
+            "Curve owner, pays for the pending alter account.", "\n", " ", "\n",
+            "<b><i>", "2", "</i></b>. <b>", "\\[writable\\]", "</b> ",
+            "Pending alter account, PDA of the curve.", "\n", " ", "\n", "<b><i>", "3",
+            "</i></b>. <b>", "\\[\\]", "</b> ", "System program", "\n", "\n",
+            " ## Usage", "\n", " ",
+            "For create instruction use builder struct [ProposeAlter]", " ",
+            "(method [into_instruction][ProposeAlter::into_instruction]).", " ", "\n\n",
+            " ",
+            "For parse accounts infos from processor use struct [ProposeAlterAccounts]",
+            " ", "(method [from_iter][ProposeAlterAccounts::from_iter]).", " ", "\n\n",
+            " ",
+            "For work with account indexes use struct [ProposeAlterAccountIndexes].",
+            "\n", }
+        };
     }
-}
-impl<const N: usize> TryFrom<[u8; N]> for DeleteCurveAccountIndexes {
-    type Error = usize;
-    fn try_from(indexes: [u8; N]) -> Result<Self, Self::Error> {
-        Self::try_from_indexes(&indexes)
+    pub(crate) use propose_alter;
+    macro_rules! apply_alter {
+        () => {
+            concat! { " ## Accounts", "\n", " ", "\n", "<b><i>", "0", "</i></b>. <b>",
+            "\\[writable\\]", "</b> ", "Curve account to alter.", "\n", " ", "\n",
+            "<b><i>", "1", "</i></b>. <b>", "\\[writable, signer\\]", "</b> ",
+            "Curve owner, receives lamports of the pending alter account.", "\n", " ",
+            "\n", "<b><i>", "2", "</i></b>. <b>", "\\[writable\\]", "</b> ",
+            "Pending alter account of the curve.", "\n", "\n", " ## Usage", "\n", " ",
+            "For create instruction use builder struct [ApplyAlter]", " ",
+            "(method [into_instruction][ApplyAlter::into_instruction]).", " ", "\n\n",
+            " ",
+            "For parse accounts infos from processor use struct [ApplyAlterAccounts]",
+            " ", "(method [from_iter][ApplyAlterAccounts::from_iter]).", " ", "\n\n",
+            " ", "For work with account indexes use struct [ApplyAlterAccountIndexes].",
+            "\n", }
+        };
     }
-}
-impl TryFrom<Vec<u8>> for DeleteCurveAccountIndexes {
-    type Error = usize;
-    fn try_from(indexes: Vec<u8>) -> Result<Self, Self::Error> {
-        Self::try_from_indexes(&indexes)
+    pub(crate) use apply_alter;
+    macro_rules! cancel_alter {
+        () => {
+            concat! { " ## Accounts", "\n", " ", "\n", "<b><i>", "0", "</i></b>. <b>",
+            "\\[writable\\]", "</b> ", "Curve account of the proposal.", "\n", " ", "\n",
+            "<b><i>", "1", "</i></b>. <b>", "\\[writable, signer\\]", "</b> ",
+            "Curve owner, receives lamports of the pending alter account.", "\n", " ",
+            "\n", "<b><i>", "2", "</i></b>. <b>", "\\[writable\\]", "</b> ",
+            "Pending alter account of the curve.", "\n", "\n", " ## Usage", "\n", " ",
+            "For create instruction use builder struct [CancelAlter]", " ",
+            "(method [into_instruction][CancelAlter::into_instruction]).", " ", "\n\n",
+            " ",
+            "For parse accounts infos from processor use struct [CancelAlterAccounts]",
+            " ", "(method [from_iter][CancelAlterAccounts::from_iter]).", " ", "\n\n",
+            " ", "For work with account indexes use struct [CancelAlterAccountIndexes].",
+            "\n", }
+        };
     }
-}
-///[CurvyInstruction::CreateCurve] instruction account infos helper
-#[derive(Debug)]
-pub struct CreateCurveAccounts<'a, 'i> {
-    ///Curve account to create.
-    pub curve: &'a solana_program::account_info::AccountInfo<'i>,
-    ///Curve owner.
-    pub owner: &'a solana_program::account_info::AccountInfo<'i>,
-    ///System program
-    pub system_program: &'a solana_program::account_info::AccountInfo<'i>,
-}
-impl<'a, 'i> CreateCurveAccounts<'a, 'i> {
-    pub fn from_iter<I>(
-        iter: &mut I,
-        program_id: &solana_program::pubkey::Pubkey,
-    ) -> std::result::Result<Self, texture_common::macros::accounts::AccountParseError>
-    where
-        I: Iterator<Item = &'a solana_program::account_info::AccountInfo<'i>>,
-    {
-        let __self_program_id__ = program_id;
-        let curve = texture_common::utils::next_account_info(iter)?;
-        let owner = texture_common::utils::next_account_info(iter)?;
-        let system_program = texture_common::utils::next_account_info(iter)?;
-        #[cfg(not(feature = "program-id-manually"))] #[allow(clippy::needless_borrow)]
-        texture_common::utils::verify_key(
-            __self_program_id__,
-            &crate::ID,
-            "self_program_id",
-        )?;
-        if !curve.is_writable {
-            solana_program::msg!(concat!(stringify!(curve), " is not writable"));
-            return Err(texture_common::error::InvalidAccount(*curve.key).into());
-        }
-        if !curve.is_signer {
-            return Err(texture_common::error::MissingSignature(*curve.key).into());
-        }
-        #[allow(clippy::needless_borrow)]
-        texture_common::utils::verify_key(
-            curve.owner,
-            &solana_program::system_program::ID,
-            concat!(stringify!(curve), " owner"),
-        )?;
-        if curve.data_len() != 0 {
-            solana_program::msg!(
-                concat!("invalid ", stringify!(curve), " account size")
-            );
-            return Err(texture_common::error::InvalidAccount(*curve.key).into());
-        }
-        if !owner.is_writable {
-            solana_program::msg!(concat!(stringify!(owner), " is not writable"));
-            return Err(texture_common::error::InvalidAccount(*owner.key).into());
-        }
-        if !owner.is_signer {
-            return Err(texture_common::error::MissingSignature(*owner.key).into());
-        }
-        #[allow(clippy::needless_borrow)]
-        texture_common::utils::verify_key(
-            system_program.key,
-            &solana_program::system_program::ID,
-            stringify!(system_program),
-        )?;
-        Ok(Self {
-            curve,
-            owner,
-            system_program,
-        })
+    pub(crate) use cancel_alter;
+    macro_rules! add_delegate {
+        () => {
+            concat! { " ## Accounts", "\n", " ", "\n", "<b><i>", "0", "</i></b>. <b>",
+            "\\[writable\\]", "</b> ", "Curve account to delegate.", "\n", " ", "\n",
+            "<b><i>", "1", "</i></b>. <b>", "\\[signer\\]", "</b> ", "Curve owner.",
+            "\n", "\n", " ## Usage", "\n", " ",
+            "For create instruction use builder struct [AddDelegate]", " ",
+            "(method [into_instruction][AddDelegate::into_instruction]).", " ", "\n\n",
+            " ",
+            "For parse accounts infos from processor use struct [AddDelegateAccounts]",
+            " ", "(method [from_iter][AddDelegateAccounts::from_iter]).", " ", "\n\n",
+            " ", "For work with account indexes use struct [AddDelegateAccountIndexes].",
+            "\n", }
+        };
     }
-}
-///[CurvyInstruction::AlterCurve] instruction account infos helper
-#[derive(Debug)]
-pub struct AlterCurveAccounts<'a, 'i> {
-    ///Curve account to update.
-    pub curve: &'a solana_program::account_info::AccountInfo<'i>,
-    ///Curve owner.
-    pub owner: &'a solana_program::account_info::AccountInfo<'i>,
-}
-impl<'a, 'i> AlterCurveAccounts<'a, 'i> {
-    pub fn from_iter<I>(
-        iter: &mut I,
-        program_id: &solana_program::pubkey::Pubkey,
-    ) -> std::result::Result<Self, texture_common::macros::accounts::AccountParseError>
-    where
-        I: Iterator<Item = &'a solana_program::account_info::AccountInfo<'i>>,
-    {
-        let __self_program_id__ = program_id;
-        let curve = texture_common::utils::next_account_info(iter)?;
-        let owner = texture_common::utils::next_account_info(iter)?;
-        #[cfg(not(feature = "program-id-manually"))] #[allow(clippy::needless_borrow)]
-        texture_common::utils::verify_key(
-            __self_program_id__,
-            &crate::ID,
-            "self_program_id",
-        )?;
-        if !curve.is_writable {
-            solana_program::msg!(concat!(stringify!(curve), " is not writable"));
-            return Err(texture_common::error::InvalidAccount(*curve.key).into());
-        }
-        #[allow(clippy::needless_borrow)]
-        texture_common::utils::verify_key(
-            curve.owner,
-            &__self_program_id__,
-            concat!(stringify!(curve), " owner"),
-        )?;
-        if !owner.is_signer {
-            return Err(texture_common::error::MissingSignature(*owner.key).into());
-        }
-        Ok(Self { curve, owner })
+    pub(crate) use add_delegate;
+    macro_rules! remove_delegate {
+        () => {
+            concat! { " ## Accounts", "\n", " ", "\n", "<b><i>", "0", "</i></b>. <b>",
+            "\\[writable\\]", "</b> ", "Curve account of the delegate.", "\n", " ", "\n",
+            "<b><i>", "1", "</i></b>. <b>", "\\[signer\\]", "</b> ", "Curve owner.",
+            "\n", "\n", " ## Usage", "\n", " ",
+            "For create instruction use builder struct [RemoveDelegate]", " ",
+            "(method [into_instruction][RemoveDelegate::into_instruction]).", " ",
+            "\n\n", " ",
+            "For parse accounts infos from processor use struct [RemoveDelegateAccounts]",
+            " ", "(method [from_iter][RemoveDelegateAccounts::from_iter]).", " ", "\n\n",
+            " ",
+            "For work with account indexes use struct [RemoveDelegateAccountIndexes].",
+            "\n", }
+        };
     }
-}
-///[CurvyInstruction::DeleteCurve] instruction account infos helper
-#[derive(Debug)]
-pub struct DeleteCurveAccounts<'a, 'i> {
-    ///Curve account to delete.
-    pub curve: &'a solana_program::account_info::AccountInfo<'i>,
-    ///Curve owner.
-    pub owner: &'a solana_program::account_info::AccountInfo<'i>,
-}
-impl<'a, 'i> DeleteCurveAccounts<'a, 'i> {
-    pub fn from_iter<I>(
-        iter: &mut I,
-        program_id: &solana_program::pubkey::Pubkey,
-    ) -> std::result::Result<Self, texture_common::macros::accounts::AccountParseError>
-    where
-        I: Iterator<Item = &'a solana_program::account_info::AccountInfo<'i>>,
-    {
-        let __self_program_id__ = program_id;
-        let curve = texture_common::utils::next_account_info(iter)?;
-        let owner = texture_common::utils::next_account_info(iter)?;
-        #[cfg(not(feature = "program-id-manually"))] #[allow(clippy::needless_borrow)]
-        texture_common::utils::verify_key(
-            __self_program_id__,
-            &crate::ID,
-            "self_program_id",
-        )?;
-        if !curve.is_writable {
-            solana_program::msg!(concat!(stringify!(curve), " is not writable"));
-            return Err(texture_common::error::InvalidAccount(*curve.key).into());
-        }
-        #[allow(clippy::needless_borrow)]
-        texture_common::utils::verify_key(
-            curve.owner,
-            &__self_program_id__,
-            concat!(stringify!(curve), " owner"),
-        )?;
-        if !owner.is_signer {
-            return Err(texture_common::error::MissingSignature(*owner.key).into());
-        }
-        Ok(Self { curve, owner })
+    pub(crate) use remove_delegate;
+    macro_rules! init_config {
+        () => {
+            concat! { " ## Accounts", "\n", " ", "\n", "<b><i>", "0", "</i></b>. <b>",
+            "\\[writable\\]", "</b> ",
+            "Config account to create, PDA of the config seed.", "\n", " ", "\n",
+            "<b><i>", "1", "</i></b>. <b>", "\\[writable, signer\\]", "</b> ",
+            "Upgrade authority of the program, pays for the config account.", "\n", " ",
+            "\n", "<b><i>", "2", "</i></b>. <b>", "\\[\\]", "</b> ",
+            "ProgramData account of the program.", "\n", " ", "\n", "<b><i>", "3",
+            "</i></b>. <b>", "\\[\\]", "</b> ", "System program", "\n", "\n",
+            " ## Usage", "\n", " ",
+            "For create instruction use builder struct [InitConfig]", " ",
+            "(method [into_instruction][InitConfig::into_instruction]).", " ", "\n\n",
+            " ",
+            "For parse accounts infos from processor use struct [InitConfigAccounts]",
+            " ", "(method [from_iter][InitConfigAccounts::from_iter]).", " ", "\n\n",
+            " ", "For work with account indexes use struct [InitConfigAccountIndexes].",
+            "\n", }
+        };
     }
-}
-pub(crate) mod ix_docs {
-    macro_rules! create_curve {
+    pub(crate) use init_config;
+    macro_rules! set_config {
         () => {
             concat! { " ## Accounts", "\n", " ", "\n", "<b><i>", "0", "</i></b>. <b>",
-            "\\[writable, signer\\]", "</b> ", "Curve account to create.", "\n", " ",
-            "\n", "<b><i>", "1", "</i></b>. <b>", "\\[writable, signer\\]", "</b> ",
-            "Curve owner.", "\n", " ", "\n", "<b><i>", "2", "</i></b>. <b>", "\\[\\]",
-            "</b> ", "System program", "\n", "\n", " ## Usage", "\n", " ",
-            "For create instruction use builder struct [CreateCurve]", " ",
-            "(method [into_instruction][CreateCurve::into_instruction]).", " ", "\n\n",
+            "\\[writable\\]", "</b> ", "Config account to update.", "\n", " ", "\n",
+            "<b><i>", "1", "</i></b>. <b>", "\\[signer\\]", "</b> ",
+            "Current config admin.", "\n", "\n", " ## Usage", "\n", " ",
+            "For create instruction use builder struct [SetConfig]", " ",
+            "(method [into_instruction][SetConfig::into_instruction]).", " ", "\n\n",
             " ",
-            "For parse accounts infos from processor use struct [CreateCurveAccounts]",
-            " ", "(method [from_iter][CreateCurveAccounts::from_iter]).", " ", "\n\n",
-            " ", "For work with account indexes use struct [CreateCurveAccountIndexes].",
+            "For parse accounts infos from processor use struct [SetConfigAccounts]",
+            " ", "(method [from_iter][SetConfigAccounts::from_iter]).", " ", "\n\n", " ",
+            "For work with account indexes use struct [SetConfigAccountIndexes].", "\n",
+            }
+        };
+    }
+    pub(crate) use set_config;
+    macro_rules! set_curve_owners {
+        () => {
+            concat! { " ## Accounts", "\n", " ", "\n", "<b><i>", "0", "</i></b>. <b>",
+            "\\[writable\\]", "</b> ", "Curve account to update.", "\n", " ", "\n",
+            "<b><i>", "1", "</i></b>. <b>", "\\[writable, signer\\]", "</b> ",
+            "Curve owner, any owner of co-owned curve. Pays for the curve owners account.",
+            "\n", " ", "\n", "<b><i>", "2", "</i></b>. <b>", "\\[writable\\]", "</b> ",
+            "Curve owners account, PDA of the curve.", "\n", " ", "\n", "<b><i>", "3",
+            "</i></b>. <b>", "\\[\\]", "</b> ", "System program", "\n", "\n",
+            " ## Usage", "\n", " ",
+            "For create instruction use builder struct [SetCurveOwners]", " ",
+            "(method [into_instruction][SetCurveOwners::into_instruction]).", " ",
+            "\n\n", " ",
+            "For parse accounts infos from processor use struct [SetCurveOwnersAccounts]",
+            " ", "(method [from_iter][SetCurveOwnersAccounts::from_iter]).", " ", "\n\n",
+            " ",
+            "For work with account indexes use struct [SetCurveOwnersAccountIndexes].",
             "\n", }
         };
     }
-    pub(crate) use create_curve;
-    macro_rules! alter_curve {
+    pub(crate) use set_curve_owners;
+    macro_rules! set_curve_extension {
+        () => {
+            concat! { " ## Accounts", "\n", " ", "\n", "<b><i>", "0", "</i></b>. <b>",
+            "\\[writable\\]", "</b> ", "Curve account to update.", "\n", " ", "\n",
+            "<b><i>", "1", "</i></b>. <b>", "\\[writable, signer\\]", "</b> ",
+            "Curve owner, pays rent of the grown account.", "\n", " ", "\n", "<b><i>",
+            "2", "</i></b>. <b>", "\\[\\]", "</b> ", "System program", "\n", "\n",
+            " ## Usage", "\n", " ",
+            "For create instruction use builder struct [SetCurveExtension]", " ",
+            "(method [into_instruction][SetCurveExtension::into_instruction]).", " ",
+            "\n\n", " ",
+            "For parse accounts infos from processor use struct [SetCurveExtensionAccounts]",
+            " ", "(method [from_iter][SetCurveExtensionAccounts::from_iter]).", " ",
+            "\n\n", " ",
+            "For work with account indexes use struct [SetCurveExtensionAccountIndexes].",
+            "\n", }
+        };
+    }
+    pub(crate) use set_curve_extension;
+    macro_rules! set_curve_y_transform {
         () => {
             concat! { " ## Accounts", "\n", " ", "\n", "<b><i>", "0", "</i></b>. <b>",
             "\\[writable\\]", "</b> ", "Curve account to update.", "\n", " ", "\n",
             "<b><i>", "1", "</i></b>. <b>", "\\[signer\\]", "</b> ", "Curve owner.",
             "\n", "\n", " ## Usage", "\n", " ",
-            "For create instruction use builder struct [AlterCurve]", " ",
-            "(method [into_instruction][AlterCurve::into_instruction]).", " ", "\n\n",
-            " ",
-            "For parse accounts infos from processor use struct [AlterCurveAccounts]",
-            " ", "(method [from_iter][AlterCurveAccounts::from_iter]).", " ", "\n\n",
-            " ", "For work with account indexes use struct [AlterCurveAccountIndexes].",
+            "For create instruction use builder struct [SetCurveYTransform]", " ",
+            "(method [into_instruction][SetCurveYTransform::into_instruction]).", " ",
+            "\n\n", " ",
+            "For parse accounts infos from processor use struct [SetCurveYTransformAccounts]",
+            " ", "(method [from_iter][SetCurveYTransformAccounts::from_iter]).", " ",
+            "\n\n", " ",
+            "For work with account indexes use struct [SetCurveYTransformAccountIndexes].",
             "\n", }
         };
     }
-    pub(crate) use alter_curve;
-    macro_rules! delete_curve {
+    pub(crate) use set_curve_y_transform;
+    macro_rules! set_curve_expiry {
         () => {
             concat! { " ## Accounts", "\n", " ", "\n", "<b><i>", "0", "</i></b>. <b>",
-            "\\[writable\\]", "</b> ", "Curve account to delete.", "\n", " ", "\n",
+            "\\[writable\\]", "</b> ", "Curve account to update.", "\n", " ", "\n",
             "<b><i>", "1", "</i></b>. <b>", "\\[signer\\]", "</b> ", "Curve owner.",
             "\n", "\n", " ## Usage", "\n", " ",
-            "For create instruction use builder struct [DeleteCurve]", " ",
-            "(method [into_instruction][DeleteCurve::into_instruction]).", " ", "\n\n",
+            "For create instruction use builder struct [SetCurveExpiry]", " ",
+            "(method [into_instruction][SetCurveExpiry::into_instruction]).", " ",
+            "\n\n", " ",
+            "For parse accounts infos from processor use struct [SetCurveExpiryAccounts]",
+            " ", "(method [from_iter][SetCurveExpiryAccounts::from_iter]).", " ", "\n\n",
             " ",
-            "For parse accounts infos from processor use struct [DeleteCurveAccounts]",
-            " ", "(method [from_iter][DeleteCurveAccounts::from_iter]).", " ", "\n\n",
-            " ", "For work with account indexes use struct [DeleteCurveAccountIndexes].",
+            "For work with account indexes use struct [SetCurveExpiryAccountIndexes].",
             "\n", }
         };
     }
-    pub(crate) use delete_curve;
+    pub(crate) use set_curve_expiry;
+    macro_rules! batch_alter {
+        () => {
+            concat! { " ## Accounts", "\n", " ", "\n", "<b><i>", "0", "</i></b>. <b>",
+            "\\[signer\\]", "</b> ", "Owner or delegate of every altered curve.", "\n",
+            "\n", " ## Usage", "\n", " ",
+            "For create instruction use builder struct [BatchAlter]", " ",
+            "(method [into_instruction][BatchAlter::into_instruction]).", " ", "\n\n",
+            " ",
+            "For parse accounts infos from processor use struct [BatchAlterAccounts]",
+            " ", "(method [from_iter][BatchAlterAccounts::from_iter]).", " ", "\n\n",
+            " ", "For work with account indexes use struct [BatchAlterAccountIndexes].",
+            "\n", }
+        };
+    }
+    pub(crate) use batch_alter;
+    macro_rules! create_reference_curve {
+        () => {
+            concat! { " ## Accounts", "\n", " ", "\n", "<b><i>", "0", "</i></b>. <b>",
+            "\\[writable\\]", "</b> ", "Curve account to create, PDA of kind and mint.",
+            "\n", " ", "\n", "<b><i>", "1", "</i></b>. <b>", "\\[writable, signer\\]",
+            "</b> ", "Config admin, pays for the curve.", "\n", " ", "\n",
+            "<b><i>", "2", "</i></b>. <b>", "\\[\\]", "</b> ", "Program config.", "\n",
+            " ", "\n", "<b><i>", "3", "</i></b>. <b>", "\\[\\]", "</b> ",
+            "SPL Token or Token-2022 mint.", "\n", " ", "\n", "<b><i>", "4",
+            "</i></b>. <b>", "\\[writable\\]", "</b> ",
+            "Name link of the config and the curve name, created or pointed to the curve.",
+            "\n", " ", "\n", "<b><i>", "5", "</i></b>. <b>", "\\[\\]", "</b> ",
+            "System program", "\n", "\n", " ## Usage", "\n", " ",
+            "For create instruction use builder struct [CreateReferenceCurve]", " ",
+            "(method [into_instruction][CreateReferenceCurve::into_instruction]).", " ",
+            "\n\n", " ",
+            "For parse accounts infos from processor use struct [CreateReferenceCurveAccounts]",
+            " ", "(method [from_iter][CreateReferenceCurveAccounts::from_iter]).", " ",
+            "\n\n", " ",
+            "For work with account indexes use struct [CreateReferenceCurveAccountIndexes].",
+            "\n", }
+        };
+    }
+    pub(crate) use create_reference_curve;
+    macro_rules! set_creation_frozen {
+        () => {
+            concat! { " ## Accounts", "\n", " ", "\n", "<b><i>", "0", "</i></b>. <b>",
+            "\\[writable\\]", "</b> ", "Config account to update.", "\n", " ", "\n",
+            "<b><i>", "1", "</i></b>. <b>", "\\[signer\\]", "</b> ",
+            "Config admin.", "\n", "\n", " ## Usage", "\n", " ",
+            "For create instruction use builder struct [SetCreationFrozen]", " ",
+            "(method [into_instruction][SetCreationFrozen::into_instruction]).", " ", "\n\n",
+            " ",
+            "For parse accounts infos from processor use struct [SetCreationFrozenAccounts]",
+            " ", "(method [from_iter][SetCreationFrozenAccounts::from_iter]).", " ", "\n\n", " ",
+            "For work with account indexes use struct [SetCreationFrozenAccountIndexes].", "\n",
+            }
+        };
+    }
+    pub(crate) use set_creation_frozen;
 }
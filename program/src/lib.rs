@@ -1,4 +1,10 @@
+#[cfg(feature = "anchor")]
+pub mod anchor;
+#[cfg(feature = "devnet-utils")]
+pub mod devnet;
 pub mod error;
+pub mod eval;
+pub mod events;
 pub mod instruction;
 pub mod processor;
 pub mod state;
@@ -6,6 +12,9 @@ pub mod state;
 #[cfg(not(feature = "no-entrypoint"))]
 pub mod entrypoint;
 
+#[cfg(all(feature = "mainnet", feature = "devnet-utils"))]
+compile_error!("`devnet-utils` test-only instructions must not be built into mainnet program");
+
 solana_program::declare_id!("CurvyNqr6HXwWUHk7MfDk7rqL3a4Kodkz8BkdBhep7ed");
 
 pub type CurvyResult<T> = std::result::Result<T, error::CurvyError>;
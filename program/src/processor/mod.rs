@@ -1,22 +1,68 @@
 use borsh::BorshDeserialize;
 use solana_program::account_info::AccountInfo;
+use solana_program::bpf_loader_upgradeable;
+use solana_program::clock::Clock;
 use solana_program::entrypoint::ProgramResult;
 use solana_program::msg;
 use solana_program::program_error::ProgramError;
+use solana_program::program_pack::Pack;
 use solana_program::pubkey::Pubkey;
 use solana_program::rent::Rent;
 use solana_program::sysvar::Sysvar;
+use spl_token::state::Mint;
 use texture_common::account::PodAccount;
+use texture_common::error::InvalidAccount;
+use texture_common::macros::accounts::AccountParseError;
 use texture_common::remote::system::SystemProgram;
 use texture_common::utils::verify_key;
 
-use crate::error::CurvyError;
+use crate::error::{AccountContext, CurvyError};
+use crate::events::{self, CurveAltered, CurveCreated, CurveDeleted, CurveEvent};
 use crate::instruction::{
-    AlterCurveAccounts, CreateCurveAccounts, CurvyInstruction, DeleteCurveAccounts,
+    AcceptOwnerAccounts, AddDelegateAccounts, AlterCurveAccounts, AlterGridCurveAccounts,
+    AlterWideCurveAccounts, ApplyAlterAccounts, BatchAlterAccounts, CancelAlterAccounts,
+    CancelDeletionAccounts, ClearCurveAssetAccounts, CreateCurveAccounts, CreateCurvePdaAccounts,
+    CreateGridCurveAccounts, CreateReferenceCurveAccounts, CreateWideCurveAccounts,
+    CurvyInstruction, DeleteCurveAccounts, DeleteCurveConfidenceAccounts,
+    DeleteCurveSeriesAccounts, EvaluateCurveAccounts, FreezeCurveAccounts, InitConfigAccounts,
+    MarkForDeletionAccounts, MigrateCurveAccounts, PatchYAccounts, ProposeAlterAccounts,
+    ProposeOwnerAccounts, RemoveDelegateAccounts, ReserveCurveAccounts, ResizeCurveAccounts,
+    SetAlterDelayAccounts, SetConfigAccounts, SetCreationFrozenAccounts, SetCurveAssetAccounts,
+    SetCurveConfidenceAccounts, SetCurveExpiryAccounts, SetCurveExtensionAccounts,
+    SetCurveOwnersAccounts, SetCurveSeriesAccounts, SetCurveStatusAccounts, SetCurveTailAccounts,
+    SetCurveXSignAccounts, SetCurveYSignAccounts, SetCurveYTransformAccounts, TopUpRentAccounts,
+    UpdateCurveDataAccounts, UpdateCurveMetadataAccounts,
 };
-use crate::state::curve::{Curve, CurveParams};
+use crate::state::asset_link::AssetLink;
+use crate::state::config::Config;
+use crate::state::curve::{
+    CompactCurveParams, Curve, CurveParams, CurveParamsRaw, CurveStatus, CurveX, CurveY,
+    YTransform, MAX_CURVE_DELEGATES, MAX_TOTAL_Y_CNT, MAX_Y_CNT, SYMBOL_MAX_SIZE,
+};
+use crate::state::curve_confidence::{CurveConfidence, CurveConfidenceBps};
+use crate::state::curve_evaluation::CurveEvaluation;
+use crate::state::curve_extension::{self, ExtensionType};
+use crate::state::curve_owners::CurveOwners;
+use crate::state::curve_series::{CurveSeries, MAX_EXTRA_SERIES};
+use crate::state::grid_curve::{GridCurve, GridCurveParams};
+use crate::state::name_link::{check_name_kept, NameLink};
+use crate::state::pending_alter::PendingAlter;
+use crate::state::wide_curve::{WideCurve, WideCurveParams};
+use crate::state::{probe, AccountKind};
 use crate::CurvyResult;
 
+/// Token-2022 program, its mints can be curve assets as well as SPL Token ones
+pub const TOKEN_2022_ID: Pubkey =
+    solana_program::pubkey!("TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb");
+
+/// Borsh tag of [CurvyInstruction::CreateCurve]
+pub const CREATE_CURVE_TAG: u8 = 0;
+/// Borsh tag of [CurvyInstruction::AlterCurve]
+pub const ALTER_CURVE_TAG: u8 = 1;
+/// First byte of test-only instructions built with `devnet-utils` feature, never a tag of
+/// [CurvyInstruction]
+pub const DEVNET_TAG: u8 = 0xDE;
+
 pub struct Processor<'a, 'b> {
     program_id: &'a Pubkey,
     accounts: &'a [AccountInfo<'b>],
@@ -30,23 +76,477 @@ impl<'a, 'b> Processor<'a, 'b> {
         }
     }
 
+    /// Instruction accounts parsed by `from_iter` of the generated `*Accounts` struct. Fails with
+    /// [CurvyError::UnexpectedAccounts] when accounts are left after them, so an instruction is
+    /// never processed with accounts its caller meant for something else.
+    fn parse_accounts<T>(
+        &self,
+        from_iter: impl FnOnce(
+            &mut std::slice::Iter<'a, AccountInfo<'b>>,
+            &Pubkey,
+        ) -> Result<T, AccountParseError>,
+    ) -> CurvyResult<T> {
+        let iter = &mut self.accounts.iter();
+        let accounts = from_iter(iter, self.program_id)?;
+        check_no_accounts_left(iter)?;
+
+        Ok(accounts)
+    }
+
+    /// Same as [Processor::parse_accounts], accounts left after the instruction ones are
+    /// returned instead of rejected. They are other signers of co-owned curves, see
+    /// [Processor::check_co_owners].
+    fn parse_accounts_with_rest<T>(
+        &self,
+        from_iter: impl FnOnce(
+            &mut std::slice::Iter<'a, AccountInfo<'b>>,
+            &Pubkey,
+        ) -> Result<T, AccountParseError>,
+    ) -> CurvyResult<(T, &'a [AccountInfo<'b>])> {
+        let iter = &mut self.accounts.iter();
+        let accounts = from_iter(iter, self.program_id)?;
+
+        Ok((accounts, iter.as_slice()))
+    }
+
+    /// Fails unless `owner` and `co_signers` are signing owners listed in the `curve_owners`
+    /// account of `curve`, at least `threshold` of them
+    fn check_co_owners(
+        &self,
+        curve: &Pubkey,
+        curve_owners: &AccountInfo<'b>,
+        owner: &AccountInfo<'b>,
+        co_signers: &[AccountInfo<'b>],
+        threshold: u8,
+    ) -> CurvyResult<()> {
+        if curve_owners.owner != self.program_id {
+            msg!("curve_owners is not owned by the program");
+            return Err(InvalidAccount(*curve_owners.key).into());
+        }
+
+        let owners_data = curve_owners.data.borrow();
+        let owners = CurveOwners::try_from_bytes(&owners_data).account(curve_owners.key)?;
+        if owners.curve != *curve {
+            msg!("curve_owners does not belong to the curve");
+            return Err(InvalidAccount(*curve_owners.key).into());
+        }
+
+        if let Some(unsigned) = co_signers.iter().find(|signer| !signer.is_signer) {
+            msg!("co-signer {} did not sign", unsigned.key);
+            return Err(InvalidAccount(*unsigned.key).into());
+        }
+
+        let signers = std::iter::once(owner)
+            .chain(co_signers)
+            .map(|signer| signer.key);
+        owners.check_signers(signers, threshold).account(curve)
+    }
+
+    /// Fails with [CurvyError::CreationFrozen] or [CurvyError::CreatorNotAllowed] when the
+    /// program config exists and freezes curve creation or `owner` is not in its creator
+    /// allow-list
+    fn check_creator(&self, config: &AccountInfo<'b>, owner: &Pubkey) -> CurvyResult<()> {
+        let (config_key, _) = Pubkey::find_program_address(&Config::seeds(), self.program_id);
+        verify_key(config.key, &config_key, "config").account(config.key)?;
+
+        // not initialized, anyone creates curves
+        if config.owner != self.program_id {
+            return Ok(());
+        }
+
+        let config_data = config.data.borrow();
+        let unpacked_config = Config::try_from_bytes(&config_data).account(config.key)?;
+
+        unpacked_config
+            .check_creation_not_frozen()
+            .account(config.key)?;
+        unpacked_config.check_creator(owner).account(config.key)
+    }
+
+    /// Whether `curve` is a reference curve, owned by the config address, see
+    /// CreateReferenceCurve
+    fn is_reference_curve(&self, curve: &Curve) -> bool {
+        let (config_key, _) = Pubkey::find_program_address(&Config::seeds(), self.program_id);
+        curve.owner == config_key
+    }
+
+    /// Fails unless `signer` is the current config admin, the config account is the first of
+    /// `rest`. Returns the accounts after it.
+    fn check_config_admin<'r>(
+        &self,
+        curve: &Pubkey,
+        signer: &Pubkey,
+        rest: &'r [AccountInfo<'b>],
+    ) -> CurvyResult<&'r [AccountInfo<'b>]> {
+        let [config, rest @ ..] = rest else {
+            msg!("config account is missing, reference curves are managed by the config admin");
+            return Err(CurvyError::OperationCanNotBePerformed).account(curve);
+        };
+
+        let (config_key, _) = Pubkey::find_program_address(&Config::seeds(), self.program_id);
+        verify_key(config.key, &config_key, "config").account(config.key)?;
+
+        let config_data = config.data.borrow();
+        let unpacked_config = Config::try_from_bytes(&config_data).account(config.key)?;
+        verify_key(signer, &unpacked_config.admin, "admin").account(config.key)?;
+
+        Ok(rest)
+    }
+
+    /// Fails unless `signer` is the single owner of `curve`, or the current config admin for
+    /// reference curves. Returns accounts of `rest` left after the config account of the latter.
+    fn check_curve_owner<'r>(
+        &self,
+        curve_key: &Pubkey,
+        curve: &Curve,
+        signer: &Pubkey,
+        rest: &'r [AccountInfo<'b>],
+    ) -> CurvyResult<&'r [AccountInfo<'b>]> {
+        if self.is_reference_curve(curve) {
+            return self.check_config_admin(curve_key, signer, rest);
+        }
+
+        curve.check_owner(signer).account(curve_key)?;
+        Ok(rest)
+    }
+
+    /// Points the name link of `owner` and `name` to `curve`, creating the link on first use
+    /// paid by `payer`
+    fn register_name(
+        &self,
+        name_link: &AccountInfo<'b>,
+        owner: &Pubkey,
+        payer: &AccountInfo<'b>,
+        system_program: &AccountInfo<'b>,
+        name: &[u8; SYMBOL_MAX_SIZE],
+        curve: &Pubkey,
+    ) -> CurvyResult<()> {
+        let seeds = NameLink::seeds(owner, name);
+        let (link_key, bump) = Pubkey::find_program_address(&seeds, self.program_id);
+        verify_key(name_link.key, &link_key, "name_link").account(name_link.key)?;
+
+        if name_link.owner == self.program_id {
+            let mut link_data = name_link.data.borrow_mut();
+            NameLink::try_from_bytes_mut(&mut link_data)
+                .account(name_link.key)?
+                .curve = *curve;
+
+            return Ok(());
+        }
+
+        let rent = Rent::get().expect("No Rent");
+        let bump = [bump];
+
+        SystemProgram::new(system_program)
+            .create_account(
+                payer,
+                name_link,
+                NameLink::SIZE as u64,
+                rent.minimum_balance(NameLink::SIZE),
+                self.program_id,
+            )
+            .call_signed(&[&[seeds[0], seeds[1], seeds[2], &bump]])
+            .account(name_link.key)?;
+
+        NameLink::init_bytes(&mut name_link.data.borrow_mut(), (*owner, *name, *curve))
+            .account(name_link.key)
+    }
+
+    /// Closes the name link of `owner` and `name` if it points to `curve`, its rent goes to
+    /// `receiver`. Links of curves created under the name after it point elsewhere.
+    fn unregister_name(
+        &self,
+        name_link: &AccountInfo<'b>,
+        owner: &Pubkey,
+        receiver: &AccountInfo<'b>,
+        name: &[u8; SYMBOL_MAX_SIZE],
+        curve: &Pubkey,
+    ) -> CurvyResult<()> {
+        let (link_key, _) =
+            Pubkey::find_program_address(&NameLink::seeds(owner, name), self.program_id);
+        verify_key(name_link.key, &link_key, "name_link").account(name_link.key)?;
+
+        if name_link.owner != self.program_id {
+            return Ok(());
+        }
+
+        {
+            let link_data = name_link.data.borrow();
+            let link = NameLink::try_from_bytes(&link_data).account(name_link.key)?;
+            if link.curve != *curve {
+                return Ok(());
+            }
+        }
+
+        name_link.data.borrow_mut().fill(0);
+        transfer_lamports(name_link, receiver, name_link.lamports())
+    }
+
     pub fn process_instruction(self, input: &[u8]) -> CurvyResult<()> {
+        // params of CreateCurve and AlterCurve are read in place, see [CurveParamsRaw]
+        match input.split_first() {
+            Some((&CREATE_CURVE_TAG, params)) => {
+                return self.create_curve(CurveParamsRaw::from_bytes(params)?)
+            }
+            Some((&ALTER_CURVE_TAG, params)) => {
+                return self.alter_curve(CurveParamsRaw::from_bytes(params)?)
+            }
+            #[cfg(feature = "devnet-utils")]
+            Some((&DEVNET_TAG, input)) => {
+                return crate::devnet::process(self.program_id, self.accounts, input)
+            }
+            _ => {}
+        }
+
         match CurvyInstruction::try_from_slice(input).map_err(CurvyError::from)? {
-            CurvyInstruction::CreateCurve { params } => self.create_curve(params),
-            CurvyInstruction::AlterCurve { params } => self.alter_curve(params),
+            CurvyInstruction::CreateCurve { params } => {
+                self.create_curve(&CurveParamsRaw::from(&params))
+            }
+            CurvyInstruction::AlterCurve { params } => {
+                self.alter_curve(&CurveParamsRaw::from(&params))
+            }
             CurvyInstruction::DeleteCurve => self.delete_curve(),
+            CurvyInstruction::UpdateCurveMetadata { name, formula } => {
+                self.update_curve_metadata(name, formula)
+            }
+            CurvyInstruction::UpdateCurveData {
+                x0,
+                x_step,
+                y_count,
+                decimals,
+                y,
+            } => self.update_curve_data(x0, x_step, y_count, decimals, y),
+            CurvyInstruction::ReserveCurve { name, formula } => self.reserve_curve(name, formula),
+            CurvyInstruction::TopUpRent => self.top_up_rent(),
+            CurvyInstruction::SetCurveAsset { kind } => self.set_curve_asset(kind),
+            CurvyInstruction::ClearCurveAsset => self.clear_curve_asset(),
+            CurvyInstruction::MigrateCurve => self.migrate_curve(),
+            CurvyInstruction::SetCurveSeries { series, y } => self.set_curve_series(series, y),
+            CurvyInstruction::DeleteCurveSeries => self.delete_curve_series(),
+            CurvyInstruction::SetCurveConfidence { confidence } => {
+                self.set_curve_confidence(confidence)
+            }
+            CurvyInstruction::DeleteCurveConfidence => self.delete_curve_confidence(),
+            CurvyInstruction::CreateWideCurve { params } => self.create_wide_curve(params),
+            CurvyInstruction::AlterWideCurve { params } => self.alter_wide_curve(params),
+            CurvyInstruction::SetCurveXSign { x0_negative } => self.set_curve_x_sign(x0_negative),
+            CurvyInstruction::SetCurveYSign { y_signed } => self.set_curve_y_sign(y_signed),
+            CurvyInstruction::SetCurveYTransform { y_transform } => {
+                self.set_curve_y_transform(y_transform)
+            }
+            CurvyInstruction::SetCurveExpiry {
+                valid_until,
+                validity_period,
+            } => self.set_curve_expiry(valid_until, validity_period),
+            CurvyInstruction::ProposeOwner { new_owner } => self.propose_owner(new_owner),
+            CurvyInstruction::AcceptOwner => self.accept_owner(),
+            CurvyInstruction::CreateCurvePda { params } => {
+                self.create_curve_pda(&CurveParamsRaw::from(&params))
+            }
+            CurvyInstruction::FreezeCurve => self.freeze_curve(),
+            CurvyInstruction::ResizeCurve { y_capacity } => self.resize_curve(y_capacity),
+            CurvyInstruction::SetCurveTail { start, y } => self.set_curve_tail(start, &y),
+            CurvyInstruction::CreateGridCurve { params } => self.create_grid_curve(params),
+            CurvyInstruction::AlterGridCurve { params } => self.alter_grid_curve(params),
+            CurvyInstruction::SetCurveStatus { status } => self.set_curve_status(status),
+            CurvyInstruction::PatchY {
+                start_index,
+                values,
+            } => self.patch_y(start_index, &values),
+            CurvyInstruction::MarkForDeletion => self.mark_for_deletion(),
+            CurvyInstruction::CancelDeletion => self.cancel_deletion(),
+            CurvyInstruction::EvaluateCurve { x } => self.evaluate_curve(x),
+            CurvyInstruction::SetAlterDelay { slots } => self.set_alter_delay(slots),
+            CurvyInstruction::ProposeAlter { params } => {
+                self.propose_alter(&CurveParamsRaw::from(&params))
+            }
+            CurvyInstruction::ApplyAlter => self.apply_alter(),
+            CurvyInstruction::CancelAlter => self.cancel_alter(),
+            CurvyInstruction::AddDelegate { delegate } => self.add_delegate(delegate),
+            CurvyInstruction::RemoveDelegate { delegate } => self.remove_delegate(&delegate),
+            CurvyInstruction::InitConfig { creators } => self.init_config(&creators),
+            CurvyInstruction::SetCurveOwners { threshold, owners } => {
+                self.set_curve_owners(threshold, &owners)
+            }
+            CurvyInstruction::SetConfig {
+                new_admin,
+                creators,
+            } => self.set_config(new_admin, &creators),
+            CurvyInstruction::SetCurveExtension {
+                extension_type,
+                value,
+            } => self.set_curve_extension(extension_type, &value),
+            CurvyInstruction::BatchAlter { params } => self.batch_alter(&params),
+            CurvyInstruction::CreateReferenceCurve { kind, params } => {
+                self.create_reference_curve(kind, &CurveParamsRaw::from(&params))
+            }
+            CurvyInstruction::SetCreationFrozen { frozen } => self.set_creation_frozen(frozen),
         }
     }
 
     #[inline(never)]
-    pub(super) fn create_curve(self, params: CurveParams) -> CurvyResult<()> {
+    pub(super) fn create_curve(self, params: &CurveParamsRaw) -> CurvyResult<()> {
         msg!("create_curve ix");
 
         let CreateCurveAccounts {
             curve,
             owner,
+            config,
+            name_link,
+            system_program,
+        } = self.parse_accounts(CreateCurveAccounts::from_iter)?;
+
+        self.check_creator(config, owner.key)?;
+
+        let rent = Rent::get().expect("No Rent");
+
+        SystemProgram::new(system_program)
+            .create_account(
+                owner,
+                curve,
+                Curve::SIZE as u64,
+                rent.minimum_balance(Curve::SIZE),
+                self.program_id,
+            )
+            .call()
+            .account(curve.key)?;
+
+        init_curve(curve, owner.key, params)?;
+
+        self.register_name(
+            name_link,
+            owner.key,
+            owner,
+            system_program,
+            &params.name,
+            curve.key,
+        )
+    }
+
+    #[inline(never)]
+    fn create_curve_pda(self, params: &CurveParamsRaw) -> CurvyResult<()> {
+        msg!("create_curve_pda ix");
+
+        let CreateCurvePdaAccounts {
+            curve,
+            owner,
+            config,
+            name_link,
+            system_program,
+        } = self.parse_accounts(CreateCurvePdaAccounts::from_iter)?;
+
+        self.check_creator(config, owner.key)?;
+
+        let seeds = Curve::seeds(owner.key, &params.name);
+        let (curve_key, bump) = Pubkey::find_program_address(&seeds, self.program_id);
+        verify_key(curve.key, &curve_key, "curve").account(curve.key)?;
+
+        let rent = Rent::get().expect("No Rent");
+        let bump = [bump];
+
+        SystemProgram::new(system_program)
+            .create_account(
+                owner,
+                curve,
+                Curve::SIZE as u64,
+                rent.minimum_balance(Curve::SIZE),
+                self.program_id,
+            )
+            .call_signed(&[&[seeds[0], seeds[1], seeds[2], &bump]])
+            .account(curve.key)?;
+
+        init_curve(curve, owner.key, params)?;
+
+        self.register_name(
+            name_link,
+            owner.key,
+            owner,
+            system_program,
+            &params.name,
+            curve.key,
+        )
+    }
+
+    #[inline(never)]
+    fn create_reference_curve(self, kind: u8, params: &CurveParamsRaw) -> CurvyResult<()> {
+        msg!("create_reference_curve ix");
+
+        let CreateReferenceCurveAccounts {
+            curve,
+            admin,
+            config,
+            asset_mint,
+            name_link,
+            system_program,
+        } = self.parse_accounts(CreateReferenceCurveAccounts::from_iter)?;
+
+        let (config_key, _) = Pubkey::find_program_address(&Config::seeds(), self.program_id);
+        verify_key(config.key, &config_key, "config").account(config.key)?;
+        {
+            let config_data = config.data.borrow();
+            let unpacked_config = Config::try_from_bytes(&config_data).account(config.key)?;
+            verify_key(admin.key, &unpacked_config.admin, "admin").account(config.key)?;
+            unpacked_config
+                .check_creation_not_frozen()
+                .account(config.key)?;
+        }
+
+        let is_token = asset_mint.owner == &spl_token::ID || asset_mint.owner == &TOKEN_2022_ID;
+        if !is_token || asset_mint.data_len() < Mint::LEN {
+            msg!("asset_mint is not a token mint");
+            return Err(InvalidAccount(*asset_mint.key).into());
+        }
+
+        let kind_seed = [kind];
+        let seeds = Curve::reference_seeds(&kind_seed, asset_mint.key);
+        let (curve_key, bump) = Pubkey::find_program_address(&seeds, self.program_id);
+        verify_key(curve.key, &curve_key, "curve").account(curve.key)?;
+
+        let rent = Rent::get().expect("No Rent");
+        let bump = [bump];
+
+        SystemProgram::new(system_program)
+            .create_account(
+                admin,
+                curve,
+                Curve::SIZE as u64,
+                rent.minimum_balance(Curve::SIZE),
+                self.program_id,
+            )
+            .call_signed(&[&[seeds[0], seeds[1], seeds[2], &bump]])
+            .account(curve.key)?;
+
+        // owned by the config address, so the admin at the time manages it, see
+        // [Processor::check_curve_owner]
+        init_curve(curve, config.key, params)?;
+
+        self.register_name(
+            name_link,
+            config.key,
+            admin,
+            system_program,
+            &params.name,
+            curve.key,
+        )
+    }
+
+    #[inline(never)]
+    fn reserve_curve(
+        self,
+        name: [u8; SYMBOL_MAX_SIZE],
+        formula: [u8; SYMBOL_MAX_SIZE],
+    ) -> CurvyResult<()> {
+        msg!("reserve_curve ix");
+
+        let ReserveCurveAccounts {
+            curve,
+            owner,
+            config,
+            name_link,
             system_program,
-        } = CreateCurveAccounts::from_iter(&mut self.accounts.iter(), self.program_id)?;
+        } = self.parse_accounts(ReserveCurveAccounts::from_iter)?;
+
+        self.check_creator(config, owner.key)?;
 
         let rent = Rent::get().expect("No Rent");
 
@@ -58,31 +558,223 @@ impl<'a, 'b> Processor<'a, 'b> {
                 rent.minimum_balance(Curve::SIZE),
                 self.program_id,
             )
-            .call()?;
+            .call()
+            .account(curve.key)?;
+
+        let mut curve_data = curve.data.borrow_mut();
+
+        // account is just created by System program, so it is zeroed
+        let curve_account: &mut Curve = bytemuck::try_from_bytes_mut(&mut curve_data)
+            .map_err(|err| CurvyError::Serialize(err.into()))
+            .account(curve.key)?;
+        curve_account.init_reserved(name, formula, *owner.key);
+
+        CurveEvent::Created(CurveCreated {
+            curve: *curve.key,
+            owner: *owner.key,
+            name,
+            y_hash: curve_account.y_hash(),
+        })
+        .emit();
+
+        self.register_name(
+            name_link,
+            owner.key,
+            owner,
+            system_program,
+            &name,
+            curve.key,
+        )
+    }
+
+    #[inline(never)]
+    fn alter_curve(&self, params: &CurveParamsRaw) -> Result<(), CurvyError> {
+        msg!("alter_curve ix");
 
-        Curve::check_params(&params)?;
+        let (AlterCurveAccounts { curve, owner }, rest) =
+            self.parse_accounts_with_rest(AlterCurveAccounts::from_iter)?;
 
+        let curve_key = curve.key;
         let mut curve_data = curve.data.borrow_mut();
+        let curve = Curve::load_mut(&mut curve_data).account(curve_key)?;
+
+        match (curve.owner_threshold, rest) {
+            (0, rest) if self.is_reference_curve(curve) => {
+                let rest = self.check_config_admin(curve_key, owner.key, rest)?;
+                check_no_accounts_left(&mut rest.iter())?;
+            }
+            (0, rest) => {
+                check_no_accounts_left(&mut rest.iter())?;
+                curve
+                    .check_updater_params(owner.key, &params.into())
+                    .account(curve_key)?;
+            }
+            (threshold, [curve_owners, co_signers @ ..]) => {
+                self.check_co_owners(curve_key, curve_owners, owner, co_signers, threshold)?;
+            }
+            (threshold, []) => {
+                msg!("curve_owners account is missing");
+                return Err(CurvyError::CoOwnersRequired { threshold }).account(curve_key);
+            }
+        }
+        curve.check_not_frozen().account(curve_key)?;
+        curve.check_not_timelocked().account(curve_key)?;
+
+        params.check().account(curve_key)?;
+        check_name_kept(&curve.name, &params.name).account(curve_key)?;
+        let old_y_hash = curve.y_hash();
+        curve.set_params_raw(params);
+        curve.check_total_dimensions().account(curve_key)?;
 
-        Curve::init_bytes(&mut curve_data, (params, *owner.key))?;
+        record_alteration(curve);
+        emit_altered(curve_key, curve, owner.key, old_y_hash);
 
         Ok(())
     }
 
     #[inline(never)]
-    fn alter_curve(&self, params: CurveParams) -> Result<(), CurvyError> {
-        msg!("alter_curve ix");
+    fn batch_alter(&self, params: &[CompactCurveParams]) -> CurvyResult<()> {
+        msg!("batch_alter ix");
+
+        let (BatchAlterAccounts { owner }, curves) =
+            self.parse_accounts_with_rest(BatchAlterAccounts::from_iter)?;
+
+        if params.is_empty() || curves.len() != params.len() {
+            msg!("{} curves for {} params", curves.len(), params.len());
+            return Err(CurvyError::InvalidParams);
+        }
+
+        // any failure reverts the alterations made so far
+        for (idx, (curve, params)) in curves.iter().zip(params).enumerate() {
+            if curves[..idx].iter().any(|other| other.key == curve.key) {
+                msg!("curve {} is listed more than once", curve.key);
+                return Err(InvalidAccount(*curve.key).into());
+            }
+            if curve.owner != self.program_id || !curve.is_writable {
+                msg!(
+                    "curve {} is not a writable account of the program",
+                    curve.key
+                );
+                return Err(InvalidAccount(*curve.key).into());
+            }
+
+            let params = CurveParamsRaw::from(&params.to_params().account(curve.key)?);
+            let mut curve_data = curve.data.borrow_mut();
+            let unpacked_curve = Curve::load_mut(&mut curve_data).account(curve.key)?;
+
+            // co-owned curves are altered by AlterCurve only
+            unpacked_curve
+                .check_updater_params(owner.key, &(&params).into())
+                .account(curve.key)?;
+            unpacked_curve.check_not_frozen().account(curve.key)?;
+            unpacked_curve.check_not_timelocked().account(curve.key)?;
+
+            params.check().account(curve.key)?;
+            check_name_kept(&unpacked_curve.name, &params.name).account(curve.key)?;
+            let old_y_hash = unpacked_curve.y_hash();
+            unpacked_curve.set_params_raw(&params);
+            unpacked_curve.check_total_dimensions().account(curve.key)?;
+
+            record_alteration(unpacked_curve);
+            emit_altered(curve.key, unpacked_curve, owner.key, old_y_hash);
+        }
+
+        Ok(())
+    }
+
+    #[inline(never)]
+    fn update_curve_metadata(
+        &self,
+        name: [u8; SYMBOL_MAX_SIZE],
+        formula: [u8; SYMBOL_MAX_SIZE],
+    ) -> Result<(), CurvyError> {
+        msg!("update_curve_metadata ix");
+
+        let UpdateCurveMetadataAccounts {
+            curve,
+            owner,
+            name_link,
+            new_name_link,
+            system_program,
+        } = self.parse_accounts(UpdateCurveMetadataAccounts::from_iter)?;
+
+        let curve_key = curve.key;
+        let mut curve_data = curve.data.borrow_mut();
+        let curve = Curve::load_mut(&mut curve_data).account(curve_key)?;
+
+        curve.check_owner(owner.key).account(curve_key)?;
+        curve.check_not_frozen().account(curve_key)?;
+
+        let old_name = curve.name;
+        curve.set_metadata(name, formula);
+
+        if curve.name != old_name {
+            self.unregister_name(name_link, owner.key, owner, &old_name, curve_key)?;
+            self.register_name(
+                new_name_link,
+                owner.key,
+                owner,
+                system_program,
+                &curve.name,
+                curve_key,
+            )?;
+        }
+
+        Ok(())
+    }
+
+    #[inline(never)]
+    fn update_curve_data(
+        &self,
+        x0: CurveX,
+        x_step: CurveX,
+        y_count: u8,
+        decimals: u8,
+        y: [CurveY; MAX_Y_CNT],
+    ) -> Result<(), CurvyError> {
+        msg!("update_curve_data ix");
 
-        let AlterCurveAccounts { curve, owner } =
-            AlterCurveAccounts::from_iter(&mut self.accounts.iter(), self.program_id)?;
+        let UpdateCurveDataAccounts { curve, owner } =
+            self.parse_accounts(UpdateCurveDataAccounts::from_iter)?;
 
+        let curve_key = curve.key;
         let mut curve_data = curve.data.borrow_mut();
-        let curve = Curve::try_from_bytes_mut(&mut curve_data)?;
+        let curve = Curve::load_mut(&mut curve_data).account(curve_key)?;
+
+        let params = CurveParams {
+            x0,
+            x_step,
+            y_count,
+            // same decimals on both axes, see [CurvyInstruction::UpdateCurveData]
+            x_decimals: decimals,
+            y_decimals: decimals,
+            y,
+            ..CurveParams::from(&*curve)
+        };
+
+        curve
+            .check_updater_params(owner.key, &params)
+            .account(curve_key)?;
+        curve.check_not_frozen().account(curve_key)?;
+        curve.check_not_timelocked().account(curve_key)?;
 
-        verify_key(owner.key, &curve.owner, "owner")?;
+        // the common decimals would overwrite the other axis
+        if curve.x_decimals != curve.y_decimals {
+            msg!(
+                "curve has x decimals {} and y decimals {}, use AlterCurve to update its data",
+                curve.x_decimals,
+                curve.y_decimals
+            );
+            return Err(CurvyError::OperationCanNotBePerformed).account(curve_key);
+        }
 
-        Curve::check_params(&params)?;
+        Curve::check_params(&params).account(curve_key)?;
+        let old_y_hash = curve.y_hash();
         curve.set_params(params);
+        curve.check_total_dimensions().account(curve_key)?;
+
+        record_alteration(curve);
+        emit_altered(curve_key, curve, owner.key, old_y_hash);
 
         Ok(())
     }
@@ -90,23 +782,1643 @@ impl<'a, 'b> Processor<'a, 'b> {
     #[inline(never)]
     fn delete_curve(&self) -> Result<(), CurvyError> {
         msg!("delete_curve ix");
-        let DeleteCurveAccounts { curve, owner } =
-            DeleteCurveAccounts::from_iter(&mut self.accounts.iter(), self.program_id)?;
+        let (
+            DeleteCurveAccounts {
+                curve,
+                owner,
+                name_link,
+            },
+            rest,
+        ) = self.parse_accounts_with_rest(DeleteCurveAccounts::from_iter)?;
+
+        let kind = probe(&curve.data.borrow());
+        match kind {
+            Some(AccountKind::WideCurve { .. }) => {
+                let rent_destination = rent_destination(curve, owner, name_link, rest)?;
+                return self.delete_wide_curve(curve, owner, name_link, rent_destination);
+            }
+            Some(AccountKind::GridCurve { .. }) => {
+                let rent_destination = rent_destination(curve, owner, name_link, rest)?;
+                return self.delete_grid_curve(curve, owner, name_link, rent_destination);
+            }
+            _ => {}
+        }
 
         let mut curve_data = curve.data.borrow_mut();
-        let unpacked_curve = Curve::try_from_bytes_mut(&mut curve_data)?;
+        let unpacked_curve = Curve::load_mut(&mut curve_data).account(curve.key)?;
+
+        let rest = self.check_curve_owner(curve.key, unpacked_curve, owner.key, rest)?;
+        let rent_destination = rent_destination(curve, owner, name_link, rest)?;
+        unpacked_curve.check_not_frozen().account(curve.key)?;
+
+        if unpacked_curve.asset().is_some() {
+            msg!("curve is associated with asset, clear the association first");
+            return Err(CurvyError::OperationCanNotBePerformed).account(curve.key);
+        }
+
+        if unpacked_curve.series_count > 0 {
+            msg!("curve has additional series, delete them first");
+            return Err(CurvyError::OperationCanNotBePerformed).account(curve.key);
+        }
+
+        if unpacked_curve.has_confidence != 0 {
+            msg!("curve has confidence, delete it first");
+            return Err(CurvyError::OperationCanNotBePerformed).account(curve.key);
+        }
+
+        // CreateCurvePda curves are re-created at the same address, the proposal would stay
+        if unpacked_curve.has_pending_alter != 0 {
+            msg!("curve has a pending alteration, cancel it first");
+            return Err(CurvyError::OperationCanNotBePerformed).account(curve.key);
+        }
+
+        unpacked_curve
+            .status()
+            .check_deletable()
+            .account(curve.key)?;
+
+        let clock = Clock::get().expect("No Clock");
+        unpacked_curve
+            .check_deletion_grace(clock.slot)
+            .account(curve.key)?;
+
+        self.unregister_name(
+            name_link,
+            &unpacked_curve.owner,
+            owner,
+            &unpacked_curve.name,
+            curve.key,
+        )?;
 
-        verify_key(owner.key, &unpacked_curve.owner, "owner")?;
+        CurveEvent::Deleted(CurveDeleted {
+            curve: *curve.key,
+            owner: *owner.key,
+            name: unpacked_curve.name,
+            y_hash: unpacked_curve.y_hash(),
+        })
+        .emit();
 
         let balance = {
             let lamports_data = curve.lamports.borrow();
             **lamports_data
         };
 
-        transfer_lamports(curve, owner, balance)?;
+        transfer_lamports(curve, rent_destination, balance)?;
+
+        Ok(())
+    }
+
+    /// DeleteCurve of WideCurve account, it has no associated accounts besides the name link
+    fn delete_wide_curve(
+        &self,
+        curve: &AccountInfo<'b>,
+        owner: &AccountInfo<'b>,
+        name_link: &AccountInfo<'b>,
+        rent_destination: &AccountInfo<'b>,
+    ) -> CurvyResult<()> {
+        {
+            let curve_data = curve.data.borrow();
+            let unpacked_curve = WideCurve::try_from_bytes(&curve_data).account(curve.key)?;
+
+            verify_key(owner.key, &unpacked_curve.owner, "owner").account(curve.key)?;
+            unpacked_curve.check_not_frozen().account(curve.key)?;
+            unpacked_curve
+                .status()
+                .check_deletable()
+                .account(curve.key)?;
+
+            let clock = Clock::get().expect("No Clock");
+            unpacked_curve
+                .check_deletion_grace(clock.slot)
+                .account(curve.key)?;
+
+            self.unregister_name(name_link, owner.key, owner, &unpacked_curve.name, curve.key)?;
+
+            CurveEvent::Deleted(CurveDeleted {
+                curve: *curve.key,
+                owner: *owner.key,
+                name: unpacked_curve.name,
+                y_hash: unpacked_curve.y_hash(),
+            })
+            .emit();
+        }
+
+        let balance = curve.lamports();
+        transfer_lamports(curve, rent_destination, balance)?;
+
+        Ok(())
+    }
+
+    /// DeleteCurve of GridCurve account, it has no associated accounts besides the name link
+    fn delete_grid_curve(
+        &self,
+        curve: &AccountInfo<'b>,
+        owner: &AccountInfo<'b>,
+        name_link: &AccountInfo<'b>,
+        rent_destination: &AccountInfo<'b>,
+    ) -> CurvyResult<()> {
+        {
+            let curve_data = curve.data.borrow();
+            let unpacked_curve = GridCurve::try_from_bytes(&curve_data).account(curve.key)?;
+
+            verify_key(owner.key, &unpacked_curve.owner, "owner").account(curve.key)?;
+            unpacked_curve.check_not_frozen().account(curve.key)?;
+            unpacked_curve
+                .status()
+                .check_deletable()
+                .account(curve.key)?;
+
+            let clock = Clock::get().expect("No Clock");
+            unpacked_curve
+                .check_deletion_grace(clock.slot)
+                .account(curve.key)?;
+
+            self.unregister_name(name_link, owner.key, owner, &unpacked_curve.name, curve.key)?;
+
+            CurveEvent::Deleted(CurveDeleted {
+                curve: *curve.key,
+                owner: *owner.key,
+                name: unpacked_curve.name,
+                y_hash: unpacked_curve.y_hash(),
+            })
+            .emit();
+        }
+
+        let balance = curve.lamports();
+        transfer_lamports(curve, rent_destination, balance)?;
+
+        Ok(())
+    }
+
+    #[inline(never)]
+    fn top_up_rent(&self) -> CurvyResult<()> {
+        msg!("top_up_rent ix");
+        let TopUpRentAccounts {
+            curve,
+            payer,
+            system_program,
+        } = self.parse_accounts(TopUpRentAccounts::from_iter)?;
+
+        top_up_rent(curve, payer, system_program)
+    }
+
+    #[inline(never)]
+    fn set_curve_asset(&self, kind: u8) -> CurvyResult<()> {
+        msg!("set_curve_asset ix");
+        let SetCurveAssetAccounts {
+            curve,
+            owner,
+            asset_mint,
+            asset_link,
+            system_program,
+        } = self.parse_accounts(SetCurveAssetAccounts::from_iter)?;
+
+        let is_token = asset_mint.owner == &spl_token::ID || asset_mint.owner == &TOKEN_2022_ID;
+        if !is_token || asset_mint.data_len() < Mint::LEN {
+            msg!("asset_mint is not a token mint");
+            return Err(InvalidAccount(*asset_mint.key).into());
+        }
+
+        let mut curve_data = curve.data.borrow_mut();
+        let unpacked_curve = Curve::load_mut(&mut curve_data).account(curve.key)?;
+
+        unpacked_curve.check_owner(owner.key).account(curve.key)?;
+        unpacked_curve.check_not_frozen().account(curve.key)?;
+
+        if unpacked_curve.asset().is_some() {
+            msg!("curve is associated with asset already, clear the association first");
+            return Err(CurvyError::OperationCanNotBePerformed).account(curve.key);
+        }
+
+        let kind_seed = [kind];
+        let seeds = AssetLink::seeds(owner.key, asset_mint.key, &kind_seed);
+        let (link_key, bump) = Pubkey::find_program_address(&seeds, self.program_id);
+        verify_key(asset_link.key, &link_key, "asset_link").account(asset_link.key)?;
+
+        let rent = Rent::get().expect("No Rent");
+        let bump = [bump];
+
+        SystemProgram::new(system_program)
+            .create_account(
+                owner,
+                asset_link,
+                AssetLink::SIZE as u64,
+                rent.minimum_balance(AssetLink::SIZE),
+                self.program_id,
+            )
+            .call_signed(&[&[seeds[0], seeds[1], seeds[2], seeds[3], &bump]])
+            .account(asset_link.key)?;
+
+        AssetLink::init_bytes(
+            &mut asset_link.data.borrow_mut(),
+            (*owner.key, *asset_mint.key, kind, *curve.key),
+        )
+        .account(asset_link.key)?;
+
+        unpacked_curve.set_asset(Some((*asset_mint.key, kind)));
 
         Ok(())
     }
+
+    #[inline(never)]
+    fn clear_curve_asset(&self) -> CurvyResult<()> {
+        msg!("clear_curve_asset ix");
+        let ClearCurveAssetAccounts {
+            curve,
+            owner,
+            asset_link,
+        } = self.parse_accounts(ClearCurveAssetAccounts::from_iter)?;
+
+        let mut curve_data = curve.data.borrow_mut();
+        let unpacked_curve = Curve::load_mut(&mut curve_data).account(curve.key)?;
+
+        unpacked_curve.check_owner(owner.key).account(curve.key)?;
+
+        let Some((asset_mint, kind)) = unpacked_curve.asset() else {
+            msg!("curve is not associated with asset");
+            return Err(CurvyError::OperationCanNotBePerformed).account(curve.key);
+        };
+
+        {
+            let link_data = asset_link.data.borrow();
+            let link = AssetLink::try_from_bytes(&link_data).account(asset_link.key)?;
+
+            if link.curve != *curve.key || link.asset_mint != asset_mint || link.kind != kind {
+                msg!("asset_link does not belong to the curve");
+                return Err(InvalidAccount(*asset_link.key).into());
+            }
+        }
+
+        asset_link.data.borrow_mut().fill(0);
+        transfer_lamports(asset_link, owner, asset_link.lamports())?;
+
+        unpacked_curve.set_asset(None);
+
+        Ok(())
+    }
+
+    #[inline(never)]
+    fn migrate_curve(&self) -> CurvyResult<()> {
+        msg!("migrate_curve ix");
+        let MigrateCurveAccounts {
+            curve,
+            payer,
+            system_program,
+        } = self.parse_accounts(MigrateCurveAccounts::from_iter)?;
+
+        if curve.data_len() < Curve::SIZE {
+            curve
+                .realloc(Curve::SIZE, true)
+                .map_err(|_| CurvyError::InvalidRealloc)
+                .account(curve.key)?;
+        }
+
+        Curve::upgrade(&mut curve.data.borrow_mut()).account(curve.key)?;
+
+        top_up_rent(curve, payer, system_program)
+    }
+
+    #[inline(never)]
+    fn set_curve_series(&self, series: u8, y: [CurveY; MAX_Y_CNT]) -> CurvyResult<()> {
+        msg!("set_curve_series ix");
+        let SetCurveSeriesAccounts {
+            curve,
+            owner,
+            curve_series,
+            system_program,
+        } = self.parse_accounts(SetCurveSeriesAccounts::from_iter)?;
+
+        let mut curve_data = curve.data.borrow_mut();
+        let unpacked_curve = Curve::load_mut(&mut curve_data).account(curve.key)?;
+
+        unpacked_curve.check_owner(owner.key).account(curve.key)?;
+        unpacked_curve.check_not_frozen().account(curve.key)?;
+        unpacked_curve.check_not_timelocked().account(curve.key)?;
+
+        let seeds = CurveSeries::seeds(curve.key);
+        let (series_key, bump) = Pubkey::find_program_address(&seeds, self.program_id);
+        verify_key(curve_series.key, &series_key, "curve_series").account(curve_series.key)?;
+
+        if curve_series.owner != self.program_id {
+            let rent = Rent::get().expect("No Rent");
+            let bump = [bump];
+
+            SystemProgram::new(system_program)
+                .create_account(
+                    owner,
+                    curve_series,
+                    CurveSeries::SIZE as u64,
+                    rent.minimum_balance(CurveSeries::SIZE),
+                    self.program_id,
+                )
+                .call_signed(&[&[seeds[0], seeds[1], &bump]])
+                .account(curve_series.key)?;
+
+            CurveSeries::init_bytes(&mut curve_series.data.borrow_mut(), *curve.key)
+                .account(curve_series.key)?;
+        }
+
+        let mut series_data = curve_series.data.borrow_mut();
+        let unpacked_series =
+            CurveSeries::try_from_bytes_mut(&mut series_data).account(curve_series.key)?;
+
+        if !unpacked_series.set_series(series, y) {
+            msg!(
+                "series {} can not be set, {} of max {} are set",
+                series,
+                unpacked_series.count,
+                MAX_EXTRA_SERIES
+            );
+            return Err(CurvyError::InvalidParams).account(curve_series.key);
+        }
+
+        unpacked_curve.series_count = unpacked_series.count;
+
+        Ok(())
+    }
+
+    #[inline(never)]
+    fn delete_curve_series(&self) -> CurvyResult<()> {
+        msg!("delete_curve_series ix");
+        let DeleteCurveSeriesAccounts {
+            curve,
+            owner,
+            curve_series,
+        } = self.parse_accounts(DeleteCurveSeriesAccounts::from_iter)?;
+
+        let mut curve_data = curve.data.borrow_mut();
+        let unpacked_curve = Curve::load_mut(&mut curve_data).account(curve.key)?;
+
+        unpacked_curve.check_owner(owner.key).account(curve.key)?;
+        unpacked_curve.check_not_frozen().account(curve.key)?;
+        unpacked_curve.check_not_timelocked().account(curve.key)?;
+
+        {
+            let series_data = curve_series.data.borrow();
+            let series = CurveSeries::try_from_bytes(&series_data).account(curve_series.key)?;
+
+            if series.curve != *curve.key {
+                msg!("curve_series does not belong to the curve");
+                return Err(InvalidAccount(*curve_series.key).into());
+            }
+        }
+
+        curve_series.data.borrow_mut().fill(0);
+        transfer_lamports(curve_series, owner, curve_series.lamports())?;
+
+        unpacked_curve.series_count = 0;
+
+        Ok(())
+    }
+
+    #[inline(never)]
+    fn create_wide_curve(&self, params: WideCurveParams) -> CurvyResult<()> {
+        msg!("create_wide_curve ix");
+
+        let CreateWideCurveAccounts {
+            curve,
+            owner,
+            config,
+            name_link,
+            system_program,
+        } = self.parse_accounts(CreateWideCurveAccounts::from_iter)?;
+
+        self.check_creator(config, owner.key)?;
+
+        params.check().account(curve.key)?;
+
+        let rent = Rent::get().expect("No Rent");
+
+        SystemProgram::new(system_program)
+            .create_account(
+                owner,
+                curve,
+                WideCurve::SIZE as u64,
+                rent.minimum_balance(WideCurve::SIZE),
+                self.program_id,
+            )
+            .call()
+            .account(curve.key)?;
+
+        WideCurve::init_bytes(&mut curve.data.borrow_mut(), (params, *owner.key))
+            .account(curve.key)?;
+
+        CurveEvent::Created(CurveCreated {
+            curve: *curve.key,
+            owner: *owner.key,
+            name: params.name,
+            y_hash: events::y_hash(&params.y[..params.y_count as usize]),
+        })
+        .emit();
+
+        self.register_name(
+            name_link,
+            owner.key,
+            owner,
+            system_program,
+            &params.name,
+            curve.key,
+        )
+    }
+
+    #[inline(never)]
+    fn alter_wide_curve(&self, params: WideCurveParams) -> CurvyResult<()> {
+        msg!("alter_wide_curve ix");
+
+        let AlterWideCurveAccounts { curve, owner } =
+            self.parse_accounts(AlterWideCurveAccounts::from_iter)?;
+
+        let mut curve_data = curve.data.borrow_mut();
+        let unpacked_curve = WideCurve::try_from_bytes_mut(&mut curve_data).account(curve.key)?;
+
+        verify_key(owner.key, &unpacked_curve.owner, "owner").account(curve.key)?;
+        unpacked_curve.check_not_frozen().account(curve.key)?;
+
+        params.check().account(curve.key)?;
+        check_name_kept(&unpacked_curve.name, &params.name).account(curve.key)?;
+        let old_y_hash = unpacked_curve.y_hash();
+        unpacked_curve.set_params(params);
+
+        CurveEvent::Altered(CurveAltered {
+            curve: *curve.key,
+            owner: unpacked_curve.owner,
+            authority: *owner.key,
+            name: unpacked_curve.name,
+            old_y_hash,
+            new_y_hash: unpacked_curve.y_hash(),
+        })
+        .emit();
+
+        Ok(())
+    }
+
+    #[inline(never)]
+    fn create_grid_curve(&self, params: GridCurveParams) -> CurvyResult<()> {
+        msg!("create_grid_curve ix");
+
+        let CreateGridCurveAccounts {
+            curve,
+            owner,
+            config,
+            name_link,
+            system_program,
+        } = self.parse_accounts(CreateGridCurveAccounts::from_iter)?;
+
+        self.check_creator(config, owner.key)?;
+
+        params.check().account(curve.key)?;
+
+        let rent = Rent::get().expect("No Rent");
+
+        SystemProgram::new(system_program)
+            .create_account(
+                owner,
+                curve,
+                GridCurve::SIZE as u64,
+                rent.minimum_balance(GridCurve::SIZE),
+                self.program_id,
+            )
+            .call()
+            .account(curve.key)?;
+
+        GridCurve::init_bytes(&mut curve.data.borrow_mut(), (params, *owner.key))
+            .account(curve.key)?;
+
+        CurveEvent::Created(CurveCreated {
+            curve: *curve.key,
+            owner: *owner.key,
+            name: params.name,
+            y_hash: events::y_hash(&params.y[..params.y_count as usize]),
+        })
+        .emit();
+
+        self.register_name(
+            name_link,
+            owner.key,
+            owner,
+            system_program,
+            &params.name,
+            curve.key,
+        )
+    }
+
+    #[inline(never)]
+    fn alter_grid_curve(&self, params: GridCurveParams) -> CurvyResult<()> {
+        msg!("alter_grid_curve ix");
+
+        let AlterGridCurveAccounts { curve, owner } =
+            self.parse_accounts(AlterGridCurveAccounts::from_iter)?;
+
+        let mut curve_data = curve.data.borrow_mut();
+        let unpacked_curve = GridCurve::try_from_bytes_mut(&mut curve_data).account(curve.key)?;
+
+        verify_key(owner.key, &unpacked_curve.owner, "owner").account(curve.key)?;
+        unpacked_curve.check_not_frozen().account(curve.key)?;
+
+        params.check().account(curve.key)?;
+        check_name_kept(&unpacked_curve.name, &params.name).account(curve.key)?;
+        let old_y_hash = unpacked_curve.y_hash();
+        unpacked_curve.set_params(params);
+
+        CurveEvent::Altered(CurveAltered {
+            curve: *curve.key,
+            owner: unpacked_curve.owner,
+            authority: *owner.key,
+            name: unpacked_curve.name,
+            old_y_hash,
+            new_y_hash: unpacked_curve.y_hash(),
+        })
+        .emit();
+
+        Ok(())
+    }
+
+    #[inline(never)]
+    fn set_curve_x_sign(&self, x0_negative: bool) -> CurvyResult<()> {
+        msg!("set_curve_x_sign ix");
+
+        let SetCurveXSignAccounts { curve, owner } =
+            self.parse_accounts(SetCurveXSignAccounts::from_iter)?;
+
+        let mut curve_data = curve.data.borrow_mut();
+        let unpacked_curve = Curve::load_mut(&mut curve_data).account(curve.key)?;
+
+        unpacked_curve.check_owner(owner.key).account(curve.key)?;
+        unpacked_curve.check_not_frozen().account(curve.key)?;
+        unpacked_curve.check_not_timelocked().account(curve.key)?;
+
+        if x0_negative && unpacked_curve.x0 == 0 {
+            msg!("x0 is zero and can not be negative");
+            return Err(CurvyError::InvalidParams).account(curve.key);
+        }
+
+        let old_y_hash = unpacked_curve.y_hash();
+        unpacked_curve.x0_negative = x0_negative as u8;
+
+        record_alteration(unpacked_curve);
+        emit_altered(curve.key, unpacked_curve, owner.key, old_y_hash);
+
+        Ok(())
+    }
+
+    #[inline(never)]
+    fn set_curve_y_sign(&self, y_signed: bool) -> CurvyResult<()> {
+        msg!("set_curve_y_sign ix");
+
+        let SetCurveYSignAccounts { curve, owner } =
+            self.parse_accounts(SetCurveYSignAccounts::from_iter)?;
+
+        let mut curve_data = curve.data.borrow_mut();
+        let unpacked_curve = Curve::load_mut(&mut curve_data).account(curve.key)?;
+
+        unpacked_curve.check_owner(owner.key).account(curve.key)?;
+        unpacked_curve.check_not_frozen().account(curve.key)?;
+        unpacked_curve.check_not_timelocked().account(curve.key)?;
+
+        let old_y_hash = unpacked_curve.y_hash();
+        unpacked_curve.y_signed = y_signed as u8;
+
+        record_alteration(unpacked_curve);
+        emit_altered(curve.key, unpacked_curve, owner.key, old_y_hash);
+
+        Ok(())
+    }
+
+    #[inline(never)]
+    fn set_curve_y_transform(&self, y_transform: u8) -> CurvyResult<()> {
+        msg!("set_curve_y_transform ix");
+
+        let SetCurveYTransformAccounts { curve, owner } =
+            self.parse_accounts(SetCurveYTransformAccounts::from_iter)?;
+
+        let mut curve_data = curve.data.borrow_mut();
+        let unpacked_curve = Curve::load_mut(&mut curve_data).account(curve.key)?;
+
+        unpacked_curve.check_owner(owner.key).account(curve.key)?;
+        unpacked_curve.check_not_frozen().account(curve.key)?;
+        unpacked_curve.check_not_timelocked().account(curve.key)?;
+
+        let y_transform = YTransform::try_from(y_transform).account(curve.key)?;
+        let old_y_hash = unpacked_curve.y_hash();
+        unpacked_curve.y_transform = y_transform as u8;
+
+        record_alteration(unpacked_curve);
+        emit_altered(curve.key, unpacked_curve, owner.key, old_y_hash);
+
+        Ok(())
+    }
+
+    #[inline(never)]
+    fn set_curve_expiry(&self, valid_until: i64, validity_period: u64) -> CurvyResult<()> {
+        msg!("set_curve_expiry ix");
+
+        let SetCurveExpiryAccounts { curve, owner } =
+            self.parse_accounts(SetCurveExpiryAccounts::from_iter)?;
+
+        let mut curve_data = curve.data.borrow_mut();
+        let unpacked_curve = Curve::load_mut(&mut curve_data).account(curve.key)?;
+
+        unpacked_curve.check_owner(owner.key).account(curve.key)?;
+        unpacked_curve.check_not_frozen().account(curve.key)?;
+
+        unpacked_curve
+            .set_expiry(valid_until, validity_period)
+            .account(curve.key)
+    }
+
+    #[inline(never)]
+    fn set_curve_status(&self, status: u8) -> CurvyResult<()> {
+        msg!("set_curve_status ix");
+
+        let (SetCurveStatusAccounts { curve, owner }, rest) =
+            self.parse_accounts_with_rest(SetCurveStatusAccounts::from_iter)?;
+
+        let kind = probe(&curve.data.borrow());
+        let mut curve_data = curve.data.borrow_mut();
+        match kind {
+            Some(AccountKind::WideCurve { .. }) => {
+                check_no_accounts_left(&mut rest.iter())?;
+                let unpacked_curve =
+                    WideCurve::try_from_bytes_mut(&mut curve_data).account(curve.key)?;
+                verify_key(owner.key, &unpacked_curve.owner, "owner").account(curve.key)?;
+                let to = unpacked_curve
+                    .status()
+                    .transition(status)
+                    .account(curve.key)?;
+                unpacked_curve.status = to as u8;
+                return Ok(());
+            }
+            Some(AccountKind::GridCurve { .. }) => {
+                check_no_accounts_left(&mut rest.iter())?;
+                let unpacked_curve =
+                    GridCurve::try_from_bytes_mut(&mut curve_data).account(curve.key)?;
+                verify_key(owner.key, &unpacked_curve.owner, "owner").account(curve.key)?;
+                let to = unpacked_curve
+                    .status()
+                    .transition(status)
+                    .account(curve.key)?;
+                unpacked_curve.status = to as u8;
+                return Ok(());
+            }
+            _ => {}
+        }
+
+        let unpacked_curve = Curve::load_mut(&mut curve_data).account(curve.key)?;
+
+        let rest = self.check_curve_owner(curve.key, unpacked_curve, owner.key, rest)?;
+        check_no_accounts_left(&mut rest.iter())?;
+
+        let to = unpacked_curve
+            .status()
+            .transition(status)
+            .account(curve.key)?;
+
+        if to == CurveStatus::Active && unpacked_curve.is_reserved() {
+            msg!("reserved curve has no samples, set its data first");
+            return Err(CurvyError::OperationCanNotBePerformed).account(curve.key);
+        }
+
+        unpacked_curve.status = status;
+
+        Ok(())
+    }
+
+    #[inline(never)]
+    fn propose_owner(&self, new_owner: Pubkey) -> CurvyResult<()> {
+        msg!("propose_owner ix");
+
+        let ProposeOwnerAccounts { curve, owner } =
+            self.parse_accounts(ProposeOwnerAccounts::from_iter)?;
+
+        let mut curve_data = curve.data.borrow_mut();
+        let unpacked_curve = Curve::load_mut(&mut curve_data).account(curve.key)?;
+
+        unpacked_curve.check_owner(owner.key).account(curve.key)?;
+
+        if unpacked_curve.asset().is_some() {
+            msg!("curve is associated with asset, clear the association first");
+            return Err(CurvyError::OperationCanNotBePerformed).account(curve.key);
+        }
+
+        unpacked_curve.pending_owner = new_owner;
+
+        Ok(())
+    }
+
+    #[inline(never)]
+    fn accept_owner(&self) -> CurvyResult<()> {
+        msg!("accept_owner ix");
+
+        let AcceptOwnerAccounts {
+            curve,
+            new_owner,
+            owner,
+            name_link,
+            new_name_link,
+            system_program,
+        } = self.parse_accounts(AcceptOwnerAccounts::from_iter)?;
+
+        let mut curve_data = curve.data.borrow_mut();
+        let unpacked_curve = Curve::load_mut(&mut curve_data).account(curve.key)?;
+
+        let Some(pending_owner) = unpacked_curve.pending_owner() else {
+            msg!("curve has no pending owner");
+            return Err(CurvyError::OperationCanNotBePerformed).account(curve.key);
+        };
+        verify_key(new_owner.key, &pending_owner, "pending_owner").account(curve.key)?;
+
+        // co-owners could be set after the proposal, they would stay owners of the new owner
+        unpacked_curve.check_single_owner().account(curve.key)?;
+
+        // asset could be set after the proposal
+        if unpacked_curve.asset().is_some() {
+            msg!("curve is associated with asset, clear the association first");
+            return Err(CurvyError::OperationCanNotBePerformed).account(curve.key);
+        }
+
+        verify_key(owner.key, &unpacked_curve.owner, "owner").account(curve.key)?;
+
+        unpacked_curve.owner = pending_owner;
+        unpacked_curve.pending_owner = Pubkey::default();
+        // hot keys of the previous owner
+        unpacked_curve.delegates = [Pubkey::default(); MAX_CURVE_DELEGATES];
+
+        if pending_owner != *owner.key {
+            let name = unpacked_curve.name;
+            self.unregister_name(name_link, owner.key, owner, &name, curve.key)?;
+            self.register_name(
+                new_name_link,
+                new_owner.key,
+                new_owner,
+                system_program,
+                &name,
+                curve.key,
+            )?;
+        }
+
+        Ok(())
+    }
+
+    #[inline(never)]
+    fn freeze_curve(&self) -> CurvyResult<()> {
+        msg!("freeze_curve ix");
+
+        let FreezeCurveAccounts { curve, owner } =
+            self.parse_accounts(FreezeCurveAccounts::from_iter)?;
+
+        let kind = probe(&curve.data.borrow());
+        let mut curve_data = curve.data.borrow_mut();
+        match kind {
+            Some(AccountKind::WideCurve { .. }) => {
+                let unpacked_curve =
+                    WideCurve::try_from_bytes_mut(&mut curve_data).account(curve.key)?;
+                verify_key(owner.key, &unpacked_curve.owner, "owner").account(curve.key)?;
+                unpacked_curve.is_frozen = 1;
+                return Ok(());
+            }
+            Some(AccountKind::GridCurve { .. }) => {
+                let unpacked_curve =
+                    GridCurve::try_from_bytes_mut(&mut curve_data).account(curve.key)?;
+                verify_key(owner.key, &unpacked_curve.owner, "owner").account(curve.key)?;
+                unpacked_curve.is_frozen = 1;
+                return Ok(());
+            }
+            _ => {}
+        }
+
+        let unpacked_curve = Curve::load_mut(&mut curve_data).account(curve.key)?;
+
+        unpacked_curve.check_owner(owner.key).account(curve.key)?;
+
+        if unpacked_curve.is_reserved() {
+            msg!("reserved curve has no samples to freeze");
+            return Err(CurvyError::OperationCanNotBePerformed).account(curve.key);
+        }
+
+        unpacked_curve.is_frozen = 1;
+
+        Ok(())
+    }
+
+    #[inline(never)]
+    fn resize_curve(&self, y_capacity: u16) -> CurvyResult<()> {
+        msg!("resize_curve ix");
+
+        let ResizeCurveAccounts {
+            curve,
+            owner,
+            system_program,
+        } = self.parse_accounts(ResizeCurveAccounts::from_iter)?;
+
+        let extensions = {
+            let curve_data = curve.data.borrow();
+            let unpacked_curve = Curve::load(&curve_data).account(curve.key)?;
+
+            unpacked_curve.check_owner(owner.key).account(curve.key)?;
+            unpacked_curve.check_not_frozen().account(curve.key)?;
+
+            unpacked_curve.extensions(&curve_data).to_vec()
+        };
+
+        if y_capacity as usize > MAX_TOTAL_Y_CNT {
+            msg!("curve can hold at most {} samples", MAX_TOTAL_Y_CNT);
+            return Err(CurvyError::InvalidParams).account(curve.key);
+        }
+
+        let old_size = curve.data_len();
+        let size = Curve::size_for(y_capacity as usize) + extensions.len();
+        if size < old_size {
+            msg!(
+                "curve holds {} samples already, it can not shrink",
+                Curve::y_capacity(old_size - extensions.len())
+            );
+            return Err(CurvyError::OperationCanNotBePerformed).account(curve.key);
+        }
+
+        curve
+            .realloc(size, true)
+            .map_err(|_| CurvyError::InvalidRealloc)
+            .account(curve.key)?;
+
+        // the extension region stays at the end of the account, the tail grows in its place
+        if !extensions.is_empty() {
+            let mut curve_data = curve.data.borrow_mut();
+            curve_data[old_size - extensions.len()..].fill(0);
+            curve_data[size - extensions.len()..].copy_from_slice(&extensions);
+        }
+
+        top_up_rent(curve, owner, system_program)
+    }
+
+    #[inline(never)]
+    fn set_curve_tail(&self, start: u16, y: &[CurveY]) -> CurvyResult<()> {
+        msg!("set_curve_tail ix");
+
+        let SetCurveTailAccounts { curve, owner } =
+            self.parse_accounts(SetCurveTailAccounts::from_iter)?;
+
+        let mut curve_data = curve.data.borrow_mut();
+        let (unpacked_curve, tail) =
+            Curve::load_mut_with_tail(&mut curve_data).account(curve.key)?;
+
+        unpacked_curve.check_owner(owner.key).account(curve.key)?;
+        unpacked_curve.check_not_frozen().account(curve.key)?;
+        unpacked_curve.check_not_timelocked().account(curve.key)?;
+
+        unpacked_curve
+            .set_tail_y(tail, start as usize, y)
+            .account(curve.key)
+    }
+
+    #[inline(never)]
+    fn mark_for_deletion(&self) -> CurvyResult<()> {
+        msg!("mark_for_deletion ix");
+
+        let MarkForDeletionAccounts { curve, owner } =
+            self.parse_accounts(MarkForDeletionAccounts::from_iter)?;
+
+        let clock = Clock::get().expect("No Clock");
+        let kind = probe(&curve.data.borrow());
+        let mut curve_data = curve.data.borrow_mut();
+        match kind {
+            Some(AccountKind::WideCurve { .. }) => {
+                let unpacked_curve =
+                    WideCurve::try_from_bytes_mut(&mut curve_data).account(curve.key)?;
+                verify_key(owner.key, &unpacked_curve.owner, "owner").account(curve.key)?;
+                unpacked_curve.check_not_frozen().account(curve.key)?;
+                return unpacked_curve
+                    .mark_for_deletion(clock.slot)
+                    .account(curve.key);
+            }
+            Some(AccountKind::GridCurve { .. }) => {
+                let unpacked_curve =
+                    GridCurve::try_from_bytes_mut(&mut curve_data).account(curve.key)?;
+                verify_key(owner.key, &unpacked_curve.owner, "owner").account(curve.key)?;
+                unpacked_curve.check_not_frozen().account(curve.key)?;
+                return unpacked_curve
+                    .mark_for_deletion(clock.slot)
+                    .account(curve.key);
+            }
+            _ => {}
+        }
+
+        let unpacked_curve = Curve::load_mut(&mut curve_data).account(curve.key)?;
+
+        unpacked_curve.check_owner(owner.key).account(curve.key)?;
+        unpacked_curve.check_not_frozen().account(curve.key)?;
+
+        unpacked_curve
+            .mark_for_deletion(clock.slot)
+            .account(curve.key)
+    }
+
+    #[inline(never)]
+    fn cancel_deletion(&self) -> CurvyResult<()> {
+        msg!("cancel_deletion ix");
+
+        let CancelDeletionAccounts { curve, owner } =
+            self.parse_accounts(CancelDeletionAccounts::from_iter)?;
+
+        let kind = probe(&curve.data.borrow());
+        let mut curve_data = curve.data.borrow_mut();
+        match kind {
+            Some(AccountKind::WideCurve { .. }) => {
+                let unpacked_curve =
+                    WideCurve::try_from_bytes_mut(&mut curve_data).account(curve.key)?;
+                verify_key(owner.key, &unpacked_curve.owner, "owner").account(curve.key)?;
+                return unpacked_curve.cancel_deletion().account(curve.key);
+            }
+            Some(AccountKind::GridCurve { .. }) => {
+                let unpacked_curve =
+                    GridCurve::try_from_bytes_mut(&mut curve_data).account(curve.key)?;
+                verify_key(owner.key, &unpacked_curve.owner, "owner").account(curve.key)?;
+                return unpacked_curve.cancel_deletion().account(curve.key);
+            }
+            _ => {}
+        }
+
+        let unpacked_curve = Curve::load_mut(&mut curve_data).account(curve.key)?;
+
+        unpacked_curve.check_owner(owner.key).account(curve.key)?;
+
+        unpacked_curve.cancel_deletion().account(curve.key)
+    }
+
+    #[inline(never)]
+    fn evaluate_curve(&self, x: i64) -> CurvyResult<()> {
+        msg!("evaluate_curve ix");
+
+        let EvaluateCurveAccounts {
+            curve,
+            result,
+            authority,
+        } = self.parse_accounts(EvaluateCurveAccounts::from_iter)?;
+
+        let curve_data = curve.data.borrow();
+        let unpacked_curve = Curve::load(&curve_data).account(curve.key)?;
+        unpacked_curve.check_consumable().account(curve.key)?;
+        unpacked_curve.check_linear_y().account(curve.key)?;
+
+        let clock = Clock::get().expect("No Clock");
+        unpacked_curve
+            .check_not_expired(clock.unix_timestamp)
+            .account(curve.key)?;
+
+        let y = unpacked_curve
+            .evaluate(&unpacked_curve.samples(&curve_data), x)
+            .account(curve.key)?;
+
+        let mut result_data = result.data.borrow_mut();
+        // zeroed account created by the caller
+        if probe(&result_data).is_none() {
+            CurveEvaluation::init_bytes(&mut result_data, *authority.key).account(result.key)?;
+        }
+        let evaluation =
+            CurveEvaluation::try_from_bytes_mut(&mut result_data).account(result.key)?;
+
+        verify_key(authority.key, &evaluation.authority, "authority").account(result.key)?;
+
+        evaluation.x_decimals = unpacked_curve.x_decimals;
+        evaluation.y_decimals = unpacked_curve.y_decimals;
+        evaluation.curve = *curve.key;
+        evaluation.x = x;
+        evaluation.y = y;
+        evaluation.slot = clock.slot;
+
+        Ok(())
+    }
+
+    #[inline(never)]
+    fn patch_y(&self, start_index: u8, values: &[CurveY]) -> CurvyResult<()> {
+        msg!("patch_y ix");
+
+        let PatchYAccounts { curve, owner } = self.parse_accounts(PatchYAccounts::from_iter)?;
+
+        let mut curve_data = curve.data.borrow_mut();
+        let unpacked_curve = Curve::load_mut(&mut curve_data).account(curve.key)?;
+
+        unpacked_curve.check_updater(owner.key).account(curve.key)?;
+        unpacked_curve.check_not_frozen().account(curve.key)?;
+        unpacked_curve.check_not_timelocked().account(curve.key)?;
+
+        let old_y_hash = unpacked_curve.y_hash();
+        unpacked_curve
+            .patch_y(start_index as usize, values)
+            .account(curve.key)?;
+
+        record_alteration(unpacked_curve);
+        emit_altered(curve.key, unpacked_curve, owner.key, old_y_hash);
+
+        Ok(())
+    }
+
+    #[inline(never)]
+    fn set_curve_confidence(&self, confidence: [CurveConfidenceBps; MAX_Y_CNT]) -> CurvyResult<()> {
+        msg!("set_curve_confidence ix");
+        let SetCurveConfidenceAccounts {
+            curve,
+            owner,
+            curve_confidence,
+            system_program,
+        } = self.parse_accounts(SetCurveConfidenceAccounts::from_iter)?;
+
+        let mut curve_data = curve.data.borrow_mut();
+        let unpacked_curve = Curve::load_mut(&mut curve_data).account(curve.key)?;
+
+        unpacked_curve.check_owner(owner.key).account(curve.key)?;
+        unpacked_curve.check_not_frozen().account(curve.key)?;
+        unpacked_curve.check_not_timelocked().account(curve.key)?;
+
+        let seeds = CurveConfidence::seeds(curve.key);
+        let (confidence_key, bump) = Pubkey::find_program_address(&seeds, self.program_id);
+        verify_key(curve_confidence.key, &confidence_key, "curve_confidence")
+            .account(curve_confidence.key)?;
+
+        if curve_confidence.owner != self.program_id {
+            let rent = Rent::get().expect("No Rent");
+            let bump = [bump];
+
+            SystemProgram::new(system_program)
+                .create_account(
+                    owner,
+                    curve_confidence,
+                    CurveConfidence::SIZE as u64,
+                    rent.minimum_balance(CurveConfidence::SIZE),
+                    self.program_id,
+                )
+                .call_signed(&[&[seeds[0], seeds[1], &bump]])
+                .account(curve_confidence.key)?;
+
+            CurveConfidence::init_bytes(&mut curve_confidence.data.borrow_mut(), *curve.key)
+                .account(curve_confidence.key)?;
+        }
+
+        let mut confidence_data = curve_confidence.data.borrow_mut();
+        let unpacked_confidence = CurveConfidence::try_from_bytes_mut(&mut confidence_data)
+            .account(curve_confidence.key)?;
+
+        unpacked_confidence.confidence = confidence;
+        unpacked_curve.has_confidence = 1;
+
+        Ok(())
+    }
+
+    #[inline(never)]
+    fn delete_curve_confidence(&self) -> CurvyResult<()> {
+        msg!("delete_curve_confidence ix");
+        let DeleteCurveConfidenceAccounts {
+            curve,
+            owner,
+            curve_confidence,
+        } = self.parse_accounts(DeleteCurveConfidenceAccounts::from_iter)?;
+
+        let mut curve_data = curve.data.borrow_mut();
+        let unpacked_curve = Curve::load_mut(&mut curve_data).account(curve.key)?;
+
+        unpacked_curve.check_owner(owner.key).account(curve.key)?;
+        unpacked_curve.check_not_frozen().account(curve.key)?;
+        unpacked_curve.check_not_timelocked().account(curve.key)?;
+
+        {
+            let confidence_data = curve_confidence.data.borrow();
+            let confidence =
+                CurveConfidence::try_from_bytes(&confidence_data).account(curve_confidence.key)?;
+
+            if confidence.curve != *curve.key {
+                msg!("curve_confidence does not belong to the curve");
+                return Err(InvalidAccount(*curve_confidence.key).into());
+            }
+        }
+
+        curve_confidence.data.borrow_mut().fill(0);
+        transfer_lamports(curve_confidence, owner, curve_confidence.lamports())?;
+
+        unpacked_curve.has_confidence = 0;
+
+        Ok(())
+    }
+
+    #[inline(never)]
+    fn set_alter_delay(&self, slots: u64) -> CurvyResult<()> {
+        msg!("set_alter_delay ix");
+
+        let SetAlterDelayAccounts { curve, owner } =
+            self.parse_accounts(SetAlterDelayAccounts::from_iter)?;
+
+        let mut curve_data = curve.data.borrow_mut();
+        let unpacked_curve = Curve::load_mut(&mut curve_data).account(curve.key)?;
+
+        unpacked_curve.check_owner(owner.key).account(curve.key)?;
+        unpacked_curve.check_not_frozen().account(curve.key)?;
+
+        unpacked_curve.set_alter_delay(slots).account(curve.key)
+    }
+
+    #[inline(never)]
+    fn propose_alter(&self, params: &CurveParamsRaw) -> CurvyResult<()> {
+        msg!("propose_alter ix");
+        let ProposeAlterAccounts {
+            curve,
+            owner,
+            pending_alter,
+            system_program,
+        } = self.parse_accounts(ProposeAlterAccounts::from_iter)?;
+
+        let mut curve_data = curve.data.borrow_mut();
+        let unpacked_curve = Curve::load_mut(&mut curve_data).account(curve.key)?;
+
+        unpacked_curve.check_owner(owner.key).account(curve.key)?;
+        unpacked_curve.check_not_frozen().account(curve.key)?;
+        params.check().account(curve.key)?;
+        check_name_kept(&unpacked_curve.name, &params.name).account(curve.key)?;
+
+        let seeds = PendingAlter::seeds(curve.key);
+        let (pending_key, bump) = Pubkey::find_program_address(&seeds, self.program_id);
+        verify_key(pending_alter.key, &pending_key, "pending_alter").account(pending_alter.key)?;
+
+        if pending_alter.owner != self.program_id {
+            let rent = Rent::get().expect("No Rent");
+            let bump = [bump];
+
+            SystemProgram::new(system_program)
+                .create_account(
+                    owner,
+                    pending_alter,
+                    PendingAlter::SIZE as u64,
+                    rent.minimum_balance(PendingAlter::SIZE),
+                    self.program_id,
+                )
+                .call_signed(&[&[seeds[0], seeds[1], &bump]])
+                .account(pending_alter.key)?;
+
+            PendingAlter::init_bytes(&mut pending_alter.data.borrow_mut(), *curve.key)
+                .account(pending_alter.key)?;
+        }
+
+        let mut pending_data = pending_alter.data.borrow_mut();
+        let unpacked_pending =
+            PendingAlter::try_from_bytes_mut(&mut pending_data).account(pending_alter.key)?;
+
+        let clock = Clock::get().expect("No Clock");
+        unpacked_pending.params = *params;
+        unpacked_pending.apply_slot = clock.slot.saturating_add(unpacked_curve.alter_delay());
+        unpacked_curve.has_pending_alter = 1;
+        msg!(
+            "alteration can be applied from slot {}",
+            unpacked_pending.apply_slot
+        );
+
+        Ok(())
+    }
+
+    #[inline(never)]
+    fn apply_alter(&self) -> CurvyResult<()> {
+        msg!("apply_alter ix");
+        let ApplyAlterAccounts {
+            curve,
+            owner,
+            pending_alter,
+        } = self.parse_accounts(ApplyAlterAccounts::from_iter)?;
+
+        let mut curve_data = curve.data.borrow_mut();
+        let unpacked_curve = Curve::load_mut(&mut curve_data).account(curve.key)?;
+
+        unpacked_curve.check_owner(owner.key).account(curve.key)?;
+        unpacked_curve.check_not_frozen().account(curve.key)?;
+
+        {
+            let pending_data = pending_alter.data.borrow();
+            let pending = pending_of(&pending_data, curve.key, pending_alter.key)?;
+
+            let clock = Clock::get().expect("No Clock");
+            pending.check_ready(clock.slot).account(pending_alter.key)?;
+
+            pending.params.check().account(curve.key)?;
+            // the curve may be renamed by UpdateCurveMetadata after the proposal
+            check_name_kept(&unpacked_curve.name, &pending.params.name).account(curve.key)?;
+            let old_y_hash = unpacked_curve.y_hash();
+            unpacked_curve.set_params_raw(&pending.params);
+            unpacked_curve.check_total_dimensions().account(curve.key)?;
+
+            record_alteration(unpacked_curve);
+            emit_altered(curve.key, unpacked_curve, owner.key, old_y_hash);
+            unpacked_curve.has_pending_alter = 0;
+        }
+
+        pending_alter.data.borrow_mut().fill(0);
+        transfer_lamports(pending_alter, owner, pending_alter.lamports())
+    }
+
+    #[inline(never)]
+    fn cancel_alter(&self) -> CurvyResult<()> {
+        msg!("cancel_alter ix");
+        let CancelAlterAccounts {
+            curve,
+            owner,
+            pending_alter,
+        } = self.parse_accounts(CancelAlterAccounts::from_iter)?;
+
+        let mut curve_data = curve.data.borrow_mut();
+        let unpacked_curve = Curve::load_mut(&mut curve_data).account(curve.key)?;
+
+        unpacked_curve.check_owner(owner.key).account(curve.key)?;
+        pending_of(&pending_alter.data.borrow(), curve.key, pending_alter.key)?;
+        unpacked_curve.has_pending_alter = 0;
+
+        pending_alter.data.borrow_mut().fill(0);
+        transfer_lamports(pending_alter, owner, pending_alter.lamports())
+    }
+
+    #[inline(never)]
+    fn add_delegate(&self, delegate: Pubkey) -> CurvyResult<()> {
+        msg!("add_delegate ix");
+
+        let AddDelegateAccounts { curve, owner } =
+            self.parse_accounts(AddDelegateAccounts::from_iter)?;
+
+        let mut curve_data = curve.data.borrow_mut();
+        let unpacked_curve = Curve::load_mut(&mut curve_data).account(curve.key)?;
+
+        unpacked_curve.check_owner(owner.key).account(curve.key)?;
+        unpacked_curve.check_not_frozen().account(curve.key)?;
+
+        unpacked_curve.add_delegate(delegate).account(curve.key)
+    }
+
+    #[inline(never)]
+    fn remove_delegate(&self, delegate: &Pubkey) -> CurvyResult<()> {
+        msg!("remove_delegate ix");
+
+        let RemoveDelegateAccounts { curve, owner } =
+            self.parse_accounts(RemoveDelegateAccounts::from_iter)?;
+
+        let mut curve_data = curve.data.borrow_mut();
+        let unpacked_curve = Curve::load_mut(&mut curve_data).account(curve.key)?;
+
+        unpacked_curve.check_owner(owner.key).account(curve.key)?;
+
+        unpacked_curve.remove_delegate(delegate).account(curve.key)
+    }
+
+    #[inline(never)]
+    fn set_curve_owners(&self, threshold: u8, owners: &[Pubkey]) -> CurvyResult<()> {
+        msg!("set_curve_owners ix");
+
+        let (
+            SetCurveOwnersAccounts {
+                curve,
+                owner,
+                curve_owners,
+                system_program,
+            },
+            co_signers,
+        ) = self.parse_accounts_with_rest(SetCurveOwnersAccounts::from_iter)?;
+
+        let mut curve_data = curve.data.borrow_mut();
+        let unpacked_curve = Curve::load_mut(&mut curve_data).account(curve.key)?;
+
+        let seeds = CurveOwners::seeds(curve.key);
+        let (owners_key, bump) = Pubkey::find_program_address(&seeds, self.program_id);
+        verify_key(curve_owners.key, &owners_key, "curve_owners").account(curve_owners.key)?;
+
+        match unpacked_curve.owner_threshold {
+            0 => {
+                check_no_accounts_left(&mut co_signers.iter())?;
+                unpacked_curve.check_owner(owner.key).account(curve.key)?;
+            }
+            current => {
+                self.check_co_owners(curve.key, curve_owners, owner, co_signers, current)?;
+            }
+        }
+        unpacked_curve.check_not_frozen().account(curve.key)?;
+
+        if threshold == 0 {
+            if !owners.is_empty() {
+                msg!("owners are listed with zero threshold");
+                return Err(CurvyError::InvalidParams).account(curve.key);
+            }
+
+            unpacked_curve.owner_threshold = 0;
+            if curve_owners.owner == self.program_id {
+                curve_owners.data.borrow_mut().fill(0);
+                transfer_lamports(curve_owners, owner, curve_owners.lamports())?;
+            }
+
+            return Ok(());
+        }
+
+        if !owners.contains(&unpacked_curve.owner) {
+            msg!(
+                "curve owner {} must be among the owners",
+                unpacked_curve.owner
+            );
+            return Err(CurvyError::InvalidParams).account(curve.key);
+        }
+
+        if curve_owners.owner != self.program_id {
+            let rent = Rent::get().expect("No Rent");
+            let bump = [bump];
+
+            SystemProgram::new(system_program)
+                .create_account(
+                    owner,
+                    curve_owners,
+                    CurveOwners::SIZE as u64,
+                    rent.minimum_balance(CurveOwners::SIZE),
+                    self.program_id,
+                )
+                .call_signed(&[&[seeds[0], seeds[1], &bump]])
+                .account(curve_owners.key)?;
+
+            CurveOwners::init_bytes(&mut curve_owners.data.borrow_mut(), *curve.key)
+                .account(curve_owners.key)?;
+        }
+
+        let mut owners_data = curve_owners.data.borrow_mut();
+        CurveOwners::try_from_bytes_mut(&mut owners_data)
+            .account(curve_owners.key)?
+            .set_owners(owners, threshold)
+            .account(curve.key)?;
+        unpacked_curve.owner_threshold = threshold;
+        // a transfer proposed by the single owner is not accepted by co-owned curves
+        unpacked_curve.pending_owner = Pubkey::default();
+
+        Ok(())
+    }
+
+    #[inline(never)]
+    fn init_config(&self, creators: &[Pubkey]) -> CurvyResult<()> {
+        msg!("init_config ix");
+
+        let InitConfigAccounts {
+            config,
+            admin,
+            program_data,
+            system_program,
+        } = self.parse_accounts(InitConfigAccounts::from_iter)?;
+
+        let (program_data_key, _) =
+            Pubkey::find_program_address(&[self.program_id.as_ref()], &bpf_loader_upgradeable::ID);
+        verify_key(program_data.key, &program_data_key, "program_data")
+            .account(program_data.key)?;
+        let authority = upgrade_authority(&program_data.data.borrow(), program_data.key)?;
+        verify_key(admin.key, &authority, "upgrade_authority").account(program_data.key)?;
+
+        let seeds = Config::seeds();
+        let (config_key, bump) = Pubkey::find_program_address(&seeds, self.program_id);
+        verify_key(config.key, &config_key, "config").account(config.key)?;
+
+        let rent = Rent::get().expect("No Rent");
+        let bump = [bump];
+
+        SystemProgram::new(system_program)
+            .create_account(
+                admin,
+                config,
+                Config::SIZE as u64,
+                rent.minimum_balance(Config::SIZE),
+                self.program_id,
+            )
+            .call_signed(&[&[seeds[0], &bump]])
+            .account(config.key)?;
+
+        let mut config_data = config.data.borrow_mut();
+        Config::init_bytes(&mut config_data, *admin.key).account(config.key)?;
+
+        Config::try_from_bytes_mut(&mut config_data)
+            .account(config.key)?
+            .set_creators(creators)
+            .account(config.key)
+    }
+
+    #[inline(never)]
+    fn set_config(&self, new_admin: Pubkey, creators: &[Pubkey]) -> CurvyResult<()> {
+        msg!("set_config ix");
+
+        let SetConfigAccounts { config, admin } =
+            self.parse_accounts(SetConfigAccounts::from_iter)?;
+
+        let mut config_data = config.data.borrow_mut();
+        let unpacked_config = Config::try_from_bytes_mut(&mut config_data).account(config.key)?;
+
+        verify_key(admin.key, &unpacked_config.admin, "admin").account(config.key)?;
+
+        if new_admin == Pubkey::default() {
+            msg!("config admin can not be zero key");
+            return Err(CurvyError::InvalidParams).account(config.key);
+        }
+
+        unpacked_config.set_creators(creators).account(config.key)?;
+        unpacked_config.admin = new_admin;
+
+        Ok(())
+    }
+
+    #[inline(never)]
+    fn set_creation_frozen(&self, frozen: bool) -> CurvyResult<()> {
+        msg!("set_creation_frozen ix");
+
+        let SetCreationFrozenAccounts { config, admin } =
+            self.parse_accounts(SetCreationFrozenAccounts::from_iter)?;
+
+        let mut config_data = config.data.borrow_mut();
+        let unpacked_config = Config::try_from_bytes_mut(&mut config_data).account(config.key)?;
+
+        verify_key(admin.key, &unpacked_config.admin, "admin").account(config.key)?;
+
+        unpacked_config.creation_frozen = frozen as u8;
+
+        Ok(())
+    }
+
+    #[inline(never)]
+    fn set_curve_extension(&self, extension_type: u16, value: &[u8]) -> CurvyResult<()> {
+        msg!("set_curve_extension ix");
+
+        let SetCurveExtensionAccounts {
+            curve,
+            owner,
+            system_program,
+        } = self.parse_accounts(SetCurveExtensionAccounts::from_iter)?;
+
+        let (extensions, old_len) = {
+            let mut curve_data = curve.data.borrow_mut();
+            let (unpacked_curve, _, extensions) =
+                Curve::load_mut_with_extensions(&mut curve_data).account(curve.key)?;
+
+            unpacked_curve.check_owner(owner.key).account(curve.key)?;
+            unpacked_curve.check_not_frozen().account(curve.key)?;
+            // guardrails bound the samples consumers get
+            if extension_type == ExtensionType::Guardrails as u16 {
+                unpacked_curve.check_not_timelocked().account(curve.key)?;
+            }
+
+            let updated = curve_extension::with_value(extensions, extension_type, value)
+                .account(curve.key)?;
+            unpacked_curve.extensions_len = (updated.len() as u16).to_le_bytes();
+
+            (updated, extensions.len())
+        };
+
+        let size = curve.data_len() - old_len + extensions.len();
+        curve
+            .realloc(size, true)
+            .map_err(|_| CurvyError::InvalidRealloc)
+            .account(curve.key)?;
+        curve.data.borrow_mut()[size - extensions.len()..].copy_from_slice(&extensions);
+
+        top_up_rent(curve, owner, system_program)
+    }
+}
+
+/// Upgrade authority in ProgramData account `data` of BPF upgradeable loader. Fails for
+/// immutable programs.
+fn upgrade_authority(data: &[u8], key: &Pubkey) -> CurvyResult<Pubkey> {
+    // bincode of UpgradeableLoaderState::ProgramData: u32 variant 3, u64 slot, Option<Pubkey>
+    let authority = match data.get(..45) {
+        Some([3, 0, 0, 0, header @ ..]) if header[8] == 1 => &header[9..],
+        _ => {
+            msg!("program has no upgrade authority");
+            return Err(InvalidAccount(*key).into());
+        }
+    };
+
+    Ok(Pubkey::try_from(authority).expect("32 bytes"))
+}
+
+/// Fails with [CurvyError::UnexpectedAccounts] unless all accounts of `iter` are parsed
+pub(crate) fn check_no_accounts_left(
+    iter: &mut std::slice::Iter<'_, AccountInfo<'_>>,
+) -> CurvyResult<()> {
+    match iter.len() {
+        0 => Ok(()),
+        count => {
+            msg!(
+                "{} unexpected accounts after the instruction accounts",
+                count
+            );
+            Err(CurvyError::UnexpectedAccounts { count })
+        }
+    }
+}
+
+/// Receiver of the deleted curve rent: the optional account after DeleteCurve accounts or
+/// `owner`. Neither `curve` nor its `name_link` receive it, the curve would survive deletion.
+fn rent_destination<'a, 'b>(
+    curve: &'a AccountInfo<'b>,
+    owner: &'a AccountInfo<'b>,
+    name_link: &'a AccountInfo<'b>,
+    rest: &'a [AccountInfo<'b>],
+) -> CurvyResult<&'a AccountInfo<'b>> {
+    match rest {
+        [] => Ok(owner),
+        [destination, rest @ ..] => {
+            check_no_accounts_left(&mut rest.iter())?;
+            if !destination.is_writable {
+                msg!("rent_destination must be writable");
+                return Err(InvalidAccount(*destination.key).into());
+            }
+            if destination.key == curve.key || destination.key == name_link.key {
+                msg!("rent_destination must differ from the curve and its name link");
+                return Err(InvalidAccount(*destination.key).into());
+            }
+            Ok(destination)
+        }
+    }
+}
+
+/// Pending alteration in `data` of the account `key`, which must belong to `curve`
+fn pending_of<'a>(data: &'a [u8], curve: &Pubkey, key: &Pubkey) -> CurvyResult<&'a PendingAlter> {
+    let pending = PendingAlter::try_from_bytes(data).account(key)?;
+
+    if pending.curve != *curve {
+        msg!("pending_alter does not belong to the curve");
+        return Err(InvalidAccount(*key).into());
+    }
+
+    Ok(pending)
+}
+
+/// Records the update of samples of the curve and moves its `valid_until`, see
+/// [Curve::set_last_update] and [Curve::refresh_validity]
+fn stamp_update(curve: &mut Curve) {
+    let clock = Clock::get().expect("No Clock");
+    curve.set_last_update(clock.slot, clock.unix_timestamp);
+    curve.refresh_validity(clock.unix_timestamp);
+}
+
+/// Counts the update of samples of the curve and stamps it, see [Curve::next_revision]
+fn record_alteration(curve: &mut Curve) {
+    curve.next_revision();
+    stamp_update(curve);
+}
+
+/// Logs [CurveEvent::Altered] of `curve` whose `y` table hashed to `old_y_hash` before
+fn emit_altered(key: &Pubkey, curve: &Curve, authority: &Pubkey, old_y_hash: [u8; 32]) {
+    CurveEvent::Altered(CurveAltered {
+        curve: *key,
+        owner: curve.owner,
+        authority: *authority,
+        name: curve.name,
+        old_y_hash,
+        new_y_hash: curve.y_hash(),
+    })
+    .emit();
+}
+
+/// Writes `params` and `owner` into Curve account just created by System program
+fn init_curve(curve: &AccountInfo<'_>, owner: &Pubkey, params: &CurveParamsRaw) -> CurvyResult<()> {
+    params.check().account(curve.key)?;
+
+    let mut curve_data = curve.data.borrow_mut();
+
+    // account is just created by System program, so it is zeroed
+    let curve_account: &mut Curve = bytemuck::try_from_bytes_mut(&mut curve_data)
+        .map_err(|err| CurvyError::Serialize(err.into()))
+        .account(curve.key)?;
+    curve_account.set_params_raw(params);
+    curve_account.owner = *owner;
+    stamp_update(curve_account);
+
+    CurveEvent::Created(CurveCreated {
+        curve: *curve.key,
+        owner: *owner,
+        name: curve_account.name,
+        y_hash: curve_account.y_hash(),
+    })
+    .emit();
+
+    Ok(())
+}
+
+/// Transfers lamports `account` misses for rent exemption from `payer` and checks it is rent
+/// exempt afterwards
+pub fn top_up_rent<'a>(
+    account: &AccountInfo<'a>,
+    payer: &AccountInfo<'a>,
+    system_program: &AccountInfo<'a>,
+) -> CurvyResult<()> {
+    let rent = Rent::get().expect("No Rent");
+    let required = rent.minimum_balance(account.data_len());
+    let delta = required.saturating_sub(account.lamports());
+
+    if delta > 0 {
+        SystemProgram::new(system_program)
+            .transfer(payer, account, delta)
+            .call()
+            .account(payer.key)?;
+        msg!("top_up_rent {} to {}", delta, account.key);
+    }
+
+    if !rent.is_exempt(account.lamports(), account.data_len()) {
+        return Err(CurvyError::NotRentExempt {
+            lamports: account.lamports(),
+            required,
+        })
+        .account(account.key);
+    }
+
+    Ok(())
 }
 
 /// Transfers `amount` lamports from `from_account` (must be program owned)
@@ -116,20 +2428,23 @@ pub fn transfer_lamports(
     to_account: &AccountInfo<'_>,
     amount: u64,
 ) -> CurvyResult<()> {
-    if **from_account
+    let balance = **from_account
         .try_borrow_lamports()
-        .map_err(|_| CurvyError::OperationCanNotBePerformed)?
-        < amount
-    {
-        return Err(CurvyError::OperationCanNotBePerformed);
+        .map_err(|_| CurvyError::OperationCanNotBePerformed)
+        .account(from_account.key)?;
+    if balance < amount {
+        msg!("insufficient balance {} < {}", balance, amount);
+        return Err(CurvyError::OperationCanNotBePerformed).account(from_account.key);
     }
 
     **from_account
         .try_borrow_mut_lamports()
-        .map_err(|_| CurvyError::OperationCanNotBePerformed)? -= amount;
+        .map_err(|_| CurvyError::OperationCanNotBePerformed)
+        .account(from_account.key)? -= amount;
     **to_account
         .try_borrow_mut_lamports()
-        .map_err(|_| CurvyError::OperationCanNotBePerformed)? += amount;
+        .map_err(|_| CurvyError::OperationCanNotBePerformed)
+        .account(to_account.key)? += amount;
 
     msg!(
         "transfer_lamports {} from {} to {}",
@@ -0,0 +1,91 @@
+use bytemuck::{Pod, Zeroable};
+use solana_program::pubkey::Pubkey;
+
+use texture_common::account::{PodAccount, PodAccountError};
+
+use crate::state::ASSET_LINK_DISCRIMINATOR;
+
+pub const ASSET_LINK_SEED: &[u8] = curvy_spec::ASSET_LINK_SEED;
+
+static_assertions::const_assert_eq!(AssetLink::SIZE, std::mem::size_of::<AssetLink>());
+static_assertions::const_assert_eq!(0, std::mem::size_of::<AssetLink>() % 8);
+static_assertions::const_assert_eq!(AssetLink::SIZE, curvy_spec::ASSET_LINK_SIZE);
+
+/// Registry entry of a curve associated with an SPL mint. Lives at the address derived from
+/// (owner, mint, kind), so there is at most one curve per such triple.
+#[derive(Debug, Copy, Clone, Pod, Zeroable)]
+#[repr(C)]
+pub struct AssetLink {
+    pub discriminator: [u8; 8],
+    pub version: u8,
+
+    /// Kind of the association, see [crate::state::curve::Curve::asset_kind]
+    pub kind: u8,
+
+    pub _padding: [u8; 6],
+
+    /// Owner of the curve at the time of association
+    pub owner: Pubkey,
+
+    pub asset_mint: Pubkey,
+
+    /// Associated curve
+    pub curve: Pubkey,
+}
+
+impl AssetLink {
+    pub fn seeds<'a>(
+        owner: &'a Pubkey,
+        asset_mint: &'a Pubkey,
+        kind: &'a [u8; 1],
+    ) -> [&'a [u8]; 4] {
+        [ASSET_LINK_SEED, owner.as_ref(), asset_mint.as_ref(), kind]
+    }
+
+    /// Address and bump of the link of `owner`'s curve associated with `asset_mint`
+    pub fn find_address(owner: &Pubkey, asset_mint: &Pubkey, kind: u8) -> (Pubkey, u8) {
+        Pubkey::find_program_address(&Self::seeds(owner, asset_mint, &[kind]), &crate::ID)
+    }
+}
+
+impl PodAccount for AssetLink {
+    const DISCRIMINATOR: &'static [u8] = ASSET_LINK_DISCRIMINATOR;
+
+    type Version = u8;
+
+    const VERSION: Self::Version = curvy_spec::ASSET_LINK_VERSION;
+
+    type InitParams = (
+        /*owner:*/ Pubkey,
+        /*asset_mint:*/ Pubkey,
+        /*kind:*/ u8,
+        /*curve:*/ Pubkey,
+    );
+
+    type InitError = PodAccountError;
+
+    fn discriminator(&self) -> &[u8] {
+        &self.discriminator
+    }
+
+    fn version(&self) -> Self::Version {
+        self.version
+    }
+
+    fn init_unckecked(
+        &mut self,
+        (owner, asset_mint, kind, curve): Self::InitParams,
+    ) -> Result<(), Self::InitError> {
+        *self = Self {
+            discriminator: *ASSET_LINK_DISCRIMINATOR,
+            version: Self::VERSION,
+            kind,
+            _padding: Zeroable::zeroed(),
+            owner,
+            asset_mint,
+            curve,
+        };
+
+        Ok(())
+    }
+}
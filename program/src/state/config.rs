@@ -0,0 +1,141 @@
+use bytemuck::{Pod, Zeroable};
+use solana_program::msg;
+use solana_program::pubkey::Pubkey;
+
+use texture_common::account::{PodAccount, PodAccountError};
+
+use crate::error::CurvyError;
+use crate::state::CONFIG_DISCRIMINATOR;
+use crate::CurvyResult;
+
+pub use curvy_spec::MAX_CONFIG_CREATORS;
+
+pub const CONFIG_SEED: &[u8] = curvy_spec::CONFIG_SEED;
+
+static_assertions::const_assert_eq!(Config::SIZE, std::mem::size_of::<Config>());
+static_assertions::const_assert_eq!(0, std::mem::size_of::<Config>() % 8);
+static_assertions::const_assert_eq!(Config::SIZE, curvy_spec::CONFIG_SIZE);
+
+/// Settings of the program deployment, a single account at the address derived from
+/// [CONFIG_SEED]. Created by InitConfig signed by the program upgrade authority, changed by
+/// SetConfig and SetCreationFrozen signed by `admin`. Until it exists anyone can create curves.
+#[derive(Debug, Copy, Clone, Pod, Zeroable)]
+#[repr(C)]
+pub struct Config {
+    pub discriminator: [u8; 8],
+    pub version: u8,
+
+    /// 1 while creation of curves is frozen by SetCreationFrozen, e.g. for a maintenance window.
+    /// Existing curves are managed as usual meanwhile.
+    pub creation_frozen: u8,
+
+    pub _padding: [u8; 6],
+
+    /// Signer required by SetConfig
+    pub admin: Pubkey,
+
+    /// Keys allowed to own created curves, zero keys are free slots
+    pub creators: [Pubkey; MAX_CONFIG_CREATORS],
+
+    pub _reserved: [u8; curvy_spec::CONFIG_RESERVED_SIZE],
+}
+
+impl Config {
+    pub fn seeds() -> [&'static [u8]; 1] {
+        [CONFIG_SEED]
+    }
+
+    /// Address and bump of the config account
+    pub fn find_address() -> (Pubkey, u8) {
+        Pubkey::find_program_address(&Self::seeds(), &crate::ID)
+    }
+
+    pub fn is_creation_frozen(&self) -> bool {
+        self.creation_frozen != 0
+    }
+
+    /// Fails with [CurvyError::CreationFrozen] while creation of curves is frozen
+    pub fn check_creation_not_frozen(&self) -> CurvyResult<()> {
+        if self.is_creation_frozen() {
+            msg!("curve creation is frozen by the config admin");
+            return Err(CurvyError::CreationFrozen);
+        }
+
+        Ok(())
+    }
+
+    /// Keys of the creator allow-list
+    pub fn creators(&self) -> impl Iterator<Item = &Pubkey> {
+        self.creators
+            .iter()
+            .filter(|creator| **creator != Pubkey::default())
+    }
+
+    /// Replaces the creator allow-list. Zero keys, duplicates and more than
+    /// [MAX_CONFIG_CREATORS] keys are rejected.
+    pub fn set_creators(&mut self, creators: &[Pubkey]) -> CurvyResult<()> {
+        if creators.len() > MAX_CONFIG_CREATORS {
+            msg!(
+                "{} creators exceed max {}",
+                creators.len(),
+                MAX_CONFIG_CREATORS
+            );
+            return Err(CurvyError::InvalidParams);
+        }
+        for (idx, creator) in creators.iter().enumerate() {
+            if *creator == Pubkey::default() || creators[..idx].contains(creator) {
+                msg!("creator {} is zero key or listed twice", creator);
+                return Err(CurvyError::InvalidParams);
+            }
+        }
+
+        self.creators = [Pubkey::default(); MAX_CONFIG_CREATORS];
+        self.creators[..creators.len()].copy_from_slice(creators);
+
+        Ok(())
+    }
+
+    /// Fails with [CurvyError::CreatorNotAllowed] unless `creator` is in the allow-list
+    pub fn check_creator(&self, creator: &Pubkey) -> CurvyResult<()> {
+        if !self.creators().any(|allowed| allowed == creator) {
+            msg!("{} is not in the creator allow-list", creator);
+            return Err(CurvyError::CreatorNotAllowed { creator: *creator });
+        }
+
+        Ok(())
+    }
+}
+
+impl PodAccount for Config {
+    const DISCRIMINATOR: &'static [u8] = CONFIG_DISCRIMINATOR;
+
+    type Version = u8;
+
+    const VERSION: Self::Version = curvy_spec::CONFIG_VERSION;
+
+    type InitParams = Pubkey;
+
+    type InitError = PodAccountError;
+
+    fn discriminator(&self) -> &[u8] {
+        &self.discriminator
+    }
+
+    fn version(&self) -> Self::Version {
+        self.version
+    }
+
+    fn init_unckecked(&mut self, admin: Self::InitParams) -> Result<(), Self::InitError> {
+        *self = Self {
+            discriminator: *CONFIG_DISCRIMINATOR,
+            version: Self::VERSION,
+            creation_frozen: 0,
+            _padding: Zeroable::zeroed(),
+            admin,
+            creators: [Pubkey::default(); MAX_CONFIG_CREATORS],
+            _reserved: [0; curvy_spec::CONFIG_RESERVED_SIZE],
+        };
+
+        Ok(())
+    }
+}
@@ -1,5 +1,6 @@
 use borsh::{BorshDeserialize, BorshSerialize};
 use bytemuck::{Pod, Zeroable};
+use solana_program::hash::{hash, Hash};
 use solana_program::msg;
 use solana_program::pubkey::Pubkey;
 
@@ -7,13 +8,40 @@ use crate::error::CurvyError;
 use crate::CurvyResult;
 use texture_common::account::{PodAccount, PodAccountError};
 use texture_common::math::{CheckedAdd, CheckedMul, Decimal};
+use texture_common::utils::verify_key;
 
+use crate::state::config::Config;
+use crate::state::utils::str_to_array;
 use crate::state::CURVE_DISCRIMINATOR;
 
-pub const SYMBOL_MAX_SIZE: usize = 16;
+pub const SYMBOL_MAX_SIZE: usize = curvy_spec::SYMBOL_MAX_SIZE;
+
+pub const CURVE_SEED: &[u8] = curvy_spec::CURVE_SEED;
+pub const REFERENCE_SEED: &[u8] = curvy_spec::REFERENCE_SEED;
 
 static_assertions::const_assert_eq!(Curve::SIZE, std::mem::size_of::<Curve>());
 static_assertions::const_assert_eq!(0, std::mem::size_of::<Curve>() % 8);
+static_assertions::const_assert_eq!(Curve::SIZE, curvy_spec::CURVE_SIZE);
+static_assertions::const_assert_eq!(
+    Curve::V1_SIZE,
+    std::mem::size_of::<Curve>()
+        - 32
+        - 8
+        - 32
+        - 8
+        - 8
+        - 32 * MAX_CURVE_DELEGATES
+        - 1
+        - 2
+        - 1
+        - 8
+        - 8
+        - 8
+        - 8
+        - 8
+        - 1
+        - CURVE_RESERVED_SIZE
+);
 
 /// These are fixed point decimal number with precision specified in Curve.
 /// X holds utilization rate in percents e.g. 45.2344 % thus usually we don't need values more than 100.
@@ -24,13 +52,203 @@ static_assertions::const_assert_eq!(0, std::mem::size_of::<Curve>() % 8);
 pub type CurveX = u32;
 pub type CurveY = u32;
 
+/// Space left in Curve for future fields
+pub const CURVE_RESERVED_SIZE: usize = curvy_spec::CURVE_RESERVED_SIZE;
+
+/// Slots between MarkForDeletion and DeleteCurve of the marked curve, see
+/// [Curve::deletion_slot]
+pub const DELETION_GRACE_SLOTS: u64 = curvy_spec::DELETION_GRACE_SLOTS;
+
+/// Max [Curve::alter_delay]
+pub const MAX_ALTER_DELAY_SLOTS: u64 = curvy_spec::MAX_ALTER_DELAY_SLOTS;
+
+/// Max number of [Curve::delegates]
+pub const MAX_CURVE_DELEGATES: usize = curvy_spec::MAX_CURVE_DELEGATES;
+
 /// To make design simple we limit number of `y` samples. This allows send all Curve data
 /// in one TX and to allocate statically known space in the account.
-pub const MAX_Y_CNT: usize = 130;
+pub const MAX_Y_CNT: usize = curvy_spec::MAX_Y_CNT;
+
+/// Max number of `y` samples of a curve grown by ResizeCurve, `y` table included. See
+/// [Curve::tail_y_count].
+pub const MAX_TOTAL_Y_CNT: usize = curvy_spec::MAX_TOTAL_Y_CNT;
+
+/// Lifecycle stage of Curve kept in [Curve::status]. Changed by SetCurveStatus along
+/// `Draft -> Active -> Deprecated -> Retired`, deprecated curves may be reactivated. Active and
+/// deprecated curves may be paused, e.g. during an incident, and resumed to either stage.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[repr(u8)]
+pub enum CurveStatus {
+    /// In use, the only stage of curves created before the status was introduced
+    Active = 0,
+    /// Being prepared, not to be consumed yet. Curves created by ReserveCurve start here.
+    Draft = 1,
+    /// Still consumed, but consumers should move to another curve
+    Deprecated = 2,
+    /// Not to be consumed anymore. Only draft and retired curves can be deleted.
+    Retired = 3,
+    /// Temporarily out of service, consumers fail with [CurvyError::CurveInactive]
+    Paused = 4,
+}
+
+impl CurveStatus {
+    /// Whether the status may change from `self` to `to`
+    pub fn can_become(self, to: Self) -> bool {
+        use CurveStatus::*;
+
+        matches!(
+            (self, to),
+            (Draft, Active)
+                | (Active, Deprecated)
+                | (Deprecated, Active)
+                | (Deprecated, Retired)
+                | (Active | Deprecated, Paused)
+                | (Paused, Active | Deprecated)
+        )
+    }
+
+    /// Whether curves in this stage can be deleted
+    pub fn is_deletable(self) -> bool {
+        matches!(self, Self::Draft | Self::Retired)
+    }
+
+    /// Fails with [CurvyError::OperationCanNotBePerformed] unless curves in this stage can be
+    /// deleted, see [CurveStatus::is_deletable]
+    pub fn check_deletable(self) -> CurvyResult<()> {
+        if !self.is_deletable() {
+            msg!(
+                "curve is {}, only draft and retired curves can be deleted",
+                self
+            );
+            return Err(CurvyError::OperationCanNotBePerformed);
+        }
+
+        Ok(())
+    }
+
+    /// Stage SetCurveStatus moves the curve to from this one. Fails with
+    /// [CurvyError::InvalidStatusTransition] unless [CurveStatus::can_become] it.
+    pub fn transition(self, to: u8) -> CurvyResult<Self> {
+        let status = Self::try_from(to)?;
+        if !self.can_become(status) {
+            msg!("curve status can not change from {} to {}", self, status);
+            return Err(CurvyError::InvalidStatusTransition {
+                from: self as u8,
+                to,
+            });
+        }
+
+        Ok(status)
+    }
+}
+
+impl TryFrom<u8> for CurveStatus {
+    type Error = CurvyError;
 
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        Ok(match value {
+            0 => Self::Active,
+            1 => Self::Draft,
+            2 => Self::Deprecated,
+            3 => Self::Retired,
+            4 => Self::Paused,
+            _ => {
+                msg!("unknown curve status {}", value);
+                return Err(CurvyError::InvalidParams);
+            }
+        })
+    }
+}
+
+impl std::fmt::Display for CurveStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Self::Active => "active",
+            Self::Draft => "draft",
+            Self::Deprecated => "deprecated",
+            Self::Retired => "retired",
+            Self::Paused => "paused",
+        })
+    }
+}
+
+impl std::str::FromStr for CurveStatus {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "active" => Self::Active,
+            "draft" => Self::Draft,
+            "deprecated" => Self::Deprecated,
+            "retired" => Self::Retired,
+            "paused" => Self::Paused,
+            _ => return Err(format!("unknown curve status `{s}`")),
+        })
+    }
+}
+
+/// How stored `y` values map to the values of the curve, kept in [Curve::y_transform].
+/// Samples are interpolated as stored, the transform applies to the interpolated value.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+#[repr(u8)]
+pub enum YTransform {
+    /// Values are stored as is
+    #[default]
+    Linear = 0,
+    /// `log10` of values is stored with `y_decimals`, e.g. `2_000000` is 100 at 6 decimals.
+    /// For values spanning several orders of magnitude, interpolated geometrically. Values
+    /// below 1 have negative logarithms and need signed `y`, see [Curve::y_signed].
+    Log10 = 1,
+}
+
+impl TryFrom<u8> for YTransform {
+    type Error = CurvyError;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        Ok(match value {
+            0 => Self::Linear,
+            1 => Self::Log10,
+            _ => {
+                msg!("unknown y transform {}", value);
+                return Err(CurvyError::InvalidParams);
+            }
+        })
+    }
+}
+
+impl std::fmt::Display for YTransform {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Self::Linear => "linear",
+            Self::Log10 => "log10",
+        })
+    }
+}
+
+impl std::str::FromStr for YTransform {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "linear" => Self::Linear,
+            "log10" => Self::Log10,
+            _ => return Err(format!("unknown y transform `{s}`")),
+        })
+    }
+}
+
+#[cfg_attr(feature = "with-serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(BorshSerialize, BorshDeserialize, Debug, Copy, Clone)]
 pub struct CurveParams {
+    #[cfg_attr(
+        feature = "with-serde",
+        serde(with = "super::utils::array_as_str_serde")
+    )]
     pub name: [u8; SYMBOL_MAX_SIZE],
+    #[cfg_attr(
+        feature = "with-serde",
+        serde(with = "super::utils::array_as_str_serde")
+    )]
     pub formula: [u8; SYMBOL_MAX_SIZE],
     /// Starting X coordinate
     pub x0: CurveX,
@@ -38,13 +256,21 @@ pub struct CurveParams {
     pub x_step: CurveX,
     /// Number of samples in `y` array
     pub y_count: u8,
-    /// Precision of
-    pub decimals: u8,
+    /// Precision of `x0` and `x_step`
+    pub x_decimals: u8,
+    /// Precision of `y` values
+    pub y_decimals: u8,
     /// Array of `y` values
+    #[cfg_attr(
+        feature = "with-serde",
+        serde(with = "serde_with::As::<[serde_with::Same; MAX_Y_CNT]>")
+    )]
     pub y: [CurveY; MAX_Y_CNT],
 }
 
 impl CurveParams {
+    /// Params with the same `decimals` on both axes, set [CurveParams::y_decimals] for `y`
+    /// scaled differently
     pub fn new(
         name: &str,
         formula: &str,
@@ -60,8 +286,229 @@ impl CurveParams {
             x0,
             x_step,
             y_count,
-            decimals,
+            x_decimals: decimals,
+            y_decimals: decimals,
+            y,
+        }
+    }
+
+    /// SHA-256 of [Self::canonical_json]. Identifies curve content regardless of its address.
+    pub fn hash(&self) -> Hash {
+        hash(self.canonical_json().as_bytes())
+    }
+
+    /// Canonical JSON used for hashing, reproducible in any language:
+    /// - keys in order `name`, `formula`, `x0`, `x_step`, `y_count`, `decimals`, `y_decimals`,
+    ///   `y`, where `decimals` holds `x_decimals` and `y_decimals` is present only when it
+    ///   differs, so hashes of curves with the same decimals on both axes are unchanged;
+    /// - no whitespace;
+    /// - `name` and `formula` are bytes up to the first zero decoded as UTF-8 (invalid sequences
+    ///   replaced with U+FFFD), `"` and `\` are escaped with backslash, other characters below
+    ///   U+0020 as `\u00xx` (lowercase hex), everything else as is;
+    /// - integers in decimal notation, no floats;
+    /// - `y` holds only the first `y_count` values.
+    pub fn canonical_json(&self) -> String {
+        use std::fmt::Write;
+
+        fn write_str(out: &mut String, bytes: &[u8]) {
+            let len = bytes.iter().position(|b| *b == 0).unwrap_or(bytes.len());
+
+            out.push('"');
+            for ch in String::from_utf8_lossy(&bytes[..len]).chars() {
+                match ch {
+                    '"' => out.push_str("\\\""),
+                    '\\' => out.push_str("\\\\"),
+                    ch if (ch as u32) < 0x20 => {
+                        let _ = write!(out, "\\u{:04x}", ch as u32);
+                    }
+                    ch => out.push(ch),
+                }
+            }
+            out.push('"');
+        }
+
+        let mut out = String::with_capacity(64 + 11 * self.y_count as usize);
+
+        out.push_str("{\"name\":");
+        write_str(&mut out, &self.name);
+        out.push_str(",\"formula\":");
+        write_str(&mut out, &self.formula);
+
+        let _ = write!(
+            out,
+            ",\"x0\":{},\"x_step\":{},\"y_count\":{},\"decimals\":{}",
+            self.x0, self.x_step, self.y_count, self.x_decimals
+        );
+        if self.y_decimals != self.x_decimals {
+            let _ = write!(out, ",\"y_decimals\":{}", self.y_decimals);
+        }
+        out.push_str(",\"y\":[");
+
+        let y_count = (self.y_count as usize).min(MAX_Y_CNT);
+        for (idx, y) in self.y[..y_count].iter().enumerate() {
+            if idx > 0 {
+                out.push(',');
+            }
+            let _ = write!(out, "{y}");
+        }
+
+        out.push_str("]}");
+        out
+    }
+}
+
+impl From<&Curve> for CurveParams {
+    fn from(curve: &Curve) -> Self {
+        Self {
+            name: curve.name,
+            formula: curve.formula,
+            x0: curve.x0,
+            x_step: curve.x_step,
+            y_count: curve.y_count,
+            x_decimals: curve.x_decimals,
+            y_decimals: curve.y_decimals,
+            y: curve.y,
+        }
+    }
+}
+
+/// [CurveParams] in borsh layout, read in place from instruction data. Saves compute units of
+/// deserializing `y` value by value and copying the whole params around.
+#[derive(Debug, Copy, Clone, Pod, Zeroable)]
+#[repr(C)]
+pub struct CurveParamsRaw {
+    pub name: [u8; SYMBOL_MAX_SIZE],
+    pub formula: [u8; SYMBOL_MAX_SIZE],
+    pub x0: [u8; 4],
+    pub x_step: [u8; 4],
+    pub y_count: u8,
+    pub x_decimals: u8,
+    pub y_decimals: u8,
+    pub y: [[u8; 4]; MAX_Y_CNT],
+}
+
+static_assertions::const_assert_eq!(
+    std::mem::size_of::<CurveParamsRaw>(),
+    2 * SYMBOL_MAX_SIZE + 4 + 4 + 1 + 1 + 1 + 4 * MAX_Y_CNT
+);
+
+impl CurveParamsRaw {
+    /// Params from the exact amount of data, same as borsh `try_from_slice`
+    pub fn from_bytes(data: &[u8]) -> CurvyResult<&Self> {
+        bytemuck::try_from_bytes(data).map_err(|_| {
+            CurvyError::Borsh(borsh::io::Error::new(
+                borsh::io::ErrorKind::InvalidData,
+                "unexpected length of curve params",
+            ))
+        })
+    }
+
+    pub fn x0(&self) -> CurveX {
+        CurveX::from_le_bytes(self.x0)
+    }
+
+    pub fn x_step(&self) -> CurveX {
+        CurveX::from_le_bytes(self.x_step)
+    }
+
+    pub fn check(&self) -> CurvyResult<()> {
+        Curve::check_dimensions(
+            self.x0(),
+            self.x_step(),
+            self.y_count,
+            self.x_decimals,
+            self.y_decimals,
+        )
+    }
+}
+
+impl From<&CurveParams> for CurveParamsRaw {
+    fn from(params: &CurveParams) -> Self {
+        Self {
+            name: params.name,
+            formula: params.formula,
+            x0: params.x0.to_le_bytes(),
+            x_step: params.x_step.to_le_bytes(),
+            y_count: params.y_count,
+            x_decimals: params.x_decimals,
+            y_decimals: params.y_decimals,
+            y: params.y.map(CurveY::to_le_bytes),
+        }
+    }
+}
+
+impl From<&CurveParamsRaw> for CurveParams {
+    fn from(params: &CurveParamsRaw) -> Self {
+        Self {
+            name: params.name,
+            formula: params.formula,
+            x0: params.x0(),
+            x_step: params.x_step(),
+            y_count: params.y_count,
+            x_decimals: params.x_decimals,
+            y_decimals: params.y_decimals,
+            y: params.y.map(CurveY::from_le_bytes),
+        }
+    }
+}
+
+/// [CurveParams] carrying only the `y_count` samples, so params of several curves fit one
+/// transaction, see BatchAlter
+#[cfg_attr(feature = "with-serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(BorshSerialize, BorshDeserialize, Debug, Clone, PartialEq, Eq)]
+pub struct CompactCurveParams {
+    #[cfg_attr(
+        feature = "with-serde",
+        serde(with = "super::utils::array_as_str_serde")
+    )]
+    pub name: [u8; SYMBOL_MAX_SIZE],
+    #[cfg_attr(
+        feature = "with-serde",
+        serde(with = "super::utils::array_as_str_serde")
+    )]
+    pub formula: [u8; SYMBOL_MAX_SIZE],
+    pub x0: CurveX,
+    pub x_step: CurveX,
+    pub x_decimals: u8,
+    pub y_decimals: u8,
+    /// Samples of the `y` table, `y_count` is their number
+    pub y: Vec<CurveY>,
+}
+
+impl CompactCurveParams {
+    /// Params with the samples padded to [MAX_Y_CNT], fails with more samples than that
+    pub fn to_params(&self) -> CurvyResult<CurveParams> {
+        if self.y.len() > MAX_Y_CNT {
+            msg!("{} samples exceed {}", self.y.len(), MAX_Y_CNT);
+            return Err(CurvyError::InvalidParams);
+        }
+
+        let mut y = [0; MAX_Y_CNT];
+        y[..self.y.len()].copy_from_slice(&self.y);
+
+        Ok(CurveParams {
+            name: self.name,
+            formula: self.formula,
+            x0: self.x0,
+            x_step: self.x_step,
+            y_count: self.y.len() as u8,
+            x_decimals: self.x_decimals,
+            y_decimals: self.y_decimals,
             y,
+        })
+    }
+}
+
+impl From<&CurveParams> for CompactCurveParams {
+    fn from(params: &CurveParams) -> Self {
+        Self {
+            name: params.name,
+            formula: params.formula,
+            x0: params.x0,
+            x_step: params.x_step,
+            x_decimals: params.x_decimals,
+            y_decimals: params.y_decimals,
+            y: params.y[..(params.y_count as usize).min(MAX_Y_CNT)].to_vec(),
         }
     }
 }
@@ -92,16 +539,206 @@ pub struct Curve {
     /// Number of samples in `y` array (2 bytes)
     pub y_count: u8,
 
-    /// Decimals number for x0, x_step, y.
-    pub decimals: u8,
+    /// Decimals number for x0, x_step. Of `y` as well before v3.
+    pub x_decimals: u8,
+
+    /// Decimals number for `y` of the curve, its tail and series (v3)
+    pub y_decimals: u8,
 
-    pub _padding1: [u8; 6],
+    /// Lifecycle stage, see [CurveStatus] and [Curve::status] (v3). Zero, i.e. active, for
+    /// curves created before.
+    pub status: u8,
+
+    pub _padding1: [u8; 4],
 
     /// Array of `y` values
     pub y: [CurveY; MAX_Y_CNT],
+
+    /// SPL mint the curve is associated with, zeroed when none (v2). See [Curve::asset].
+    pub asset_mint: Pubkey,
+
+    /// Kind of the association with `asset_mint`, e.g. borrow and supply rate curves of the
+    /// same mint. Meaning is up to consumers (v2).
+    pub asset_kind: u8,
+
+    /// Number of additional `y` series kept in the curve series account, 0 when there is none
+    /// (v2). See [crate::state::curve_series::CurveSeries].
+    pub series_count: u8,
+
+    /// 1 when the curve has per-sample confidence in the curve confidence account (v2). See
+    /// [crate::state::curve_confidence::CurveConfidence].
+    pub has_confidence: u8,
+
+    /// 1 when `x0` is negative, i.e. the curve starts at `-x0` (v2). X grid is still
+    /// `x0 + i * x_step` in signed terms, see [Curve::x0_signed].
+    pub x0_negative: u8,
+
+    /// 1 when the curve is frozen by FreezeCurve (v2): its samples, metadata, series and
+    /// confidence can not be changed and the curve can not be deleted. Irreversible.
+    pub is_frozen: u8,
+
+    /// 1 when `y` values of the curve, its tail and series are signed, i.e. hold `i32` bits
+    /// (v2). See [Curve::y_value].
+    pub y_signed: u8,
+
+    /// Number of `y` samples following the full `y` table (v2). They are kept after the Curve
+    /// layout at the end of the account grown by ResizeCurve and set by SetCurveTail, see
+    /// [Curve::tail_y]. Zero unless `y_count` is [MAX_Y_CNT].
+    pub tail_y_count: u16,
+
+    /// Owner proposed by the current one, zeroed when none (v2). Becomes `owner` when it signs
+    /// AcceptOwner, see [Curve::pending_owner].
+    pub pending_owner: Pubkey,
+
+    /// Little-endian slot of MarkForDeletion, zeroed when the curve is not marked (v3). See
+    /// [Curve::deletion_slot].
+    pub deletion_slot: [u8; 8],
+
+    /// Little-endian number of slots alterations of the curve wait for, zero when they are not
+    /// timelocked (v3). See [Curve::alter_delay].
+    pub alter_delay: [u8; 8],
+
+    /// Keys allowed to update samples of the curve besides the owner, zeroed slots are free
+    /// (v3). Set by AddDelegate and RemoveDelegate, see [Curve::delegates].
+    pub delegates: [Pubkey; MAX_CURVE_DELEGATES],
+
+    /// Number of owners which must sign changes of the curve, zero when the curve has the
+    /// single `owner` (v3). Set by SetCurveOwners, see [Curve::check_owner].
+    pub owner_threshold: u8,
+
+    /// Little-endian size of the TLV extension region at the end of the account, after the
+    /// tail samples (v3). Zero when the curve has no extensions, see
+    /// [crate::state::curve_extension].
+    pub extensions_len: [u8; 2],
+
+    /// [YTransform] of the stored `y` values (v3), linear when zero. Set by SetCurveYTransform,
+    /// see [Curve::y_transform].
+    pub y_transform: u8,
+
+    /// Little-endian unix timestamp the curve is stale from, zero when it does not expire (v3).
+    /// See [Curve::valid_until].
+    pub valid_until: [u8; 8],
+
+    /// Little-endian number of seconds sample updates move `valid_until` past their time, zero
+    /// when they keep it (v3). Set by SetCurveExpiry, see [Curve::refresh_validity].
+    pub validity_period: [u8; 8],
+
+    /// Little-endian slot of the last creation or sample update, zero for curves not updated
+    /// since (v3). See [Curve::last_update_slot].
+    pub last_update_slot: [u8; 8],
+
+    /// Little-endian unix timestamp of the update in `last_update_slot` (v3)
+    pub last_update_unix: [u8; 8],
+
+    /// Little-endian number of sample updates since creation (v3). See [Curve::revision].
+    pub revision: [u8; 8],
+
+    /// 1 while the pending alter account of the curve holds a proposal of ProposeAlter (v3), see
+    /// [crate::state::pending_alter::PendingAlter]. Zero for proposals made before the field.
+    pub has_pending_alter: u8,
+
+    /// Zeroed, for future fields (v2)
+    pub _reserved: [u8; CURVE_RESERVED_SIZE],
+}
+
+/// Address and bump of the curve of `owner` named `name` created by CreateCurvePda. Panics when
+/// `name` is longer than [SYMBOL_MAX_SIZE], as [CurveParams::new] does.
+///
+/// The address is derived once: curves renamed or transferred after creation stay where they
+/// were created.
+pub fn find_curve_address(owner: &Pubkey, name: &str) -> (Pubkey, u8) {
+    Curve::find_address(owner, &str_to_array(name))
+}
+
+/// Address and bump of the canonical reference curve of `kind` for `asset_mint`, created by
+/// the config admin with CreateReferenceCurve. Unlike curves of owners, the address is the same
+/// for every integrator.
+pub fn find_reference_curve_address(kind: u8, asset_mint: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&Curve::reference_seeds(&[kind], asset_mint), &crate::ID)
+}
+
+/// Slot of the little-endian deletion mark of a curve account of any kind, none unless marked
+pub(crate) fn marked_slot(deletion_slot: [u8; 8]) -> Option<u64> {
+    let slot = u64::from_le_bytes(deletion_slot);
+    (slot != 0).then_some(slot)
+}
+
+/// Marks a curve account of any kind for deletion in `slot`, see [Curve::mark_for_deletion]
+pub(crate) fn mark_for_deletion(deletion_slot: &mut [u8; 8], slot: u64) -> CurvyResult<()> {
+    if let Some(marked) = marked_slot(*deletion_slot) {
+        msg!("curve is already marked for deletion in slot {}", marked);
+        return Err(CurvyError::OperationCanNotBePerformed);
+    }
+
+    // zero means not marked, no real curve is marked in the genesis slot
+    *deletion_slot = slot.max(1).to_le_bytes();
+
+    Ok(())
+}
+
+/// Removes the mark set by [mark_for_deletion]
+pub(crate) fn cancel_deletion(deletion_slot: &mut [u8; 8]) -> CurvyResult<()> {
+    if marked_slot(*deletion_slot).is_none() {
+        msg!("curve is not marked for deletion");
+        return Err(CurvyError::OperationCanNotBePerformed);
+    }
+
+    *deletion_slot = [0; 8];
+
+    Ok(())
+}
+
+/// See [Curve::check_deletion_grace], `deletable_from` is the first slot the marked curve can
+/// be deleted in
+pub(crate) fn check_deletion_grace(
+    deletable_from: Option<u64>,
+    alter_delay: u64,
+    slot: u64,
+) -> CurvyResult<()> {
+    match (deletable_from, alter_delay) {
+        (Some(from), _) if slot < from => {
+            msg!(
+                "curve is marked for deletion and can be deleted from slot {}",
+                from
+            );
+            Err(CurvyError::DeletionGracePeriod { from })
+        }
+        (None, delay) if delay > 0 => {
+            msg!(
+                "curve alterations are timelocked for {} slots, mark it for deletion first",
+                delay
+            );
+            Err(CurvyError::AlterTimelocked { delay })
+        }
+        _ => Ok(()),
+    }
 }
 
 impl Curve {
+    /// Size of v1 accounts, which end with the `y` table
+    pub const V1_SIZE: usize = curvy_spec::CURVE_V1_SIZE;
+
+    /// Seeds of curves created by CreateCurvePda, `name` is zero padded as stored in the curve
+    pub fn seeds<'a>(owner: &'a Pubkey, name: &'a [u8; SYMBOL_MAX_SIZE]) -> [&'a [u8]; 3] {
+        [CURVE_SEED, owner.as_ref(), name]
+    }
+
+    /// See [find_curve_address]
+    pub fn find_address(owner: &Pubkey, name: &[u8; SYMBOL_MAX_SIZE]) -> (Pubkey, u8) {
+        Pubkey::find_program_address(&Self::seeds(owner, name), &crate::ID)
+    }
+
+    /// Seeds of reference curves created by CreateReferenceCurve
+    pub fn reference_seeds<'a>(kind: &'a [u8; 1], asset_mint: &'a Pubkey) -> [&'a [u8]; 3] {
+        [REFERENCE_SEED, kind, asset_mint.as_ref()]
+    }
+
+    /// Whether the curve was created by CreateReferenceCurve. Reference curves are owned by the
+    /// config address and managed by the config admin at the time.
+    pub fn is_reference(&self) -> bool {
+        self.owner == Config::find_address().0
+    }
+
     pub fn set_params(&mut self, params: CurveParams) {
         let Self {
             discriminator,
@@ -113,9 +750,33 @@ impl Curve {
             x_step,
             y_count,
             owner: _,
-            decimals,
+            x_decimals,
+            y_decimals,
+            status: _,
             _padding1,
             y,
+            asset_mint: _,
+            asset_kind: _,
+            series_count: _,
+            has_confidence: _,
+            x0_negative: _,
+            is_frozen: _,
+            y_signed: _,
+            tail_y_count,
+            pending_owner: _,
+            deletion_slot: _,
+            alter_delay: _,
+            delegates: _,
+            owner_threshold: _,
+            extensions_len: _,
+            y_transform: _,
+            valid_until: _,
+            validity_period: _,
+            last_update_slot: _,
+            last_update_unix: _,
+            revision: _,
+            has_pending_alter: _,
+            _reserved: _,
         } = self;
 
         *discriminator = *CURVE_DISCRIMINATOR;
@@ -126,43 +787,717 @@ impl Curve {
         *x0 = params.x0;
         *x_step = params.x_step;
         *y_count = params.y_count;
-        *decimals = params.decimals;
+        *x_decimals = params.x_decimals;
+        *y_decimals = params.y_decimals;
         *_padding1 = Zeroable::zeroed();
         *y = params.y;
+        if params.y_count as usize != MAX_Y_CNT {
+            *tail_y_count = 0;
+        }
+    }
+
+    /// Casts account data to Curve. Unlike [PodAccount::try_from_bytes_mut] reports wrong
+    /// discriminator and unsupported (e.g. not yet migrated) version as distinct errors. Data
+    /// of accounts grown by ResizeCurve is longer than [Curve::SIZE], the tail is not checked.
+    pub fn load_mut(data: &mut [u8]) -> CurvyResult<&mut Self> {
+        Ok(Self::load_mut_with_tail(data)?.0)
+    }
+
+    /// See [Curve::load_mut]
+    pub fn load(data: &[u8]) -> CurvyResult<&Self> {
+        Self::check_header(data)?;
+        Ok(Self::try_from_bytes(&data[..data.len().min(Self::SIZE)])?)
+    }
+
+    /// Same as [Curve::load_mut] returning the tail bytes following the Curve layout as well.
+    /// The tail ends where the extension region starts.
+    pub fn load_mut_with_tail(data: &mut [u8]) -> CurvyResult<(&mut Self, &mut [u8])> {
+        let (curve, tail, _) = Self::load_mut_with_extensions(data)?;
+        Ok((curve, tail))
+    }
+
+    /// Same as [Curve::load_mut_with_tail] returning the extension region as well, see
+    /// [crate::state::curve_extension]
+    pub fn load_mut_with_extensions(
+        data: &mut [u8],
+    ) -> CurvyResult<(&mut Self, &mut [u8], &mut [u8])> {
+        Self::check_header(data)?;
+        let (curve, rest) = data.split_at_mut(data.len().min(Self::SIZE));
+        let curve = Self::try_from_bytes_mut(curve)?;
+
+        let tail_len = rest
+            .len()
+            .checked_sub(curve.extensions_len())
+            .ok_or_else(|| {
+                msg!("extension region exceeds the account");
+                CurvyError::InvalidParams
+            })?;
+        let (tail, extensions) = rest.split_at_mut(tail_len);
+
+        Ok((curve, tail, extensions))
+    }
+
+    /// Extension region of the account `data`, empty when `data` is shorter than the curve says
+    pub fn extensions<'d>(&self, data: &'d [u8]) -> &'d [u8] {
+        data.len()
+            .checked_sub(self.extensions_len())
+            .filter(|start| *start >= Self::SIZE)
+            .map_or(&[], |start| &data[start..])
+    }
+
+    /// Size of the extension region, see [Curve::extensions_len]
+    pub fn extensions_len(&self) -> usize {
+        u16::from_le_bytes(self.extensions_len) as usize
+    }
+
+    fn check_header(data: &[u8]) -> CurvyResult<()> {
+        if let Some(found) = data.get(..CURVE_DISCRIMINATOR.len()) {
+            if found != CURVE_DISCRIMINATOR {
+                let mut discriminator = [0; 8];
+                discriminator.copy_from_slice(found);
+                return Err(CurvyError::WrongDiscriminator {
+                    expected: *CURVE_DISCRIMINATOR,
+                    found: discriminator,
+                });
+            }
+        }
+
+        match data.get(CURVE_DISCRIMINATOR.len()) {
+            Some(&found) if found != Self::VERSION => Err(CurvyError::UnsupportedVersion {
+                found,
+                max_supported: Self::VERSION,
+            }),
+            _ => Ok(()),
+        }
+    }
+
+    /// Same as [Curve::set_params] writing `y` directly from instruction data
+    pub fn set_params_raw(&mut self, params: &CurveParamsRaw) {
+        self.discriminator = *CURVE_DISCRIMINATOR;
+        self.version = Self::VERSION;
+        self._padding = Zeroable::zeroed();
+        self.name = params.name;
+        self.formula = params.formula;
+        self.x0 = params.x0();
+        self.x_step = params.x_step();
+        self.y_count = params.y_count;
+        self.x_decimals = params.x_decimals;
+        self.y_decimals = params.y_decimals;
+        self._padding1 = Zeroable::zeroed();
+
+        for (y, raw) in self.y.iter_mut().zip(&params.y) {
+            *y = CurveY::from_le_bytes(*raw);
+        }
+        if params.y_count as usize != MAX_Y_CNT {
+            self.tail_y_count = 0;
+        }
+    }
+
+    /// Initializes zeroed account data without `y` table, see [Curve::is_reserved]
+    pub fn init_reserved(
+        &mut self,
+        name: [u8; SYMBOL_MAX_SIZE],
+        formula: [u8; SYMBOL_MAX_SIZE],
+        owner: Pubkey,
+    ) {
+        self.discriminator = *CURVE_DISCRIMINATOR;
+        self.version = Self::VERSION;
+        self.name = name;
+        self.formula = formula;
+        self.owner = owner;
+        self.status = CurveStatus::Draft as u8;
+    }
+
+    /// Mint and kind the curve is associated with
+    pub fn asset(&self) -> Option<(Pubkey, u8)> {
+        (self.asset_mint != Pubkey::default()).then_some((self.asset_mint, self.asset_kind))
+    }
+
+    pub fn set_asset(&mut self, asset: Option<(Pubkey, u8)>) {
+        let (mint, kind) = asset.unwrap_or_default();
+        self.asset_mint = mint;
+        self.asset_kind = kind;
+    }
+
+    /// Fails with [CurvyError::CurveFrozen] when the curve is frozen, see [Curve::is_frozen]
+    pub fn check_not_frozen(&self) -> CurvyResult<()> {
+        if self.is_frozen != 0 {
+            msg!("curve is frozen");
+            return Err(CurvyError::CurveFrozen);
+        }
+
+        Ok(())
+    }
+
+    /// Lifecycle stage of the curve. Unknown values are read as retired, the most restrictive
+    /// stage.
+    pub fn status(&self) -> CurveStatus {
+        CurveStatus::try_from(self.status).unwrap_or(CurveStatus::Retired)
+    }
+
+    /// Curve is taken out of service by SetCurveStatus until it is resumed
+    pub fn is_paused(&self) -> bool {
+        self.status() == CurveStatus::Paused
+    }
+
+    /// Fails with [CurvyError::CurveNotActive] unless the curve may be consumed: active curves
+    /// may, deprecated ones still may but log a warning. Paused curves fail with
+    /// [CurvyError::CurveInactive]. For programs reading curves.
+    pub fn check_consumable(&self) -> CurvyResult<()> {
+        match self.status() {
+            CurveStatus::Active => Ok(()),
+            CurveStatus::Deprecated => {
+                msg!("curve is deprecated and is going to be retired");
+                Ok(())
+            }
+            CurveStatus::Paused => {
+                msg!("curve is paused");
+                Err(CurvyError::CurveInactive)
+            }
+            status => {
+                msg!("curve is {}", status);
+                Err(CurvyError::CurveNotActive {
+                    status: status as u8,
+                })
+            }
+        }
+    }
+
+    /// Owner proposed by ProposeOwner and not accepted yet
+    pub fn pending_owner(&self) -> Option<Pubkey> {
+        (self.pending_owner != Pubkey::default()).then_some(self.pending_owner)
+    }
+
+    /// Slot the curve was marked for deletion in by MarkForDeletion, none unless marked
+    pub fn deletion_slot(&self) -> Option<u64> {
+        marked_slot(self.deletion_slot)
+    }
+
+    /// First slot DeleteCurve accepts the curve marked for deletion in. The grace is
+    /// [DELETION_GRACE_SLOTS] or [Curve::alter_delay], whichever is longer.
+    pub fn deletable_from_slot(&self) -> Option<u64> {
+        let grace = DELETION_GRACE_SLOTS.max(self.alter_delay());
+        self.deletion_slot().map(|slot| slot.saturating_add(grace))
+    }
+
+    /// Marks the curve for deletion in `slot`, DeleteCurve accepts it from
+    /// [Curve::deletable_from_slot]. Curves which are not marked can be deleted at once, unless
+    /// they are timelocked.
+    pub fn mark_for_deletion(&mut self, slot: u64) -> CurvyResult<()> {
+        mark_for_deletion(&mut self.deletion_slot, slot)
+    }
+
+    /// Removes the mark set by [Curve::mark_for_deletion]
+    pub fn cancel_deletion(&mut self) -> CurvyResult<()> {
+        cancel_deletion(&mut self.deletion_slot)
+    }
+
+    /// Fails with [CurvyError::DeletionGracePeriod] while the curve marked for deletion waits
+    /// for its grace period to pass in `slot`. Timelocked curves which are not marked fail with
+    /// [CurvyError::AlterTimelocked]: deleted and created again at once, they would skip the
+    /// delay.
+    pub fn check_deletion_grace(&self, slot: u64) -> CurvyResult<()> {
+        check_deletion_grace(self.deletable_from_slot(), self.alter_delay(), slot)
+    }
+
+    /// Slots between ProposeAlter and ApplyAlter of the curve set by SetAlterDelay. Samples of
+    /// curves with non-zero delay are changed only this way, see [Curve::check_not_timelocked].
+    pub fn alter_delay(&self) -> u64 {
+        u64::from_le_bytes(self.alter_delay)
+    }
+
+    /// Sets [Curve::alter_delay]. It can only grow, so a compromised owner can not remove it.
+    pub fn set_alter_delay(&mut self, slots: u64) -> CurvyResult<()> {
+        if slots > MAX_ALTER_DELAY_SLOTS {
+            msg!("alter delay exceeds {} slots", MAX_ALTER_DELAY_SLOTS);
+            return Err(CurvyError::InvalidParams);
+        }
+        if slots < self.alter_delay() {
+            msg!(
+                "alter delay can not be decreased from {} slots",
+                self.alter_delay()
+            );
+            return Err(CurvyError::OperationCanNotBePerformed);
+        }
+
+        self.alter_delay = slots.to_le_bytes();
+
+        Ok(())
+    }
+
+    /// Fails with [CurvyError::AlterTimelocked] when samples of the curve are changed only by
+    /// ProposeAlter and ApplyAlter
+    pub fn check_not_timelocked(&self) -> CurvyResult<()> {
+        match self.alter_delay() {
+            0 => Ok(()),
+            delay => {
+                msg!(
+                    "curve alterations are timelocked for {} slots, use ProposeAlter",
+                    delay
+                );
+                Err(CurvyError::AlterTimelocked { delay })
+            }
+        }
+    }
+
+    /// Keys allowed to sign AlterCurve, UpdateCurveData and PatchY besides the owner. They can
+    /// not change anything else, e.g. delete or transfer the curve.
+    pub fn delegates(&self) -> impl Iterator<Item = &Pubkey> {
+        self.delegates
+            .iter()
+            .filter(|delegate| **delegate != Pubkey::default())
+    }
+
+    pub fn is_delegate(&self, key: &Pubkey) -> bool {
+        *key != Pubkey::default() && self.delegates.contains(key)
+    }
+
+    /// Adds `delegate` to [Curve::delegates], up to [MAX_CURVE_DELEGATES] of them
+    pub fn add_delegate(&mut self, delegate: Pubkey) -> CurvyResult<()> {
+        if delegate == Pubkey::default() || delegate == self.owner {
+            msg!("delegate must differ from the owner and the default key");
+            return Err(CurvyError::InvalidParams);
+        }
+        if self.is_delegate(&delegate) {
+            msg!("{} is a delegate already", delegate);
+            return Err(CurvyError::OperationCanNotBePerformed);
+        }
+
+        let Some(free) = self
+            .delegates
+            .iter_mut()
+            .find(|slot| **slot == Pubkey::default())
+        else {
+            msg!("curve has {} delegates already", MAX_CURVE_DELEGATES);
+            return Err(CurvyError::OperationCanNotBePerformed);
+        };
+        *free = delegate;
+
+        Ok(())
+    }
+
+    /// Removes `delegate` from [Curve::delegates]
+    pub fn remove_delegate(&mut self, delegate: &Pubkey) -> CurvyResult<()> {
+        if !self.is_delegate(delegate) {
+            msg!("{} is not a delegate", delegate);
+            return Err(CurvyError::OperationCanNotBePerformed);
+        }
+
+        for slot in self.delegates.iter_mut().filter(|slot| *slot == delegate) {
+            *slot = Pubkey::default();
+        }
+
+        Ok(())
+    }
+
+    /// Fails unless `signer` is the owner or one of [Curve::delegates], who may update samples.
+    /// Co-owned curves are updated by AlterCurve only, see [Curve::check_owner].
+    pub fn check_updater(&self, signer: &Pubkey) -> CurvyResult<()> {
+        self.check_single_owner()?;
+        if self.is_delegate(signer) {
+            return Ok(());
+        }
+
+        verify_key(signer, &self.owner, "owner")?;
+
+        Ok(())
+    }
+
+    /// Same as [Curve::check_updater] for setting the curve to `params`. Delegates update
+    /// samples only, so they fail unless `params` keep all but [Curve::y] and [Curve::y_count].
+    pub fn check_updater_params(&self, signer: &Pubkey, params: &CurveParams) -> CurvyResult<()> {
+        self.check_updater(signer)?;
+        if *signer == self.owner {
+            return Ok(());
+        }
+
+        let kept = |params: &CurveParams| {
+            (
+                params.name,
+                params.formula,
+                params.x0,
+                params.x_step,
+                params.x_decimals,
+                params.y_decimals,
+            )
+        };
+        if kept(params) != kept(&CurveParams::from(self)) {
+            msg!("delegates change y and y_count only");
+            return Err(CurvyError::OperationCanNotBePerformed);
+        }
+
+        Ok(())
+    }
+
+    /// Fails unless `signer` is the owner of the curve. Co-owned curves, those with non-zero
+    /// [Curve::owner_threshold], fail with [CurvyError::CoOwnersRequired]: only AlterCurve and
+    /// SetCurveOwners signed by the threshold of owners change them, see
+    /// [crate::state::curve_owners::CurveOwners].
+    pub fn check_owner(&self, signer: &Pubkey) -> CurvyResult<()> {
+        self.check_single_owner()?;
+        verify_key(signer, &self.owner, "owner")?;
+
+        Ok(())
+    }
+
+    /// Fails with [CurvyError::CoOwnersRequired] when the curve is co-owned
+    pub fn check_single_owner(&self) -> CurvyResult<()> {
+        match self.owner_threshold {
+            0 => Ok(()),
+            threshold => {
+                msg!("curve is co-owned, {} of its owners must sign", threshold);
+                Err(CurvyError::CoOwnersRequired { threshold })
+            }
+        }
+    }
+
+    /// Upgrades v1 account data already resized to [Curve::SIZE], or v2 account data. New
+    /// fields are expected to be zeroed by the resize, so the content stays in place, version is
+    /// bumped and `y_decimals` (padding before v3) gets the decimals both axes had.
+    pub fn upgrade(data: &mut [u8]) -> CurvyResult<&mut Self> {
+        let version = CURVE_DISCRIMINATOR.len();
+        let upgradable = matches!(data.get(version), Some(1 | 2));
+        if data.starts_with(CURVE_DISCRIMINATOR) && upgradable {
+            data[version] = Self::VERSION;
+            let curve = Self::load_mut(data)?;
+            curve.y_decimals = curve.x_decimals;
+            return Ok(curve);
+        }
+
+        Self::load_mut(data)
+    }
+
+    /// Copy of the curve in account `data` of any supported version. Accounts not yet migrated
+    /// by MigrateCurve are upgraded on a copy, see [Curve::upgrade], so off-chain reads keep
+    /// working across program upgrades. Instructions take migrated accounts only, see
+    /// [Curve::load].
+    pub fn read(data: &[u8]) -> CurvyResult<Self> {
+        let version = CURVE_DISCRIMINATOR.len();
+        if data.starts_with(CURVE_DISCRIMINATOR) && matches!(data.get(version), Some(1 | 2)) {
+            let mut upgraded = data[..data.len().min(Self::SIZE)].to_vec();
+            upgraded.resize(Self::SIZE, 0);
+            return Ok(*Self::upgrade(&mut upgraded)?);
+        }
+
+        Ok(*Self::load(data)?)
+    }
+
+    /// Starting X coordinate with its sign, see [Curve::x0_negative]
+    pub fn x0_signed(&self) -> i64 {
+        if self.x0_negative != 0 {
+            -(self.x0 as i64)
+        } else {
+            self.x0 as i64
+        }
+    }
+
+    /// Transform of stored `y` values. Unknown values of later program versions read as
+    /// [YTransform::Log10], so they are never taken for linear ones.
+    pub fn y_transform(&self) -> YTransform {
+        YTransform::try_from(self.y_transform).unwrap_or(YTransform::Log10)
+    }
+
+    /// Fails unless stored `y` values are the values of the curve, see [YTransform]. For
+    /// integer evaluation, which does not apply transforms.
+    pub fn check_linear_y(&self) -> CurvyResult<()> {
+        match self.y_transform() {
+            YTransform::Linear => Ok(()),
+            transform => {
+                msg!("curve stores {} of y, evaluate it off-chain", transform);
+                Err(CurvyError::InvalidParams)
+            }
+        }
+    }
+
+    /// Unix timestamp the curve is stale from, `None` when it does not expire. Consumers
+    /// reading the curve directly check it by [Curve::check_not_expired].
+    pub fn valid_until(&self) -> Option<i64> {
+        let valid_until = i64::from_le_bytes(self.valid_until);
+        (valid_until != 0).then_some(valid_until)
+    }
+
+    /// Seconds [Curve::refresh_validity] moves `valid_until` past the update time
+    pub fn validity_period(&self) -> u64 {
+        u64::from_le_bytes(self.validity_period)
+    }
+
+    /// Sets [Curve::valid_until] (zero for none) and [Curve::validity_period]
+    pub fn set_expiry(&mut self, valid_until: i64, validity_period: u64) -> CurvyResult<()> {
+        if valid_until < 0 || i64::try_from(validity_period).is_err() {
+            msg!("expiry must be a positive unix timestamp and period");
+            return Err(CurvyError::InvalidParams);
+        }
+
+        self.valid_until = valid_until.to_le_bytes();
+        self.validity_period = validity_period.to_le_bytes();
+
+        Ok(())
+    }
+
+    /// Moves `valid_until` [Curve::validity_period] seconds past `now` (unix timestamp) when
+    /// samples are updated. Curves without the period keep their `valid_until`.
+    pub fn refresh_validity(&mut self, now: i64) {
+        if let Some(valid_until) = i64::try_from(self.validity_period())
+            .ok()
+            .filter(|period| *period > 0)
+            .and_then(|period| now.checked_add(period))
+        {
+            self.valid_until = valid_until.to_le_bytes();
+        }
+    }
+
+    /// Slot the curve was created or its samples were last updated in, `None` for curves not
+    /// updated since the field was added
+    pub fn last_update_slot(&self) -> Option<u64> {
+        let slot = u64::from_le_bytes(self.last_update_slot);
+        (slot != 0).then_some(slot)
+    }
+
+    /// Unix timestamp of [Curve::last_update_slot]
+    pub fn last_update_unix(&self) -> Option<i64> {
+        self.last_update_slot()
+            .map(|_| i64::from_le_bytes(self.last_update_unix))
+    }
+
+    /// Number of alterations (AlterCurve, BatchAlter, UpdateCurveData, PatchY, ApplyAlter and the
+    /// setters of x0 sign, y sign and y transform) since the creation, or since the field was
+    /// added for older curves. Only grows, so clients detect concurrent updates by comparing it
+    /// with the revision they read.
+    pub fn revision(&self) -> u64 {
+        u64::from_le_bytes(self.revision)
+    }
+
+    /// Counts one more sample update, see [Curve::revision]
+    pub fn next_revision(&mut self) {
+        self.revision = self.revision().saturating_add(1).to_le_bytes();
+    }
+
+    /// Records the creation or sample update in `slot` at unix timestamp `unix`
+    pub fn set_last_update(&mut self, slot: u64, unix: i64) {
+        self.last_update_slot = slot.to_le_bytes();
+        self.last_update_unix = unix.to_le_bytes();
+    }
+
+    /// Fails with [CurvyError::CurveExpired] once unix timestamp `now` reaches `valid_until`
+    pub fn check_not_expired(&self, now: i64) -> CurvyResult<()> {
+        match self.valid_until() {
+            Some(valid_until) if now >= valid_until => {
+                msg!("curve expired at {}", valid_until);
+                Err(CurvyError::CurveExpired { valid_until })
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Value of sample `y` with its sign, see [Curve::y_signed]
+    pub fn y_value(&self, y: CurveY) -> i64 {
+        if self.y_signed != 0 {
+            y as i32 as i64
+        } else {
+            y as i64
+        }
+    }
+
+    /// First and last X of the grid with their signs, in curve units (scaled by decimals)
+    pub fn x_range_signed(&self) -> (i64, i64) {
+        let x_first = self.x0_signed();
+        let span = (self.y_count.max(1) as i64 - 1) * self.x_step as i64;
+
+        (x_first, x_first + span)
+    }
+
+    /// Size of the account holding `y_capacity` samples in total, see [Curve::tail_y_count]
+    pub fn size_for(y_capacity: usize) -> usize {
+        Self::SIZE + y_capacity.saturating_sub(MAX_Y_CNT) * std::mem::size_of::<CurveY>()
+    }
+
+    /// Number of samples the account of `data_len` bytes without extension region holds in
+    /// total
+    pub fn y_capacity(data_len: usize) -> usize {
+        MAX_Y_CNT + data_len.saturating_sub(Self::SIZE) / std::mem::size_of::<CurveY>()
+    }
+
+    /// [crate::events::y_hash] of the `y` table
+    pub fn y_hash(&self) -> [u8; 32] {
+        crate::events::y_hash(&self.y[..(self.y_count as usize).min(MAX_Y_CNT)])
+    }
+
+    /// Number of samples, `y` table and its tail
+    pub fn total_y_count(&self) -> usize {
+        self.y_count as usize + self.tail_y_count as usize
+    }
+
+    /// Samples following the `y` table, read from the account `data`. Empty when `data` is
+    /// shorter than the curve says.
+    pub fn tail_y(&self, data: &[u8]) -> Vec<CurveY> {
+        let len = self.tail_y_count as usize * std::mem::size_of::<CurveY>();
+
+        data.get(Self::SIZE..Self::SIZE + len)
+            .unwrap_or_default()
+            .chunks_exact(std::mem::size_of::<CurveY>())
+            .map(|chunk| CurveY::from_le_bytes(chunk.try_into().expect("chunk of CurveY size")))
+            .collect()
+    }
+
+    /// All `total_y_count` samples, see [Curve::tail_y]
+    pub fn samples(&self, data: &[u8]) -> Vec<CurveY> {
+        let mut samples = self.y[..(self.y_count as usize).min(MAX_Y_CNT)].to_vec();
+        samples.extend(self.tail_y(data));
+        samples
+    }
+
+    /// Writes samples `y` to the `tail` bytes (see [Curve::load_mut_with_tail]) starting at tail
+    /// sample `start` and cuts the tail after them
+    pub fn set_tail_y(&mut self, tail: &mut [u8], start: usize, y: &[CurveY]) -> CurvyResult<()> {
+        if !y.is_empty() && self.y_count as usize != MAX_Y_CNT {
+            msg!("y table must be full to have samples after it");
+            return Err(CurvyError::InvalidParams);
+        }
+
+        if start > self.tail_y_count as usize {
+            msg!(
+                "tail samples must be set without gaps, tail has {}",
+                self.tail_y_count
+            );
+            return Err(CurvyError::InvalidParams);
+        }
+
+        let end = start + y.len();
+        let capacity = tail.len() / std::mem::size_of::<CurveY>();
+        if end > capacity || end > MAX_TOTAL_Y_CNT - MAX_Y_CNT {
+            msg!(
+                "tail holds {} samples, grow the curve with ResizeCurve",
+                capacity
+            );
+            return Err(CurvyError::InvalidParams);
+        }
+
+        let chunks = tail.chunks_exact_mut(std::mem::size_of::<CurveY>());
+        for (chunk, y) in chunks.skip(start).zip(y) {
+            chunk.copy_from_slice(&y.to_le_bytes());
+        }
+        self.tail_y_count = end as u16;
+
+        self.check_total_dimensions()
+    }
+
+    /// Value of the curve with `samples` (see [Curve::samples]) in `x`, both in curve units
+    /// (scaled by decimals). Linear interpolation between neighbouring samples in integers,
+    /// rounded down, so it may differ from the exact value by less than one unit of `y`.
+    pub fn evaluate(&self, samples: &[CurveY], x: i64) -> CurvyResult<i64> {
+        let x0 = self.x0_signed() as i128;
+        let x_step = self.x_step as i128;
+        let offset = x as i128 - x0;
+
+        if x_step == 0 || offset < 0 || offset > (samples.len() as i128 - 1) * x_step {
+            msg!("x {} is out of curve range", x);
+            return Err(CurvyError::InvalidParams);
+        }
+
+        let idx = (offset / x_step) as usize;
+        let rem = offset % x_step;
+        let y_left = self.y_value(samples[idx]) as i128;
+        if rem == 0 {
+            return Ok(y_left as i64);
+        }
+
+        let y_right = self.y_value(samples[idx + 1]) as i128;
+        let y = y_left + ((y_right - y_left) * rem).div_euclid(x_step);
+
+        Ok(y as i64)
+    }
+
+    /// Overwrites `y` samples starting at `start`, all of them must lie within `y_count`
+    pub fn patch_y(&mut self, start: usize, values: &[CurveY]) -> CurvyResult<()> {
+        if values.is_empty() {
+            msg!("no samples to patch");
+            return Err(CurvyError::InvalidParams);
+        }
+
+        let end = start + values.len();
+        if end > self.y_count as usize {
+            msg!(
+                "samples {}..{} are out of y table of {} samples",
+                start,
+                end,
+                self.y_count
+            );
+            return Err(CurvyError::InvalidParams);
+        }
+
+        self.y[start..end].copy_from_slice(values);
+
+        Ok(())
+    }
+
+    /// Checks that the X grid of all samples fits, see [Curve::check_dimensions]
+    pub fn check_total_dimensions(&self) -> CurvyResult<()> {
+        Self::check_x_range(self.x0, self.x_step, self.total_y_count(), self.x_decimals)
+    }
+
+    /// Curve is created by ReserveCurve and has no samples yet
+    pub fn is_reserved(&self) -> bool {
+        self.y_count == 0
+    }
+
+    pub fn set_metadata(&mut self, name: [u8; SYMBOL_MAX_SIZE], formula: [u8; SYMBOL_MAX_SIZE]) {
+        self.name = name;
+        self.formula = formula;
     }
 
     /// Checks that x0, x_step, y_count are aligned with each other
     pub fn check_params(params: &CurveParams) -> CurvyResult<()> {
-        if params.x_step == 0 {
+        Self::check_dimensions(
+            params.x0,
+            params.x_step,
+            params.y_count,
+            params.x_decimals,
+            params.y_decimals,
+        )
+    }
+
+    /// See [Curve::check_params]
+    pub fn check_dimensions(
+        x0: CurveX,
+        x_step: CurveX,
+        y_count: u8,
+        x_decimals: u8,
+        y_decimals: u8,
+    ) -> CurvyResult<()> {
+        if x_step == 0 {
             msg!("x_step must be non zero");
             return Err(CurvyError::InvalidParams);
         }
 
-        if params.y_count == 0 {
+        if y_count == 0 {
             msg!("y_count must be non zero");
             return Err(CurvyError::InvalidParams);
         }
 
-        if params.decimals > 9 {
+        if x_decimals > 9 || y_decimals > 9 {
             msg!("decimals must be in range [0, 9]");
             return Err(CurvyError::InvalidParams);
         }
 
+        Self::check_x_range(x0, x_step, y_count as usize, x_decimals)
+    }
+
+    /// Checks that maximum X of `y_count` samples fits CurveX with given decimals
+    fn check_x_range(x0: CurveX, x_step: CurveX, y_count: usize, decimals: u8) -> CurvyResult<()> {
         // maximum X coordinate value should not be bigger then maximum value CurveX can hold with
         // given decimals
-        let max_x = Decimal::from_i128_with_scale(params.x0 as i128, params.decimals as u32)?
-            .checked_add(
-                Decimal::from_i128_with_scale(params.x_step as i128, params.decimals as u32)?
-                    .checked_mul(Decimal::from_i128_with_scale(params.y_count as i128, 0)?)?,
-            )?;
-        let u32_max = Decimal::from_i128_with_scale(u32::MAX as i128, params.decimals as u32)?;
+        let max_x = Decimal::from_i128_with_scale(x0 as i128, decimals as u32)?.checked_add(
+            Decimal::from_i128_with_scale(x_step as i128, decimals as u32)?
+                .checked_mul(Decimal::from_i128_with_scale(y_count as i128, 0)?)?,
+        )?;
+        let u32_max = Decimal::from_i128_with_scale(u32::MAX as i128, decimals as u32)?;
 
         if max_x > u32_max {
             msg!("Provided x0, x_step and y_count results in too big maximum X value {}. It should not exceed {}", max_x, u32_max);
             return Err(CurvyError::InvalidParams);
         }
 
-        if max_x <= Decimal::from_i128_with_scale(params.x0 as i128, 0)? {
+        if max_x <= Decimal::from_i128_with_scale(x0 as i128, 0)? {
             msg!("y_count*x_step results in very small number. Choose other value so x0 will be less then calculated max_x");
             return Err(CurvyError::InvalidParams);
         }
@@ -176,7 +1511,7 @@ impl PodAccount for Curve {
 
     type Version = u8;
 
-    const VERSION: Self::Version = 1;
+    const VERSION: Self::Version = curvy_spec::CURVE_VERSION;
 
     type InitParams = (/*params:*/ CurveParams, /*owner:*/ Pubkey);
 
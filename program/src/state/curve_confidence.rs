@@ -0,0 +1,83 @@
+use bytemuck::{Pod, Zeroable};
+use solana_program::pubkey::Pubkey;
+
+use texture_common::account::{PodAccount, PodAccountError};
+
+use crate::state::curve::MAX_Y_CNT;
+use crate::state::CURVE_CONFIDENCE_DISCRIMINATOR;
+
+pub const CURVE_CONFIDENCE_SEED: &[u8] = curvy_spec::CURVE_CONFIDENCE_SEED;
+
+/// Confidence of a sample in basis points of its `y`
+pub type CurveConfidenceBps = u16;
+
+static_assertions::const_assert_eq!(
+    CurveConfidence::SIZE,
+    std::mem::size_of::<CurveConfidence>()
+);
+static_assertions::const_assert_eq!(0, std::mem::size_of::<CurveConfidence>() % 8);
+static_assertions::const_assert_eq!(CurveConfidence::SIZE, curvy_spec::CURVE_CONFIDENCE_SIZE);
+
+/// Per-sample confidence of a curve, sharing its X grid. Like oracle price confidence, each value
+/// is the half-width of the interval the true `y` is expected in, so consumers may refuse values
+/// which are too uncertain. Lives at the address derived from the curve.
+#[derive(Debug, Copy, Clone, Pod, Zeroable)]
+#[repr(C)]
+pub struct CurveConfidence {
+    pub discriminator: [u8; 8],
+    pub version: u8,
+
+    pub _padding: [u8; 7],
+
+    /// Curve the confidence belongs to
+    pub curve: Pubkey,
+
+    /// Confidence of `y[i]` of the curve, in basis points of it
+    pub confidence: [CurveConfidenceBps; MAX_Y_CNT],
+
+    pub _padding2: [u8; 4],
+}
+
+impl CurveConfidence {
+    pub fn seeds(curve: &Pubkey) -> [&[u8]; 2] {
+        [CURVE_CONFIDENCE_SEED, curve.as_ref()]
+    }
+
+    /// Address and bump of the confidence account of `curve`
+    pub fn find_address(curve: &Pubkey) -> (Pubkey, u8) {
+        Pubkey::find_program_address(&Self::seeds(curve), &crate::ID)
+    }
+}
+
+impl PodAccount for CurveConfidence {
+    const DISCRIMINATOR: &'static [u8] = CURVE_CONFIDENCE_DISCRIMINATOR;
+
+    type Version = u8;
+
+    const VERSION: Self::Version = curvy_spec::CURVE_CONFIDENCE_VERSION;
+
+    type InitParams = Pubkey;
+
+    type InitError = PodAccountError;
+
+    fn discriminator(&self) -> &[u8] {
+        &self.discriminator
+    }
+
+    fn version(&self) -> Self::Version {
+        self.version
+    }
+
+    fn init_unckecked(&mut self, curve: Self::InitParams) -> Result<(), Self::InitError> {
+        *self = Self {
+            discriminator: *CURVE_CONFIDENCE_DISCRIMINATOR,
+            version: Self::VERSION,
+            _padding: Zeroable::zeroed(),
+            curve,
+            confidence: Zeroable::zeroed(),
+            _padding2: Zeroable::zeroed(),
+        };
+
+        Ok(())
+    }
+}
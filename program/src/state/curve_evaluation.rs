@@ -0,0 +1,87 @@
+use bytemuck::{Pod, Zeroable};
+use solana_program::pubkey::Pubkey;
+
+use texture_common::account::{PodAccount, PodAccountError};
+
+use crate::state::CURVE_EVALUATION_DISCRIMINATOR;
+
+static_assertions::const_assert_eq!(
+    CurveEvaluation::SIZE,
+    std::mem::size_of::<CurveEvaluation>()
+);
+static_assertions::const_assert_eq!(0, std::mem::size_of::<CurveEvaluation>() % 8);
+static_assertions::const_assert_eq!(CurveEvaluation::SIZE, curvy_spec::CURVE_EVALUATION_SIZE);
+
+/// Result of the last EvaluateCurve into this account, for consumers which CPI into the program
+/// instead of interpolating curves themselves. Created by the caller as a zeroed account owned
+/// by the program, the first EvaluateCurve sets its `authority`.
+#[derive(Debug, Copy, Clone, Pod, Zeroable)]
+#[repr(C)]
+pub struct CurveEvaluation {
+    pub discriminator: [u8; 8],
+    pub version: u8,
+
+    /// Decimals of `x`, those of the evaluated curve
+    pub x_decimals: u8,
+
+    /// Decimals of `y`, those of the evaluated curve
+    pub y_decimals: u8,
+
+    pub _padding: [u8; 5],
+
+    /// Signer required by EvaluateCurve into this account
+    pub authority: Pubkey,
+
+    /// Evaluated curve
+    pub curve: Pubkey,
+
+    /// Point the curve was evaluated in, scaled by `x_decimals`
+    pub x: i64,
+
+    /// Value of the curve in `x` scaled by `y_decimals` and rounded down, see
+    /// [Curve::evaluate](crate::state::curve::Curve::evaluate)
+    pub y: i64,
+
+    /// Slot of the evaluation
+    pub slot: u64,
+
+    pub _reserved: [u8; 32],
+}
+
+impl PodAccount for CurveEvaluation {
+    const DISCRIMINATOR: &'static [u8] = CURVE_EVALUATION_DISCRIMINATOR;
+
+    type Version = u8;
+
+    const VERSION: Self::Version = curvy_spec::CURVE_EVALUATION_VERSION;
+
+    type InitParams = Pubkey;
+
+    type InitError = PodAccountError;
+
+    fn discriminator(&self) -> &[u8] {
+        &self.discriminator
+    }
+
+    fn version(&self) -> Self::Version {
+        self.version
+    }
+
+    fn init_unckecked(&mut self, authority: Self::InitParams) -> Result<(), Self::InitError> {
+        *self = Self {
+            discriminator: *CURVE_EVALUATION_DISCRIMINATOR,
+            version: Self::VERSION,
+            x_decimals: 0,
+            y_decimals: 0,
+            _padding: Zeroable::zeroed(),
+            authority,
+            curve: Pubkey::default(),
+            x: 0,
+            y: 0,
+            slot: 0,
+            _reserved: Zeroable::zeroed(),
+        };
+
+        Ok(())
+    }
+}
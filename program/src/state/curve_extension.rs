@@ -0,0 +1,316 @@
+//! Extensions of Curve kept in the TLV region at the end of its account, after the tail
+//! samples. Each entry is a little-endian `u16` type and `u16` length followed by the value, so
+//! readers skip entries of types they do not know. New optional fields become extension types
+//! instead of another layout version. The region size is
+//! [crate::state::curve::Curve::extensions_len], SetCurveExtension writes it.
+
+use bytemuck::{Pod, Zeroable};
+use solana_program::msg;
+
+use crate::error::CurvyError;
+use crate::state::curve::SYMBOL_MAX_SIZE;
+use crate::state::utils::str_to_array;
+use crate::CurvyResult;
+
+pub use curvy_spec::MAX_CURVE_EXTENSIONS_SIZE;
+
+/// Size of the type and length of an entry
+pub const EXTENSION_HEADER_SIZE: usize = 4;
+
+/// Known extension types
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(u16)]
+pub enum ExtensionType {
+    Tags = curvy_spec::CURVE_EXTENSION_TAGS,
+    Guardrails = curvy_spec::CURVE_EXTENSION_GUARDRAILS,
+    Schedule = curvy_spec::CURVE_EXTENSION_SCHEDULE,
+}
+
+impl ExtensionType {
+    /// Size of the value of the type
+    pub fn value_size(self) -> usize {
+        match self {
+            ExtensionType::Tags => std::mem::size_of::<CurveTags>(),
+            ExtensionType::Guardrails => std::mem::size_of::<CurveGuardrails>(),
+            ExtensionType::Schedule => std::mem::size_of::<CurveSchedule>(),
+        }
+    }
+}
+
+impl TryFrom<u16> for ExtensionType {
+    type Error = CurvyError;
+
+    fn try_from(value: u16) -> Result<Self, Self::Error> {
+        match value {
+            curvy_spec::CURVE_EXTENSION_TAGS => Ok(ExtensionType::Tags),
+            curvy_spec::CURVE_EXTENSION_GUARDRAILS => Ok(ExtensionType::Guardrails),
+            curvy_spec::CURVE_EXTENSION_SCHEDULE => Ok(ExtensionType::Schedule),
+            _ => {
+                msg!("unknown extension type {}", value);
+                Err(CurvyError::InvalidParams)
+            }
+        }
+    }
+}
+
+impl std::fmt::Display for ExtensionType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ExtensionType::Tags => write!(f, "tags"),
+            ExtensionType::Guardrails => write!(f, "guardrails"),
+            ExtensionType::Schedule => write!(f, "schedule"),
+        }
+    }
+}
+
+impl std::str::FromStr for ExtensionType {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "tags" => Self::Tags,
+            "guardrails" => Self::Guardrails,
+            "schedule" => Self::Schedule,
+            _ => return Err(format!("unknown extension type `{s}`")),
+        })
+    }
+}
+
+/// Value of a known extension type
+pub trait CurveExtension: Pod {
+    const TYPE: ExtensionType;
+
+    /// Value bytes as SetCurveExtension takes them
+    fn to_value(&self) -> Vec<u8> {
+        bytemuck::bytes_of(self).to_vec()
+    }
+}
+
+/// Labels classifying the curve, e.g. `borrow-rate,usdc`. Zero-padded like the curve name.
+#[derive(Debug, Copy, Clone, Pod, Zeroable, PartialEq, Eq)]
+#[repr(C)]
+pub struct CurveTags {
+    pub tags: [u8; SYMBOL_MAX_SIZE],
+}
+
+impl CurveTags {
+    /// Tags of the `label`, at most [SYMBOL_MAX_SIZE] bytes
+    pub fn new(label: &str) -> CurvyResult<Self> {
+        if label.len() > SYMBOL_MAX_SIZE {
+            msg!("tags take at most {} bytes", SYMBOL_MAX_SIZE);
+            return Err(CurvyError::InvalidParams);
+        }
+
+        Ok(Self {
+            tags: str_to_array(label),
+        })
+    }
+
+    /// Comma-separated labels of the tags, e.g. `borrow-rate` and `usdc`
+    pub fn labels(&self) -> impl Iterator<Item = &str> {
+        let len = self
+            .tags
+            .iter()
+            .position(|byte| *byte == 0)
+            .unwrap_or(SYMBOL_MAX_SIZE);
+
+        std::str::from_utf8(&self.tags[..len])
+            .unwrap_or_default()
+            .split(',')
+            .map(str::trim)
+            .filter(|label| !label.is_empty())
+    }
+
+    pub fn contains(&self, label: &str) -> bool {
+        self.labels().any(|tag| tag == label)
+    }
+}
+
+impl CurveExtension for CurveTags {
+    const TYPE: ExtensionType = ExtensionType::Tags;
+}
+
+/// Bounds consumers keep values of the curve in, little-endian `i64` in `y` units (scaled by
+/// `y_decimals`)
+#[derive(Debug, Copy, Clone, Pod, Zeroable, PartialEq, Eq)]
+#[repr(C)]
+pub struct CurveGuardrails {
+    pub min_y: [u8; 8],
+    pub max_y: [u8; 8],
+}
+
+impl CurveGuardrails {
+    pub fn new(min_y: i64, max_y: i64) -> Self {
+        Self {
+            min_y: min_y.to_le_bytes(),
+            max_y: max_y.to_le_bytes(),
+        }
+    }
+
+    pub fn min_y(&self) -> i64 {
+        i64::from_le_bytes(self.min_y)
+    }
+
+    pub fn max_y(&self) -> i64 {
+        i64::from_le_bytes(self.max_y)
+    }
+
+    /// `y` moved into the bounds
+    pub fn clamp(&self, y: i64) -> i64 {
+        y.max(self.min_y()).min(self.max_y())
+    }
+}
+
+impl CurveExtension for CurveGuardrails {
+    const TYPE: ExtensionType = ExtensionType::Guardrails;
+}
+
+/// Period the curve is meant to be used in, little-endian unix timestamps. Zero `valid_from`
+/// or `valid_until` leaves the period open on that side.
+#[derive(Debug, Copy, Clone, Pod, Zeroable, PartialEq, Eq)]
+#[repr(C)]
+pub struct CurveSchedule {
+    pub valid_from: [u8; 8],
+    pub valid_until: [u8; 8],
+}
+
+impl CurveSchedule {
+    pub fn new(valid_from: i64, valid_until: i64) -> Self {
+        Self {
+            valid_from: valid_from.to_le_bytes(),
+            valid_until: valid_until.to_le_bytes(),
+        }
+    }
+
+    pub fn valid_from(&self) -> i64 {
+        i64::from_le_bytes(self.valid_from)
+    }
+
+    pub fn valid_until(&self) -> i64 {
+        i64::from_le_bytes(self.valid_until)
+    }
+
+    /// Whether unix timestamp `now` is in the period
+    pub fn is_valid_at(&self, now: i64) -> bool {
+        (self.valid_from() == 0 || now >= self.valid_from())
+            && (self.valid_until() == 0 || now < self.valid_until())
+    }
+}
+
+impl CurveExtension for CurveSchedule {
+    const TYPE: ExtensionType = ExtensionType::Schedule;
+}
+
+/// Entries of the extension `region` as raw types and values, unknown types included. Fails on
+/// an entry running past the region.
+pub fn entries(region: &[u8]) -> impl Iterator<Item = CurvyResult<(u16, &[u8])>> {
+    let mut rest = region;
+    std::iter::from_fn(move || {
+        if rest.is_empty() {
+            return None;
+        }
+
+        let Some((header, after)) = split_header(rest) else {
+            rest = &[];
+            msg!("extension entry header is cut");
+            return Some(Err(CurvyError::InvalidParams));
+        };
+        let (extension_type, len) = header;
+        if len > after.len() {
+            rest = &[];
+            msg!("extension {} runs past the region", extension_type);
+            return Some(Err(CurvyError::InvalidParams));
+        }
+
+        let (value, next) = after.split_at(len);
+        rest = next;
+        Some(Ok((extension_type, value)))
+    })
+}
+
+fn split_header(data: &[u8]) -> Option<((u16, usize), &[u8])> {
+    if data.len() < EXTENSION_HEADER_SIZE {
+        return None;
+    }
+
+    let extension_type = u16::from_le_bytes([data[0], data[1]]);
+    let len = u16::from_le_bytes([data[2], data[3]]) as usize;
+
+    Some(((extension_type, len), &data[EXTENSION_HEADER_SIZE..]))
+}
+
+/// Raw value of the extension of `extension_type` in the `region`
+pub fn find(region: &[u8], extension_type: u16) -> CurvyResult<Option<&[u8]>> {
+    for entry in entries(region) {
+        let (found, value) = entry?;
+        if found == extension_type {
+            return Ok(Some(value));
+        }
+    }
+
+    Ok(None)
+}
+
+/// Value of extension `E` in the `region`, `None` when the curve does not have it
+pub fn get<E: CurveExtension>(region: &[u8]) -> CurvyResult<Option<E>> {
+    let Some(value) = find(region, E::TYPE as u16)? else {
+        return Ok(None);
+    };
+
+    match bytemuck::try_pod_read_unaligned(value) {
+        Ok(extension) => Ok(Some(extension)),
+        Err(err) => {
+            msg!("extension {} has {} bytes", E::TYPE, value.len());
+            Err(CurvyError::Serialize(err.into()))
+        }
+    }
+}
+
+/// Extension `region` with the value of `extension_type` replaced or appended, or removed when
+/// `value` is empty. Only known types of their value size are written, unknown entries of the
+/// region are kept.
+pub fn with_value(region: &[u8], extension_type: u16, value: &[u8]) -> CurvyResult<Vec<u8>> {
+    let known = ExtensionType::try_from(extension_type)?;
+    if !value.is_empty() && value.len() != known.value_size() {
+        msg!(
+            "extension {} takes {} bytes, {} given",
+            known,
+            known.value_size(),
+            value.len()
+        );
+        return Err(CurvyError::InvalidParams);
+    }
+
+    let mut updated = Vec::with_capacity(region.len() + EXTENSION_HEADER_SIZE + value.len());
+    for entry in entries(region) {
+        let (found, found_value) = entry?;
+        if found != extension_type {
+            push_entry(&mut updated, found, found_value);
+        }
+    }
+    if !value.is_empty() {
+        push_entry(&mut updated, extension_type, value);
+    }
+
+    if updated.len() > MAX_CURVE_EXTENSIONS_SIZE {
+        msg!(
+            "extensions take {} bytes, max {}",
+            updated.len(),
+            MAX_CURVE_EXTENSIONS_SIZE
+        );
+        return Err(CurvyError::InvalidParams);
+    }
+
+    Ok(updated)
+}
+
+/// Same as [with_value] for extension `E`
+pub fn with<E: CurveExtension>(region: &[u8], extension: &E) -> CurvyResult<Vec<u8>> {
+    with_value(region, E::TYPE as u16, &extension.to_value())
+}
+
+fn push_entry(region: &mut Vec<u8>, extension_type: u16, value: &[u8]) {
+    region.extend_from_slice(&extension_type.to_le_bytes());
+    region.extend_from_slice(&(value.len() as u16).to_le_bytes());
+    region.extend_from_slice(value);
+}
@@ -0,0 +1,149 @@
+use bytemuck::{Pod, Zeroable};
+use solana_program::msg;
+use solana_program::pubkey::Pubkey;
+
+use texture_common::account::{PodAccount, PodAccountError};
+
+use crate::error::CurvyError;
+use crate::state::CURVE_OWNERS_DISCRIMINATOR;
+use crate::CurvyResult;
+
+pub use curvy_spec::MAX_CURVE_OWNERS;
+
+pub const CURVE_OWNERS_SEED: &[u8] = curvy_spec::CURVE_OWNERS_SEED;
+
+static_assertions::const_assert_eq!(CurveOwners::SIZE, std::mem::size_of::<CurveOwners>());
+static_assertions::const_assert_eq!(0, std::mem::size_of::<CurveOwners>() % 8);
+static_assertions::const_assert_eq!(CurveOwners::SIZE, curvy_spec::CURVE_OWNERS_SIZE);
+
+/// Owners of a co-owned curve, [crate::state::curve::Curve::owner_threshold] of them must sign
+/// AlterCurve and SetCurveOwners of the curve. Lives at the address derived from the curve,
+/// created and closed by SetCurveOwners.
+#[derive(Debug, Copy, Clone, Pod, Zeroable)]
+#[repr(C)]
+pub struct CurveOwners {
+    pub discriminator: [u8; 8],
+    pub version: u8,
+
+    pub _padding: [u8; 7],
+
+    /// Curve the owners own
+    pub curve: Pubkey,
+
+    /// Owner keys, the curve `owner` among them. Zero keys are free slots.
+    pub owners: [Pubkey; MAX_CURVE_OWNERS],
+
+    pub _reserved: [u8; 32],
+}
+
+impl CurveOwners {
+    pub fn seeds(curve: &Pubkey) -> [&[u8]; 2] {
+        [CURVE_OWNERS_SEED, curve.as_ref()]
+    }
+
+    /// Address and bump of the curve owners account of `curve`
+    pub fn find_address(curve: &Pubkey) -> (Pubkey, u8) {
+        Pubkey::find_program_address(&Self::seeds(curve), &crate::ID)
+    }
+
+    pub fn owners(&self) -> impl Iterator<Item = &Pubkey> {
+        self.owners
+            .iter()
+            .filter(|owner| **owner != Pubkey::default())
+    }
+
+    pub fn is_owner(&self, key: &Pubkey) -> bool {
+        *key != Pubkey::default() && self.owners.contains(key)
+    }
+
+    /// Replaces the owners. Fails unless `threshold` is from 1 to the number of `owners`, which
+    /// are distinct non-zero keys, at most [MAX_CURVE_OWNERS] of them.
+    pub fn set_owners(&mut self, owners: &[Pubkey], threshold: u8) -> CurvyResult<()> {
+        if owners.len() > MAX_CURVE_OWNERS {
+            msg!("{} owners exceed max {}", owners.len(), MAX_CURVE_OWNERS);
+            return Err(CurvyError::InvalidParams);
+        }
+        if threshold == 0 || threshold as usize > owners.len() {
+            msg!(
+                "threshold {} must be from 1 to {} owners",
+                threshold,
+                owners.len()
+            );
+            return Err(CurvyError::InvalidParams);
+        }
+        for (idx, owner) in owners.iter().enumerate() {
+            if *owner == Pubkey::default() || owners[..idx].contains(owner) {
+                msg!("owner {} is zero key or listed twice", owner);
+                return Err(CurvyError::InvalidParams);
+            }
+        }
+
+        self.owners = [Pubkey::default(); MAX_CURVE_OWNERS];
+        self.owners[..owners.len()].copy_from_slice(owners);
+
+        Ok(())
+    }
+
+    /// Fails unless `signers` are owners and at least `threshold` distinct ones. Fails with
+    /// [CurvyError::OwnerMismatch] on a signer which is not an owner, so no account passed as a
+    /// signer is ignored.
+    pub fn check_signers<'k>(
+        &self,
+        signers: impl IntoIterator<Item = &'k Pubkey>,
+        threshold: u8,
+    ) -> CurvyResult<()> {
+        let mut signed = [false; MAX_CURVE_OWNERS];
+        for signer in signers {
+            let Some(idx) = self
+                .owners
+                .iter()
+                .position(|owner| owner == signer && *owner != Pubkey::default())
+            else {
+                msg!("{} is not an owner of the curve", signer);
+                return Err(CurvyError::OwnerMismatch);
+            };
+            signed[idx] = true;
+        }
+
+        let count = signed.iter().filter(|signed| **signed).count();
+        if count < threshold as usize {
+            msg!("{} of {} required owners signed", count, threshold);
+            return Err(CurvyError::CoOwnersRequired { threshold });
+        }
+
+        Ok(())
+    }
+}
+
+impl PodAccount for CurveOwners {
+    const DISCRIMINATOR: &'static [u8] = CURVE_OWNERS_DISCRIMINATOR;
+
+    type Version = u8;
+
+    const VERSION: Self::Version = curvy_spec::CURVE_OWNERS_VERSION;
+
+    type InitParams = Pubkey;
+
+    type InitError = PodAccountError;
+
+    fn discriminator(&self) -> &[u8] {
+        &self.discriminator
+    }
+
+    fn version(&self) -> Self::Version {
+        self.version
+    }
+
+    fn init_unckecked(&mut self, curve: Self::InitParams) -> Result<(), Self::InitError> {
+        *self = Self {
+            discriminator: *CURVE_OWNERS_DISCRIMINATOR,
+            version: Self::VERSION,
+            _padding: Zeroable::zeroed(),
+            curve,
+            owners: [Pubkey::default(); MAX_CURVE_OWNERS],
+            _reserved: [0; 32],
+        };
+
+        Ok(())
+    }
+}
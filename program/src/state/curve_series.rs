@@ -0,0 +1,107 @@
+use bytemuck::{Pod, Zeroable};
+use solana_program::pubkey::Pubkey;
+
+use texture_common::account::{PodAccount, PodAccountError};
+
+use crate::state::curve::{CurveY, MAX_Y_CNT};
+use crate::state::CURVE_SERIES_DISCRIMINATOR;
+
+pub const CURVE_SERIES_SEED: &[u8] = curvy_spec::CURVE_SERIES_SEED;
+
+/// Max number of `y` series of a curve, including its own `y` table
+pub const MAX_SERIES: usize = curvy_spec::MAX_SERIES;
+
+/// Max number of series kept in [CurveSeries]
+pub const MAX_EXTRA_SERIES: usize = curvy_spec::MAX_EXTRA_SERIES;
+
+static_assertions::const_assert_eq!(CurveSeries::SIZE, std::mem::size_of::<CurveSeries>());
+static_assertions::const_assert_eq!(0, std::mem::size_of::<CurveSeries>() % 8);
+static_assertions::const_assert_eq!(CurveSeries::SIZE, curvy_spec::CURVE_SERIES_SIZE);
+
+/// Additional `y` tables of a curve sharing its X grid (`x0`, `x_step`, `y_count`, `decimals`),
+/// e.g. lower and upper bands around the curve. Lives at the address derived from the curve.
+///
+/// Series are numbered from 1, series 0 is the `y` table of the curve itself.
+#[derive(Debug, Copy, Clone, Pod, Zeroable)]
+#[repr(C)]
+pub struct CurveSeries {
+    pub discriminator: [u8; 8],
+    pub version: u8,
+
+    /// Number of series set, see [crate::state::curve::Curve::series_count]
+    pub count: u8,
+
+    pub _padding: [u8; 6],
+
+    /// Curve the series belong to
+    pub curve: Pubkey,
+
+    /// `y` tables of series 1..=count
+    pub y: [[CurveY; MAX_Y_CNT]; MAX_EXTRA_SERIES],
+}
+
+impl CurveSeries {
+    pub fn seeds(curve: &Pubkey) -> [&[u8]; 2] {
+        [CURVE_SERIES_SEED, curve.as_ref()]
+    }
+
+    /// Address and bump of the series account of `curve`
+    pub fn find_address(curve: &Pubkey) -> (Pubkey, u8) {
+        Pubkey::find_program_address(&Self::seeds(curve), &crate::ID)
+    }
+
+    /// `y` table of series `idx` (from 1) when it is set
+    pub fn series(&self, idx: u8) -> Option<&[CurveY; MAX_Y_CNT]> {
+        if idx == 0 || idx > self.count {
+            return None;
+        }
+
+        self.y.get(idx as usize - 1)
+    }
+
+    /// Sets `y` table of series `idx` (from 1). Series must be set in order, so returns `false`
+    /// when `idx` is out of range or previous series is not set.
+    pub fn set_series(&mut self, idx: u8, y: [CurveY; MAX_Y_CNT]) -> bool {
+        if idx == 0 || idx as usize > MAX_EXTRA_SERIES || idx > self.count + 1 {
+            return false;
+        }
+
+        self.y[idx as usize - 1] = y;
+        self.count = self.count.max(idx);
+
+        true
+    }
+}
+
+impl PodAccount for CurveSeries {
+    const DISCRIMINATOR: &'static [u8] = CURVE_SERIES_DISCRIMINATOR;
+
+    type Version = u8;
+
+    const VERSION: Self::Version = curvy_spec::CURVE_SERIES_VERSION;
+
+    type InitParams = Pubkey;
+
+    type InitError = PodAccountError;
+
+    fn discriminator(&self) -> &[u8] {
+        &self.discriminator
+    }
+
+    fn version(&self) -> Self::Version {
+        self.version
+    }
+
+    fn init_unckecked(&mut self, curve: Self::InitParams) -> Result<(), Self::InitError> {
+        *self = Self {
+            discriminator: *CURVE_SERIES_DISCRIMINATOR,
+            version: Self::VERSION,
+            count: 0,
+            _padding: Zeroable::zeroed(),
+            curve,
+            y: Zeroable::zeroed(),
+        };
+
+        Ok(())
+    }
+}
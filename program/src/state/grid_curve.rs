@@ -0,0 +1,272 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use bytemuck::{Pod, Zeroable};
+use solana_program::msg;
+use solana_program::pubkey::Pubkey;
+
+use texture_common::account::{PodAccount, PodAccountError};
+
+use crate::error::CurvyError;
+use crate::state::curve::{
+    cancel_deletion, check_deletion_grace, mark_for_deletion, marked_slot, CurveStatus, CurveX,
+    CurveY, DELETION_GRACE_SLOTS, SYMBOL_MAX_SIZE,
+};
+use crate::state::GRID_CURVE_DISCRIMINATOR;
+use crate::CurvyResult;
+
+/// Twice less samples than [crate::state::curve::MAX_Y_CNT], so `x` and `y` tables take the
+/// space of the curve `y` table and grid curve params still fit in one TX.
+pub const MAX_GRID_Y_CNT: usize = curvy_spec::MAX_GRID_Y_CNT;
+
+/// Space left in GridCurve for future fields
+pub const GRID_CURVE_RESERVED_SIZE: usize = curvy_spec::GRID_CURVE_RESERVED_SIZE;
+
+static_assertions::const_assert_eq!(GridCurve::SIZE, std::mem::size_of::<GridCurve>());
+static_assertions::const_assert_eq!(0, std::mem::size_of::<GridCurve>() % 8);
+static_assertions::const_assert_eq!(GridCurve::SIZE, curvy_spec::GRID_CURVE_SIZE);
+
+#[cfg_attr(feature = "with-serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(BorshSerialize, BorshDeserialize, Debug, Copy, Clone)]
+pub struct GridCurveParams {
+    #[cfg_attr(
+        feature = "with-serde",
+        serde(with = "super::utils::array_as_str_serde")
+    )]
+    pub name: [u8; SYMBOL_MAX_SIZE],
+    #[cfg_attr(
+        feature = "with-serde",
+        serde(with = "super::utils::array_as_str_serde")
+    )]
+    pub formula: [u8; SYMBOL_MAX_SIZE],
+    /// Number of samples in `x` and `y` arrays
+    pub y_count: u8,
+    /// Precision of x and y
+    pub decimals: u8,
+    /// Array of strictly increasing `x` values
+    #[cfg_attr(
+        feature = "with-serde",
+        serde(with = "serde_with::As::<[serde_with::Same; MAX_GRID_Y_CNT]>")
+    )]
+    pub x: [CurveX; MAX_GRID_Y_CNT],
+    /// Array of `y` values in points of `x`
+    #[cfg_attr(
+        feature = "with-serde",
+        serde(with = "serde_with::As::<[serde_with::Same; MAX_GRID_Y_CNT]>")
+    )]
+    pub y: [CurveY; MAX_GRID_Y_CNT],
+}
+
+impl GridCurveParams {
+    pub fn new(
+        name: &str,
+        formula: &str,
+        y_count: u8,
+        decimals: u8,
+        x: [CurveX; MAX_GRID_Y_CNT],
+        y: [CurveY; MAX_GRID_Y_CNT],
+    ) -> Self {
+        Self {
+            name: super::utils::str_to_array(name),
+            formula: super::utils::str_to_array(formula),
+            y_count,
+            decimals,
+            x,
+            y,
+        }
+    }
+
+    /// Checks sizes and decimals as [crate::state::curve::Curve::check_params] does and that
+    /// `x` values are strictly increasing
+    pub fn check(&self) -> CurvyResult<()> {
+        if self.y_count == 0 {
+            msg!("y_count must be non zero");
+            return Err(CurvyError::InvalidParams);
+        }
+
+        if self.y_count as usize > MAX_GRID_Y_CNT {
+            msg!("y_count must not exceed {}", MAX_GRID_Y_CNT);
+            return Err(CurvyError::InvalidParams);
+        }
+
+        if self.decimals > 9 {
+            msg!("decimals must be in range [0, 9]");
+            return Err(CurvyError::InvalidParams);
+        }
+
+        let x = &self.x[..self.y_count as usize];
+        if let Some(idx) = x.windows(2).position(|pair| pair[0] >= pair[1]) {
+            msg!(
+                "x must be strictly increasing, x[{}] >= x[{}]",
+                idx,
+                idx + 1
+            );
+            return Err(CurvyError::InvalidParams);
+        }
+
+        Ok(())
+    }
+}
+
+impl From<&GridCurve> for GridCurveParams {
+    fn from(curve: &GridCurve) -> Self {
+        Self {
+            name: curve.name,
+            formula: curve.formula,
+            y_count: curve.y_count,
+            decimals: curve.decimals,
+            x: curve.x,
+            y: curve.y,
+        }
+    }
+}
+
+/// Curve sampled in explicit `x` points instead of the uniform `x0 + i * x_step` grid of
+/// [crate::state::curve::Curve], so samples can be dense where the curve bends and sparse where
+/// it is flat. Can not be associated with assets nor have series or confidence.
+#[derive(Debug, Copy, Clone, Pod, Zeroable)]
+#[repr(C)]
+pub struct GridCurve {
+    pub discriminator: [u8; 8],
+    pub version: u8,
+
+    pub _padding: [u8; 7],
+
+    /// a human-readable name
+    pub name: [u8; SYMBOL_MAX_SIZE],
+
+    /// a human-readable formula
+    pub formula: [u8; SYMBOL_MAX_SIZE],
+
+    /// authority who has full rights to manage that account
+    pub owner: Pubkey,
+
+    /// Number of samples in `x` and `y` arrays
+    pub y_count: u8,
+
+    /// Decimals number for x, y.
+    pub decimals: u8,
+
+    /// Lifecycle stage, see [CurveStatus] and [GridCurve::status]. Zero, i.e. active, for curves
+    /// created before.
+    pub status: u8,
+
+    /// 1 when the curve is frozen by FreezeCurve: its samples can not be changed and the curve
+    /// can not be deleted. Irreversible.
+    pub is_frozen: u8,
+
+    pub _padding1: [u8; 4],
+
+    /// Array of strictly increasing `x` values
+    pub x: [CurveX; MAX_GRID_Y_CNT],
+
+    /// Array of `y` values
+    pub y: [CurveY; MAX_GRID_Y_CNT],
+
+    /// Little-endian slot of MarkForDeletion, zeroed when the curve is not marked. See
+    /// [GridCurve::deletion_slot].
+    pub deletion_slot: [u8; 8],
+
+    /// Zeroed, for future fields
+    pub _reserved: [u8; GRID_CURVE_RESERVED_SIZE],
+}
+
+impl GridCurve {
+    pub fn set_params(&mut self, params: GridCurveParams) {
+        self.name = params.name;
+        self.formula = params.formula;
+        self.y_count = params.y_count;
+        self.decimals = params.decimals;
+        self.x = params.x;
+        self.y = params.y;
+    }
+
+    /// [crate::events::y_hash] of the `y` table
+    pub fn y_hash(&self) -> [u8; 32] {
+        crate::events::y_hash(&self.y[..(self.y_count as usize).min(MAX_GRID_Y_CNT)])
+    }
+
+    /// Fails with [CurvyError::CurveFrozen] when the curve is frozen, see [GridCurve::is_frozen]
+    pub fn check_not_frozen(&self) -> CurvyResult<()> {
+        if self.is_frozen != 0 {
+            msg!("curve is frozen");
+            return Err(CurvyError::CurveFrozen);
+        }
+
+        Ok(())
+    }
+
+    /// Lifecycle stage of the curve, same as [crate::state::curve::Curve::status]
+    pub fn status(&self) -> CurveStatus {
+        CurveStatus::try_from(self.status).unwrap_or(CurveStatus::Retired)
+    }
+
+    /// Slot the curve was marked for deletion in by MarkForDeletion, none unless marked
+    pub fn deletion_slot(&self) -> Option<u64> {
+        marked_slot(self.deletion_slot)
+    }
+
+    /// First slot DeleteCurve accepts the curve marked for deletion in. Without an alter delay
+    /// the grace is [DELETION_GRACE_SLOTS].
+    pub fn deletable_from_slot(&self) -> Option<u64> {
+        self.deletion_slot()
+            .map(|slot| slot.saturating_add(DELETION_GRACE_SLOTS))
+    }
+
+    /// Marks the curve for deletion in `slot`, same as
+    /// [crate::state::curve::Curve::mark_for_deletion]
+    pub fn mark_for_deletion(&mut self, slot: u64) -> CurvyResult<()> {
+        mark_for_deletion(&mut self.deletion_slot, slot)
+    }
+
+    /// Removes the mark set by [GridCurve::mark_for_deletion]
+    pub fn cancel_deletion(&mut self) -> CurvyResult<()> {
+        cancel_deletion(&mut self.deletion_slot)
+    }
+
+    /// Fails with [CurvyError::DeletionGracePeriod] while the curve marked for deletion waits
+    /// for its grace period to pass in `slot`
+    pub fn check_deletion_grace(&self, slot: u64) -> CurvyResult<()> {
+        check_deletion_grace(self.deletable_from_slot(), 0, slot)
+    }
+}
+
+impl PodAccount for GridCurve {
+    const DISCRIMINATOR: &'static [u8] = GRID_CURVE_DISCRIMINATOR;
+
+    type Version = u8;
+
+    const VERSION: Self::Version = curvy_spec::GRID_CURVE_VERSION;
+
+    type InitParams = (/*params:*/ GridCurveParams, /*owner:*/ Pubkey);
+
+    type InitError = PodAccountError;
+
+    fn discriminator(&self) -> &[u8] {
+        &self.discriminator
+    }
+
+    fn version(&self) -> Self::Version {
+        self.version
+    }
+
+    fn init_unckecked(&mut self, (params, owner): Self::InitParams) -> Result<(), Self::InitError> {
+        *self = Self {
+            discriminator: *GRID_CURVE_DISCRIMINATOR,
+            version: Self::VERSION,
+            _padding: Zeroable::zeroed(),
+            name: params.name,
+            formula: params.formula,
+            owner,
+            y_count: params.y_count,
+            decimals: params.decimals,
+            status: CurveStatus::Active as u8,
+            is_frozen: 0,
+            _padding1: Zeroable::zeroed(),
+            x: params.x,
+            y: params.y,
+            deletion_slot: [0; 8],
+            _reserved: Zeroable::zeroed(),
+        };
+
+        Ok(())
+    }
+}
@@ -1,6 +1,118 @@
 pub use texture_common::account as texture_account;
 
+pub mod asset_link;
+pub mod config;
 pub mod curve;
+pub mod curve_confidence;
+pub mod curve_evaluation;
+pub mod curve_extension;
+pub mod curve_owners;
+pub mod curve_series;
+pub mod grid_curve;
+pub mod name_link;
+pub mod pending_alter;
 pub mod utils;
+pub mod wide_curve;
 
-pub const CURVE_DISCRIMINATOR: &[u8; 8] = b"CURVE___";
+pub use curvy_spec::{
+    ASSET_LINK_DISCRIMINATOR, CONFIG_DISCRIMINATOR, CURVE_CONFIDENCE_DISCRIMINATOR,
+    CURVE_DISCRIMINATOR, CURVE_EVALUATION_DISCRIMINATOR, CURVE_OWNERS_DISCRIMINATOR,
+    CURVE_SERIES_DISCRIMINATOR, GRID_CURVE_DISCRIMINATOR, NAME_LINK_DISCRIMINATOR,
+    PENDING_ALTER_DISCRIMINATOR, WIDE_CURVE_DISCRIMINATOR,
+};
+
+/// Kind of Curvy account recognized by its discriminator together with layout version
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum AccountKind {
+    Curve { version: u8 },
+    AssetLink { version: u8 },
+    CurveSeries { version: u8 },
+    CurveConfidence { version: u8 },
+    WideCurve { version: u8 },
+    GridCurve { version: u8 },
+    CurveEvaluation { version: u8 },
+    PendingAlter { version: u8 },
+    Config { version: u8 },
+    CurveOwners { version: u8 },
+    NameLink { version: u8 },
+}
+
+impl AccountKind {
+    pub fn version(&self) -> u8 {
+        match *self {
+            AccountKind::Curve { version }
+            | AccountKind::AssetLink { version }
+            | AccountKind::CurveSeries { version }
+            | AccountKind::CurveConfidence { version }
+            | AccountKind::WideCurve { version }
+            | AccountKind::GridCurve { version }
+            | AccountKind::CurveEvaluation { version }
+            | AccountKind::PendingAlter { version }
+            | AccountKind::Config { version }
+            | AccountKind::CurveOwners { version }
+            | AccountKind::NameLink { version } => version,
+        }
+    }
+}
+
+impl std::fmt::Display for AccountKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AccountKind::Curve { version } => write!(f, "Curve v{version}"),
+            AccountKind::AssetLink { version } => write!(f, "AssetLink v{version}"),
+            AccountKind::CurveSeries { version } => write!(f, "CurveSeries v{version}"),
+            AccountKind::CurveConfidence { version } => write!(f, "CurveConfidence v{version}"),
+            AccountKind::WideCurve { version } => write!(f, "WideCurve v{version}"),
+            AccountKind::GridCurve { version } => write!(f, "GridCurve v{version}"),
+            AccountKind::CurveEvaluation { version } => write!(f, "CurveEvaluation v{version}"),
+            AccountKind::PendingAlter { version } => write!(f, "PendingAlter v{version}"),
+            AccountKind::Config { version } => write!(f, "Config v{version}"),
+            AccountKind::CurveOwners { version } => write!(f, "CurveOwners v{version}"),
+            AccountKind::NameLink { version } => write!(f, "NameLink v{version}"),
+        }
+    }
+}
+
+/// Inspects the first 8 bytes of account data against known discriminators. Version byte
+/// follows the discriminator in all Curvy accounts.
+pub fn probe(data: &[u8]) -> Option<AccountKind> {
+    let discriminator = data.get(..8)?;
+    let version = *data.get(8)?;
+
+    if discriminator == CURVE_DISCRIMINATOR {
+        return Some(AccountKind::Curve { version });
+    }
+    if discriminator == ASSET_LINK_DISCRIMINATOR {
+        return Some(AccountKind::AssetLink { version });
+    }
+    if discriminator == CURVE_SERIES_DISCRIMINATOR {
+        return Some(AccountKind::CurveSeries { version });
+    }
+    if discriminator == CURVE_CONFIDENCE_DISCRIMINATOR {
+        return Some(AccountKind::CurveConfidence { version });
+    }
+    if discriminator == WIDE_CURVE_DISCRIMINATOR {
+        return Some(AccountKind::WideCurve { version });
+    }
+    if discriminator == GRID_CURVE_DISCRIMINATOR {
+        return Some(AccountKind::GridCurve { version });
+    }
+    if discriminator == CURVE_EVALUATION_DISCRIMINATOR {
+        return Some(AccountKind::CurveEvaluation { version });
+    }
+    if discriminator == PENDING_ALTER_DISCRIMINATOR {
+        return Some(AccountKind::PendingAlter { version });
+    }
+    if discriminator == CONFIG_DISCRIMINATOR {
+        return Some(AccountKind::Config { version });
+    }
+    if discriminator == CURVE_OWNERS_DISCRIMINATOR {
+        return Some(AccountKind::CurveOwners { version });
+    }
+    if discriminator == NAME_LINK_DISCRIMINATOR {
+        return Some(AccountKind::NameLink { version });
+    }
+
+    None
+}
@@ -0,0 +1,104 @@
+use bytemuck::{Pod, Zeroable};
+use solana_program::msg;
+use solana_program::pubkey::Pubkey;
+
+use texture_common::account::{PodAccount, PodAccountError};
+
+use crate::error::CurvyError;
+use crate::state::curve::SYMBOL_MAX_SIZE;
+use crate::state::NAME_LINK_DISCRIMINATOR;
+use crate::CurvyResult;
+
+pub const NAME_LINK_SEED: &[u8] = curvy_spec::NAME_LINK_SEED;
+
+static_assertions::const_assert_eq!(NameLink::SIZE, std::mem::size_of::<NameLink>());
+static_assertions::const_assert_eq!(0, std::mem::size_of::<NameLink>() % 8);
+static_assertions::const_assert_eq!(NameLink::SIZE, curvy_spec::NAME_LINK_SIZE);
+
+/// Registry entry of a curve by its owner and name. Lives at the address derived from (owner,
+/// name), so a curve is resolved with a single account read. Set by curve creation to the
+/// curve created last under the name and closed by DeleteCurve of that curve. UpdateCurveMetadata
+/// renames and AcceptOwner transfers move the entry to the new name or owner, other instructions
+/// setting curve params keep the name, see [check_name_kept].
+#[derive(Debug, Copy, Clone, Pod, Zeroable)]
+#[repr(C)]
+pub struct NameLink {
+    pub discriminator: [u8; 8],
+    pub version: u8,
+
+    pub _padding: [u8; 7],
+
+    /// Owner of the curve
+    pub owner: Pubkey,
+
+    /// Name of the curve
+    pub name: [u8; SYMBOL_MAX_SIZE],
+
+    /// Registered curve
+    pub curve: Pubkey,
+}
+
+impl NameLink {
+    pub fn seeds<'a>(owner: &'a Pubkey, name: &'a [u8; SYMBOL_MAX_SIZE]) -> [&'a [u8]; 3] {
+        [NAME_LINK_SEED, owner.as_ref(), name]
+    }
+
+    /// Address and bump of the link of `owner`'s curve named `name`
+    pub fn find_address(owner: &Pubkey, name: &[u8; SYMBOL_MAX_SIZE]) -> (Pubkey, u8) {
+        Pubkey::find_program_address(&Self::seeds(owner, name), &crate::ID)
+    }
+}
+
+/// Fails with [CurvyError::InvalidParams] unless `new_name` is the current `name` of the curve.
+/// Only UpdateCurveMetadata renames curves, as it moves their name link along.
+pub fn check_name_kept(
+    name: &[u8; SYMBOL_MAX_SIZE],
+    new_name: &[u8; SYMBOL_MAX_SIZE],
+) -> CurvyResult<()> {
+    if name != new_name {
+        msg!("curve is renamed by UpdateCurveMetadata only");
+        return Err(CurvyError::InvalidParams);
+    }
+
+    Ok(())
+}
+
+impl PodAccount for NameLink {
+    const DISCRIMINATOR: &'static [u8] = NAME_LINK_DISCRIMINATOR;
+
+    type Version = u8;
+
+    const VERSION: Self::Version = curvy_spec::NAME_LINK_VERSION;
+
+    type InitParams = (
+        /*owner:*/ Pubkey,
+        /*name:*/ [u8; SYMBOL_MAX_SIZE],
+        /*curve:*/ Pubkey,
+    );
+
+    type InitError = PodAccountError;
+
+    fn discriminator(&self) -> &[u8] {
+        &self.discriminator
+    }
+
+    fn version(&self) -> Self::Version {
+        self.version
+    }
+
+    fn init_unckecked(
+        &mut self,
+        (owner, name, curve): Self::InitParams,
+    ) -> Result<(), Self::InitError> {
+        *self = Self {
+            discriminator: *NAME_LINK_DISCRIMINATOR,
+            version: Self::VERSION,
+            _padding: Zeroable::zeroed(),
+            owner,
+            name,
+            curve,
+        };
+
+        Ok(())
+    }
+}
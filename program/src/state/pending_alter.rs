@@ -0,0 +1,99 @@
+use bytemuck::{Pod, Zeroable};
+use solana_program::msg;
+use solana_program::pubkey::Pubkey;
+
+use texture_common::account::{PodAccount, PodAccountError};
+
+use crate::error::CurvyError;
+use crate::state::curve::CurveParamsRaw;
+use crate::state::PENDING_ALTER_DISCRIMINATOR;
+use crate::CurvyResult;
+
+pub const PENDING_ALTER_SEED: &[u8] = curvy_spec::PENDING_ALTER_SEED;
+
+static_assertions::const_assert_eq!(PendingAlter::SIZE, std::mem::size_of::<PendingAlter>());
+static_assertions::const_assert_eq!(0, std::mem::size_of::<PendingAlter>() % 8);
+static_assertions::const_assert_eq!(PendingAlter::SIZE, curvy_spec::PENDING_ALTER_SIZE);
+
+/// Curve params proposed by ProposeAlter, ApplyAlter sets them once the alter delay of the curve
+/// passes (see [crate::state::curve::Curve::alter_delay]). Lives at the address derived from
+/// the curve and is closed when applied or cancelled.
+#[derive(Debug, Copy, Clone, Pod, Zeroable)]
+#[repr(C)]
+pub struct PendingAlter {
+    pub discriminator: [u8; 8],
+    pub version: u8,
+
+    pub _padding: [u8; 7],
+
+    /// Curve the params are proposed for
+    pub curve: Pubkey,
+
+    /// First slot ApplyAlter accepts the proposal in
+    pub apply_slot: u64,
+
+    /// Proposed params
+    pub params: CurveParamsRaw,
+
+    pub _padding2: [u8; 5],
+}
+
+impl PendingAlter {
+    pub fn seeds(curve: &Pubkey) -> [&[u8]; 2] {
+        [PENDING_ALTER_SEED, curve.as_ref()]
+    }
+
+    /// Address and bump of the pending alteration account of `curve`
+    pub fn find_address(curve: &Pubkey) -> (Pubkey, u8) {
+        Pubkey::find_program_address(&Self::seeds(curve), &crate::ID)
+    }
+
+    /// Fails with [CurvyError::AlterNotReady] until `apply_slot`
+    pub fn check_ready(&self, slot: u64) -> CurvyResult<()> {
+        if slot < self.apply_slot {
+            msg!(
+                "proposed alteration can be applied from slot {}",
+                self.apply_slot
+            );
+            return Err(CurvyError::AlterNotReady {
+                from: self.apply_slot,
+            });
+        }
+
+        Ok(())
+    }
+}
+
+impl PodAccount for PendingAlter {
+    const DISCRIMINATOR: &'static [u8] = PENDING_ALTER_DISCRIMINATOR;
+
+    type Version = u8;
+
+    const VERSION: Self::Version = curvy_spec::PENDING_ALTER_VERSION;
+
+    type InitParams = Pubkey;
+
+    type InitError = PodAccountError;
+
+    fn discriminator(&self) -> &[u8] {
+        &self.discriminator
+    }
+
+    fn version(&self) -> Self::Version {
+        self.version
+    }
+
+    fn init_unckecked(&mut self, curve: Self::InitParams) -> Result<(), Self::InitError> {
+        *self = Self {
+            discriminator: *PENDING_ALTER_DISCRIMINATOR,
+            version: Self::VERSION,
+            _padding: Zeroable::zeroed(),
+            curve,
+            apply_slot: 0,
+            params: Zeroable::zeroed(),
+            _padding2: Zeroable::zeroed(),
+        };
+
+        Ok(())
+    }
+}
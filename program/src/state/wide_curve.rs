@@ -0,0 +1,265 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use bytemuck::{Pod, Zeroable};
+use solana_program::msg;
+use solana_program::pubkey::Pubkey;
+
+use texture_common::account::{PodAccount, PodAccountError};
+
+use crate::error::CurvyError;
+use crate::state::curve::{
+    cancel_deletion, check_deletion_grace, mark_for_deletion, marked_slot, Curve, CurveStatus,
+    CurveX, DELETION_GRACE_SLOTS, SYMBOL_MAX_SIZE,
+};
+use crate::state::WIDE_CURVE_DISCRIMINATOR;
+use crate::CurvyResult;
+
+/// Y of wide curves, for values exceeding [crate::state::curve::CurveY] even at low decimals
+/// (e.g. liquidity depth in token units)
+pub type WideCurveY = u64;
+
+/// Twice less samples than [crate::state::curve::MAX_Y_CNT], so the `y` table takes the same
+/// space and wide curve params still fit in one TX.
+pub const MAX_WIDE_Y_CNT: usize = curvy_spec::MAX_WIDE_Y_CNT;
+
+/// Space left in WideCurve for future fields
+pub const WIDE_CURVE_RESERVED_SIZE: usize = curvy_spec::WIDE_CURVE_RESERVED_SIZE;
+
+static_assertions::const_assert_eq!(WideCurve::SIZE, std::mem::size_of::<WideCurve>());
+static_assertions::const_assert_eq!(0, std::mem::size_of::<WideCurve>() % 8);
+static_assertions::const_assert_eq!(WideCurve::SIZE, curvy_spec::WIDE_CURVE_SIZE);
+
+#[cfg_attr(feature = "with-serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(BorshSerialize, BorshDeserialize, Debug, Copy, Clone)]
+pub struct WideCurveParams {
+    #[cfg_attr(
+        feature = "with-serde",
+        serde(with = "super::utils::array_as_str_serde")
+    )]
+    pub name: [u8; SYMBOL_MAX_SIZE],
+    #[cfg_attr(
+        feature = "with-serde",
+        serde(with = "super::utils::array_as_str_serde")
+    )]
+    pub formula: [u8; SYMBOL_MAX_SIZE],
+    /// Starting X coordinate
+    pub x0: CurveX,
+    /// Step on X scale between Y samples
+    pub x_step: CurveX,
+    /// Number of samples in `y` array
+    pub y_count: u8,
+    /// Precision of x0, x_step and y
+    pub decimals: u8,
+    /// Array of `y` values
+    #[cfg_attr(
+        feature = "with-serde",
+        serde(with = "serde_with::As::<[serde_with::Same; MAX_WIDE_Y_CNT]>")
+    )]
+    pub y: [WideCurveY; MAX_WIDE_Y_CNT],
+}
+
+impl WideCurveParams {
+    pub fn new(
+        name: &str,
+        formula: &str,
+        x0: CurveX,
+        x_step: CurveX,
+        y_count: u8,
+        decimals: u8,
+        y: [WideCurveY; MAX_WIDE_Y_CNT],
+    ) -> Self {
+        Self {
+            name: super::utils::str_to_array(name),
+            formula: super::utils::str_to_array(formula),
+            x0,
+            x_step,
+            y_count,
+            decimals,
+            y,
+        }
+    }
+
+    /// Same checks as [Curve::check_params] with the wide `y` table capacity
+    pub fn check(&self) -> CurvyResult<()> {
+        if self.y_count as usize > MAX_WIDE_Y_CNT {
+            msg!("y_count must not exceed {}", MAX_WIDE_Y_CNT);
+            return Err(CurvyError::InvalidParams);
+        }
+
+        Curve::check_dimensions(
+            self.x0,
+            self.x_step,
+            self.y_count,
+            self.decimals,
+            self.decimals,
+        )
+    }
+}
+
+impl From<&WideCurve> for WideCurveParams {
+    fn from(curve: &WideCurve) -> Self {
+        Self {
+            name: curve.name,
+            formula: curve.formula,
+            x0: curve.x0,
+            x_step: curve.x_step,
+            y_count: curve.y_count,
+            decimals: curve.decimals,
+            y: curve.y,
+        }
+    }
+}
+
+/// Curve with [WideCurveY] samples. Same as [Curve] otherwise, except it can not be associated
+/// with assets nor have series or confidence.
+#[derive(Debug, Copy, Clone, Pod, Zeroable)]
+#[repr(C)]
+pub struct WideCurve {
+    pub discriminator: [u8; 8],
+    pub version: u8,
+
+    pub _padding: [u8; 7],
+
+    /// a human-readable name
+    pub name: [u8; SYMBOL_MAX_SIZE],
+
+    /// a human-readable formula
+    pub formula: [u8; SYMBOL_MAX_SIZE],
+
+    /// authority who has full rights to manage that account
+    pub owner: Pubkey,
+
+    /// Starting X coordinate
+    pub x0: CurveX,
+
+    /// Step on X scale between Y samples
+    pub x_step: CurveX,
+
+    /// Number of samples in `y` array
+    pub y_count: u8,
+
+    /// Decimals number for x0, x_step, y.
+    pub decimals: u8,
+
+    /// Lifecycle stage, see [CurveStatus] and [WideCurve::status]. Zero, i.e. active, for curves
+    /// created before.
+    pub status: u8,
+
+    /// 1 when the curve is frozen by FreezeCurve: its samples can not be changed and the curve
+    /// can not be deleted. Irreversible.
+    pub is_frozen: u8,
+
+    pub _padding1: [u8; 4],
+
+    /// Array of `y` values
+    pub y: [WideCurveY; MAX_WIDE_Y_CNT],
+
+    /// Little-endian slot of MarkForDeletion, zeroed when the curve is not marked. See
+    /// [WideCurve::deletion_slot].
+    pub deletion_slot: [u8; 8],
+
+    /// Zeroed, for future fields
+    pub _reserved: [u8; WIDE_CURVE_RESERVED_SIZE],
+}
+
+impl WideCurve {
+    pub fn set_params(&mut self, params: WideCurveParams) {
+        self.name = params.name;
+        self.formula = params.formula;
+        self.x0 = params.x0;
+        self.x_step = params.x_step;
+        self.y_count = params.y_count;
+        self.decimals = params.decimals;
+        self.y = params.y;
+    }
+
+    /// [crate::events::y_hash] of the `y` table
+    pub fn y_hash(&self) -> [u8; 32] {
+        crate::events::y_hash(&self.y[..(self.y_count as usize).min(MAX_WIDE_Y_CNT)])
+    }
+
+    /// Fails with [CurvyError::CurveFrozen] when the curve is frozen, see [WideCurve::is_frozen]
+    pub fn check_not_frozen(&self) -> CurvyResult<()> {
+        if self.is_frozen != 0 {
+            msg!("curve is frozen");
+            return Err(CurvyError::CurveFrozen);
+        }
+
+        Ok(())
+    }
+
+    /// Lifecycle stage of the curve, same as [Curve::status]
+    pub fn status(&self) -> CurveStatus {
+        CurveStatus::try_from(self.status).unwrap_or(CurveStatus::Retired)
+    }
+
+    /// Slot the curve was marked for deletion in by MarkForDeletion, none unless marked
+    pub fn deletion_slot(&self) -> Option<u64> {
+        marked_slot(self.deletion_slot)
+    }
+
+    /// First slot DeleteCurve accepts the curve marked for deletion in. Without an alter delay
+    /// the grace is [DELETION_GRACE_SLOTS].
+    pub fn deletable_from_slot(&self) -> Option<u64> {
+        self.deletion_slot()
+            .map(|slot| slot.saturating_add(DELETION_GRACE_SLOTS))
+    }
+
+    /// Marks the curve for deletion in `slot`, same as [Curve::mark_for_deletion]
+    pub fn mark_for_deletion(&mut self, slot: u64) -> CurvyResult<()> {
+        mark_for_deletion(&mut self.deletion_slot, slot)
+    }
+
+    /// Removes the mark set by [WideCurve::mark_for_deletion]
+    pub fn cancel_deletion(&mut self) -> CurvyResult<()> {
+        cancel_deletion(&mut self.deletion_slot)
+    }
+
+    /// Fails with [CurvyError::DeletionGracePeriod] while the curve marked for deletion waits
+    /// for its grace period to pass in `slot`
+    pub fn check_deletion_grace(&self, slot: u64) -> CurvyResult<()> {
+        check_deletion_grace(self.deletable_from_slot(), 0, slot)
+    }
+}
+
+impl PodAccount for WideCurve {
+    const DISCRIMINATOR: &'static [u8] = WIDE_CURVE_DISCRIMINATOR;
+
+    type Version = u8;
+
+    const VERSION: Self::Version = curvy_spec::WIDE_CURVE_VERSION;
+
+    type InitParams = (/*params:*/ WideCurveParams, /*owner:*/ Pubkey);
+
+    type InitError = PodAccountError;
+
+    fn discriminator(&self) -> &[u8] {
+        &self.discriminator
+    }
+
+    fn version(&self) -> Self::Version {
+        self.version
+    }
+
+    fn init_unckecked(&mut self, (params, owner): Self::InitParams) -> Result<(), Self::InitError> {
+        *self = Self {
+            discriminator: *WIDE_CURVE_DISCRIMINATOR,
+            version: Self::VERSION,
+            _padding: Zeroable::zeroed(),
+            name: params.name,
+            formula: params.formula,
+            owner,
+            x0: params.x0,
+            x_step: params.x_step,
+            y_count: params.y_count,
+            decimals: params.decimals,
+            status: CurveStatus::Active as u8,
+            is_frozen: 0,
+            _padding1: Zeroable::zeroed(),
+            y: params.y,
+            deletion_slot: [0; 8],
+            _reserved: Zeroable::zeroed(),
+        };
+
+        Ok(())
+    }
+}
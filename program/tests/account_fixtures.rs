@@ -0,0 +1,179 @@
+//! Replays account data written by deployed program versions, see `account_fixtures.json`.
+//! Fixtures are recorded with `curvy record-fixture`, every field the program wrote must be read
+//! back unchanged by the current decoder. Layouts never deployed get no fixtures; the synthetic
+//! v1 fixture stands in until a mainnet v1 account is recorded.
+
+use texture_common::account::PodAccount;
+
+use curvy::state::curve::Curve;
+use curvy::state::{probe, AccountKind};
+
+fn hex_decode(hex: &str) -> Vec<u8> {
+    (0..hex.len())
+        .step_by(2)
+        .map(|idx| u8::from_str_radix(&hex[idx..idx + 2], 16).unwrap())
+        .collect()
+}
+
+fn symbol(bytes: &[u8]) -> &str {
+    let end = bytes
+        .iter()
+        .position(|byte| *byte == 0)
+        .unwrap_or(bytes.len());
+    std::str::from_utf8(&bytes[..end]).unwrap()
+}
+
+#[test]
+fn account_fixtures() {
+    let fixtures: serde_json::Value =
+        serde_json::from_str(include_str!("account_fixtures.json")).unwrap();
+
+    for fixture in fixtures.as_array().unwrap() {
+        let description = fixture["description"].as_str().unwrap();
+        let expected = &fixture["expected"];
+        let int = |field: &str| expected[field].as_u64().unwrap();
+        let flag = |field: &str| expected[field].as_bool().unwrap();
+
+        let mut data = hex_decode(fixture["data"].as_str().unwrap());
+        let Some(AccountKind::Curve { version }) = probe(&data) else {
+            panic!("{description}: not a Curve account");
+        };
+        assert_eq!(version as u64, int("version"), "{description}");
+
+        let curve = if version == 1 {
+            assert_eq!(data.len(), Curve::V1_SIZE, "{description}");
+            // as migration does: realloc zeroes the grown part
+            data.resize(Curve::SIZE, 0);
+            *Curve::upgrade(&mut data).unwrap()
+        } else if version == 2 {
+            assert_eq!(data.len(), Curve::SIZE, "{description}");
+            *Curve::upgrade(&mut data).unwrap()
+        } else {
+            assert_eq!(data.len(), Curve::SIZE, "{description}");
+            *Curve::load(&data).unwrap()
+        };
+
+        assert_eq!(
+            symbol(&curve.name),
+            expected["name"].as_str().unwrap(),
+            "{description}"
+        );
+        assert_eq!(
+            symbol(&curve.formula),
+            expected["formula"].as_str().unwrap(),
+            "{description}"
+        );
+        assert_eq!(
+            curve.owner.to_string(),
+            expected["owner"].as_str().unwrap(),
+            "{description}"
+        );
+        assert_eq!(curve.x0 as u64, int("x0"), "{description}");
+        assert_eq!(curve.x0_negative != 0, flag("x0_negative"), "{description}");
+        assert_eq!(curve.x_step as u64, int("x_step"), "{description}");
+        assert_eq!(curve.y_count as u64, int("y_count"), "{description}");
+        assert_eq!(curve.x_decimals as u64, int("decimals"), "{description}");
+        assert_eq!(curve.y_decimals as u64, int("y_decimals"), "{description}");
+
+        let y = expected["y"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|value| value.as_u64().unwrap() as u32)
+            .collect::<Vec<_>>();
+        assert_eq!(&curve.y[..curve.y_count as usize], &y[..], "{description}");
+
+        assert_eq!(
+            curve.asset().map(|(mint, _)| mint.to_string()),
+            expected["asset_mint"].as_str().map(String::from),
+            "{description}"
+        );
+        assert_eq!(curve.asset_kind as u64, int("asset_kind"), "{description}");
+        assert_eq!(
+            curve.series_count as u64,
+            int("series_count"),
+            "{description}"
+        );
+        assert_eq!(
+            curve.has_confidence != 0,
+            flag("has_confidence"),
+            "{description}"
+        );
+        assert_eq!(curve.y_signed != 0, flag("y_signed"), "{description}");
+        assert_eq!(curve.is_frozen != 0, flag("is_frozen"), "{description}");
+        assert_eq!(
+            curve.status().to_string(),
+            expected["status"].as_str().unwrap(),
+            "{description}"
+        );
+        assert_eq!(
+            curve.deletion_slot(),
+            expected["deletion_slot"].as_u64(),
+            "{description}"
+        );
+        assert_eq!(curve.alter_delay(), int("alter_delay"), "{description}");
+        assert_eq!(
+            curve
+                .delegates()
+                .map(|delegate| delegate.to_string())
+                .collect::<Vec<_>>(),
+            expected["delegates"]
+                .as_array()
+                .unwrap()
+                .iter()
+                .map(|delegate| delegate.as_str().unwrap().to_string())
+                .collect::<Vec<_>>(),
+            "{description}"
+        );
+        assert_eq!(
+            curve.owner_threshold as u64,
+            int("owner_threshold"),
+            "{description}"
+        );
+        assert_eq!(
+            curve.extensions_len() as u64,
+            int("extensions_len"),
+            "{description}"
+        );
+        assert_eq!(
+            curve.y_transform().to_string(),
+            expected["y_transform"].as_str().unwrap(),
+            "{description}"
+        );
+        assert_eq!(
+            curve.valid_until(),
+            expected["valid_until"].as_i64(),
+            "{description}"
+        );
+        assert_eq!(
+            curve.validity_period(),
+            expected["validity_period"].as_u64().unwrap(),
+            "{description}"
+        );
+        assert_eq!(
+            curve.last_update_slot(),
+            expected["last_update_slot"].as_u64(),
+            "{description}"
+        );
+        assert_eq!(
+            curve.last_update_unix(),
+            expected["last_update_unix"].as_i64(),
+            "{description}"
+        );
+        assert_eq!(
+            curve.revision(),
+            expected["revision"].as_u64().unwrap(),
+            "{description}"
+        );
+        assert_eq!(
+            curve.tail_y_count as u64,
+            int("tail_y_count"),
+            "{description}"
+        );
+        assert_eq!(
+            curve.pending_owner().map(|owner| owner.to_string()),
+            expected["pending_owner"].as_str().map(String::from),
+            "{description}"
+        );
+    }
+}
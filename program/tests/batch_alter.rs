@@ -0,0 +1,110 @@
+mod common;
+
+use solana_program::instruction::{AccountMeta, Instruction};
+use solana_program::pubkey::Pubkey;
+use texture_common::account::PodAccount;
+
+use curvy::error::CurvyError;
+use curvy::instruction::{BatchAlter, FreezeCurve};
+use curvy::state::curve::{Curve, CurveY};
+
+use common::{curve_account, params, process, TestAccount};
+
+fn batch_alter(owner: Pubkey, curves: &[Pubkey], y: &[u32]) -> Instruction {
+    let mut ix = BatchAlter {
+        owner,
+        params: y.iter().map(|y| (&params(*y)).into()).collect(),
+    }
+    .into_instruction();
+    ix.accounts
+        .extend(curves.iter().map(|curve| AccountMeta::new(*curve, false)));
+    ix
+}
+
+fn y0(account: &TestAccount) -> CurveY {
+    Curve::load(&account.data).unwrap().y[0]
+}
+
+fn failed_account(result: Result<(), CurvyError>) -> (Pubkey, CurvyError) {
+    match result {
+        Err(CurvyError::Account { account, error }) => (account, *error),
+        result => panic!("unexpected {result:?}"),
+    }
+}
+
+#[test]
+fn all_curves_are_altered() {
+    let owner = Pubkey::new_unique();
+    let mut accounts = [
+        curve_account(Pubkey::new_unique(), owner, 1),
+        curve_account(Pubkey::new_unique(), owner, 1),
+    ];
+    let curves = [accounts[0].key, accounts[1].key];
+
+    process(batch_alter(owner, &curves, &[2, 3]), &mut accounts).unwrap();
+    assert_eq!(y0(&accounts[0]), 2);
+    assert_eq!(y0(&accounts[1]), 3);
+    for account in &accounts {
+        assert_eq!(Curve::load(&account.data).unwrap().revision(), 1);
+    }
+}
+
+#[test]
+fn no_curve_is_altered_when_one_fails() {
+    let owner = Pubkey::new_unique();
+    let stranger = Pubkey::new_unique();
+    let mut accounts = [
+        curve_account(Pubkey::new_unique(), owner, 1),
+        curve_account(Pubkey::new_unique(), owner, 1),
+        curve_account(Pubkey::new_unique(), stranger, 1),
+    ];
+    let curves = [accounts[0].key, accounts[1].key, accounts[2].key];
+
+    let result = process(batch_alter(owner, &curves, &[2, 2, 2]), &mut accounts);
+    assert_eq!(failed_account(result).0, curves[2]);
+
+    let freeze = FreezeCurve {
+        curve: curves[1],
+        owner,
+    };
+    process(freeze.into_instruction(), &mut accounts[1..2]).unwrap();
+    let result = process(batch_alter(owner, &curves[..2], &[2, 2]), &mut accounts);
+    assert!(matches!(
+        failed_account(result),
+        (curve, CurvyError::CurveFrozen) if curve == curves[1]
+    ));
+
+    for account in &accounts {
+        assert_eq!(y0(account), 1);
+    }
+}
+
+#[test]
+fn curves_must_match_params() {
+    let owner = Pubkey::new_unique();
+    let mut accounts = [
+        curve_account(Pubkey::new_unique(), owner, 1),
+        curve_account(Pubkey::new_unique(), owner, 1),
+    ];
+    let curves = [accounts[0].key, accounts[1].key];
+
+    assert!(matches!(
+        process(batch_alter(owner, &curves, &[2]), &mut accounts),
+        Err(CurvyError::InvalidParams)
+    ));
+    assert!(matches!(
+        process(batch_alter(owner, &[], &[]), &mut accounts),
+        Err(CurvyError::InvalidParams)
+    ));
+    assert!(matches!(
+        process(
+            batch_alter(owner, &[curves[0], curves[0]], &[2, 3]),
+            &mut accounts
+        ),
+        Err(CurvyError::InvalidAccount(_))
+    ));
+
+    for account in &accounts {
+        assert_eq!(y0(account), 1);
+    }
+}
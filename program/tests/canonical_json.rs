@@ -0,0 +1,47 @@
+//! Test vectors shared with other language bindings, see `params_hash_vectors.json`.
+
+use curvy::state::curve::{CurveParams, MAX_Y_CNT};
+
+#[test]
+fn params_hash_vectors() {
+    let vectors: serde_json::Value =
+        serde_json::from_str(include_str!("params_hash_vectors.json")).unwrap();
+
+    for vector in vectors.as_array().unwrap() {
+        let description = vector["description"].as_str().unwrap();
+        let int = |field: &str| vector[field].as_u64().unwrap();
+
+        let mut y = [0; MAX_Y_CNT];
+        for (idx, value) in vector["y"].as_array().unwrap().iter().enumerate() {
+            y[idx] = value.as_u64().unwrap() as u32;
+        }
+
+        let params = CurveParams::new(
+            vector["name"].as_str().unwrap(),
+            vector["formula"].as_str().unwrap(),
+            int("x0") as u32,
+            int("x_step") as u32,
+            int("y_count") as u8,
+            int("decimals") as u8,
+            y,
+        );
+
+        assert_eq!(
+            params.canonical_json(),
+            vector["canonical_json"].as_str().unwrap(),
+            "{description}"
+        );
+
+        let hash_hex = params
+            .hash()
+            .to_bytes()
+            .iter()
+            .map(|byte| format!("{byte:02x}"))
+            .collect::<String>();
+        assert_eq!(
+            hash_hex,
+            vector["sha256"].as_str().unwrap(),
+            "{description}"
+        );
+    }
+}
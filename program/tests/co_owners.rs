@@ -0,0 +1,258 @@
+mod common;
+
+use solana_program::instruction::{AccountMeta, Instruction};
+use solana_program::pubkey::Pubkey;
+use texture_common::account::PodAccount;
+
+use curvy::error::CurvyError;
+use curvy::instruction::{
+    AcceptOwner, AlterCurve, PatchY, ProposeOwner, SetCurveOwners, SetCurveStatus,
+};
+use curvy::state::curve::{Curve, CurveStatus};
+use curvy::state::curve_owners::CurveOwners;
+use curvy::state::name_link::NameLink;
+
+use common::{curve_data, params, process, unwrap_account_error, TestAccount};
+
+/// Curve of `owners[0]` co-owned by `owners`, `threshold` of them sign
+fn co_owned(curve: Pubkey, owners: &[Pubkey], threshold: u8) -> [TestAccount; 2] {
+    let mut curve_data = curve_data(owners[0], 1);
+    Curve::load_mut(&mut curve_data).unwrap().owner_threshold = threshold;
+
+    let mut owners_data = vec![0; CurveOwners::SIZE];
+    CurveOwners::init_bytes(&mut owners_data, curve).unwrap();
+    CurveOwners::try_from_bytes_mut(&mut owners_data)
+        .unwrap()
+        .set_owners(owners, threshold)
+        .unwrap();
+
+    [
+        TestAccount::program(curve, curve_data),
+        TestAccount::program(CurveOwners::find_address(&curve).0, owners_data).with_lamports(1_000),
+    ]
+}
+
+/// Appends the curve owners account and `co_signers` to an owner instruction of `curve`
+fn co_signed(mut ix: Instruction, curve: &Pubkey, co_signers: &[Pubkey]) -> Instruction {
+    ix.accounts.push(AccountMeta::new_readonly(
+        CurveOwners::find_address(curve).0,
+        false,
+    ));
+    ix.accounts.extend(
+        co_signers
+            .iter()
+            .map(|signer| AccountMeta::new_readonly(*signer, true)),
+    );
+    ix
+}
+
+fn alter(curve: Pubkey, by: Pubkey, y: u32) -> Instruction {
+    AlterCurve {
+        curve,
+        owner: by,
+        params: params(y),
+    }
+    .into_instruction()
+}
+
+#[test]
+fn threshold_of_owners_alters_curve() {
+    let curve = Pubkey::new_unique();
+    let owners = [
+        Pubkey::new_unique(),
+        Pubkey::new_unique(),
+        Pubkey::new_unique(),
+    ];
+    let mut accounts = co_owned(curve, &owners, 2);
+
+    assert!(matches!(
+        unwrap_account_error(process(alter(curve, owners[0], 2), &mut accounts)),
+        CurvyError::CoOwnersRequired { threshold: 2 }
+    ));
+    assert!(matches!(
+        unwrap_account_error(process(
+            co_signed(alter(curve, owners[0], 2), &curve, &[]),
+            &mut accounts
+        )),
+        CurvyError::CoOwnersRequired { threshold: 2 }
+    ));
+    let stranger = Pubkey::new_unique();
+    assert!(matches!(
+        unwrap_account_error(process(
+            co_signed(alter(curve, owners[0], 2), &curve, &[owners[1], stranger]),
+            &mut accounts
+        )),
+        CurvyError::OwnerMismatch
+    ));
+
+    // unsigned co-signer
+    let mut ix = co_signed(alter(curve, owners[0], 2), &curve, &[owners[1]]);
+    ix.accounts[3].is_signer = false;
+    assert!(process(ix, &mut accounts).is_err());
+
+    // any owners, not only the curve owner
+    process(
+        co_signed(alter(curve, owners[2], 2), &curve, &[owners[1]]),
+        &mut accounts,
+    )
+    .unwrap();
+    assert_eq!(Curve::load(&accounts[0].data).unwrap().y[0], 2);
+}
+
+#[test]
+fn co_owned_curve_refuses_single_owner() {
+    let curve = Pubkey::new_unique();
+    let owners = [Pubkey::new_unique(), Pubkey::new_unique()];
+    let mut accounts = co_owned(curve, &owners, 2);
+
+    let patch = PatchY {
+        curve,
+        owner: owners[0],
+        start_index: 0,
+        values: vec![3],
+    };
+    assert!(matches!(
+        unwrap_account_error(process(patch.into_instruction(), &mut accounts)),
+        CurvyError::CoOwnersRequired { threshold: 2 }
+    ));
+    let status = SetCurveStatus {
+        curve,
+        owner: owners[0],
+        status: CurveStatus::Deprecated as u8,
+    };
+    assert!(matches!(
+        unwrap_account_error(process(status.into_instruction(), &mut accounts)),
+        CurvyError::CoOwnersRequired { threshold: 2 }
+    ));
+
+    // curve owners of another curve
+    let other = Pubkey::new_unique();
+    let [_, mut other_owners] = co_owned(other, &owners, 2);
+    other_owners.key = CurveOwners::find_address(&curve).0;
+    accounts[1] = other_owners;
+    assert!(process(
+        co_signed(alter(curve, owners[0], 2), &curve, &[owners[1]]),
+        &mut accounts
+    )
+    .is_err());
+}
+
+#[test]
+fn owners_change_and_dissolve() {
+    let curve = Pubkey::new_unique();
+    let owners = [Pubkey::new_unique(), Pubkey::new_unique()];
+    let mut accounts = co_owned(curve, &owners, 2);
+
+    let set = |by, threshold, owners: &[Pubkey]| SetCurveOwners {
+        curve,
+        owner: by,
+        curve_owners: CurveOwners::find_address(&curve).0,
+        threshold,
+        owners: owners.to_vec(),
+    };
+    // the curve owners account is listed by the instruction already
+    let co_signed = |ix: SetCurveOwners, co_signers: &[Pubkey]| {
+        let mut ix = ix.into_instruction();
+        ix.accounts.extend(
+            co_signers
+                .iter()
+                .map(|signer| AccountMeta::new_readonly(*signer, true)),
+        );
+        ix
+    };
+
+    assert!(matches!(
+        unwrap_account_error(process(
+            set(owners[0], 0, &[]).into_instruction(),
+            &mut accounts
+        )),
+        CurvyError::CoOwnersRequired { threshold: 2 }
+    ));
+
+    let third = Pubkey::new_unique();
+    assert!(
+        process(
+            co_signed(set(owners[1], 1, &[owners[1], third]), &[owners[0]]),
+            &mut accounts
+        )
+        .is_err(),
+        "curve owner must stay among the owners"
+    );
+    assert!(process(
+        co_signed(set(owners[1], 3, &[owners[0], third]), &[owners[0]]),
+        &mut accounts
+    )
+    .is_err());
+    process(
+        co_signed(set(owners[1], 1, &[owners[0], third]), &[owners[0]]),
+        &mut accounts,
+    )
+    .unwrap();
+    let curve_owners = CurveOwners::try_from_bytes(&accounts[1].data).unwrap();
+    assert_eq!(
+        curve_owners.owners().collect::<Vec<_>>(),
+        [&owners[0], &third]
+    );
+    assert_eq!(Curve::load(&accounts[0].data).unwrap().owner_threshold, 1);
+
+    // the owner left out signs no more
+    assert!(process(co_signed(set(owners[1], 0, &[]), &[]), &mut accounts).is_err());
+    process(co_signed(set(third, 0, &[]), &[]), &mut accounts).unwrap();
+    assert_eq!(Curve::load(&accounts[0].data).unwrap().owner_threshold, 0);
+    assert!(accounts[1].data.iter().all(|byte| *byte == 0));
+    assert_eq!(accounts[1].lamports, 0);
+
+    // single owner again
+    process(alter(curve, owners[0], 5), &mut accounts).unwrap();
+    assert!(process(alter(curve, third, 6), &mut accounts).is_err());
+}
+
+#[test]
+fn co_owned_curve_is_not_transferred() {
+    let curve = Pubkey::new_unique();
+    let owners = [Pubkey::new_unique(), Pubkey::new_unique()];
+    let new_owner = Pubkey::new_unique();
+    // proposed by the single owner before co-owners were set
+    let mut accounts = co_owned(curve, &owners, 2);
+    Curve::load_mut(&mut accounts[0].data)
+        .unwrap()
+        .pending_owner = new_owner;
+
+    let propose = ProposeOwner {
+        curve,
+        owner: owners[0],
+        new_owner,
+    };
+    let accept = AcceptOwner {
+        curve,
+        new_owner,
+        owner: owners[0],
+        name_link: NameLink::find_address(&owners[0], &params(1).name).0,
+        new_name_link: NameLink::find_address(&new_owner, &params(1).name).0,
+    };
+    for ix in [propose.into_instruction(), accept.into_instruction()] {
+        assert!(matches!(
+            unwrap_account_error(process(ix, &mut accounts)),
+            CurvyError::CoOwnersRequired { threshold: 2 }
+        ));
+    }
+    assert_eq!(Curve::load(&accounts[0].data).unwrap().owner, owners[0]);
+
+    // setting co-owners drops the proposal
+    let mut accounts = co_owned(curve, &owners, 2);
+    {
+        let loaded = Curve::load_mut(&mut accounts[0].data).unwrap();
+        loaded.owner_threshold = 0;
+        loaded.pending_owner = new_owner;
+    }
+    let set = SetCurveOwners {
+        curve,
+        owner: owners[0],
+        curve_owners: CurveOwners::find_address(&curve).0,
+        threshold: 2,
+        owners: owners.to_vec(),
+    };
+    process(set.into_instruction(), &mut accounts).unwrap();
+    let loaded = Curve::load(&accounts[0].data).unwrap();
+    assert_eq!((loaded.owner_threshold, loaded.pending_owner()), (2, None));
+}
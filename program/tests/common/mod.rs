@@ -0,0 +1,210 @@
+//! Harness of the processor tests: instructions run against [TestAccount]s serialized as the
+//! runtime does, with stubs of the clock, rent and event logs.
+
+#![allow(dead_code)]
+
+use std::mem::size_of;
+use std::sync::atomic::{AtomicI64, AtomicU64, Ordering};
+use std::sync::{Mutex, Once};
+
+use solana_program::clock::Clock;
+use solana_program::entrypoint::{
+    deserialize, MAX_PERMITTED_DATA_INCREASE, NON_DUP_MARKER, SUCCESS,
+};
+use solana_program::instruction::Instruction;
+use solana_program::program_stubs::{set_syscall_stubs, SyscallStubs};
+use solana_program::pubkey::Pubkey;
+use solana_program::rent::Rent;
+
+use texture_common::account::PodAccount;
+
+use curvy::error::CurvyError;
+use curvy::events::CurveEvent;
+use curvy::processor::Processor;
+use curvy::state::curve::{Curve, CurveParams, MAX_Y_CNT, SYMBOL_MAX_SIZE};
+use curvy::state::name_link::NameLink;
+
+static SLOT: AtomicU64 = AtomicU64::new(0);
+static UNIX_TIMESTAMP: AtomicI64 = AtomicI64::new(0);
+
+/// Fields of `sol_log_data` calls
+static LOGGED: Mutex<Vec<Vec<Vec<u8>>>> = Mutex::new(vec![]);
+
+struct TestStubs;
+
+impl SyscallStubs for TestStubs {
+    fn sol_get_clock_sysvar(&self, var_addr: *mut u8) -> u64 {
+        let clock = Clock {
+            slot: SLOT.load(Ordering::Relaxed),
+            unix_timestamp: UNIX_TIMESTAMP.load(Ordering::Relaxed),
+            ..Clock::default()
+        };
+        unsafe { *(var_addr as *mut Clock) = clock };
+        SUCCESS
+    }
+
+    fn sol_get_rent_sysvar(&self, var_addr: *mut u8) -> u64 {
+        unsafe { *(var_addr as *mut Rent) = Rent::default() };
+        SUCCESS
+    }
+
+    fn sol_log_data(&self, fields: &[&[u8]]) {
+        LOGGED
+            .lock()
+            .unwrap()
+            .push(fields.iter().map(|field| field.to_vec()).collect());
+    }
+}
+
+/// Slot of the clock read by the program
+pub fn set_slot(slot: u64) {
+    SLOT.store(slot, Ordering::Relaxed);
+}
+
+/// Unix timestamp of the clock read by the program
+pub fn set_unix_timestamp(unix_timestamp: i64) {
+    UNIX_TIMESTAMP.store(unix_timestamp, Ordering::Relaxed);
+}
+
+/// Events logged since the last call
+pub fn take_events() -> Vec<CurveEvent> {
+    std::mem::take(&mut *LOGGED.lock().unwrap())
+        .iter()
+        .map(|fields| {
+            let fields = fields.iter().map(Vec::as_slice).collect::<Vec<_>>();
+            CurveEvent::decode(&fields).unwrap()
+        })
+        .collect()
+}
+
+/// Account of a test: key, owner, lamports and data
+#[derive(Debug, Clone)]
+pub struct TestAccount {
+    pub key: Pubkey,
+    pub owner: Pubkey,
+    pub lamports: u64,
+    pub data: Vec<u8>,
+}
+
+impl TestAccount {
+    pub fn new(key: Pubkey, owner: Pubkey, data: Vec<u8>) -> Self {
+        Self {
+            key,
+            owner,
+            lamports: 0,
+            data,
+        }
+    }
+
+    /// Account of the program holding `data`
+    pub fn program(key: Pubkey, data: Vec<u8>) -> Self {
+        Self::new(key, curvy::ID, data)
+    }
+
+    pub fn with_lamports(self, lamports: u64) -> Self {
+        Self { lamports, ..self }
+    }
+}
+
+/// Params of the test curves: two samples, both set to `y`
+pub fn params(y: u32) -> CurveParams {
+    CurveParams::new("SOL-borrow", "y=x", 0, 10, 2, 6, [y; MAX_Y_CNT])
+}
+
+/// Data of a curve of `owner` with [params] of `y`
+pub fn curve_data(owner: Pubkey, y: u32) -> Vec<u8> {
+    let mut data = vec![0; Curve::SIZE];
+    Curve::init_bytes(&mut data, (params(y), owner)).unwrap();
+    data
+}
+
+/// Curve account at `key` with [curve_data] of `owner` and `y`
+pub fn curve_account(key: Pubkey, owner: Pubkey, y: u32) -> TestAccount {
+    TestAccount::program(key, curve_data(owner, y))
+}
+
+/// Name link of `owner` and `name` pointing to `curve`. Links are created by the system program,
+/// not available here, so tests creating or moving links provide them in advance.
+pub fn name_link(owner: Pubkey, name: [u8; SYMBOL_MAX_SIZE], curve: Pubkey) -> TestAccount {
+    let mut data = vec![0; NameLink::SIZE];
+    NameLink::init_bytes(&mut data, (owner, name, curve)).unwrap();
+    TestAccount::program(NameLink::find_address(&owner, &name).0, data).with_lamports(1_000)
+}
+
+/// Runs `ix` against `accounts`, accounts of the instruction missing there are empty system
+/// ones. Accounts are serialized as the runtime does, so realloc resizes them, and like the
+/// runtime `accounts` are written back only when the instruction succeeds.
+pub fn process(ix: Instruction, accounts: &mut [TestAccount]) -> Result<(), CurvyError> {
+    static STUBS: Once = Once::new();
+    STUBS.call_once(|| {
+        set_syscall_stubs(Box::new(TestStubs));
+    });
+
+    let empty = TestAccount::new(
+        Pubkey::default(),
+        solana_program::system_program::ID,
+        vec![],
+    );
+    let mut input = vec![];
+    input.extend((ix.accounts.len() as u64).to_le_bytes());
+    for (idx, meta) in ix.accounts.iter().enumerate() {
+        // accounts listed more than once refer to the first entry
+        let first = ix.accounts[..idx]
+            .iter()
+            .position(|other| other.pubkey == meta.pubkey);
+        if let Some(first) = first {
+            input.push(first as u8);
+            input.extend([0; 7]);
+            continue;
+        }
+
+        let account = accounts
+            .iter()
+            .find(|account| account.key == meta.pubkey)
+            .unwrap_or(&empty);
+        input.extend([
+            NON_DUP_MARKER,
+            meta.is_signer as u8,
+            meta.is_writable as u8,
+            0,
+        ]);
+        input.extend([0; size_of::<u32>()]);
+        input.extend(meta.pubkey.to_bytes());
+        input.extend(account.owner.to_bytes());
+        input.extend(account.lamports.to_le_bytes());
+        input.extend((account.data.len() as u64).to_le_bytes());
+        input.extend(&account.data);
+        input.resize(input.len() + MAX_PERMITTED_DATA_INCREASE, 0);
+        input.resize(input.len().next_multiple_of(8), 0);
+        input.extend(0_u64.to_le_bytes());
+    }
+    input.extend((ix.data.len() as u64).to_le_bytes());
+    input.extend(&ix.data);
+    input.extend(curvy::ID.to_bytes());
+
+    // u64 words keep the serialized fields aligned
+    let mut words = vec![0_u64; input.len().div_ceil(8)];
+    bytemuck::cast_slice_mut::<u64, u8>(&mut words)[..input.len()].copy_from_slice(&input);
+    let input = bytemuck::cast_slice_mut::<u64, u8>(&mut words);
+
+    let (program_id, infos, ix_data) = unsafe { deserialize(input.as_mut_ptr()) };
+    Processor::new(program_id, &infos).process_instruction(ix_data)?;
+
+    for account in accounts.iter_mut() {
+        if let Some(info) = infos.iter().find(|info| *info.key == account.key) {
+            account.owner = *info.owner;
+            account.lamports = info.lamports();
+            account.data = info.data.borrow().to_vec();
+        }
+    }
+
+    Ok(())
+}
+
+/// Error wrapped with the account it occurred on
+pub fn unwrap_account_error(result: Result<(), CurvyError>) -> CurvyError {
+    match result {
+        Err(CurvyError::Account { error, .. }) => *error,
+        result => panic!("unexpected {result:?}"),
+    }
+}
@@ -0,0 +1,379 @@
+mod common;
+
+use solana_program::bpf_loader_upgradeable;
+use solana_program::instruction::{AccountMeta, Instruction};
+use solana_program::program_pack::Pack;
+use solana_program::pubkey::Pubkey;
+use spl_token::state::Mint;
+use texture_common::account::PodAccount;
+
+use curvy::error::CurvyError;
+use curvy::instruction::{
+    AlterCurve, CreateCurve, CreateReferenceCurve, DeleteCurve, InitConfig, SetConfig,
+    SetCreationFrozen, SetCurveStatus,
+};
+use curvy::state::config::{Config, MAX_CONFIG_CREATORS};
+use curvy::state::curve::{
+    find_reference_curve_address, Curve, CurveParams, CurveStatus, MAX_Y_CNT,
+};
+use curvy::state::name_link::NameLink;
+
+use common::{curve_account, name_link, params, process, unwrap_account_error, TestAccount};
+
+fn config_account(admin: Pubkey, creators: &[Pubkey]) -> TestAccount {
+    let mut data = vec![0; Config::SIZE];
+    Config::init_bytes(&mut data, admin).unwrap();
+    Config::try_from_bytes_mut(&mut data)
+        .unwrap()
+        .set_creators(creators)
+        .unwrap();
+    TestAccount::new(Config::find_address().0, curvy::ID, data)
+}
+
+fn create_curve(owner: Pubkey) -> Instruction {
+    let params = CurveParams::new("SOL-borrow", "y=x", 0, 10, 2, 6, [1; MAX_Y_CNT]);
+    CreateCurve {
+        curve: Pubkey::new_unique(),
+        owner,
+        config: Config::find_address().0,
+        name_link: NameLink::find_address(&owner, &params.name).0,
+        params,
+    }
+    .into_instruction()
+}
+
+#[test]
+fn creators_are_checked_once_config_exists() {
+    let creator = Pubkey::new_unique();
+    let stranger = Pubkey::new_unique();
+
+    // without the config creation goes on to the system program, not available here
+    let result = process(create_curve(stranger), &mut []);
+    assert!(!matches!(
+        unwrap_account_error(result),
+        CurvyError::CreatorNotAllowed { .. }
+    ));
+
+    let mut accounts = [config_account(Pubkey::new_unique(), &[creator])];
+    assert!(matches!(
+        unwrap_account_error(process(create_curve(stranger), &mut accounts)),
+        CurvyError::CreatorNotAllowed { creator } if creator == stranger
+    ));
+    let result = process(create_curve(creator), &mut accounts);
+    assert!(!matches!(
+        unwrap_account_error(result),
+        CurvyError::CreatorNotAllowed { .. }
+    ));
+
+    // an account at another address is no config
+    let mut ix = create_curve(stranger);
+    ix.accounts[2].pubkey = Pubkey::new_unique();
+    assert!(process(ix, &mut accounts).is_err());
+}
+
+#[test]
+fn config_is_set_by_admin() {
+    let admin = Pubkey::new_unique();
+    let new_admin = Pubkey::new_unique();
+    let creator = Pubkey::new_unique();
+    let mut accounts = [config_account(admin, &[])];
+
+    let set = |admin, new_admin, creators: &[Pubkey]| SetConfig {
+        config: Config::find_address().0,
+        admin,
+        new_admin,
+        creators: creators.to_vec(),
+    };
+
+    assert!(process(
+        set(new_admin, new_admin, &[creator]).into_instruction(),
+        &mut accounts
+    )
+    .is_err());
+    assert!(process(
+        set(admin, Pubkey::default(), &[creator]).into_instruction(),
+        &mut accounts
+    )
+    .is_err());
+    assert!(process(
+        set(admin, admin, &[creator, creator]).into_instruction(),
+        &mut accounts
+    )
+    .is_err());
+    let too_many = (0..=MAX_CONFIG_CREATORS)
+        .map(|_| Pubkey::new_unique())
+        .collect::<Vec<_>>();
+    assert!(process(
+        set(admin, admin, &too_many).into_instruction(),
+        &mut accounts
+    )
+    .is_err());
+
+    process(
+        set(admin, new_admin, &[creator]).into_instruction(),
+        &mut accounts,
+    )
+    .unwrap();
+    let config = Config::try_from_bytes(&accounts[0].data).unwrap();
+    assert_eq!(config.admin, new_admin);
+    assert_eq!(config.creators().collect::<Vec<_>>(), [&creator]);
+
+    assert!(process(set(admin, admin, &[]).into_instruction(), &mut accounts).is_err());
+    process(
+        set(new_admin, new_admin, &[]).into_instruction(),
+        &mut accounts,
+    )
+    .unwrap();
+    assert_eq!(
+        Config::try_from_bytes(&accounts[0].data)
+            .unwrap()
+            .creators()
+            .count(),
+        0
+    );
+}
+
+#[test]
+fn config_is_initialized_by_upgrade_authority() {
+    let authority = Pubkey::new_unique();
+    let program_data =
+        Pubkey::find_program_address(&[curvy::ID.as_ref()], &bpf_loader_upgradeable::ID).0;
+
+    // bincode of UpgradeableLoaderState::ProgramData followed by the program
+    let program_data_account = |authority: Option<Pubkey>| {
+        let mut data = vec![3, 0, 0, 0];
+        data.extend(42_u64.to_le_bytes());
+        match authority {
+            Some(authority) => {
+                data.push(1);
+                data.extend(authority.to_bytes());
+            }
+            None => data.extend([0; 33]),
+        }
+        data.extend([0; 64]);
+        TestAccount::new(program_data, bpf_loader_upgradeable::ID, data)
+    };
+    let init = |admin| InitConfig {
+        config: Config::find_address().0,
+        admin,
+        program_data,
+        creators: vec![],
+    };
+
+    let mut accounts = [program_data_account(Some(authority))];
+    assert!(matches!(
+        unwrap_account_error(process(
+            init(Pubkey::new_unique()).into_instruction(),
+            &mut accounts
+        )),
+        CurvyError::InvalidKey(_)
+    ));
+
+    let mut accounts = [program_data_account(None)];
+    assert!(matches!(
+        process(init(authority).into_instruction(), &mut accounts),
+        Err(CurvyError::InvalidAccount(_))
+    ));
+
+    // ProgramData of another program
+    let mut ix = init(authority).into_instruction();
+    ix.accounts[2].pubkey = Pubkey::new_unique();
+    let mut accounts = [program_data_account(Some(authority))];
+    accounts[0].key = ix.accounts[2].pubkey;
+    assert!(matches!(
+        unwrap_account_error(process(ix, &mut accounts)),
+        CurvyError::InvalidKey(_)
+    ));
+}
+
+#[test]
+fn reference_curves_are_created_by_admin() {
+    let admin = Pubkey::new_unique();
+    let mint = Pubkey::new_unique();
+    let params = CurveParams::new("SOL-borrow", "y=x", 0, 10, 2, 6, [1; MAX_Y_CNT]);
+
+    let create = |admin, kind| {
+        CreateReferenceCurve {
+            curve: find_reference_curve_address(kind, &mint).0,
+            admin,
+            config: Config::find_address().0,
+            asset_mint: mint,
+            name_link: NameLink::find_address(&Config::find_address().0, &params.name).0,
+            kind,
+            params,
+        }
+        .into_instruction()
+    };
+    let mut accounts = [
+        config_account(admin, &[]),
+        TestAccount::new(mint, spl_token::ID, vec![0; Mint::LEN]),
+    ];
+
+    assert!(matches!(
+        unwrap_account_error(process(create(Pubkey::new_unique(), 0), &mut accounts)),
+        CurvyError::InvalidKey(_)
+    ));
+
+    // the address of another kind
+    let mut ix = create(admin, 0);
+    ix.accounts[0].pubkey = find_reference_curve_address(1, &mint).0;
+    assert!(matches!(
+        unwrap_account_error(process(ix, &mut accounts)),
+        CurvyError::InvalidKey(_)
+    ));
+
+    // admin passes the checks, creation goes on to the system program, not available here
+    let result = process(create(admin, 0), &mut accounts);
+    assert!(!matches!(
+        unwrap_account_error(result),
+        CurvyError::InvalidKey(_)
+    ));
+
+    accounts[1].owner = Pubkey::new_unique();
+    assert!(matches!(
+        process(create(admin, 0), &mut accounts),
+        Err(CurvyError::InvalidAccount(_))
+    ));
+
+    // without the config there is no admin
+    assert!(process(create(admin, 0), &mut accounts[1..]).is_err());
+}
+
+#[test]
+fn frozen_creation_creates_no_curves() {
+    let admin = Pubkey::new_unique();
+    let creator = Pubkey::new_unique();
+    let mint = Pubkey::new_unique();
+    let mut accounts = [
+        config_account(admin, &[creator]),
+        TestAccount::new(mint, spl_token::ID, vec![0; Mint::LEN]),
+    ];
+
+    let freeze = |admin, frozen| {
+        SetCreationFrozen {
+            config: Config::find_address().0,
+            admin,
+            frozen,
+        }
+        .into_instruction()
+    };
+
+    assert!(matches!(
+        unwrap_account_error(process(freeze(creator, true), &mut accounts)),
+        CurvyError::InvalidKey(_)
+    ));
+
+    process(freeze(admin, true), &mut accounts).unwrap();
+    assert!(Config::try_from_bytes(&accounts[0].data)
+        .unwrap()
+        .is_creation_frozen());
+    assert!(matches!(
+        unwrap_account_error(process(create_curve(creator), &mut accounts)),
+        CurvyError::CreationFrozen
+    ));
+    // reference curves of the admin as well
+    let reference = CreateReferenceCurve {
+        curve: find_reference_curve_address(0, &mint).0,
+        admin,
+        config: Config::find_address().0,
+        asset_mint: mint,
+        name_link: NameLink::find_address(&Config::find_address().0, &params(1).name).0,
+        kind: 0,
+        params: params(1),
+    };
+    assert!(matches!(
+        unwrap_account_error(process(reference.into_instruction(), &mut accounts)),
+        CurvyError::CreationFrozen
+    ));
+
+    process(freeze(admin, false), &mut accounts).unwrap();
+    let result = process(create_curve(creator), &mut accounts);
+    assert!(!matches!(
+        unwrap_account_error(result),
+        CurvyError::CreationFrozen
+    ));
+}
+
+#[test]
+fn reference_curves_are_managed_by_current_admin() {
+    let admin = Pubkey::new_unique();
+    let new_admin = Pubkey::new_unique();
+    let config = Config::find_address().0;
+    let curve = Pubkey::new_unique();
+    let mut accounts = [
+        config_account(admin, &[]),
+        curve_account(curve, config, 1),
+        name_link(config, params(1).name, curve),
+    ];
+
+    // reference curves take the config after the listed accounts
+    let with_config = |mut ix: Instruction| {
+        ix.accounts.push(AccountMeta::new_readonly(config, false));
+        ix
+    };
+    let status = |owner, status: CurveStatus| {
+        with_config(
+            SetCurveStatus {
+                curve,
+                owner,
+                status: status as u8,
+            }
+            .into_instruction(),
+        )
+    };
+    let alter = |owner| {
+        with_config(
+            AlterCurve {
+                curve,
+                owner,
+                params: params(2),
+            }
+            .into_instruction(),
+        )
+    };
+    let delete = |owner| {
+        with_config(
+            DeleteCurve {
+                curve,
+                owner,
+                name_link: NameLink::find_address(&config, &params(1).name).0,
+            }
+            .into_instruction(),
+        )
+    };
+
+    let mut ix = status(admin, CurveStatus::Deprecated);
+    ix.accounts.pop();
+    assert!(matches!(
+        unwrap_account_error(process(ix, &mut accounts)),
+        CurvyError::OperationCanNotBePerformed
+    ));
+
+    let rotate = SetConfig {
+        config,
+        admin,
+        new_admin,
+        creators: vec![],
+    };
+    process(rotate.into_instruction(), &mut accounts).unwrap();
+
+    // the admin at creation time is out
+    for ix in [
+        status(admin, CurveStatus::Deprecated),
+        alter(admin),
+        delete(admin),
+    ] {
+        assert!(matches!(
+            unwrap_account_error(process(ix, &mut accounts)),
+            CurvyError::InvalidKey(_)
+        ));
+    }
+
+    process(alter(new_admin), &mut accounts).unwrap();
+    assert_eq!(Curve::load(&accounts[1].data).unwrap().y[0], 2);
+
+    process(status(new_admin, CurveStatus::Deprecated), &mut accounts).unwrap();
+    process(status(new_admin, CurveStatus::Retired), &mut accounts).unwrap();
+    process(delete(new_admin), &mut accounts).unwrap();
+    assert!(accounts[2].data.iter().all(|byte| *byte == 0));
+}
@@ -0,0 +1,196 @@
+mod common;
+
+use std::mem::size_of;
+
+use solana_program::instruction::Instruction;
+use texture_common::_export::Pubkey;
+use texture_common::account::PodAccount;
+
+use curvy::error::CurvyError;
+use curvy::instruction::{ResizeCurve, SetCurveExtension, SetCurveTail};
+use curvy::state::curve::{Curve, CurveParams, MAX_Y_CNT};
+use curvy::state::curve_extension::{
+    self, CurveExtension, CurveGuardrails, CurveSchedule, CurveTags, ExtensionType,
+    EXTENSION_HEADER_SIZE, MAX_CURVE_EXTENSIONS_SIZE,
+};
+
+use common::TestAccount;
+
+#[test]
+fn extensions_are_replaced_and_removed() {
+    let tags = CurveTags::new("borrow-rate,usdc").unwrap();
+    let guardrails = CurveGuardrails::new(-5, 100);
+
+    let region = curve_extension::with(&[], &tags).unwrap();
+    let region = curve_extension::with(&region, &guardrails).unwrap();
+    assert_eq!(
+        curve_extension::get::<CurveTags>(&region).unwrap(),
+        Some(tags)
+    );
+    assert_eq!(
+        curve_extension::get::<CurveGuardrails>(&region).unwrap(),
+        Some(guardrails)
+    );
+    assert_eq!(
+        curve_extension::get::<CurveSchedule>(&region).unwrap(),
+        None
+    );
+    assert_eq!(guardrails.clamp(-7), -5);
+    assert_eq!(guardrails.clamp(7), 7);
+
+    // replacing keeps one entry of the type
+    let other = CurveTags::new("usdt").unwrap();
+    let replaced = curve_extension::with(&region, &other).unwrap();
+    assert_eq!(replaced.len(), region.len());
+    assert_eq!(
+        curve_extension::get::<CurveTags>(&replaced).unwrap(),
+        Some(other)
+    );
+
+    let removed = curve_extension::with_value(&replaced, ExtensionType::Tags as u16, &[]).unwrap();
+    assert_eq!(curve_extension::get::<CurveTags>(&removed).unwrap(), None);
+    assert_eq!(
+        curve_extension::get::<CurveGuardrails>(&removed).unwrap(),
+        Some(guardrails)
+    );
+
+    assert_eq!(tags.labels().collect::<Vec<_>>(), ["borrow-rate", "usdc"]);
+    assert!(tags.contains("usdc"));
+    assert!(!tags.contains("usd"));
+    assert!(CurveTags::new("more-than-sixteen-bytes").is_err());
+    assert!(curve_extension::with_value(&[], ExtensionType::Tags as u16, &[1, 2]).is_err());
+    assert!(curve_extension::with_value(&[], 77, &[1, 2]).is_err());
+}
+
+#[test]
+fn unknown_extensions_are_skipped() {
+    // an entry of a type added by a later program version
+    let mut region = vec![77, 0, 3, 0, 1, 2, 3];
+    region.extend(curve_extension::with(&[], &CurveSchedule::new(10, 20)).unwrap());
+
+    let schedule = curve_extension::get::<CurveSchedule>(&region)
+        .unwrap()
+        .unwrap();
+    assert!(!schedule.is_valid_at(9));
+    assert!(schedule.is_valid_at(10));
+    assert!(!schedule.is_valid_at(20));
+    assert!(CurveSchedule::new(0, 0).is_valid_at(i64::MAX));
+
+    // writes keep the unknown entry
+    let updated = curve_extension::with(&region, &CurveTags::new("usdc").unwrap()).unwrap();
+    assert_eq!(
+        curve_extension::find(&updated, 77).unwrap(),
+        Some(&[1, 2, 3][..])
+    );
+
+    // entries past the region
+    region.truncate(region.len() - 1);
+    assert!(curve_extension::get::<CurveSchedule>(&region).is_err());
+    assert!(curve_extension::get::<CurveTags>(&[1, 0]).is_err());
+
+    let too_big = vec![0; MAX_CURVE_EXTENSIONS_SIZE - EXTENSION_HEADER_SIZE];
+    let mut region = vec![77, 0];
+    region.extend((too_big.len() as u16).to_le_bytes());
+    region.extend(too_big);
+    assert!(curve_extension::with(&region, &CurveTags::new("usdc").unwrap()).is_err());
+}
+
+/// Runs `ix` on the curve account `data` funded well above rent, returns its data afterwards
+fn process(ix: Instruction, curve: &Pubkey, data: &[u8]) -> Result<Vec<u8>, CurvyError> {
+    let mut accounts = [TestAccount::program(*curve, data.to_vec()).with_lamports(1_000_000_000)];
+    common::process(ix, &mut accounts)?;
+    let [curve] = accounts;
+    Ok(curve.data)
+}
+
+#[test]
+fn extensions_stay_at_account_end() {
+    let curve = Pubkey::new_unique();
+    let owner = Pubkey::new_unique();
+    let params = CurveParams::new(
+        "SOL-borrow",
+        "y=x",
+        0,
+        10,
+        MAX_Y_CNT as u8,
+        6,
+        [1; MAX_Y_CNT],
+    );
+    let mut data = vec![0; Curve::SIZE];
+    Curve::init_bytes(&mut data, (params, owner)).unwrap();
+
+    let set = |by, extension_type: ExtensionType, value: Vec<u8>| {
+        SetCurveExtension {
+            curve,
+            owner: by,
+            extension_type: extension_type as u16,
+            value,
+        }
+        .into_instruction()
+    };
+    let tags = CurveTags::new("usdc").unwrap();
+    let schedule = CurveSchedule::new(0, 1_000);
+
+    assert!(process(
+        set(Pubkey::new_unique(), ExtensionType::Tags, tags.to_value()),
+        &curve,
+        &data
+    )
+    .is_err());
+    let data = process(
+        set(owner, ExtensionType::Tags, tags.to_value()),
+        &curve,
+        &data,
+    )
+    .unwrap();
+    let size = EXTENSION_HEADER_SIZE + size_of::<CurveTags>();
+    assert_eq!(data.len(), Curve::SIZE + size);
+    let loaded = Curve::load(&data).unwrap();
+    assert_eq!(loaded.extensions_len(), size);
+    assert_eq!(
+        curve_extension::get::<CurveTags>(loaded.extensions(&data)).unwrap(),
+        Some(tags)
+    );
+
+    // the tail grows in front of the extensions
+    let resize = ResizeCurve {
+        curve,
+        owner,
+        y_capacity: MAX_Y_CNT as u16 + 2,
+    };
+    let data = process(resize.into_instruction(), &curve, &data).unwrap();
+    assert_eq!(data.len(), Curve::size_for(MAX_Y_CNT + 2) + size);
+    let tail = SetCurveTail {
+        curve,
+        owner,
+        start: 0,
+        y: vec![7, 8],
+    };
+    let data = process(tail.into_instruction(), &curve, &data).unwrap();
+    let data = process(
+        set(owner, ExtensionType::Schedule, schedule.to_value()),
+        &curve,
+        &data,
+    )
+    .unwrap();
+
+    let loaded = Curve::load(&data).unwrap();
+    assert_eq!(loaded.tail_y(&data), vec![7, 8]);
+    let extensions = loaded.extensions(&data);
+    assert_eq!(
+        curve_extension::get::<CurveTags>(extensions).unwrap(),
+        Some(tags)
+    );
+    assert_eq!(
+        curve_extension::get::<CurveSchedule>(extensions).unwrap(),
+        Some(schedule)
+    );
+
+    // removing shrinks the account back
+    let data = process(set(owner, ExtensionType::Tags, vec![]), &curve, &data).unwrap();
+    let data = process(set(owner, ExtensionType::Schedule, vec![]), &curve, &data).unwrap();
+    assert_eq!(data.len(), Curve::size_for(MAX_Y_CNT + 2));
+    let loaded = Curve::load(&data).unwrap();
+    assert_eq!(loaded.extensions_len(), 0);
+    assert_eq!(loaded.tail_y(&data), vec![7, 8]);
+}
@@ -0,0 +1,37 @@
+use texture_common::_export::Pubkey;
+use texture_common::account::PodAccount;
+
+use curvy::state::curve::MAX_Y_CNT;
+use curvy::state::curve_series::{CurveSeries, MAX_EXTRA_SERIES};
+use curvy::state::{probe, AccountKind};
+
+#[test]
+fn series_are_set_in_order() {
+    let curve = Pubkey::new_unique();
+    let mut data = vec![0; CurveSeries::SIZE];
+    CurveSeries::init_bytes(&mut data, curve).unwrap();
+    let series = CurveSeries::try_from_bytes_mut(&mut data).unwrap();
+
+    assert_eq!(series.curve, curve);
+    assert!(series.series(1).is_none());
+
+    assert!(
+        !series.set_series(0, [1; MAX_Y_CNT]),
+        "series 0 is the curve itself"
+    );
+    assert!(
+        !series.set_series(2, [2; MAX_Y_CNT]),
+        "series 1 is not set yet"
+    );
+    assert!(series.set_series(1, [1; MAX_Y_CNT]));
+    assert!(series.set_series(2, [2; MAX_Y_CNT]));
+    assert!(!series.set_series(MAX_EXTRA_SERIES as u8 + 1, [3; MAX_Y_CNT]));
+
+    // overwriting keeps the count
+    assert!(series.set_series(1, [4; MAX_Y_CNT]));
+    assert_eq!(series.count, 2);
+    assert_eq!(series.series(1), Some(&[4; MAX_Y_CNT]));
+    assert_eq!(series.series(2), Some(&[2; MAX_Y_CNT]));
+
+    assert_eq!(probe(&data), Some(AccountKind::CurveSeries { version: 1 }));
+}
@@ -0,0 +1,70 @@
+use texture_common::_export::Pubkey;
+use texture_common::account::PodAccount;
+
+use curvy::error::CurvyError;
+use curvy::state::curve::{Curve, CurveParams, MAX_TOTAL_Y_CNT, MAX_Y_CNT};
+
+fn curve_data(y_count: u8, y_capacity: usize) -> Vec<u8> {
+    let params = CurveParams::new("SOL-borrow", "y=x", 0, 10, y_count, 6, [1; MAX_Y_CNT]);
+    let mut data = vec![0; Curve::SIZE];
+    Curve::init_bytes(&mut data, (params, Pubkey::new_unique())).unwrap();
+    data.resize(Curve::size_for(y_capacity), 0);
+    data
+}
+
+#[test]
+fn tail_is_set_in_order() {
+    let mut data = curve_data(MAX_Y_CNT as u8, MAX_Y_CNT + 4);
+    assert_eq!(Curve::y_capacity(data.len()), MAX_Y_CNT + 4);
+
+    let (curve, tail) = Curve::load_mut_with_tail(&mut data).unwrap();
+    assert!(matches!(
+        curve.set_tail_y(tail, 1, &[2]),
+        Err(CurvyError::InvalidParams)
+    ));
+    assert!(matches!(
+        curve.set_tail_y(tail, 0, &[2; 5]),
+        Err(CurvyError::InvalidParams)
+    ));
+
+    curve.set_tail_y(tail, 0, &[2, 3]).unwrap();
+    curve.set_tail_y(tail, 2, &[4, 5]).unwrap();
+    assert_eq!(curve.total_y_count(), MAX_Y_CNT + 4);
+
+    let curve = *Curve::load(&data).unwrap();
+    assert_eq!(curve.tail_y(&data), vec![2, 3, 4, 5]);
+    assert_eq!(curve.samples(&data).len(), MAX_Y_CNT + 4);
+
+    // cut the tail
+    let (curve, tail) = Curve::load_mut_with_tail(&mut data).unwrap();
+    curve.set_tail_y(tail, 1, &[]).unwrap();
+    let curve = Curve::load(&data).unwrap();
+    assert_eq!(curve.tail_y(&data), vec![2]);
+}
+
+#[test]
+fn tail_needs_full_y_table() {
+    let mut data = curve_data(10, MAX_Y_CNT + 4);
+
+    let (curve, tail) = Curve::load_mut_with_tail(&mut data).unwrap();
+    assert!(curve.set_tail_y(tail, 0, &[2]).is_err());
+
+    let mut data = curve_data(MAX_Y_CNT as u8, MAX_Y_CNT + 4);
+    let (curve, tail) = Curve::load_mut_with_tail(&mut data).unwrap();
+    curve.set_tail_y(tail, 0, &[2]).unwrap();
+
+    // shortening the `y` table drops the tail
+    let params = CurveParams::new("SOL-borrow", "y=x", 0, 10, 10, 6, [1; MAX_Y_CNT]);
+    curve.set_params(params);
+    assert_eq!(curve.tail_y_count, 0);
+}
+
+#[test]
+fn resized_account_size() {
+    assert_eq!(Curve::size_for(MAX_Y_CNT), Curve::SIZE);
+    assert_eq!(Curve::size_for(10), Curve::SIZE);
+    assert_eq!(
+        Curve::size_for(MAX_TOTAL_Y_CNT),
+        Curve::SIZE + 4 * (MAX_TOTAL_Y_CNT - MAX_Y_CNT)
+    );
+}
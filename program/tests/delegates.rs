@@ -0,0 +1,302 @@
+mod common;
+
+use solana_program::instruction::AccountMeta;
+use solana_program::pubkey::Pubkey;
+use texture_common::account::PodAccount;
+
+use curvy::error::CurvyError;
+use curvy::instruction::{
+    AcceptOwner, AddDelegate, AlterCurve, BatchAlter, DeleteCurve, PatchY, ProposeOwner,
+    RemoveDelegate, SetCurveStatus, UpdateCurveData, UpdateCurveMetadata,
+};
+use curvy::state::curve::{Curve, CurveStatus, MAX_CURVE_DELEGATES, SYMBOL_MAX_SIZE};
+use curvy::state::name_link::NameLink;
+use curvy::state::utils::str_to_array;
+
+use common::{
+    curve_account, curve_data, name_link, params, process, set_slot, set_unix_timestamp,
+    unwrap_account_error, TestAccount,
+};
+
+/// Slot and unix timestamp of the Clock sysvar in tests
+const SLOT: u64 = 100;
+const UNIX_TIMESTAMP: i64 = 1_700_000_000;
+
+fn add(accounts: &mut [TestAccount], owner: Pubkey, delegate: Pubkey) -> Result<(), CurvyError> {
+    let ix = AddDelegate {
+        curve: accounts[0].key,
+        owner,
+        delegate,
+    };
+    process(ix.into_instruction(), accounts)
+}
+
+fn alter(accounts: &mut [TestAccount], by: Pubkey, y: u32) -> Result<(), CurvyError> {
+    let ix = AlterCurve {
+        curve: accounts[0].key,
+        owner: by,
+        params: params(y),
+    };
+    process(ix.into_instruction(), accounts)
+}
+
+#[test]
+fn delegate_updates_samples_only() {
+    let curve = Pubkey::new_unique();
+    let owner = Pubkey::new_unique();
+    let delegate = Pubkey::new_unique();
+    let mut accounts = [curve_account(curve, owner, 1)];
+    set_slot(SLOT);
+    set_unix_timestamp(UNIX_TIMESTAMP);
+
+    assert!(alter(&mut accounts, delegate, 2).is_err());
+    assert_eq!(
+        Curve::load(&accounts[0].data).unwrap().last_update_slot(),
+        None
+    );
+    assert_eq!(Curve::load(&accounts[0].data).unwrap().revision(), 0);
+    assert!(
+        add(&mut accounts, delegate, delegate).is_err(),
+        "only owner adds delegates"
+    );
+    add(&mut accounts, owner, delegate).unwrap();
+
+    alter(&mut accounts, delegate, 2).unwrap();
+    let patch = PatchY {
+        curve,
+        owner: delegate,
+        start_index: 1,
+        values: vec![3],
+    };
+    process(patch.into_instruction(), &mut accounts).unwrap();
+    let loaded = Curve::load(&accounts[0].data).unwrap();
+    assert_eq!(loaded.y[..2], [2, 3]);
+    // sample updates are stamped by the clock
+    assert_eq!(loaded.last_update_slot(), Some(SLOT));
+    assert_eq!(loaded.last_update_unix(), Some(UNIX_TIMESTAMP));
+    assert_eq!(loaded.revision(), 2);
+
+    let metadata = UpdateCurveMetadata {
+        curve,
+        owner: delegate,
+        name_link: NameLink::find_address(&delegate, &params(1).name).0,
+        new_name_link: NameLink::find_address(&delegate, &[0; SYMBOL_MAX_SIZE]).0,
+        name: [0; SYMBOL_MAX_SIZE],
+        formula: [0; SYMBOL_MAX_SIZE],
+    };
+    assert!(process(metadata.into_instruction(), &mut accounts).is_err());
+    let propose = ProposeOwner {
+        curve,
+        owner: delegate,
+        new_owner: delegate,
+    };
+    assert!(process(propose.into_instruction(), &mut accounts).is_err());
+
+    let remove = RemoveDelegate {
+        curve,
+        owner,
+        delegate,
+    };
+    process(remove.into_instruction(), &mut accounts).unwrap();
+    assert!(alter(&mut accounts, delegate, 4).is_err());
+}
+
+#[test]
+fn delegate_keeps_params_other_than_samples() {
+    let curve = Pubkey::new_unique();
+    let owner = Pubkey::new_unique();
+    let delegate = Pubkey::new_unique();
+    let mut accounts = [curve_account(curve, owner, 1)];
+    add(&mut accounts, owner, delegate).unwrap();
+
+    let mut renamed = params(2);
+    renamed.name = str_to_array("SOL-supply");
+    let mut redecimaled = params(2);
+    redecimaled.y_decimals = 9;
+    let mut batch = BatchAlter {
+        owner: delegate,
+        params: vec![(&redecimaled).into()],
+    }
+    .into_instruction();
+    batch.accounts.push(AccountMeta::new(curve, false));
+    let update = |decimals| UpdateCurveData {
+        curve,
+        owner: delegate,
+        x0: 0,
+        x_step: 10,
+        y_count: 3,
+        decimals,
+        y: params(2).y,
+    };
+
+    for ix in [
+        AlterCurve {
+            curve,
+            owner: delegate,
+            params: renamed,
+        }
+        .into_instruction(),
+        AlterCurve {
+            curve,
+            owner: delegate,
+            params: redecimaled,
+        }
+        .into_instruction(),
+        batch,
+        update(9).into_instruction(),
+    ] {
+        assert!(matches!(
+            unwrap_account_error(process(ix, &mut accounts)),
+            CurvyError::OperationCanNotBePerformed
+        ));
+    }
+    assert_eq!(Curve::load(&accounts[0].data).unwrap().y[0], 1);
+
+    // more samples of the same axes
+    process(update(6).into_instruction(), &mut accounts).unwrap();
+    let loaded = Curve::load(&accounts[0].data).unwrap();
+    assert_eq!((loaded.y_count, loaded.y[0]), (3, 2));
+
+    // the owner changes any params
+    let alter = AlterCurve {
+        curve,
+        owner,
+        params: redecimaled,
+    };
+    process(alter.into_instruction(), &mut accounts).unwrap();
+    assert_eq!(Curve::load(&accounts[0].data).unwrap().y_decimals, 9);
+}
+
+#[test]
+fn split_decimals_are_not_updated_with_common_ones() {
+    let curve = Pubkey::new_unique();
+    let owner = Pubkey::new_unique();
+    let mut data = curve_data(owner, 1);
+    Curve::load_mut(&mut data).unwrap().y_decimals = 9;
+    let mut accounts = [TestAccount::program(curve, data)];
+
+    let update = |decimals| UpdateCurveData {
+        curve,
+        owner,
+        x0: 0,
+        x_step: 10,
+        y_count: 3,
+        decimals,
+        y: params(2).y,
+    };
+
+    // either axis would lose its decimals, even for the owner
+    for decimals in [6, 9] {
+        assert!(matches!(
+            unwrap_account_error(process(update(decimals).into_instruction(), &mut accounts)),
+            CurvyError::OperationCanNotBePerformed
+        ));
+    }
+    let loaded = Curve::load(&accounts[0].data).unwrap();
+    assert_eq!(
+        (loaded.x_decimals, loaded.y_decimals, loaded.y[0]),
+        (6, 9, 1)
+    );
+}
+
+#[test]
+fn delegate_does_not_delete() {
+    let curve = Pubkey::new_unique();
+    let owner = Pubkey::new_unique();
+    let delegate = Pubkey::new_unique();
+    let mut accounts = [curve_account(curve, owner, 1)];
+
+    add(&mut accounts, owner, delegate).unwrap();
+    for status in [CurveStatus::Deprecated, CurveStatus::Retired] {
+        let ix = SetCurveStatus {
+            curve,
+            owner,
+            status: status as u8,
+        };
+        process(ix.into_instruction(), &mut accounts).unwrap();
+    }
+
+    let delete = |by, accounts: &mut [TestAccount]| {
+        let ix = DeleteCurve {
+            curve,
+            owner: by,
+            name_link: NameLink::find_address(&by, &params(1).name).0,
+        };
+        process(ix.into_instruction(), accounts)
+    };
+    assert!(delete(delegate, &mut accounts).is_err());
+    delete(owner, &mut accounts).unwrap();
+}
+
+#[test]
+fn delegates_are_limited() {
+    let curve = Pubkey::new_unique();
+    let owner = Pubkey::new_unique();
+    let mut accounts = [curve_account(curve, owner, 1)];
+
+    assert!(add(&mut accounts, owner, owner).is_err());
+    assert!(add(&mut accounts, owner, Pubkey::default()).is_err());
+
+    let delegates = (0..MAX_CURVE_DELEGATES)
+        .map(|_| Pubkey::new_unique())
+        .collect::<Vec<_>>();
+    for delegate in &delegates {
+        add(&mut accounts, owner, *delegate).unwrap();
+    }
+    assert!(add(&mut accounts, owner, Pubkey::new_unique()).is_err());
+    assert!(add(&mut accounts, owner, delegates[0]).is_err());
+
+    let remove = |delegate, accounts: &mut [TestAccount]| {
+        let ix = RemoveDelegate {
+            curve,
+            owner,
+            delegate,
+        };
+        process(ix.into_instruction(), accounts)
+    };
+    remove(delegates[1], &mut accounts).unwrap();
+    assert!(remove(delegates[1], &mut accounts).is_err());
+    assert!(remove(Pubkey::default(), &mut accounts).is_err());
+
+    // the freed slot is reused
+    add(&mut accounts, owner, Pubkey::new_unique()).unwrap();
+    assert_eq!(
+        Curve::load(&accounts[0].data).unwrap().delegates().count(),
+        MAX_CURVE_DELEGATES
+    );
+}
+
+#[test]
+fn new_owner_drops_delegates() {
+    let curve = Pubkey::new_unique();
+    let owner = Pubkey::new_unique();
+    let new_owner = Pubkey::new_unique();
+    let delegate = Pubkey::new_unique();
+    let mut accounts = [
+        curve_account(curve, owner, 1),
+        name_link(new_owner, params(1).name, Pubkey::default()),
+    ];
+
+    add(&mut accounts, owner, delegate).unwrap();
+
+    let propose = ProposeOwner {
+        curve,
+        owner,
+        new_owner,
+    };
+    process(propose.into_instruction(), &mut accounts).unwrap();
+    let accept = AcceptOwner {
+        curve,
+        new_owner,
+        owner,
+        name_link: NameLink::find_address(&owner, &params(1).name).0,
+        new_name_link: NameLink::find_address(&new_owner, &params(1).name).0,
+    };
+    process(accept.into_instruction(), &mut accounts).unwrap();
+
+    assert_eq!(
+        Curve::load(&accounts[0].data).unwrap().delegates().count(),
+        0
+    );
+    assert!(alter(&mut accounts, delegate, 2).is_err());
+}
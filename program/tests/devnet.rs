@@ -0,0 +1,27 @@
+use borsh::BorshDeserialize;
+
+use curvy::instruction::CurvyInstruction;
+use curvy::processor::DEVNET_TAG;
+
+/// Test-only instructions are told apart by the first byte, so regular builds reject them
+#[test]
+fn devnet_tag_is_not_an_instruction() {
+    assert!(CurvyInstruction::try_from_slice(&[DEVNET_TAG]).is_err());
+}
+
+#[cfg(feature = "devnet-utils")]
+#[test]
+fn devnet_instruction_data() {
+    use curvy::devnet::DevnetInstruction;
+    use texture_common::_export::Pubkey;
+
+    let owner = Pubkey::new_unique();
+    let ix = DevnetInstruction::ForceSetOwner { owner }
+        .into_instruction(Pubkey::new_unique(), Pubkey::new_unique());
+
+    assert_eq!(ix.data[0], DEVNET_TAG);
+    assert!(matches!(
+        DevnetInstruction::try_from_slice(&ix.data[1..]).unwrap(),
+        DevnetInstruction::ForceSetOwner { owner: decoded } if decoded == owner
+    ));
+}
@@ -0,0 +1,43 @@
+use solana_program::program_error::ProgramError;
+use solana_program::pubkey::Pubkey;
+
+use curvy::error::{CurvyError, ERROR_CODES};
+
+/// Codes in the table are the ones actually returned, including errors wrapped with account
+#[test]
+fn table_matches_returned_codes() {
+    let errors = [
+        CurvyError::InvalidParams,
+        CurvyError::OwnerMismatch,
+        CurvyError::UnsupportedVersion {
+            found: 2,
+            max_supported: 1,
+        },
+        CurvyError::Account {
+            account: Pubkey::new_unique(),
+            error: Box::new(CurvyError::OperationCanNotBePerformed),
+        },
+    ];
+    let names = [
+        "InvalidParams",
+        "OwnerMismatch",
+        "UnsupportedVersion",
+        "OperationCanNotBePerformed",
+    ];
+
+    for (error, name) in errors.into_iter().zip(names) {
+        let ProgramError::Custom(code) = ProgramError::from(error) else {
+            panic!("{name} is not a custom error");
+        };
+        assert_eq!(CurvyError::name_by_code(code), Some(name));
+    }
+}
+
+#[test]
+fn table_is_ordered() {
+    assert!(ERROR_CODES
+        .windows(2)
+        .all(|pair| pair[0].code <= pair[1].code));
+    assert_eq!(CurvyError::name_by_code(6), Some("PodAccount"));
+    assert_eq!(CurvyError::name_by_code(2), None);
+}
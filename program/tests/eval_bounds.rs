@@ -0,0 +1,105 @@
+//! Evaluation at the extremes of supported ranges, see [curvy::eval]. Tests are built with
+//! overflow checks, so any overflow panics.
+
+use texture_common::_export::Pubkey;
+use texture_common::account::PodAccount;
+
+use curvy::eval::{calc_y_fixed, calc_y_u128, MAX_UPSCALE_DECIMALS};
+use curvy::state::curve::{Curve, CurveParams, CurveY, MAX_Y_CNT};
+
+const MAX: u64 = u32::MAX as u64;
+
+/// Curve with max `x0` and `x_step` alternating between 0 and max `y`
+fn extreme_curve(y_decimals: u8) -> Curve {
+    let mut y = [0; MAX_Y_CNT];
+    for (idx, y) in y.iter_mut().enumerate() {
+        *y = if idx % 2 == 0 { 0 } else { CurveY::MAX };
+    }
+    let params = CurveParams::new("max", "", u32::MAX, u32::MAX, MAX_Y_CNT as u8, 9, y);
+    let mut curve = Curve::from_init_params((params, Pubkey::new_unique()));
+    curve.y_decimals = y_decimals;
+    curve
+}
+
+#[test]
+fn max_samples_and_grid() {
+    let curve = extreme_curve(9);
+    let samples = curve.samples(bytemuck::bytes_of(&curve));
+    let last_x = MAX + (MAX_Y_CNT as u64 - 1) * MAX;
+
+    // rising segment right before its end and falling one right after its start
+    let rising = MAX + MAX - 1;
+    let falling = MAX + MAX + 1;
+    assert_eq!(calc_y_fixed(&curve, rising).unwrap(), MAX - 1);
+    assert_eq!(calc_y_fixed(&curve, falling).unwrap(), MAX - 1);
+
+    for x in [MAX, rising, falling, last_x - 1, last_x] {
+        assert_eq!(
+            calc_y_fixed(&curve, x).unwrap() as i64,
+            curve.evaluate(&samples, x as i64).unwrap(),
+            "x={x}"
+        );
+        assert_eq!(
+            calc_y_u128(&curve, x, 9).unwrap(),
+            calc_y_fixed(&curve, x).unwrap() as u128,
+            "x={x}"
+        );
+    }
+
+    for x in [0, MAX - 1, last_x + 1, u64::MAX] {
+        assert!(calc_y_fixed(&curve, x).is_err(), "x={x}");
+        assert!(calc_y_u128(&curve, x, 9).is_err(), "x={x}");
+    }
+}
+
+#[test]
+fn negative_x0_at_max() {
+    let mut curve = extreme_curve(9);
+    curve.x0_negative = 1;
+
+    // the grid starts at -x0, the first segment rises to 0
+    assert_eq!(calc_y_fixed(&curve, 0).unwrap(), MAX);
+    assert_eq!(calc_y_fixed(&curve, MAX).unwrap(), 0);
+    assert!(calc_y_fixed(&curve, u64::MAX).is_err());
+    assert!(calc_y_u128(&curve, u64::MAX, 18).is_err());
+}
+
+#[test]
+fn max_upscale() {
+    let curve = extreme_curve(9);
+    let decimals = 9 + MAX_UPSCALE_DECIMALS;
+    let scale = 10_u128.pow(MAX_UPSCALE_DECIMALS as u32);
+
+    assert_eq!(
+        calc_y_u128(&curve, 2 * MAX, decimals).unwrap(),
+        MAX as u128 * scale
+    );
+    assert_eq!(
+        calc_y_u128(&curve, 2 * MAX - 1, decimals).unwrap(),
+        (MAX as u128 - 1) * scale
+    );
+    assert_eq!(
+        calc_y_u128(&curve, 2 * MAX + 1, decimals).unwrap(),
+        (MAX as u128 - 1) * scale
+    );
+
+    // WAD out of 9 decimals
+    assert_eq!(
+        calc_y_u128(&curve, 2 * MAX, 18).unwrap(),
+        MAX as u128 * 1_000_000_000
+    );
+    assert!(calc_y_u128(&curve, 2 * MAX, decimals + 1).is_err());
+}
+
+#[test]
+fn downscale() {
+    let curve = extreme_curve(9);
+    // 4.294967295
+    assert_eq!(calc_y_u128(&curve, 2 * MAX, 0).unwrap(), 4);
+    assert_eq!(calc_y_u128(&curve, 2 * MAX, 3).unwrap(), 4_294);
+
+    // decimals of a garbage account do not overflow
+    let curve = extreme_curve(u8::MAX);
+    assert_eq!(calc_y_u128(&curve, 2 * MAX, 0).unwrap(), 0);
+    assert_eq!(calc_y_u128(&curve, 2 * MAX, u8::MAX).unwrap(), MAX as u128);
+}
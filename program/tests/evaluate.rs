@@ -0,0 +1,115 @@
+use texture_common::_export::Pubkey;
+use texture_common::account::PodAccount;
+
+use curvy::error::CurvyError;
+use curvy::eval::calc_y_fixed;
+use curvy::instruction::CurvyInstruction;
+use curvy::state::curve::{Curve, CurveParams, CurveY, MAX_Y_CNT};
+use curvy::state::curve_evaluation::CurveEvaluation;
+use curvy::state::{probe, AccountKind};
+
+fn curve(x0: u32, x_step: u32, samples: &[CurveY]) -> Curve {
+    let mut y = [0; MAX_Y_CNT];
+    y[..samples.len()].copy_from_slice(samples);
+    let params = CurveParams::new("test", "y=f(x)", x0, x_step, samples.len() as u8, 2, y);
+    Curve::from_init_params((params, Pubkey::new_unique()))
+}
+
+#[test]
+fn interpolates_between_samples() {
+    // (0.10; 10.00), (0.20; 20.00), (0.30; 40.00)
+    let curve = curve(10, 10, &[1_000, 2_000, 4_000]);
+    let samples = curve.samples(bytemuck::bytes_of(&curve));
+
+    assert_eq!(curve.evaluate(&samples, 10).unwrap(), 1_000);
+    assert_eq!(curve.evaluate(&samples, 15).unwrap(), 1_500);
+    assert_eq!(curve.evaluate(&samples, 20).unwrap(), 2_000);
+    assert_eq!(curve.evaluate(&samples, 27).unwrap(), 3_400);
+    assert_eq!(curve.evaluate(&samples, 30).unwrap(), 4_000);
+
+    for x in [9, 31, -10] {
+        assert!(matches!(
+            curve.evaluate(&samples, x),
+            Err(CurvyError::InvalidParams)
+        ));
+    }
+}
+
+#[test]
+fn rounds_down() {
+    let curve = curve(0, 3, &[0, 1]);
+    let samples = curve.samples(bytemuck::bytes_of(&curve));
+    assert_eq!(curve.evaluate(&samples, 2).unwrap(), 0);
+
+    // decreasing signed values, -1/3 and -2/3 go down to -1
+    let mut curve = curve;
+    curve.y[1] = -1_i32 as CurveY;
+    curve.y_signed = 1;
+    let samples = curve.samples(bytemuck::bytes_of(&curve));
+    assert_eq!(curve.evaluate(&samples, 1).unwrap(), -1);
+    assert_eq!(curve.evaluate(&samples, 2).unwrap(), -1);
+    assert_eq!(curve.evaluate(&samples, 3).unwrap(), -1);
+}
+
+#[test]
+fn negative_x() {
+    // x from -0.10 to 0.10
+    let mut curve = curve(10, 10, &[0, 100, 200]);
+    curve.x0_negative = 1;
+    let samples = curve.samples(bytemuck::bytes_of(&curve));
+
+    assert_eq!(curve.evaluate(&samples, -10).unwrap(), 0);
+    assert_eq!(curve.evaluate(&samples, -5).unwrap(), 50);
+    assert_eq!(curve.evaluate(&samples, 10).unwrap(), 200);
+    assert!(curve.evaluate(&samples, -11).is_err());
+}
+
+#[test]
+fn fixed_matches_evaluate() {
+    // increasing, flat and decreasing segments with a step not dividing their rises
+    let mut curve = curve(5, 7, &[100, 350, 350, 20, 4_000_000_000]);
+    let samples = curve.samples(bytemuck::bytes_of(&curve));
+    for x in 5..=33 {
+        assert_eq!(
+            calc_y_fixed(&curve, x).unwrap() as i64,
+            curve.evaluate(&samples, x as i64).unwrap(),
+            "x={x}"
+        );
+    }
+    assert!(calc_y_fixed(&curve, 4).is_err());
+    assert!(calc_y_fixed(&curve, 34).is_err());
+
+    curve.x0_negative = 1;
+    let samples = curve.samples(bytemuck::bytes_of(&curve));
+    for x in 0..=23 {
+        assert_eq!(
+            calc_y_fixed(&curve, x).unwrap() as i64,
+            curve.evaluate(&samples, x as i64).unwrap(),
+            "x={x}"
+        );
+    }
+    assert!(calc_y_fixed(&curve, 24).is_err());
+
+    curve.y_signed = 1;
+    assert!(calc_y_fixed(&curve, 0).is_err());
+}
+
+#[test]
+fn result_account() {
+    let authority = Pubkey::new_unique();
+    let mut data = vec![0; CurveEvaluation::SIZE];
+    assert_eq!(probe(&data), None);
+
+    CurveEvaluation::init_bytes(&mut data, authority).unwrap();
+    assert_eq!(
+        probe(&data),
+        Some(AccountKind::CurveEvaluation { version: 1 })
+    );
+    assert_eq!(
+        CurveEvaluation::try_from_bytes(&data).unwrap().authority,
+        authority
+    );
+
+    let ix = borsh::to_vec(&CurvyInstruction::EvaluateCurve { x: -2 }).unwrap();
+    assert_eq!(ix, [30, 254, 255, 255, 255, 255, 255, 255, 255]);
+}
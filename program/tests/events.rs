@@ -0,0 +1,116 @@
+mod common;
+
+use solana_program::pubkey::Pubkey;
+
+use curvy::events::{self, CurveAltered, CurveDeleted, CurveEvent, EVENT_DISCRIMINATOR};
+use curvy::instruction::{AddDelegate, AlterCurve, DeleteCurve, PatchY, SetCurveStatus};
+use curvy::state::curve::CurveStatus;
+use curvy::state::name_link::NameLink;
+
+use common::{curve_account, params, process, take_events};
+
+#[test]
+fn alterations_and_deletion_are_logged() {
+    let curve = Pubkey::new_unique();
+    let owner = Pubkey::new_unique();
+    let delegate = Pubkey::new_unique();
+    let mut accounts = [curve_account(curve, owner, 1)];
+    let name = params(1).name;
+
+    let add = AddDelegate {
+        curve,
+        owner,
+        delegate,
+    };
+    process(add.into_instruction(), &mut accounts).unwrap();
+    let alter = AlterCurve {
+        curve,
+        owner,
+        params: params(2),
+    };
+    process(alter.into_instruction(), &mut accounts).unwrap();
+    let patch = PatchY {
+        curve,
+        owner: delegate,
+        start_index: 1,
+        values: vec![3],
+    };
+    process(patch.into_instruction(), &mut accounts).unwrap();
+
+    assert_eq!(
+        take_events(),
+        [
+            CurveEvent::Altered(CurveAltered {
+                curve,
+                owner,
+                authority: owner,
+                name,
+                old_y_hash: events::y_hash(&[1_u32, 1]),
+                new_y_hash: events::y_hash(&[2_u32, 2]),
+            }),
+            CurveEvent::Altered(CurveAltered {
+                curve,
+                owner,
+                authority: delegate,
+                name,
+                old_y_hash: events::y_hash(&[2_u32, 2]),
+                new_y_hash: events::y_hash(&[2_u32, 3]),
+            }),
+        ]
+    );
+
+    // failed alteration logs nothing
+    let alter = AlterCurve {
+        curve,
+        owner: Pubkey::new_unique(),
+        params: params(4),
+    };
+    assert!(process(alter.into_instruction(), &mut accounts).is_err());
+    assert!(take_events().is_empty());
+
+    for status in [CurveStatus::Deprecated, CurveStatus::Retired] {
+        let ix = SetCurveStatus {
+            curve,
+            owner,
+            status: status as u8,
+        };
+        process(ix.into_instruction(), &mut accounts).unwrap();
+    }
+    let delete = DeleteCurve {
+        curve,
+        owner,
+        name_link: NameLink::find_address(&owner, &name).0,
+    };
+    process(delete.into_instruction(), &mut accounts).unwrap();
+    assert_eq!(
+        take_events(),
+        [CurveEvent::Deleted(CurveDeleted {
+            curve,
+            owner,
+            name,
+            y_hash: events::y_hash(&[2_u32, 3]),
+        })]
+    );
+}
+
+#[test]
+fn foreign_data_is_not_decoded() {
+    let event = CurveEvent::Deleted(CurveDeleted {
+        curve: Pubkey::new_unique(),
+        owner: Pubkey::new_unique(),
+        name: params(1).name,
+        y_hash: [7; 32],
+    });
+    let data = borsh::to_vec(&event).unwrap();
+
+    assert_eq!(
+        CurveEvent::decode(&[EVENT_DISCRIMINATOR, &data]),
+        Some(event)
+    );
+    assert_eq!(CurveEvent::decode(&[b"otherevt", &data]), None);
+    assert_eq!(CurveEvent::decode(&[EVENT_DISCRIMINATOR]), None);
+    assert_eq!(
+        CurveEvent::decode(&[EVENT_DISCRIMINATOR, &data[..data.len() - 1]]),
+        None
+    );
+}
@@ -0,0 +1,139 @@
+mod common;
+
+use solana_program::pubkey::Pubkey;
+use texture_common::account::PodAccount;
+
+use curvy::error::CurvyError;
+use curvy::instruction::{AlterCurve, EvaluateCurve, PatchY, SetCurveExpiry, SetCurveStatus};
+use curvy::state::curve::{Curve, CurveStatus};
+use curvy::state::curve_evaluation::CurveEvaluation;
+
+use common::{
+    curve_account, params, process, set_unix_timestamp, unwrap_account_error, TestAccount,
+};
+
+fn set_expiry(
+    accounts: &mut [TestAccount],
+    owner: Pubkey,
+    valid_until: i64,
+    validity_period: u64,
+) -> Result<(), CurvyError> {
+    let ix = SetCurveExpiry {
+        curve: accounts[0].key,
+        owner,
+        valid_until,
+        validity_period,
+    };
+    process(ix.into_instruction(), accounts)
+}
+
+fn evaluate(accounts: &mut [TestAccount], authority: Pubkey) -> Result<(), CurvyError> {
+    let ix = EvaluateCurve {
+        curve: accounts[0].key,
+        result: accounts[1].key,
+        authority,
+        x: 5,
+    };
+    process(ix.into_instruction(), accounts)
+}
+
+#[test]
+fn expired_curve_is_not_evaluated() {
+    let owner = Pubkey::new_unique();
+    let authority = Pubkey::new_unique();
+    let mut accounts = [
+        curve_account(Pubkey::new_unique(), owner, 1),
+        TestAccount::new(
+            Pubkey::new_unique(),
+            curvy::ID,
+            vec![0; CurveEvaluation::SIZE],
+        ),
+    ];
+    let loaded = |accounts: &[TestAccount]| *Curve::load(&accounts[0].data).unwrap();
+
+    set_unix_timestamp(1_000);
+    evaluate(&mut accounts, authority).unwrap();
+
+    assert!(set_expiry(&mut accounts, Pubkey::new_unique(), 2_000, 0).is_err());
+    assert!(set_expiry(&mut accounts, owner, -1, 0).is_err());
+    set_expiry(&mut accounts, owner, 2_000, 0).unwrap();
+    assert_eq!(loaded(&accounts).valid_until(), Some(2_000));
+
+    set_unix_timestamp(1_999);
+    evaluate(&mut accounts, authority).unwrap();
+    set_unix_timestamp(2_000);
+    assert!(matches!(
+        unwrap_account_error(evaluate(&mut accounts, authority)),
+        CurvyError::CurveExpired { valid_until: 2_000 }
+    ));
+
+    // without a validity period alterations keep the expiry
+    let curve = accounts[0].key;
+    let alter = |y| AlterCurve {
+        curve,
+        owner,
+        params: params(y),
+    };
+    let ix = alter(2).into_instruction();
+    process(ix, &mut accounts).unwrap();
+    assert_eq!(loaded(&accounts).valid_until(), Some(2_000));
+
+    set_expiry(&mut accounts, owner, 2_000, 600).unwrap();
+    let ix = alter(3).into_instruction();
+    process(ix, &mut accounts).unwrap();
+    assert_eq!(loaded(&accounts).valid_until(), Some(2_600));
+    evaluate(&mut accounts, authority).unwrap();
+
+    set_unix_timestamp(2_500);
+    let patch = PatchY {
+        curve: accounts[0].key,
+        owner,
+        start_index: 0,
+        values: vec![4],
+    };
+    process(patch.into_instruction(), &mut accounts).unwrap();
+    assert_eq!(loaded(&accounts).valid_until(), Some(3_100));
+
+    // zero never expires
+    set_expiry(&mut accounts, owner, 0, 0).unwrap();
+    assert_eq!(loaded(&accounts).valid_until(), None);
+    set_unix_timestamp(i64::MAX);
+    evaluate(&mut accounts, authority).unwrap();
+}
+
+#[test]
+fn paused_curve_is_not_evaluated() {
+    let owner = Pubkey::new_unique();
+    let authority = Pubkey::new_unique();
+    let mut accounts = [
+        curve_account(Pubkey::new_unique(), owner, 1),
+        TestAccount::new(
+            Pubkey::new_unique(),
+            curvy::ID,
+            vec![0; CurveEvaluation::SIZE],
+        ),
+    ];
+    let curve = accounts[0].key;
+    let set_status = |status: CurveStatus| SetCurveStatus {
+        curve,
+        owner,
+        status: status as u8,
+    };
+
+    process(
+        set_status(CurveStatus::Paused).into_instruction(),
+        &mut accounts,
+    )
+    .unwrap();
+    assert!(matches!(
+        unwrap_account_error(evaluate(&mut accounts, authority)),
+        CurvyError::CurveInactive
+    ));
+
+    process(
+        set_status(CurveStatus::Active).into_instruction(),
+        &mut accounts,
+    )
+    .unwrap();
+    evaluate(&mut accounts, authority).unwrap();
+}
@@ -0,0 +1,137 @@
+mod common;
+
+use texture_common::_export::Pubkey;
+use texture_common::account::PodAccount;
+
+use curvy::error::CurvyError;
+use curvy::instruction::{
+    AlterGridCurve, CancelDeletion, CurvyInstruction, DeleteCurve, FreezeCurve, MarkForDeletion,
+    SetCurveStatus,
+};
+use curvy::state::curve::{CurveParams, CurveStatus, MAX_Y_CNT};
+use curvy::state::grid_curve::{GridCurve, GridCurveParams, MAX_GRID_Y_CNT};
+use curvy::state::name_link::NameLink;
+use curvy::state::{probe, AccountKind};
+
+use common::{name_link, process, unwrap_account_error, TestAccount};
+
+fn params(y_count: u8) -> GridCurveParams {
+    let mut x = [0; MAX_GRID_Y_CNT];
+    let mut y = [0; MAX_GRID_Y_CNT];
+    for (idx, (x, y)) in x.iter_mut().zip(y.iter_mut()).enumerate() {
+        // dense near zero, sparse further
+        *x = (idx * idx) as u32;
+        *y = idx as u32 * 100;
+    }
+
+    GridCurveParams::new("SOL-borrow", "y=sqrt(x)", y_count, 2, x, y)
+}
+
+/// Grid params must fit in a TX as well as regular ones
+#[test]
+fn grid_params_are_not_larger() {
+    let grid = borsh::to_vec(&CurvyInstruction::CreateGridCurve {
+        params: params(MAX_GRID_Y_CNT as u8),
+    })
+    .unwrap();
+    let regular = borsh::to_vec(&CurvyInstruction::CreateCurve {
+        params: CurveParams::new("", "", 0, 1, 1, 0, [0; MAX_Y_CNT]),
+    })
+    .unwrap();
+
+    assert!(grid.len() <= regular.len());
+}
+
+#[test]
+fn grid_curve_params() {
+    assert!(params(MAX_GRID_Y_CNT as u8).check().is_ok());
+    assert!(params(MAX_GRID_Y_CNT as u8 + 1).check().is_err());
+    assert!(params(0).check().is_err());
+
+    let mut unordered = params(3);
+    unordered.x[2] = unordered.x[1];
+    assert!(unordered.check().is_err());
+    // only first `y_count` values are checked
+    assert!(params(3).check().is_ok());
+
+    let owner = Pubkey::new_unique();
+    let mut data = vec![0; GridCurve::SIZE];
+    GridCurve::init_bytes(&mut data, (params(3), owner)).unwrap();
+
+    let curve = GridCurve::try_from_bytes(&data).unwrap();
+    assert_eq!(curve.owner, owner);
+    assert_eq!(curve.x[2], 4);
+    assert_eq!(curve.y[2], 200);
+    assert_eq!(probe(&data), Some(AccountKind::GridCurve { version: 1 }));
+}
+
+#[test]
+fn frozen_grid_curve_is_not_deleted() {
+    let curve = Pubkey::new_unique();
+    let owner = Pubkey::new_unique();
+    let mut data = vec![0; GridCurve::SIZE];
+    GridCurve::init_bytes(&mut data, (params(3), owner)).unwrap();
+    let mut accounts = [
+        TestAccount::program(curve, data).with_lamports(1_000),
+        name_link(owner, params(3).name, curve),
+    ];
+
+    let delete = || {
+        DeleteCurve {
+            curve,
+            owner,
+            name_link: NameLink::find_address(&owner, &params(3).name).0,
+        }
+        .into_instruction()
+    };
+    let status = |status: CurveStatus| {
+        SetCurveStatus {
+            curve,
+            owner,
+            status: status as u8,
+        }
+        .into_instruction()
+    };
+
+    assert!(matches!(
+        unwrap_account_error(process(status(CurveStatus::Retired), &mut accounts)),
+        CurvyError::InvalidStatusTransition { from: 0, to: 3 }
+    ));
+    process(status(CurveStatus::Deprecated), &mut accounts).unwrap();
+    process(status(CurveStatus::Retired), &mut accounts).unwrap();
+
+    // the slot is never set here, the grace is ahead
+    process(
+        MarkForDeletion { curve, owner }.into_instruction(),
+        &mut accounts,
+    )
+    .unwrap();
+    assert!(matches!(
+        unwrap_account_error(process(delete(), &mut accounts)),
+        CurvyError::DeletionGracePeriod { .. }
+    ));
+    process(
+        CancelDeletion { curve, owner }.into_instruction(),
+        &mut accounts,
+    )
+    .unwrap();
+    let loaded = GridCurve::try_from_bytes(&accounts[0].data).unwrap();
+    assert_eq!(loaded.deletion_slot(), None);
+
+    process(
+        FreezeCurve { curve, owner }.into_instruction(),
+        &mut accounts,
+    )
+    .unwrap();
+    let alter = AlterGridCurve {
+        curve,
+        owner,
+        params: params(4),
+    };
+    for ix in [delete(), alter.into_instruction()] {
+        assert!(matches!(
+            unwrap_account_error(process(ix, &mut accounts)),
+            CurvyError::CurveFrozen
+        ));
+    }
+}
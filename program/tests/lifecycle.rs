@@ -0,0 +1,278 @@
+mod common;
+
+use solana_program::instruction::AccountMeta;
+use solana_program::pubkey::Pubkey;
+use texture_common::account::PodAccount;
+
+use curvy::error::CurvyError;
+use curvy::instruction::{DeleteCurve, MarkForDeletion, SetAlterDelay, SetCurveStatus};
+use curvy::state::curve::{Curve, CurveStatus, DELETION_GRACE_SLOTS, SYMBOL_MAX_SIZE};
+use curvy::state::name_link::NameLink;
+
+use common::{
+    curve_account, curve_data, params, process, set_slot, unwrap_account_error, TestAccount,
+};
+
+/// Curve as created by ReserveCurve
+fn reserved_data(owner: Pubkey) -> Vec<u8> {
+    let mut data = vec![0; Curve::SIZE];
+    bytemuck::from_bytes_mut::<Curve>(&mut data).init_reserved(
+        [0; SYMBOL_MAX_SIZE],
+        [0; SYMBOL_MAX_SIZE],
+        owner,
+    );
+    data
+}
+
+fn set_status(
+    accounts: &mut [TestAccount],
+    owner: Pubkey,
+    status: CurveStatus,
+) -> Result<(), CurvyError> {
+    let ix = SetCurveStatus {
+        curve: accounts[0].key,
+        owner,
+        status: status as u8,
+    };
+    process(ix.into_instruction(), accounts)
+}
+
+#[test]
+fn created_curve_is_active() {
+    let curve = curve_data(Pubkey::new_unique(), 1);
+    let curve = Curve::load(&curve).unwrap();
+
+    assert_eq!(curve.status(), CurveStatus::Active);
+    assert!(curve.check_consumable().is_ok());
+}
+
+#[test]
+fn status_follows_lifecycle() {
+    let curve = Pubkey::new_unique();
+    let owner = Pubkey::new_unique();
+    let mut accounts = [curve_account(curve, owner, 1)];
+
+    match set_status(&mut accounts, owner, CurveStatus::Retired) {
+        Err(CurvyError::Account { error, .. }) => assert!(matches!(
+            *error,
+            CurvyError::InvalidStatusTransition { from: 0, to: 3 }
+        )),
+        result => panic!("unexpected {result:?}"),
+    }
+    assert!(set_status(&mut accounts, owner, CurveStatus::Draft).is_err());
+    assert!(
+        set_status(&mut accounts, Pubkey::new_unique(), CurveStatus::Deprecated).is_err(),
+        "only owner changes status"
+    );
+
+    set_status(&mut accounts, owner, CurveStatus::Deprecated).unwrap();
+    assert!(Curve::load(&accounts[0].data)
+        .unwrap()
+        .check_consumable()
+        .is_ok());
+
+    // deprecation can be reverted
+    set_status(&mut accounts, owner, CurveStatus::Active).unwrap();
+    set_status(&mut accounts, owner, CurveStatus::Deprecated).unwrap();
+
+    set_status(&mut accounts, owner, CurveStatus::Retired).unwrap();
+    let loaded = Curve::load(&accounts[0].data).unwrap();
+    assert_eq!(loaded.status(), CurveStatus::Retired);
+    assert!(matches!(
+        loaded.check_consumable(),
+        Err(CurvyError::CurveNotActive { status: 3 })
+    ));
+
+    assert!(set_status(&mut accounts, owner, CurveStatus::Active).is_err());
+
+    let ix = SetCurveStatus {
+        curve,
+        owner,
+        status: 5,
+    };
+    assert!(process(ix.into_instruction(), &mut accounts).is_err());
+}
+
+#[test]
+fn paused_curve_is_not_consumed() {
+    let curve = Pubkey::new_unique();
+    let owner = Pubkey::new_unique();
+    let mut accounts = [curve_account(curve, owner, 1)];
+
+    set_status(&mut accounts, owner, CurveStatus::Paused).unwrap();
+    let loaded = Curve::load(&accounts[0].data).unwrap();
+    assert!(loaded.is_paused());
+    assert!(matches!(
+        loaded.check_consumable(),
+        Err(CurvyError::CurveInactive)
+    ));
+    assert!(set_status(&mut accounts, owner, CurveStatus::Retired).is_err());
+
+    // resumed to either consumable stage
+    set_status(&mut accounts, owner, CurveStatus::Deprecated).unwrap();
+    set_status(&mut accounts, owner, CurveStatus::Paused).unwrap();
+    set_status(&mut accounts, owner, CurveStatus::Active).unwrap();
+    assert!(Curve::load(&accounts[0].data)
+        .unwrap()
+        .check_consumable()
+        .is_ok());
+
+    let mut draft = [TestAccount::program(curve, reserved_data(owner))];
+    assert!(set_status(&mut draft, owner, CurveStatus::Paused).is_err());
+}
+
+#[test]
+fn only_draft_and_retired_curves_are_deleted() {
+    let curve = Pubkey::new_unique();
+    let owner = Pubkey::new_unique();
+    let mut accounts = [curve_account(curve, owner, 1)];
+
+    let delete = |accounts: &mut [TestAccount]| {
+        let name = Curve::load(&accounts[0].data).unwrap().name;
+        let ix = DeleteCurve {
+            curve,
+            owner,
+            name_link: NameLink::find_address(&owner, &name).0,
+        };
+        process(ix.into_instruction(), accounts)
+    };
+
+    assert!(delete(&mut accounts).is_err());
+    set_status(&mut accounts, owner, CurveStatus::Deprecated).unwrap();
+    assert!(delete(&mut accounts).is_err());
+    set_status(&mut accounts, owner, CurveStatus::Retired).unwrap();
+    delete(&mut accounts).unwrap();
+
+    let mut draft = [TestAccount::program(curve, reserved_data(owner))];
+    delete(&mut draft).unwrap();
+}
+
+#[test]
+fn reserved_curve_is_activated_with_data() {
+    let curve = Pubkey::new_unique();
+    let owner = Pubkey::new_unique();
+    let mut accounts = [TestAccount::program(curve, reserved_data(owner))];
+
+    let loaded = Curve::load(&accounts[0].data).unwrap();
+    assert_eq!(loaded.status(), CurveStatus::Draft);
+    assert!(loaded.check_consumable().is_err());
+
+    assert!(set_status(&mut accounts, owner, CurveStatus::Active).is_err());
+
+    Curve::load_mut(&mut accounts[0].data).unwrap().y_count = 2;
+    set_status(&mut accounts, owner, CurveStatus::Active).unwrap();
+    assert_eq!(
+        Curve::load(&accounts[0].data).unwrap().status(),
+        CurveStatus::Active
+    );
+}
+
+#[test]
+fn marked_curve_waits_for_grace_period() {
+    let mut data = curve_data(Pubkey::new_unique(), 1);
+    let curve = Curve::load_mut(&mut data).unwrap();
+
+    // not marked, deleted at once
+    assert_eq!(curve.deletion_slot(), None);
+    curve.check_deletion_grace(0).unwrap();
+    assert!(curve.cancel_deletion().is_err());
+
+    curve.mark_for_deletion(100).unwrap();
+    assert!(curve.mark_for_deletion(200).is_err(), "already marked");
+    assert_eq!(curve.deletion_slot(), Some(100));
+
+    let from = 100 + DELETION_GRACE_SLOTS;
+    assert_eq!(curve.deletable_from_slot(), Some(from));
+    assert!(matches!(
+        curve.check_deletion_grace(from - 1),
+        Err(CurvyError::DeletionGracePeriod { from: slot }) if slot == from
+    ));
+    curve.check_deletion_grace(from).unwrap();
+
+    curve.cancel_deletion().unwrap();
+    assert_eq!(curve.deletion_slot(), None);
+    curve.check_deletion_grace(0).unwrap();
+
+    // timelocked curves are marked first and wait for the delay when it is longer
+    curve.set_alter_delay(2 * DELETION_GRACE_SLOTS).unwrap();
+    assert!(matches!(
+        curve.check_deletion_grace(0),
+        Err(CurvyError::AlterTimelocked { .. })
+    ));
+    curve.mark_for_deletion(100).unwrap();
+    assert_eq!(
+        curve.deletable_from_slot(),
+        Some(100 + 2 * DELETION_GRACE_SLOTS)
+    );
+}
+
+#[test]
+fn timelocked_curve_is_not_deleted_at_once() {
+    let curve = Pubkey::new_unique();
+    let owner = Pubkey::new_unique();
+    let mut accounts = [curve_account(curve, owner, 1).with_lamports(5_000)];
+
+    let delay = 2 * DELETION_GRACE_SLOTS;
+    let ix = SetAlterDelay {
+        curve,
+        owner,
+        slots: delay,
+    };
+    process(ix.into_instruction(), &mut accounts).unwrap();
+
+    // retiring takes two instant status changes, deleting does not
+    set_status(&mut accounts, owner, CurveStatus::Deprecated).unwrap();
+    set_status(&mut accounts, owner, CurveStatus::Retired).unwrap();
+
+    let delete = |accounts: &mut [TestAccount]| {
+        let ix = DeleteCurve {
+            curve,
+            owner,
+            name_link: NameLink::find_address(&owner, &params(1).name).0,
+        };
+        process(ix.into_instruction(), accounts)
+    };
+    assert!(matches!(
+        unwrap_account_error(delete(&mut accounts)),
+        CurvyError::AlterTimelocked { delay: slots } if slots == delay
+    ));
+
+    set_slot(1_000);
+    let ix = MarkForDeletion { curve, owner };
+    process(ix.into_instruction(), &mut accounts).unwrap();
+
+    set_slot(1_000 + DELETION_GRACE_SLOTS);
+    assert!(matches!(
+        unwrap_account_error(delete(&mut accounts)),
+        CurvyError::DeletionGracePeriod { from } if from == 1_000 + delay
+    ));
+
+    set_slot(1_000 + delay);
+    delete(&mut accounts).unwrap();
+    assert_eq!(accounts[0].lamports, 0);
+}
+
+#[test]
+fn unexpected_accounts_are_rejected() {
+    let curve = Pubkey::new_unique();
+    let owner = Pubkey::new_unique();
+    let mut accounts = [curve_account(curve, owner, 1)];
+
+    let mut ix = SetCurveStatus {
+        curve,
+        owner,
+        status: CurveStatus::Deprecated as u8,
+    }
+    .into_instruction();
+    ix.accounts
+        .push(AccountMeta::new_readonly(Pubkey::new_unique(), false));
+
+    assert!(matches!(
+        process(ix, &mut accounts),
+        Err(CurvyError::UnexpectedAccounts { count: 1 })
+    ));
+    assert_eq!(
+        Curve::load(&accounts[0].data).unwrap().status(),
+        CurveStatus::Active
+    );
+}
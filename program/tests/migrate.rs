@@ -0,0 +1,124 @@
+mod common;
+
+use solana_program::rent::Rent;
+use texture_common::_export::Pubkey;
+use texture_common::account::PodAccount;
+
+use curvy::error::CurvyError;
+use curvy::instruction::MigrateCurve;
+use curvy::state::curve::{Curve, CurveParams, CurveStatus, MAX_Y_CNT};
+use curvy::state::{probe, AccountKind};
+
+use common::{process, TestAccount};
+
+/// v1 account data: v2 layout up to `y` with version 1
+fn v1_data(params: CurveParams, owner: Pubkey) -> Vec<u8> {
+    let curve = Curve::from_init_params((params, owner));
+    let mut data = bytemuck::bytes_of(&curve)[..Curve::V1_SIZE].to_vec();
+    data[8] = 1;
+    data
+}
+
+#[test]
+fn upgrade_v1_keeps_content() {
+    let params = CurveParams::new("SOL-borrow", "y=kx+b", 0, 10, 11, 6, [7; MAX_Y_CNT]);
+    let owner = Pubkey::new_unique();
+
+    let mut data = v1_data(params, owner);
+    assert!(matches!(
+        Curve::load(&data),
+        Err(CurvyError::UnsupportedVersion { found: 1, .. })
+    ));
+
+    // realloc zeroes the grown part
+    data.resize(Curve::SIZE, 0);
+    let curve = Curve::upgrade(&mut data).unwrap();
+
+    assert_eq!(curve.version, Curve::VERSION);
+    assert_eq!(curve.owner, owner);
+    assert_eq!(CurveParams::from(&*curve).hash(), params.hash());
+    assert_eq!(curve.asset(), None);
+
+    // upgrading again is a no-op
+    Curve::upgrade(&mut data).unwrap();
+}
+
+#[test]
+fn upgrade_v2_sets_y_decimals() {
+    let params = CurveParams::new("SOL-borrow", "y=kx+b", 0, 10, 11, 6, [7; MAX_Y_CNT]);
+
+    // v2 layout is the same, `y_decimals` was padding
+    let mut curve = Curve::from_init_params((params, Pubkey::new_unique()));
+    curve.version = 2;
+    curve.y_decimals = 0;
+    let mut data = bytemuck::bytes_of(&curve).to_vec();
+    assert!(matches!(
+        Curve::load(&data),
+        Err(CurvyError::UnsupportedVersion { found: 2, .. })
+    ));
+
+    let curve = Curve::upgrade(&mut data).unwrap();
+    assert_eq!(curve.version, Curve::VERSION);
+    assert_eq!((curve.x_decimals, curve.y_decimals), (6, 6));
+    assert_eq!(CurveParams::from(&*curve).hash(), params.hash());
+}
+
+/// Runs MigrateCurve on the curve account `data` with `lamports`, returns its data afterwards
+fn migrate(data: &[u8], lamports: u64) -> Result<Vec<u8>, CurvyError> {
+    let curve = Pubkey::new_unique();
+    let ix = MigrateCurve {
+        curve,
+        payer: Pubkey::new_unique(),
+    }
+    .into_instruction();
+
+    let mut accounts = [TestAccount::program(curve, data.to_vec()).with_lamports(lamports)];
+    process(ix, &mut accounts)?;
+    let [curve] = accounts;
+    Ok(curve.data)
+}
+
+#[test]
+fn migrates_mixed_version_fleet() {
+    let params = CurveParams::new("SOL-borrow", "y=kx+b", 0, 10, 11, 6, [7; MAX_Y_CNT]);
+    let owner = Pubkey::new_unique();
+    // funded for the current size, so no top up is needed
+    let lamports = Rent::default().minimum_balance(Curve::SIZE);
+
+    let current = Curve::from_init_params((params, owner));
+    let mut v2 = current;
+    v2.version = 2;
+    v2.y_decimals = 0;
+    let mut unknown = current;
+    unknown.version = Curve::VERSION + 1;
+
+    let fleet = [
+        v1_data(params, owner),
+        bytemuck::bytes_of(&v2).to_vec(),
+        bytemuck::bytes_of(&current).to_vec(),
+    ];
+    for data in &fleet {
+        let migrated = migrate(data, lamports).unwrap();
+        assert_eq!(migrated.len(), Curve::SIZE);
+        assert_eq!(
+            probe(&migrated),
+            Some(AccountKind::Curve {
+                version: Curve::VERSION
+            })
+        );
+
+        let curve = Curve::load(&migrated).unwrap();
+        assert_eq!(curve.owner, owner);
+        assert_eq!(CurveParams::from(curve).hash(), params.hash());
+        // fields added after v1 read as their defaults
+        assert_eq!(curve.status(), CurveStatus::Active);
+        assert_eq!(curve.deletion_slot(), None);
+        assert_eq!(curve.asset(), None);
+
+        // migrating a migrated curve changes nothing
+        assert_eq!(migrate(&migrated, lamports).unwrap(), migrated);
+    }
+
+    assert!(migrate(bytemuck::bytes_of(&unknown), lamports).is_err());
+    assert!(migrate(&[0; Curve::V1_SIZE], lamports).is_err());
+}
@@ -0,0 +1,351 @@
+mod common;
+
+use solana_program::instruction::AccountMeta;
+use solana_program::pubkey::Pubkey;
+use texture_common::account::PodAccount;
+
+use curvy::error::CurvyError;
+use curvy::instruction::{
+    AcceptOwner, AlterCurve, AlterGridCurve, AlterWideCurve, ApplyAlter, BatchAlter, DeleteCurve,
+    ProposeAlter, ProposeOwner, UpdateCurveMetadata,
+};
+use curvy::state::curve::{Curve, CurveParams, CurveStatus, MAX_Y_CNT};
+use curvy::state::grid_curve::{GridCurve, GridCurveParams, MAX_GRID_Y_CNT};
+use curvy::state::name_link::NameLink;
+use curvy::state::pending_alter::PendingAlter;
+use curvy::state::utils::str_to_array;
+use curvy::state::wide_curve::{WideCurve, WideCurveParams, MAX_WIDE_Y_CNT};
+
+use common::{curve_account, name_link, process, unwrap_account_error, TestAccount};
+
+fn params() -> CurveParams {
+    CurveParams::new("USDC-main", "y=x", 0, 10, 2, 6, [1; MAX_Y_CNT])
+}
+
+/// Retired curve of `owner` and its name link pointing to `linked`
+fn curve_with_link(curve: Pubkey, owner: Pubkey, linked: Pubkey) -> [TestAccount; 2] {
+    let mut curve_data = vec![0; Curve::SIZE];
+    Curve::init_bytes(&mut curve_data, (params(), owner)).unwrap();
+    Curve::load_mut(&mut curve_data).unwrap().status = CurveStatus::Retired as u8;
+
+    [
+        TestAccount::program(curve, curve_data).with_lamports(5_000),
+        name_link(owner, params().name, linked),
+    ]
+}
+
+fn system_account(key: Pubkey) -> TestAccount {
+    TestAccount::new(key, solana_program::system_program::ID, vec![])
+}
+
+fn delete(curve: Pubkey, owner: Pubkey) -> DeleteCurve {
+    DeleteCurve {
+        curve,
+        owner,
+        name_link: NameLink::find_address(&owner, &params().name).0,
+    }
+}
+
+#[test]
+fn delete_closes_name_link_of_curve() {
+    let curve = Pubkey::new_unique();
+    let owner = Pubkey::new_unique();
+    let mut accounts = curve_with_link(curve, owner, curve);
+
+    process(delete(curve, owner).into_instruction(), &mut accounts).unwrap();
+    assert_eq!(accounts[0].lamports, 0);
+    assert_eq!(accounts[1].lamports, 0);
+    assert!(accounts[1].data.iter().all(|byte| *byte == 0));
+}
+
+#[test]
+fn delete_keeps_name_link_of_another_curve() {
+    let curve = Pubkey::new_unique();
+    let owner = Pubkey::new_unique();
+    // another curve created later under the same name
+    let newer = Pubkey::new_unique();
+    let mut accounts = curve_with_link(curve, owner, newer);
+
+    process(delete(curve, owner).into_instruction(), &mut accounts).unwrap();
+    assert_eq!(accounts[0].lamports, 0);
+    assert_eq!(accounts[1].lamports, 1_000);
+    assert_eq!(
+        NameLink::try_from_bytes(&accounts[1].data).unwrap().curve,
+        newer
+    );
+}
+
+#[test]
+fn delete_checks_name_link_address() {
+    let curve = Pubkey::new_unique();
+    let owner = Pubkey::new_unique();
+    let mut accounts = curve_with_link(curve, owner, curve);
+
+    let mut ix = delete(curve, owner);
+    ix.name_link = Pubkey::new_unique();
+    accounts[1].key = ix.name_link;
+    assert!(process(ix.into_instruction(), &mut accounts).is_err());
+    assert_eq!(accounts[0].lamports, 5_000);
+}
+
+#[test]
+fn delete_sends_rent_to_destination() {
+    let curve = Pubkey::new_unique();
+    let owner = Pubkey::new_unique();
+    let treasury = Pubkey::new_unique();
+    let [curve_account, link_account] = curve_with_link(curve, owner, curve);
+    let mut accounts = [
+        curve_account,
+        link_account,
+        system_account(owner),
+        system_account(treasury),
+    ];
+
+    let mut ix = delete(curve, owner).into_instruction();
+    ix.accounts.push(AccountMeta::new_readonly(treasury, false));
+    assert!(process(ix.clone(), &mut accounts).is_err());
+    ix.accounts
+        .push(AccountMeta::new(Pubkey::new_unique(), false));
+    ix.accounts[3].is_writable = true;
+    assert!(process(ix.clone(), &mut accounts).is_err());
+    ix.accounts.pop();
+
+    process(ix, &mut accounts).unwrap();
+    assert_eq!(accounts[0].lamports, 0);
+    // the name link rent returns to the owner
+    assert_eq!(accounts[2].lamports, 1_000);
+    assert_eq!(accounts[3].lamports, 5_000);
+}
+
+#[test]
+fn delete_rejects_curve_and_name_link_as_destination() {
+    let curve = Pubkey::new_unique();
+    let owner = Pubkey::new_unique();
+    let name_link = NameLink::find_address(&owner, &params().name).0;
+
+    let mut wide_data = vec![0; WideCurve::SIZE];
+    let wide_params = WideCurveParams::new("USDC-main", "y=x", 0, 10, 2, 6, [1; MAX_WIDE_Y_CNT]);
+    WideCurve::init_bytes(&mut wide_data, (wide_params, owner)).unwrap();
+    let mut grid_data = vec![0; GridCurve::SIZE];
+    let grid_params = GridCurveParams::new(
+        "USDC-main",
+        "y=x",
+        2,
+        6,
+        [1; MAX_GRID_Y_CNT],
+        [1; MAX_GRID_Y_CNT],
+    );
+    GridCurve::init_bytes(&mut grid_data, (grid_params, owner)).unwrap();
+
+    let [curve_account, link_account] = curve_with_link(curve, owner, curve);
+    for curve_account in [
+        curve_account,
+        TestAccount::program(curve, wide_data).with_lamports(5_000),
+        TestAccount::program(curve, grid_data).with_lamports(5_000),
+    ] {
+        let mut accounts = [curve_account, link_account.clone(), system_account(owner)];
+
+        for destination in [curve, name_link] {
+            let mut ix = delete(curve, owner).into_instruction();
+            ix.accounts.push(AccountMeta::new(destination, false));
+            assert!(matches!(
+                process(ix, &mut accounts),
+                Err(CurvyError::InvalidAccount(_))
+            ));
+            assert_eq!(accounts[0].lamports, 5_000);
+            assert_eq!(accounts[1].lamports, 1_000);
+        }
+    }
+}
+
+#[test]
+fn rename_moves_name_link() {
+    let curve = Pubkey::new_unique();
+    let owner = Pubkey::new_unique();
+    let new_name = str_to_array("USDC-old");
+    let [curve_account, link_account] = curve_with_link(curve, owner, curve);
+    let mut accounts = [
+        curve_account,
+        link_account,
+        name_link(owner, new_name, Pubkey::new_unique()),
+        system_account(owner),
+    ];
+
+    let rename = |name, formula| UpdateCurveMetadata {
+        curve,
+        owner,
+        name_link: NameLink::find_address(&owner, &params().name).0,
+        new_name_link: NameLink::find_address(&owner, &name).0,
+        name,
+        formula,
+    };
+
+    // the link stays with the formula changes only
+    let formula = str_to_array("y=2x");
+    process(
+        rename(params().name, formula).into_instruction(),
+        &mut accounts,
+    )
+    .unwrap();
+    assert_eq!(accounts[1].lamports, 1_000);
+    assert_eq!(
+        NameLink::try_from_bytes(&accounts[1].data).unwrap().curve,
+        curve
+    );
+
+    process(rename(new_name, formula).into_instruction(), &mut accounts).unwrap();
+    assert_eq!(Curve::load(&accounts[0].data).unwrap().name, new_name);
+    assert_eq!(accounts[1].lamports, 0);
+    assert!(accounts[1].data.iter().all(|byte| *byte == 0));
+    assert_eq!(
+        NameLink::try_from_bytes(&accounts[2].data).unwrap().curve,
+        curve
+    );
+    assert_eq!(accounts[3].lamports, 1_000);
+}
+
+#[test]
+fn new_owner_takes_name_link() {
+    let curve = Pubkey::new_unique();
+    let owner = Pubkey::new_unique();
+    let new_owner = Pubkey::new_unique();
+    let [curve_account, link_account] = curve_with_link(curve, owner, curve);
+    let mut accounts = [
+        curve_account,
+        link_account,
+        name_link(new_owner, params().name, Pubkey::new_unique()),
+        system_account(owner),
+    ];
+
+    let propose = ProposeOwner {
+        curve,
+        owner,
+        new_owner,
+    };
+    process(propose.into_instruction(), &mut accounts).unwrap();
+
+    let accept = |owner| AcceptOwner {
+        curve,
+        new_owner,
+        owner,
+        name_link: NameLink::find_address(&owner, &params().name).0,
+        new_name_link: NameLink::find_address(&new_owner, &params().name).0,
+    };
+    // the link of another owner
+    let stranger = Pubkey::new_unique();
+    assert!(process(accept(stranger).into_instruction(), &mut accounts).is_err());
+
+    process(accept(owner).into_instruction(), &mut accounts).unwrap();
+    assert_eq!(Curve::load(&accounts[0].data).unwrap().owner, new_owner);
+    assert_eq!(accounts[1].lamports, 0);
+    assert!(accounts[1].data.iter().all(|byte| *byte == 0));
+    let link = NameLink::try_from_bytes(&accounts[2].data).unwrap();
+    assert_eq!((link.owner, link.curve), (new_owner, curve));
+    // rent of the closed link returns to the previous owner
+    assert_eq!(accounts[3].lamports, 1_000);
+}
+
+#[test]
+fn alterations_keep_name() {
+    let curve = Pubkey::new_unique();
+    let owner = Pubkey::new_unique();
+    // params of another name
+    let renamed = || {
+        let mut renamed = common::params(2);
+        renamed.name = params().name;
+        renamed
+    };
+    let pending_alter = PendingAlter::find_address(&curve).0;
+    // proposal made before the curve got its current name
+    let mut pending_data = vec![0; PendingAlter::SIZE];
+    PendingAlter::init_bytes(&mut pending_data, curve).unwrap();
+    PendingAlter::try_from_bytes_mut(&mut pending_data)
+        .unwrap()
+        .params = (&renamed()).into();
+    let mut accounts = [
+        curve_account(curve, owner, 1),
+        TestAccount::program(pending_alter, pending_data).with_lamports(1_000),
+    ];
+
+    let alter = AlterCurve {
+        curve,
+        owner,
+        params: renamed(),
+    };
+    let mut batch = BatchAlter {
+        owner,
+        params: vec![(&renamed()).into()],
+    }
+    .into_instruction();
+    batch.accounts.push(AccountMeta::new(curve, false));
+    let propose = ProposeAlter {
+        curve,
+        owner,
+        pending_alter,
+        params: renamed(),
+    };
+    let apply = ApplyAlter {
+        curve,
+        owner,
+        pending_alter,
+    };
+
+    for ix in [
+        alter.into_instruction(),
+        batch,
+        propose.into_instruction(),
+        apply.into_instruction(),
+    ] {
+        assert!(matches!(
+            unwrap_account_error(process(ix, &mut accounts)),
+            CurvyError::InvalidParams
+        ));
+    }
+    let loaded = Curve::load(&accounts[0].data).unwrap();
+    assert_eq!(loaded.name, common::params(1).name);
+    assert_eq!(loaded.y[0], 1);
+}
+
+#[test]
+fn wide_and_grid_alterations_keep_name() {
+    let owner = Pubkey::new_unique();
+    let wide_params = |name| WideCurveParams::new(name, "y=kx", 0, 10, 2, 2, [1; MAX_WIDE_Y_CNT]);
+    let grid_params = |name| {
+        let x = std::array::from_fn(|idx| idx as u32);
+        GridCurveParams::new(name, "y=x", 2, 2, x, [1; MAX_GRID_Y_CNT])
+    };
+
+    let mut wide_data = vec![0; WideCurve::SIZE];
+    WideCurve::init_bytes(&mut wide_data, (wide_params("SOL-depth"), owner)).unwrap();
+    let mut grid_data = vec![0; GridCurve::SIZE];
+    GridCurve::init_bytes(&mut grid_data, (grid_params("SOL-borrow"), owner)).unwrap();
+    let (wide, grid) = (Pubkey::new_unique(), Pubkey::new_unique());
+    let mut accounts = [
+        TestAccount::program(wide, wide_data),
+        TestAccount::program(grid, grid_data),
+    ];
+
+    let alter_wide = |name| AlterWideCurve {
+        curve: wide,
+        owner,
+        params: wide_params(name),
+    };
+    let alter_grid = |name| AlterGridCurve {
+        curve: grid,
+        owner,
+        params: grid_params(name),
+    };
+    for ix in [
+        alter_wide("SOL-supply").into_instruction(),
+        alter_grid("SOL-supply").into_instruction(),
+    ] {
+        assert!(matches!(
+            unwrap_account_error(process(ix, &mut accounts)),
+            CurvyError::InvalidParams
+        ));
+    }
+
+    // the same names are kept
+    process(alter_wide("SOL-depth").into_instruction(), &mut accounts).unwrap();
+    process(alter_grid("SOL-borrow").into_instruction(), &mut accounts).unwrap();
+}
@@ -0,0 +1,174 @@
+mod common;
+
+use solana_program::program_pack::Pack;
+use solana_program::pubkey::Pubkey;
+use spl_token::state::Mint;
+use texture_common::account::PodAccount;
+
+use curvy::error::CurvyError;
+use curvy::instruction::{
+    AcceptOwner, AlterCurve, DeleteCurve, FreezeCurve, ProposeOwner, SetCurveAsset,
+};
+use curvy::state::curve::{Curve, CurveParams, MAX_Y_CNT};
+use curvy::state::name_link::NameLink;
+
+use common::{
+    curve_account, curve_data, name_link, params, process, unwrap_account_error, TestAccount,
+};
+
+/// AcceptOwner of `curve` by `new_owner` moving the name link of `owner`
+fn accept_owner(curve: Pubkey, owner: Pubkey, new_owner: Pubkey) -> AcceptOwner {
+    AcceptOwner {
+        curve,
+        new_owner,
+        owner,
+        name_link: NameLink::find_address(&owner, &params(1).name).0,
+        new_name_link: NameLink::find_address(&new_owner, &params(1).name).0,
+    }
+}
+
+#[test]
+fn ownership_changes_when_accepted() {
+    let curve = Pubkey::new_unique();
+    let owner = Pubkey::new_unique();
+    let new_owner = Pubkey::new_unique();
+    let mut accounts = [
+        curve_account(curve, owner, 1),
+        name_link(new_owner, params(1).name, Pubkey::default()),
+    ];
+
+    let accept = |accounts: &mut [TestAccount], by: Pubkey| {
+        process(accept_owner(curve, owner, by).into_instruction(), accounts)
+    };
+
+    assert!(
+        accept(&mut accounts, new_owner).is_err(),
+        "nothing is proposed"
+    );
+
+    let propose = ProposeOwner {
+        curve,
+        owner,
+        new_owner,
+    };
+    process(propose.into_instruction(), &mut accounts).unwrap();
+
+    let loaded = Curve::load(&accounts[0].data).unwrap();
+    assert_eq!(loaded.owner, owner, "owner changes only on accept");
+    assert_eq!(loaded.pending_owner(), Some(new_owner));
+
+    assert!(accept(&mut accounts, Pubkey::new_unique()).is_err());
+    accept(&mut accounts, new_owner).unwrap();
+
+    let loaded = Curve::load(&accounts[0].data).unwrap();
+    assert_eq!(loaded.owner, new_owner);
+    assert_eq!(loaded.pending_owner(), None);
+}
+
+#[test]
+fn proposal_can_be_cancelled() {
+    let curve = Pubkey::new_unique();
+    let owner = Pubkey::new_unique();
+    let new_owner = Pubkey::new_unique();
+    let mut accounts = [curve_account(curve, owner, 1)];
+
+    let propose = |accounts: &mut [TestAccount], new_owner: Pubkey| {
+        let ix = ProposeOwner {
+            curve,
+            owner,
+            new_owner,
+        };
+        process(ix.into_instruction(), accounts)
+    };
+
+    propose(&mut accounts, new_owner).unwrap();
+    propose(&mut accounts, Pubkey::default()).unwrap();
+
+    let ix = accept_owner(curve, owner, new_owner);
+    assert!(process(ix.into_instruction(), &mut accounts).is_err());
+    assert_eq!(Curve::load(&accounts[0].data).unwrap().owner, owner);
+}
+
+#[test]
+fn curve_with_asset_is_not_transferred() {
+    let curve = Pubkey::new_unique();
+    let owner = Pubkey::new_unique();
+    let mut accounts = [curve_account(curve, owner, 1)];
+    Curve::load_mut(&mut accounts[0].data)
+        .unwrap()
+        .set_asset(Some((Pubkey::new_unique(), 0)));
+
+    let ix = ProposeOwner {
+        curve,
+        owner,
+        new_owner: Pubkey::new_unique(),
+    };
+    assert!(process(ix.into_instruction(), &mut accounts).is_err());
+    assert_eq!(
+        Curve::load(&accounts[0].data).unwrap().pending_owner(),
+        None
+    );
+}
+
+#[test]
+fn only_owner_proposes() {
+    let curve = Pubkey::new_unique();
+    let mut accounts = [TestAccount::program(
+        curve,
+        curve_data(Pubkey::new_unique(), 1),
+    )];
+
+    let ix = ProposeOwner {
+        curve,
+        owner: Pubkey::new_unique(),
+        new_owner: Pubkey::new_unique(),
+    };
+    assert!(process(ix.into_instruction(), &mut accounts).is_err());
+}
+
+#[test]
+fn frozen_curve_is_not_changed() {
+    let curve = Pubkey::new_unique();
+    let owner = Pubkey::new_unique();
+    let asset_mint = Pubkey::new_unique();
+    let mut accounts = [
+        curve_account(curve, owner, 1),
+        TestAccount::new(asset_mint, spl_token::ID, vec![0; Mint::LEN]),
+    ];
+
+    process(
+        FreezeCurve { curve, owner }.into_instruction(),
+        &mut accounts,
+    )
+    .unwrap();
+    assert_eq!(Curve::load(&accounts[0].data).unwrap().is_frozen, 1);
+
+    let params = CurveParams::new("SOL-borrow", "y=2x", 0, 10, 2, 6, [2; MAX_Y_CNT]);
+    let alter = AlterCurve {
+        curve,
+        owner,
+        params,
+    };
+    assert!(process(alter.into_instruction(), &mut accounts).is_err());
+    assert_eq!(Curve::load(&accounts[0].data).unwrap().y[0], 1);
+
+    let delete = DeleteCurve {
+        curve,
+        owner,
+        name_link: NameLink::find_address(&owner, &params.name).0,
+    };
+    assert!(process(delete.into_instruction(), &mut accounts).is_err());
+
+    let set_asset = SetCurveAsset {
+        curve,
+        owner,
+        asset_mint,
+        asset_link: Pubkey::new_unique(),
+        kind: 0,
+    };
+    assert!(matches!(
+        unwrap_account_error(process(set_asset.into_instruction(), &mut accounts)),
+        CurvyError::CurveFrozen
+    ));
+    assert_eq!(Curve::load(&accounts[0].data).unwrap().asset(), None);
+}
@@ -0,0 +1,39 @@
+use texture_common::_export::{Pubkey, Zeroable};
+use texture_common::account::PodAccount;
+
+use curvy::instruction::CurvyInstruction;
+use curvy::processor::{ALTER_CURVE_TAG, CREATE_CURVE_TAG};
+use curvy::state::curve::{Curve, CurveParams, CurveParamsRaw, MAX_Y_CNT};
+
+fn params() -> CurveParams {
+    let mut y = [0; MAX_Y_CNT];
+    for (idx, y) in y.iter_mut().enumerate() {
+        *y = 1_000_000 + idx as u32 * 0x0102_0304;
+    }
+
+    CurveParams::new("SOL-borrow", "y=kx+b", 100, 7, MAX_Y_CNT as u8, 6, y)
+}
+
+/// Params read in place must match borsh deserialization of the same instruction data
+#[test]
+fn raw_params_match_borsh() {
+    let params = params();
+    let owner = Pubkey::new_unique();
+    let expected = Curve::from_init_params((params, owner));
+
+    for (tag, ix) in [
+        (CREATE_CURVE_TAG, CurvyInstruction::CreateCurve { params }),
+        (ALTER_CURVE_TAG, CurvyInstruction::AlterCurve { params }),
+    ] {
+        let data = borsh::to_vec(&ix).unwrap();
+        assert_eq!(data[0], tag);
+
+        let raw = CurveParamsRaw::from_bytes(&data[1..]).unwrap();
+        let mut curve = Curve::zeroed();
+        curve.owner = owner;
+        curve.set_params_raw(raw);
+
+        assert_eq!(bytemuck::bytes_of(&curve), bytemuck::bytes_of(&expected));
+        assert!(CurveParamsRaw::from_bytes(&data[2..]).is_err());
+    }
+}
@@ -0,0 +1,59 @@
+use texture_common::_export::Pubkey;
+use texture_common::account::PodAccount;
+
+use curvy::error::CurvyError;
+use curvy::instruction::CurvyInstruction;
+use curvy::state::curve::{Curve, CurveParams, MAX_Y_CNT};
+
+fn curve(y_count: u8) -> Curve {
+    let mut y = [0; MAX_Y_CNT];
+    y.iter_mut()
+        .enumerate()
+        .for_each(|(idx, y)| *y = idx as u32);
+    let params = CurveParams::new("SOL-borrow", "y=x", 0, 10, y_count, 6, y);
+    Curve::from_init_params((params, Pubkey::new_unique()))
+}
+
+#[test]
+fn patches_slice_of_y() {
+    let mut curve = curve(20);
+
+    curve.patch_y(15, &[100, 200, 300]).unwrap();
+    assert_eq!(&curve.y[14..19], &[14, 100, 200, 300, 18]);
+
+    // up to the last sample
+    curve.patch_y(19, &[400]).unwrap();
+    assert_eq!(curve.y[19], 400);
+    assert_eq!(curve.y[20], 20, "samples after y_count are untouched");
+}
+
+#[test]
+fn patch_is_within_y_count() {
+    let mut curve = curve(20);
+
+    assert!(matches!(
+        curve.patch_y(18, &[1, 2, 3]),
+        Err(CurvyError::InvalidParams)
+    ));
+    assert!(matches!(
+        curve.patch_y(20, &[1]),
+        Err(CurvyError::InvalidParams)
+    ));
+    assert!(matches!(
+        curve.patch_y(0, &[]),
+        Err(CurvyError::InvalidParams)
+    ));
+    assert_eq!(curve.y[18], 18);
+}
+
+#[test]
+fn instruction_layout() {
+    let data = borsh::to_vec(&CurvyInstruction::PatchY {
+        start_index: 3,
+        values: vec![7, 8],
+    })
+    .unwrap();
+
+    // tag, start, vec length and values
+    assert_eq!(data, [27, 3, 2, 0, 0, 0, 7, 0, 0, 0, 8, 0, 0, 0]);
+}
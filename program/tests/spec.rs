@@ -0,0 +1,43 @@
+/// Program ID can not be taken from `curvy-spec` by `declare_id!`, so it is checked here
+#[test]
+fn program_id_matches_spec() {
+    assert_eq!(curvy::ID.to_string(), curvy_spec::PROGRAM_ID);
+    assert_eq!(curvy::ID.to_bytes(), curvy_spec::PROGRAM_ID_BYTES);
+}
+
+/// Seeds are documented for consumers deriving curve addresses without the program crate
+#[test]
+fn curve_address_seeds() {
+    use curvy::state::curve::find_curve_address;
+    use solana_program::pubkey::Pubkey;
+
+    let owner = Pubkey::new_unique();
+    let mut name = [0; curvy_spec::SYMBOL_MAX_SIZE];
+    name[..10].copy_from_slice(b"SOL-borrow");
+
+    assert_eq!(
+        find_curve_address(&owner, "SOL-borrow"),
+        Pubkey::find_program_address(&[curvy_spec::CURVE_SEED, owner.as_ref(), &name], &curvy::ID)
+    );
+    assert_ne!(
+        find_curve_address(&owner, "SOL-borrow").0,
+        find_curve_address(&Pubkey::new_unique(), "SOL-borrow").0
+    );
+}
+
+/// Integrators resolve reference curves from the seeds alone
+#[test]
+fn reference_curve_address_seeds() {
+    use curvy::state::curve::find_reference_curve_address;
+    use solana_program::pubkey::Pubkey;
+
+    let mint = Pubkey::new_unique();
+
+    assert_eq!(
+        find_reference_curve_address(1, &mint),
+        Pubkey::find_program_address(
+            &[curvy_spec::REFERENCE_SEED, &[1], mint.as_ref()],
+            &curvy::ID
+        )
+    );
+}
@@ -0,0 +1,282 @@
+mod common;
+
+use solana_program::pubkey::Pubkey;
+use texture_common::account::PodAccount;
+
+use curvy::error::CurvyError;
+use curvy::instruction::{
+    AlterCurve, ApplyAlter, CancelAlter, DeleteCurve, DeleteCurveConfidence, DeleteCurveSeries,
+    PatchY, ProposeAlter, SetAlterDelay, SetCurveConfidence, SetCurveExtension, SetCurveSeries,
+    SetCurveStatus,
+};
+use curvy::state::curve::{Curve, CurveStatus, MAX_ALTER_DELAY_SLOTS, MAX_Y_CNT};
+use curvy::state::curve_confidence::CurveConfidence;
+use curvy::state::curve_extension::{CurveGuardrails, ExtensionType};
+use curvy::state::curve_series::CurveSeries;
+use curvy::state::name_link::NameLink;
+use curvy::state::pending_alter::PendingAlter;
+
+use common::{
+    curve_account, name_link, params, process, set_slot, unwrap_account_error, TestAccount,
+};
+
+fn set_delay(accounts: &mut [TestAccount], owner: Pubkey, slots: u64) -> Result<(), CurvyError> {
+    let ix = SetAlterDelay {
+        curve: accounts[0].key,
+        owner,
+        slots,
+    };
+    process(ix.into_instruction(), accounts)
+}
+
+#[test]
+fn delay_only_grows() {
+    let owner = Pubkey::new_unique();
+    let mut accounts = [curve_account(Pubkey::new_unique(), owner, 1)];
+
+    assert_eq!(Curve::load(&accounts[0].data).unwrap().alter_delay(), 0);
+    assert!(set_delay(&mut accounts, Pubkey::new_unique(), 10).is_err());
+    assert!(set_delay(&mut accounts, owner, MAX_ALTER_DELAY_SLOTS + 1).is_err());
+
+    set_delay(&mut accounts, owner, 100).unwrap();
+    set_delay(&mut accounts, owner, 100).unwrap();
+    assert!(set_delay(&mut accounts, owner, 99).is_err());
+    assert!(set_delay(&mut accounts, owner, 0).is_err());
+    set_delay(&mut accounts, owner, MAX_ALTER_DELAY_SLOTS).unwrap();
+
+    assert_eq!(
+        Curve::load(&accounts[0].data).unwrap().alter_delay(),
+        MAX_ALTER_DELAY_SLOTS
+    );
+}
+
+#[test]
+fn timelocked_curve_is_not_altered_directly() {
+    let curve = Pubkey::new_unique();
+    let owner = Pubkey::new_unique();
+    let mut accounts = [curve_account(curve, owner, 1)];
+
+    let alter = AlterCurve {
+        curve,
+        owner,
+        params: params(2),
+    };
+    process(alter.into_instruction(), &mut accounts).unwrap();
+
+    set_delay(&mut accounts, owner, 100).unwrap();
+
+    let alter = AlterCurve {
+        curve,
+        owner,
+        params: params(3),
+    };
+    assert!(matches!(
+        unwrap_account_error(process(alter.into_instruction(), &mut accounts)),
+        CurvyError::AlterTimelocked { delay: 100 }
+    ));
+    let patch = PatchY {
+        curve,
+        owner,
+        start_index: 0,
+        values: vec![3],
+    };
+    assert!(process(patch.into_instruction(), &mut accounts).is_err());
+
+    assert_eq!(Curve::load(&accounts[0].data).unwrap().y[0], 2);
+}
+
+#[test]
+fn proposal_waits_for_delay() {
+    let curve = Pubkey::new_unique();
+    let owner = Pubkey::new_unique();
+    let pending_alter = PendingAlter::find_address(&curve).0;
+
+    // proposal account left by a previous proposal, creating it takes a system program call
+    let mut pending_data = vec![0; PendingAlter::SIZE];
+    PendingAlter::init_bytes(&mut pending_data, curve).unwrap();
+    let mut pending = TestAccount::new(pending_alter, curvy::ID, pending_data);
+    pending.lamports = 1_000;
+
+    let mut accounts = [curve_account(curve, owner, 1), pending];
+    set_delay(&mut accounts, owner, 100).unwrap();
+
+    let propose = |y| ProposeAlter {
+        curve,
+        owner,
+        pending_alter,
+        params: params(y),
+    };
+    let apply = || ApplyAlter {
+        curve,
+        owner,
+        pending_alter,
+    };
+
+    set_slot(1_000);
+    process(propose(5).into_instruction(), &mut accounts).unwrap();
+    let pending = PendingAlter::try_from_bytes(&accounts[1].data).unwrap();
+    assert_eq!(pending.apply_slot, 1_100);
+    assert_eq!(pending.params.y[0], 5_u32.to_le_bytes());
+
+    // a new proposal waits the whole delay again
+    set_slot(1_050);
+    process(propose(6).into_instruction(), &mut accounts).unwrap();
+
+    set_slot(1_100);
+    assert!(matches!(
+        unwrap_account_error(process(apply().into_instruction(), &mut accounts)),
+        CurvyError::AlterNotReady { from: 1_150 }
+    ));
+    assert_eq!(Curve::load(&accounts[0].data).unwrap().y[0], 1);
+
+    set_slot(1_150);
+    process(apply().into_instruction(), &mut accounts).unwrap();
+    assert_eq!(Curve::load(&accounts[0].data).unwrap().y[0], 6);
+
+    // the pending alter account is closed
+    assert_eq!(accounts[1].lamports, 0);
+    assert!(accounts[1].data.iter().all(|byte| *byte == 0));
+    assert!(process(apply().into_instruction(), &mut accounts).is_err());
+}
+
+#[test]
+fn proposal_is_cancelled() {
+    let curve = Pubkey::new_unique();
+    let owner = Pubkey::new_unique();
+    let pending_alter = PendingAlter::find_address(&curve).0;
+
+    let mut pending_data = vec![0; PendingAlter::SIZE];
+    PendingAlter::init_bytes(&mut pending_data, curve).unwrap();
+    let mut pending = TestAccount::new(pending_alter, curvy::ID, pending_data);
+    pending.lamports = 1_000;
+
+    // proposals of other curves are not cancelled
+    let other = Pubkey::new_unique();
+    let mut accounts = [curve_account(other, owner, 1), pending];
+    let cancel = CancelAlter {
+        curve: other,
+        owner,
+        pending_alter,
+    };
+    assert!(process(cancel.into_instruction(), &mut accounts).is_err());
+
+    accounts[0] = curve_account(curve, owner, 1);
+    let cancel = |owner| CancelAlter {
+        curve,
+        owner,
+        pending_alter,
+    };
+    assert!(process(
+        cancel(Pubkey::new_unique()).into_instruction(),
+        &mut accounts
+    )
+    .is_err());
+
+    process(cancel(owner).into_instruction(), &mut accounts).unwrap();
+    assert_eq!(accounts[1].lamports, 0);
+}
+
+#[test]
+fn timelocked_curve_keeps_series_confidence_and_guardrails() {
+    let curve = Pubkey::new_unique();
+    let owner = Pubkey::new_unique();
+    let mut accounts = [curve_account(curve, owner, 1)];
+    set_delay(&mut accounts, owner, 100).unwrap();
+
+    let curve_series = CurveSeries::find_address(&curve).0;
+    let curve_confidence = CurveConfidence::find_address(&curve).0;
+    let guardrails = CurveGuardrails::new(0, 10);
+    for ix in [
+        SetCurveSeries {
+            curve,
+            owner,
+            curve_series,
+            series: 1,
+            y: [2; MAX_Y_CNT],
+        }
+        .into_instruction(),
+        DeleteCurveSeries {
+            curve,
+            owner,
+            curve_series,
+        }
+        .into_instruction(),
+        SetCurveConfidence {
+            curve,
+            owner,
+            curve_confidence,
+            confidence: [100; MAX_Y_CNT],
+        }
+        .into_instruction(),
+        DeleteCurveConfidence {
+            curve,
+            owner,
+            curve_confidence,
+        }
+        .into_instruction(),
+        SetCurveExtension {
+            curve,
+            owner,
+            extension_type: ExtensionType::Guardrails as u16,
+            value: bytemuck::bytes_of(&guardrails).to_vec(),
+        }
+        .into_instruction(),
+    ] {
+        assert!(matches!(
+            unwrap_account_error(process(ix, &mut accounts)),
+            CurvyError::AlterTimelocked { delay: 100 }
+        ));
+    }
+}
+
+#[test]
+fn curve_with_proposal_is_not_deleted() {
+    let curve = Pubkey::new_unique();
+    let owner = Pubkey::new_unique();
+    let pending_alter = PendingAlter::find_address(&curve).0;
+
+    let mut pending_data = vec![0; PendingAlter::SIZE];
+    PendingAlter::init_bytes(&mut pending_data, curve).unwrap();
+    let mut accounts = [
+        curve_account(curve, owner, 1).with_lamports(5_000),
+        TestAccount::program(pending_alter, pending_data).with_lamports(1_000),
+        name_link(owner, params(1).name, curve),
+    ];
+
+    let propose = ProposeAlter {
+        curve,
+        owner,
+        pending_alter,
+        params: params(2),
+    };
+    process(propose.into_instruction(), &mut accounts).unwrap();
+    assert_eq!(Curve::load(&accounts[0].data).unwrap().has_pending_alter, 1);
+
+    for status in [CurveStatus::Deprecated, CurveStatus::Retired] {
+        let ix = SetCurveStatus {
+            curve,
+            owner,
+            status: status as u8,
+        };
+        process(ix.into_instruction(), &mut accounts).unwrap();
+    }
+    let delete = || DeleteCurve {
+        curve,
+        owner,
+        name_link: NameLink::find_address(&owner, &params(1).name).0,
+    };
+    assert!(matches!(
+        unwrap_account_error(process(delete().into_instruction(), &mut accounts)),
+        CurvyError::OperationCanNotBePerformed
+    ));
+
+    let cancel = CancelAlter {
+        curve,
+        owner,
+        pending_alter,
+    };
+    process(cancel.into_instruction(), &mut accounts).unwrap();
+    assert_eq!(Curve::load(&accounts[0].data).unwrap().has_pending_alter, 0);
+    process(delete().into_instruction(), &mut accounts).unwrap();
+    assert_eq!(accounts[0].lamports, 0);
+}
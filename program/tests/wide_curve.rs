@@ -0,0 +1,107 @@
+mod common;
+
+use texture_common::_export::Pubkey;
+use texture_common::account::PodAccount;
+
+use curvy::error::CurvyError;
+use curvy::instruction::{CurvyInstruction, DeleteCurve, MarkForDeletion, SetCurveStatus};
+use curvy::state::curve::{CurveParams, CurveStatus, DELETION_GRACE_SLOTS, MAX_Y_CNT};
+use curvy::state::name_link::NameLink;
+use curvy::state::wide_curve::{WideCurve, WideCurveParams, MAX_WIDE_Y_CNT};
+use curvy::state::{probe, AccountKind};
+
+use common::{name_link, process, set_slot, unwrap_account_error, TestAccount};
+
+fn params(y_count: u8) -> WideCurveParams {
+    let mut y = [0; MAX_WIDE_Y_CNT];
+    for (idx, y) in y.iter_mut().enumerate() {
+        *y = u32::MAX as u64 * (idx as u64 + 1);
+    }
+
+    WideCurveParams::new("SOL-depth", "y=kx", 0, 10, y_count, 2, y)
+}
+
+/// Wide params must fit in a TX as well as regular ones
+#[test]
+fn wide_params_are_not_larger() {
+    let wide = borsh::to_vec(&CurvyInstruction::CreateWideCurve {
+        params: params(MAX_WIDE_Y_CNT as u8),
+    })
+    .unwrap();
+    let regular = borsh::to_vec(&CurvyInstruction::CreateCurve {
+        params: CurveParams::new("", "", 0, 1, 1, 0, [0; MAX_Y_CNT]),
+    })
+    .unwrap();
+
+    assert!(wide.len() <= regular.len());
+}
+
+#[test]
+fn wide_curve_params() {
+    assert!(params(MAX_WIDE_Y_CNT as u8).check().is_ok());
+    assert!(params(MAX_WIDE_Y_CNT as u8 + 1).check().is_err());
+    assert!(params(0).check().is_err());
+
+    let owner = Pubkey::new_unique();
+    let mut data = vec![0; WideCurve::SIZE];
+    WideCurve::init_bytes(&mut data, (params(3), owner)).unwrap();
+
+    let curve = WideCurve::try_from_bytes(&data).unwrap();
+    assert_eq!(curve.owner, owner);
+    assert_eq!(curve.y[2], 3 * u32::MAX as u64);
+    assert_eq!(probe(&data), Some(AccountKind::WideCurve { version: 1 }));
+}
+
+#[test]
+fn wide_curve_is_deleted_retired_and_after_grace() {
+    let curve = Pubkey::new_unique();
+    let owner = Pubkey::new_unique();
+    let mut data = vec![0; WideCurve::SIZE];
+    WideCurve::init_bytes(&mut data, (params(3), owner)).unwrap();
+    let mut accounts = [
+        TestAccount::program(curve, data).with_lamports(1_000),
+        name_link(owner, params(3).name, curve),
+    ];
+
+    let delete = || {
+        DeleteCurve {
+            curve,
+            owner,
+            name_link: NameLink::find_address(&owner, &params(3).name).0,
+        }
+        .into_instruction()
+    };
+    let status = |status: CurveStatus| {
+        SetCurveStatus {
+            curve,
+            owner,
+            status: status as u8,
+        }
+        .into_instruction()
+    };
+
+    assert!(matches!(
+        unwrap_account_error(process(delete(), &mut accounts)),
+        CurvyError::OperationCanNotBePerformed
+    ));
+
+    process(status(CurveStatus::Deprecated), &mut accounts).unwrap();
+    process(status(CurveStatus::Retired), &mut accounts).unwrap();
+    let loaded = WideCurve::try_from_bytes(&accounts[0].data).unwrap();
+    assert_eq!(loaded.status(), CurveStatus::Retired);
+
+    set_slot(1_000);
+    process(
+        MarkForDeletion { curve, owner }.into_instruction(),
+        &mut accounts,
+    )
+    .unwrap();
+    assert!(matches!(
+        unwrap_account_error(process(delete(), &mut accounts)),
+        CurvyError::DeletionGracePeriod { from } if from == 1_000 + DELETION_GRACE_SLOTS
+    ));
+
+    set_slot(1_000 + DELETION_GRACE_SLOTS);
+    process(delete(), &mut accounts).unwrap();
+    assert_eq!(accounts[0].lamports, 0);
+}
@@ -0,0 +1,72 @@
+mod common;
+
+use solana_program::pubkey::Pubkey;
+use texture_common::account::PodAccount;
+
+use curvy::error::CurvyError;
+use curvy::eval::{calc_y_fixed, calc_y_u128};
+use curvy::events::CurveEvent;
+use curvy::instruction::SetCurveYTransform;
+use curvy::state::curve::{Curve, CurveParams, YTransform, MAX_Y_CNT};
+
+use common::{process, take_events, TestAccount};
+
+#[test]
+fn transform_is_set_by_owner() {
+    let curve = Pubkey::new_unique();
+    let owner = Pubkey::new_unique();
+    let params = CurveParams::new("SOL-borrow", "y=10^x", 0, 10, 2, 6, [1; MAX_Y_CNT]);
+    let mut data = vec![0; Curve::SIZE];
+    Curve::init_bytes(&mut data, (params, owner)).unwrap();
+    let mut accounts = [TestAccount::program(curve, data)];
+
+    let set = |owner, y_transform| {
+        SetCurveYTransform {
+            curve,
+            owner,
+            y_transform,
+        }
+        .into_instruction()
+    };
+    let loaded = |data: &[u8]| *Curve::load(data).unwrap();
+    assert_eq!(loaded(&accounts[0].data).y_transform(), YTransform::Linear);
+
+    assert!(process(
+        set(Pubkey::new_unique(), YTransform::Log10 as u8),
+        &mut accounts
+    )
+    .is_err());
+    assert!(matches!(
+        process(set(owner, 2), &mut accounts),
+        Err(CurvyError::Account { .. })
+    ));
+    assert_eq!(loaded(&accounts[0].data).y_transform(), YTransform::Linear);
+
+    process(set(owner, YTransform::Log10 as u8), &mut accounts).unwrap();
+    let log = loaded(&accounts[0].data);
+    assert_eq!(log.y_transform(), YTransform::Log10);
+    // an alteration keeping the samples
+    assert_eq!(log.revision(), 1);
+    match &take_events()[..] {
+        [CurveEvent::Altered(event)] => assert_eq!(event.old_y_hash, event.new_y_hash),
+        events => panic!("unexpected {events:?}"),
+    }
+    // integer evaluation does not apply transforms
+    assert!(calc_y_fixed(&log, 0).is_err());
+    assert!(calc_y_u128(&log, 0, 6).is_err());
+
+    process(set(owner, YTransform::Linear as u8), &mut accounts).unwrap();
+    assert_eq!(calc_y_fixed(&loaded(&accounts[0].data), 0).unwrap(), 1);
+}
+
+#[test]
+fn unknown_transform_is_not_linear() {
+    let params = CurveParams::new("SOL-borrow", "y=x", 0, 10, 2, 6, [1; MAX_Y_CNT]);
+    let mut curve = Curve::from_init_params((params, Pubkey::new_unique()));
+    curve.y_transform = 7;
+
+    assert_eq!(curve.y_transform(), YTransform::Log10);
+    assert!(curve.check_linear_y().is_err());
+    assert_eq!("log10".parse::<YTransform>(), Ok(YTransform::Log10));
+    assert!("ln".parse::<YTransform>().is_err());
+}
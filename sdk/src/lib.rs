@@ -0,0 +1,108 @@
+//! Supported API of Curvy for downstream projects: account types, instruction builders,
+//! evaluators and the RPC client (`client` feature, on by default).
+//!
+//! Items are re-exported from the workspace crates, which are free to move them around.
+//! Everything reachable from this crate keeps its path and signature within a minor version, so
+//! depend on `curvy-sdk` rather than on `curvy`, `curvy-utils` or `curvy-client` directly.
+
+pub use curvy::{CurvyResult, ID};
+pub use curvy_spec::PROGRAM_ID;
+pub use texture_common::account::PodAccount;
+pub use texture_common::math::Decimal;
+
+/// Accounts of the program, their params and limits
+pub mod state {
+    pub use curvy::state::asset_link::AssetLink;
+    pub use curvy::state::config::Config;
+    pub use curvy::state::curve::{
+        find_curve_address, find_reference_curve_address, CompactCurveParams, Curve, CurveParams,
+        CurveStatus, CurveX, CurveY, YTransform, MAX_TOTAL_Y_CNT, MAX_Y_CNT, SYMBOL_MAX_SIZE,
+    };
+    pub use curvy::state::curve_confidence::{CurveConfidence, CurveConfidenceBps};
+    pub use curvy::state::curve_evaluation::CurveEvaluation;
+    pub use curvy::state::curve_extension::{
+        CurveGuardrails, CurveSchedule, CurveTags, ExtensionType,
+    };
+    pub use curvy::state::curve_owners::CurveOwners;
+    pub use curvy::state::curve_series::{CurveSeries, MAX_SERIES};
+    pub use curvy::state::grid_curve::{GridCurve, GridCurveParams, MAX_GRID_Y_CNT};
+    pub use curvy::state::name_link::NameLink;
+    pub use curvy::state::pending_alter::PendingAlter;
+    pub use curvy::state::wide_curve::{WideCurve, WideCurveParams, WideCurveY, MAX_WIDE_Y_CNT};
+    pub use curvy::state::{probe, AccountKind};
+}
+
+/// Builders of program instructions, see [CurvyInstruction] for their accounts
+pub mod instruction {
+    pub use curvy::instruction::{
+        AcceptOwner, AddDelegate, AlterCurve, AlterGridCurve, AlterWideCurve, ApplyAlter,
+        BatchAlter, CancelAlter, CancelDeletion, ClearCurveAsset, CreateCurve, CreateCurvePda,
+        CreateGridCurve, CreateReferenceCurve, CreateWideCurve, CurvyInstruction, DeleteCurve,
+        DeleteCurveConfidence, DeleteCurveSeries, EvaluateCurve, FreezeCurve, InitConfig,
+        MarkForDeletion, MigrateCurve, PatchY, ProposeAlter, ProposeOwner, RemoveDelegate,
+        ReserveCurve, ResizeCurve, SetAlterDelay, SetConfig, SetCreationFrozen, SetCurveAsset,
+        SetCurveConfidence, SetCurveExpiry, SetCurveExtension, SetCurveOwners, SetCurveSeries,
+        SetCurveStatus, SetCurveTail, SetCurveXSign, SetCurveYSign, SetCurveYTransform, TopUpRent,
+        UpdateCurveData, UpdateCurveMetadata,
+    };
+}
+
+/// Errors of the program and their codes
+pub mod error {
+    pub use curvy::error::{CurvyError, ErrorCode, ERROR_CODES};
+}
+
+/// Events logged by the program
+pub mod events {
+    pub use curvy::events::{
+        y_hash, CurveAltered, CurveCreated, CurveDeleted, CurveEvent, EVENT_DISCRIMINATOR,
+    };
+}
+
+/// Evaluation of `y` at `x`: fixed point ones usable on-chain and [Decimal] ones
+pub mod eval {
+    pub use curvy::eval::{calc_y_fixed, calc_y_u128, MAX_UPSCALE_DECIMALS};
+    pub use curvy_utils::{
+        apply_y_transform, calc_y, calc_y_at, calc_y_checked, calc_y_extended, calc_y_grid,
+        calc_y_scaled, calc_y_series, calc_y_wide, calc_y_with_confidence, curve_from_base64,
+        curve_from_bytes, eval_matrix, AnyCurve, CurveSet, Rounding,
+    };
+}
+
+/// RPC client of the program
+#[cfg(feature = "client")]
+pub mod client {
+    pub use curvy_client::{
+        curve_from_account, curve_from_encoded, curve_from_ui_account, watch_curve, AlterGuard,
+        Confirmation, CurveEvent, CurveView, CurvyClient, CurvyClientError, ReadBudget,
+        ReadLimiter, ReadStats, Resubscribe, WithContext,
+    };
+    pub use curvy_client::{plan, ExpectedCurve, ExpectedState, ReconcileAction, ReconcilePlan};
+}
+
+#[cfg(test)]
+mod tests {
+    use texture_common::_export::Pubkey;
+
+    use super::eval::{calc_y, calc_y_fixed};
+    use super::state::{Curve, CurveParams, MAX_Y_CNT};
+    use super::*;
+
+    #[test]
+    fn surface_is_usable_on_its_own() {
+        assert_eq!(ID.to_string(), PROGRAM_ID);
+
+        let params = CurveParams::new("test curve", "y=x", 0, 10, 3, 2, {
+            let mut y = [0; MAX_Y_CNT];
+            y[..3].copy_from_slice(&[100, 200, 300]);
+            y
+        });
+        let curve = Curve::from_init_params((params, Pubkey::default()));
+        let x = Decimal::from_i128_with_scale(15, 2).unwrap();
+        assert_eq!(
+            calc_y(x, &curve).unwrap(),
+            Decimal::from_i128_with_scale(250, 2).unwrap()
+        );
+        assert_eq!(calc_y_fixed(&curve, 15).unwrap(), 250);
+    }
+}
@@ -0,0 +1,187 @@
+//! Constants of the Curvy program: limits, account layouts and error codes. Has no
+//! dependencies, so other on-chain programs can read Curvy accounts without depending on the
+//! program crate and its `solana-program` version. The program checks these values at compile
+//! time.
+
+/// Curvy program ID in base58
+pub const PROGRAM_ID: &str = "CurvyNqr6HXwWUHk7MfDk7rqL3a4Kodkz8BkdBhep7ed";
+
+/// Bytes of [PROGRAM_ID]
+pub const PROGRAM_ID_BYTES: [u8; 32] = [
+    176, 251, 55, 4, 201, 136, 251, 115, 238, 199, 69, 78, 200, 26, 222, 42, 94, 124, 251, 5, 145,
+    40, 107, 157, 241, 57, 230, 188, 83, 2, 4, 134,
+];
+
+/// Size of name and formula of a curve
+pub const SYMBOL_MAX_SIZE: usize = 16;
+
+/// Max number of `y` samples of a curve
+pub const MAX_Y_CNT: usize = 130;
+
+/// Max number of `y` samples of a curve grown by ResizeCurve, including its `y` table. Samples
+/// after the `y` table follow the Curve layout at the end of the account.
+pub const MAX_TOTAL_Y_CNT: usize = 1024;
+
+/// Max number of `y` samples of a wide curve
+pub const MAX_WIDE_Y_CNT: usize = 65;
+
+/// Max number of samples of a grid curve, its `x` and `y` tables take the space of the curve
+/// `y` table
+pub const MAX_GRID_Y_CNT: usize = 65;
+
+/// Max number of `y` series of a curve, including its own `y` table
+pub const MAX_SERIES: usize = 3;
+
+/// Max number of series kept in the curve series account
+pub const MAX_EXTRA_SERIES: usize = MAX_SERIES - 1;
+
+/// Space left in Curve for future fields
+pub const CURVE_RESERVED_SIZE: usize = 35;
+
+/// Max size of the TLV extension region at the end of a Curve account. Each extension is a
+/// little-endian `u16` type and `u16` length followed by the value.
+pub const MAX_CURVE_EXTENSIONS_SIZE: usize = 1024;
+
+/// Types of Curve extensions
+pub const CURVE_EXTENSION_TAGS: u16 = 1;
+pub const CURVE_EXTENSION_GUARDRAILS: u16 = 2;
+pub const CURVE_EXTENSION_SCHEDULE: u16 = 3;
+
+/// Slots a curve marked by MarkForDeletion waits before DeleteCurve accepts it, about a day
+pub const DELETION_GRACE_SLOTS: u64 = 216_000;
+
+/// Max delay of timelocked curve alterations set by SetAlterDelay, about 30 days
+pub const MAX_ALTER_DELAY_SLOTS: u64 = 6_480_000;
+
+/// Max number of delegates of a curve, keys allowed to update its samples
+pub const MAX_CURVE_DELEGATES: usize = 4;
+
+/// Max number of owners of a co-owned curve
+pub const MAX_CURVE_OWNERS: usize = 8;
+
+/// Max number of keys in the creator allow-list of Config
+pub const MAX_CONFIG_CREATORS: usize = 16;
+
+/// Space left in Config for future fields
+pub const CONFIG_RESERVED_SIZE: usize = 64;
+
+/// Space left in WideCurve for future fields
+pub const WIDE_CURVE_RESERVED_SIZE: usize = 56;
+
+/// Space left in GridCurve for future fields
+pub const GRID_CURVE_RESERVED_SIZE: usize = 56;
+
+pub const CURVE_DISCRIMINATOR: &[u8; 8] = b"CURVE___";
+pub const ASSET_LINK_DISCRIMINATOR: &[u8; 8] = b"ASSETLNK";
+pub const CURVE_SERIES_DISCRIMINATOR: &[u8; 8] = b"CRVSERIE";
+pub const CURVE_CONFIDENCE_DISCRIMINATOR: &[u8; 8] = b"CRVCONFD";
+pub const WIDE_CURVE_DISCRIMINATOR: &[u8; 8] = b"WIDECURV";
+pub const GRID_CURVE_DISCRIMINATOR: &[u8; 8] = b"GRIDCURV";
+pub const CURVE_EVALUATION_DISCRIMINATOR: &[u8; 8] = b"CRVEVALU";
+pub const PENDING_ALTER_DISCRIMINATOR: &[u8; 8] = b"CRVPNDAL";
+pub const CONFIG_DISCRIMINATOR: &[u8; 8] = b"CRVCONFG";
+pub const CURVE_OWNERS_DISCRIMINATOR: &[u8; 8] = b"CRVOWNRS";
+pub const NAME_LINK_DISCRIMINATOR: &[u8; 8] = b"NAMELINK";
+
+/// Current layout versions, the version byte follows the discriminator in all accounts
+pub const CURVE_VERSION: u8 = 3;
+pub const ASSET_LINK_VERSION: u8 = 1;
+pub const CURVE_SERIES_VERSION: u8 = 1;
+pub const CURVE_CONFIDENCE_VERSION: u8 = 1;
+pub const WIDE_CURVE_VERSION: u8 = 1;
+pub const GRID_CURVE_VERSION: u8 = 1;
+pub const CURVE_EVALUATION_VERSION: u8 = 1;
+pub const PENDING_ALTER_VERSION: u8 = 1;
+pub const CONFIG_VERSION: u8 = 1;
+pub const CURVE_OWNERS_VERSION: u8 = 1;
+pub const NAME_LINK_VERSION: u8 = 1;
+
+/// Account sizes of the current layout versions
+pub const CURVE_SIZE: usize = 912;
+/// Size of Curve v1 accounts, which end with the `y` table
+pub const CURVE_V1_SIZE: usize = 616;
+pub const ASSET_LINK_SIZE: usize = 112;
+pub const CURVE_SERIES_SIZE: usize = 1088;
+pub const CURVE_CONFIDENCE_SIZE: usize = 312;
+pub const WIDE_CURVE_SIZE: usize = 680;
+pub const GRID_CURVE_SIZE: usize = 672;
+pub const CURVE_EVALUATION_SIZE: usize = 136;
+pub const PENDING_ALTER_SIZE: usize = 624;
+pub const CONFIG_SIZE: usize = 624;
+pub const CURVE_OWNERS_SIZE: usize = 336;
+pub const NAME_LINK_SIZE: usize = 96;
+
+/// Seeds prefixes of program derived accounts
+pub const CURVE_SEED: &[u8] = b"curve";
+pub const ASSET_LINK_SEED: &[u8] = b"asset";
+pub const CURVE_SERIES_SEED: &[u8] = b"series";
+pub const CURVE_CONFIDENCE_SEED: &[u8] = b"confidence";
+pub const PENDING_ALTER_SEED: &[u8] = b"pending_alter";
+pub const CONFIG_SEED: &[u8] = b"config";
+pub const CURVE_OWNERS_SEED: &[u8] = b"owners";
+pub const NAME_LINK_SEED: &[u8] = b"name";
+pub const REFERENCE_SEED: &[u8] = b"reference";
+
+/// Custom program error code with the name of the program error reported with it
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct ErrorCode {
+    pub code: u32,
+    pub name: &'static str,
+    pub description: &'static str,
+}
+
+macro_rules! error_codes {
+    ($($code:literal => $name:ident: $description:literal,)*) => {
+        /// Custom program error codes returned by the program, ordered by code
+        pub const ERROR_CODES: &[ErrorCode] = &[$(
+            ErrorCode {
+                code: $code,
+                name: stringify!($name),
+                description: $description,
+            },
+        )*];
+    };
+}
+
+error_codes! {
+    3 => MathError: "math operation failed (overflow, division by zero)",
+    4 => Borsh: "instruction data or account can not be (de)serialized with borsh",
+    5 => Serialize: "account data has unexpected size or layout",
+    6 => PodAccount: "account data can not be loaded",
+    6 => PodAccountExt: "account data can not be loaded",
+    8 => InvalidKey: "account address differs from the expected one",
+    9 => InvalidAccount: "account is not valid for the instruction (owner, size, flags)",
+    10 => NotEnoughAccountKeys: "instruction is missing accounts",
+    11 => MissingSignature: "account required to sign did not sign",
+    12 => Unimplemented: "operation is not implemented",
+    13 => UninitializedAccount: "account is not initialized",
+    14 => AddressCreation: "program address can not be derived",
+    15 => AccountUnpackError: "account can not be unpacked",
+    23 => Internal: "internal logic error",
+    24 => InvalidAccountData: "account data contains unexpected values",
+    25 => OperationCanNotBePerformed: "operation can not be performed in the current state",
+    27 => InvalidRealloc: "account can not be resized",
+    28 => OwnerMismatch: "signer is not the curve owner",
+    29 => InvalidParams: "curve parameters are not valid (sizes, decimals, x scale)",
+    30 => WrongDiscriminator: "account is not a curve (wrong discriminator)",
+    31 => UnsupportedVersion: "curve account version is newer than the program supports",
+    32 => NotRentExempt: "account balance is below rent exempt minimum",
+    33 => CurveFrozen: "curve is frozen, its data can not be changed and it can not be deleted",
+    34 => InvalidStatusTransition: "curve status can not change between the given stages",
+    35 => CurveNotActive: "curve is draft or retired and must not be consumed",
+    36 => DeletionGracePeriod: "curve is marked for deletion and its grace period has not passed",
+    37 => AlterTimelocked: "curve alterations are timelocked, propose them by ProposeAlter",
+    38 => AlterNotReady: "proposed curve alteration can not be applied before its delay passes",
+    39 => UnexpectedAccounts: "instruction got more accounts than it expects",
+    40 => CreatorNotAllowed: "curve creator is not in the allow-list of the program config",
+    41 => CoOwnersRequired: "curve is co-owned, the threshold of its owners must sign AlterCurve or SetCurveOwners",
+    42 => CurveExpired: "curve is stale, its valid_until has passed and it must be altered again",
+    43 => CurveInactive: "curve is paused by its owner and must not be consumed until it is resumed",
+    44 => CreationFrozen: "curve creation is frozen by the config admin, curves can not be created until it is unfrozen",
+}
+
+/// Entry of [ERROR_CODES] with given code
+pub fn describe_code(code: u32) -> Option<&'static ErrorCode> {
+    ERROR_CODES.iter().find(|error| error.code == code)
+}
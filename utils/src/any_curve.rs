@@ -0,0 +1,134 @@
+use anyhow::{anyhow, bail, Result};
+use texture_common::_export::Pubkey;
+use texture_common::account::PodAccount;
+use texture_common::math::{Decimal, MathResult};
+
+use curvy::state::curve::{Curve, CurveStatus, CurveX, SYMBOL_MAX_SIZE};
+use curvy::state::grid_curve::{GridCurve, MAX_GRID_Y_CNT};
+use curvy::state::wide_curve::{WideCurve, MAX_WIDE_Y_CNT};
+use curvy::state::{probe, AccountKind};
+
+use crate::{calc_y, calc_y_grid, calc_y_wide, curve_from_bytes};
+
+/// Any curve layout, for code which evaluates curves regardless of their `y` width and X grid
+// both layouts are of similar size, boxing would only cost an allocation
+#[allow(clippy::large_enum_variant)]
+#[derive(Debug, Clone, Copy)]
+pub enum AnyCurve {
+    Curve(Curve),
+    Wide(WideCurve),
+    Grid(GridCurve),
+}
+
+impl AnyCurve {
+    /// Decodes Curve, WideCurve or GridCurve account data, see [curve_from_bytes]
+    pub fn from_bytes(data: &[u8]) -> Result<Self> {
+        match probe(data) {
+            Some(AccountKind::WideCurve { version }) if version == WideCurve::VERSION => {
+                let curve = WideCurve::try_from_bytes(data)
+                    .map_err(|err| anyhow!("error unpacking WideCurve account: {}", err))?;
+                Ok(Self::Wide(*curve))
+            }
+            Some(AccountKind::WideCurve { version }) => {
+                bail!("unsupported account: WideCurve v{version}")
+            }
+            Some(AccountKind::GridCurve { version }) if version == GridCurve::VERSION => {
+                let curve = GridCurve::try_from_bytes(data)
+                    .map_err(|err| anyhow!("error unpacking GridCurve account: {}", err))?;
+                Ok(Self::Grid(*curve))
+            }
+            Some(AccountKind::GridCurve { version }) => {
+                bail!("unsupported account: GridCurve v{version}")
+            }
+            _ => curve_from_bytes(data).map(Self::Curve),
+        }
+    }
+
+    /// Y in given X point, see [calc_y]
+    pub fn calc_y(&self, x: Decimal) -> MathResult<Decimal> {
+        match self {
+            Self::Curve(curve) => calc_y(x, curve),
+            Self::Wide(curve) => calc_y_wide(x, curve),
+            Self::Grid(curve) => calc_y_grid(x, curve),
+        }
+    }
+
+    pub fn owner(&self) -> Pubkey {
+        match self {
+            Self::Curve(curve) => curve.owner,
+            Self::Wide(curve) => curve.owner,
+            Self::Grid(curve) => curve.owner,
+        }
+    }
+
+    pub fn name(&self) -> &[u8; SYMBOL_MAX_SIZE] {
+        match self {
+            Self::Curve(curve) => &curve.name,
+            Self::Wide(curve) => &curve.name,
+            Self::Grid(curve) => &curve.name,
+        }
+    }
+
+    /// Lifecycle stage, see [Curve::status]
+    pub fn status(&self) -> CurveStatus {
+        match self {
+            Self::Curve(curve) => curve.status(),
+            Self::Wide(curve) => curve.status(),
+            Self::Grid(curve) => curve.status(),
+        }
+    }
+
+    /// Whether the curve is frozen by FreezeCurve
+    pub fn is_frozen(&self) -> bool {
+        match self {
+            Self::Curve(curve) => curve.is_frozen != 0,
+            Self::Wide(curve) => curve.is_frozen != 0,
+            Self::Grid(curve) => curve.is_frozen != 0,
+        }
+    }
+
+    /// Slot the curve was marked for deletion in by MarkForDeletion, none unless marked
+    pub fn deletion_slot(&self) -> Option<u64> {
+        match self {
+            Self::Curve(curve) => curve.deletion_slot(),
+            Self::Wide(curve) => curve.deletion_slot(),
+            Self::Grid(curve) => curve.deletion_slot(),
+        }
+    }
+
+    /// First slot DeleteCurve accepts the curve marked for deletion in, see
+    /// [Curve::deletable_from_slot]
+    pub fn deletable_from_slot(&self) -> Option<u64> {
+        match self {
+            Self::Curve(curve) => curve.deletable_from_slot(),
+            Self::Wide(curve) => curve.deletable_from_slot(),
+            Self::Grid(curve) => curve.deletable_from_slot(),
+        }
+    }
+
+    /// `x0`, `x_step`, `y_count` and `decimals`. Grid curves have no uniform step, their
+    /// `x_step` is 0 and `x0` is the first of their `x` values.
+    pub fn dimensions(&self) -> (CurveX, CurveX, u8, u8) {
+        match self {
+            Self::Curve(curve) => (curve.x0, curve.x_step, curve.y_count, curve.x_decimals),
+            Self::Wide(curve) => (curve.x0, curve.x_step, curve.y_count, curve.decimals),
+            Self::Grid(curve) => (curve.x[0], 0, curve.y_count, curve.decimals),
+        }
+    }
+
+    /// `y` table values up to `y_count`, widened. Signed curve values are kept as bits, see
+    /// [Curve::y_value].
+    pub fn y(&self) -> Vec<u64> {
+        match self {
+            Self::Curve(curve) => curve.y[..curve.y_count as usize]
+                .iter()
+                .map(|y| *y as u64)
+                .collect(),
+            Self::Wide(curve) => curve.y[..(curve.y_count as usize).min(MAX_WIDE_Y_CNT)].to_vec(),
+            Self::Grid(curve) => curve.y[..(curve.y_count as usize).min(MAX_GRID_Y_CNT)]
+                .iter()
+                .map(|y| *y as u64)
+                .collect(),
+        }
+    }
+}
@@ -0,0 +1,80 @@
+use texture_common::math::{CheckedAdd, CheckedDiv, CheckedSub, Decimal, MathResult};
+
+use curvy::state::curve::Curve;
+
+use crate::calc_y;
+
+/// Interpolation error of a stored curve against the samples it was built from. Errors are
+/// absolute, in units of Y.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "with-serde", derive(serde::Serialize))]
+pub struct ApproximationError {
+    /// Number of samples compared
+    pub samples: usize,
+    /// Number of samples outside of the curve X range, not compared
+    pub out_of_range: usize,
+    #[cfg_attr(
+        feature = "with-serde",
+        serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+    )]
+    pub max: Decimal,
+    /// X of the sample with the max error
+    #[cfg_attr(
+        feature = "with-serde",
+        serde(with = "serde_with::As::<Option<serde_with::DisplayFromStr>>")
+    )]
+    pub max_at: Option<Decimal>,
+    #[cfg_attr(
+        feature = "with-serde",
+        serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+    )]
+    pub mean: Decimal,
+}
+
+/// Compares Y of `curve` (see [calc_y]) with Y of original `samples` given as (x, y) pairs in
+/// human-readable units
+pub fn approximation_error(
+    curve: &Curve,
+    samples: &[(Decimal, Decimal)],
+) -> MathResult<ApproximationError> {
+    let decimals = curve.x_decimals as u32;
+    let (x_first, x_last) = curve.x_range_signed();
+    let x_first = Decimal::from_i128_with_scale(x_first as i128, decimals)?;
+    let x_last = Decimal::from_i128_with_scale(x_last as i128, decimals)?;
+
+    let mut report = ApproximationError {
+        samples: 0,
+        out_of_range: 0,
+        max: Decimal::ZERO,
+        max_at: None,
+        mean: Decimal::ZERO,
+    };
+    let mut sum = Decimal::ZERO;
+
+    for (x, y) in samples {
+        if curve.y_count == 0 || !(x_first..=x_last).contains(x) {
+            report.out_of_range += 1;
+            continue;
+        }
+
+        let stored = calc_y(*x, curve)?;
+        let error = if stored > *y {
+            stored.checked_sub(*y)?
+        } else {
+            y.checked_sub(stored)?
+        };
+
+        report.samples += 1;
+        sum = sum.checked_add(error)?;
+        if report.max_at.is_none() || error > report.max {
+            report.max = error;
+            report.max_at = Some(*x);
+        }
+    }
+
+    if report.samples > 0 {
+        report.mean = sum.checked_div(Decimal::from_i128_with_scale(report.samples as i128, 0)?)?;
+    }
+
+    Ok(report)
+}
@@ -0,0 +1,92 @@
+use std::collections::HashMap;
+
+use anyhow::{anyhow, bail, Result};
+use texture_common::_export::Pubkey;
+use texture_common::math::Decimal;
+
+use curvy::state::curve::Curve;
+
+use crate::calc_y;
+
+/// Set of curves indexed by address and by name. Built from [HashMap] returned by `load_curves`.
+#[derive(Debug, Clone, Default)]
+pub struct CurveSet {
+    curves: HashMap<Pubkey, Curve>,
+    by_name: HashMap<String, Vec<Pubkey>>,
+}
+
+impl CurveSet {
+    pub fn new(curves: HashMap<Pubkey, Curve>) -> Self {
+        let mut by_name: HashMap<String, Vec<Pubkey>> = HashMap::new();
+
+        for (key, curve) in &curves {
+            by_name.entry(curve_name(curve)).or_default().push(*key);
+        }
+
+        for keys in by_name.values_mut() {
+            keys.sort();
+        }
+
+        Self { curves, by_name }
+    }
+
+    pub fn get(&self, key: &Pubkey) -> Option<&Curve> {
+        self.curves.get(key)
+    }
+
+    /// Returns curve with given name. Fails when there is no such curve or the name is
+    /// ambiguous.
+    pub fn get_by_name(&self, name: &str) -> Result<(Pubkey, &Curve)> {
+        let key = match self.by_name.get(name).map(Vec::as_slice) {
+            None | Some([]) => bail!("curve `{name}` not found"),
+            Some([key]) => key,
+            Some(keys) => bail!("curve name `{name}` is ambiguous: {keys:?}"),
+        };
+
+        Ok((*key, &self.curves[key]))
+    }
+
+    /// Calculates Y in the point `x` (see [calc_y]) on the curve with given address
+    pub fn eval(&self, key: &Pubkey, x: Decimal) -> Result<Decimal> {
+        let curve = self
+            .get(key)
+            .ok_or_else(|| anyhow!("curve {key} not found"))?;
+
+        Ok(calc_y(x, curve)?)
+    }
+
+    /// Calculates Y in the point `x` (see [calc_y]) on the curve with given name
+    pub fn eval_by_name(&self, name: &str, x: Decimal) -> Result<Decimal> {
+        let (_key, curve) = self.get_by_name(name)?;
+
+        Ok(calc_y(x, curve)?)
+    }
+
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.by_name.keys().map(String::as_str)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&Pubkey, &Curve)> {
+        self.curves.iter()
+    }
+
+    pub fn len(&self) -> usize {
+        self.curves.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.curves.is_empty()
+    }
+}
+
+impl From<HashMap<Pubkey, Curve>> for CurveSet {
+    fn from(curves: HashMap<Pubkey, Curve>) -> Self {
+        Self::new(curves)
+    }
+}
+
+fn curve_name(curve: &Curve) -> String {
+    String::from_utf8_lossy(&curve.name)
+        .trim_end_matches('\0')
+        .to_string()
+}
@@ -0,0 +1,173 @@
+use std::cmp::Ordering;
+
+use anyhow::{anyhow, bail, Result};
+use texture_common::account::PodAccount;
+use texture_common::math::{CheckedAdd, CheckedDiv, CheckedMul, CheckedSub, Decimal};
+
+use curvy::state::curve::{Curve, CurveParams, CurveX, CurveY, MAX_Y_CNT};
+
+use crate::{approximation_error, ApproximationError};
+
+/// Uniform grid fitted to dense samples
+#[derive(Debug, Clone, Copy)]
+pub struct Fit {
+    pub x0: CurveX,
+    pub x_step: CurveX,
+    pub y_count: u8,
+    pub y: [CurveY; MAX_Y_CNT],
+    /// Error of the grid against the samples
+    pub error: ApproximationError,
+}
+
+/// Compresses dense `samples` ((x, y) pairs in human-readable units, as in
+/// [approximation_error]) into a curve grid with `decimals`. `x0` and `x_step` are chosen
+/// automatically so the grid covers all samples, Y values are linearly interpolated.
+///
+/// Without `target_error` the grid with the least max error is returned. With it, the grid
+/// with the fewest points having max error within the target, fails when there is none.
+pub fn fit_uniform(
+    samples: &[(Decimal, Decimal)],
+    decimals: u8,
+    target_error: Option<Decimal>,
+) -> Result<Fit> {
+    let mut samples = samples.to_vec();
+    samples.sort_by(|(a, _), (b, _)| a.partial_cmp(b).unwrap_or(Ordering::Equal));
+    samples.dedup_by(|(a, _), (b, _)| a == b);
+
+    let (Some((x_first, _)), Some((x_last, _))) = (samples.first(), samples.last()) else {
+        bail!("no samples");
+    };
+    if samples.len() < 2 {
+        bail!("at least 2 distinct samples required");
+    }
+    if *x_first < Decimal::ZERO || samples.iter().any(|(_, y)| *y < Decimal::ZERO) {
+        bail!("negative samples can not be stored in a curve");
+    }
+
+    let scale = Decimal::from_i128_with_scale(10, 0)?.checked_pow(decimals as u64)?;
+    let x0 = x_first.checked_mul(scale)?.floor()?;
+    let span = x_last.checked_mul(scale)?.checked_sub(from_u64(x0)?)?;
+
+    let mut best: Option<Fit> = None;
+
+    for y_count in 2..=MAX_Y_CNT as u64 {
+        let x_step = ceil(span.checked_div(from_u64(y_count - 1)?)?)?.max(1);
+        let fit = match grid(&samples, decimals, scale, x0, x_step, y_count) {
+            Ok(fit) => fit,
+            // e.g. grid end does not fit in X type
+            Err(_) => continue,
+        };
+
+        if let Some(target) = target_error {
+            if fit.error.max <= target {
+                return Ok(fit);
+            }
+        }
+
+        match best {
+            Some(best) if best.error.max <= fit.error.max => {}
+            _ => best = Some(fit),
+        }
+    }
+
+    let best = best.ok_or_else(|| anyhow!("samples can not be fitted into a curve grid"))?;
+    if let Some(target) = target_error {
+        bail!(
+            "target error {target} is not reachable, the best max error is {} with {} points",
+            best.error.max,
+            best.y_count
+        );
+    }
+
+    Ok(best)
+}
+
+/// Grid of `y_count` points starting at `x0` (in curve units) with Y interpolated from sorted
+/// `samples`
+fn grid(
+    samples: &[(Decimal, Decimal)],
+    decimals: u8,
+    scale: Decimal,
+    x0: u64,
+    x_step: u64,
+    y_count: u64,
+) -> Result<Fit> {
+    let x_end = x0 + x_step * (y_count - 1);
+    if x_end > CurveX::MAX as u64 {
+        bail!("grid end {x_end} is out of X range");
+    }
+
+    let mut y = [0; MAX_Y_CNT];
+    let mut segment = 0;
+
+    for (idx, value) in y.iter_mut().take(y_count as usize).enumerate() {
+        let x = from_u64(x0 + x_step * idx as u64)?.checked_div(scale)?;
+
+        while segment + 2 < samples.len() && samples[segment + 1].0 < x {
+            segment += 1;
+        }
+        let y_scaled =
+            interpolate(samples[segment], samples[segment + 1], x)?.checked_mul(scale)?;
+        *value = CurveY::try_from(round(y_scaled)?)
+            .map_err(|_| anyhow!("y {y_scaled} is out of Y range"))?;
+    }
+
+    let params = CurveParams::new(
+        "",
+        "",
+        x0 as CurveX,
+        x_step as CurveX,
+        y_count as u8,
+        decimals,
+        y,
+    );
+    let curve = Curve::from_init_params((params, Default::default()));
+
+    Ok(Fit {
+        x0: x0 as CurveX,
+        x_step: x_step as CurveX,
+        y_count: y_count as u8,
+        y,
+        error: approximation_error(&curve, samples)?,
+    })
+}
+
+/// Linear interpolation (or extrapolation out of the segment) of Y in `x`
+fn interpolate(
+    (x1, y1): (Decimal, Decimal),
+    (x2, y2): (Decimal, Decimal),
+    x: Decimal,
+) -> Result<Decimal> {
+    if x <= x1 {
+        return Ok(y1);
+    }
+    if x >= x2 {
+        return Ok(y2);
+    }
+
+    let share = x.checked_sub(x1)?.checked_div(x2.checked_sub(x1)?)?;
+    Ok(if y2 >= y1 {
+        y1.checked_add(y2.checked_sub(y1)?.checked_mul(share)?)?
+    } else {
+        y1.checked_sub(y1.checked_sub(y2)?.checked_mul(share)?)?
+    })
+}
+
+fn from_u64(value: u64) -> Result<Decimal> {
+    Ok(Decimal::from_i128_with_scale(value as i128, 0)?)
+}
+
+fn ceil(value: Decimal) -> Result<u64> {
+    let floor = value.floor()?;
+    Ok(if from_u64(floor)? == value {
+        floor
+    } else {
+        floor + 1
+    })
+}
+
+pub(crate) fn round(value: Decimal) -> Result<u64> {
+    Ok(value
+        .checked_add(Decimal::from_i128_with_scale(5, 1)?)?
+        .floor()?)
+}
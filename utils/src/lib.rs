@@ -1,44 +1,248 @@
-use anyhow::Result;
+use anyhow::{anyhow, bail, Result};
+use base64::Engine;
+use solana_sdk::clock::Clock;
 
 use texture_common::account::PodAccount;
 use texture_common::math::{CheckedAdd, CheckedDiv, CheckedMul, CheckedSub, Decimal};
 
-use curvy::state::curve::Curve;
+use curvy::error::CurvyError;
+use curvy::state::curve::{Curve, CurveY, MAX_Y_CNT};
+use curvy::state::curve_confidence::CurveConfidence;
+use curvy::state::curve_series::CurveSeries;
+use curvy::state::grid_curve::{GridCurve, MAX_GRID_Y_CNT};
+use curvy::state::wide_curve::{WideCurve, MAX_WIDE_Y_CNT};
+use curvy::state::{probe, AccountKind};
+
+pub use any_curve::AnyCurve;
+pub use approximation::{approximation_error, ApproximationError};
+pub use curve_set::CurveSet;
+pub use fit::{fit_uniform, Fit};
+pub use pack::{CurvePack, PackEntry, PackSignature, PACK_EXTENSION};
+pub use scaled::{calc_y_scaled, to_scaled, Rounding};
+pub use smooth::{changed_points, smooth, ChangedPoint, SmoothMethod};
+pub use summary::{describe_change, describe_curve, SummaryStyle};
+pub use transform::{apply_y_transform, invert_y_transform, log10, pow10};
+pub use twa::{twa_curve, CurveVersion};
+
+mod any_curve;
+mod approximation;
+mod curve_set;
+mod fit;
+mod pack;
+mod scaled;
+mod smooth;
+mod summary;
+mod transform;
+mod twa;
 
 /// Calculates Y value in given X point using linear interpolation between X0 < X < X1 points.
 /// Expects raw Curvy account data as input.
+/// Samples following the `y` table of curves grown by ResizeCurve are taken into account.
 pub fn calc_y_raw(x: Decimal, curve_account_data: &[u8]) -> Result<Decimal, String> {
-    let curve = Curve::try_from_bytes(curve_account_data)
+    let curve = Curve::read(curve_account_data)
         .map_err(|_err| String::from("error unpacking Curve account"))?;
 
-    calc_y(x, curve).map_err(|err| err.to_string())
+    calc_y_extended(x, &curve, &curve.tail_y(curve_account_data)).map_err(|err| err.to_string())
+}
+
+/// Decodes Curve account from base64 encoded account data (e.g. copied from an explorer).
+pub fn curve_from_base64(data: &str) -> Result<Curve> {
+    let bytes = base64::engine::general_purpose::STANDARD.decode(data.trim())?;
+
+    curve_from_bytes(&bytes)
+}
+
+/// Decodes Curve account data reporting what the data is when it is not a Curve. Accounts
+/// not yet migrated are read as upgraded, see [Curve::read]. Samples following the `y` table
+/// are not part of [Curve], see [Curve::tail_y].
+pub fn curve_from_bytes(data: &[u8]) -> Result<Curve> {
+    match probe(data) {
+        Some(AccountKind::Curve { version }) if version <= Curve::VERSION => {}
+        Some(kind) => bail!("unsupported account: {kind}"),
+        None => bail!("not a Curvy account"),
+    }
+
+    Curve::read(data).map_err(|err| anyhow!("error unpacking Curve account: {}", err))
+}
+
+/// Calculates Y (see [calc_y]) of every curve in every point of `xs`. Row `i` holds values of
+/// `curves[i]`, column `j` values in `xs[j]`. Points out of a curve range give `None`.
+pub fn eval_matrix(curves: &[Curve], xs: &[Decimal]) -> Vec<Vec<Option<Decimal>>> {
+    curves
+        .iter()
+        .map(|curve| xs.iter().map(|x| calc_y(*x, curve).ok()).collect())
+        .collect()
 }
 
 /// Calculates Y value in given X point using linear interpolation between X0 < X < X1 points.
 /// Based on deserialized Curve account
-/// `x` - is human-readable number WITHOUT any knowledge about decimals inside Curve.
+/// `x` - is human-readable number WITHOUT any knowledge about decimals inside Curve. Negative
+/// for curves starting at negative X, see [Curve::x0_negative].
 pub fn calc_y(x1: Decimal, curve: &Curve) -> texture_common::math::MathResult<Decimal> {
-    let &Curve {
-        x0,
-        x_step,
-        y_count,
-        decimals,
-        y,
-        ..
-    } = curve;
+    calc_y_samples(x1, curve, &curve.y[0..curve.y_count as usize])
+}
+
+/// Calculates Y value (see [calc_y]) of the curve as of `clock`. Fails with
+/// [CurvyError::CurveExpired] once the curve is stale, see [Curve::valid_until].
+pub fn calc_y_at(x: Decimal, curve: &Curve, clock: &Clock) -> Result<Decimal, CurvyError> {
+    curve.check_not_expired(clock.unix_timestamp)?;
+
+    Ok(calc_y(x, curve)?)
+}
+
+/// Calculates Y value (see [calc_y]) of a curve which may be consumed, see
+/// [Curve::check_consumable]. Fails with [CurvyError::CurveInactive] while the curve is paused.
+pub fn calc_y_checked(x: Decimal, curve: &Curve) -> Result<Decimal, CurvyError> {
+    curve.check_consumable()?;
+
+    Ok(calc_y(x, curve)?)
+}
+
+/// Interpolates `y` samples on the grid of `curve`, signed when the curve `y` is, see
+/// [Curve::y_signed]. The interpolated value is transformed by [Curve::y_transform].
+fn calc_y_samples(
+    x: Decimal,
+    curve: &Curve,
+    y: &[CurveY],
+) -> texture_common::math::MathResult<Decimal> {
+    let x0 = Decimal::from_i128_with_scale(curve.x0_signed() as i128, 0)?;
+
+    let y = if curve.y_signed != 0 {
+        let y = y.iter().map(|y| *y as i32).collect::<Vec<_>>();
+        calc_y_with_params(&y, curve.x_decimals, curve.y_decimals, curve.x_step, x0, x)?
+    } else {
+        calc_y_with_params(y, curve.x_decimals, curve.y_decimals, curve.x_step, x0, x)?
+    };
+
+    apply_y_transform(curve.y_transform(), y)
+}
 
+/// Calculates Y value (see [calc_y]) of the curve grown by ResizeCurve, `tail` holds samples
+/// following its `y` table, see [Curve::tail_y]
+pub fn calc_y_extended(
+    x: Decimal,
+    curve: &Curve,
+    tail: &[CurveY],
+) -> texture_common::math::MathResult<Decimal> {
+    if tail.is_empty() {
+        return calc_y(x, curve);
+    }
+
+    let mut y = curve.y[..(curve.y_count as usize).min(MAX_Y_CNT)].to_vec();
+    y.extend_from_slice(&tail[..tail.len().min(curve.tail_y_count as usize)]);
+
+    calc_y_samples(x, curve, &y)
+}
+
+/// Calculates Y value of wide curve in given X point, see [calc_y]
+pub fn calc_y_wide(x: Decimal, curve: &WideCurve) -> texture_common::math::MathResult<Decimal> {
     calc_y_with_params(
-        &y[0..y_count as usize],
-        decimals,
-        x_step,
-        Decimal::from_i128_with_scale(x0 as i128, 0)?,
-        x1,
+        &curve.y[..(curve.y_count as usize).min(MAX_WIDE_Y_CNT)],
+        curve.decimals,
+        curve.decimals,
+        curve.x_step,
+        Decimal::from_i128_with_scale(curve.x0 as i128, 0)?,
+        x,
     )
 }
 
-pub fn calc_y_with_params(
-    y: &[u32],
-    decimals: u8,
+/// Calculates Y value of grid curve in given X point, see [calc_y]. The segment holding `x` is
+/// found by binary search over the `x` table.
+pub fn calc_y_grid(x: Decimal, curve: &GridCurve) -> texture_common::math::MathResult<Decimal> {
+    let count = (curve.y_count as usize).min(MAX_GRID_Y_CNT);
+    let (xs, ys) = (&curve.x[..count], &curve.y[..count]);
+    let scaled = |value: u32| Decimal::from_i128_with_scale(value as i128, curve.decimals as u32);
+
+    let (Some(&x_first), Some(&x_last)) = (xs.first(), xs.last()) else {
+        return Err(texture_common::math::MathError(
+            "grid curve has no samples".to_string(),
+        ));
+    };
+    if x < scaled(x_first)? || x > scaled(x_last)? {
+        return Err(texture_common::math::MathError(format!(
+            "x={x} is out of function range {}..={}",
+            scaled(x_first)?,
+            scaled(x_last)?
+        )));
+    }
+
+    // first point after `x`, there is one before it as `x` is in range
+    let post_idx = xs.partition_point(|x_i| scaled(*x_i).is_ok_and(|x_i| x_i <= x));
+
+    let pre_idx = post_idx - 1;
+    let (pre_x, pre_y) = (scaled(xs[pre_idx])?, scaled(ys[pre_idx])?);
+    if pre_x == x || post_idx == count {
+        return Ok(pre_y);
+    }
+    let (post_x, post_y) = (scaled(xs[post_idx])?, scaled(ys[post_idx])?);
+
+    // multiply before dividing: ratio within uneven segments is rarely a finite decimal
+    post_y
+        .checked_sub(pre_y)?
+        .checked_mul(x.checked_sub(pre_x)?)?
+        .checked_div(post_x.checked_sub(pre_x)?)?
+        .checked_add(pre_y)
+}
+
+/// Calculates Y value of series `series_idx` in given X point, see [calc_y]. Series 0 is the
+/// curve `y` table, others are taken from the curve series account.
+pub fn calc_y_series(
+    x: Decimal,
+    curve: &Curve,
+    series: Option<&CurveSeries>,
+    series_idx: u8,
+) -> texture_common::math::MathResult<Decimal> {
+    if series_idx == 0 {
+        return calc_y(x, curve);
+    }
+
+    let y = series
+        .filter(|_| series_idx <= curve.series_count)
+        .and_then(|series| series.series(series_idx))
+        .ok_or(texture_common::math::MathError(format!(
+            "series {series_idx} not found, curve has {} additional series",
+            curve.series_count
+        )))?;
+
+    calc_y_samples(x, curve, &y[0..curve.y_count as usize])
+}
+
+/// Calculates Y value (see [calc_y]) together with its confidence in basis points of Y,
+/// interpolated the same way as Y. Fails when the curve has no confidence.
+pub fn calc_y_with_confidence(
+    x: Decimal,
+    curve: &Curve,
+    confidence: Option<&CurveConfidence>,
+) -> texture_common::math::MathResult<(Decimal, Decimal)> {
+    let confidence =
+        confidence
+            .filter(|_| curve.has_confidence != 0)
+            .ok_or(texture_common::math::MathError(
+                "curve has no confidence".to_string(),
+            ))?;
+
+    let y = calc_y(x, curve)?;
+
+    // interpolated value is scaled down by decimals as Y is, scale it back to basis points
+    let bps = calc_y_with_params(
+        &confidence.confidence[..curve.y_count as usize],
+        curve.x_decimals,
+        curve.y_decimals,
+        curve.x_step,
+        Decimal::from_i128_with_scale(curve.x0_signed() as i128, 0)?,
+        x,
+    )?
+    .checked_mul(Decimal::from_i128_with_scale(10, 0)?.checked_pow(curve.y_decimals as u64)?)?;
+
+    Ok((y, bps))
+}
+
+/// Interpolates `y` samples (with `y_decimals`) on the uniform grid of `x0` and `x_step` (with
+/// `x_decimals`), see [calc_y]
+pub fn calc_y_with_params<Y: Copy + Into<i128>>(
+    y: &[Y],
+    x_decimals: u8,
+    y_decimals: u8,
     x_step: u32,
     x0: Decimal,
     x: Decimal,
@@ -56,7 +260,7 @@ pub fn calc_y_with_params(
 
     // Adjust X to be on the same scale as x0 and x_step
     let x_scaled =
-        x.checked_mul(Decimal::from_i128_with_scale(10, 0)?.checked_pow(decimals as u64)?)?;
+        x.checked_mul(Decimal::from_i128_with_scale(10, 0)?.checked_pow(x_decimals as u64)?)?;
 
     if !(x0..=x_last).contains(&x_scaled) {
         return Err(texture_common::math::MathError(format!(
@@ -75,11 +279,13 @@ pub fn calc_y_with_params(
         // NOTE: for prevent index out of array bounds
         // (when `x` is MAX, `post_x_idx = last_x_idx + 1`)
         return Decimal::from_i128_with_scale(
-            *y.get(pre_x_idx as usize)
+            y.get(pre_x_idx as usize)
+                .copied()
                 .ok_or(texture_common::math::MathError(format!(
                     "get y failure: idx={pre_x_idx}"
-                )))? as i128,
-            decimals as u32,
+                )))?
+                .into(),
+            y_decimals as u32,
         );
     }
 
@@ -108,18 +314,22 @@ pub fn calc_y_with_params(
     };
 
     let pre_y = Decimal::from_i128_with_scale(
-        *y.get(pre_x_idx as usize)
+        y.get(pre_x_idx as usize)
+            .copied()
             .ok_or(texture_common::math::MathError(format!(
                 "get pre y failure, idx={pre_x_idx}"
-            )))? as i128,
-        decimals as u32,
+            )))?
+            .into(),
+        y_decimals as u32,
     )?;
     let post_y = Decimal::from_i128_with_scale(
-        *y.get(post_x_idx as usize)
+        y.get(post_x_idx as usize)
+            .copied()
             .ok_or(texture_common::math::MathError(format!(
                 "get post y failure, idx={post_x_idx}"
-            )))? as i128,
-        decimals as u32,
+            )))?
+            .into(),
+        y_decimals as u32,
     )?;
 
     // count how much percentage x takes up on it's nearest segment
@@ -135,7 +345,7 @@ pub fn calc_y_with_params(
 
 #[cfg(test)]
 mod tests {
-    use curvy::state::curve::{CurveParams, CurveY, MAX_Y_CNT};
+    use curvy::state::curve::{CurveParams, CurveStatus, CurveY, YTransform, MAX_Y_CNT};
     use curvy::state::utils;
     use texture_common::_export::Pubkey;
 
@@ -157,7 +367,8 @@ mod tests {
             x0: 0,
             x_step: 2,
             y_count: Y.len() as u8,
-            decimals: 2,
+            x_decimals: 2,
+            y_decimals: 2,
             y,
         };
 
@@ -203,4 +414,374 @@ mod tests {
             Decimal::from_i128_with_scale((700 + 1_000_000_000) / 2, 2).unwrap()
         );
     }
+
+    #[test]
+    fn negative_x() {
+        let mut y = [0; MAX_Y_CNT];
+        y[..3].copy_from_slice(&[100, 0, 300]);
+        // X grid is -2.5; 0; 2.5 (basis in bps)
+        let params = CurveParams::new("basis", "y=f(x)", 25, 25, 3, 1, y);
+        let mut curve = Curve::from_init_params((params, Pubkey::default()));
+        curve.x0_negative = 1;
+
+        let dec = |value: i128, scale: u32| Decimal::from_i128_with_scale(value, scale).unwrap();
+        assert_eq!(calc_y(dec(-25, 1), &curve).unwrap(), dec(100, 1));
+        assert_eq!(calc_y(dec(-1, 0), &curve).unwrap(), dec(40, 1));
+        assert_eq!(calc_y(Decimal::ZERO, &curve).unwrap(), Decimal::ZERO);
+        assert_eq!(calc_y(dec(25, 1), &curve).unwrap(), dec(300, 1));
+        assert!(
+            calc_y(dec(-3, 0), &curve).is_err(),
+            "out of bounds (before first)"
+        );
+        assert!(
+            calc_y(dec(3, 0), &curve).is_err(),
+            "out of bounds (after last)"
+        );
+    }
+
+    #[test]
+    fn negative_y() {
+        let mut y = [0; MAX_Y_CNT];
+        // funding rate -0.05%, 0.01%, 0.03%
+        y[..3].copy_from_slice(&[-500_i32 as CurveY, 100, 300]);
+        let params = CurveParams::new("funding", "y=f(x)", 0, 10, 3, 4, y);
+        let mut curve = Curve::from_init_params((params, Pubkey::default()));
+
+        let dec = |value: i128, scale: u32| Decimal::from_i128_with_scale(value, scale).unwrap();
+        // unsigned curve reads the bits as a huge value
+        assert!(calc_y(Decimal::ZERO, &curve).unwrap() > Decimal::ZERO);
+
+        curve.y_signed = 1;
+        assert_eq!(calc_y(Decimal::ZERO, &curve).unwrap(), dec(-500, 4));
+        // halfway through the first segment
+        assert_eq!(calc_y(dec(5, 4), &curve).unwrap(), dec(-200, 4));
+        assert_eq!(calc_y(dec(20, 4), &curve).unwrap(), dec(300, 4));
+        assert_eq!(
+            calc_y_extended(dec(5, 4), &curve, &[]).unwrap(),
+            dec(-200, 4)
+        );
+    }
+
+    #[test]
+    fn y_decimals() {
+        let mut y = [0; MAX_Y_CNT];
+        y[..3].copy_from_slice(&[0, 1_000_000, 3_000_000]);
+        // utilization 0%, 50%, 100% in bps, APR with 9 decimals
+        let mut params = CurveParams::new("borrow", "y=f(x)", 0, 5000, 3, 4, y);
+        params.y_decimals = 9;
+        let curve = Curve::from_init_params((params, Pubkey::default()));
+
+        let dec = |value: i128, scale: u32| Decimal::from_i128_with_scale(value, scale).unwrap();
+        assert_eq!(calc_y(dec(5, 1), &curve).unwrap(), dec(1_000_000, 9));
+        assert_eq!(calc_y(dec(75, 2), &curve).unwrap(), dec(2_000_000, 9));
+        assert!(calc_y(dec(11, 1), &curve).is_err());
+    }
+
+    #[test]
+    fn log_y() {
+        let mut y = [0; MAX_Y_CNT];
+        // 0.01, 1 and 10000 stored as log10 with 2 decimals
+        y[..3].copy_from_slice(&[-200_i32 as CurveY, 0, 400]);
+        let params = CurveParams::new("spread", "y=10^f(x)", 0, 10, 3, 2, y);
+        let mut curve = Curve::from_init_params((params, Pubkey::default()));
+        curve.y_signed = 1;
+        curve.y_transform = YTransform::Log10 as u8;
+
+        let dec = |value: i128, scale: u32| Decimal::from_i128_with_scale(value, scale).unwrap();
+        assert_eq!(calc_y(Decimal::ZERO, &curve).unwrap(), dec(1, 2));
+        assert_eq!(calc_y(dec(20, 2), &curve).unwrap(), dec(10_000, 0));
+        // geometric interpolation: halfway between 1 and 10000 is 100
+        assert_eq!(calc_y(dec(15, 2), &curve).unwrap(), dec(100, 0));
+    }
+
+    #[test]
+    fn curve_set() {
+        let y = [0; MAX_Y_CNT];
+        let sol = CurveParams::new("SOL-borrow", "y=x", 0, 1, 2, 0, y);
+        let usdc = CurveParams::new("USDC-borrow", "y=x", 0, 1, 2, 0, y);
+
+        let set = CurveSet::new(
+            [
+                (
+                    Pubkey::new_unique(),
+                    Curve::from_init_params((sol, Pubkey::default())),
+                ),
+                (
+                    Pubkey::new_unique(),
+                    Curve::from_init_params((usdc, Pubkey::default())),
+                ),
+                (
+                    Pubkey::new_unique(),
+                    Curve::from_init_params((usdc, Pubkey::default())),
+                ),
+            ]
+            .into(),
+        );
+
+        assert_eq!(
+            set.eval_by_name("SOL-borrow", Decimal::ONE).unwrap(),
+            Decimal::ZERO
+        );
+        assert!(set.get_by_name("USDC-borrow").is_err(), "ambiguous name");
+        assert!(set.get_by_name("ETH-borrow").is_err(), "unknown name");
+    }
+
+    #[test]
+    fn matrix() {
+        let mut y = [0; MAX_Y_CNT];
+        y[..3].copy_from_slice(&[0, 10, 20]);
+        let rising = CurveParams::new("rising", "y=10x", 0, 1, 3, 0, y);
+        y[..3].copy_from_slice(&[20, 10, 0]);
+        let falling = CurveParams::new("falling", "y=20-10x", 0, 1, 3, 0, y);
+
+        let curves =
+            [rising, falling].map(|params| Curve::from_init_params((params, Pubkey::default())));
+        let xs = [
+            Decimal::ZERO,
+            Decimal::ONE,
+            Decimal::from_i128_with_scale(3, 0).unwrap(),
+        ];
+
+        let value = |y: i128| Some(Decimal::from_i128_with_scale(y, 0).unwrap());
+        assert_eq!(
+            eval_matrix(&curves, &xs),
+            vec![
+                vec![value(0), value(10), None],
+                vec![value(20), value(10), None],
+            ]
+        );
+    }
+
+    #[test]
+    fn approximation() {
+        let mut y = [0; MAX_Y_CNT];
+        y[..2].copy_from_slice(&[0, 100]);
+        // y = x on 0..=1, built from samples of y = x^2
+        let params = CurveParams::new("square", "y=x^2", 0, 100, 2, 2, y);
+        let curve = Curve::from_init_params((params, Pubkey::default()));
+
+        let dec = |value: i128, scale: u32| Decimal::from_i128_with_scale(value, scale).unwrap();
+        let samples = [
+            (dec(0, 0), dec(0, 0)),
+            (dec(5, 1), dec(25, 2)),
+            (dec(1, 0), dec(1, 0)),
+            (dec(2, 0), dec(4, 0)),
+        ];
+
+        let report = approximation_error(&curve, &samples).unwrap();
+        assert_eq!(report.samples, 3);
+        assert_eq!(report.out_of_range, 1);
+        assert_eq!(report.max, dec(25, 2));
+        assert_eq!(report.max_at, Some(dec(5, 1)));
+        assert_eq!(report.mean, dec(25, 2).checked_div(dec(3, 0)).unwrap());
+    }
+
+    #[test]
+    fn fit() {
+        let dec = |value: i128, scale: u32| Decimal::from_i128_with_scale(value, scale).unwrap();
+        // y = 2x on 0.05..=0.95
+        let samples = (5..=95)
+            .map(|x| (dec(x, 2), dec(2 * x, 2)))
+            .collect::<Vec<_>>();
+
+        let fit = fit_uniform(&samples, 2, Some(dec(1, 9))).unwrap();
+        assert_eq!((fit.x0, fit.x_step, fit.y_count), (5, 90, 2));
+        assert_eq!(fit.y[..2], [10, 190]);
+        assert_eq!(fit.error.samples, samples.len());
+
+        assert!(fit_uniform(&samples[..1], 2, None).is_err());
+    }
+
+    #[test]
+    fn series() {
+        let mut y = [0; MAX_Y_CNT];
+        y[..2].copy_from_slice(&[100, 200]);
+        let params = CurveParams::new("mid", "y=x+1", 0, 1, 2, 2, y);
+        let mut curve = Curve::from_init_params((params, Pubkey::default()));
+
+        let mut series = CurveSeries::from_init_params(Pubkey::default());
+        y[..2].copy_from_slice(&[50, 150]);
+        assert!(series.set_series(1, y));
+        curve.series_count = series.count;
+
+        let x = Decimal::from_i128_with_scale(5, 3).unwrap();
+        assert_eq!(
+            calc_y_series(x, &curve, Some(&series), 0).unwrap(),
+            Decimal::from_i128_with_scale(150, 2).unwrap()
+        );
+        assert_eq!(
+            calc_y_series(x, &curve, Some(&series), 1).unwrap(),
+            Decimal::from_i128_with_scale(100, 2).unwrap()
+        );
+        assert!(calc_y_series(x, &curve, Some(&series), 2).is_err());
+        assert!(calc_y_series(x, &curve, None, 1).is_err());
+    }
+
+    #[test]
+    fn confidence() {
+        let mut y = [0; MAX_Y_CNT];
+        y[..2].copy_from_slice(&[100, 200]);
+        let params = CurveParams::new("mid", "y=x+1", 0, 1, 2, 2, y);
+        let mut curve = Curve::from_init_params((params, Pubkey::default()));
+
+        let mut confidence = CurveConfidence::from_init_params(Pubkey::default());
+        confidence.confidence[..2].copy_from_slice(&[10, 30]);
+
+        let x = Decimal::from_i128_with_scale(5, 3).unwrap();
+        assert!(calc_y_with_confidence(x, &curve, Some(&confidence)).is_err());
+
+        curve.has_confidence = 1;
+        assert_eq!(
+            calc_y_with_confidence(x, &curve, Some(&confidence)).unwrap(),
+            (
+                Decimal::from_i128_with_scale(150, 2).unwrap(),
+                Decimal::from_i128_with_scale(20, 0).unwrap()
+            )
+        );
+        assert!(calc_y_with_confidence(x, &curve, None).is_err());
+    }
+
+    #[test]
+    fn grid() {
+        use curvy::state::grid_curve::GridCurveParams;
+
+        let mut x = [0; MAX_GRID_Y_CNT];
+        let mut y = [0; MAX_GRID_Y_CNT];
+        // flat until 0.80, kink, then steep
+        x[..4].copy_from_slice(&[0, 80, 85, 100]);
+        y[..4].copy_from_slice(&[200, 400, 1_000, 10_000]);
+        let params = GridCurveParams::new("SOL-borrow", "kink", 4, 2, x, y);
+        let mut data = vec![0; GridCurve::SIZE];
+        GridCurve::init_bytes(&mut data, (params, Pubkey::default())).unwrap();
+
+        let any = AnyCurve::from_bytes(&data).unwrap();
+        let calc = |x: i128| any.calc_y(Decimal::from_i128_with_scale(x, 2).unwrap());
+        let y = |y: i128| Decimal::from_i128_with_scale(y, 2).unwrap();
+
+        assert_eq!(calc(0).unwrap(), y(200));
+        assert_eq!(calc(40).unwrap(), y(300));
+        assert_eq!(calc(80).unwrap(), y(400));
+        assert_eq!(calc(82).unwrap(), y(640));
+        assert_eq!(calc(95).unwrap(), y(7_000));
+        assert_eq!(calc(100).unwrap(), y(10_000));
+        assert!(calc(101).is_err());
+        assert!(calc(-1).is_err());
+    }
+
+    #[test]
+    fn wide() {
+        use curvy::state::wide_curve::WideCurveParams;
+
+        let mut y = [0; MAX_WIDE_Y_CNT];
+        y[..2].copy_from_slice(&[10_000_000_000, 30_000_000_000]);
+        let params = WideCurveParams::new("depth", "y=kx", 0, 10, 2, 2, y);
+        let mut data = vec![0; WideCurve::SIZE];
+        WideCurve::init_bytes(&mut data, (params, Pubkey::default())).unwrap();
+
+        let any = AnyCurve::from_bytes(&data).unwrap();
+        assert_eq!(
+            any.calc_y(Decimal::from_i128_with_scale(5, 2).unwrap())
+                .unwrap(),
+            Decimal::from_i128_with_scale(20_000_000_000, 2).unwrap()
+        );
+
+        data[0] = 0;
+        assert!(AnyCurve::from_bytes(&data).is_err());
+    }
+
+    #[test]
+    fn matches_on_chain_evaluation() {
+        let mut y = [0; MAX_Y_CNT];
+        y[..Y.len()].copy_from_slice(&Y);
+        let params = CurveParams::new("test curve", "y=f(x)", 3, 7, Y.len() as u8, 2, y);
+        let mut curve = Curve::from_init_params((params, Pubkey::default()));
+        curve.y_decimals = 4;
+        let samples = &curve.y[..Y.len()];
+        let scale = Decimal::from_i128_with_scale(10_000, 0).unwrap();
+
+        for x in 3..=31 {
+            let exact = calc_y(Decimal::from_i128_with_scale(x, 2).unwrap(), &curve)
+                .and_then(|y| y.checked_mul(scale))
+                .and_then(|y| y.floor())
+                .unwrap();
+            assert_eq!(
+                curve.evaluate(samples, x as i64).unwrap() as u64,
+                exact,
+                "x={x}"
+            );
+        }
+    }
+
+    #[test]
+    fn expired_curve_is_not_calculated() {
+        let params = CurveParams::new("test curve", "y=x", 0, 10, 2, 2, [100; MAX_Y_CNT]);
+        let mut curve = Curve::from_init_params((params, Pubkey::default()));
+        let x = Decimal::from_i128_with_scale(5, 2).unwrap();
+        let at = |unix_timestamp| Clock {
+            unix_timestamp,
+            ..Clock::default()
+        };
+
+        assert!(calc_y_at(x, &curve, &at(i64::MAX)).is_ok());
+
+        curve.set_expiry(1_000, 0).unwrap();
+        assert!(calc_y_at(x, &curve, &at(999)).is_ok());
+        assert!(matches!(
+            calc_y_at(x, &curve, &at(1_000)),
+            Err(CurvyError::CurveExpired { valid_until: 1_000 })
+        ));
+
+        // updates move the expiry past their time
+        curve.set_expiry(1_000, 60).unwrap();
+        curve.refresh_validity(2_000);
+        assert_eq!(curve.valid_until(), Some(2_060));
+        assert!(calc_y_at(x, &curve, &at(2_059)).is_ok());
+    }
+
+    #[test]
+    fn paused_curve_is_not_calculated() {
+        let params = CurveParams::new("test curve", "y=x", 0, 10, 2, 2, [100; MAX_Y_CNT]);
+        let mut curve = Curve::from_init_params((params, Pubkey::default()));
+        let x = Decimal::from_i128_with_scale(5, 2).unwrap();
+        assert_eq!(
+            calc_y_checked(x, &curve).unwrap(),
+            calc_y(x, &curve).unwrap()
+        );
+
+        curve.status = CurveStatus::Paused as u8;
+        assert!(curve.is_paused());
+        assert!(matches!(
+            calc_y_checked(x, &curve),
+            Err(CurvyError::CurveInactive)
+        ));
+        // the unchecked evaluation is unaffected
+        assert!(calc_y(x, &curve).is_ok());
+
+        curve.status = CurveStatus::Deprecated as u8;
+        assert!(calc_y_checked(x, &curve).is_ok());
+    }
+
+    #[test]
+    fn unmigrated_curves_are_read() {
+        let params = CurveParams::new("test curve", "y=x", 0, 10, 2, 2, [100; MAX_Y_CNT]);
+        let curve = Curve::from_init_params((params, Pubkey::default()));
+        let x = Decimal::from_i128_with_scale(5, 2).unwrap();
+
+        let mut v3 = vec![0; Curve::SIZE];
+        Curve::init_bytes(&mut v3, (params, Pubkey::default())).unwrap();
+        let mut v2 = v3.clone();
+        v2[8] = 2;
+        // v1 accounts end with the `y` table
+        let mut v1 = v3[..Curve::V1_SIZE].to_vec();
+        v1[8] = 1;
+        for data in [v1, v2] {
+            let read = curve_from_bytes(&data).unwrap();
+            assert_eq!(read.version, Curve::VERSION);
+            assert_eq!((read.name, read.y_decimals), (curve.name, 2));
+            assert_eq!(calc_y_raw(x, &data).unwrap(), calc_y(x, &curve).unwrap());
+        }
+
+        v3[8] = Curve::VERSION + 1;
+        assert!(curve_from_bytes(&v3).is_err());
+    }
 }
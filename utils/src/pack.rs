@@ -0,0 +1,359 @@
+//! `.curvypack` files: a whole set of curves as one binary artifact instead of a folder of
+//! CSVs. A pack is the header followed by `count` entries:
+//!
+//! | bytes | field                                  |
+//! |-------|----------------------------------------|
+//! | 8     | magic `CURVYPAK`                       |
+//! | 1     | format version, [PACK_VERSION]         |
+//! | 4     | `count`, little-endian `u32`           |
+//!
+//! Each entry is borsh of the curve name (`String`), [CurveParams::hash] (32 bytes) and
+//! [CurveParams]. Readers check the hash of every entry, so a corrupted pack is rejected as a
+//! whole. Signs of `x0` and `y` are not part of [CurveParams] and not packed.
+//!
+//! Since version 2 the entries are followed by a byte telling whether the pack is signed, then
+//! the signer key (32 bytes) and ed25519 signature (64 bytes) of the header and entries,
+//! [CurvePack::content]. Readers check the signature against the signer written in the pack,
+//! [CurvePack::verify] checks the signer is the release key. Version 1 packs are unsigned.
+
+use std::collections::HashMap;
+use std::path::Path;
+
+use anyhow::{bail, Context, Result};
+use borsh::BorshDeserialize;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::{Signature, Signer};
+use texture_common::account::PodAccount;
+
+use curvy::state::curve::{Curve, CurveParams};
+
+use crate::CurveSet;
+
+pub const PACK_MAGIC: &[u8; 8] = b"CURVYPAK";
+
+/// Version of the pack format written by [CurvePack::to_bytes]
+pub const PACK_VERSION: u8 = 2;
+
+/// Oldest version read by [CurvePack::from_bytes], without signatures
+const UNSIGNED_PACK_VERSION: u8 = 1;
+
+/// File extension of packs
+pub const PACK_EXTENSION: &str = "curvypack";
+
+const HEADER_SIZE: usize = PACK_MAGIC.len() + 1 + 4;
+
+/// Curve of a pack
+#[derive(Debug, Clone, borsh::BorshSerialize, borsh::BorshDeserialize)]
+pub struct PackEntry {
+    /// Name the curve is created and resolved by, the same as `params.name`
+    pub name: String,
+    /// [CurveParams::hash] of `params`
+    pub hash: [u8; 32],
+    pub params: CurveParams,
+}
+
+impl PackEntry {
+    pub fn new(params: CurveParams) -> Self {
+        Self {
+            name: name_of(&params),
+            hash: params.hash().to_bytes(),
+            params,
+        }
+    }
+}
+
+/// Signature of a pack by its release key
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PackSignature {
+    pub signer: Pubkey,
+    pub signature: Signature,
+}
+
+/// Set of curves with distinct names, see the [module docs](self)
+#[derive(Debug, Clone, Default)]
+pub struct CurvePack {
+    pub entries: Vec<PackEntry>,
+    /// Signature of [CurvePack::content], invalid once entries change
+    pub signature: Option<PackSignature>,
+}
+
+impl CurvePack {
+    /// Pack of curves with `params` sorted by name. Fails on two curves of the same name.
+    pub fn new(params: impl IntoIterator<Item = CurveParams>) -> Result<Self> {
+        let mut entries = params.into_iter().map(PackEntry::new).collect::<Vec<_>>();
+        entries.sort_by(|a, b| a.name.cmp(&b.name));
+
+        if let Some(pair) = entries.windows(2).find(|pair| pair[0].name == pair[1].name) {
+            bail!("two curves are named `{}`", pair[0].name);
+        }
+
+        Ok(Self {
+            entries,
+            signature: None,
+        })
+    }
+
+    /// Header and entries, the signed part of the pack
+    pub fn content(&self) -> Result<Vec<u8>> {
+        let mut data = Vec::with_capacity(HEADER_SIZE);
+        data.extend_from_slice(PACK_MAGIC);
+        data.push(PACK_VERSION);
+        data.extend_from_slice(&u32::try_from(self.entries.len())?.to_le_bytes());
+
+        for entry in &self.entries {
+            borsh::to_writer(&mut data, entry)?;
+        }
+
+        Ok(data)
+    }
+
+    pub fn to_bytes(&self) -> Result<Vec<u8>> {
+        let mut data = self.content()?;
+        match &self.signature {
+            Some(signature) => {
+                data.push(1);
+                data.extend_from_slice(signature.signer.as_ref());
+                data.extend_from_slice(signature.signature.as_ref());
+            }
+            None => data.push(0),
+        }
+
+        Ok(data)
+    }
+
+    /// Signs the pack by `signer`, replacing the previous signature
+    pub fn sign(&mut self, signer: &dyn Signer) -> Result<()> {
+        let signature = signer.try_sign_message(&self.content()?)?;
+        self.signature = Some(PackSignature {
+            signer: signer.try_pubkey()?,
+            signature,
+        });
+
+        Ok(())
+    }
+
+    /// Fails unless the pack is signed by `release_key`
+    pub fn verify(&self, release_key: &Pubkey) -> Result<()> {
+        let Some(signature) = &self.signature else {
+            bail!("pack is not signed, expected signature of {release_key}");
+        };
+        if signature.signer != *release_key {
+            bail!(
+                "pack is signed by {}, expected {release_key}",
+                signature.signer
+            );
+        }
+        if !signature
+            .signature
+            .verify(release_key.as_ref(), &self.content()?)
+        {
+            bail!("pack signature does not match its content");
+        }
+
+        Ok(())
+    }
+
+    /// Decodes a pack checking its header, entry hashes and names
+    pub fn from_bytes(data: &[u8]) -> Result<Self> {
+        if data.len() < HEADER_SIZE || !data.starts_with(PACK_MAGIC) {
+            bail!("not a curve pack");
+        }
+        let version = data[PACK_MAGIC.len()];
+        if !(UNSIGNED_PACK_VERSION..=PACK_VERSION).contains(&version) {
+            bail!("unsupported pack version {version}, expected {PACK_VERSION}");
+        }
+        let count = u32::from_le_bytes(data[PACK_MAGIC.len() + 1..HEADER_SIZE].try_into()?);
+
+        let mut rest = &data[HEADER_SIZE..];
+        let mut entries = vec![];
+        for idx in 0..count {
+            let entry = PackEntry::deserialize(&mut rest)
+                .with_context(|| format!("entry {idx} of {count}"))?;
+
+            if entry.hash != entry.params.hash().to_bytes() {
+                bail!("entry `{}`: hash does not match params", entry.name);
+            }
+            if entry.name != name_of(&entry.params) || entry.name.is_empty() {
+                bail!("entry `{}`: name differs from params", entry.name);
+            }
+            if entries
+                .iter()
+                .any(|other: &PackEntry| other.name == entry.name)
+            {
+                bail!("two entries are named `{}`", entry.name);
+            }
+
+            entries.push(entry);
+        }
+
+        let content = &data[..data.len() - rest.len()];
+        let signature = if version == UNSIGNED_PACK_VERSION {
+            None
+        } else {
+            let signature = read_signature(&mut rest)?;
+            if let Some(PackSignature { signer, signature }) = &signature {
+                if !signature.verify(signer.as_ref(), content) {
+                    bail!("pack signature of {signer} does not match its content");
+                }
+            }
+            signature
+        };
+        if !rest.is_empty() {
+            bail!("{} bytes follow the last entry", rest.len());
+        }
+
+        Ok(Self { entries, signature })
+    }
+
+    pub fn read(path: &Path) -> Result<Self> {
+        let data = std::fs::read(path).with_context(|| format!("reading {}", path.display()))?;
+        Self::from_bytes(&data).with_context(|| format!("pack {}", path.display()))
+    }
+
+    pub fn write(&self, path: &Path) -> Result<()> {
+        std::fs::write(path, self.to_bytes()?)
+            .with_context(|| format!("writing {}", path.display()))
+    }
+
+    pub fn get(&self, name: &str) -> Option<&PackEntry> {
+        self.entries.iter().find(|entry| entry.name == name)
+    }
+
+    /// Curves of the pack owned by `owner` as [CurveSet], for tests and fixtures without RPC.
+    /// A pack has no addresses, curves are keyed by their params hash.
+    pub fn curve_set(&self, owner: Pubkey) -> CurveSet {
+        let curves = self
+            .entries
+            .iter()
+            .map(|entry| {
+                let curve = Curve::from_init_params((entry.params, owner));
+                (Pubkey::new_from_array(entry.hash), curve)
+            })
+            .collect::<HashMap<_, _>>();
+
+        CurveSet::new(curves)
+    }
+}
+
+/// Signature trailer of version 2 packs
+fn read_signature(rest: &mut &[u8]) -> Result<Option<PackSignature>> {
+    const SIGNED_SIZE: usize = 1 + 32 + 64;
+
+    match rest.first() {
+        Some(0) => {
+            *rest = &rest[1..];
+            Ok(None)
+        }
+        Some(1) if rest.len() >= SIGNED_SIZE => {
+            let signer = Pubkey::try_from(&rest[1..33])?;
+            let signature = Signature::try_from(&rest[33..SIGNED_SIZE])?;
+            *rest = &rest[SIGNED_SIZE..];
+            Ok(Some(PackSignature { signer, signature }))
+        }
+        Some(1) => bail!("pack signature is truncated"),
+        Some(flag) => bail!("invalid signature flag {flag}"),
+        None => bail!("pack signature flag is missing"),
+    }
+}
+
+fn name_of(params: &CurveParams) -> String {
+    String::from_utf8_lossy(&params.name)
+        .trim_end_matches('\0')
+        .to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use curvy::state::curve::MAX_Y_CNT;
+    use solana_sdk::signature::Keypair;
+    use texture_common::math::Decimal;
+
+    use super::*;
+
+    fn params(name: &str, y: u32) -> CurveParams {
+        CurveParams::new(name, "y=x", 0, 10, 3, 2, [y; MAX_Y_CNT])
+    }
+
+    #[test]
+    fn pack_roundtrip() {
+        let pack = CurvePack::new([params("usdt", 7), params("usdc", 5)]).unwrap();
+        let data = pack.to_bytes().unwrap();
+        assert!(data.starts_with(PACK_MAGIC));
+
+        let decoded = CurvePack::from_bytes(&data).unwrap();
+        let names = decoded
+            .entries
+            .iter()
+            .map(|entry| entry.name.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(names, ["usdc", "usdt"]);
+        assert_eq!(
+            decoded.get("usdt").unwrap().hash,
+            params("usdt", 7).hash().to_bytes()
+        );
+
+        let set = decoded.curve_set(Pubkey::new_unique());
+        assert_eq!(
+            set.eval_by_name("usdc", Decimal::from_i128_with_scale(5, 2).unwrap())
+                .unwrap(),
+            Decimal::from_i128_with_scale(5, 2).unwrap()
+        );
+    }
+
+    #[test]
+    fn corrupted_pack_is_rejected() {
+        assert!(CurvePack::new([params("usdc", 5), params("usdc", 6)]).is_err());
+
+        let data = CurvePack::new([params("usdc", 5)])
+            .unwrap()
+            .to_bytes()
+            .unwrap();
+        assert!(CurvePack::from_bytes(&data[..data.len() - 1]).is_err());
+        assert!(CurvePack::from_bytes(&[data.as_slice(), &[0]].concat()).is_err());
+
+        let mut version = data.clone();
+        version[PACK_MAGIC.len()] = PACK_VERSION + 1;
+        assert!(CurvePack::from_bytes(&version).is_err());
+
+        // the first sample changed after packing, followed by the signature flag
+        let mut sample = data.clone();
+        let first = sample.len() - 1 - MAX_Y_CNT * std::mem::size_of::<u32>();
+        sample[first] ^= 1;
+        assert!(CurvePack::from_bytes(&sample).is_err());
+    }
+
+    #[test]
+    fn signed_pack_is_verified() {
+        let release = Keypair::new();
+        let mut pack = CurvePack::new([params("usdc", 5)]).unwrap();
+        assert!(pack.verify(&release.pubkey()).is_err());
+
+        pack.sign(&release).unwrap();
+        let data = pack.to_bytes().unwrap();
+        let decoded = CurvePack::from_bytes(&data).unwrap();
+        decoded.verify(&release.pubkey()).unwrap();
+        assert!(decoded.verify(&Pubkey::new_unique()).is_err());
+
+        // entries changed after signing
+        let mut altered = decoded.clone();
+        altered.entries[0] = PackEntry::new(params("usdc", 6));
+        assert!(altered.verify(&release.pubkey()).is_err());
+        assert!(CurvePack::from_bytes(&altered.to_bytes().unwrap()).is_err());
+
+        let mut signature = data.clone();
+        let last = signature.len() - 1;
+        signature[last] ^= 1;
+        assert!(CurvePack::from_bytes(&signature).is_err());
+    }
+
+    #[test]
+    fn unsigned_version_is_read() {
+        let pack = CurvePack::new([params("usdc", 5)]).unwrap();
+        let mut data = pack.content().unwrap();
+        data[PACK_MAGIC.len()] = UNSIGNED_PACK_VERSION;
+
+        let decoded = CurvePack::from_bytes(&data).unwrap();
+        assert_eq!(decoded.entries.len(), 1);
+        assert!(decoded.signature.is_none());
+    }
+}
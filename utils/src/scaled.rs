@@ -0,0 +1,126 @@
+use anyhow::{anyhow, Result};
+use texture_common::math::{CheckedMul, CheckedSub, Decimal};
+
+use curvy::state::curve::Curve;
+
+use crate::calc_y;
+
+/// Rounding of [to_scaled] results which do not fit `decimals`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Rounding {
+    /// Towards negative infinity
+    Down,
+    /// Towards positive infinity
+    Up,
+    /// To the nearest, half away from zero
+    Nearest,
+}
+
+/// [calc_y] as an integer with `decimals` decimals, e.g. 18 for WAD or 9 for lamports, see
+/// [to_scaled]
+pub fn calc_y_scaled<T: TryFrom<i128>>(
+    x: Decimal,
+    curve: &Curve,
+    decimals: u32,
+    rounding: Rounding,
+) -> Result<T> {
+    let y = calc_y(x, curve).map_err(|err| anyhow!("x={x}: {err}"))?;
+    to_scaled(y, decimals, rounding)
+}
+
+/// `value` multiplied by `10^decimals` and rounded to an integer of type `T`, e.g. `u64` or
+/// `u128`. Fails when it does not fit `T`, negative values do not fit unsigned types.
+pub fn to_scaled<T: TryFrom<i128>>(value: Decimal, decimals: u32, rounding: Rounding) -> Result<T> {
+    let overflow = || anyhow!("{value} scaled to {decimals} decimals is out of range");
+
+    let negative = value < Decimal::ZERO;
+    let abs = if negative {
+        Decimal::ZERO.checked_sub(value)?
+    } else {
+        value
+    };
+
+    // integer and fractional parts are scaled separately to keep the whole in i128
+    let int = abs.floor()?;
+    let frac = abs.checked_sub(Decimal::from_i128_with_scale(int as i128, 0)?)?;
+    let frac =
+        frac.checked_mul(Decimal::from_i128_with_scale(10, 0)?.checked_pow(decimals as u64)?)?;
+    let frac_int = frac.floor()?;
+    let rem = frac.checked_sub(Decimal::from_i128_with_scale(frac_int as i128, 0)?)?;
+
+    let mut units = 10_i128
+        .checked_pow(decimals)
+        .and_then(|scale| (int as i128).checked_mul(scale))
+        .and_then(|units| units.checked_add(frac_int as i128))
+        .ok_or_else(overflow)?;
+
+    let half = Decimal::from_i128_with_scale(5, 1)?;
+    let away_from_zero = match rounding {
+        Rounding::Down => negative && rem > Decimal::ZERO,
+        Rounding::Up => !negative && rem > Decimal::ZERO,
+        Rounding::Nearest => rem >= half,
+    };
+    if away_from_zero {
+        units = units.checked_add(1).ok_or_else(overflow)?;
+    }
+    if negative {
+        units = -units;
+    }
+
+    T::try_from(units).map_err(|_| overflow())
+}
+
+#[cfg(test)]
+mod tests {
+    use curvy::state::curve::{CurveParams, MAX_Y_CNT};
+    use texture_common::account::PodAccount;
+
+    use super::*;
+
+    fn value(mantissa: i128, scale: u32) -> Decimal {
+        Decimal::from_i128_with_scale(mantissa, scale).unwrap()
+    }
+
+    #[test]
+    fn rounds_as_requested() {
+        let scaled = |v, rounding| to_scaled::<i128>(v, 2, rounding).unwrap();
+
+        assert_eq!(scaled(value(12345, 4), Rounding::Down), 123);
+        assert_eq!(scaled(value(12345, 4), Rounding::Up), 124);
+        assert_eq!(scaled(value(12345, 4), Rounding::Nearest), 123);
+        assert_eq!(scaled(value(1235, 3), Rounding::Nearest), 124);
+        assert_eq!(scaled(value(123, 2), Rounding::Up), 123);
+
+        assert_eq!(scaled(value(-12345, 4), Rounding::Down), -124);
+        assert_eq!(scaled(value(-12345, 4), Rounding::Up), -123);
+        assert_eq!(scaled(value(-1235, 3), Rounding::Nearest), -124);
+    }
+
+    #[test]
+    fn checks_target_range() {
+        // 0.05 in WAD
+        let wad = to_scaled::<u64>(value(5, 2), 18, Rounding::Down).unwrap();
+        assert_eq!(wad, 50_000_000_000_000_000);
+
+        assert!(to_scaled::<u64>(value(19, 0), 18, Rounding::Down).is_err());
+        assert_eq!(
+            to_scaled::<u128>(value(19, 0), 18, Rounding::Down).unwrap(),
+            19_000_000_000_000_000_000
+        );
+        assert!(to_scaled::<u64>(value(-1, 2), 9, Rounding::Down).is_err());
+    }
+
+    #[test]
+    fn curve_values() {
+        // (0; 2.00), (1; 3.00)
+        let mut y = [0; MAX_Y_CNT];
+        y[..2].copy_from_slice(&[200, 300]);
+        let params = CurveParams::new("", "", 0, 1, 2, 0, y);
+        let mut curve = Curve::from_init_params((params, Default::default()));
+        curve.y_decimals = 2;
+
+        let lamports = calc_y_scaled::<u64>(value(1, 1), &curve, 9, Rounding::Down).unwrap();
+        assert_eq!(lamports, 2_100_000_000);
+        assert!(calc_y_scaled::<u64>(value(2, 0), &curve, 9, Rounding::Down).is_err());
+    }
+}
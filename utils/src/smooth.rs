@@ -0,0 +1,161 @@
+use anyhow::{bail, Result};
+
+use curvy::state::curve::{CurveParams, CurveY};
+
+/// How [smooth] replaces every sample by a value of its window
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "with-serde", derive(serde::Serialize))]
+pub enum SmoothMethod {
+    /// Mean of the window rounded to the nearest, spreads a spike over its neighbours
+    MovingAverage,
+    /// Median of the window, drops single-point spikes and keeps steps sharp
+    Median,
+}
+
+impl std::fmt::Display for SmoothMethod {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Self::MovingAverage => "moving-average",
+            Self::Median => "median",
+        })
+    }
+}
+
+impl std::str::FromStr for SmoothMethod {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "moving-average" => Self::MovingAverage,
+            "median" => Self::Median,
+            _ => return Err(format!("unknown smoothing method `{s}`")),
+        })
+    }
+}
+
+/// Sample changed by [smooth]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "with-serde", derive(serde::Serialize))]
+pub struct ChangedPoint {
+    /// Index of the sample in `y`
+    pub idx: usize,
+    pub before: CurveY,
+    pub after: CurveY,
+}
+
+impl ChangedPoint {
+    pub fn change(&self) -> CurveY {
+        self.before.abs_diff(self.after)
+    }
+}
+
+/// `params` with every `y` sample replaced by `method` over the centered window of `window`
+/// samples (odd). Windows shrink symmetrically near the ends, so the first and the last
+/// samples are kept. `y` is taken as unsigned, see [curvy::state::curve::Curve::y_signed].
+pub fn smooth(params: &CurveParams, method: SmoothMethod, window: usize) -> Result<CurveParams> {
+    if window % 2 != 1 {
+        bail!("window of {window} samples is not odd");
+    }
+    let count = params.y_count as usize;
+    if count > params.y.len() {
+        bail!("y_count {count} exceeds the y table");
+    }
+
+    let half = window / 2;
+    let y = &params.y[..count];
+    let mut smoothed = *params;
+    for (idx, value) in smoothed.y[..count].iter_mut().enumerate() {
+        let reach = half.min(idx).min(count - 1 - idx);
+        let window = &y[idx - reach..=idx + reach];
+
+        *value = match method {
+            SmoothMethod::MovingAverage => {
+                let sum = window.iter().map(|y| *y as u64).sum::<u64>();
+                let len = window.len() as u64;
+                ((sum + len / 2) / len) as CurveY
+            }
+            SmoothMethod::Median => {
+                let mut window = window.to_vec();
+                window.sort_unstable();
+                window[window.len() / 2]
+            }
+        };
+    }
+
+    Ok(smoothed)
+}
+
+/// Samples of `before` changed in `after` by more than `threshold` raw units, e.g. by
+/// [smooth]. Samples past the shorter `y_count` are not compared.
+pub fn changed_points(
+    before: &CurveParams,
+    after: &CurveParams,
+    threshold: CurveY,
+) -> Vec<ChangedPoint> {
+    let count = before.y_count.min(after.y_count) as usize;
+
+    before.y[..count]
+        .iter()
+        .zip(&after.y[..count])
+        .enumerate()
+        .map(|(idx, (before, after))| ChangedPoint {
+            idx,
+            before: *before,
+            after: *after,
+        })
+        .filter(|point| point.change() > threshold)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use curvy::state::curve::MAX_Y_CNT;
+
+    use super::*;
+
+    fn params(samples: &[CurveY]) -> CurveParams {
+        let mut y = [0; MAX_Y_CNT];
+        y[..samples.len()].copy_from_slice(samples);
+        CurveParams::new("usdc", "y=x", 0, 10, samples.len() as u8, 2, y)
+    }
+
+    #[test]
+    fn spike_is_repaired() {
+        let noisy = params(&[100, 200, 9_000, 400, 500]);
+
+        let median = smooth(&noisy, SmoothMethod::Median, 3).unwrap();
+        assert_eq!(&median.y[..5], &[100, 200, 400, 500, 500]);
+        assert_eq!(
+            changed_points(&noisy, &median, 100),
+            [ChangedPoint {
+                idx: 2,
+                before: 9_000,
+                after: 400,
+            }]
+        );
+
+        // the spike is spread over its neighbours
+        let average = smooth(&noisy, SmoothMethod::MovingAverage, 3).unwrap();
+        assert_eq!(&average.y[..5], &[100, 3_100, 3_200, 3_300, 500]);
+        assert_eq!(changed_points(&noisy, &average, 0).len(), 3);
+
+        // windows shrink at the ends
+        let wide = smooth(&noisy, SmoothMethod::Median, 5).unwrap();
+        assert_eq!(&wide.y[..5], &[100, 200, 400, 500, 500]);
+    }
+
+    #[test]
+    fn window_is_odd() {
+        let noisy = params(&[1, 2, 3]);
+        assert!(smooth(&noisy, SmoothMethod::Median, 2).is_err());
+        assert_eq!(
+            smooth(&noisy, SmoothMethod::MovingAverage, 1).unwrap().y,
+            noisy.y
+        );
+        assert_eq!(
+            "moving-average".parse::<SmoothMethod>(),
+            Ok(SmoothMethod::MovingAverage)
+        );
+        assert!("mean".parse::<SmoothMethod>().is_err());
+    }
+}
@@ -0,0 +1,196 @@
+use anyhow::{anyhow, Result};
+use texture_common::math::{CheckedMul, CheckedSub, Decimal};
+
+use curvy::state::curve::Curve;
+
+use crate::calc_y;
+use crate::fit::round;
+
+/// Wording of curve summaries written for proposal reviewers, see [describe_curve] and
+/// [describe_change]
+#[derive(Debug, Clone)]
+pub struct SummaryStyle {
+    /// What `y` is, e.g. `Borrow APR`
+    pub subject: String,
+    /// What `x` is, e.g. `utilization`
+    pub x_label: String,
+    /// `x` is a fraction shown in percent, e.g. `0.8` as `80%`
+    pub x_percent: bool,
+    /// `y` is a fraction shown in percent
+    pub y_percent: bool,
+    /// Max decimals of shown values (of percent when shown in percent)
+    pub precision: u32,
+    /// Reference `x` points. The first, middle and last samples of the curve when empty.
+    pub points: Vec<Decimal>,
+}
+
+impl SummaryStyle {
+    fn at(&self, x: Decimal) -> Result<String> {
+        let x = format_value(x, self.x_percent, self.precision)?;
+        Ok(match (self.x_label.is_empty(), self.x_percent) {
+            (true, _) => format!("at {x}"),
+            (false, true) => format!("at {x} {}", self.x_label),
+            (false, false) => format!("at {} {x}", self.x_label),
+        })
+    }
+
+    fn y(&self, y: Option<Decimal>) -> Result<String> {
+        match y {
+            Some(y) => format_value(y, self.y_percent, self.precision),
+            None => Ok("undefined".to_string()),
+        }
+    }
+
+    /// Configured points, or reference points of `curve`
+    fn points(&self, curve: &Curve) -> Result<Vec<Decimal>> {
+        if !self.points.is_empty() {
+            return Ok(self.points.clone());
+        }
+
+        let last = curve.y_count.saturating_sub(1) as i128;
+        let mut idxs = vec![0, last / 2, last];
+        idxs.dedup();
+
+        idxs.into_iter()
+            .map(|idx| {
+                let x = curve.x0_signed() as i128 + idx * curve.x_step as i128;
+                Ok(Decimal::from_i128_with_scale(x, curve.x_decimals as u32)?)
+            })
+            .collect()
+    }
+}
+
+/// One sentence per reference point, e.g. `Borrow APR at 80% utilization is 6.2%`
+pub fn describe_curve(curve: &Curve, style: &SummaryStyle) -> Result<Vec<String>> {
+    style
+        .points(curve)?
+        .into_iter()
+        .map(|x| {
+            let y = calc_y(x, curve).ok();
+            Ok(format!(
+                "{} {} is {}",
+                style.subject,
+                style.at(x)?,
+                style.y(y)?
+            ))
+        })
+        .collect()
+}
+
+/// One sentence per reference point comparing curve versions, e.g.
+/// `Borrow APR at 80% utilization changes from 6.2% to 9.5%`. Default reference points are
+/// those of the `to` curve.
+pub fn describe_change(from: &Curve, to: &Curve, style: &SummaryStyle) -> Result<Vec<String>> {
+    style
+        .points(to)?
+        .into_iter()
+        .map(|x| {
+            let before = calc_y(x, from).ok();
+            let after = calc_y(x, to).ok();
+            let change = match (style.y(before)?, style.y(after)?) {
+                _ if before.is_none() && after.is_none() => "is undefined".to_string(),
+                (before, after) if before == after => format!("stays at {after}"),
+                (before, after) => format!("changes from {before} to {after}"),
+            };
+            Ok(format!("{} {} {change}", style.subject, style.at(x)?))
+        })
+        .collect()
+}
+
+/// `value` rounded half away from zero to `precision` decimals, trailing zeros dropped
+fn format_value(value: Decimal, percent: bool, precision: u32) -> Result<String> {
+    let decimals = precision + if percent { 2 } else { 0 };
+    let scale = Decimal::from_i128_with_scale(10_i128.pow(decimals), 0)?;
+
+    let negative = value < Decimal::ZERO;
+    let abs = if negative {
+        Decimal::ZERO.checked_sub(value)?
+    } else {
+        value
+    };
+    let units = round(abs.checked_mul(scale)?)
+        .map_err(|err| anyhow!("value {value} can not be shown: {err}"))?;
+
+    let divisor = 10_u64.pow(precision);
+    let mut text = (units / divisor).to_string();
+    let fraction = format!("{:0width$}", units % divisor, width = precision as usize);
+    let fraction = fraction.trim_end_matches('0');
+    if !fraction.is_empty() {
+        text = format!("{text}.{fraction}");
+    }
+    if negative && units > 0 {
+        text.insert(0, '-');
+    }
+    if percent {
+        text.push('%');
+    }
+
+    Ok(text)
+}
+
+#[cfg(test)]
+mod tests {
+    use curvy::state::curve::{CurveParams, MAX_Y_CNT};
+    use texture_common::account::PodAccount;
+
+    use super::*;
+
+    fn apr(y: [u32; 3]) -> Curve {
+        // utilization 0%, 50%, 100% with 4 decimals
+        let mut samples = [0; MAX_Y_CNT];
+        samples[..3].copy_from_slice(&y);
+        let params = CurveParams::new("", "", 0, 5000, 3, 4, samples);
+        Curve::from_init_params((params, Default::default()))
+    }
+
+    fn style(points: Vec<Decimal>) -> SummaryStyle {
+        SummaryStyle {
+            subject: "Borrow APR".to_string(),
+            x_label: "utilization".to_string(),
+            x_percent: true,
+            y_percent: true,
+            precision: 2,
+            points,
+        }
+    }
+
+    #[test]
+    fn sentences() {
+        let from = apr([200, 400, 1200]);
+        let to = apr([200, 500, 1500]);
+
+        assert_eq!(
+            describe_curve(&from, &style(vec![])).unwrap(),
+            [
+                "Borrow APR at 0% utilization is 2%",
+                "Borrow APR at 50% utilization is 4%",
+                "Borrow APR at 100% utilization is 12%",
+            ]
+        );
+
+        let points = vec![
+            Decimal::from_i128_with_scale(0, 0).unwrap(),
+            Decimal::from_i128_with_scale(8, 1).unwrap(),
+            Decimal::from_i128_with_scale(12, 1).unwrap(),
+        ];
+        assert_eq!(
+            describe_change(&from, &to, &style(points)).unwrap(),
+            [
+                "Borrow APR at 0% utilization stays at 2%",
+                "Borrow APR at 80% utilization changes from 8.8% to 11%",
+                "Borrow APR at 120% utilization is undefined",
+            ]
+        );
+    }
+
+    #[test]
+    fn values() {
+        let value = |mantissa, scale| Decimal::from_i128_with_scale(mantissa, scale).unwrap();
+
+        assert_eq!(format_value(value(62, 3), true, 2).unwrap(), "6.2%");
+        assert_eq!(format_value(value(123456, 5), false, 2).unwrap(), "1.23");
+        assert_eq!(format_value(value(-125, 2), false, 1).unwrap(), "-1.3");
+        assert_eq!(format_value(value(-1, 4), false, 2).unwrap(), "0");
+        assert_eq!(format_value(value(15, 0), false, 0).unwrap(), "15");
+    }
+}
@@ -0,0 +1,132 @@
+use texture_common::math::{
+    CheckedAdd, CheckedDiv, CheckedMul, CheckedSub, Decimal, MathError, MathResult,
+};
+
+use curvy::state::curve::YTransform;
+
+/// `ln(10)` with 18 decimals
+const LN_10: i128 = 2_302585092994045684;
+
+/// Terms of the `e^z` series, enough for `z < ln(10)` to vanish at 18 decimals
+const EXP_TERMS: i128 = 40;
+
+/// Value of the curve from the interpolated stored `y`, see [YTransform]
+pub fn apply_y_transform(transform: YTransform, y: Decimal) -> MathResult<Decimal> {
+    match transform {
+        YTransform::Linear => Ok(y),
+        YTransform::Log10 => pow10(y),
+    }
+}
+
+/// Stored `y` of `value` with `decimals` decimals (rounded down), the inverse of
+/// [apply_y_transform]. Fails for non-positive values of logarithmic transforms.
+pub fn invert_y_transform(
+    transform: YTransform,
+    value: Decimal,
+    decimals: u32,
+) -> MathResult<Decimal> {
+    match transform {
+        YTransform::Linear => Ok(value),
+        YTransform::Log10 => log10(value, decimals),
+    }
+}
+
+/// `10^exp`, the integer part of `exp` is raised exactly and the fractional one as `e^(f*ln10)`
+pub fn pow10(exp: Decimal) -> MathResult<Decimal> {
+    let one = Decimal::from_i128_with_scale(1, 0)?;
+    if exp < Decimal::ZERO {
+        return one.checked_div(pow10(Decimal::ZERO.checked_sub(exp)?)?);
+    }
+
+    let int = exp.floor()?;
+    let frac = exp.checked_sub(Decimal::from_i128_with_scale(int as i128, 0)?)?;
+    let z = frac.checked_mul(Decimal::from_i128_with_scale(LN_10, 18)?)?;
+
+    // e^z = sum of z^k / k!
+    let mut term = one;
+    let mut sum = one;
+    for k in 1..=EXP_TERMS {
+        term = term
+            .checked_mul(z)?
+            .checked_div(Decimal::from_i128_with_scale(k, 0)?)?;
+        if term == Decimal::ZERO {
+            break;
+        }
+        sum = sum.checked_add(term)?;
+    }
+
+    Decimal::from_i128_with_scale(10, 0)?
+        .checked_pow(int)?
+        .checked_mul(sum)
+}
+
+/// `log10(value)` with `decimals` decimals rounded down, found digit by digit with [pow10]
+pub fn log10(value: Decimal, decimals: u32) -> MathResult<Decimal> {
+    if value <= Decimal::ZERO {
+        return Err(MathError(format!("log10 of non-positive {value}")));
+    }
+    let ten = Decimal::from_i128_with_scale(10, 0)?;
+    let scale = 10_i128
+        .checked_pow(decimals)
+        .ok_or(MathError(format!("log10 to {decimals} decimals")))?;
+
+    // integer part: 10^int <= value < 10^(int + 1)
+    let mut int = 0_i128;
+    let mut power = Decimal::from_i128_with_scale(1, 0)?;
+    while value >= power.checked_mul(ten)? {
+        power = power.checked_mul(ten)?;
+        int += 1;
+    }
+    while value < power {
+        power = power.checked_div(ten)?;
+        int -= 1;
+    }
+
+    // the largest fraction `lo / scale` with 10^(int + lo / scale) <= value
+    let (mut lo, mut hi) = (0_i128, scale);
+    while hi - lo > 1 {
+        let mid = (lo + hi) / 2;
+        if pow10(Decimal::from_i128_with_scale(int * scale + mid, decimals)?)? <= value {
+            lo = mid;
+        } else {
+            hi = mid;
+        }
+    }
+
+    Decimal::from_i128_with_scale(int * scale + lo, decimals)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn value(mantissa: i128, scale: u32) -> Decimal {
+        Decimal::from_i128_with_scale(mantissa, scale).unwrap()
+    }
+
+    #[test]
+    fn powers_of_ten() {
+        assert_eq!(pow10(value(2, 0)).unwrap(), value(100, 0));
+        assert_eq!(pow10(value(-2, 0)).unwrap(), value(1, 2));
+        assert_eq!(pow10(Decimal::ZERO).unwrap(), value(1, 0));
+
+        // 10^0.5 = 3.16227766...
+        let root = pow10(value(5, 1)).unwrap();
+        assert!(root > value(316227766, 8) && root < value(316227767, 8));
+    }
+
+    #[test]
+    fn log_is_inverse_of_power() {
+        assert_eq!(log10(value(100, 0), 6).unwrap(), value(2, 0));
+        assert_eq!(log10(value(1, 3), 6).unwrap(), value(-3, 0));
+        assert_eq!(log10(value(316227767, 8), 6).unwrap(), value(500000, 6));
+        assert_eq!(log10(value(2, 0), 6).unwrap(), value(301029, 6));
+        assert_eq!(log10(value(5, 1), 6).unwrap(), value(-301030, 6));
+        assert!(log10(Decimal::ZERO, 6).is_err());
+        assert!(log10(value(-1, 0), 6).is_err());
+
+        let stored = invert_y_transform(YTransform::Log10, value(250, 0), 6).unwrap();
+        let restored = apply_y_transform(YTransform::Log10, stored).unwrap();
+        assert!(restored <= value(250, 0) && restored > value(24999, 2));
+    }
+}
@@ -0,0 +1,163 @@
+use anyhow::{anyhow, bail, Result};
+use texture_common::math::{CheckedAdd, CheckedDiv, CheckedMul, CheckedSub, Decimal};
+
+use curvy::state::curve::{Curve, CurveY};
+
+use crate::calc_y;
+use crate::fit::round;
+use crate::transform::invert_y_transform;
+
+/// Curve state active from `since` (unix timestamp) until the next version
+#[derive(Debug, Clone, Copy)]
+pub struct CurveVersion {
+    pub since: i64,
+    pub curve: Curve,
+}
+
+/// Time-weighted average of the curve `history` (sorted by `since`) over `from..to`: every `y`
+/// sample is the average of versions weighted by the time each was active within the window.
+/// Time before the first version is not counted.
+///
+/// The result has the grid of the version active at `to`, versions with another grid take part
+/// with values interpolated at its sample points, so they must cover its X range. Samples
+/// following the `y` table (see [Curve::tail_y]) are not averaged. Values are averaged, not
+/// stored samples, so curves of [Curve::y_transform] other than linear average in value space.
+pub fn twa_curve(history: &[CurveVersion], from: i64, to: i64) -> Result<Curve> {
+    if from >= to {
+        bail!("empty window {from}..{to}");
+    }
+    if history.windows(2).any(|pair| pair[0].since > pair[1].since) {
+        bail!("history is not sorted by time");
+    }
+
+    let weighted = history
+        .iter()
+        .enumerate()
+        .filter_map(|(idx, version)| {
+            let until = history.get(idx + 1).map_or(to, |next| next.since.min(to));
+            let active = until - version.since.max(from);
+            (active > 0).then_some((&version.curve, active))
+        })
+        .collect::<Vec<_>>();
+
+    let Some((latest, _)) = weighted.last() else {
+        bail!("no curve versions active in {from}..{to}");
+    };
+    let total = weighted.iter().map(|(_, active)| *active).sum::<i64>();
+
+    let mut average = **latest;
+    let scale = Decimal::from_i128_with_scale(10, 0)?.checked_pow(latest.y_decimals as u64)?;
+    for (idx, y) in average
+        .y
+        .iter_mut()
+        .take(latest.y_count as usize)
+        .enumerate()
+    {
+        let x = Decimal::from_i128_with_scale(
+            latest.x0_signed() as i128 + idx as i128 * latest.x_step as i128,
+            latest.x_decimals as u32,
+        )?;
+
+        let mut sum = Decimal::ZERO;
+        for (curve, active) in &weighted {
+            let value = calc_y(x, curve).map_err(|err| anyhow!("x={x}: {err}"))?;
+            sum = sum.checked_add(
+                value.checked_mul(Decimal::from_i128_with_scale(*active as i128, 0)?)?,
+            )?;
+        }
+
+        let value = invert_y_transform(
+            latest.y_transform(),
+            sum.checked_div(Decimal::from_i128_with_scale(total as i128, 0)?)?,
+            latest.y_decimals as u32,
+        )
+        .map_err(|err| anyhow!("x={x}: {err}"))?
+        .checked_mul(scale)?;
+        *y = to_sample(latest, value)?;
+    }
+
+    Ok(average)
+}
+
+/// `value` scaled by decimals rounded to the sample of `curve`, see [Curve::y_value]
+fn to_sample(curve: &Curve, value: Decimal) -> Result<CurveY> {
+    let out_of_range = || anyhow!("y {value} is out of Y range");
+
+    if curve.y_signed == 0 {
+        return CurveY::try_from(round(value)?).map_err(|_| out_of_range());
+    }
+
+    let signed = if value < Decimal::ZERO {
+        -(round(Decimal::ZERO.checked_sub(value)?)? as i128)
+    } else {
+        round(value)? as i128
+    };
+    Ok(i32::try_from(signed).map_err(|_| out_of_range())? as CurveY)
+}
+
+#[cfg(test)]
+mod tests {
+    use curvy::state::curve::{CurveParams, MAX_Y_CNT};
+    use texture_common::account::PodAccount;
+
+    use super::*;
+
+    fn version(since: i64, x_step: u32, y_count: u8, y: CurveY) -> CurveVersion {
+        let params = CurveParams::new("", "", 0, x_step, y_count, 2, [y; MAX_Y_CNT]);
+        CurveVersion {
+            since,
+            curve: Curve::from_init_params((params, Default::default())),
+        }
+    }
+
+    #[test]
+    fn weights_by_active_time() {
+        let history = [
+            version(0, 100, 3, 100),
+            version(30, 100, 3, 200),
+            version(90, 100, 3, 400),
+        ];
+
+        // 100 for 20s, 200 for 60s, 400 for 20s
+        let twa = twa_curve(&history, 10, 110).unwrap();
+        assert_eq!(&twa.y[..3], &[220, 220, 220]);
+
+        // before the first version nothing is counted
+        let twa = twa_curve(&history, -100, 30).unwrap();
+        assert_eq!(&twa.y[..3], &[100, 100, 100]);
+
+        assert!(twa_curve(&history[1..], 0, 20).is_err());
+        assert!(twa_curve(&history, 20, 20).is_err());
+    }
+
+    #[test]
+    fn grid_of_latest_version() {
+        // twice denser grid over the same range, constant values keep interpolation exact
+        let history = [version(0, 100, 3, 100), version(10, 50, 5, 300)];
+
+        let twa = twa_curve(&history, 0, 20).unwrap();
+        assert_eq!(twa.y_count, 5);
+        assert_eq!(twa.x_step, 50);
+        assert_eq!(&twa.y[..5], &[200; 5]);
+
+        // latest grid is out of the range of the first version
+        let history = [version(0, 100, 2, 100), version(10, 100, 3, 300)];
+        assert!(twa_curve(&history, 0, 20).is_err());
+    }
+
+    #[test]
+    fn signed_values() {
+        let mut history = [
+            version(0, 100, 3, -300_i32 as CurveY),
+            version(10, 100, 3, 100),
+        ];
+        history[0].curve.y_signed = 1;
+        history[1].curve.y_signed = 1;
+
+        // -300 for 10s, 100 for 30s
+        let twa = twa_curve(&history, 0, 40).unwrap();
+        assert_eq!(twa.y_value(twa.y[0]), 0);
+        let twa = twa_curve(&history, 0, 20).unwrap();
+        assert_eq!(twa.y_value(twa.y[1]), -100);
+    }
+}